@@ -0,0 +1,22 @@
+use std::time::Instant;
+
+/// Run `f` `warmup` times untimed, then `iterations` times timed, and print a
+/// single `zinc-bench` summary line (in nanoseconds) for `zinc bench` to parse.
+pub fn run_bench<F: FnMut()>(warmup: u64, iterations: u64, mut f: F) {
+    for _ in 0..warmup {
+        f();
+    }
+    let iterations = iterations.max(1);
+    let mut total: u128 = 0;
+    let mut min = u128::MAX;
+    let mut max: u128 = 0;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        f();
+        let elapsed = start.elapsed().as_nanos();
+        total += elapsed;
+        min = min.min(elapsed);
+        max = max.max(elapsed);
+    }
+    println!("zinc-bench iterations={} total_ns={} min_ns={} max_ns={}", iterations, total, min, max);
+}
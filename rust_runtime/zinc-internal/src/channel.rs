@@ -71,6 +71,14 @@ impl<T> Clone for Channel<T> {
     }
 }
 
+impl<T> std::fmt::Debug for Channel<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Channel")
+            .field("closed", &self.closed.load(std::sync::atomic::Ordering::SeqCst))
+            .finish_non_exhaustive()
+    }
+}
+
 impl<T: Send + 'static> Channel<T> {
     pub fn bounded(capacity: i64) -> Self {
         let (sender, receiver) = tokio::sync::mpsc::channel(capacity as usize);
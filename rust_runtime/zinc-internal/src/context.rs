@@ -1,6 +1,6 @@
 use crate::Channel;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Context {
     done: Channel<bool>,
 }
@@ -0,0 +1,45 @@
+/// Format a number by a spec such as ",.2f": an optional `,` that groups
+/// thousands, an optional `.N` precision, and a style of `f` (fixed), `%`
+/// (percent), or `e` (scientific). Zinc checks the spec when it compiles.
+pub fn format_number(value: f64, spec: &str) -> String {
+    let grouped = spec.starts_with(',');
+    let rest = spec.trim_start_matches(',');
+    let (precision, style) = match rest.strip_prefix('.') {
+        Some(tail) => {
+            let end = tail.find(|c: char| !c.is_ascii_digit()).unwrap_or(tail.len());
+            (Some(tail[..end].parse::<usize>().expect("a precision is a number")), &tail[end..])
+        }
+        None => (None, rest),
+    };
+    let text = match (style, precision) {
+        ("e", Some(precision)) => format!("{:.*e}", precision, value),
+        ("e", None) => format!("{:e}", value),
+        ("%", precision) => format!("{:.*}%", precision.unwrap_or(6), value * 100.0),
+        ("f", precision) => format!("{:.*}", precision.unwrap_or(6), value),
+        ("", Some(precision)) => format!("{:.*}", precision, value),
+        ("", None) => format!("{}", value),
+        _ => panic!("invalid number format '{}'", spec),
+    };
+    if grouped {
+        group_thousands(&text)
+    } else {
+        text
+    }
+}
+
+fn group_thousands(text: &str) -> String {
+    let (sign, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", text),
+    };
+    let end = unsigned.find(|c: char| !c.is_ascii_digit()).unwrap_or(unsigned.len());
+    let (whole, tail) = unsigned.split_at(end);
+    let mut grouped = String::with_capacity(text.len() + whole.len() / 3);
+    for (index, digit) in whole.chars().enumerate() {
+        if index > 0 && (whole.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    format!("{}{}{}", sign, grouped, tail)
+}
@@ -0,0 +1,210 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+
+/// Values that `to_json()` can serialize; `@derive(json)` implements it for structs and enums.
+pub trait ToJson {
+    fn write_json(&self, out: &mut String);
+}
+
+pub fn to_json<T: ToJson + ?Sized>(value: &T) -> String {
+    let mut out = String::new();
+    value.write_json(&mut out);
+    out
+}
+
+/// Write `"name":` for one object member.
+pub fn write_json_key(out: &mut String, name: &str) {
+    name.write_json(out);
+    out.push(':');
+}
+
+macro_rules! json_number {
+    ($($ty:ty),*) => {
+        $(impl ToJson for $ty {
+            fn write_json(&self, out: &mut String) {
+                let _ = write!(out, "{}", self);
+            }
+        })*
+    };
+}
+
+json_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! json_float {
+    ($($ty:ty),*) => {
+        $(impl ToJson for $ty {
+            fn write_json(&self, out: &mut String) {
+                // JSON has no NaN or infinities.
+                if self.is_finite() {
+                    let _ = write!(out, "{:?}", self);
+                } else {
+                    out.push_str("null");
+                }
+            }
+        })*
+    };
+}
+
+json_float!(f32, f64);
+
+impl ToJson for bool {
+    fn write_json(&self, out: &mut String) {
+        out.push_str(if *self { "true" } else { "false" });
+    }
+}
+
+impl ToJson for str {
+    fn write_json(&self, out: &mut String) {
+        out.push('"');
+        for ch in self.chars() {
+            match ch {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                ch if (ch as u32) < 0x20 => {
+                    let _ = write!(out, "\\u{:04x}", ch as u32);
+                }
+                ch => out.push(ch),
+            }
+        }
+        out.push('"');
+    }
+}
+
+impl ToJson for String {
+    fn write_json(&self, out: &mut String) {
+        self.as_str().write_json(out);
+    }
+}
+
+impl ToJson for char {
+    fn write_json(&self, out: &mut String) {
+        self.to_string().write_json(out);
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for &T {
+    fn write_json(&self, out: &mut String) {
+        (**self).write_json(out);
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for Box<T> {
+    fn write_json(&self, out: &mut String) {
+        (**self).write_json(out);
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn write_json(&self, out: &mut String) {
+        match self {
+            Some(value) => value.write_json(out),
+            None => out.push_str("null"),
+        }
+    }
+}
+
+fn write_array<'a, T: ToJson + 'a>(out: &mut String, items: impl Iterator<Item = &'a T>) {
+    out.push('[');
+    for (index, item) in items.enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        item.write_json(out);
+    }
+    out.push(']');
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn write_json(&self, out: &mut String) {
+        write_array(out, self.iter());
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn write_json(&self, out: &mut String) {
+        write_array(out, self.iter());
+    }
+}
+
+impl<T: ToJson> ToJson for BTreeSet<T> {
+    fn write_json(&self, out: &mut String) {
+        write_array(out, self.iter());
+    }
+}
+
+impl<T: ToJson, S> ToJson for HashSet<T, S> {
+    fn write_json(&self, out: &mut String) {
+        // Hash order changes from run to run; sorting the encoded items keeps the output stable.
+        let mut items: Vec<String> = self.iter().map(to_json).collect();
+        items.sort();
+        out.push('[');
+        out.push_str(&items.join(","));
+        out.push(']');
+    }
+}
+
+/// Object keys are strings in JSON, so other keys are written as their JSON text in quotes.
+fn json_key<K: ToJson>(key: &K) -> String {
+    let text = to_json(key);
+    if text.starts_with('"') {
+        text
+    } else {
+        to_json(&text)
+    }
+}
+
+fn write_object(out: &mut String, entries: Vec<(String, String)>) {
+    out.push('{');
+    for (index, (key, value)) in entries.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        out.push_str(key);
+        out.push(':');
+        out.push_str(value);
+    }
+    out.push('}');
+}
+
+impl<K: ToJson, V: ToJson> ToJson for BTreeMap<K, V> {
+    fn write_json(&self, out: &mut String) {
+        write_object(out, self.iter().map(|(key, value)| (json_key(key), to_json(value))).collect());
+    }
+}
+
+impl<K: ToJson, V: ToJson, S> ToJson for HashMap<K, V, S> {
+    fn write_json(&self, out: &mut String) {
+        let mut entries: Vec<(String, String)> = self.iter().map(|(key, value)| (json_key(key), to_json(value))).collect();
+        entries.sort();
+        write_object(out, entries);
+    }
+}
+
+macro_rules! json_tuple {
+    ($(($($name:ident $index:tt),+)),*) => {
+        $(impl<$($name: ToJson),+> ToJson for ($($name,)+) {
+            fn write_json(&self, out: &mut String) {
+                out.push('[');
+                $(
+                    if $index > 0 {
+                        out.push(',');
+                    }
+                    self.$index.write_json(out);
+                )+
+                out.push(']');
+            }
+        })*
+    };
+}
+
+json_tuple!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+);
@@ -1,17 +1,45 @@
+#[cfg(feature = "bench")]
+mod bench;
 #[cfg(feature = "channel")]
 mod channel;
 #[cfg(feature = "context")]
 mod context;
+#[cfg(feature = "format")]
+mod format;
+#[cfg(feature = "hash")]
+mod hash;
+#[cfg(feature = "json")]
+mod json;
 #[cfg(feature = "metadata")]
 mod metadata;
+#[cfg(feature = "signal")]
+mod signal;
+#[cfg(feature = "temp")]
+mod temp;
+#[cfg(feature = "uuid")]
+mod uuid;
 
+#[cfg(feature = "bench")]
+pub use bench::run_bench;
 #[cfg(feature = "channel")]
 pub use channel::{Channel, TryRecv, TrySend};
 #[cfg(feature = "context")]
 pub use context::Context;
+#[cfg(feature = "format")]
+pub use format::format_number;
+#[cfg(feature = "hash")]
+pub use hash::{hmac_sha256_hex, md5_hex, sha256_hex};
+#[cfg(feature = "json")]
+pub use json::{to_json, write_json_key, ToJson};
 #[cfg(feature = "metadata")]
 pub use metadata::{
     BuiltinMeta, ComponentOrder, ConstMeta, EnumMeta, FieldMeta, FunctionMeta,
     FunctionParameterMeta, MethodMeta, MethodParameterMeta, StructMeta, TypeMeta, VariableMeta,
     VariantMeta,
 };
+#[cfg(feature = "signal")]
+pub use signal::on_interrupt;
+#[cfg(feature = "temp")]
+pub use temp::TempPath;
+#[cfg(feature = "uuid")]
+pub use uuid::uuid_v4;
@@ -0,0 +1,18 @@
+use signal_hook::consts::SIGINT;
+use signal_hook::iterator::Signals;
+
+/// Run `handler` on a background thread every time the process receives Ctrl-C.
+///
+/// Installing a handler replaces the default "terminate on SIGINT" behavior, so
+/// handlers that want the process to stop should call `std::process::exit`.
+pub fn on_interrupt<F>(handler: F)
+where
+    F: Fn() + Send + 'static,
+{
+    let mut signals = Signals::new([SIGINT]).expect("failed to install Ctrl-C handler");
+    std::thread::spawn(move || {
+        for _ in signals.forever() {
+            handler();
+        }
+    });
+}
@@ -0,0 +1,69 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static NEXT_TEMP_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A temporary file or directory that is removed when the guard is dropped.
+pub struct TempPath {
+    path: String,
+    is_dir: bool,
+}
+
+impl TempPath {
+    pub fn file() -> Self {
+        let path = create_unique("file", |path| {
+            std::fs::OpenOptions::new().write(true).create_new(true).open(path).map(|_| ())
+        })
+        .expect("failed to create temporary file");
+        Self { path, is_dir: false }
+    }
+
+    pub fn dir() -> Self {
+        let path = create_unique("dir", |path| std::fs::create_dir(path))
+            .expect("failed to create temporary directory");
+        Self { path, is_dir: true }
+    }
+
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        let _ = if self.is_dir {
+            std::fs::remove_dir_all(&self.path)
+        } else {
+            std::fs::remove_file(&self.path)
+        };
+    }
+}
+
+/// Creates a fresh path in the temp directory with `create`, which must fail when the path
+/// already exists, so a file or symlink planted under a predictable name is never reused.
+fn create_unique(
+    kind: &str,
+    create: impl Fn(&str) -> std::io::Result<()>,
+) -> std::io::Result<String> {
+    const ATTEMPTS: usize = 100;
+    let mut last_error = None;
+    for _ in 0..ATTEMPTS {
+        let path = unique_temp_path(kind);
+        match create(&path) {
+            Ok(()) => return Ok(path),
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => last_error = Some(error),
+            Err(error) => return Err(error),
+        }
+    }
+    Err(last_error.expect("at least one attempt was made"))
+}
+
+fn unique_temp_path(kind: &str) -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or(0);
+    let id = NEXT_TEMP_ID.fetch_add(1, Ordering::Relaxed);
+    let name = format!("zinc-{}-{}-{}-{}", kind, std::process::id(), nanos, id);
+    std::env::temp_dir().join(name).to_string_lossy().to_string()
+}
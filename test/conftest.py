@@ -0,0 +1,30 @@
+"""Fixtures shared by the test modules."""
+
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+
+PKG_TOML = '[package]\nname = "tmp"\nversion = "0.1.0"\n'
+
+
+@pytest.fixture
+def write_package() -> Callable[..., Path]:
+    """Write a Zinc package and return the path of its entry file.
+
+    `write_package(root, source, name)` writes `pkg.toml` and the entry file
+    `name` under `root`. `modules` maps more paths under `root` to their
+    source, and `manifest` is added to the end of `pkg.toml`.
+    """
+
+    def write(root: Path, source: str, name: str = "prog.zn", *, modules: dict[str, str] | None = None, manifest: str = "") -> Path:
+        root.mkdir(parents=True, exist_ok=True)
+        (root / "pkg.toml").write_text(PKG_TOML + manifest)
+        for path, text in (modules or {}).items():
+            (root / path).parent.mkdir(parents=True, exist_ok=True)
+            (root / path).write_text(text)
+        entry = root / name
+        entry.write_text(source)
+        return entry
+
+    return write
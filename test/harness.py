@@ -0,0 +1,381 @@
+"""End-to-end harness: compile Zinc fixtures, then build and run what the compiler produced.
+
+`test_compile.py` builds the checked-in Rust goldens; this harness builds the
+Rust the compiler generates right now, so a case fails if either the code or
+its behavior drifts from the snapshots. Cases are discovered, not listed:
+every fixture under `zinc_source/` is one, and so is every Rust snapshot, so a
+snapshot left behind by a deleted fixture fails. A case's generated Rust is
+compared with `rust_source/src/<case>.rs`, its stdout with
+`output/<case>.out`, and its stderr with `output/<case>.err`, or with nothing
+when there is no `.err` snapshot. A program must exit with status 0 unless its
+fixture expects another on an `// exit: 101` line. All cases are built
+together in one scratch Cargo crate under `harness_build/`, which keeps its
+`target/` between runs.
+
+Fixtures under `compile_errors/` are compile-fail cases: they pass when the
+compiler rejects them with the diagnostics they list on `// expected-error:`
+(a regex), `// expected-code:`, and `// expected-message:` lines, and nothing
+is built.
+
+Run it with `python -m test.harness [CASE ...]`. A mismatch is shown as a
+unified diff against the snapshot; `--bless`, or `UPDATE_GOLDEN=1`, rewrites
+the snapshots that don't match instead, for changes that are intended.
+`--filter TEXT` keeps the cases whose name contains TEXT, and `--tag TAG` the
+cases with that tag. A case is tagged with the folders it sits in, like
+`concurrency` and `channels`, and with any tags its fixture lists on a
+`// tags: slow, strings` line.
+
+Stdout is compared exactly unless the fixture picks another mode on a
+`// compare:` line: `sorted` or `multiset` accept lines in any order, for
+output that interleaves differently from run to run, and `regex` treats each
+snapshot line as a pattern the matching output line must match. Fixtures under
+`non_deterministic/` default to `multiset`. Stderr is compared the same way.
+
+Compiled cases run in parallel, up to `--jobs` at a time, and a case still
+running after `--timeout` seconds is killed and reported as failed.
+`--junit PATH` and `--json PATH` also write the results, with each case's
+duration and captured output, as JUnit XML or JSON for CI to display.
+`--color=auto|always|never` and `NO_COLOR` control whether the status column
+is colored, as they do for the compiler.
+"""
+
+import difflib
+import json
+import os
+import re
+import shutil
+import subprocess
+import sys
+import tempfile
+import time
+import xml.etree.ElementTree as ET
+from concurrent.futures import ThreadPoolExecutor
+from dataclasses import dataclass, field
+from pathlib import Path
+
+import click
+from test.test_compile import (
+    OUTPUT_DIR,
+    RUST_SOURCE_DIR,
+    RUST_SRC_DIR,
+    RUNTIME_CRATE_DIR,
+    TEST_DIR,
+    ZINC_SOURCE_DIR,
+    blessed_output,
+    compile_fail_problems,
+    compile_zinc_program,
+    format_multiset_diff,
+    generate_cargo_toml,
+    is_compile_fail_case,
+    is_entry_fixture,
+    outputs_match,
+    read_compare_mode,
+    read_expected_exit,
+    regex_mismatch,
+    sorted_lines,
+)
+from zinc.codegen import RustProgram
+from zinc.exceptions import ZincError
+from zinc.style import color_option, paint, should_color
+
+TAGS_PREFIX = "// tags:"
+# Seconds a case may run; a deadlocked channel test would otherwise hang the whole run.
+DEFAULT_TIMEOUT = 60.0
+# Characters XML 1.0 can't hold, like the escape codes of colored output.
+XML_INVALID_CHARS = re.compile("[^\t\n\r\x20-\ud7ff\ue000-\ufffd\U00010000-\U0010ffff]")
+
+
+@dataclass(frozen=True)
+class Layout:
+    """Where the harness reads fixtures and snapshots and builds the cases."""
+
+    zinc_dir: Path = ZINC_SOURCE_DIR
+    rust_dir: Path = RUST_SRC_DIR
+    output_dir: Path = OUTPUT_DIR
+    build_dir: Path = TEST_DIR / "harness_build"
+    # Defaults to `target/` in the build directory; several crates can share one to build dependencies once.
+    target_dir: Path | None = None
+
+    @property
+    def release_dir(self) -> Path:
+        """Where cargo puts the case binaries."""
+        return (self.target_dir or self.build_dir / "target") / "release"
+
+    def discover(self) -> list[str]:
+        """Every case, sorted: each entry fixture, compile-fail ones included, and each Rust snapshot, even one whose fixture is gone."""
+        cases = set()
+        for source in self.zinc_dir.glob("**/*.zn"):
+            relative = source.relative_to(self.zinc_dir).with_suffix("")
+            if is_entry_fixture(relative):
+                cases.add(relative.as_posix())
+        cases.update(path.relative_to(self.rust_dir).with_suffix("").as_posix() for path in self.rust_dir.glob("**/*.rs"))
+        return sorted(cases)
+
+    def tags(self, case: str) -> set[str]:
+        """A case's tags: the folders it sits in, plus any listed on a `// tags:` line in its fixture."""
+        tags = set(case.split("/")[:-1])
+        source = self.zinc_dir / f"{case}.zn"
+        for line in source.read_text().splitlines() if source.exists() else []:
+            if line.strip().startswith(TAGS_PREFIX):
+                tags.update(tag.strip() for tag in line.strip()[len(TAGS_PREFIX) :].split(",") if tag.strip())
+        return tags
+
+    def bin_name(self, case: str) -> str:
+        """The Cargo binary a case builds to, e.g. `structs_01_basic_fields`."""
+        return case.replace("/", "_")
+
+
+@dataclass
+class CaseResult:
+    """How one case went; it passed if nothing went wrong."""
+
+    case: str
+    problems: list[str] = field(default_factory=list)
+    stdout: str = ""
+    stderr: str = ""
+    # Seconds spent compiling and running the case; the shared cargo build isn't counted.
+    duration: float = 0.0
+    # Snapshots rewritten to match this run.
+    blessed: list[Path] = field(default_factory=list)
+
+    @property
+    def passed(self) -> bool:
+        """Whether the case compiled, built, ran, and matched its snapshots."""
+        return not self.problems
+
+
+def _unified_diff(expected: str, observed: str, snapshot: Path) -> str:
+    lines = difflib.unified_diff(expected.splitlines(), observed.splitlines(), str(snapshot), "observed", lineterm="")
+    return "\n".join(lines)
+
+
+def check_snapshot(result: CaseResult, what: str, snapshot: Path, observed: str, bless: bool, mode: str = "exact") -> None:
+    """Compare `observed` with a snapshot, showing a diff on mismatch; with `bless`, rewrite the snapshot instead.
+
+    `mode` is a fixture's compare mode: `exact`, `sorted` or `multiset` for
+    lines in any order, or `regex` for a snapshot of one pattern per line. A
+    regex snapshot is only written when it is missing; after that it is
+    maintained by hand.
+    """
+    expected = snapshot.read_text() if snapshot.exists() else None
+    if expected is not None and outputs_match(expected, observed, mode):
+        return
+    if bless and (text := blessed_output(expected, observed, mode)) is not None:
+        snapshot.parent.mkdir(parents=True, exist_ok=True)
+        snapshot.write_text(text)
+        result.blessed.append(snapshot)
+    elif expected is None:
+        result.problems.append(f"no {what} snapshot at {snapshot}; run with --bless to write it")
+    elif mode == "regex":
+        result.problems.append(f"{what} doesn't match the patterns in {snapshot}: {regex_mismatch(expected, observed)}")
+    elif mode == "sorted":
+        result.problems.append(f"{what} differs from {snapshot}\n{_unified_diff(expected, sorted_lines(observed), snapshot)}")
+    elif mode == "multiset":
+        result.problems.append(f"{what} differs from {snapshot}\n{format_multiset_diff(expected, observed)}")
+    else:
+        result.problems.append(f"{what} differs from {snapshot}\n{_unified_diff(expected, observed, snapshot)}")
+
+
+def build_cases(programs: dict[str, RustProgram], layout: Layout) -> str:
+    """Write every compiled case into the scratch crate and build it; returns cargo's stderr."""
+    src_dir = layout.build_dir / "src"
+    if src_dir.exists():
+        shutil.rmtree(src_dir)
+    for case, program in programs.items():
+        path = src_dir / f"{case}.rs"
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text(program.render())
+    features = {feature for program in programs.values() for feature in program.runtime_features}
+    (layout.build_dir / "Cargo.toml").write_text(generate_cargo_toml(list(programs), features, RUNTIME_CRATE_DIR))
+    # Start from the goldens' lockfile so both crates build the same dependency versions.
+    lockfile = layout.build_dir / "Cargo.lock"
+    if not lockfile.exists() and (RUST_SOURCE_DIR / "Cargo.lock").exists():
+        shutil.copy2(RUST_SOURCE_DIR / "Cargo.lock", lockfile)
+    # A case whose Rust no longer builds must not run the binary from an earlier run.
+    for case in programs:
+        (layout.release_dir / layout.bin_name(case)).unlink(missing_ok=True)
+    env = {**os.environ, "RUSTFLAGS": os.environ.get("RUSTFLAGS", "-A warnings")}
+    command = ["cargo", "build", "--release", "--quiet", "--keep-going", "--target-dir", str(layout.release_dir.parent)]
+    return subprocess.run(command, cwd=layout.build_dir, capture_output=True, text=True, env=env).stderr
+
+
+def run_case(result: CaseResult, layout: Layout, build_errors: str, timeout: float = DEFAULT_TIMEOUT, expected_exit: int = 0) -> bool:
+    """Run a case's binary, keeping its output; returns whether it built and exited with `expected_exit` within `timeout` seconds."""
+    binary = layout.release_dir / layout.bin_name(result.case)
+    if not binary.exists():
+        result.problems.append(f"cargo build failed:\n{build_errors}")
+        return False
+    # A file rather than a pipe, so a child process that outlives the program can't hold the run open.
+    started = time.perf_counter()
+    with tempfile.TemporaryFile(mode="w+", encoding="utf-8") as stdout_file:
+        try:
+            command = [str(binary)]
+            process = subprocess.run(command, stdout=stdout_file, stderr=subprocess.PIPE, text=True, cwd=layout.build_dir, timeout=timeout)
+        except subprocess.TimeoutExpired:
+            process = None
+        stdout_file.seek(0)
+        result.stdout = stdout_file.read()
+    result.duration += time.perf_counter() - started
+    if process is None:
+        result.problems.append(f"timed out after {timeout:g}s and was killed; stdout so far:\n{result.stdout}")
+        return False
+    result.stderr = process.stderr
+    if process.returncode != expected_exit:
+        result.problems.append(f"exited with status {process.returncode}, expected {expected_exit}\n{process.stderr}")
+    return process.returncode == expected_exit
+
+
+def run_cases(
+    cases: list[str], layout: Layout = Layout(), bless: bool = False, *, jobs: int | None = None, timeout: float = DEFAULT_TIMEOUT
+) -> list[CaseResult]:
+    """Compile, build, run, and check every case; with `bless`, snapshots that don't match are rewritten.
+
+    Up to `jobs` binaries run at once (by default, one per CPU), and one still
+    running after `timeout` seconds is killed and fails. Results come back in
+    the order of `cases`.
+    """
+    results = {case: CaseResult(case) for case in cases}
+    programs: dict[str, RustProgram] = {}
+    modes: dict[str, str] = {}
+    exits: dict[str, int] = {}
+    for case, result in results.items():
+        source = layout.zinc_dir / f"{case}.zn"
+        if not source.exists():
+            result.problems.append(f"no fixture at {source}")
+            continue
+        started = time.perf_counter()
+        try:
+            if is_compile_fail_case(case):
+                result.problems.extend(compile_fail_problems(source))
+                continue
+            modes[case] = read_compare_mode(source, case)
+            exits[case] = read_expected_exit(source)
+            programs[case] = compile_zinc_program(source)
+        except (ValueError, AssertionError) as exc:
+            result.problems.append(str(exc))
+            continue
+        except ZincError as exc:
+            result.problems.append(f"compile error: {exc}")
+            continue
+        finally:
+            result.duration += time.perf_counter() - started
+        check_snapshot(result, "generated Rust", layout.rust_dir / f"{case}.rs", programs[case].render(), bless)
+    build_errors = build_cases(programs, layout) if programs else ""
+
+    def run_and_check(result: CaseResult) -> None:
+        if run_case(result, layout, build_errors, timeout, exits[result.case]):
+            mode = modes[result.case]
+            check_snapshot(result, "stdout", layout.output_dir / f"{result.case}.out", result.stdout, bless, mode)
+            stderr_snapshot = layout.output_dir / f"{result.case}.err"
+            if bless and not result.stderr and stderr_snapshot.exists():
+                stderr_snapshot.unlink()
+                result.blessed.append(stderr_snapshot)
+            elif result.stderr or stderr_snapshot.exists():
+                check_snapshot(result, "stderr", stderr_snapshot, result.stderr, bless, mode)
+
+    with ThreadPoolExecutor(max_workers=jobs or os.cpu_count()) as pool:
+        list(pool.map(run_and_check, [results[case] for case in programs]))
+    return list(results.values())
+
+
+def select_cases(cases: list[str], layout: Layout, filters: tuple[str, ...] = (), tags: tuple[str, ...] = ()) -> list[str]:
+    """The cases whose name contains one of `filters` and that have one of `tags`; an empty selector keeps every case."""
+    return [
+        case
+        for case in cases
+        if (not filters or any(text in case for text in filters)) and (not tags or layout.tags(case) & set(tags))
+    ]
+
+
+def _xml_text(text: str) -> str:
+    return XML_INVALID_CHARS.sub("\ufffd", text)
+
+
+def junit_report(results: list[CaseResult], elapsed: float) -> str:
+    """The results as JUnit XML: one `testcase` per case, named after its folder and file, with its output and any failure."""
+    failures = sum(1 for result in results if not result.passed)
+    counts = {"tests": str(len(results)), "failures": str(failures), "errors": "0", "skipped": "0", "time": f"{elapsed:.3f}"}
+    suites = ET.Element("testsuites", counts)
+    suite = ET.SubElement(suites, "testsuite", {"name": "zinc-harness", **counts})
+    for result in results:
+        folder, _, name = result.case.rpartition("/")
+        classname = folder.replace("/", ".") or "harness"
+        testcase = ET.SubElement(suite, "testcase", classname=classname, name=name, time=f"{result.duration:.3f}")
+        if not result.passed:
+            failure = ET.SubElement(testcase, "failure", message=_xml_text(result.problems[0].splitlines()[0]))
+            failure.text = _xml_text("\n\n".join(result.problems))
+        for tag, text in (("system-out", result.stdout), ("system-err", result.stderr)):
+            if text:
+                ET.SubElement(testcase, tag).text = _xml_text(text)
+    ET.indent(suites)
+    return ET.tostring(suites, encoding="unicode", xml_declaration=True) + "\n"
+
+
+def json_report(results: list[CaseResult], elapsed: float) -> str:
+    """The results as JSON: totals, then each case's outcome, duration, problems, output, and blessed snapshots."""
+    failed = sum(1 for result in results if not result.passed)
+    cases = [
+        {
+            "case": result.case,
+            "passed": result.passed,
+            "duration": round(result.duration, 3),
+            "problems": result.problems,
+            "stdout": result.stdout,
+            "stderr": result.stderr,
+            "blessed": [str(snapshot) for snapshot in result.blessed],
+        }
+        for result in results
+    ]
+    report = {"passed": len(results) - failed, "failed": failed, "duration": round(elapsed, 3), "cases": cases}
+    return json.dumps(report, indent=2) + "\n"
+
+
+@click.command()
+@click.argument("cases", nargs=-1)
+@click.option(
+    "--bless", is_flag=True, envvar="UPDATE_GOLDEN", help="Rewrite snapshots that don't match instead of failing (or set UPDATE_GOLDEN=1)"
+)
+@click.option("--filter", "filters", multiple=True, help="Only run cases whose name contains this text; repeat to allow several")
+@click.option("--tag", "tags", multiple=True, help="Only run cases with this tag, like `structs` or `channels`; repeat to allow several")
+@click.option("-j", "--jobs", type=click.IntRange(min=1), help="How many cases run at once (default: one per CPU)")
+@click.option("--timeout", type=click.FloatRange(min=0, min_open=True), default=DEFAULT_TIMEOUT, help="Seconds before a case is killed")
+@click.option("--junit", "junit_path", type=click.Path(dir_okay=False, path_type=Path), help="Also write JUnit XML results to this file")
+@click.option("--json", "json_path", type=click.Path(dir_okay=False, path_type=Path), help="Also write JSON results to this file")
+@color_option
+def main(
+    cases: tuple[str, ...],
+    bless: bool,
+    filters: tuple[str, ...],
+    tags: tuple[str, ...],
+    jobs: int | None,
+    timeout: float,
+    junit_path: Path | None,
+    json_path: Path | None,
+) -> None:
+    """Check CASES, or every case, end to end; exits with status 1 if any fail."""
+    layout = Layout()
+    selected = select_cases(list(cases) or layout.discover(), layout, filters, tags)
+    if not selected:
+        raise click.UsageError("no cases match the filters and tags")
+    started = time.perf_counter()
+    results = run_cases(selected, layout, bless=bless, jobs=jobs, timeout=timeout)
+    elapsed = time.perf_counter() - started
+    for path, report in ((junit_path, junit_report), (json_path, json_report)):
+        if path is not None:
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(report(results, elapsed))
+    color = should_color()
+    for result in results:
+        status = paint("ok  ", color, fg="green") if result.passed else paint("FAIL", color, fg="red", bold=True)
+        click.echo(f"{status} {result.case}")
+        for problem in result.problems:
+            click.echo("     " + problem.replace("\n", "\n     "))
+        for snapshot in result.blessed:
+            click.echo(f"     updated {snapshot}")
+    failed = sum(1 for result in results if not result.passed)
+    click.echo(f"\n{len(results) - failed} passed, {failed} failed")
+    sys.exit(1 if failed else 0)
+
+
+if __name__ == "__main__":
+    main()
@@ -10,11 +10,11 @@ impl Default for annotations_01_typed_locals_and_params__Pair {
 }
 
 fn annotations_01_typed_locals_and_params__add_f32_f32_i64(x: f32, y: i64) -> f32 {
-    return (x + (y as f32));
+    x + y as f32
 }
 
 fn annotations_01_typed_locals_and_params__add_i32_i32_i32(x: i32, y: i32) -> i32 {
-    return (x + y);
+    x + y
 }
 
 fn main() {
@@ -1,17 +1,17 @@
 fn annotations_03_exact_numeric_width_shadowing__keep_f32_f32(x: f32) -> f32 {
-    return x;
+    x
 }
 
 fn annotations_03_exact_numeric_width_shadowing__keep_f64_f64(x: f64) -> f64 {
-    return x;
+    x
 }
 
 fn annotations_03_exact_numeric_width_shadowing__keep_i32_i32(x: i32) -> i32 {
-    return x;
+    x
 }
 
 fn annotations_03_exact_numeric_width_shadowing__keep_i64_i64(x: i64) -> i64 {
-    return x;
+    x
 }
 
 fn main() {
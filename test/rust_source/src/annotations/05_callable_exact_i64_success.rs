@@ -26,15 +26,15 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn annotations_05_callable_exact_i64_success____lambda_annotations_05_callable_exact_i64_success__main_56_67_i64(__env: __ZincClosureEnv_annotations_05_callable_exact_i64_success___lambda_annotations_05_callable_exact_i64_success__main_56_67, value: i64) -> i64 {
-    return (value + 2);
+    value + 2
 }
 
 fn annotations_05_callable_exact_i64_success__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn annotations_05_callable_exact_i64_success__apply_twice_i64_to_unknown_i64(f: __ZincCallable_i64_to_i64, x: i64) -> i64 {
-    return f.call(f.call(x));
+    f.call(f.call(x))
 }
 
 fn main() {
@@ -33,7 +33,7 @@ impl Default for annotations_06_metadata_constraints__Circle {
 
 impl annotations_06_metadata_constraints__Circle {
     fn area(&self) -> f64 {
-        return (self.radius * self.radius);
+        self.radius * self.radius
     }
 }
 
@@ -62,7 +62,7 @@ impl Default for annotations_06_metadata_constraints__Rectangle {
 
 impl annotations_06_metadata_constraints__Rectangle {
     fn area(&self) -> i64 {
-        return self.width;
+        self.width
     }
 }
 
@@ -78,7 +78,7 @@ impl Default for annotations_06_metadata_constraints__Shape2D {
 
 impl annotations_06_metadata_constraints__Shape2D {
     fn area() -> i64 {
-        return 0;
+        0
     }
 }
 
@@ -97,7 +97,7 @@ impl Default for annotations_06_metadata_constraints__TaggedCircle {
 
 impl annotations_06_metadata_constraints__TaggedCircle {
     fn area(&self) -> f64 {
-        return (self.radius * self.radius);
+        self.radius * self.radius
     }
 }
 
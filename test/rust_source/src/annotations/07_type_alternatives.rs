@@ -11,19 +11,19 @@ struct __ZincAnonStruct_AnonStruct_value_i32 {
 // infer-backed struct family annotations_07_type_alternatives__Measure uses synthesized concrete shapes
 
 fn annotations_07_type_alternatives__keep_numeric_attr_i64(x: i64) -> i64 {
-    return x;
+    x
 }
 
 fn annotations_07_type_alternatives__keep_numeric_i64(x: i64) -> i64 {
-    return x;
+    x
 }
 
 fn annotations_07_type_alternatives__keep_specific_f32(x: f32) -> f32 {
-    return x;
+    x
 }
 
 fn annotations_07_type_alternatives__keep_specific_i32(x: i32) -> i32 {
-    return x;
+    x
 }
 
 fn main() {
@@ -1,21 +1,21 @@
 fn annotations_08_rust_numeric_literals__id_f32_f32(x: f32) -> f32 {
-    return x;
+    x
 }
 
 fn annotations_08_rust_numeric_literals__id_f64_f64(x: f64) -> f64 {
-    return x;
+    x
 }
 
 fn annotations_08_rust_numeric_literals__id_i16_i16(x: i16) -> i16 {
-    return x;
+    x
 }
 
 fn annotations_08_rust_numeric_literals__id_u8_u8(x: u8) -> u8 {
-    return x;
+    x
 }
 
 fn annotations_08_rust_numeric_literals__id_usize_usize(x: usize) -> usize {
-    return x;
+    x
 }
 
 fn main() {
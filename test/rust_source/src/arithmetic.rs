@@ -1,13 +1,16 @@
 fn main() {
-    let a = (2 + 3);
-    let b = (10 - 4);
-    let c = (3 * 5);
-    let d = (20 / 4);
+    // Integer arithmetic
+    let a: i64 = 5;
+    let b: i64 = 6;
+    let c: i64 = 15;
+    let d: i64 = 5;
     println!("a: {}, b: {}, c: {}, d: {}", a, b, c, d);
-    let e = (3.14 + 2.0);
-    let f = (10.0 / 4.0);
+    // Float arithmetic
+    let e = 5.140000000000001;
+    let f = 2.5;
     println!("e: {}, f: {}", e, f);
-    let g = (((2 + 3)) * 4);
-    let h = (10 - (2 * 3));
+    // Compound expressions
+    let g: i64 = 20;
+    let h: i64 = 4;
     println!("g: {}, h: {}", g, h);
 }
\ No newline at end of file
@@ -1,12 +1,16 @@
 fn main() {
+    // create an array
     let a = vec![1, 2, 3];
+    // print elements
     println!("{}", a[0]);
     println!("{}", a[1]);
     println!("{}", a[2]);
+    // gets promoted to a vector if we use push
     let mut b = vec![];
     b.push(10);
     b.push(20);
     b.push(30);
+    // print elements
     println!("{}", b[0]);
     println!("{}", b[1]);
     println!("{}", b[2]);
@@ -1,5 +1,6 @@
 fn main() {
-    let x = 1;
+    // variable assignment
+    let x: i64 = 1;
     println!("x: {}", x);
     let x = 3.14;
     println!("x: {}", x);
@@ -20,11 +20,11 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_01_named_function__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn callables_01_named_function__apply_unknown_to_unknown_i64(f: __ZincCallable_i64_to_i64, x: i64) -> i64 {
-    return f.call(x);
+    f.call(x)
 }
 
 fn main() {
@@ -24,7 +24,7 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_02_lambda_array____lambda_callables_02_lambda_array__main_13_22_i64(__env: __ZincClosureEnv_callables_02_lambda_array___lambda_callables_02_lambda_array__main_13_22, x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn main() {
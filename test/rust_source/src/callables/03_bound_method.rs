@@ -34,10 +34,10 @@ impl Default for callables_03_bound_method__Counter {
 
 impl callables_03_bound_method__Counter {
     fn inc(&mut self) {
-        self.count = (self.count + 1);
+        self.count = self.count + 1;
     }
     fn get(&self) -> i64 {
-        return self.count;
+        self.count
     }
 }
 
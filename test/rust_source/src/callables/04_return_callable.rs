@@ -20,11 +20,11 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_04_return_callable__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn callables_04_return_callable__make() -> __ZincCallable_i64_to_i64 {
-    return __ZincCallable_i64_to_i64::V0;
+    __ZincCallable_i64_to_i64::V0
 }
 
 fn main() {
@@ -30,7 +30,7 @@ impl Default for callables_05_static_method__Math {
 
 impl callables_05_static_method__Math {
     fn add_one(x: i64) -> i64 {
-        return (x + 1);
+        x + 1
     }
 }
 
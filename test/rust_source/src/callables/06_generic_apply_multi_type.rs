@@ -41,19 +41,19 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_06_generic_apply_multi_type__identity_String(x: String) -> String {
-    return x;
+    x
 }
 
 fn callables_06_generic_apply_multi_type__apply_unknown_to_unknown_String(f: __ZincCallable_String_to_String, x: String) -> String {
-    return f.call(x);
+    f.call(x)
 }
 
 fn callables_06_generic_apply_multi_type__identity_i64(x: i64) -> i64 {
-    return x;
+    x
 }
 
 fn callables_06_generic_apply_multi_type__apply_unknown_to_unknown_i64(f: __ZincCallable_i64_to_i64, x: i64) -> i64 {
-    return f.call(x);
+    f.call(x)
 }
 
 fn main() {
@@ -22,11 +22,11 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_07_rebinding_same_signature__double_i64(x: i64) -> i64 {
-    return (x * 2);
+    x * 2
 }
 
 fn callables_07_rebinding_same_signature__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn main() {
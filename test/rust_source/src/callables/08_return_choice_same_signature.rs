@@ -25,15 +25,15 @@ fn callables_08_return_choice_same_signature__choose_bool(flag: bool) -> __ZincC
     if flag {
         return __ZincCallable_i64_to_i64::V1;
     }
-    return __ZincCallable_i64_to_i64::V0;
+    __ZincCallable_i64_to_i64::V0
 }
 
 fn callables_08_return_choice_same_signature__double_i64(x: i64) -> i64 {
-    return (x * 2);
+    x * 2
 }
 
 fn callables_08_return_choice_same_signature__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn main() {
@@ -24,11 +24,11 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_09_typed_lambda_argument____lambda_callables_09_typed_lambda_argument__main_31_42_i64(__env: __ZincClosureEnv_callables_09_typed_lambda_argument___lambda_callables_09_typed_lambda_argument__main_31_42, value: i64) -> i64 {
-    return (value * 3);
+    value * 3
 }
 
 fn callables_09_typed_lambda_argument__apply_i64_to_unknown_i64(f: __ZincCallable_i64_to_i64, x: i64) -> i64 {
-    return f.call(x);
+    f.call(x)
 }
 
 fn main() {
@@ -24,11 +24,11 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_10_return_lambda____lambda_callables_10_return_lambda__make_6_15_i64(__env: __ZincClosureEnv_callables_10_return_lambda___lambda_callables_10_return_lambda__make_6_15, x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn callables_10_return_lambda__make() -> __ZincCallable_i64_to_i64 {
-    return __ZincCallable_i64_to_i64::V0(__ZincClosureEnv_callables_10_return_lambda___lambda_callables_10_return_lambda__make_6_15 {});
+    __ZincCallable_i64_to_i64::V0(__ZincClosureEnv_callables_10_return_lambda___lambda_callables_10_return_lambda__make_6_15 {})
 }
 
 fn main() {
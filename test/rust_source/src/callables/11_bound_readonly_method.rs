@@ -34,7 +34,7 @@ impl Default for callables_11_bound_readonly_method__Counter {
 
 impl callables_11_bound_readonly_method__Counter {
     fn get(&self) -> i64 {
-        return self.count;
+        self.count
     }
 }
 
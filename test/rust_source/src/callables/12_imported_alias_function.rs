@@ -20,7 +20,7 @@ impl __ZincCallable_i64_i64_to_i64 {
 }
 
 fn modules__lib_math__add_i64_i64(a: i64, b: i64) -> i64 {
-    return (a + b);
+    a + b
 }
 
 fn main() {
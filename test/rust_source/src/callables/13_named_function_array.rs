@@ -22,11 +22,11 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_13_named_function_array__double_i64(x: i64) -> i64 {
-    return (x * 2);
+    x * 2
 }
 
 fn callables_13_named_function_array__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn main() {
@@ -32,7 +32,7 @@ impl Default for callables_15_channel_static_method__Math {
 
 impl callables_15_channel_static_method__Math {
     fn inc(x: i64) -> i64 {
-        return (x + 1);
+        x + 1
     }
 }
 
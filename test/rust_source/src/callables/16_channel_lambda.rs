@@ -26,7 +26,7 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_16_channel_lambda____lambda_callables_16_channel_lambda__main_12_23_i64(__env: __ZincClosureEnv_callables_16_channel_lambda___lambda_callables_16_channel_lambda__main_12_23, x: i64) -> i64 {
-    return (x + 2);
+    x + 2
 }
 
 #[tokio::main]
@@ -22,7 +22,7 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_17_channel_helper_param__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 async fn callables_17_channel_helper_param__publish_Channel(out: Channel<__ZincCallable_i64_to_i64>) {
@@ -22,7 +22,7 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_18_channel_spawn_helper__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 async fn callables_18_channel_spawn_helper__worker_Channel(out: Channel<__ZincCallable_i64_to_i64>) {
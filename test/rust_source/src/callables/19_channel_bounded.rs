@@ -22,7 +22,7 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_19_channel_bounded__double_i64(x: i64) -> i64 {
-    return (x * 2);
+    x * 2
 }
 
 #[tokio::main]
@@ -22,7 +22,7 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_20_channel_select_receive__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 #[tokio::main]
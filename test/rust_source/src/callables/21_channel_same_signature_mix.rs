@@ -24,11 +24,11 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn callables_21_channel_same_signature_mix__double_i64(x: i64) -> i64 {
-    return (x * 2);
+    x * 2
 }
 
 fn callables_21_channel_same_signature_mix__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 #[tokio::main]
@@ -28,14 +28,14 @@ impl __ZincCallable_Unit_to_i64 {
 
 fn closures_02_lambda_super_assign____lambda_closures_02_lambda_super_assign__make_counter_i64_10_22(__env: __ZincClosureEnv_closures_02_lambda_super_assign___lambda_closures_02_lambda_super_assign__make_counter_i64_10_22) -> i64 {
     let __zv_closures_02_lambda_super_assign____lambda_closures_02_lambda_super_assign__make_counter_i64_10_22_x_i64 = __env.x.clone();
-    let __zinc_captured_write_14_19 = (*__zv_closures_02_lambda_super_assign____lambda_closures_02_lambda_super_assign__make_counter_i64_10_22_x_i64.lock().unwrap() + 1);
+    let __zinc_captured_write_14_19 = *__zv_closures_02_lambda_super_assign____lambda_closures_02_lambda_super_assign__make_counter_i64_10_22_x_i64.lock().unwrap() + 1;
     *__zv_closures_02_lambda_super_assign____lambda_closures_02_lambda_super_assign__make_counter_i64_10_22_x_i64.lock().unwrap() = __zinc_captured_write_14_19;
     return *__zv_closures_02_lambda_super_assign____lambda_closures_02_lambda_super_assign__make_counter_i64_10_22_x_i64.lock().unwrap();
 }
 
 fn closures_02_lambda_super_assign__make_counter_i64(start: i64) -> __ZincCallable_Unit_to_i64 {
     let __zv_closures_02_lambda_super_assign__make_counter_i64_x_i64 = Arc::new(Mutex::new(start));
-    return __ZincCallable_Unit_to_i64::V0(__ZincClosureEnv_closures_02_lambda_super_assign___lambda_closures_02_lambda_super_assign__make_counter_i64_10_22 { x: __zv_closures_02_lambda_super_assign__make_counter_i64_x_i64.clone() });
+    __ZincCallable_Unit_to_i64::V0(__ZincClosureEnv_closures_02_lambda_super_assign___lambda_closures_02_lambda_super_assign__make_counter_i64_10_22 { x: __zv_closures_02_lambda_super_assign__make_counter_i64_x_i64.clone() })
 }
 
 fn main() {
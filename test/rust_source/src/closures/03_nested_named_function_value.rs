@@ -28,7 +28,7 @@ impl __ZincCallable_i64_to_i64 {
 
 fn closures_03_nested_named_function_value____lexical_closures_03_nested_named_function_value__main_add_8_18_i64(__env: __ZincClosureEnv_closures_03_nested_named_function_value___lexical_closures_03_nested_named_function_value__main_add_8_18, y: i64) -> i64 {
     let __zv_closures_03_nested_named_function_value____lexical_closures_03_nested_named_function_value__main_add_8_18_i64_x_i64 = __env.x.clone();
-    return (*__zv_closures_03_nested_named_function_value____lexical_closures_03_nested_named_function_value__main_add_8_18_i64_x_i64.lock().unwrap() + y);
+    return *__zv_closures_03_nested_named_function_value____lexical_closures_03_nested_named_function_value__main_add_8_18_i64_x_i64.lock().unwrap() + y;
 }
 
 fn main() {
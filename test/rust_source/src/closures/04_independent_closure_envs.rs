@@ -35,7 +35,7 @@ fn closures_04_independent_closure_envs____lambda_closures_04_independent_closur
 
 fn closures_04_independent_closure_envs__make_counter_i64(start: i64) -> __ZincCallable_Unit_to_i64 {
     let __zv_closures_04_independent_closure_envs__make_counter_i64_x_i64 = Arc::new(Mutex::new(start));
-    return __ZincCallable_Unit_to_i64::V0(__ZincClosureEnv_closures_04_independent_closure_envs___lambda_closures_04_independent_closure_envs__make_counter_i64_10_20 { x: __zv_closures_04_independent_closure_envs__make_counter_i64_x_i64.clone() });
+    __ZincCallable_Unit_to_i64::V0(__ZincClosureEnv_closures_04_independent_closure_envs___lambda_closures_04_independent_closure_envs__make_counter_i64_10_20 { x: __zv_closures_04_independent_closure_envs__make_counter_i64_x_i64.clone() })
 }
 
 fn main() {
@@ -48,7 +48,7 @@ fn closures_05_sibling_closures_shared_cell__make_pair() -> (__ZincCallable_Unit
     let __zv_closures_05_sibling_closures_shared_cell__make_pair_count_i64 = Arc::new(Mutex::new(0));
     let inc = __ZincCallable_Unit_to_Unit::V0(__ZincClosureEnv_closures_05_sibling_closures_shared_cell___lambda_closures_05_sibling_closures_shared_cell__make_pair_10_18 { count: __zv_closures_05_sibling_closures_shared_cell__make_pair_count_i64.clone() });
     let read = __ZincCallable_Unit_to_Unit::V1(__ZincClosureEnv_closures_05_sibling_closures_shared_cell___lambda_closures_05_sibling_closures_shared_cell__make_pair_21_29 { count: __zv_closures_05_sibling_closures_shared_cell__make_pair_count_i64.clone() });
-    return (inc, read);
+    (inc, read)
 }
 
 fn main() {
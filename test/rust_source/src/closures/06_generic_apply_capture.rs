@@ -28,11 +28,11 @@ impl __ZincCallable_i64_to_i64 {
 
 fn closures_06_generic_apply_capture____lambda_closures_06_generic_apply_capture__main_26_37_i64(__env: __ZincClosureEnv_closures_06_generic_apply_capture___lambda_closures_06_generic_apply_capture__main_26_37, x: i64) -> i64 {
     let __zv_closures_06_generic_apply_capture____lambda_closures_06_generic_apply_capture__main_26_37_i64_offset_i64 = __env.offset.clone();
-    return (*__zv_closures_06_generic_apply_capture____lambda_closures_06_generic_apply_capture__main_26_37_i64_offset_i64.lock().unwrap() + x);
+    return *__zv_closures_06_generic_apply_capture____lambda_closures_06_generic_apply_capture__main_26_37_i64_offset_i64.lock().unwrap() + x;
 }
 
 fn closures_06_generic_apply_capture__apply_i64_to_unknown_i64(f: __ZincCallable_i64_to_i64, x: i64) -> i64 {
-    return f.call(x);
+    f.call(x)
 }
 
 fn main() {
@@ -28,7 +28,7 @@ impl __ZincCallable_Unit_to_Unit {
 
 fn closures_08_spawn_closure_value____lambda_closures_08_spawn_closure_value__main_10_20(__env: __ZincClosureEnv_closures_08_spawn_closure_value___lambda_closures_08_spawn_closure_value__main_10_20) {
     let __zv_closures_08_spawn_closure_value____lambda_closures_08_spawn_closure_value__main_10_20_base_i64 = __env.base.clone();
-    println!("{}", (*__zv_closures_08_spawn_closure_value____lambda_closures_08_spawn_closure_value__main_10_20_base_i64.lock().unwrap() + 1));
+    println!("{}", 5);
 }
 
 #[tokio::main]
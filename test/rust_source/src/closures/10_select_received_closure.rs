@@ -29,7 +29,7 @@ impl __ZincCallable_i64_to_i64 {
 
 fn closures_10_select_received_closure____lambda_closures_10_select_received_closure__main_15_24_i64(__env: __ZincClosureEnv_closures_10_select_received_closure___lambda_closures_10_select_received_closure__main_15_24, x: i64) -> i64 {
     let __zv_closures_10_select_received_closure____lambda_closures_10_select_received_closure__main_15_24_i64_base_i64 = __env.base.clone();
-    return (*__zv_closures_10_select_received_closure____lambda_closures_10_select_received_closure__main_15_24_i64_base_i64.lock().unwrap() + x);
+    return *__zv_closures_10_select_received_closure____lambda_closures_10_select_received_closure__main_15_24_i64_base_i64.lock().unwrap() + x;
 }
 
 #[tokio::main]
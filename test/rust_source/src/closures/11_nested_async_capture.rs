@@ -7,7 +7,7 @@ struct __ZincClosureEnv_closures_11_nested_async_capture___lexical_closures_11_n
 
 async fn closures_11_nested_async_capture____lexical_closures_11_nested_async_capture__main_inner_8_20_i64(__env: __ZincClosureEnv_closures_11_nested_async_capture___lexical_closures_11_nested_async_capture__main_inner_8_20, x: i64) {
     let __zv_closures_11_nested_async_capture____lexical_closures_11_nested_async_capture__main_inner_8_20_i64_base_i64 = __env.base.clone();
-    println!("{}", (*__zv_closures_11_nested_async_capture____lexical_closures_11_nested_async_capture__main_inner_8_20_i64_base_i64.lock().unwrap() + x));
+    println!("{}", *__zv_closures_11_nested_async_capture____lexical_closures_11_nested_async_capture__main_inner_8_20_i64_base_i64.lock().unwrap() + x);
 }
 
 #[tokio::main]
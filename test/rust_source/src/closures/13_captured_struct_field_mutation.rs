@@ -38,7 +38,7 @@ impl Default for closures_13_captured_struct_field_mutation__Counter {
 
 fn closures_13_captured_struct_field_mutation____lambda_closures_13_captured_struct_field_mutation__main_19_32(__env: __ZincClosureEnv_closures_13_captured_struct_field_mutation___lambda_closures_13_captured_struct_field_mutation__main_19_32) {
     let __zv_closures_13_captured_struct_field_mutation____lambda_closures_13_captured_struct_field_mutation__main_19_32_counter_Struct = __env.counter.clone();
-    let __zinc_captured_field_23_31 = (__zv_closures_13_captured_struct_field_mutation____lambda_closures_13_captured_struct_field_mutation__main_19_32_counter_Struct.lock().unwrap().count + 1);
+    let __zinc_captured_field_23_31 = __zv_closures_13_captured_struct_field_mutation____lambda_closures_13_captured_struct_field_mutation__main_19_32_counter_Struct.lock().unwrap().count + 1;
     __zv_closures_13_captured_struct_field_mutation____lambda_closures_13_captured_struct_field_mutation__main_19_32_counter_Struct.lock().unwrap().count = __zinc_captured_field_23_31;
 }
 
@@ -9,7 +9,7 @@ fn main() {
     println!("{}", right);
     println!("{}", has_left);
     { scores.remove("left"); () };
-    let after_remove = (scores.len() as i64);
+    let after_remove = scores.len() as i64;
     println!("{}", after_remove);
     { scores.clear(); () };
     let is_empty = scores.is_empty();
@@ -5,6 +5,6 @@ fn main() {
     let has_two = values.contains(&2);
     println!("{}", has_two);
     { values.remove(&1); () };
-    let count = (values.len() as i64);
+    let count = values.len() as i64;
     println!("{}", count);
 }
\ No newline at end of file
@@ -1,23 +1,23 @@
 use std::collections::{HashMap, HashSet};
 
 fn collections_06_collection_parameters__add_score_HashMap_String_f64(scores: &mut HashMap<String, f64>) {
-    { scores.insert(String::from("c"), (3 as f64)); () };
+    { scores.insert(String::from("c"), 3.0); () };
 }
 
 fn collections_06_collection_parameters__has_two_HashSet_i64(values: &HashSet<i64>) -> bool {
-    return values.contains(&2);
+    values.contains(&2)
 }
 
 fn collections_06_collection_parameters__sum_scores_HashMap_String_f64(scores: &HashMap<String, f64>) -> f64 {
-    return (scores.get("a").unwrap().clone() + scores.get("b").unwrap().clone());
+    scores.get("a").unwrap().clone() + scores.get("b").unwrap().clone()
 }
 
 fn main() {
-    let mut scores = HashMap::from([(String::from("a"), (1 as f64)), (String::from("b"), 2.5)]);
+    let mut scores = HashMap::from([(String::from("a"), 1.0), (String::from("b"), 2.5)]);
     let total = collections_06_collection_parameters__sum_scores_HashMap_String_f64(&scores);
     println!("{}", total);
     collections_06_collection_parameters__add_score_HashMap_String_f64(&mut scores);
-    let score_count = (scores.len() as i64);
+    let score_count = scores.len() as i64;
     println!("{}", score_count);
     let values = HashSet::from([1, 2]);
     let has_value = collections_06_collection_parameters__has_two_HashSet_i64(&values);
@@ -1,11 +1,11 @@
 use std::collections::{HashMap, HashSet};
 
 fn collections_07_collection_returns__make_scores_i64(seed: i64) -> HashMap<String, f64> {
-    return HashMap::from([(String::from("made"), (seed as f64)), (String::from("bonus"), 1.5)]);
+    HashMap::from([(String::from("made"), seed as f64), (String::from("bonus"), 1.5)])
 }
 
 fn collections_07_collection_returns__make_values_i64(seed: i64) -> HashSet<i64> {
-    return HashSet::from([seed, 4]);
+    HashSet::from([seed, 4])
 }
 
 fn main() {
@@ -0,0 +1,39 @@
+struct collections_09_fixed_arrays__Palette {
+    pub name: String,
+    pub shades: [i64; 3],
+}
+
+impl Default for collections_09_fixed_arrays__Palette {
+    fn default() -> Self {
+        Self { name: String::new(), shades: std::array::from_fn(|_| Default::default()) }
+    }
+}
+
+fn collections_09_fixed_arrays__labels() -> [String; 2] {
+    let names = { let __fill = String::from("?"); std::array::from_fn::<_, 2, _>(|_| __fill.clone()) };
+    names
+}
+
+fn collections_09_fixed_arrays__total_Array4_i64(values: &[i64; 4]) -> i64 {
+    let mut sum: i64 = 0;
+    for value in values.iter().cloned() {
+        sum = sum + value;
+    }
+    sum
+}
+
+fn main() {
+    let mut board: [i64; 4] = [3, 1, 4, 1];
+    println!("{}", collections_09_fixed_arrays__total_Array4_i64(&board));
+    println!("{}", board.len() as i64);
+    let zeros = [0; 4];
+    println!("{}", collections_09_fixed_arrays__total_Array4_i64(&zeros));
+    let middle: i64 = 2;
+    println!("{}", board[middle as usize] + board[0]);
+    board = [2, 7, 1, 8];
+    println!("{}", collections_09_fixed_arrays__total_Array4_i64(&board));
+    let names = collections_09_fixed_arrays__labels();
+    println!("{}{}", names[0], names[1]);
+    let palette = collections_09_fixed_arrays__Palette { name: String::from("warm"), shades: [10, 20, 30] };
+    println!("{}", palette.shades[2]);
+}
\ No newline at end of file
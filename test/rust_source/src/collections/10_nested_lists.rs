@@ -0,0 +1,60 @@
+struct collections_10_nested_lists__Board {
+    pub cells: Vec<Vec<i64>>,
+}
+
+impl Default for collections_10_nested_lists__Board {
+    fn default() -> Self {
+        Self { cells: Default::default() }
+    }
+}
+
+fn collections_10_nested_lists__identity_i64(size: i64) -> Vec<Vec<i64>> {
+    let mut grid = vec![vec![0; usize::try_from(size).expect("matrix() sizes can't be negative")]; usize::try_from(size).expect("matrix() sizes can't be negative")];
+    for i in 0..size {
+        grid[i as usize][i as usize] = 1;
+    }
+    grid
+}
+
+fn collections_10_nested_lists__trace_Vec_Vec_i64(grid: &Vec<Vec<i64>>) -> i64 {
+    let mut total: i64 = 0;
+    for i in 0..grid.len() as i64 {
+        total += grid[i as usize][i as usize];
+    }
+    total
+}
+
+fn main() {
+    let mut grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    println!("{}", grid[1][2]);
+    let row: i64 = 0;
+    let col: i64 = 1;
+    grid[row as usize][col as usize] = 9;
+    grid[1][0] *= 3;
+    println!("{}", grid[0][1] + grid[1][0]);
+    let mut copy = grid[1].clone();
+    copy[2] = 100;
+    println!("{}", grid[1][2]);
+    grid.push(copy);
+    grid[2].push(7);
+    println!("{}", grid[2].len() as i64);
+    for line in grid.iter().cloned() {
+        println!("{}", line[0]);
+    }
+    println!("{}", collections_10_nested_lists__trace_Vec_Vec_i64(&collections_10_nested_lists__identity_i64(4)));
+    let mut board = collections_10_nested_lists__Board { cells: vec![vec![0; 2]; 2] };
+    board.cells[1][0] = 5;
+    println!("{}", board.cells[1][0] + board.cells[0][1]);
+    let mut canvas = vec![vec!["."; 3]; 2];
+    canvas[1][2] = "#";
+    for cells in canvas.iter().cloned() {
+        let mut text = String::from("");
+        for cell in cells.iter().cloned() {
+            text.push_str(&cell);
+        }
+        println!("{}", text);
+    }
+    let mut rows: Vec<Vec<f64>> = vec![];
+    rows.push(vec![0.5, 1.5]);
+    println!("{}", rows[0][1]);
+}
\ No newline at end of file
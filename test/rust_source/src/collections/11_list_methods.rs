@@ -0,0 +1,78 @@
+struct collections_11_list_methods__Task {
+    pub name: String,
+    pub cost: i64,
+}
+
+impl Default for collections_11_list_methods__Task {
+    fn default() -> Self {
+        Self { name: String::new(), cost: 0 }
+    }
+}
+
+fn collections_11_list_methods__describe_Option_i64(position: Option<i64>) -> String {
+    {
+        let __zinc_match_23_43 = position;
+        match __zinc_match_23_43.clone() {
+            Some(index) => {
+                return format!("at {}", index);
+            },
+            None => {
+                return String::from("missing");
+            },
+        }
+    }
+}
+
+fn collections_11_list_methods__drain_Vec_i64(queue: &mut Vec<i64>) -> i64 {
+    let mut total: i64 = 0;
+    while !queue.is_empty() {
+        {
+            let __zinc_match_68_97 = queue.pop();
+            match __zinc_match_68_97.clone() {
+                Some(value) => {
+                    total = total + value;
+                },
+                None => {
+                    total = -1;
+                },
+            }
+        }
+    }
+    total
+}
+
+fn main() {
+    let mut numbers = vec![10, 20, 30];
+    numbers.insert(0, 5);
+    numbers.insert(numbers.len(), 40);
+    let at: i64 = 2;
+    numbers.insert(at as usize, 15);
+    println!("{}", numbers.len() as i64);
+    println!("{}", numbers.remove(3 as usize));
+    println!("{}", numbers.contains(&30));
+    println!("{}", numbers.contains(&20));
+    println!("{}", collections_11_list_methods__describe_Option_i64(numbers.iter().position(|__zinc_item| *__zinc_item == 30).map(|index| index as i64)));
+    println!("{}", collections_11_list_methods__describe_Option_i64(numbers.iter().position(|__zinc_item| *__zinc_item == 99).map(|index| index as i64)));
+    numbers.reverse();
+    println!("{}", numbers[0]);
+    println!("{}", collections_11_list_methods__drain_Vec_i64(&mut numbers));
+    println!("{}", numbers.len() as i64);
+    let mut words = vec!["kiwi", "fig"];
+    words.insert(1, "plum");
+    println!("{}", words.iter().any(|__zinc_item| *__zinc_item == "plum"));
+    println!("{}", collections_11_list_methods__describe_Option_i64(words.iter().position(|__zinc_item| *__zinc_item == "fig").map(|index| index as i64)));
+    println!("{}", words.remove(0));
+    let mut tasks = vec![collections_11_list_methods__Task { name: String::from("write"), cost: 3 }, collections_11_list_methods__Task { name: String::from("test"), cost: 2 }];
+    tasks.reverse();
+    let last = tasks.remove(1);
+    println!("{}", last.name);
+    tasks.pop();
+    println!("{}", tasks.len() as i64);
+    let mut grid = vec![vec![1, 2], vec![3, 4]];
+    grid[0].reverse();
+    println!("{}", grid[0][0]);
+    let row = grid.remove(1);
+    println!("{}", row[0]);
+    let weights = vec![0.5, 2.0];
+    println!("{}", weights.contains(&2.0));
+}
\ No newline at end of file
@@ -7,8 +7,11 @@ async fn concurrency_channels_02_spawn_parameter_round_trip__tx_i64_Channel(x: i
 #[tokio::main]
 async fn main() {
     let mut __zinc_spawn_handles = Vec::new();
+    // create channel
     let x_chan = Channel::<i64>::unbounded();
+    // send data on the channel from a spawned task
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_1 = x_chan.clone(); async move { concurrency_channels_02_spawn_parameter_round_trip__tx_i64_Channel(42, __zinc_spawn_arg_1.clone()).await; } }));
+    // receive data from the channel
     let x = x_chan.recv().await;
     println!("{}", x);
     while let Some(__zinc_spawn_handle) = __zinc_spawn_handles.pop() {
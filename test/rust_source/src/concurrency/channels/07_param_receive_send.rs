@@ -2,7 +2,7 @@ use zinc_internal::{Channel};
 
 async fn concurrency_channels_07_param_receive_send__bounce_Channel_Channel(input: Channel<i64>, output: Channel<i64>) {
     let value = input.recv().await;
-    output.send((value + 1)).await;
+    output.send(value + 1).await;
     output.close();
 }
 
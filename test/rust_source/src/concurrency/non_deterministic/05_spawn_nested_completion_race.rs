@@ -4,7 +4,7 @@ async fn concurrency_non_deterministic_05_spawn_nested_completion_race__child_i6
 
 async fn concurrency_non_deterministic_05_spawn_nested_completion_race__parent_i64(x: i64) {
     let mut __zinc_spawn_handles = Vec::new();
-    __zinc_spawn_handles.push(tokio::spawn(async move { concurrency_non_deterministic_05_spawn_nested_completion_race__child_i64((x + 1)).await; }));
+    __zinc_spawn_handles.push(tokio::spawn(async move { concurrency_non_deterministic_05_spawn_nested_completion_race__child_i64(x + 1).await; }));
     println!("{}", x);
     while let Some(__zinc_spawn_handle) = __zinc_spawn_handles.pop() {
         __zinc_spawn_handle.await.unwrap();
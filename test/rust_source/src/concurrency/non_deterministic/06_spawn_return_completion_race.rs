@@ -8,7 +8,7 @@ async fn concurrency_non_deterministic_06_spawn_return_completion_race__launch_i
     while let Some(__zinc_spawn_handle) = __zinc_spawn_handles.pop() {
         __zinc_spawn_handle.await.unwrap();
     }
-    return 99;
+    99
 }
 
 #[tokio::main]
@@ -12,12 +12,17 @@ async fn concurrency_non_deterministic_09_bounded_backpressure_interleaving__tx_
 #[tokio::main]
 async fn main() {
     let mut __zinc_spawn_handles = Vec::new();
+    // create channel
     let x_chan = Channel::<i64>::bounded(2);
+    // send data on the channel from a spawned task
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = x_chan.clone(); async move { concurrency_non_deterministic_09_bounded_backpressure_interleaving__tx_BoundedChannel(__zinc_spawn_arg_0.clone()).await; } }));
+    // receive data from the channel
     let mut x = x_chan.recv().await;
     println!("{} <-", x);
+    // receive data from the channel
     x = x_chan.recv().await;
     println!("{} <-", x);
+    // receive data from the channel
     x = x_chan.recv().await;
     println!("{} <-", x);
     while let Some(__zinc_spawn_handle) = __zinc_spawn_handles.pop() {
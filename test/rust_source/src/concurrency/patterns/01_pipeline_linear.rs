@@ -11,7 +11,7 @@ async fn main() {
     let stage2 = Channel::<i64>::unbounded();
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = stage1.clone(); async move { concurrency_patterns_01_pipeline_linear__source_Channel(__zinc_spawn_arg_0.clone()).await; } }));
     let value = stage1.recv().await;
-    stage2.send((value * 2)).await;
+    stage2.send(value * 2).await;
     let result = stage2.recv().await;
     println!("{}", result);
     while let Some(__zinc_spawn_handle) = __zinc_spawn_handles.pop() {
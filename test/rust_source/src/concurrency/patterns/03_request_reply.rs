@@ -1,7 +1,7 @@
 use zinc_internal::{Channel};
 
 async fn concurrency_patterns_03_request_reply__respond_Channel_i64(reply: Channel<i64>, request: i64) {
-    reply.send((request + 1)).await;
+    reply.send(request + 1).await;
 }
 
 #[tokio::main]
@@ -1,7 +1,7 @@
 use zinc_internal::{Channel};
 
 async fn concurrency_patterns_04_worker_pool_sum__worker_Channel_i64(results: Channel<i64>, value: i64) {
-    results.send((value * value)).await;
+    results.send(value * value).await;
 }
 
 #[tokio::main]
@@ -11,10 +11,10 @@ async fn main() {
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = results.clone(); async move { concurrency_patterns_04_worker_pool_sum__worker_Channel_i64(__zinc_spawn_arg_0.clone(), 1).await; } }));
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = results.clone(); async move { concurrency_patterns_04_worker_pool_sum__worker_Channel_i64(__zinc_spawn_arg_0.clone(), 2).await; } }));
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = results.clone(); async move { concurrency_patterns_04_worker_pool_sum__worker_Channel_i64(__zinc_spawn_arg_0.clone(), 3).await; } }));
-    let mut total = 0;
+    let mut total: i64 = 0;
     for i in 0..3 {
         let value = results.recv().await;
-        total = (total + value);
+        total = total + value;
     }
     println!("{}", total);
     while let Some(__zinc_spawn_handle) = __zinc_spawn_handles.pop() {
@@ -1,11 +1,11 @@
 use zinc_internal::{Channel};
 
 async fn concurrency_patterns_06_fan_out_coordinated__double_Channel_i64(out: Channel<i64>, value: i64) {
-    out.send((value * 2)).await;
+    out.send(value * 2).await;
 }
 
 async fn concurrency_patterns_06_fan_out_coordinated__triple_Channel_i64(out: Channel<i64>, value: i64) {
-    out.send((value * 3)).await;
+    out.send(value * 3).await;
 }
 
 #[tokio::main]
@@ -13,7 +13,7 @@ async fn main() {
     let mut __zinc_spawn_handles = Vec::new();
     let doubles = Channel::<i64>::unbounded();
     let triples = Channel::<i64>::unbounded();
-    let source = 5;
+    let source: i64 = 5;
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = doubles.clone(); async move { concurrency_patterns_06_fan_out_coordinated__double_Channel_i64(__zinc_spawn_arg_0.clone(), source).await; } }));
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = triples.clone(); async move { concurrency_patterns_06_fan_out_coordinated__triple_Channel_i64(__zinc_spawn_arg_0.clone(), source).await; } }));
     println!("{}", doubles.recv().await);
@@ -8,7 +8,7 @@ async fn concurrency_spawn_02_helper_waits_for_child__helper() -> i64 {
     while let Some(__zinc_spawn_handle) = __zinc_spawn_handles.pop() {
         __zinc_spawn_handle.await.unwrap();
     }
-    return 99;
+    99
 }
 
 #[tokio::main]
@@ -11,10 +11,10 @@ async fn main() {
     for i in 1..=4 {
         __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = results.clone(); async move { concurrency_spawn_04_loop_collects_all__emit_Channel_i64(__zinc_spawn_arg_0.clone(), i).await; } }));
     }
-    let mut total = 0;
+    let mut total: i64 = 0;
     for i in 0..4 {
         let value = results.recv().await;
-        total = (total + value);
+        total = total + value;
     }
     println!("{}", total);
     while let Some(__zinc_spawn_handle) = __zinc_spawn_handles.pop() {
@@ -0,0 +1,48 @@
+use zinc_internal::{Channel};
+
+#[derive(Clone)]
+struct __ZincClosureEnv_concurrency_spawn_05_on_interrupt___lambda_concurrency_spawn_05_on_interrupt__main_17_29 {
+}
+
+#[derive(Clone)]
+enum __ZincCallable_Unit_to_Unit {
+    Closed,
+    V0(__ZincClosureEnv_concurrency_spawn_05_on_interrupt___lambda_concurrency_spawn_05_on_interrupt__main_17_29),
+}
+
+impl Default for __ZincCallable_Unit_to_Unit {
+    fn default() -> Self {
+        Self::Closed
+    }
+}
+
+impl __ZincCallable_Unit_to_Unit {
+    fn call(&self, ) {
+        match self {
+            Self::Closed => panic!("callable used after closed receive"),
+            Self::V0(env) => { concurrency_spawn_05_on_interrupt____lambda_concurrency_spawn_05_on_interrupt__main_17_29(env.clone()); }
+        }
+    }
+}
+
+fn concurrency_spawn_05_on_interrupt____lambda_concurrency_spawn_05_on_interrupt__main_17_29(__env: __ZincClosureEnv_concurrency_spawn_05_on_interrupt___lambda_concurrency_spawn_05_on_interrupt__main_17_29) {
+    println!("draining connections");
+    std::process::exit(0);
+}
+
+async fn concurrency_spawn_05_on_interrupt__serve_Channel(done: Channel<String>) {
+    done.send(String::from("served")).await;
+}
+
+#[tokio::main]
+async fn main() {
+    let mut __zinc_spawn_handles = Vec::new();
+    tokio::spawn({ let __zinc_interrupt_handler = __ZincCallable_Unit_to_Unit::V0(__ZincClosureEnv_concurrency_spawn_05_on_interrupt___lambda_concurrency_spawn_05_on_interrupt__main_17_29 {}); async move { while tokio::signal::ctrl_c().await.is_ok() { __zinc_interrupt_handler.call(); } } });
+    let done = Channel::<String>::unbounded();
+    __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = done.clone(); async move { concurrency_spawn_05_on_interrupt__serve_Channel(__zinc_spawn_arg_0.clone()).await; } }));
+    let status = done.recv().await;
+    println!("{}", status);
+    while let Some(__zinc_spawn_handle) = __zinc_spawn_handles.pop() {
+        __zinc_spawn_handle.await.unwrap();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+// `when` blocks pick declarations, struct fields, and statements per build.
+static CONDITIONAL_01_WHEN__SEPARATOR: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| String::from("/"));
+
+struct conditional_01_when__Settings {
+    pub name: String,
+    pub trace: bool,
+}
+
+impl Default for conditional_01_when__Settings {
+    fn default() -> Self {
+        Self { name: String::new(), trace: false }
+    }
+}
+
+fn conditional_01_when__join_String_String(a: &str, b: &str) -> String {
+    format!("{}{}{}", a, (*CONDITIONAL_01_WHEN__SEPARATOR).clone(), b)
+}
+
+fn main() {
+    let settings = conditional_01_when__Settings { name: String::from("dev"), trace: true };
+    println!("{} trace={}", settings.name, settings.trace);
+    println!("{}", conditional_01_when__join_String_String("usr", "bin"));
+    let when: i64 = 3;
+    println!("{}", 6);
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 const CONST_TEST__PI: f64 = 3.14159;
 
 fn main() {
-    let area = ((CONST_TEST__PI * 5.0) * 5.0);
+    let area = 78.53975;
     println!("Area: {}", area);
 }
\ No newline at end of file
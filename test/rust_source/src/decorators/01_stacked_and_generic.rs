@@ -95,21 +95,21 @@ fn decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic
 fn decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic__labeler_String_60_89_i64_to_i64(__env: __ZincClosureEnv_decorators_01_stacked_and_generic___lambda_decorators_01_stacked_and_generic__labeler_String_60_89, f: __ZincCallable_i64_to_i64) -> __ZincCallable_i64_to_i64 {
     let __zv_decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic__labeler_String_60_89_i64_to_i64_label_String = __env.label.clone();
     let __zv_decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic__labeler_String_60_89_i64_to_i64_f_i64_i64 = Arc::new(Mutex::new(f));
-    return __ZincCallable_i64_to_i64::V0(__ZincClosureEnv_decorators_01_stacked_and_generic___lambda_decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic__labeler_String_60_89_i64_to_i64_72_88 { label: __zv_decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic__labeler_String_60_89_i64_to_i64_label_String.clone(), f: __zv_decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic__labeler_String_60_89_i64_to_i64_f_i64_i64.clone() });
+    __ZincCallable_i64_to_i64::V0(__ZincClosureEnv_decorators_01_stacked_and_generic___lambda_decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic__labeler_String_60_89_i64_to_i64_72_88 { label: __zv_decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic__labeler_String_60_89_i64_to_i64_label_String.clone(), f: __zv_decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic__labeler_String_60_89_i64_to_i64_f_i64_i64.clone() })
 }
 
 fn decorators_01_stacked_and_generic__labeler_String(label: String) -> __ZincCallable_i64_to_i64_to_i64_to_i64 {
     let __zv_decorators_01_stacked_and_generic__labeler_String_label_String = Arc::new(Mutex::new(label));
-    return __ZincCallable_i64_to_i64_to_i64_to_i64::V0(__ZincClosureEnv_decorators_01_stacked_and_generic___lambda_decorators_01_stacked_and_generic__labeler_String_60_89 { label: __zv_decorators_01_stacked_and_generic__labeler_String_label_String.clone() });
+    __ZincCallable_i64_to_i64_to_i64_to_i64::V0(__ZincClosureEnv_decorators_01_stacked_and_generic___lambda_decorators_01_stacked_and_generic__labeler_String_60_89 { label: __zv_decorators_01_stacked_and_generic__labeler_String_label_String.clone() })
 }
 
 fn decorators_01_stacked_and_generic__logged_i64_to_i64(f: __ZincCallable_i64_to_i64) -> __ZincCallable_i64_to_i64 {
     let __zv_decorators_01_stacked_and_generic__logged_i64_to_i64_f_i64_i64 = Arc::new(Mutex::new(f));
-    return __ZincCallable_i64_to_i64::V1(__ZincClosureEnv_decorators_01_stacked_and_generic___lambda_decorators_01_stacked_and_generic__logged_i64_to_i64_19_35 { f: __zv_decorators_01_stacked_and_generic__logged_i64_to_i64_f_i64_i64.clone() });
+    __ZincCallable_i64_to_i64::V1(__ZincClosureEnv_decorators_01_stacked_and_generic___lambda_decorators_01_stacked_and_generic__logged_i64_to_i64_19_35 { f: __zv_decorators_01_stacked_and_generic__logged_i64_to_i64_f_i64_i64.clone() })
 }
 
 fn decorators_01_stacked_and_generic__inc_i64__zinc_impl(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn decorators_01_stacked_and_generic__inc_i64(x: i64) -> i64 {
@@ -117,7 +117,7 @@ fn decorators_01_stacked_and_generic__inc_i64(x: i64) -> i64 {
     let __zinc_decorated_1 = decorators_01_stacked_and_generic__logged_i64_to_i64(__zinc_decorated_0.clone());
     let __zinc_decorator_factory_2 = decorators_01_stacked_and_generic__labeler_String(String::from("outer"));
     let __zinc_decorated_2 = __zinc_decorator_factory_2.call(__zinc_decorated_1.clone());
-    return __zinc_decorated_2.call(x);
+    __zinc_decorated_2.call(x)
 }
 
 fn decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic__logged_i64_to_i64_19_35_i64(__env: __ZincClosureEnv_decorators_01_stacked_and_generic___lambda_decorators_01_stacked_and_generic__logged_i64_to_i64_19_35, x: i64) -> i64 {
@@ -127,31 +127,31 @@ fn decorators_01_stacked_and_generic____lambda_decorators_01_stacked_and_generic
 }
 
 fn decorators_01_stacked_and_generic__identity_dec_String_to_String(f: __ZincCallable_String_to_String) -> __ZincCallable_String_to_String {
-    return f;
+    f
 }
 
 fn decorators_01_stacked_and_generic__echo_String__zinc_impl(x: String) -> String {
-    return x;
+    x
 }
 
 fn decorators_01_stacked_and_generic__echo_String(x: String) -> String {
     let __zinc_decorated_0 = __ZincCallable_String_to_String::V0;
     let __zinc_decorated_1 = decorators_01_stacked_and_generic__identity_dec_String_to_String(__zinc_decorated_0.clone());
-    return __zinc_decorated_1.call(x);
+    __zinc_decorated_1.call(x)
 }
 
 fn decorators_01_stacked_and_generic__identity_dec_i64_to_i64(f: __ZincCallable_i64_to_i64) -> __ZincCallable_i64_to_i64 {
-    return f;
+    f
 }
 
 fn decorators_01_stacked_and_generic__echo_i64__zinc_impl(x: i64) -> i64 {
-    return x;
+    x
 }
 
 fn decorators_01_stacked_and_generic__echo_i64(x: i64) -> i64 {
     let __zinc_decorated_0 = __ZincCallable_i64_to_i64::V2;
     let __zinc_decorated_1 = decorators_01_stacked_and_generic__identity_dec_i64_to_i64(__zinc_decorated_0.clone());
-    return __zinc_decorated_1.call(x);
+    __zinc_decorated_1.call(x)
 }
 
 fn main() {
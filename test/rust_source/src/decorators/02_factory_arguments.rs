@@ -47,67 +47,67 @@ impl __ZincCallable_i64_to_i64_to_i64_to_i64 {
 }
 
 fn decorators_02_factory_arguments__identity_i64_i64_to_i64(f: __ZincCallable_i64_to_i64) -> __ZincCallable_i64_to_i64 {
-    return f;
+    f
 }
 
-fn decorators_02_factory_arguments__announce_String_i64_String(label: String, times: i64, suffix: String) -> __ZincCallable_i64_to_i64_to_i64_to_i64 {
+fn decorators_02_factory_arguments__announce_String_i64_String(label: &str, times: i64, suffix: &str) -> __ZincCallable_i64_to_i64_to_i64_to_i64 {
     println!("{}", label);
     println!("{}", times);
     println!("{}", suffix);
-    return __ZincCallable_i64_to_i64_to_i64_to_i64::V0;
+    __ZincCallable_i64_to_i64_to_i64_to_i64::V0
 }
 
-fn decorators_02_factory_arguments__direct_label_i64_to_i64_String(f: __ZincCallable_i64_to_i64, label: String) -> __ZincCallable_i64_to_i64 {
+fn decorators_02_factory_arguments__direct_label_i64_to_i64_String(f: __ZincCallable_i64_to_i64, label: &str) -> __ZincCallable_i64_to_i64 {
     println!("{}", label);
-    return f;
+    f
 }
 
 fn decorators_02_factory_arguments__direct_default_i64__zinc_impl(x: i64) -> i64 {
-    return (x + 100);
+    x + 100
 }
 
 fn decorators_02_factory_arguments__direct_default_i64(x: i64) -> i64 {
     let __zinc_decorated_0 = __ZincCallable_i64_to_i64::V0;
-    let __zinc_decorated_1 = decorators_02_factory_arguments__direct_label_i64_to_i64_String(__zinc_decorated_0.clone(), String::from("direct-default"));
-    return __zinc_decorated_1.call(x);
+    let __zinc_decorated_1 = decorators_02_factory_arguments__direct_label_i64_to_i64_String(__zinc_decorated_0.clone(), "direct-default");
+    __zinc_decorated_1.call(x)
 }
 
 fn decorators_02_factory_arguments__empty_factory() -> __ZincCallable_i64_to_i64_to_i64_to_i64 {
     println!("empty");
-    return __ZincCallable_i64_to_i64_to_i64_to_i64::V0;
+    __ZincCallable_i64_to_i64_to_i64_to_i64::V0
 }
 
 fn decorators_02_factory_arguments__empty_value_i64__zinc_impl(x: i64) -> i64 {
-    return (x + 30);
+    x + 30
 }
 
 fn decorators_02_factory_arguments__empty_value_i64(x: i64) -> i64 {
     let __zinc_decorated_0 = __ZincCallable_i64_to_i64::V1;
     let __zinc_decorator_factory_1 = decorators_02_factory_arguments__empty_factory();
     let __zinc_decorated_1 = __zinc_decorator_factory_1.call(__zinc_decorated_0.clone());
-    return __zinc_decorated_1.call(x);
+    __zinc_decorated_1.call(x)
 }
 
 fn decorators_02_factory_arguments__named_i64__zinc_impl(x: i64) -> i64 {
-    return (x + 10);
+    x + 10
 }
 
 fn decorators_02_factory_arguments__named_i64(x: i64) -> i64 {
     let __zinc_decorated_0 = __ZincCallable_i64_to_i64::V2;
-    let __zinc_decorator_factory_1 = decorators_02_factory_arguments__announce_String_i64_String(String::from("named"), 1, String::from("?"));
+    let __zinc_decorator_factory_1 = decorators_02_factory_arguments__announce_String_i64_String("named", 1, "?");
     let __zinc_decorated_1 = __zinc_decorator_factory_1.call(__zinc_decorated_0.clone());
-    return __zinc_decorated_1.call(x);
+    __zinc_decorated_1.call(x)
 }
 
 fn decorators_02_factory_arguments__positional_i64__zinc_impl(x: i64) -> i64 {
-    return (x + 20);
+    x + 20
 }
 
 fn decorators_02_factory_arguments__positional_i64(x: i64) -> i64 {
     let __zinc_decorated_0 = __ZincCallable_i64_to_i64::V3;
-    let __zinc_decorator_factory_1 = decorators_02_factory_arguments__announce_String_i64_String(String::from("positional"), 2, String::from("!"));
+    let __zinc_decorator_factory_1 = decorators_02_factory_arguments__announce_String_i64_String("positional", 2, "!");
     let __zinc_decorated_1 = __zinc_decorator_factory_1.call(__zinc_decorated_0.clone());
-    return __zinc_decorated_1.call(x);
+    __zinc_decorated_1.call(x)
 }
 
 fn main() {
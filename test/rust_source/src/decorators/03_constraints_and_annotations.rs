@@ -22,31 +22,31 @@ impl __ZincCallable_i64_to_i64 {
 }
 
 fn decorators_03_constraints_and_annotations__identity_i64_i64_to_i64(f: __ZincCallable_i64_to_i64) -> __ZincCallable_i64_to_i64 {
-    return f;
+    f
 }
 
 fn decorators_03_constraints_and_annotations__annotated_i64__zinc_impl(x: i64) -> i64 {
-    return (x * 2);
+    x * 2
 }
 
 fn decorators_03_constraints_and_annotations__annotated_i64(x: i64) -> i64 {
     let __zinc_decorated_0 = __ZincCallable_i64_to_i64::V0;
     let __zinc_decorated_1 = decorators_03_constraints_and_annotations__identity_i64_i64_to_i64(__zinc_decorated_0.clone());
-    return __zinc_decorated_1.call(x);
+    __zinc_decorated_1.call(x)
 }
 
 fn decorators_03_constraints_and_annotations__identity_dec_i64_to_i64(f: __ZincCallable_i64_to_i64) -> __ZincCallable_i64_to_i64 {
-    return f;
+    f
 }
 
 fn decorators_03_constraints_and_annotations__constrained_i64__zinc_impl(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn decorators_03_constraints_and_annotations__constrained_i64(x: i64) -> i64 {
     let __zinc_decorated_0 = __ZincCallable_i64_to_i64::V1;
     let __zinc_decorated_1 = decorators_03_constraints_and_annotations__identity_dec_i64_to_i64(__zinc_decorated_0.clone());
-    return __zinc_decorated_1.call(x);
+    __zinc_decorated_1.call(x)
 }
 
 fn main() {
@@ -0,0 +1,111 @@
+use zinc_internal::{ToJson, to_json, write_json_key};
+use std::collections::{HashMap};
+
+#[derive(Clone, PartialEq)]
+enum decorators_05_derive_and_inline__Shape {
+    Dot,
+    Circle { r: f64 },
+}
+
+impl ToJson for decorators_05_derive_and_inline__Shape {
+    fn write_json(&self, out: &mut String) {
+        match self {
+            Self::Dot => "Dot".write_json(out),
+            Self::Circle { r } => {
+                out.push('{');
+                write_json_key(out, "Circle");
+                out.push('{');
+                write_json_key(out, "r");
+                r.write_json(out);
+                out.push_str("}}");
+            }
+        }
+    }
+}
+
+#[derive(PartialEq)]
+struct decorators_05_derive_and_inline__Point {
+    pub x: i64,
+    pub label: String,
+    _id: i64,
+}
+
+impl Default for decorators_05_derive_and_inline__Point {
+    fn default() -> Self {
+        Self { x: 0, label: String::new(), _id: 0 }
+    }
+}
+
+impl ToJson for decorators_05_derive_and_inline__Point {
+    fn write_json(&self, out: &mut String) {
+        out.push('{');
+        write_json_key(out, "x");
+        self.x.write_json(out);
+        out.push(',');
+        write_json_key(out, "label");
+        self.label.write_json(out);
+        out.push('}');
+    }
+}
+
+impl decorators_05_derive_and_inline__Point {
+    #[inline]
+    fn shifted(&self, dx: i64) -> i64 {
+        self.x + dx
+    }
+}
+
+struct decorators_05_derive_and_inline__Scene {
+    pub points: Vec<decorators_05_derive_and_inline__Point>,
+    pub tags: HashMap<String, i64>,
+}
+
+impl Default for decorators_05_derive_and_inline__Scene {
+    fn default() -> Self {
+        Self { points: Default::default(), tags: Default::default() }
+    }
+}
+
+impl ToJson for decorators_05_derive_and_inline__Scene {
+    fn write_json(&self, out: &mut String) {
+        out.push('{');
+        write_json_key(out, "points");
+        self.points.write_json(out);
+        out.push(',');
+        write_json_key(out, "tags");
+        self.tags.write_json(out);
+        out.push('}');
+    }
+}
+
+#[cold]
+fn decorators_05_derive_and_inline__give_up_String(reason: &str) -> i64 {
+    println!("giving up: {}", reason);
+    -1
+}
+
+#[inline]
+fn decorators_05_derive_and_inline__twice_i64(x: i64) -> i64 {
+    x * 2
+}
+
+fn main() {
+    let a = decorators_05_derive_and_inline__Point { x: 1, label: String::from("say \"hi\""), _id: 7 };
+    let b = decorators_05_derive_and_inline__Point { x: 1, label: String::from("say \"hi\""), _id: 7 };
+    let c = decorators_05_derive_and_inline__Point { x: 2, label: String::from("c"), _id: 0 };
+    println!("{}", a == b);
+    println!("{}", a != c);
+    println!("{}", decorators_05_derive_and_inline__Shape::Dot == decorators_05_derive_and_inline__Shape::Dot);
+    println!("{}", decorators_05_derive_and_inline__Shape::Circle { r: 1.5 } == decorators_05_derive_and_inline__Shape::Dot);
+    println!("{}", decorators_05_derive_and_inline__twice_i64(a.shifted(3)));
+    if a != b {
+        println!("{}", decorators_05_derive_and_inline__give_up_String("points differ"));
+    }
+    println!("{}", decorators_05_derive_and_inline__give_up_String("done"));
+    println!("{}", to_json(&a));
+    println!("{}", to_json(&decorators_05_derive_and_inline__Shape::Dot));
+    println!("{}", to_json(&decorators_05_derive_and_inline__Shape::Circle { r: 2.0 }));
+    let scene = decorators_05_derive_and_inline__Scene { points: vec![a, c], tags: HashMap::from([(String::from("b"), 2), (String::from("a"), 1)]) };
+    println!("{}", to_json(&scene));
+    println!("{}", to_json(&vec![(1, true), (2, false)]));
+}
\ No newline at end of file
@@ -0,0 +1,85 @@
+fn decorators_06_memoize__divisors_i64__zinc_uncached(n: i64) -> Vec<i64> {
+    let mut found: Vec<i64> = vec![];
+    for d in 1..n + 1 {
+        if (n % d) == 0 {
+            found.push(d);
+        }
+    }
+    found
+}
+
+fn decorators_06_memoize__divisors_i64(n: i64) -> Vec<i64> {
+    static CACHE: std::sync::LazyLock<std::sync::Mutex<std::collections::HashMap<(i64,), Vec<i64>>>> = std::sync::LazyLock::new(Default::default);
+    let key = (n,);
+    if let Some(value) = CACHE.lock().unwrap().get(&key) {
+        return value.clone();
+    }
+    let value = decorators_06_memoize__divisors_i64__zinc_uncached(n);
+    CACHE.lock().unwrap().insert(key, value.clone());
+    value
+}
+
+fn decorators_06_memoize__fib_i64__zinc_uncached(n: i64) -> i64 {
+    if n < 2 {
+        return n;
+    }
+    decorators_06_memoize__fib_i64(n - 1) + decorators_06_memoize__fib_i64(n - 2)
+}
+
+fn decorators_06_memoize__fib_i64(n: i64) -> i64 {
+    static CACHE: std::sync::LazyLock<std::sync::Mutex<std::collections::HashMap<(i64,), i64>>> = std::sync::LazyLock::new(Default::default);
+    let key = (n,);
+    if let Some(value) = CACHE.lock().unwrap().get(&key) {
+        return value.clone();
+    }
+    let value = decorators_06_memoize__fib_i64__zinc_uncached(n);
+    CACHE.lock().unwrap().insert(key, value.clone());
+    value
+}
+
+fn decorators_06_memoize__label_String_i64__zinc_uncached(name: &str, count: i64) -> String {
+    println!("building {}", name);
+    format!("{} x{}", name, count)
+}
+
+fn decorators_06_memoize__label_String_i64(name: &str, count: i64) -> String {
+    static CACHE: std::sync::LazyLock<std::sync::Mutex<std::collections::HashMap<(String, i64), String>>> = std::sync::LazyLock::new(Default::default);
+    let key = (name.to_string(), count);
+    if let Some(value) = CACHE.lock().unwrap().get(&key) {
+        return value.clone();
+    }
+    let value = decorators_06_memoize__label_String_i64__zinc_uncached(name, count);
+    CACHE.lock().unwrap().insert(key, value.clone());
+    value
+}
+
+fn decorators_06_memoize__paths_i64_i64__zinc_uncached(rows: i64, cols: i64) -> i64 {
+    if (rows == 0) || cols == 0 {
+        return 1;
+    }
+    decorators_06_memoize__paths_i64_i64(rows - 1, cols) + decorators_06_memoize__paths_i64_i64(rows, cols - 1)
+}
+
+fn decorators_06_memoize__paths_i64_i64(rows: i64, cols: i64) -> i64 {
+    static CACHE: std::sync::LazyLock<std::sync::Mutex<std::collections::HashMap<(i64, i64), i64>>> = std::sync::LazyLock::new(Default::default);
+    let key = (rows, cols);
+    if let Some(value) = CACHE.lock().unwrap().get(&key) {
+        return value.clone();
+    }
+    let value = decorators_06_memoize__paths_i64_i64__zinc_uncached(rows, cols);
+    CACHE.lock().unwrap().insert(key, value.clone());
+    value
+}
+
+fn main() {
+    println!("{}", decorators_06_memoize__fib_i64(60));
+    println!("{}", decorators_06_memoize__paths_i64_i64(16, 16));
+    println!("{}", decorators_06_memoize__label_String_i64("apples", 3));
+    println!("{}", decorators_06_memoize__label_String_i64("apples", 3));
+    println!("{}", decorators_06_memoize__label_String_i64("pears", 3));
+    let mut first = decorators_06_memoize__divisors_i64(12);
+    first.push(99);
+    let again = decorators_06_memoize__divisors_i64(12);
+    println!("{}", again.len() as i64);
+    println!("{}", again[(again.len() as i64 - 1) as usize]);
+}
\ No newline at end of file
@@ -1,24 +1,38 @@
+// Test: Rapid type shadowing chain
+// Shadow same variable through all types multiple times
+// Expected: Valid - each shadow creates new `let` binding
 fn main() {
-    let x = 1;
+    // Start with integer
+    let x: i64 = 1;
     println!("int: {}", x);
+    // Shadow to float
     let x = 3.14;
     println!("float: {}", x);
+    // Shadow to string
     let x = "hello";
     println!("string: {}", x);
+    // Shadow to boolean
     let x = true;
     println!("bool: {}", x);
-    let x = 42;
+    // Shadow back to integer
+    let x: i64 = 42;
     println!("int: {}", x);
+    // Shadow to float again
     let x = 2.718;
     println!("float: {}", x);
+    // Shadow to string again
     let x = "world";
     println!("string: {}", x);
+    // Shadow to boolean again
     let x = false;
     println!("bool: {}", x);
-    let x = 100;
+    // Shadow to integer again
+    let x: i64 = 100;
     println!("int: {}", x);
+    // Shadow to float one more time
     let x = 0.5;
     println!("float: {}", x);
+    // Final shadow to string
     let x = "done";
     println!("string: {}", x);
 }
\ No newline at end of file
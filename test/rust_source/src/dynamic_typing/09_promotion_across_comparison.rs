@@ -1,25 +1,36 @@
+// Test: Type promotion in comparison context
+// Float result compared to int
+// Expected: Valid - comparison works, result is bool
 fn main() {
-    let a = ((((1 as f64) + 2.0)) > (3 as f64));
+    // Float compared to int
+    let a = false;
     println!("(1 + 2.0) > 3: {}", a);
-    let b = ((5 as f64) < 4.5);
+    // Int compared to float
+    let b = false;
     println!("5 < 4.5: {}", b);
-    let c = (3.0 == (3 as f64));
+    // Equality with promotion
+    let c = true;
     println!("3.0 == 3: {}", c);
-    let d = ((((10 as f64) / 2.0)) >= (5 as f64));
+    // Complex expression comparison
+    let d = true;
     println!("(10 / 2.0) >= 5: {}", d);
+    // Chained comparisons (if supported) or separate
     let x = 1.5;
-    let e = (x > (1 as f64));
+    let e = true;
     println!("1.5 > 1: {}", e);
-    let f = (x < (2 as f64));
+    let f = true;
     println!("1.5 < 2: {}", f);
-    let g = (((((1 as f64) + 0.5)) > (1 as f64)) && ((((2 as f64) + 0.5)) > (2 as f64)));
+    // Comparison result used in logic
+    let g = true;
     println!("both comparisons true: {}", g);
-    let h = (2.5 != (2 as f64));
+    // Inequality
+    let h = true;
     println!("2.5 != 2: {}", h);
-    let i = (2.0 != (2 as f64));
+    let i = false;
     println!("2.0 != 2: {}", i);
-    let j = (0.0 == (0 as f64));
+    // Edge cases
+    let j = true;
     println!("0.0 == 0: {}", j);
-    let k = (((-1) as f64) < 0.0);
+    let k = true;
     println!("-1 < 0.0: {}", k);
 }
\ No newline at end of file
@@ -1,33 +1,48 @@
+// Test: Recursive function with different types at each level
+// Expected: Potentially undefined - recursive specialization
 fn dynamic_typing_11_mono_recursive_generic__countdown_i64(n: i64) -> i64 {
-    if (n <= 0) {
-        println!("done");
-        return 0;
+    let mut __zinc_tail_args = (n,);
+    '__zinc_tail: loop {
+        let (n,) = __zinc_tail_args;
+        if n <= 0 {
+            println!("done");
+            return 0;
+        }
+        println!("n: {}", n);
+        __zinc_tail_args = (n - 1,);
+        continue '__zinc_tail;
     }
-    println!("n: {}", n);
-    return dynamic_typing_11_mono_recursive_generic__countdown_i64((n - 1));
 }
 
 fn dynamic_typing_11_mono_recursive_generic__factorial_i64(n: i64) -> i64 {
-    if (n <= 1) {
+    if n <= 1 {
         return 1;
     }
-    return (n * dynamic_typing_11_mono_recursive_generic__factorial_i64((n - 1)));
+    n * dynamic_typing_11_mono_recursive_generic__factorial_i64(n - 1)
 }
 
 fn dynamic_typing_11_mono_recursive_generic__sum_to_i64(n: i64) -> i64 {
-    if (n <= 0) {
+    if n <= 0 {
         return 0;
     }
-    return (n + dynamic_typing_11_mono_recursive_generic__sum_to_i64((n - 1)));
+    n + dynamic_typing_11_mono_recursive_generic__sum_to_i64(n - 1)
 }
 
 fn main() {
+    // Simple recursive countdown with int
     let result1 = dynamic_typing_11_mono_recursive_generic__countdown_i64(5);
     println!("countdown result: {}", result1);
+    // Sum using recursion
     let result2 = dynamic_typing_11_mono_recursive_generic__sum_to_i64(10);
     println!("sum_to(10): {}", result2);
+    // Factorial
     let result3 = dynamic_typing_11_mono_recursive_generic__factorial_i64(5);
     println!("factorial(5): {}", result3);
+    // What if we call with float? Type changes through recursion
+    // This could create type mismatch issues
+    // result4 = countdown(5.0)
+    // print("countdown(5.0): {result4}")
+    // Recursive with type that stays consistent
     let result5 = dynamic_typing_11_mono_recursive_generic__sum_to_i64(5);
     println!("sum_to(5): {}", result5);
 }
\ No newline at end of file
@@ -1,4 +1,9 @@
+// Test: Empty array that never gets element type
+// Expected: Undefined - Vec<unknown> cannot compile in Rust
 fn main() {
+    // Create empty array but never push to it
+    // This should result in Vec<unknown> which won't compile
+    // For now, test arrays that DO get types
     let mut a = vec![];
     a.push(1);
     println!("a[0]: {}", a[0]);
@@ -11,8 +16,13 @@ fn main() {
     let mut d = vec![];
     d.push("hello");
     println!("d[0]: {}", d[0]);
+    // Array with initial elements
     let e = vec![1, 2, 3];
     println!("e[0]: {}", e[0]);
+    // Empty array - uncommenting this should fail:
+    // empty = []
+    // print("{empty}")
+    // But we can create and immediately push
     let mut f = vec![];
     f.push(42);
     f.push(43);
@@ -1,4 +1,8 @@
+// Test: Array with mixed literal types
+// [1, 2.0, 3] - Array with mixed int/float literals
+// Expected: Ambiguous - what is element type?
 fn main() {
+    // Homogeneous arrays are fine
     let ints = vec![1, 2, 3, 4, 5];
     println!("ints[0]: {}", ints[0]);
     println!("ints[4]: {}", ints[4]);
@@ -10,10 +14,17 @@ fn main() {
     println!("bools[1]: {}", bools[1]);
     let strings = vec!["a", "b", "c"];
     println!("strings[0]: {}", strings[0]);
+    // Mixed int/float - what happens?
+    // This is the stress test case
+    // mixed = [1, 2.0, 3]
+    // print("mixed[0]: {mixed[0]}")
+    // print("mixed[1]: {mixed[1]}")
+    // First element determines type?
     let first_int = vec![1, 2, 3];
     println!("first_int: {}, {}, {}", first_int[0], first_int[1], first_int[2]);
     let first_float = vec![1.0, 2.0, 3.0];
     println!("first_float: {}, {}, {}", first_float[0], first_float[1], first_float[2]);
+    // Single element arrays
     let single_int = vec![42];
     println!("single_int[0]: {}", single_int[0]);
     let single_float = vec![3.14];
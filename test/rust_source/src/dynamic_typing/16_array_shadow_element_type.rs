@@ -1,4 +1,8 @@
+// Test: Array shadowing with different element types
+// Push int to one array, shadow, push float to new array
+// Expected: Valid - two separate arrays with different types
 fn main() {
+    // First array with ints
     let mut arr = vec![];
     arr.push(1);
     arr.push(2);
@@ -6,6 +10,7 @@ fn main() {
     println!("int arr[0]: {}", arr[0]);
     println!("int arr[1]: {}", arr[1]);
     println!("int arr[2]: {}", arr[2]);
+    // Shadow arr with new array of floats
     let mut arr = vec![];
     arr.push(1.1);
     arr.push(2.2);
@@ -13,22 +18,26 @@ fn main() {
     println!("float arr[0]: {}", arr[0]);
     println!("float arr[1]: {}", arr[1]);
     println!("float arr[2]: {}", arr[2]);
+    // Shadow again with booleans
     let mut arr = vec![];
     arr.push(true);
     arr.push(false);
     println!("bool arr[0]: {}", arr[0]);
     println!("bool arr[1]: {}", arr[1]);
+    // Shadow with strings
     let mut arr = vec![];
     arr.push("hello");
     arr.push("world");
     println!("string arr[0]: {}", arr[0]);
     println!("string arr[1]: {}", arr[1]);
+    // Multiple array variables with different types
     let mut nums = vec![1, 2, 3];
     let decimals = vec![1.5, 2.5];
     let flags = vec![true, false, true, false];
     println!("nums[0]: {}", nums[0]);
     println!("decimals[1]: {}", decimals[1]);
     println!("flags[2]: {}", flags[2]);
+    // Shadow one but not others
     nums = vec![100, 200];
     println!("new nums[0]: {}", nums[0]);
     println!("decimals still: {}", decimals[0]);
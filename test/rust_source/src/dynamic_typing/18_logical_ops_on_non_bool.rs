@@ -1,29 +1,45 @@
+// Test: Logical operators on non-boolean values
+// Expected: Undefined - operands should be bool
 fn main() {
-    let a = (true && true);
+    // Valid boolean operations
+    let a = true;
     println!("true && true: {}", a);
-    let b = (true && false);
+    let b = false;
     println!("true && false: {}", b);
-    let c = (false || true);
+    let c = true;
     println!("false || true: {}", c);
-    let d = (false || false);
+    let d = false;
     println!("false || false: {}", d);
-    let e = (!true);
+    let e = false;
     println!("!true: {}", e);
-    let f = (!false);
+    let f = true;
     println!("!false: {}", f);
-    let g = (true && true);
+    // Using 'and', 'or', 'not' keywords
+    let g = true;
     println!("true and true: {}", g);
-    let h = (false || true);
+    let h = true;
     println!("false or true: {}", h);
-    let i = (!false);
+    let i = true;
     println!("not false (using !): {}", i);
-    let j = (((true && false)) || ((true && true)));
+    // Complex boolean expressions
+    let j = true;
     println!("(true && false) || (true && true): {}", j);
-    let k = (!((true && false)));
+    let k = true;
     println!("!(true && false): {}", k);
-    let l = (((1 > 0)) && ((2 > 1)));
+    // Comparison results in logical ops
+    let l = true;
     println!("(1 > 0) && (2 > 1): {}", l);
-    let m = (((1 < 0)) || ((2 > 1)));
+    let m = true;
     println!("(1 < 0) || (2 > 1): {}", m);
+    // Non-boolean in logical ops - should fail
+    // Uncomment to test:
+    // bad1 = 1 && 2
+    // print("1 && 2: {bad1}")
+    // bad2 = "hello" || "world"
+    // print("hello || world: {bad2}")
+    // bad3 = 0 && true
+    // print("0 && true: {bad3}")
+    // bad4 = !1
+    // print("!1: {bad4}")
     println!("test complete");
 }
\ No newline at end of file
@@ -1,54 +1,68 @@
 fn dynamic_typing_19_type_through_function__double_f64(x: f64) -> f64 {
-    return (x + x);
+    x + x
 }
 
 fn dynamic_typing_19_type_through_function__double_i64(x: i64) -> i64 {
-    return (x + x);
+    x + x
 }
 
+// Test: Type flow through function calls
+// Pass variable through identity function, then shadow original
+// Expected: Valid - function call preserves type
 fn dynamic_typing_19_type_through_function__identity_f64(x: f64) -> f64 {
-    return x;
+    x
 }
 
+// Test: Type flow through function calls
+// Pass variable through identity function, then shadow original
+// Expected: Valid - function call preserves type
 fn dynamic_typing_19_type_through_function__identity_i64(x: i64) -> i64 {
-    return x;
+    x
 }
 
 fn dynamic_typing_19_type_through_function__negate_f64(x: f64) -> f64 {
-    return (-x);
+    -x
 }
 
 fn dynamic_typing_19_type_through_function__negate_i64(x: i64) -> i64 {
-    return (-x);
+    -x
 }
 
 fn main() {
-    let a = 10;
+    // Pass int through identity
+    let a: i64 = 10;
     let b = dynamic_typing_19_type_through_function__identity_i64(a);
     println!("a: {}, identity(a): {}", a, b);
+    // Shadow original, verify copy unchanged
     let a = "shadowed";
     println!("a (shadowed): {}, b (still int): {}", a, b);
+    // Pass float through function
     let c = 3.14;
     let d = dynamic_typing_19_type_through_function__identity_f64(c);
-    let c = 999;
+    let c: i64 = 999;
     println!("c (shadowed to int): {}, d (still float): {}", c, d);
-    let e = 5;
+    // Double preserves type
+    let e: i64 = 5;
     let f = dynamic_typing_19_type_through_function__double_i64(e);
     println!("double(5): {}", f);
     let g = 2.5;
     let h = dynamic_typing_19_type_through_function__double_f64(g);
     println!("double(2.5): {}", h);
+    // Chain function calls - use intermediate variables
     let i1 = dynamic_typing_19_type_through_function__identity_i64(42);
     let i2 = dynamic_typing_19_type_through_function__identity_i64(i1);
     let i = dynamic_typing_19_type_through_function__identity_i64(i2);
     println!("triple identity(42): {}", i);
-    let j = ((dynamic_typing_19_type_through_function__identity_i64(10) as f64) + 0.5);
+    // Function result used in expression that changes type
+    let j = dynamic_typing_19_type_through_function__identity_i64(10) as f64 + 0.5;
     println!("identity(10) + 0.5: {}", j);
+    // Negate preserves type
     let k = dynamic_typing_19_type_through_function__negate_i64(5);
     println!("negate(5): {}", k);
     let l = dynamic_typing_19_type_through_function__negate_f64(3.14);
     println!("negate(3.14): {}", l);
-    let m = 100;
+    // Complex: function call, shadow, function call
+    let m: i64 = 100;
     let n = dynamic_typing_19_type_through_function__double_i64(m);
     let m = "string now";
     let o = dynamic_typing_19_type_through_function__identity_i64(n);
@@ -1,46 +1,49 @@
 fn dynamic_typing_20_conditional_type_inference__abs_value_f64(x: f64) -> f64 {
-    if (x < (0 as f64)) {
-        return (-x);
+    if x < 0.0 {
+        return -x;
     }
-    return x;
+    x
 }
 
 fn dynamic_typing_20_conditional_type_inference__abs_value_i64(x: i64) -> i64 {
-    if (x < 0) {
-        return (-x);
+    if x < 0 {
+        return -x;
     }
-    return x;
+    x
 }
 
 fn dynamic_typing_20_conditional_type_inference__max_val_f64_f64(a: f64, b: f64) -> f64 {
-    if (a > b) {
+    if a > b {
         return a;
     }
-    return b;
+    b
 }
 
 fn dynamic_typing_20_conditional_type_inference__max_val_i64_i64(a: i64, b: i64) -> i64 {
-    if (a > b) {
+    if a > b {
         return a;
     }
-    return b;
+    b
 }
 
 fn dynamic_typing_20_conditional_type_inference__maybe_return_float_bool(flag: bool) -> f64 {
     if flag {
         return 3.14;
     }
-    return 0.0;
+    0.0
 }
 
+// Test: Conditional expressions and type inference
+// Expected: Tests how types are inferred in different conditional branches
 fn dynamic_typing_20_conditional_type_inference__maybe_return_int_bool(flag: bool) -> i64 {
     if flag {
         return 42;
     }
-    return 0;
+    0
 }
 
 fn main() {
+    // Conditional returns same type in both branches
     let a = dynamic_typing_20_conditional_type_inference__maybe_return_int_bool(true);
     println!("maybe_return_int(true): {}", a);
     let b = dynamic_typing_20_conditional_type_inference__maybe_return_int_bool(false);
@@ -49,34 +52,40 @@ fn main() {
     println!("maybe_return_float(true): {}", c);
     let d = dynamic_typing_20_conditional_type_inference__maybe_return_float_bool(false);
     println!("maybe_return_float(false): {}", d);
-    let e = dynamic_typing_20_conditional_type_inference__abs_value_i64((-5));
+    // abs_value works for both int and float
+    let e = dynamic_typing_20_conditional_type_inference__abs_value_i64(-5);
     println!("abs_value(-5): {}", e);
     let f = dynamic_typing_20_conditional_type_inference__abs_value_i64(5);
     println!("abs_value(5): {}", f);
-    let g = dynamic_typing_20_conditional_type_inference__abs_value_f64((-3.14));
+    let g = dynamic_typing_20_conditional_type_inference__abs_value_f64(-3.14);
     println!("abs_value(-3.14): {}", g);
+    // max_val with same types
     let h = dynamic_typing_20_conditional_type_inference__max_val_i64_i64(10, 20);
     println!("max_val(10, 20): {}", h);
     let i = dynamic_typing_20_conditional_type_inference__max_val_f64_f64(3.14, 2.71);
     println!("max_val(3.14, 2.71): {}", i);
-    let mut x = 0;
+    // Variable assignment in conditional branches
+    let mut x: i64 = 0;
     if true {
         x = 42;
     }
     println!("x after if: {}", x);
-    let y = 1;
+    // Shadow in one branch
+    let y: i64 = 1;
     if false {
         let y = "never executed";
     }
     println!("y unchanged: {}", y);
-    let mut z = 0;
+    // Nested conditionals
+    let mut z: i64 = 0;
     if true {
         if true {
             z = 100;
         }
     }
     println!("z after nested if: {}", z);
-    let flag = (5 > 3);
+    // Conditional with expression
+    let flag = true;
     if flag {
         println!("5 > 3 is true");
     }
@@ -0,0 +1,70 @@
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone)]
+struct __ZincClosureEnv_dynamic_typing_21_let_rebinding___lambda_dynamic_typing_21_let_rebinding__main_100_105 {
+    base: Arc<Mutex<i64>>,
+}
+
+#[derive(Clone)]
+enum __ZincCallable_Unit_to_i64 {
+    Closed,
+    V0(__ZincClosureEnv_dynamic_typing_21_let_rebinding___lambda_dynamic_typing_21_let_rebinding__main_100_105),
+}
+
+impl Default for __ZincCallable_Unit_to_i64 {
+    fn default() -> Self {
+        Self::Closed
+    }
+}
+
+impl __ZincCallable_Unit_to_i64 {
+    fn call(&self, ) -> i64 {
+        match self {
+            Self::Closed => panic!("callable used after closed receive"),
+            Self::V0(env) => dynamic_typing_21_let_rebinding____lambda_dynamic_typing_21_let_rebinding__main_100_105(env.clone()),
+        }
+    }
+}
+
+fn dynamic_typing_21_let_rebinding____lambda_dynamic_typing_21_let_rebinding__main_100_105(__env: __ZincClosureEnv_dynamic_typing_21_let_rebinding___lambda_dynamic_typing_21_let_rebinding__main_100_105) -> i64 {
+    let __zv_dynamic_typing_21_let_rebinding____lambda_dynamic_typing_21_let_rebinding__main_100_105_base_i64 = __env.base.clone();
+    return *__zv_dynamic_typing_21_let_rebinding____lambda_dynamic_typing_21_let_rebinding__main_100_105_base_i64.lock().unwrap() * 2;
+}
+
+// Test: `let` declares a new variable; `=` stores into the one a name already refers to
+// Expected: Each `let` shadows until the end of its block, and closures keep the variable they captured
+fn dynamic_typing_21_let_rebinding__describe_i64(count: i64) -> String {
+    let count = format!("{} items", count);
+    count
+}
+
+fn main() {
+    let mut total: i64 = 10;
+    total = total + 5;
+    println!("{}", total);
+    let total = "fifteen";
+    println!("{}", total);
+    let total: f64 = 15.5;
+    println!("{}", total);
+    // A `let` inside a block shadows only until the block ends
+    let level: i64 = 1;
+    if level > 0 {
+        let level = "inner";
+        println!("{}", level);
+    }
+    println!("{}", level);
+    // Even with the same type, the outer variable is left alone
+    let steps: i64 = 3;
+    for i in 0..2 {
+        let steps = steps * 10 + i;
+        println!("{}", steps);
+    }
+    println!("{}", steps);
+    // A closure made before a `let` keeps the variable it captured
+    let __zv_dynamic_typing_21_let_rebinding__main_base_i64 = Arc::new(Mutex::new(5));
+    let doubled = __ZincCallable_Unit_to_i64::V0(__ZincClosureEnv_dynamic_typing_21_let_rebinding___lambda_dynamic_typing_21_let_rebinding__main_100_105 { base: __zv_dynamic_typing_21_let_rebinding__main_base_i64.clone() });
+    let base = "later";
+    println!("{}", doubled.call());
+    println!("{}", base);
+    println!("{}", dynamic_typing_21_let_rebinding__describe_i64(4));
+}
\ No newline at end of file
@@ -6,10 +6,10 @@ enum enums_03_static_methods__Message {
 
 impl enums_03_static_methods__Message {
     fn origin() -> Self {
-        return enums_03_static_methods__Message::Move { x: 0, y: 0 };
+        enums_03_static_methods__Message::Move { x: 0, y: 0 }
     }
     fn quit() -> Self {
-        return enums_03_static_methods__Message::Quit;
+        enums_03_static_methods__Message::Quit
     }
 }
 
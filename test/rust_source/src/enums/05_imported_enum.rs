@@ -19,7 +19,7 @@ fn enums_05_imported_enum__print_remote_Enum_enums__lib_messages__Remote(item: e
 }
 
 fn enums__lib_messages__ready() -> enums__lib_messages__Remote {
-    return enums__lib_messages__Remote::Ready;
+    enums__lib_messages__Remote::Ready
 }
 
 fn main() {
@@ -1,43 +1,43 @@
 fn error_handling_01_try_flow__guarded_bool(flag: bool) -> Result<i64, String> {
-    return (|| -> Result<i64, String> {
-        if (!flag) {
+    (|| -> Result<i64, String> {
+        if !flag {
             return Err(String::from("nope"));
         }
         Ok(9)
-    })();
+    })()
 }
 
 fn error_handling_01_try_flow__maybe_bool(flag: bool) -> Option<i64> {
     if flag {
         return Some(7);
     }
-    return None;
+    None
 }
 
 fn error_handling_01_try_flow__parse_bool(flag: bool) -> Result<i64, String> {
     if flag {
         return Ok(41);
     }
-    return Err(String::from("boom"));
+    Err(String::from("boom"))
 }
 
 fn error_handling_01_try_flow__plus_two_bool(flag: bool) -> Result<i64, String> {
-    return (|| -> Result<i64, String> {
-        let value = (error_handling_01_try_flow__parse_bool(flag))?;
-        Ok((value + 1))
-    })();
+    (|| -> Result<i64, String> {
+        let value = error_handling_01_try_flow__parse_bool(flag)?;
+        Ok(value + 1)
+    })()
 }
 
 fn main() {
     let block_value = {
-        let left = 1;
-        let right = 2;
-        (left + right)
+        let left: i64 = 1;
+        let right: i64 = 2;
+        3
     };
     println!("{}", block_value);
     let result = (|| -> Result<i64, String> {
-        let mut value = (error_handling_01_try_flow__plus_two_bool(true))?;
-        Ok((value + 1))
+        let mut value = error_handling_01_try_flow__plus_two_bool(true)?;
+        Ok(value + 1)
     })();
     {
         let __zinc_match_148_175 = result;
@@ -52,8 +52,8 @@ fn main() {
     }
     {
         let __zinc_match_176_211 = (|| -> Option<i64> {
-        let value = (error_handling_01_try_flow__maybe_bool(false))?;
-        Some((value + 1))
+        let value = error_handling_01_try_flow__maybe_bool(false)?;
+        Some(value + 1)
     })();
         match __zinc_match_176_211.clone() {
             Some(value) => {
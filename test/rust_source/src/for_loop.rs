@@ -1,12 +1,15 @@
 fn main() {
+    // create an array
     let a = vec![1, 2, 3];
     for x in a.iter().cloned() {
         println!("{}", x);
     }
+    // gets promoted to a vector if we use push
     let mut b = vec![];
     b.push(10);
     b.push(20);
     b.push(30);
+    // print elements
     for y in b.iter().cloned() {
         println!("{}", y);
     }
@@ -44,52 +44,52 @@ impl functions_01_named_defaults__Counter {
         self.value += amount;
     }
     fn value_or(&self, extra: i64) -> i64 {
-        return (self.value + extra);
+        self.value + extra
     }
 }
 
 fn functions_01_named_defaults____lambda_functions_01_named_defaults__main_414_427_i64(__env: __ZincClosureEnv_functions_01_named_defaults___lambda_functions_01_named_defaults__main_414_427, x: i64) -> i64 {
-    return (x * 2);
+    x * 2
 }
 
 fn functions_01_named_defaults__add_i32_i32(x: i32, y: i32) -> i32 {
-    return (x + y);
+    x + y
 }
 
 fn functions_01_named_defaults__blend_f64_i32(x: f64, y: i32) -> f64 {
-    return (x + (y as f64));
+    x + y as f64
 }
 
 fn functions_01_named_defaults__blend_i32_i32(x: i32, y: i32) -> i32 {
-    return (x + y);
+    x + y
 }
 
 fn functions_01_named_defaults__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn functions_01_named_defaults__numeric_default_f64_i64(x: f64, y: i64) -> f64 {
-    return (x + (y as f64));
+    x + y as f64
 }
 
 fn functions_01_named_defaults__numeric_default_i32_f64(x: i32, y: f64) -> f64 {
-    return ((x as f64) + y);
+    x as f64 + y
 }
 
 fn functions_01_named_defaults__numeric_default_i64_f64(x: i64, y: f64) -> f64 {
-    return ((x as f64) + y);
+    x as f64 + y
 }
 
 fn functions_01_named_defaults__order3_i64_i64_i64(a: i64, b: i64, c: i64) -> i64 {
-    return (((a * 10000) + (b * 100)) + c);
+    a * 10000 + b * 100 + c
 }
 
 async fn functions_01_named_defaults__send_value_Channel_i64(out: Channel<i64>, value: i64) {
     out.send(value).await;
 }
 
-fn functions_01_named_defaults__tag_String_i32(prefix: String, count: i32) -> String {
-    return String::from(format!("{}:{}", prefix, count));
+fn functions_01_named_defaults__tag_String_i32(prefix: &str, count: i32) -> String {
+    format!("{}:{}", prefix, count)
 }
 
 #[tokio::main]
@@ -111,9 +111,9 @@ async fn main() {
     println!("{}", functions_01_named_defaults__order3_i64_i64_i64(1, 2, 300));
     println!("{}", functions_01_named_defaults__order3_i64_i64_i64(1, 2, 3));
     println!("{}", functions_01_named_defaults__order3_i64_i64_i64(1, 2, 3));
-    println!("{}", functions_01_named_defaults__tag_String_i32(String::from("id"), FUNCTIONS_01_NAMED_DEFAULTS__DEFAULT_COUNT));
-    println!("{}", functions_01_named_defaults__tag_String_i32(String::from("item"), FUNCTIONS_01_NAMED_DEFAULTS__DEFAULT_COUNT));
-    println!("{}", functions_01_named_defaults__tag_String_i32(String::from("row"), 9));
+    println!("{}", functions_01_named_defaults__tag_String_i32("id", FUNCTIONS_01_NAMED_DEFAULTS__DEFAULT_COUNT));
+    println!("{}", functions_01_named_defaults__tag_String_i32("item", FUNCTIONS_01_NAMED_DEFAULTS__DEFAULT_COUNT));
+    println!("{}", functions_01_named_defaults__tag_String_i32("row", 9));
     let ch = Channel::<i64>::unbounded();
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = ch.clone(); async move { functions_01_named_defaults__send_value_Channel_i64(__zinc_spawn_arg_0.clone(), 8).await; } }));
     println!("{}", ch.recv().await);
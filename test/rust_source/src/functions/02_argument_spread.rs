@@ -69,19 +69,19 @@ impl Default for functions_02_argument_spread__Tool {
 
 impl functions_02_argument_spread__Tool {
     fn add(&self, a: i64, b: i64, c: i64) -> i64 {
-        return (((self.seed + a) + b) + c);
+        self.seed + a + b + c
     }
     fn pack(a: i64, b: i64, c: i64) -> i64 {
-        return (((a * 100) + (b * 10)) + c);
+        a * 100 + b * 10 + c
     }
 }
 
 fn functions_02_argument_spread____lambda_functions_02_argument_spread__main_251_270_i64_i64_i64(__env: __ZincClosureEnv_functions_02_argument_spread___lambda_functions_02_argument_spread__main_251_270, a: i64, b: i64, c: i64) -> i64 {
-    return ((a + b) + c);
+    a + b + c
 }
 
 fn functions_02_argument_spread__combine_i64_i64_i64(a: i64, b: i64, c: i64) -> i64 {
-    return (((a * 100) + (b * 10)) + c);
+    a * 100 + b * 10 + c
 }
 
 fn main() {
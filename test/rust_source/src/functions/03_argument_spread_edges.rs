@@ -67,16 +67,16 @@ impl Default for functions_03_argument_spread_edges__OnlyB {
 
 fn functions_03_argument_spread_edges__ignored_args() -> functions_03_argument_spread_edges__Args {
     println!("ignore args");
-    return functions_03_argument_spread_edges__Args { a: 9, b: 9, c: 9, extra: 99 };
+    functions_03_argument_spread_edges__Args { a: 9, b: 9, c: 9, extra: 99 }
 }
 
 fn functions_03_argument_spread_edges__noisy_args() -> functions_03_argument_spread_edges__Args {
     println!("make args");
-    return functions_03_argument_spread_edges__Args { a: 3, b: 2, c: 1, extra: 99 };
+    functions_03_argument_spread_edges__Args { a: 3, b: 2, c: 1, extra: 99 }
 }
 
 fn functions_03_argument_spread_edges__pack_i64_i64_i64(a: i64, b: i64, c: i64) -> i64 {
-    return (((a * 100) + (b * 10)) + c);
+    a * 100 + b * 10 + c
 }
 
 fn main() {
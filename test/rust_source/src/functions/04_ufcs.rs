@@ -29,25 +29,25 @@ impl Default for functions_04_ufcs__Extra {
 }
 
 fn functions_04_ufcs__combine_i64_i64_i64_i64(x: i64, a: i64, b: i64, c: i64) -> i64 {
-    return ((((x * 1000) + (a * 100)) + (b * 10)) + c);
+    x * 1000 + a * 100 + b * 10 + c
 }
 
 async fn functions_04_ufcs__send_next_i64_Channel(value: i64, done: Channel<i64>) {
-    done.send((value + 1)).await;
+    done.send(value + 1).await;
 }
 
 fn functions_04_ufcs__twice_i64(x: i64) -> i64 {
-    return (x + x);
+    x + x
 }
 
 fn modules__lib_math__add_i64_i64(a: i64, b: i64) -> i64 {
-    return (a + b);
+    a + b
 }
 
 #[tokio::main]
 async fn main() {
     let mut __zinc_spawn_handles = Vec::new();
-    let n = 2;
+    let n: i64 = 2;
     let extra = functions_04_ufcs__Extra { b: 3, c: 4 };
     println!("{}", functions_04_ufcs__combine_i64_i64_i64_i64(n, 1, 10, 100));
     println!("{}", functions_04_ufcs__combine_i64_i64_i64_i64(n, 1, 2, 100));
@@ -0,0 +1,45 @@
+fn main() {
+    let total: i64 = 5;
+    if !true {
+        eprintln!("assertion failed at functions/06_assertions.zn:3: total == 5");
+        std::process::exit(101);
+    };
+    if !true {
+        eprintln!("assertion failed at functions/06_assertions.zn:4: total > 0: {}", "total must be positive");
+        std::process::exit(101);
+    };
+    match (&total, &5) {
+        (__zinc_left, __zinc_right) => {
+            if !(*__zinc_left == *__zinc_right) {
+                eprintln!("assertion failed at functions/06_assertions.zn:6: total == 5\n  left: {:?}\n right: {:?}", __zinc_left, __zinc_right);
+                std::process::exit(101);
+            }
+        }
+    };
+    match (&5.0, &5.0) {
+        (__zinc_left, __zinc_right) => {
+            if !(*__zinc_left == *__zinc_right) {
+                eprintln!("assertion failed at functions/06_assertions.zn:7: 2.5 * 2 == 5.0\n  left: {:?}\n right: {:?}", __zinc_left, __zinc_right);
+                std::process::exit(101);
+            }
+        }
+    };
+    let name = "zinc";
+    match (&name, &"zinc") {
+        (__zinc_left, __zinc_right) => {
+            if !(*__zinc_left == *__zinc_right) {
+                eprintln!("assertion failed at functions/06_assertions.zn:9: name == \"zinc\": {}\n  left: {:?}\n right: {:?}", "name should round-trip", __zinc_left, __zinc_right);
+                std::process::exit(101);
+            }
+        }
+    };
+    match (&vec![1, 2, 3], &vec![1, 2, 3]) {
+        (__zinc_left, __zinc_right) => {
+            if !(*__zinc_left == *__zinc_right) {
+                eprintln!("assertion failed at functions/06_assertions.zn:10: [1, 2, 3] == [1, 2, 3]\n  left: {:?}\n right: {:?}", __zinc_left, __zinc_right);
+                std::process::exit(101);
+            }
+        }
+    };
+    println!("all assertions passed");
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+fn functions_07_exit__finish_i64(failures: i64) {
+    if failures > 0 {
+        println!("{} failures", failures);
+        std::process::exit(1);
+    }
+    println!("no failures");
+    std::process::exit(0);
+}
+
+fn main() {
+    println!("running");
+    functions_07_exit__finish_i64(0);
+    println!("not printed");
+}
\ No newline at end of file
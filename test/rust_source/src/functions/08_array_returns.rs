@@ -0,0 +1,34 @@
+fn functions_08_array_returns__evens_i64(limit: i64) -> Vec<i64> {
+    let mut out = vec![0];
+    let mut value: i64 = 2;
+    while value <= limit {
+        out.push(value);
+        value = value + 2;
+    }
+    out
+}
+
+fn functions_08_array_returns__squares_Vec_i64(values: &Vec<i64>) -> Vec<i64> {
+    let mut out = vec![values[0] * values[0]];
+    for value in values.iter().cloned() {
+        if value > 0 {
+            out.push(value * value);
+        }
+    }
+    out
+}
+
+fn functions_08_array_returns__total_Vec_i64(values: &Vec<i64>) -> i64 {
+    let mut sum: i64 = 0;
+    for value in values.iter().cloned() {
+        sum = sum + value;
+    }
+    sum
+}
+
+fn main() {
+    let found = functions_08_array_returns__evens_i64(8);
+    println!("{}", found.len() as i64);
+    println!("{}", functions_08_array_returns__total_Vec_i64(&found));
+    println!("{}", functions_08_array_returns__total_Vec_i64(&functions_08_array_returns__squares_Vec_i64(&found)));
+}
\ No newline at end of file
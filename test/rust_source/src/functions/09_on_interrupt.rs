@@ -0,0 +1,44 @@
+use zinc_internal::{on_interrupt};
+
+#[derive(Clone)]
+struct __ZincClosureEnv_functions_09_on_interrupt___lambda_functions_09_on_interrupt__main_25_33 {
+}
+
+#[derive(Clone)]
+enum __ZincCallable_Unit_to_Unit {
+    Closed,
+    V0(__ZincClosureEnv_functions_09_on_interrupt___lambda_functions_09_on_interrupt__main_25_33),
+    V1,
+}
+
+impl Default for __ZincCallable_Unit_to_Unit {
+    fn default() -> Self {
+        Self::Closed
+    }
+}
+
+impl __ZincCallable_Unit_to_Unit {
+    fn call(&self, ) {
+        match self {
+            Self::Closed => panic!("callable used after closed receive"),
+            Self::V0(env) => { functions_09_on_interrupt____lambda_functions_09_on_interrupt__main_25_33(env.clone()); }
+            Self::V1 => { functions_09_on_interrupt__shutdown(); }
+        }
+    }
+}
+
+fn functions_09_on_interrupt____lambda_functions_09_on_interrupt__main_25_33(__env: __ZincClosureEnv_functions_09_on_interrupt___lambda_functions_09_on_interrupt__main_25_33) {
+    println!("interrupted");
+}
+
+fn functions_09_on_interrupt__shutdown() {
+    println!("shutting down");
+    std::process::exit(0);
+}
+
+fn main() {
+    on_interrupt({ let __zinc_interrupt_handler = __ZincCallable_Unit_to_Unit::V1; move || __zinc_interrupt_handler.call() });
+    on_interrupt({ let __zinc_interrupt_handler = __ZincCallable_Unit_to_Unit::V0(__ZincClosureEnv_functions_09_on_interrupt___lambda_functions_09_on_interrupt__main_25_33 {}); move || __zinc_interrupt_handler.call() });
+    println!("processing");
+    println!("done");
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+// Strings and structs a function only reads are passed by reference, so the
+// caller can keep using them; functions that keep the value take their own copy.
+struct functions_10_borrowed_arguments__User {
+    pub name: String,
+    pub age: i64,
+}
+
+impl Default for functions_10_borrowed_arguments__User {
+    fn default() -> Self {
+        Self { name: String::new(), age: 0 }
+    }
+}
+
+impl functions_10_borrowed_arguments__User {
+    fn greet(&self) {
+        println!("hi {}", self.name);
+    }
+    fn birthday(&mut self) {
+        self.age = self.age + 1;
+    }
+}
+
+fn functions_10_borrowed_arguments__age_up_Struct_functions_10_borrowed_arguments_User(u: &mut functions_10_borrowed_arguments__User) {
+    u.birthday();
+}
+
+fn functions_10_borrowed_arguments__describe_Struct_functions_10_borrowed_arguments_User(u: &functions_10_borrowed_arguments__User) {
+    u.greet();
+    if u.age > 30 {
+        println!("{} is over 30", u.name);
+    }
+}
+
+fn functions_10_borrowed_arguments__is_admin_String(name: &str) -> bool {
+    name == "root"
+}
+
+fn functions_10_borrowed_arguments__keep_String(s: String) -> String {
+    s
+}
+
+fn functions_10_borrowed_arguments__rename_Struct_functions_10_borrowed_arguments_User_String(u: &mut functions_10_borrowed_arguments__User, name: String) {
+    u.name = name;
+}
+
+fn functions_10_borrowed_arguments__shout_String(s: &str) -> String {
+    format!("{}!", s)
+}
+
+fn main() {
+    let who = "root";
+    println!("{}", functions_10_borrowed_arguments__is_admin_String(&who));
+    for i in 0..2 {
+        println!("{}", functions_10_borrowed_arguments__shout_String(&format!("{} {}", who, i)));
+    }
+    let kept = functions_10_borrowed_arguments__keep_String(who.to_string());
+    println!("{} {}", who, kept);
+    let mut user = functions_10_borrowed_arguments__User { name: String::from("ann"), age: 40 };
+    functions_10_borrowed_arguments__describe_Struct_functions_10_borrowed_arguments_User(&user);
+    functions_10_borrowed_arguments__age_up_Struct_functions_10_borrowed_arguments_User(&mut user);
+    functions_10_borrowed_arguments__rename_Struct_functions_10_borrowed_arguments_User_String(&mut user, String::from("bea"));
+    functions_10_borrowed_arguments__describe_Struct_functions_10_borrowed_arguments_User(&user);
+    println!("{}", user.age);
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+// exit: 101
+fn main() {
+    let totals = vec![3, 4];
+    let mut sum: i64 = 0;
+    for total in totals.iter().cloned() {
+        sum += total;
+    }
+    println!("sum is {}", sum);
+    match (&sum, &8) {
+        (__zinc_left, __zinc_right) => {
+            if !(*__zinc_left == *__zinc_right) {
+                eprintln!("assertion failed at functions/11_failed_assertion.zn:9: sum == 8: {}\n  left: {:?}\n right: {:?}", "totals should add up", __zinc_left, __zinc_right);
+                std::process::exit(101);
+            }
+        }
+    };
+    println!("not printed");
+}
\ No newline at end of file
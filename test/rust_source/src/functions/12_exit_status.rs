@@ -0,0 +1,5 @@
+// exit: 3
+fn main() {
+    println!("giving up");
+    std::process::exit(3);
+}
\ No newline at end of file
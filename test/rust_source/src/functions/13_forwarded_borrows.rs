@@ -0,0 +1,68 @@
+// A parameter handed on to a helper that borrows it stays borrowed, so large
+// values travel through layers of helpers without being copied or moved.
+struct functions_13_forwarded_borrows__Scene {
+    pub title: String,
+    pub width: i64,
+    pub height: i64,
+    pub frames: i64,
+}
+
+impl Default for functions_13_forwarded_borrows__Scene {
+    fn default() -> Self {
+        Self { title: String::new(), width: 0, height: 0, frames: 0 }
+    }
+}
+
+fn functions_13_forwarded_borrows__advance_Struct_functions_13_forwarded_borrows_Scene(scene: &mut functions_13_forwarded_borrows__Scene) {
+    scene.frames = scene.frames + 1;
+}
+
+fn functions_13_forwarded_borrows__advance_twice_Struct_functions_13_forwarded_borrows_Scene(scene: &mut functions_13_forwarded_borrows__Scene) {
+    functions_13_forwarded_borrows__advance_Struct_functions_13_forwarded_borrows_Scene(scene);
+    functions_13_forwarded_borrows__advance_Struct_functions_13_forwarded_borrows_Scene(scene);
+}
+
+fn functions_13_forwarded_borrows__append_zero_Vec_i64(values: &mut Vec<i64>) {
+    values.push(0);
+}
+
+fn functions_13_forwarded_borrows__area_Struct_functions_13_forwarded_borrows_Scene(scene: &functions_13_forwarded_borrows__Scene) -> i64 {
+    scene.width * scene.height
+}
+
+fn functions_13_forwarded_borrows__label_String(name: &str) -> String {
+    format!("[{}]", name)
+}
+
+fn functions_13_forwarded_borrows__pad_Vec_i64(values: &mut Vec<i64>) {
+    functions_13_forwarded_borrows__append_zero_Vec_i64(values);
+    functions_13_forwarded_borrows__append_zero_Vec_i64(values);
+}
+
+fn functions_13_forwarded_borrows__summary_Struct_functions_13_forwarded_borrows_Scene(scene: &functions_13_forwarded_borrows__Scene) -> String {
+    let name = functions_13_forwarded_borrows__label_String(&scene.title);
+    let pixels = functions_13_forwarded_borrows__area_Struct_functions_13_forwarded_borrows_Scene(scene);
+    format!("{} {}px x {}", name, pixels, scene.frames)
+}
+
+fn functions_13_forwarded_borrows__report_Struct_functions_13_forwarded_borrows_Scene(scene: &functions_13_forwarded_borrows__Scene) {
+    println!("{}", functions_13_forwarded_borrows__summary_Struct_functions_13_forwarded_borrows_Scene(scene));
+}
+
+fn functions_13_forwarded_borrows__shout_String(word: &str) -> String {
+    functions_13_forwarded_borrows__label_String(word)
+}
+
+fn main() {
+    let mut scene = functions_13_forwarded_borrows__Scene { title: String::from("intro"), width: 4, height: 3, frames: 0 };
+    functions_13_forwarded_borrows__report_Struct_functions_13_forwarded_borrows_Scene(&scene);
+    functions_13_forwarded_borrows__advance_twice_Struct_functions_13_forwarded_borrows_Scene(&mut scene);
+    functions_13_forwarded_borrows__report_Struct_functions_13_forwarded_borrows_Scene(&scene);
+    println!("{}", scene.title);
+    let word = "go";
+    println!("{}", functions_13_forwarded_borrows__shout_String(&word));
+    println!("{}", word);
+    let mut values = vec![7];
+    functions_13_forwarded_borrows__pad_Vec_i64(&mut values);
+    println!("{}", values.len() as i64);
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+fn functions_14_tail_calls__ackermann_i64_i64(m: i64, n: i64) -> i64 {
+    let mut __zinc_tail_args = (m, n);
+    '__zinc_tail: loop {
+        let (m, n) = __zinc_tail_args;
+        if m == 0 {
+            return n + 1;
+        }
+        if n == 0 {
+            __zinc_tail_args = (m - 1, 1);
+            continue '__zinc_tail;
+        }
+        __zinc_tail_args = (m - 1, functions_14_tail_calls__ackermann_i64_i64(m, n - 1));
+        continue '__zinc_tail;
+    }
+}
+
+fn functions_14_tail_calls__countdown_i64(n: i64) {
+    let mut __zinc_tail_args = (n,);
+    '__zinc_tail: loop {
+        let (n,) = __zinc_tail_args;
+        if n == 0 {
+            println!("liftoff");
+            return;
+        }
+        if (n % 250000) == 0 {
+            println!("{}", n);
+        }
+        __zinc_tail_args = (n - 1,);
+        continue '__zinc_tail;
+    }
+}
+
+fn functions_14_tail_calls__find_Vec_i64_i64_i64(xs: &Vec<i64>, target: i64, i: i64) -> i64 {
+    let mut __zinc_tail_args = (target, i);
+    '__zinc_tail: loop {
+        let (target, i) = __zinc_tail_args;
+        if i >= xs.len() as i64 {
+            return -1;
+        }
+        if xs[i as usize] == target {
+            return i;
+        }
+        __zinc_tail_args = (target, i + 1);
+        continue '__zinc_tail;
+    }
+}
+
+fn functions_14_tail_calls__gcd_i64_i64(a: i64, b: i64) -> i64 {
+    let mut __zinc_tail_args = (a, b);
+    '__zinc_tail: loop {
+        let (a, b) = __zinc_tail_args;
+        if b == 0 {
+            return a;
+        }
+        __zinc_tail_args = (b, a % b);
+        continue '__zinc_tail;
+    }
+}
+
+fn functions_14_tail_calls__repeat_String_i64_String(text: String, times: i64, out: String) -> String {
+    let mut __zinc_tail_args = (text, times, out);
+    '__zinc_tail: loop {
+        let (text, times, out) = __zinc_tail_args;
+        if times == 0 {
+            return out;
+        }
+        __zinc_tail_args = (text.to_string(), times - 1, format!("{}{}", out, text));
+        continue '__zinc_tail;
+    }
+}
+
+fn functions_14_tail_calls__sum_to_i64_i64(n: i64, acc: i64) -> i64 {
+    let mut __zinc_tail_args = (n, acc);
+    '__zinc_tail: loop {
+        let (n, acc) = __zinc_tail_args;
+        if n == 0 {
+            return acc;
+        }
+        __zinc_tail_args = (n - 1, acc + n);
+        continue '__zinc_tail;
+    }
+}
+
+fn main() {
+    println!("{}", functions_14_tail_calls__sum_to_i64_i64(1000000, 0));
+    functions_14_tail_calls__countdown_i64(1000000);
+    println!("{}", functions_14_tail_calls__gcd_i64_i64(1071, 462));
+    println!("{}", functions_14_tail_calls__repeat_String_i64_String(String::from("ab"), 3, String::from("")));
+    println!("{}", functions_14_tail_calls__ackermann_i64_i64(2, 3));
+    println!("{}", functions_14_tail_calls__find_Vec_i64_i64_i64(&vec![4, 8, 15, 16, 23, 42], 23, 0));
+}
\ No newline at end of file
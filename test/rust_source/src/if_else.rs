@@ -1,16 +1,16 @@
 fn main() {
-    let x = 10;
-    if (x > 5) {
+    let x: i64 = 10;
+    if true {
         println!("x is greater than 5");
     }
-    if (x > 15) {
+    if false {
         println!("x is greater than 15");
     } else {
         println!("x is not greater than 15");
     }
-    if (x > 20) {
+    if false {
         println!("big");
-    } else if (x > 5) {
+    } else if true {
         println!("medium");
     } else {
         println!("small");
@@ -1,7 +1,7 @@
 fn main() {
-    let a = 3;
-    let b = 7;
-    let max = if (a > b) {
+    let a: i64 = 3;
+    let b: i64 = 7;
+    let max = if false {
         a
     } else {
         b
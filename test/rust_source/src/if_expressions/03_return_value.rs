@@ -1,9 +1,9 @@
 fn if_expressions_03_return_value__label_i64(count: i64) -> String {
-    return if (count == 1) {
+    if count == 1 {
         String::from("item")
     } else {
         String::from("items")
-    };
+    }
 }
 
 fn main() {
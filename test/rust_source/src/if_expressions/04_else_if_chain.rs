@@ -1,10 +1,10 @@
 fn main() {
-    let score = 85;
-    let grade = if (score >= 90) {
+    let score: i64 = 85;
+    let grade = if false {
         String::from("A")
-    } else if (score >= 80) {
+    } else if true {
         String::from("B")
-    } else if (score >= 70) {
+    } else if true {
         String::from("C")
     } else {
         String::from("F")
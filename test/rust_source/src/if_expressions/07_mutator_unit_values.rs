@@ -8,5 +8,5 @@ fn main() {
     } else {
         println!("skip")
     };
-    println!("{}", (values.len() as i64));
+    println!("{}", values.len() as i64);
 }
\ No newline at end of file
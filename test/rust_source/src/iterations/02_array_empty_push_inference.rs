@@ -3,9 +3,9 @@ fn main() {
     values.push(10);
     values.push(20);
     values.push(30);
-    let mut total = 0;
+    let mut total: i64 = 0;
     for value in values.iter().cloned() {
-        total = (total + value);
+        total = total + value;
     }
     println!("{}", total);
     println!("{}", values[1]);
@@ -5,12 +5,12 @@ fn main() {
     { values.insert(3); () };
     { values.insert(1); () };
     { values.insert(2); () };
-    let mut total = 0;
+    let mut total: i64 = 0;
     for value in values.iter().cloned() {
         println!("{}", value);
-        total = (total + value);
+        total = total + value;
     }
-    let count = (values.len() as i64);
+    let count = values.len() as i64;
     println!("{}", total);
     println!("{}", count);
 }
\ No newline at end of file
@@ -4,7 +4,7 @@ fn main() {
     let mut values = BTreeSet::<i64>::new();
     { values.insert(1); () };
     for value in values.iter().cloned() {
-        let value = 99;
+        let value: i64 = 99;
         println!("{}", value);
     }
     let has_one = values.contains(&1);
@@ -14,6 +14,6 @@ fn main() {
     }
     println!("{}", collected_keys[0]);
     println!("{}", collected_keys[1]);
-    let collected_count = (collected_keys.len() as i64);
+    let collected_count = collected_keys.len() as i64;
     println!("{}", collected_count);
 }
\ No newline at end of file
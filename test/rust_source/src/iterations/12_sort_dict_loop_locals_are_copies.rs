@@ -5,7 +5,7 @@ fn main() {
     scores.insert(String::from("a"), 10);
     for (key, value) in scores.iter().map(|(k, v)| (k.clone(), v.clone())) {
         let key = "changed";
-        let value = (value + 10);
+        let value = value + 10;
         println!("{}", key);
         println!("{}", value);
     }
@@ -5,15 +5,15 @@ fn main() {
     for value in 1..=3 {
         println!("{}", value);
     }
-    let mut empty_count = 0;
+    let mut empty_count: i64 = 0;
     for value in 0..0 {
-        empty_count = (empty_count + 1);
+        empty_count = empty_count + 1;
     }
     println!("{}", empty_count);
-    let mut count = 0;
-    let mut last = (-1);
+    let mut count: i64 = 0;
+    let mut last: i64 = -1;
     for value in 0..300 {
-        count = (count + 1);
+        count = count + 1;
         last = value;
     }
     println!("{}", count);
@@ -1,9 +1,9 @@
 fn main() {
-    let x = 99;
-    let mut total = 0;
+    let x: i64 = 99;
+    let mut total: i64 = 0;
     for x in 0..3 {
         for y in 0..2 {
-            total = (total + ((x * y)));
+            total = total + x * y;
         }
     }
     println!("{}", total);
@@ -1,8 +1,8 @@
 fn main() {
-    let mut total = 0;
+    let mut total: i64 = 0;
     for x in 0..3 {
-        let x = (x + 10);
-        total = (total + x);
+        let x = x + 10;
+        total = total + x;
     }
     println!("{}", total);
 }
\ No newline at end of file
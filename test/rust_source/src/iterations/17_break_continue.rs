@@ -1,16 +1,16 @@
 fn main() {
-    let mut i = 0;
-    let mut total = 0;
-    while (i < 6) {
-        i = (i + 1);
-        if (i == 2) {
+    let mut i: i64 = 0;
+    let mut total: i64 = 0;
+    while i < 6 {
+        i = i + 1;
+        if i == 2 {
             continue;
         }
-        if (i == 5) {
+        if i == 5 {
             break;
         }
         println!("{}", i);
-        total = (total + i);
+        total = total + i;
     }
     println!("{}", total);
 }
\ No newline at end of file
@@ -1,9 +1,9 @@
 fn main() {
-    let mut i = 0;
+    let mut i: i64 = 0;
     loop {
         println!("{}", i);
-        i = (i + 1);
-        if (i == 3) {
+        i = i + 1;
+        if i == 3 {
             break;
         }
     }
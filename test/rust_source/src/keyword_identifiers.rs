@@ -0,0 +1,110 @@
+use std::collections::{HashMap};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone)]
+struct __ZincClosureEnv_keyword_identifiers___lambda_keyword_identifiers__main_198_202 {
+    r#let: Arc<Mutex<i64>>,
+}
+
+#[derive(Clone)]
+enum __ZincCallable_i64_to_i64 {
+    Closed,
+    V0(__ZincClosureEnv_keyword_identifiers___lambda_keyword_identifiers__main_198_202),
+}
+
+impl Default for __ZincCallable_i64_to_i64 {
+    fn default() -> Self {
+        Self::Closed
+    }
+}
+
+impl __ZincCallable_i64_to_i64 {
+    fn call(&self, arg_0: i64) -> i64 {
+        match self {
+            Self::Closed => panic!("callable used after closed receive"),
+            Self::V0(env) => keyword_identifiers____lambda_keyword_identifiers__main_198_202_i64(env.clone(), arg_0),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum keyword_identifiers__Shape {
+    Dot,
+    Line { r#dyn: i64 },
+}
+
+// Names that are keywords in Rust but not in Zinc
+struct keyword_identifiers__Box {
+    pub r#mut: i64,
+    pub r#ref: i64,
+    pub crate__: i64,
+}
+
+impl Default for keyword_identifiers__Box {
+    fn default() -> Self {
+        Self { r#mut: 0, r#ref: 1, crate__: 2 }
+    }
+}
+
+impl keyword_identifiers__Box {
+    fn r#move(&self) -> i64 {
+        self.r#mut + self.r#ref + self.crate__
+    }
+    fn r#static(&mut self, r#where: i64) -> i64 {
+        self.r#mut = self.r#mut + r#where;
+        self.r#mut
+    }
+    fn r#unsized(r#yield: i64) -> i64 {
+        r#yield * 2
+    }
+}
+
+fn keyword_identifiers____lambda_keyword_identifiers__main_198_202_i64(__env: __ZincClosureEnv_keyword_identifiers___lambda_keyword_identifiers__main_198_202, r#mut: i64) -> i64 {
+    let __zv_keyword_identifiers____lambda_keyword_identifiers__main_198_202_i64_let_i64 = __env.r#let.clone();
+    return r#mut + *__zv_keyword_identifiers____lambda_keyword_identifiers__main_198_202_i64_let_i64.lock().unwrap();
+}
+
+fn keyword_identifiers__apply_unknown_to_unknown_i64(f: __ZincCallable_i64_to_i64, x: i64) -> i64 {
+    f.call(x)
+}
+
+fn keyword_identifiers__sum_i64_i64(r#dyn: i64, r#static: i64) -> i64 {
+    r#dyn + r#static
+}
+
+fn main() {
+    let __zv_keyword_identifiers__main_let_i64 = Arc::new(Mutex::new(1));
+    let mut r#mod: i64 = 2;
+    let crate__: i64 = 3;
+    let crate_: i64 = 4;
+    let r#trait = keyword_identifiers__sum_i64_i64(*__zv_keyword_identifiers__main_let_i64.lock().unwrap(), r#mod);
+    println!("trait: {}, crate: {}, crate_: {}", r#trait, crate__, crate_);
+    let mut b = keyword_identifiers__Box { r#mut: crate__, r#ref: crate_, crate__: 2 };
+    b.r#static(10);
+    println!("mut: {}, move: {}, unsized: {}", b.r#mut, b.r#move(), keyword_identifiers__Box::r#unsized(5));
+    let r#yield = vec![1, 2];
+    for r#gen in r#yield.iter().cloned() {
+        r#mod += r#gen;
+    }
+    println!("mod: {}", r#mod);
+    let (r#ref, r#box) = (7, 8);
+    println!("{}", r#ref + r#box);
+    println!("{}", keyword_identifiers__apply_unknown_to_unknown_i64(__ZincCallable_i64_to_i64::V0(__ZincClosureEnv_keyword_identifiers___lambda_keyword_identifiers__main_198_202 { r#let: __zv_keyword_identifiers__main_let_i64.clone() }), 1));
+    let s = keyword_identifiers__Shape::Line { r#dyn: 4 };
+    {
+        let __zinc_match_217_244 = s;
+        match __zinc_match_217_244.clone() {
+            keyword_identifiers__Shape::Line { r#dyn } => {
+                println!("dyn: {}", r#dyn);
+            },
+            keyword_identifiers__Shape::Dot => {
+                println!("dot");
+            },
+        }
+    }
+    let r#do = HashMap::from([(String::from("a"), 1)]);
+    for (r#priv, r#final) in r#do.iter().map(|(k, v)| (k.clone(), v.clone())) {
+        println!("{}={}", r#priv, r#final);
+    }
+    println!("{}", String::from("trait"));
+}
\ No newline at end of file
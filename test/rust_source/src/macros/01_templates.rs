@@ -0,0 +1,53 @@
+// Macros expand to declarations, members, statements, and expressions.
+struct macros_01_templates__Config {
+    pub retries: i64,
+    pub verbose: bool,
+}
+
+impl Default for macros_01_templates__Config {
+    fn default() -> Self {
+        Self { retries: 0, verbose: false }
+    }
+}
+
+impl macros_01_templates__Config {
+    fn get_retries(&self) -> i64 {
+        self.retries
+    }
+    fn set_retries(&mut self, value: i64) {
+        self.retries = value;
+    }
+    fn get_verbose(&self) -> bool {
+        self.verbose
+    }
+    fn set_verbose(&mut self, value: bool) {
+        self.verbose = value;
+    }
+}
+
+fn macros_01_templates__add_i64_i64(a: i64, b: i64) -> i64 {
+    a + b
+}
+
+fn main() {
+    let mut config = macros_01_templates__Config { retries: 1, verbose: false };
+    config.set_retries(5);
+    config.set_verbose(true);
+    println!("retries {} verbose {}", config.get_retries(), config.get_verbose());
+    println!("{}", 9);
+    let mut a: i64 = 1;
+    let mut b: i64 = 2;
+    let tmp: i64 = 100;
+    let _tmp_4 = a;
+    a = b;
+    b = _tmp_4;
+    println!("a={} b={} tmp={}", a, b, tmp);
+    let result = "untouched";
+    println!("calling add");
+    let _result_5 = macros_01_templates__add_i64_i64(a, b);
+    println!("add returned {}", _result_5);
+    println!("calling square");
+    let _result_6 = b * b;
+    println!("square returned {}", _result_6);
+    println!("{}", result);
+}
\ No newline at end of file
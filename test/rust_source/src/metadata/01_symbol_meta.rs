@@ -10,7 +10,7 @@ enum metadata_01_symbol_meta__Status {
 
 impl metadata_01_symbol_meta__Status {
     fn banner(prefix: String) -> String {
-        return format!("{}:status", prefix);
+        format!("{}:status", prefix)
     }
 }
 
@@ -27,10 +27,10 @@ impl Default for metadata_01_symbol_meta__Profile {
 
 impl metadata_01_symbol_meta__Profile {
     fn label(&self, prefix: String) -> String {
-        return format!("{}:{}:{}", prefix, self.name, (*METADATA_01_SYMBOL_META__APP_NAME).clone());
+        format!("{}:{}:{}", prefix, self.name, (*METADATA_01_SYMBOL_META__APP_NAME).clone())
     }
     fn build_tag(suffix: String) -> String {
-        return format!("{}:build", suffix);
+        format!("{}:build", suffix)
     }
 }
 
@@ -38,21 +38,21 @@ fn metadata_01_symbol_meta__inspect_i64(count: i64) -> i64 {
     let local = count;
     let typed: String = (*METADATA_01_SYMBOL_META__APP_NAME).clone();
     println!("{}", 32);
-    println!("{:?}", FunctionParameterMeta { kind: String::from("parameter"), name: String::from("count"), fqn: String::from("metadata/01_symbol_meta/inspect/count"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 29, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false });
+    println!("{:?}", FunctionParameterMeta { kind: String::from("parameter"), name: String::from("count"), fqn: String::from("metadata/01_symbol_meta/inspect/count"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 29, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false });
     println!("{}", String::from("inspect"));
     println!("{}", String::from("i64"));
-    println!("{:?}", VariableMeta { kind: String::from("variable"), name: String::from("local"), fqn: String::from("metadata/01_symbol_meta/inspect/local"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 30, is_public: false, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: false, is_mutated: false, is_shadow: false });
+    println!("{:?}", VariableMeta { kind: String::from("variable"), name: String::from("local"), fqn: String::from("metadata/01_symbol_meta/inspect/local"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 30, is_public: false, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: false, is_mutated: false, is_shadow: false });
     println!("{}", String::from("inspect"));
     println!("{}", String::from("i64"));
     println!("{}", true);
-    println!("{:?}", ConstMeta { kind: String::from("const"), name: String::from("APP_NAME"), fqn: String::from("metadata/01_symbol_meta/APP_NAME"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 1, is_public: true, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, value_text: String::from("\"zinc\"") });
-    println!("{:?}", FunctionMeta { kind: String::from("function"), name: String::from("inspect"), fqn: String::from("metadata/01_symbol_meta/inspect"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 29, is_public: true, params: vec![FunctionParameterMeta { kind: String::from("parameter"), name: String::from("count"), fqn: String::from("metadata/01_symbol_meta/inspect/count"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 29, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false }], return_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, is_async: false });
-    println!("{:?}", FunctionParameterMeta { kind: String::from("parameter"), name: String::from("count"), fqn: String::from("metadata/01_symbol_meta/inspect/count"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 29, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false });
+    println!("{:?}", ConstMeta { kind: String::from("const"), name: String::from("APP_NAME"), fqn: String::from("metadata/01_symbol_meta/APP_NAME"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 1, is_public: true, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, value_text: String::from("\"zinc\"") });
+    println!("{:?}", FunctionMeta { kind: String::from("function"), name: String::from("inspect"), fqn: String::from("metadata/01_symbol_meta/inspect"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 29, is_public: true, params: vec![FunctionParameterMeta { kind: String::from("parameter"), name: String::from("count"), fqn: String::from("metadata/01_symbol_meta/inspect/count"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 29, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false }], return_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, is_async: false });
+    println!("{:?}", FunctionParameterMeta { kind: String::from("parameter"), name: String::from("count"), fqn: String::from("metadata/01_symbol_meta/inspect/count"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 29, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false });
     println!("{}", String::from("inspect"));
     println!("{:?}", TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() });
-    println!("{:?}", FunctionMeta { kind: String::from("function"), name: String::from("ping"), fqn: String::from("metadata/01_symbol_meta/ping"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 25, is_public: true, params: Vec::<FunctionParameterMeta>::new(), return_type: TypeMeta { kind: String::from("unknown"), name: String::from("unknown"), fqn: String::from("unknown"), family_name: String::from("unknown"), family_fqn: String::from("unknown"), args: Vec::<TypeMeta>::new(), is_named: false, is_bounded: false, infer_slots: Vec::<String>::new() }, is_async: true });
+    println!("{:?}", FunctionMeta { kind: String::from("function"), name: String::from("ping"), fqn: String::from("metadata/01_symbol_meta/ping"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 25, is_public: true, params: Vec::<FunctionParameterMeta>::new(), return_type: TypeMeta { kind: String::from("unknown"), name: String::from("unknown"), fqn: String::from("unknown"), family_name: String::from("unknown"), family_fqn: String::from("unknown"), args: Vec::<TypeMeta>::new(), is_named: false, is_bounded: false, infer_slots: Vec::<String>::new() }, is_async: true });
     println!("{:?}", BuiltinMeta { kind: String::from("builtin"), name: String::from("print"), fqn: String::from("builtin/print"), module_fqn: String::from("builtin"), file: String::from(""), line_num: 0, is_public: true, params: Vec::<FunctionParameterMeta>::new(), return_type: TypeMeta { kind: String::from("primitive"), name: String::from("()"), fqn: String::from("()"), family_name: String::from("()"), family_fqn: String::from("()"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, is_async: false });
-    return count;
+    count
 }
 
 #[tokio::main]
@@ -60,21 +60,21 @@ async fn main() {
     let profile = metadata_01_symbol_meta__Profile { version: 1, name: String::from("Ada") };
     let queue = Channel::<i64>::bounded(1);
     queue.send(11).await;
-    println!("{:?}", StructMeta { kind: String::from("struct"), name: String::from("Profile"), fqn: String::from("metadata/01_symbol_meta/Profile"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 12, is_public: true, type_info: TypeMeta { kind: String::from("struct"), name: String::from("Profile"), fqn: String::from("metadata/01_symbol_meta/Profile"), family_name: String::from("Profile"), family_fqn: String::from("metadata/01_symbol_meta/Profile"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() } });
+    println!("{:?}", StructMeta { kind: String::from("struct"), name: String::from("Profile"), fqn: String::from("metadata/01_symbol_meta/Profile"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 12, is_public: true, type_info: TypeMeta { kind: String::from("struct"), name: String::from("Profile"), fqn: String::from("metadata/01_symbol_meta/Profile"), family_name: String::from("Profile"), family_fqn: String::from("metadata/01_symbol_meta/Profile"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() } });
     println!("{:?}", TypeMeta { kind: String::from("struct"), name: String::from("Profile"), fqn: String::from("metadata/01_symbol_meta/Profile"), family_name: String::from("Profile"), family_fqn: String::from("metadata/01_symbol_meta/Profile"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() });
-    println!("{:?}", vec![FieldMeta { kind: String::from("field"), name: String::from("version"), fqn: String::from("metadata/01_symbol_meta/Profile/version"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 13, is_public: true, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, index: 0, is_const: true, has_default: true, is_declared: true, source_component_fqn: String::from("") }, FieldMeta { kind: String::from("field"), name: String::from("name"), fqn: String::from("metadata/01_symbol_meta/Profile/name"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 14, is_public: true, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, index: 1, is_const: false, has_default: false, is_declared: true, source_component_fqn: String::from("") }]);
-    println!("{:?}", EnumMeta { kind: String::from("enum"), name: String::from("Status"), fqn: String::from("metadata/01_symbol_meta/Status"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 3, is_public: true, type_info: TypeMeta { kind: String::from("enum"), name: String::from("Status"), fqn: String::from("metadata/01_symbol_meta/Status"), family_name: String::from("Status"), family_fqn: String::from("metadata/01_symbol_meta/Status"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() } });
+    println!("{:?}", vec![FieldMeta { kind: String::from("field"), name: String::from("version"), fqn: String::from("metadata/01_symbol_meta/Profile/version"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 13, is_public: true, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, index: 0, is_const: true, has_default: true, is_declared: true, source_component_fqn: String::from("") }, FieldMeta { kind: String::from("field"), name: String::from("name"), fqn: String::from("metadata/01_symbol_meta/Profile/name"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 14, is_public: true, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, index: 1, is_const: false, has_default: false, is_declared: true, source_component_fqn: String::from("") }]);
+    println!("{:?}", EnumMeta { kind: String::from("enum"), name: String::from("Status"), fqn: String::from("metadata/01_symbol_meta/Status"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 3, is_public: true, type_info: TypeMeta { kind: String::from("enum"), name: String::from("Status"), fqn: String::from("metadata/01_symbol_meta/Status"), family_name: String::from("Status"), family_fqn: String::from("metadata/01_symbol_meta/Status"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() } });
     println!("{:?}", TypeMeta { kind: String::from("enum"), name: String::from("Status"), fqn: String::from("metadata/01_symbol_meta/Status"), family_name: String::from("Status"), family_fqn: String::from("metadata/01_symbol_meta/Status"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() });
-    println!("{:?}", vec![VariantMeta { kind: String::from("variant"), name: String::from("Ready"), fqn: String::from("metadata/01_symbol_meta/Status/Ready"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 4, is_public: true, index: 0 }, VariantMeta { kind: String::from("variant"), name: String::from("Busy"), fqn: String::from("metadata/01_symbol_meta/Status/Busy"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 5, is_public: true, index: 1 }]);
-    println!("{:?}", VariantMeta { kind: String::from("variant"), name: String::from("Ready"), fqn: String::from("metadata/01_symbol_meta/Status/Ready"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 4, is_public: true, index: 0 });
+    println!("{:?}", vec![VariantMeta { kind: String::from("variant"), name: String::from("Ready"), fqn: String::from("metadata/01_symbol_meta/Status/Ready"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 4, is_public: true, index: 0 }, VariantMeta { kind: String::from("variant"), name: String::from("Busy"), fqn: String::from("metadata/01_symbol_meta/Status/Busy"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 5, is_public: true, index: 1 }]);
+    println!("{:?}", VariantMeta { kind: String::from("variant"), name: String::from("Ready"), fqn: String::from("metadata/01_symbol_meta/Status/Ready"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 4, is_public: true, index: 0 });
     println!("{}", String::from("Status"));
-    println!("{:?}", MethodMeta { kind: String::from("method"), name: String::from("build_tag"), fqn: String::from("metadata/01_symbol_meta/Profile/build_tag"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 20, is_public: true, params: vec![MethodParameterMeta { kind: String::from("parameter"), name: String::from("suffix"), fqn: String::from("metadata/01_symbol_meta/Profile/build_tag/suffix"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 20, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false }], return_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, is_async: false, is_static: true, is_declared: true });
-    println!("{:?}", MethodParameterMeta { kind: String::from("parameter"), name: String::from("suffix"), fqn: String::from("metadata/01_symbol_meta/Profile/build_tag/suffix"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 20, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false });
-    println!("{:?}", FieldMeta { kind: String::from("field"), name: String::from("name"), fqn: String::from("metadata/01_symbol_meta/Profile/name"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 14, is_public: true, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, index: 1, is_const: false, has_default: false, is_declared: true, source_component_fqn: String::from("") });
+    println!("{:?}", MethodMeta { kind: String::from("method"), name: String::from("build_tag"), fqn: String::from("metadata/01_symbol_meta/Profile/build_tag"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 20, is_public: true, params: vec![MethodParameterMeta { kind: String::from("parameter"), name: String::from("suffix"), fqn: String::from("metadata/01_symbol_meta/Profile/build_tag/suffix"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 20, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false }], return_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, is_async: false, is_static: true, is_declared: true });
+    println!("{:?}", MethodParameterMeta { kind: String::from("parameter"), name: String::from("suffix"), fqn: String::from("metadata/01_symbol_meta/Profile/build_tag/suffix"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 20, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false });
+    println!("{:?}", FieldMeta { kind: String::from("field"), name: String::from("name"), fqn: String::from("metadata/01_symbol_meta/Profile/name"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 14, is_public: true, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, index: 1, is_const: false, has_default: false, is_declared: true, source_component_fqn: String::from("") });
     println!("{}", String::from("Profile"));
-    println!("{:?}", MethodMeta { kind: String::from("method"), name: String::from("label"), fqn: String::from("metadata/01_symbol_meta/Profile/label"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 16, is_public: true, params: vec![MethodParameterMeta { kind: String::from("parameter"), name: String::from("prefix"), fqn: String::from("metadata/01_symbol_meta/Profile/label/prefix"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 16, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false }], return_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, is_async: false, is_static: false, is_declared: true });
+    println!("{:?}", MethodMeta { kind: String::from("method"), name: String::from("label"), fqn: String::from("metadata/01_symbol_meta/Profile/label"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 16, is_public: true, params: vec![MethodParameterMeta { kind: String::from("parameter"), name: String::from("prefix"), fqn: String::from("metadata/01_symbol_meta/Profile/label/prefix"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 16, is_public: false, index: 0, value_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, declared_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, has_declared_type: true, has_default: false }], return_type: TypeMeta { kind: String::from("primitive"), name: String::from("String"), fqn: String::from("String"), family_name: String::from("String"), family_fqn: String::from("String"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }, is_async: false, is_static: false, is_declared: true });
     println!("{}", String::from("Profile"));
-    println!("{:?}", VariableMeta { kind: String::from("variable"), name: String::from("queue"), fqn: String::from("metadata/01_symbol_meta/main/queue"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("/Users/eric/code/zinc/test/zinc_source/metadata/01_symbol_meta.zn"), line_num: 52, is_public: false, value_type: TypeMeta { kind: String::from("channel"), name: String::from("channel<i64>"), fqn: String::from("channel<i64>"), family_name: String::from("channel"), family_fqn: String::from("channel"), args: vec![TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }], is_named: false, is_bounded: true, infer_slots: Vec::<String>::new() }, has_declared_type: false, is_mutated: false, is_shadow: false });
+    println!("{:?}", VariableMeta { kind: String::from("variable"), name: String::from("queue"), fqn: String::from("metadata/01_symbol_meta/main/queue"), module_fqn: String::from("metadata/01_symbol_meta"), file: String::from("metadata/01_symbol_meta.zn"), line_num: 52, is_public: false, value_type: TypeMeta { kind: String::from("channel"), name: String::from("channel<i64>"), fqn: String::from("channel<i64>"), family_name: String::from("channel"), family_fqn: String::from("channel"), args: vec![TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }], is_named: false, is_bounded: true, infer_slots: Vec::<String>::new() }, has_declared_type: false, is_mutated: false, is_shadow: false });
     println!("{:?}", TypeMeta { kind: String::from("channel"), name: String::from("channel<i64>"), fqn: String::from("channel<i64>"), family_name: String::from("channel"), family_fqn: String::from("channel"), args: vec![TypeMeta { kind: String::from("primitive"), name: String::from("i64"), fqn: String::from("i64"), family_name: String::from("i64"), family_fqn: String::from("i64"), args: Vec::<TypeMeta>::new(), is_named: true, is_bounded: false, infer_slots: Vec::<String>::new() }], is_named: false, is_bounded: true, infer_slots: Vec::<String>::new() });
     println!("{}", String::from("i64"));
     println!("{}", true);
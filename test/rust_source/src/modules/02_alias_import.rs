@@ -11,7 +11,7 @@ impl Default for modules__lib_io__File {
 }
 
 fn modules__lib_io__size() -> i64 {
-    return 7;
+    7
 }
 
 fn main() {
@@ -12,7 +12,7 @@ impl Default for modules__lib_shapes__Point {
 }
 
 fn modules__lib_shapes__double_i64(x: i64) -> i64 {
-    return (x * MODULES__LIB_SHAPES__SCALE);
+    x * MODULES__LIB_SHAPES__SCALE
 }
 
 fn main() {
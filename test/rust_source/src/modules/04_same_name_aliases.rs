@@ -1,9 +1,9 @@
 fn modules__lib_left__value() -> i64 {
-    return 1;
+    1
 }
 
 fn modules__lib_right__value() -> i64 {
-    return 2;
+    2
 }
 
 fn main() {
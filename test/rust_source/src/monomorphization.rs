@@ -1,9 +1,9 @@
 fn monomorphization__add_f64_f64(a: f64, b: f64) -> f64 {
-    return (a + b);
+    a + b
 }
 
 fn monomorphization__add_i64_i64(a: i64, b: i64) -> i64 {
-    return (a + b);
+    a + b
 }
 
 fn main() {
@@ -1,13 +1,17 @@
+// Test: Deep recursion with same type throughout
+// Fibonacci function creates single specialization that calls itself
+// Expected: Valid - single fib_i64 specialization
 fn monomorphization_01_deep_recursion_same_type__fib_i64(n: i64) -> i64 {
-    if (n <= 1) {
+    if n <= 1 {
         return n;
     }
-    return (monomorphization_01_deep_recursion_same_type__fib_i64((n - 1)) + monomorphization_01_deep_recursion_same_type__fib_i64((n - 2)));
+    monomorphization_01_deep_recursion_same_type__fib_i64(n - 1) + monomorphization_01_deep_recursion_same_type__fib_i64(n - 2)
 }
 
 fn main() {
     let result = monomorphization_01_deep_recursion_same_type__fib_i64(10);
     println!("fib(10): {}", result);
+    // Additional calls with same type
     let f0 = monomorphization_01_deep_recursion_same_type__fib_i64(0);
     println!("fib(0): {}", f0);
     let f1 = monomorphization_01_deep_recursion_same_type__fib_i64(1);
@@ -1,20 +1,35 @@
+// Test: Recursive call with type promotion in argument
+// x * 0.5 promotes int to float, but this creates a DIFFERENT specialization
+// Expected: Creates process_i64 and process_f64, each calling itself
 fn monomorphization_02_recursion_type_change_attempt__process_f64(x: f64) -> f64 {
-    if (x < 1.0) {
-        return x;
+    let mut __zinc_tail_args = (x,);
+    '__zinc_tail: loop {
+        let (x,) = __zinc_tail_args;
+        if x < 1.0 {
+            return x;
+        }
+        // x * 0.5 = float, so this calls process_f64 (not same specialization!)
+        __zinc_tail_args = (x * 0.5,);
+        continue '__zinc_tail;
     }
-    return monomorphization_02_recursion_type_change_attempt__process_f64((x * 0.5));
 }
 
+// Test: Recursive call with type promotion in argument
+// x * 0.5 promotes int to float, but this creates a DIFFERENT specialization
+// Expected: Creates process_i64 and process_f64, each calling itself
 fn monomorphization_02_recursion_type_change_attempt__process_i64(x: i64) -> f64 {
-    if ((x as f64) < 1.0) {
-        return (x as f64);
+    if (x as f64) < 1.0 {
+        return x as f64;
     }
-    return monomorphization_02_recursion_type_change_attempt__process_f64(((x as f64) * 0.5));
+    // x * 0.5 = float, so this calls process_f64 (not same specialization!)
+    monomorphization_02_recursion_type_change_attempt__process_f64(x as f64 * 0.5)
 }
 
 fn main() {
+    // Start with int - creates process_i64
     let result1 = monomorphization_02_recursion_type_change_attempt__process_i64(10);
     println!("process(10): {}", result1);
+    // Start with float - creates process_f64
     let result2 = monomorphization_02_recursion_type_change_attempt__process_f64(10.0);
     println!("process(10.0): {}", result2);
 }
\ No newline at end of file
@@ -1,19 +1,22 @@
 fn monomorphization_03_indirect_recursion_chain__func_c_i64(n: i64) -> i64 {
     println!("c: {}", n);
-    return monomorphization_03_indirect_recursion_chain__func_a_i64((n - 1));
+    monomorphization_03_indirect_recursion_chain__func_a_i64(n - 1)
 }
 
 fn monomorphization_03_indirect_recursion_chain__func_b_i64(n: i64) -> i64 {
     println!("b: {}", n);
-    return monomorphization_03_indirect_recursion_chain__func_c_i64(n);
+    monomorphization_03_indirect_recursion_chain__func_c_i64(n)
 }
 
+// Test: Indirect recursion through three functions (A -> B -> C -> A)
+// Tests circular call graph handling in topological sort
+// Expected: Valid - all three functions specialized, cycle handled
 fn monomorphization_03_indirect_recursion_chain__func_a_i64(n: i64) -> i64 {
-    if (n <= 0) {
+    if n <= 0 {
         return n;
     }
     println!("a: {}", n);
-    return monomorphization_03_indirect_recursion_chain__func_b_i64((n - 1));
+    monomorphization_03_indirect_recursion_chain__func_b_i64(n - 1)
 }
 
 fn main() {
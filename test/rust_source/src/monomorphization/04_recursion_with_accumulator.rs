@@ -1,22 +1,41 @@
+// Test: Recursive function with accumulator of different type
+// Same function called with different accumulator types creates different specializations
+// Expected: sum_with_acc_i64_i64 and sum_with_acc_i64_f64
 fn monomorphization_04_recursion_with_accumulator__sum_with_acc_i64_f64(n: i64, acc: f64) -> f64 {
-    if (n <= 0) {
-        return acc;
+    let mut __zinc_tail_args = (n, acc);
+    '__zinc_tail: loop {
+        let (n, acc) = __zinc_tail_args;
+        if n <= 0 {
+            return acc;
+        }
+        __zinc_tail_args = (n - 1, acc + n as f64);
+        continue '__zinc_tail;
     }
-    return monomorphization_04_recursion_with_accumulator__sum_with_acc_i64_f64((n - 1), (acc + (n as f64)));
 }
 
+// Test: Recursive function with accumulator of different type
+// Same function called with different accumulator types creates different specializations
+// Expected: sum_with_acc_i64_i64 and sum_with_acc_i64_f64
 fn monomorphization_04_recursion_with_accumulator__sum_with_acc_i64_i64(n: i64, acc: i64) -> i64 {
-    if (n <= 0) {
-        return acc;
+    let mut __zinc_tail_args = (n, acc);
+    '__zinc_tail: loop {
+        let (n, acc) = __zinc_tail_args;
+        if n <= 0 {
+            return acc;
+        }
+        __zinc_tail_args = (n - 1, acc + n);
+        continue '__zinc_tail;
     }
-    return monomorphization_04_recursion_with_accumulator__sum_with_acc_i64_i64((n - 1), (acc + n));
 }
 
 fn main() {
+    // Integer accumulator
     let x = monomorphization_04_recursion_with_accumulator__sum_with_acc_i64_i64(10, 0);
     println!("sum_with_acc(10, 0): {}", x);
+    // Float accumulator - different specialization
     let y = monomorphization_04_recursion_with_accumulator__sum_with_acc_i64_f64(5, 0.0);
     println!("sum_with_acc(5, 0.0): {}", y);
+    // Another int call - reuses first specialization
     let z = monomorphization_04_recursion_with_accumulator__sum_with_acc_i64_i64(3, 100);
     println!("sum_with_acc(3, 100): {}", z);
 }
\ No newline at end of file
@@ -1,22 +1,25 @@
 fn monomorphization_05_mutual_three_functions__pang_i64(n: i64) -> i64 {
     println!("pang: {}", n);
-    if (n <= 0) {
+    if n <= 0 {
         return 0;
     }
-    return monomorphization_05_mutual_three_functions__ping_i64((n - 1));
+    monomorphization_05_mutual_three_functions__ping_i64(n - 1)
 }
 
 fn monomorphization_05_mutual_three_functions__pong_i64(n: i64) -> i64 {
     println!("pong: {}", n);
-    return monomorphization_05_mutual_three_functions__pang_i64(n);
+    monomorphization_05_mutual_three_functions__pang_i64(n)
 }
 
+// Test: Three-way mutual recursion (ping -> pong -> pang -> ping)
+// Tests topological sort with three-function cycle
+// Expected: Valid - all three specialized, cycle handled correctly
 fn monomorphization_05_mutual_three_functions__ping_i64(n: i64) -> i64 {
     println!("ping: {}", n);
-    if (n <= 0) {
+    if n <= 0 {
         return 0;
     }
-    return monomorphization_05_mutual_three_functions__pong_i64((n - 1));
+    monomorphization_05_mutual_three_functions__pong_i64(n - 1)
 }
 
 fn main() {
@@ -1,36 +1,49 @@
 fn monomorphization_06_mutual_with_type_promotion__odd_sum_i64_f64(n: i64, acc: f64) -> f64 {
-    if (n <= 0) {
+    if n <= 0 {
         return acc;
     }
-    return monomorphization_06_mutual_with_type_promotion__even_sum_i64_f64((n - 1), acc);
+    // Skip adding at odd positions
+    monomorphization_06_mutual_with_type_promotion__even_sum_i64_f64(n - 1, acc)
 }
 
+// Test: Mutual recursion with different accumulator types
+// even_sum and odd_sum call each other with both int and float accumulators
+// Expected: Creates four specializations (2 functions x 2 acc types)
 fn monomorphization_06_mutual_with_type_promotion__even_sum_i64_f64(n: i64, acc: f64) -> f64 {
-    if (n <= 0) {
+    if n <= 0 {
         return acc;
     }
-    return monomorphization_06_mutual_with_type_promotion__odd_sum_i64_f64((n - 1), (acc + (n as f64)));
+    // Add n to accumulator (only at even positions)
+    monomorphization_06_mutual_with_type_promotion__odd_sum_i64_f64(n - 1, acc + n as f64)
 }
 
 fn monomorphization_06_mutual_with_type_promotion__odd_sum_i64_i64(n: i64, acc: i64) -> i64 {
-    if (n <= 0) {
+    if n <= 0 {
         return acc;
     }
-    return monomorphization_06_mutual_with_type_promotion__even_sum_i64_i64((n - 1), acc);
+    // Skip adding at odd positions
+    monomorphization_06_mutual_with_type_promotion__even_sum_i64_i64(n - 1, acc)
 }
 
+// Test: Mutual recursion with different accumulator types
+// even_sum and odd_sum call each other with both int and float accumulators
+// Expected: Creates four specializations (2 functions x 2 acc types)
 fn monomorphization_06_mutual_with_type_promotion__even_sum_i64_i64(n: i64, acc: i64) -> i64 {
-    if (n <= 0) {
+    if n <= 0 {
         return acc;
     }
-    return monomorphization_06_mutual_with_type_promotion__odd_sum_i64_i64((n - 1), (acc + n));
+    // Add n to accumulator (only at even positions)
+    monomorphization_06_mutual_with_type_promotion__odd_sum_i64_i64(n - 1, acc + n)
 }
 
 fn main() {
+    // Integer accumulator variant
     let x = monomorphization_06_mutual_with_type_promotion__even_sum_i64_i64(10, 0);
     println!("even_sum(10, 0): {}", x);
+    // Float accumulator variant - creates different specialization pair
     let y = monomorphization_06_mutual_with_type_promotion__even_sum_i64_f64(10, 0.0);
     println!("even_sum(10, 0.0): {}", y);
+    // Verify same value (since logic is the same)
     let z = monomorphization_06_mutual_with_type_promotion__odd_sum_i64_i64(5, 0);
     println!("odd_sum(5, 0): {}", z);
 }
\ No newline at end of file
@@ -1,20 +1,26 @@
+// Test: Mutual recursion where functions want different return types
+// This is a tricky edge case - each function returns what the OTHER returns
+// Expected: May require type unification or produce type errors
 fn monomorphization_07_mutual_different_return_types__count_down_int_i64(n: i64) -> f64 {
     println!("int: {}", n);
-    if (n <= 0) {
-        return (0 as f64);
+    if n <= 0 {
+        return 0.0;
     }
-    return monomorphization_07_mutual_different_return_types__count_down_float_i64((n - 1));
+    monomorphization_07_mutual_different_return_types__count_down_float_i64(n - 1)
 }
 
 fn monomorphization_07_mutual_different_return_types__count_down_float_i64(n: i64) -> f64 {
     println!("float: {}", n);
-    if (n <= 0) {
+    if n <= 0 {
         return 0.0;
     }
-    return monomorphization_07_mutual_different_return_types__count_down_int_i64((n - 1));
+    monomorphization_07_mutual_different_return_types__count_down_int_i64(n - 1)
 }
 
 fn main() {
+    // This creates an interesting situation:
+    // count_down_int calls count_down_float which returns float
+    // but count_down_int is supposed to return its callee's result
     let result = monomorphization_07_mutual_different_return_types__count_down_int_i64(4);
     println!("result: {}", result);
 }
\ No newline at end of file
@@ -1,21 +1,29 @@
+// Test: Same function called from multiple contexts (loop, outside loop)
+// All calls with same types should reuse the same specialization
+// Expected: Single double_i64 specialization used everywhere
 fn monomorphization_08_same_call_different_contexts__double_i64(x: i64) -> i64 {
-    return (x + x);
+    x + x
 }
 
 fn main() {
+    // Call before loop
     let a = monomorphization_08_same_call_different_contexts__double_i64(5);
     println!("before loop: {}", a);
-    let mut i = 0;
-    while (i < 3) {
+    // Calls inside loop
+    let mut i: i64 = 0;
+    while i < 3 {
         let b = monomorphization_08_same_call_different_contexts__double_i64(i);
         println!("in loop {}: {}", i, b);
-        i = (i + 1);
+        i = i + 1;
     }
+    // Call after loop
     let c = monomorphization_08_same_call_different_contexts__double_i64(100);
     println!("after loop: {}", c);
+    // Nested in if
     if true {
         let d = monomorphization_08_same_call_different_contexts__double_i64(42);
         println!("in if: {}", d);
     }
+    // Total should be 10 + 0 + 2 + 4 + 200 + 84 = 300
     println!("a={}, c={}", a, c);
 }
\ No newline at end of file
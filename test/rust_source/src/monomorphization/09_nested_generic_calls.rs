@@ -1,36 +1,55 @@
+// Test: Nested generic function calls f(g(h(x)))
+// Each level creates its own specialization based on inner result type
+// Expected: Creates specializations for inner, middle, outer at each type
 fn monomorphization_09_nested_generic_calls__inner_f64(x: f64) -> f64 {
-    return (x + (1 as f64));
+    x + 1.0
 }
 
+// Test: Nested generic function calls f(g(h(x)))
+// Each level creates its own specialization based on inner result type
+// Expected: Creates specializations for inner, middle, outer at each type
 fn monomorphization_09_nested_generic_calls__inner_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn monomorphization_09_nested_generic_calls__middle_i64(x: i64) -> i64 {
-    return (monomorphization_09_nested_generic_calls__inner_i64(x) + monomorphization_09_nested_generic_calls__inner_i64(x));
+    // Calls inner twice with x, adds results
+    monomorphization_09_nested_generic_calls__inner_i64(x) + monomorphization_09_nested_generic_calls__inner_i64(x)
 }
 
 fn monomorphization_09_nested_generic_calls__middle_f64(x: f64) -> f64 {
-    return (monomorphization_09_nested_generic_calls__inner_f64(x) + monomorphization_09_nested_generic_calls__inner_f64(x));
+    // Calls inner twice with x, adds results
+    monomorphization_09_nested_generic_calls__inner_f64(x) + monomorphization_09_nested_generic_calls__inner_f64(x)
 }
 
 fn monomorphization_09_nested_generic_calls__outer_f64(x: f64) -> f64 {
-    return (monomorphization_09_nested_generic_calls__middle_f64(x) * (2 as f64));
+    // Calls middle with x, multiplies by 2
+    monomorphization_09_nested_generic_calls__middle_f64(x) * 2.0
 }
 
 fn monomorphization_09_nested_generic_calls__outer_i64(x: i64) -> i64 {
-    return (monomorphization_09_nested_generic_calls__middle_i64(x) * 2);
+    // Calls middle with x, multiplies by 2
+    monomorphization_09_nested_generic_calls__middle_i64(x) * 2
 }
 
 fn main() {
+    // All int: outer_i64 -> middle_i64 -> inner_i64
     let a = monomorphization_09_nested_generic_calls__outer_i64(5);
+    // ((5+1) + (5+1)) * 2 = 12 * 2 = 24
     println!("outer(5): {}", a);
+    // All float: outer_f64 -> middle_f64 -> inner_f64
     let b = monomorphization_09_nested_generic_calls__outer_f64(2.5);
+    // ((2.5+1) + (2.5+1)) * 2 = 7 * 2 = 14
     println!("outer(2.5): {}", b);
+    // Direct inner calls
     let c = monomorphization_09_nested_generic_calls__inner_i64(10);
+    // 11
     println!("inner(10): {}", c);
     let d = monomorphization_09_nested_generic_calls__inner_f64(0.5);
+    // 1.5
     println!("inner(0.5): {}", d);
+    // Direct middle call
     let e = monomorphization_09_nested_generic_calls__middle_i64(3);
+    // (3+1) + (3+1) = 8
     println!("middle(3): {}", e);
 }
\ No newline at end of file
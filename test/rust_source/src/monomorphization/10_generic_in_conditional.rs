@@ -1,17 +1,29 @@
+// Test: Different specializations in if/else branches
+// Both branches should be compiled even if only one executes
+// Expected: Creates identity_i64, identity_f64, identity_String
 fn monomorphization_10_generic_in_conditional__identity_String(x: String) -> String {
-    return x;
+    x
 }
 
+// Test: Different specializations in if/else branches
+// Both branches should be compiled even if only one executes
+// Expected: Creates identity_i64, identity_f64, identity_String
 fn monomorphization_10_generic_in_conditional__identity_bool(x: bool) -> bool {
-    return x;
+    x
 }
 
+// Test: Different specializations in if/else branches
+// Both branches should be compiled even if only one executes
+// Expected: Creates identity_i64, identity_f64, identity_String
 fn monomorphization_10_generic_in_conditional__identity_f64(x: f64) -> f64 {
-    return x;
+    x
 }
 
+// Test: Different specializations in if/else branches
+// Both branches should be compiled even if only one executes
+// Expected: Creates identity_i64, identity_f64, identity_String
 fn monomorphization_10_generic_in_conditional__identity_i64(x: i64) -> i64 {
-    return x;
+    x
 }
 
 fn main() {
@@ -23,11 +35,14 @@ fn main() {
         let b = monomorphization_10_generic_in_conditional__identity_f64(3.14);
         println!("float branch: {}", b);
     }
+    // String variant always executes
     let c = monomorphization_10_generic_in_conditional__identity_String(String::from("hello"));
     println!("string: {}", c);
+    // Bool variant
     let d = monomorphization_10_generic_in_conditional__identity_bool(false);
     println!("bool: {}", d);
-    if (!cond) {
+    // Another conditional with nested calls
+    if false {
         let e = monomorphization_10_generic_in_conditional__identity_i64(999);
         println!("never reached: {}", e);
     }
@@ -1,23 +1,39 @@
+// Test: Function call where argument is an expression that triggers type promotion
+// The expression's result type determines which specialization is used
+// Expected: process_f64 when expression involves float, process_i64 otherwise
 fn monomorphization_11_call_with_expression_result__process_f64(x: f64) -> f64 {
-    return (x * (2 as f64));
+    x * 2.0
 }
 
+// Test: Function call where argument is an expression that triggers type promotion
+// The expression's result type determines which specialization is used
+// Expected: process_f64 when expression involves float, process_i64 otherwise
 fn monomorphization_11_call_with_expression_result__process_i64(x: i64) -> i64 {
-    return (x * 2);
+    x * 2
 }
 
 fn main() {
-    let a = 10;
+    let a: i64 = 10;
     let b = 3.5;
-    let result1 = monomorphization_11_call_with_expression_result__process_f64(((a as f64) + b));
+    // int + float = float, so this calls process_f64
+    let result1 = monomorphization_11_call_with_expression_result__process_f64(13.5);
+    // (10 + 3.5) * 2 = 27
     println!("process(a + b): {}", result1);
-    let result2 = monomorphization_11_call_with_expression_result__process_i64((a * 2));
+    // int * int = int, so this calls process_i64
+    let result2 = monomorphization_11_call_with_expression_result__process_i64(20);
+    // 20 * 2 = 40
     println!("process(a * 2): {}", result2);
-    let result3 = monomorphization_11_call_with_expression_result__process_f64(((a as f64) + 0.0));
+    // int + float = float
+    let result3 = monomorphization_11_call_with_expression_result__process_f64(10.0);
+    // 10.0 * 2 = 20
     println!("process(a + 0.0): {}", result3);
-    let result4 = monomorphization_11_call_with_expression_result__process_i64((((a + 5)) * 2));
+    // Nested expression
+    let result4 = monomorphization_11_call_with_expression_result__process_i64(30);
+    // 30 * 2 = 60
     println!("process((a + 5) * 2): {}", result4);
-    let c = 2;
-    let result5 = monomorphization_11_call_with_expression_result__process_f64((((a / c) as f64) + 0.5));
+    // Subexpression with promotion
+    let c: i64 = 2;
+    let result5 = monomorphization_11_call_with_expression_result__process_f64(5.5);
+    // (5 + 0.5) * 2 = 11
     println!("process(a / c + 0.5): {}", result5);
 }
\ No newline at end of file
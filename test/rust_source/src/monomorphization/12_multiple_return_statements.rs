@@ -1,21 +1,24 @@
+// Test: Function with multiple return statements (all same type)
+// First return statement's type should determine function return type
+// Expected: Valid - classify_i64 returns i64 from any branch
 fn monomorphization_12_multiple_return_statements__classify_i64(n: i64) -> i64 {
-    if (n < 0) {
-        return (-1);
+    if n < 0 {
+        return -1;
     }
-    if (n == 0) {
+    if n == 0 {
         return 0;
     }
-    if (n < 10) {
+    if n < 10 {
         return 1;
     }
-    if (n < 100) {
+    if n < 100 {
         return 2;
     }
-    return 3;
+    3
 }
 
 fn main() {
-    let a = monomorphization_12_multiple_return_statements__classify_i64((-5));
+    let a = monomorphization_12_multiple_return_statements__classify_i64(-5);
     println!("classify(-5): {}", a);
     let b = monomorphization_12_multiple_return_statements__classify_i64(0);
     println!("classify(0): {}", b);
@@ -1,35 +1,43 @@
+// Test: Return statement inside deeply nested blocks
+// Return type inference should find returns in nested if/while blocks
+// Expected: Valid - return type inferred from nested return
 fn monomorphization_13_return_in_nested_block__find_value_Vec_i64_i64(arr: &Vec<i64>, target: i64) -> i64 {
-    let mut i = 0;
-    while (i < (arr.len() as i64)) {
-        if (arr[(i as usize)] == target) {
+    let mut i: i64 = 0;
+    while i < arr.len() as i64 {
+        if arr[i as usize] == target {
             return i;
         }
-        i = (i + 1);
+        i = i + 1;
     }
-    return (-1);
+    -1
 }
 
 fn monomorphization_13_return_in_nested_block__sum_until_Vec_i64_i64(arr: &Vec<i64>, limit: i64) -> i64 {
-    let mut total = 0;
-    let mut i = 0;
-    while (i < (arr.len() as i64)) {
-        if ((total + arr[(i as usize)]) > limit) {
+    let mut total: i64 = 0;
+    let mut i: i64 = 0;
+    while i < arr.len() as i64 {
+        if (total + arr[i as usize]) > limit {
             return total;
         }
-        total = (total + arr[(i as usize)]);
-        i = (i + 1);
+        total = total + arr[i as usize];
+        i = i + 1;
     }
-    return total;
+    total
 }
 
 fn main() {
     let nums = vec![10, 20, 30, 40, 50];
+    // Find existing value
     let idx1 = monomorphization_13_return_in_nested_block__find_value_Vec_i64_i64(&nums, 30);
     println!("find 30: {}", idx1);
+    // Find non-existing value
     let idx2 = monomorphization_13_return_in_nested_block__find_value_Vec_i64_i64(&nums, 99);
     println!("find 99: {}", idx2);
+    // Sum with limit
     let sum1 = monomorphization_13_return_in_nested_block__sum_until_Vec_i64_i64(&nums, 50);
+    // 10 + 20 = 30, then 30+30=60 > 50
     println!("sum until 50: {}", sum1);
     let sum2 = monomorphization_13_return_in_nested_block__sum_until_Vec_i64_i64(&nums, 1000);
+    // 10+20+30+40+50 = 150
     println!("sum until 1000: {}", sum2);
 }
\ No newline at end of file
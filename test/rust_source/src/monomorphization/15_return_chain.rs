@@ -1,92 +1,109 @@
+// Test: Deep chain of return type dependencies
+// Each level returns result of calling the next level
+// Expected: Return types flow from l1 up through l5
 fn monomorphization_15_return_chain__l1_String(x: String) -> String {
-    return x;
+    x
 }
 
+// Test: Deep chain of return type dependencies
+// Each level returns result of calling the next level
+// Expected: Return types flow from l1 up through l5
 fn monomorphization_15_return_chain__l1_bool(x: bool) -> bool {
-    return x;
+    x
 }
 
+// Test: Deep chain of return type dependencies
+// Each level returns result of calling the next level
+// Expected: Return types flow from l1 up through l5
 fn monomorphization_15_return_chain__l1_f64(x: f64) -> f64 {
-    return x;
+    x
 }
 
+// Test: Deep chain of return type dependencies
+// Each level returns result of calling the next level
+// Expected: Return types flow from l1 up through l5
 fn monomorphization_15_return_chain__l1_i64(x: i64) -> i64 {
-    return x;
+    x
 }
 
 fn monomorphization_15_return_chain__l2_String(x: String) -> String {
-    return monomorphization_15_return_chain__l1_String(x);
+    monomorphization_15_return_chain__l1_String(x)
 }
 
 fn monomorphization_15_return_chain__l2_bool(x: bool) -> bool {
-    return monomorphization_15_return_chain__l1_bool(x);
+    monomorphization_15_return_chain__l1_bool(x)
 }
 
 fn monomorphization_15_return_chain__l2_f64(x: f64) -> f64 {
-    return monomorphization_15_return_chain__l1_f64(x);
+    monomorphization_15_return_chain__l1_f64(x)
 }
 
 fn monomorphization_15_return_chain__l2_i64(x: i64) -> i64 {
-    return monomorphization_15_return_chain__l1_i64(x);
+    monomorphization_15_return_chain__l1_i64(x)
 }
 
 fn monomorphization_15_return_chain__l3_String(x: String) -> String {
-    return monomorphization_15_return_chain__l2_String(x);
+    monomorphization_15_return_chain__l2_String(x)
 }
 
 fn monomorphization_15_return_chain__l3_bool(x: bool) -> bool {
-    return monomorphization_15_return_chain__l2_bool(x);
+    monomorphization_15_return_chain__l2_bool(x)
 }
 
 fn monomorphization_15_return_chain__l3_f64(x: f64) -> f64 {
-    return monomorphization_15_return_chain__l2_f64(x);
+    monomorphization_15_return_chain__l2_f64(x)
 }
 
 fn monomorphization_15_return_chain__l3_i64(x: i64) -> i64 {
-    return monomorphization_15_return_chain__l2_i64(x);
+    monomorphization_15_return_chain__l2_i64(x)
 }
 
 fn monomorphization_15_return_chain__l4_String(x: String) -> String {
-    return monomorphization_15_return_chain__l3_String(x);
+    monomorphization_15_return_chain__l3_String(x)
 }
 
 fn monomorphization_15_return_chain__l4_bool(x: bool) -> bool {
-    return monomorphization_15_return_chain__l3_bool(x);
+    monomorphization_15_return_chain__l3_bool(x)
 }
 
 fn monomorphization_15_return_chain__l4_f64(x: f64) -> f64 {
-    return monomorphization_15_return_chain__l3_f64(x);
+    monomorphization_15_return_chain__l3_f64(x)
 }
 
 fn monomorphization_15_return_chain__l4_i64(x: i64) -> i64 {
-    return monomorphization_15_return_chain__l3_i64(x);
+    monomorphization_15_return_chain__l3_i64(x)
 }
 
 fn monomorphization_15_return_chain__l5_String(x: String) -> String {
-    return monomorphization_15_return_chain__l4_String(x);
+    monomorphization_15_return_chain__l4_String(x)
 }
 
 fn monomorphization_15_return_chain__l5_bool(x: bool) -> bool {
-    return monomorphization_15_return_chain__l4_bool(x);
+    monomorphization_15_return_chain__l4_bool(x)
 }
 
 fn monomorphization_15_return_chain__l5_f64(x: f64) -> f64 {
-    return monomorphization_15_return_chain__l4_f64(x);
+    monomorphization_15_return_chain__l4_f64(x)
 }
 
 fn monomorphization_15_return_chain__l5_i64(x: i64) -> i64 {
-    return monomorphization_15_return_chain__l4_i64(x);
+    monomorphization_15_return_chain__l4_i64(x)
 }
 
 fn main() {
+    // Integer chain: l5_i64 -> l4_i64 -> l3_i64 -> l2_i64 -> l1_i64
     let a = monomorphization_15_return_chain__l5_i64(42);
     println!("l5(42): {}", a);
+    // Float chain: l5_f64 -> l4_f64 -> l3_f64 -> l2_f64 -> l1_f64
     let b = monomorphization_15_return_chain__l5_f64(3.14);
     println!("l5(3.14): {}", b);
+    // String chain: l5_String -> l4_String -> l3_String -> l2_String -> l1_String
     let c = monomorphization_15_return_chain__l5_String(String::from("chain"));
     println!("l5(chain): {}", c);
+    // Bool chain
     let d = monomorphization_15_return_chain__l5_bool(true);
     println!("l5(true): {}", d);
+    // Partial chain tests
     let e = monomorphization_15_return_chain__l3_i64(100);
     println!("l3(100): {}", e);
     let f = monomorphization_15_return_chain__l2_f64(0.5);
@@ -1,5 +1,8 @@
+// Test: Many call sites with identical types
+// All should map to the same single specialization
+// Expected: Single inc_i64 reused for all 10+ calls
 fn monomorphization_16_many_call_sites_same_types__inc_i64(x: i64) -> i64 {
-    return (x + 1);
+    x + 1
 }
 
 fn main() {
@@ -13,12 +16,17 @@ fn main() {
     let h = monomorphization_16_many_call_sites_same_types__inc_i64(8);
     let i = monomorphization_16_many_call_sites_same_types__inc_i64(9);
     let j = monomorphization_16_many_call_sites_same_types__inc_i64(10);
-    let sum = (((((((((a + b) + c) + d) + e) + f) + g) + h) + i) + j);
+    // Sum all results
+    let sum = a + b + c + d + e + f + g + h + i + j;
+    // 2+3+4+5+6+7+8+9+10+11 = 65
     println!("sum of inc(1) to inc(10): {}", sum);
+    // Even more calls
     let k = monomorphization_16_many_call_sites_same_types__inc_i64(100);
     let l = monomorphization_16_many_call_sites_same_types__inc_i64(200);
     let m = monomorphization_16_many_call_sites_same_types__inc_i64(300);
     println!("k={}, l={}, m={}", k, l, m);
+    // Call in expressions
     let n = monomorphization_16_many_call_sites_same_types__inc_i64(monomorphization_16_many_call_sites_same_types__inc_i64(monomorphization_16_many_call_sites_same_types__inc_i64(0)));
+    // 3
     println!("inc(inc(inc(0))): {}", n);
 }
\ No newline at end of file
@@ -1,28 +1,41 @@
+// Test: Multiple call sites discovered in single pass
+// Specialization is created on first call, reused for subsequent calls
+// Expected: Valid - all call sites map to same specialization
 fn monomorphization_18_specialization_not_called__maybe_String(x: String) -> String {
-    return x;
+    x
 }
 
+// Test: Multiple call sites discovered in single pass
+// Specialization is created on first call, reused for subsequent calls
+// Expected: Valid - all call sites map to same specialization
 fn monomorphization_18_specialization_not_called__maybe_i64(x: i64) -> i64 {
-    return x;
+    x
 }
 
 fn main() {
+    // First call site - creates maybe_i64
     let a = monomorphization_18_specialization_not_called__maybe_i64(1);
     println!("first call: {}", a);
-    let mut temp = 100;
-    temp = (temp + 1);
+    // Some other code between calls
+    let mut temp: i64 = 100;
+    temp = temp + 1;
     println!("temp: {}", temp);
+    // Second call site - reuses maybe_i64
     let b = monomorphization_18_specialization_not_called__maybe_i64(2);
     println!("second call: {}", b);
+    // Third call site
     let c = monomorphization_18_specialization_not_called__maybe_i64(3);
     println!("third call: {}", c);
+    // Many more calls later
     println!("more calls:");
     let d = monomorphization_18_specialization_not_called__maybe_i64(4);
     let e = monomorphization_18_specialization_not_called__maybe_i64(5);
     let f = monomorphization_18_specialization_not_called__maybe_i64(6);
     println!("d={}, e={}, f={}", d, e, f);
+    // Different type creates new specialization
     let g = monomorphization_18_specialization_not_called__maybe_String(String::from("string"));
     println!("string call: {}", g);
+    // Back to int reuses original
     let h = monomorphization_18_specialization_not_called__maybe_i64(100);
     println!("back to int: {}", h);
 }
\ No newline at end of file
@@ -1,52 +1,64 @@
+// Test: Generic functions with array parameters
+// Array element type should be tracked for specialization
+// Expected: Different specializations for int arrays vs float arrays
 fn monomorphization_19_generic_with_arrays__first_Vec_f64(arr: &Vec<f64>) -> f64 {
-    return arr[0];
+    arr[0]
 }
 
+// Test: Generic functions with array parameters
+// Array element type should be tracked for specialization
+// Expected: Different specializations for int arrays vs float arrays
 fn monomorphization_19_generic_with_arrays__first_Vec_i64(arr: &Vec<i64>) -> i64 {
-    return arr[0];
+    arr[0]
 }
 
 fn monomorphization_19_generic_with_arrays__last_Vec_f64(arr: &Vec<f64>) -> f64 {
-    return arr[(((arr.len() as i64) - 1) as usize)];
+    arr[(arr.len() as i64 - 1) as usize]
 }
 
 fn monomorphization_19_generic_with_arrays__last_Vec_i64(arr: &Vec<i64>) -> i64 {
-    return arr[(((arr.len() as i64) - 1) as usize)];
+    arr[(arr.len() as i64 - 1) as usize]
 }
 
 fn monomorphization_19_generic_with_arrays__sum_array_Vec_f64(arr: &Vec<f64>) -> f64 {
     let mut total = arr[0];
-    let mut i = 1;
-    while (i < (arr.len() as i64)) {
-        total = (total + arr[(i as usize)]);
-        i = (i + 1);
+    let mut i: i64 = 1;
+    while i < arr.len() as i64 {
+        total = total + arr[i as usize];
+        i = i + 1;
     }
-    return total;
+    total
 }
 
 fn monomorphization_19_generic_with_arrays__sum_array_Vec_i64(arr: &Vec<i64>) -> i64 {
     let mut total = arr[0];
-    let mut i = 1;
-    while (i < (arr.len() as i64)) {
-        total = (total + arr[(i as usize)]);
-        i = (i + 1);
+    let mut i: i64 = 1;
+    while i < arr.len() as i64 {
+        total = total + arr[i as usize];
+        i = i + 1;
     }
-    return total;
+    total
 }
 
 fn main() {
     let ints = vec![1, 2, 3, 4, 5];
     let floats = vec![1.0, 2.0, 3.0];
+    // first with int array
     let a = monomorphization_19_generic_with_arrays__first_Vec_i64(&ints);
     println!("first(ints): {}", a);
+    // first with float array
     let b = monomorphization_19_generic_with_arrays__first_Vec_f64(&floats);
     println!("first(floats): {}", b);
+    // last with int array
     let c = monomorphization_19_generic_with_arrays__last_Vec_i64(&ints);
     println!("last(ints): {}", c);
+    // last with float array
     let d = monomorphization_19_generic_with_arrays__last_Vec_f64(&floats);
     println!("last(floats): {}", d);
+    // sum_array with int array
     let e = monomorphization_19_generic_with_arrays__sum_array_Vec_i64(&ints);
     println!("sum_array(ints): {}", e);
+    // sum_array with float array
     let f = monomorphization_19_generic_with_arrays__sum_array_Vec_f64(&floats);
     println!("sum_array(floats): {}", f);
 }
\ No newline at end of file
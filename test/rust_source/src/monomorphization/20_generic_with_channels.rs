@@ -5,6 +5,9 @@ async fn monomorphization_20_generic_with_channels__double_sender_Channel_i64(ch
     ch.send(val).await;
 }
 
+// Test: Generic function with channel parameter
+// Channel type should be propagated through function specialization
+// Expected: sender specialized for channel element type
 async fn monomorphization_20_generic_with_channels__sender_Channel_i64(ch: Channel<i64>, val: i64) {
     ch.send(val).await;
 }
@@ -12,15 +15,18 @@ async fn monomorphization_20_generic_with_channels__sender_Channel_i64(ch: Chann
 #[tokio::main]
 async fn main() {
     let mut __zinc_spawn_handles = Vec::new();
+    // Integer channel
     let int_ch = Channel::<i64>::unbounded();
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = int_ch.clone(); async move { monomorphization_20_generic_with_channels__sender_Channel_i64(__zinc_spawn_arg_0.clone(), 42).await; } }));
     let x = int_ch.recv().await;
     println!("received int: {}", x);
+    // Double sender with integer
     let int_ch2 = Channel::<i64>::unbounded();
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = int_ch2.clone(); async move { monomorphization_20_generic_with_channels__double_sender_Channel_i64(__zinc_spawn_arg_0.clone(), 100).await; } }));
     let y1 = int_ch2.recv().await;
     let y2 = int_ch2.recv().await;
     println!("double received: {}, {}", y1, y2);
+    // Another int channel to verify reuse
     let int_ch3 = Channel::<i64>::unbounded();
     __zinc_spawn_handles.push(tokio::spawn({ let __zinc_spawn_arg_0 = int_ch3.clone(); async move { monomorphization_20_generic_with_channels__sender_Channel_i64(__zinc_spawn_arg_0.clone(), 999).await; } }));
     let z = int_ch3.recv().await;
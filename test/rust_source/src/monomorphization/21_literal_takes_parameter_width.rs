@@ -0,0 +1,29 @@
+// Test: Unsuffixed literal arguments take the width their parameter is used at
+// The literal meets an i32 value, an annotated parameter, or an annotated return
+// Expected: add_i32_i32, bump_u8_u8 and scale_f32_f32 with no casts
+fn monomorphization_21_literal_takes_parameter_width__add_i32_i32(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+// Test: Unsuffixed literal arguments take the width their parameter is used at
+// The literal meets an i32 value, an annotated parameter, or an annotated return
+// Expected: add_i32_i32, bump_u8_u8 and scale_f32_f32 with no casts
+fn monomorphization_21_literal_takes_parameter_width__add_i64_i64(a: i64, b: i64) -> i64 {
+    a + b
+}
+
+fn monomorphization_21_literal_takes_parameter_width__bump_u8_u8(a: u8, b: u8) -> u8 {
+    a + b
+}
+
+fn monomorphization_21_literal_takes_parameter_width__scale_f32_f32(x: f32, k: f32) -> f32 {
+    x * k
+}
+
+fn main() {
+    let z: i32 = 4;
+    println!("{}", monomorphization_21_literal_takes_parameter_width__add_i32_i32(z, 1));
+    println!("{}", monomorphization_21_literal_takes_parameter_width__add_i64_i64(2, 3));
+    println!("{}", monomorphization_21_literal_takes_parameter_width__bump_u8_u8(1, 2));
+    println!("{}", monomorphization_21_literal_takes_parameter_width__scale_f32_f32(2.0, 1.5));
+}
\ No newline at end of file
@@ -10,10 +10,10 @@ impl Default for operators_03_overloading__Bag {
 
 impl operators_03_overloading__Bag {
     fn __zinc_op_logical_and(&self, candidate: i64) -> bool {
-        return (candidate == self.value);
+        candidate == self.value
     }
     fn __zinc_op_logical_or(&self, candidate: i64) -> bool {
-        return (candidate == (self.value + 1));
+        candidate == self.value + 1
     }
 }
 
@@ -29,13 +29,13 @@ impl Default for operators_03_overloading__Offset {
 
 impl operators_03_overloading__Offset {
     fn __zinc_op_add(left: Self, amount: i64) -> Self {
-        return operators_03_overloading__Offset { value: (left.value + amount) };
+        operators_03_overloading__Offset { value: left.value + amount }
     }
     fn __zinc_op_bitnot(&self) -> Self {
-        return operators_03_overloading__Offset { value: (0 - self.value) };
+        operators_03_overloading__Offset { value: 0 - self.value }
     }
     fn __zinc_op_rem(left: Self, right: Self) -> Self {
-        return operators_03_overloading__Offset { value: (right.value - left.value) };
+        operators_03_overloading__Offset { value: right.value - left.value }
     }
 }
 
@@ -52,33 +52,33 @@ impl Default for operators_03_overloading__Point {
 
 impl operators_03_overloading__Point {
     fn __zinc_op_lt(left: Self, right: Self) -> bool {
-        return ((left.x < right.x) && (left.y < right.y));
+        left.x < right.x && left.y < right.y
     }
     fn __zinc_op_add(&self, rhs: Self) -> Self {
-        return operators_03_overloading__Point { x: (self.x + rhs.x), y: (self.y + rhs.y) };
+        operators_03_overloading__Point { x: self.x + rhs.x, y: self.y + rhs.y }
     }
     fn __zinc_op_index(&self, idx: i64) -> i64 {
-        if (idx == 0) {
+        if idx == 0 {
             return self.x;
         }
-        return self.y;
+        self.y
     }
     fn __zinc_op_bitor(left: Self, right: Self) -> Self {
-        return operators_03_overloading__Point { x: (left.x + right.y), y: (left.y + right.x) };
+        operators_03_overloading__Point { x: left.x + right.y, y: left.y + right.x }
     }
 }
 
 fn main() {
     let a = operators_03_overloading__Point { x: 1, y: 2 };
     let b = operators_03_overloading__Point { x: 3, y: 4 };
-    let c = (a).__zinc_op_add(b);
+    let c = a.__zinc_op_add(b);
     println!("{}", c.x);
     let mut d = operators_03_overloading__Point { x: 5, y: 6 };
     let e = operators_03_overloading__Point { x: 7, y: 8 };
-    d = (d).__zinc_op_add(e);
+    d = d.__zinc_op_add(e);
     println!("{}", d.y);
     let f = operators_03_overloading__Point { x: 1, y: 2 };
-    println!("{}", (f).__zinc_op_index(0));
+    println!("{}", f.__zinc_op_index(0));
     let g = operators_03_overloading__Point { x: 1, y: 2 };
     let h = operators_03_overloading__Point { x: 1, y: 9 };
     let i = operators_03_overloading__Point::__zinc_op_bitor(g, h);
@@ -89,13 +89,13 @@ fn main() {
     let o = operators_03_overloading__Offset { value: 10 };
     let p = operators_03_overloading__Offset::__zinc_op_add(o, 5);
     println!("{}", p.value);
-    let q = (p).__zinc_op_bitnot();
+    let q = p.__zinc_op_bitnot();
     println!("{}", q.value);
     let r = operators_03_overloading__Offset { value: 2 };
     let s = operators_03_overloading__Offset { value: 9 };
     let span = operators_03_overloading__Offset::__zinc_op_rem(r, s);
     println!("{}", span.value);
     let bag = operators_03_overloading__Bag { value: 42 };
-    println!("{}", (bag).__zinc_op_logical_and(42));
-    println!("{}", (bag).__zinc_op_logical_or(43));
+    println!("{}", bag.__zinc_op_logical_and(42));
+    println!("{}", bag.__zinc_op_logical_or(43));
 }
\ No newline at end of file
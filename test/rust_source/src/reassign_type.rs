@@ -1,5 +1,6 @@
 fn main() {
-    let mut x = 1;
+    // variable assignment
+    let mut x: i64 = 1;
     println!("x: {}", x);
     x = 2;
     println!("x: {}", x);
@@ -0,0 +1,156 @@
+fn __zinc_encoding_utf8_encode(text: String) -> Vec<u8> {
+    text.into_bytes()
+}
+
+fn __zinc_encoding_utf8_decode(data: &Vec<u8>) -> Result<String, String> {
+    String::from_utf8(data.clone()).map_err(|err| err.to_string())
+}
+
+fn __zinc_encoding_base64_encode(data: &Vec<u8>) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn __zinc_encoding_base64_decode(text: String) -> Result<Vec<u8>, String> {
+    let input = text.trim_end_matches('=').as_bytes();
+    if input.len() % 4 == 1 || text.len() - input.len() > 2 {
+        return Err(format!("invalid base64 length {}", text.len()));
+    }
+    let mut out = Vec::with_capacity(input.len() * 3 / 4);
+    let (mut acc, mut bits) = (0u32, 0u32);
+    for (index, &byte) in input.iter().enumerate() {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return Err(format!("invalid base64 character at position {}", index)),
+        };
+        acc = acc << 6 | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits & 0xff) as u8);
+        }
+    }
+    Ok(out)
+}
+
+fn __zinc_encoding_hex_encode(data: &Vec<u8>) -> String {
+    data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn __zinc_encoding_hex_decode(text: String) -> Result<Vec<u8>, String> {
+    if text.len() % 2 != 0 {
+        return Err(format!("invalid hex length {}", text.len()));
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|index| {
+            text.get(index..index + 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| format!("invalid hex digit at position {}", index))
+        })
+        .collect()
+}
+
+fn std_encoding__base64_decode_String(text: String) -> Result<Vec<u8>, String> {
+    __zinc_encoding_base64_decode(text)
+}
+
+fn std_encoding__base64_encode_Vec_u8(data: &Vec<u8>) -> String {
+    __zinc_encoding_base64_encode(&data)
+}
+
+fn std_encoding__hex_decode_String(text: String) -> Result<Vec<u8>, String> {
+    __zinc_encoding_hex_decode(text)
+}
+
+fn std_encoding__hex_encode_Vec_u8(data: &Vec<u8>) -> String {
+    __zinc_encoding_hex_encode(&data)
+}
+
+fn std_encoding__utf8_decode_Vec_u8(data: &Vec<u8>) -> Result<String, String> {
+    __zinc_encoding_utf8_decode(&data)
+}
+
+fn std_encoding__utf8_encode_String(text: String) -> Vec<u8> {
+    __zinc_encoding_utf8_encode(text)
+}
+
+fn main() {
+    let data = std_encoding__utf8_encode_String(String::from("zinc!"));
+    println!("{}", std_encoding__base64_encode_Vec_u8(&data));
+    println!("{}", std_encoding__hex_encode_Vec_u8(&data));
+    println!("{}", std_encoding__base64_encode_Vec_u8(&std_encoding__utf8_encode_String(String::from(""))));
+    println!("{}", std_encoding__base64_encode_Vec_u8(&std_encoding__utf8_encode_String(String::from("f"))));
+    println!("{}", std_encoding__base64_encode_Vec_u8(&std_encoding__utf8_encode_String(String::from("fo"))));
+    println!("{}", std_encoding__base64_encode_Vec_u8(&std_encoding__utf8_encode_String(String::from("foo"))));
+    {
+        let __zinc_match_93_148 = std_encoding__base64_decode_String(String::from("emluYyE="));
+        match __zinc_match_93_148.clone() {
+            Ok(decoded) => {
+                {
+                    let __zinc_match_107_135 = std_encoding__utf8_decode_Vec_u8(&decoded);
+                    match __zinc_match_107_135.clone() {
+                        Ok(text) => {
+                            println!("{}", text);
+                        },
+                        Err(err) => {
+                            println!("{}", err);
+                        },
+                    }
+                }
+            },
+            Err(err) => {
+                println!("{}", err);
+            },
+        }
+    }
+    {
+        let __zinc_match_149_177 = std_encoding__hex_decode_String(String::from("00ff10"));
+        match __zinc_match_149_177.clone() {
+            Ok(raw) => {
+                println!("{} bytes, last {}", raw.len(), raw[2]);
+            },
+            Err(err) => {
+                println!("{}", err);
+            },
+        }
+    }
+    {
+        let __zinc_match_178_206 = std_encoding__hex_decode_String(String::from("abc"));
+        match __zinc_match_178_206.clone() {
+            Ok(_) => {
+                println!("unexpected");
+            },
+            Err(err) => {
+                println!("{}", err);
+            },
+        }
+    }
+    {
+        let __zinc_match_207_235 = std_encoding__base64_decode_String(String::from("not base64!"));
+        match __zinc_match_207_235.clone() {
+            Ok(_) => {
+                println!("unexpected");
+            },
+            Err(err) => {
+                println!("{}", err);
+            },
+        }
+    }
+}
\ No newline at end of file
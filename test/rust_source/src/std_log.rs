@@ -0,0 +1,101 @@
+fn __zinc_log_rank(level: &str) -> Option<u8> {
+    match level.to_ascii_lowercase().as_str() {
+        "debug" => Some(0),
+        "info" => Some(1),
+        "warn" | "warning" => Some(2),
+        "error" => Some(3),
+        "off" => Some(4),
+        _ => None,
+    }
+}
+
+fn __zinc_log_threshold() -> &'static std::sync::atomic::AtomicU8 {
+    static THRESHOLD: std::sync::OnceLock<std::sync::atomic::AtomicU8> = std::sync::OnceLock::new();
+    THRESHOLD.get_or_init(|| {
+        let level = std::env::var("ZINC_LOG").map_or(1, |value| {
+            __zinc_log_rank(&value).unwrap_or_else(|| {
+                eprintln!("warning: unknown ZINC_LOG level {value:?}; expected debug, info, warn, error, or off; logging at info");
+                1
+            })
+        });
+        std::sync::atomic::AtomicU8::new(level)
+    })
+}
+
+fn __zinc_log_set_level(level: String) {
+    __zinc_log_threshold().store(__zinc_log_rank(&level).unwrap_or(1), std::sync::atomic::Ordering::Relaxed);
+}
+
+fn __zinc_log_enabled(level: String) -> bool {
+    let rank = __zinc_log_rank(&level).unwrap_or(1);
+    rank < 4 && rank >= __zinc_log_threshold().load(std::sync::atomic::Ordering::Relaxed)
+}
+
+fn __zinc_log_timestamp() -> String {
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+    let secs = now.as_secs() as i64;
+    let (days, rem) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60,
+        now.subsec_millis()
+    )
+}
+
+fn __zinc_log_emit(level: String, message: String) {
+    if __zinc_log_enabled(level.clone()) {
+        eprintln!("{} {:<5} {}", __zinc_log_timestamp(), level.to_uppercase(), message);
+    }
+}
+
+fn std_log__debug_String(message: String) {
+    __zinc_log_emit(String::from("debug"), message);
+}
+
+fn std_log__enabled_String(level: String) -> bool {
+    __zinc_log_enabled(level)
+}
+
+fn std_log__error_String(message: String) {
+    __zinc_log_emit(String::from("error"), message);
+}
+
+fn std_log__info_String(message: String) {
+    __zinc_log_emit(String::from("info"), message);
+}
+
+fn std_log__set_level_String(level: String) {
+    __zinc_log_set_level(level);
+}
+
+fn std_log__warn_String(message: String) {
+    __zinc_log_emit(String::from("warn"), message);
+}
+
+fn main() {
+    std_log__info_String(String::from("starting"));
+    println!("{}", std_log__enabled_String(String::from("debug")));
+    println!("{}", std_log__enabled_String(String::from("info")));
+    std_log__set_level_String(String::from("debug"));
+    std_log__debug_String(String::from("now visible"));
+    println!("{}", std_log__enabled_String(String::from("debug")));
+    std_log__set_level_String(String::from("error"));
+    std_log__warn_String(String::from("filtered out"));
+    std_log__error_String(String::from("still visible"));
+    println!("{}", std_log__enabled_String(String::from("warn")));
+    println!("{}", std_log__enabled_String(String::from("error")));
+}
\ No newline at end of file
@@ -0,0 +1,153 @@
+use zinc_internal::{TempPath};
+
+fn __zinc_fs_exists(path: String) -> bool {
+    std::fs::metadata(path).is_ok()
+}
+
+fn __zinc_fs_mkdir(path: String) -> Result<(), String> {
+    std::fs::create_dir_all(path).map_err(|error| error.to_string())
+}
+
+fn __zinc_fs_remove_dir_all(path: String) -> Result<(), String> {
+    std::fs::remove_dir_all(path).map_err(|error| error.to_string())
+}
+
+fn __zinc_fs_read_text(path: String) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|error| error.to_string())
+}
+
+fn __zinc_fs_write_text(path: String, contents: String) -> Result<(), String> {
+    std::fs::write(path, contents).map_err(|error| error.to_string())
+}
+
+fn __zinc_fs_read_lines(path: String) -> Result<Vec<String>, String> {
+    std::fs::read_to_string(path).map(|contents| {
+        contents.lines().map(|line| line.to_string()).collect()
+    }).map_err(|error| error.to_string())
+}
+
+fn __zinc_fs_write_lines(path: String, lines: &Vec<String>) -> Result<(), String> {
+    std::fs::write(path, lines.join("\n")).map_err(|error| error.to_string())
+}
+
+fn __zinc_fs_entry_names(dir: &str) -> Result<Vec<String>, String> {
+    let dir = if dir.is_empty() { "." } else { dir };
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(dir).map_err(|error| error.to_string())? {
+        let entry = entry.map_err(|error| error.to_string())?;
+        names.push(entry.file_name().to_string_lossy().to_string());
+    }
+    names.sort();
+    Ok(names)
+}
+
+fn __zinc_fs_join(base: &str, name: &str) -> String {
+    if base.is_empty() {
+        name.to_string()
+    } else if base.ends_with('/') {
+        format!("{}{}", base, name)
+    } else {
+        format!("{}/{}", base, name)
+    }
+}
+
+fn __zinc_fs_list_dir(path: String) -> Result<Vec<String>, String> {
+    Ok(__zinc_fs_entry_names(&path)?.iter().map(|name| __zinc_fs_join(&path, name)).collect())
+}
+
+fn __zinc_fs_glob_match(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skip| __zinc_fs_glob_match(rest, &name[skip..])),
+        Some(('?', rest)) => !name.is_empty() && __zinc_fs_glob_match(rest, &name[1..]),
+        Some((ch, rest)) => name.first() == Some(ch) && __zinc_fs_glob_match(rest, &name[1..]),
+    }
+}
+
+fn __zinc_fs_glob_walk(base: &str, parts: &[&str], matches: &mut Vec<String>) -> Result<(), String> {
+    let Some((part, rest)) = parts.split_first() else {
+        if !base.is_empty() {
+            matches.push(base.to_string());
+        }
+        return Ok(());
+    };
+    if *part == "**" {
+        __zinc_fs_glob_walk(base, rest, matches)?;
+        for name in __zinc_fs_entry_names(base)? {
+            let child = __zinc_fs_join(base, &name);
+            if std::path::Path::new(&child).is_dir() {
+                __zinc_fs_glob_walk(&child, parts, matches)?;
+            }
+        }
+        return Ok(());
+    }
+    if !part.contains(['*', '?']) {
+        let child = __zinc_fs_join(base, part);
+        if std::fs::metadata(&child).is_ok() {
+            __zinc_fs_glob_walk(&child, rest, matches)?;
+        }
+        return Ok(());
+    }
+    let pattern: Vec<char> = part.chars().collect();
+    for name in __zinc_fs_entry_names(base)? {
+        let chars: Vec<char> = name.chars().collect();
+        if __zinc_fs_glob_match(&pattern, &chars) {
+            __zinc_fs_glob_walk(&__zinc_fs_join(base, &name), rest, matches)?;
+        }
+    }
+    Ok(())
+}
+
+fn __zinc_fs_glob(pattern: String) -> Result<Vec<String>, String> {
+    let root = if pattern.starts_with('/') { "/" } else { "" };
+    let parts: Vec<&str> = pattern.split('/').filter(|part| !part.is_empty()).collect();
+    let mut matches = Vec::new();
+    __zinc_fs_glob_walk(root, &parts, &mut matches)?;
+    matches.sort();
+    matches.dedup();
+    Ok(matches)
+}
+
+fn std_filesystem__exists_String(path: String) -> bool {
+    __zinc_fs_exists(path)
+}
+
+fn std_filesystem__read_text_String(path: String) -> Result<String, String> {
+    __zinc_fs_read_text(path)
+}
+
+fn std_filesystem__write_text_String_String(path: String, contents: String) -> Result<(), String> {
+    __zinc_fs_write_text(path, contents)
+}
+
+fn std_tempfile__stage_report() -> String {
+    let __zinc_temp_path_15_17 = TempPath::dir();
+    let scratch = __zinc_temp_path_15_17.path();
+    std_filesystem__write_text_String_String(format!("{}/report.txt", scratch), String::from("staged"));
+    println!("{}", std_filesystem__exists_String(format!("{}/report.txt", scratch)));
+    scratch
+}
+
+fn main() {
+    let staged_dir = std_tempfile__stage_report();
+    println!("{}", std_filesystem__exists_String(staged_dir));
+    let __zinc_temp_path_59_61 = TempPath::dir();
+    let workspace = __zinc_temp_path_59_61.path();
+    std_filesystem__write_text_String_String(format!("{}/draft.txt", workspace), String::from("draft"));
+    {
+        let __zinc_match_70_102 = std_filesystem__read_text_String(format!("{}/draft.txt", workspace));
+        match __zinc_match_70_102.clone() {
+            Ok(contents) => {
+                println!("{}", contents);
+            },
+            Err(error) => {
+                println!("read_text error");
+            },
+        }
+    }
+    for round in 0..2 {
+        let __zinc_temp_path_112_114 = TempPath::file();
+        let scratch = __zinc_temp_path_112_114.path();
+        println!("{}", std_filesystem__exists_String(scratch.to_string()));
+    }
+}
\ No newline at end of file
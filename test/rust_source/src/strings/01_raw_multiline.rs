@@ -8,20 +8,17 @@ impl Default for strings_01_raw_multiline__Message {
     }
 }
 
-fn strings_01_raw_multiline__echo_String(text: String) {
+fn strings_01_raw_multiline__echo_String(text: &str) {
     println!("{}", text);
 }
 
 fn main() {
-    let poem = r"roses are red
-    violets are blue";
+    let poem = "roses are red\nviolets are blue";
     let tick = r"literal backtick: `";
     let braces = r"{not interpolation}";
     let path = r"c:\temp\logs";
-    strings_01_raw_multiline__echo_String(String::from(r"from helper
-    second line"));
-    let msg = strings_01_raw_multiline__Message { body: String::from(r#"struct field
-    with "quotes" and \slashes\"#) };
+    strings_01_raw_multiline__echo_String("from helper\nsecond line");
+    let msg = strings_01_raw_multiline__Message { body: String::from("struct field\nwith \"quotes\" and \\slashes\\") };
     println!("{}", poem);
     println!("{}", tick);
     println!("{}", braces);
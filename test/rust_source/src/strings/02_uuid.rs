@@ -0,0 +1,8 @@
+use zinc_internal::{uuid_v4};
+
+fn main() {
+    let first = uuid_v4();
+    let second = uuid_v4();
+    println!("{}", first.len() as i64);
+    println!("{}", first != second);
+}
\ No newline at end of file
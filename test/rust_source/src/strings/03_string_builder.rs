@@ -0,0 +1,69 @@
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone)]
+struct __ZincClosureEnv_strings_03_string_builder___lambda_strings_03_string_builder__main_123_136 {
+    log: Arc<Mutex<String>>,
+}
+
+#[derive(Clone)]
+enum __ZincCallable_String_to_Unit {
+    Closed,
+    V0(__ZincClosureEnv_strings_03_string_builder___lambda_strings_03_string_builder__main_123_136),
+}
+
+impl Default for __ZincCallable_String_to_Unit {
+    fn default() -> Self {
+        Self::Closed
+    }
+}
+
+impl __ZincCallable_String_to_Unit {
+    fn call(&self, arg_0: String) {
+        match self {
+            Self::Closed => panic!("callable used after closed receive"),
+            Self::V0(env) => { strings_03_string_builder____lambda_strings_03_string_builder__main_123_136_String(env.clone(), arg_0); }
+        }
+    }
+}
+
+fn strings_03_string_builder____lambda_strings_03_string_builder__main_123_136_String(__env: __ZincClosureEnv_strings_03_string_builder___lambda_strings_03_string_builder__main_123_136, line: String) {
+    let __zv_strings_03_string_builder____lambda_strings_03_string_builder__main_123_136_String_log_String = __env.log.clone();
+    let __zinc_captured_compound_133_135 = format!("{};", line);
+    __zv_strings_03_string_builder____lambda_strings_03_string_builder__main_123_136_String_log_String.lock().unwrap().push_str(&__zinc_captured_compound_133_135);
+}
+
+fn strings_03_string_builder__exclaim_String_i64(mut text: String, times: i64) -> String {
+    for _ in 0..times {
+        text.push_str("!");
+    }
+    text
+}
+
+fn strings_03_string_builder__greeting_String(name: String) -> String {
+    format!("hello, {}", name)
+}
+
+fn main() {
+    let mut csv = String::from("");
+    for word in vec!["red", "green", "blue"].iter().cloned() {
+        csv.push_str(&word);
+        csv.push_str(",");
+    }
+    println!("{}", csv);
+    let mut squares = String::from("squares:");
+    for n in 1..5 {
+        squares.push_str(&format!(" {}", n * n));
+    }
+    println!("{}", squares);
+    let mut echo = String::from("ab");
+    let __zinc_appended_97_97 = echo.to_string();
+    echo.push_str(&__zinc_appended_97_97);
+    println!("{}", echo);
+    println!("{}", strings_03_string_builder__greeting_String(String::from("zinc")));
+    println!("{}", strings_03_string_builder__exclaim_String_i64(String::from("wow"), 3));
+    let __zv_strings_03_string_builder__main_log_String = Arc::new(Mutex::new(String::from("")));
+    let record = __ZincCallable_String_to_Unit::V0(__ZincClosureEnv_strings_03_string_builder___lambda_strings_03_string_builder__main_123_136 { log: __zv_strings_03_string_builder__main_log_String.clone() });
+    record.call(String::from("start"));
+    record.call(String::from("stop"));
+    println!("{}", __zv_strings_03_string_builder__main_log_String.lock().unwrap().clone());
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use zinc_internal::{format_number};
+
+fn main() {
+    let items = vec!["licenses", "support", "training"];
+    let amounts = vec![1234567.891, 98000.5, -2500.0];
+    let mut total = 0.0;
+    for i in 0..items.len() as i64 {
+        total = total + amounts[i as usize];
+        println!("{}: {}", items[i as usize], format_number(amounts[i as usize], ",.2f"));
+    }
+    println!("total: {}", format_number(total, ",.2f"));
+    println!("support share: {}", format_number(amounts[1] / total, ".1%"));
+    println!("{}", format_number(1500000.0, ","));
+    println!("{}", format_number(42.0, ".3"));
+    println!("{}", format_number(0.5, "%"));
+    println!("{}", format_number(total, ".3e"));
+    println!("{}", format_number(0.000042, "e"));
+    println!("{}", format_number(7.25, ""));
+}
\ No newline at end of file
@@ -10,6 +10,7 @@ impl Default for structs_01_basic_fields__Counter {
     }
 }
 
+// Test: Basic struct fields with explicit types and default values
 struct structs_01_basic_fields__Point {
     pub x: i32,
     pub y: i32,
@@ -22,9 +23,11 @@ impl Default for structs_01_basic_fields__Point {
 }
 
 fn main() {
+    // Create Point with explicit field values
     let p = structs_01_basic_fields__Point { x: 10, y: 20 };
     println!("{}", p.x);
     println!("{}", p.y);
+    // Create Counter - uses default values for count and step
     let c = structs_01_basic_fields__Counter { count: 0, step: 1, name: String::from("my_counter") };
     println!("{}", c.count);
     println!("{}", c.step);
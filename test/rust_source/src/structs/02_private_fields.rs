@@ -1,3 +1,5 @@
+// Test: Private fields (underscore prefix)
+// Fields starting with _ are private (no pub in Rust output)
 struct structs_02_private_fields__User {
     _id: i32,
     _password: String,
@@ -11,9 +13,23 @@ impl Default for structs_02_private_fields__User {
     }
 }
 
+impl structs_02_private_fields__User {
+    // Private fields are read through methods of the struct
+    fn id(&self) -> i32 {
+        self._id
+    }
+    fn password_matches(&self, attempt: String) -> bool {
+        attempt == self._password
+    }
+}
+
 fn main() {
+    // Create user with all fields
     let user = structs_02_private_fields__User { _id: 42, _password: String::from("secret123"), username: String::from("alice"), email: String::from("alice@example.com") };
+    // Access public fields
     println!("{}", user.username);
     println!("{}", user.email);
-    println!("{}", user._id);
+    // Private fields are only accessible through the struct's methods
+    println!("{}", user.id());
+    println!("{}", user.password_matches(String::from("secret123")));
 }
\ No newline at end of file
@@ -9,6 +9,7 @@ impl Default for structs_03_const_fields__Circle {
     }
 }
 
+// Test: Const fields (immutable after initialization)
 struct structs_03_const_fields__Config {
     pub max_retries: i64,
     pub timeout: i64,
@@ -23,9 +24,11 @@ impl Default for structs_03_const_fields__Config {
 }
 
 fn main() {
+    // Create config - const fields use defaults or must be set once
     let cfg = structs_03_const_fields__Config { max_retries: 3, timeout: 30, api_version: String::from("v1.0"), name: String::from("my_app") };
     println!("{}", cfg.max_retries);
     println!("{}", cfg.timeout);
+    // Create circle
     let circle = structs_03_const_fields__Circle { pi: 3.14159, radius: 5.0 };
     println!("{}", circle.radius);
 }
\ No newline at end of file
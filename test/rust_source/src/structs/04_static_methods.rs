@@ -1,3 +1,5 @@
+// Test: Static methods (methods that don't use self)
+// Methods are automatically static if they never reference 'self'
 struct structs_04_static_methods__Calculator {
     pub value: i32,
 }
@@ -9,22 +11,27 @@ impl Default for structs_04_static_methods__Calculator {
 }
 
 impl structs_04_static_methods__Calculator {
+    // Static method - returns a constant
     fn pi() -> i64 {
-        return 3;
+        3
     }
+    // Static method - constructor pattern
     fn new(initial: i32) -> Self {
-        return structs_04_static_methods__Calculator { value: initial };
+        structs_04_static_methods__Calculator { value: initial }
     }
+    // Static method - factory with default
     fn zero() -> Self {
-        return structs_04_static_methods__Calculator { value: 0 };
+        structs_04_static_methods__Calculator { value: 0 }
     }
 }
 
 fn main() {
     let pi_value = structs_04_static_methods__Calculator::pi();
     println!("{}", pi_value);
-    let calc = structs_04_static_methods__Calculator::new((100) as i32);
+    // Use static constructor
+    let calc = structs_04_static_methods__Calculator::new(100);
     println!("{}", calc.value);
+    // Use factory method
     let zero_calc = structs_04_static_methods__Calculator::zero();
     println!("{}", zero_calc.value);
 }
\ No newline at end of file
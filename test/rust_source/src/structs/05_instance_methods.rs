@@ -1,3 +1,6 @@
+// Test: Instance methods and self mutability
+// - Methods that read self get &self
+// - Methods that write to self get &mut self
 struct structs_05_instance_methods__Counter {
     pub count: i64,
     pub step: i64,
@@ -10,36 +13,42 @@ impl Default for structs_05_instance_methods__Counter {
 }
 
 impl structs_05_instance_methods__Counter {
+    // Instance method - reads self (becomes &self)
     fn get_count(&self) -> i64 {
-        return self.count;
-    }
-    fn get_step(&self) -> i64 {
-        return self.step;
+        self.count
     }
+    // Instance method - writes to self (becomes &mut self)
     fn increment(&mut self) {
-        self.count = (self.count + self.step);
+        self.count = self.count + self.step;
     }
+    // Instance method - writes to self (becomes &mut self)
     fn reset(&mut self) {
         self.count = 0;
     }
+    // Instance method - writes to self (becomes &mut self)
     fn set_step(&mut self, new_step: i64) {
         self.step = new_step;
     }
+    // Static constructor
     fn new(initial: i64, step: i64) -> Self {
-        return structs_05_instance_methods__Counter { count: initial, step: step };
+        structs_05_instance_methods__Counter { count: initial, step }
     }
 }
 
 fn main() {
     let mut counter = structs_05_instance_methods__Counter::new(0, 5);
+    // Read initial value
     println!("{}", counter.get_count());
+    // Increment a few times
     counter.increment();
     println!("{}", counter.get_count());
     counter.increment();
     println!("{}", counter.get_count());
+    // Change step and increment
     counter.set_step(10);
     counter.increment();
     println!("{}", counter.get_count());
+    // Reset
     counter.reset();
     println!("{}", counter.get_count());
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
+// Test: String interpolation in methods
+// Strings with {expr} are converted to format!() macro
 struct structs_06_string_interpolation__Person {
     pub name: String,
     pub age: i32,
@@ -10,14 +12,16 @@ impl Default for structs_06_string_interpolation__Person {
 }
 
 impl structs_06_string_interpolation__Person {
+    // Method using string interpolation with self fields
     fn greeting(&self) -> String {
-        return format!("Hello, my name is {}", self.name);
+        format!("Hello, my name is {}", self.name)
     }
+    // Method with multiple interpolations
     fn describe(&self) -> String {
-        return format!("Person: {}, Age: {}", self.name, self.age);
+        format!("Person: {}, Age: {}", self.name, self.age)
     }
     fn new(name: String, age: i32) -> Self {
-        return structs_06_string_interpolation__Person { name: name, age: age };
+        structs_06_string_interpolation__Person { name, age }
     }
 }
 
@@ -34,15 +38,15 @@ impl Default for structs_06_string_interpolation__Rectangle {
 
 impl structs_06_string_interpolation__Rectangle {
     fn area(&self) -> i32 {
-        return (self.width * self.height);
+        self.width * self.height
     }
     fn describe(&self) -> String {
-        return format!("Rectangle {}x{}", self.width, self.height);
+        format!("Rectangle {}x{}", self.width, self.height)
     }
 }
 
 fn main() {
-    let person = structs_06_string_interpolation__Person::new(String::from("Alice"), (30) as i32);
+    let person = structs_06_string_interpolation__Person::new(String::from("Alice"), 30);
     println!("{}", person.greeting());
     println!("{}", person.describe());
     let rect = structs_06_string_interpolation__Rectangle { width: 10, height: 5 };
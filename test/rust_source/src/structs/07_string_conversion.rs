@@ -10,10 +10,14 @@ impl Default for structs_07_string_conversion__Greeting {
 
 impl structs_07_string_conversion__Greeting {
     fn new(text: String) -> Self {
-        return structs_07_string_conversion__Greeting { text: text };
+        structs_07_string_conversion__Greeting { text }
     }
 }
 
+// Test: String literal to String::from() conversion
+// String literals are automatically converted when:
+// - Assigned to String-typed struct fields
+// - Passed as arguments to methods expecting String parameters
 struct structs_07_string_conversion__Message {
     pub content: String,
     pub sender: String,
@@ -28,23 +32,27 @@ impl Default for structs_07_string_conversion__Message {
 
 impl structs_07_string_conversion__Message {
     fn new(content: String, sender: String) -> Self {
-        return structs_07_string_conversion__Message { content: content, sender: sender, priority: 0 };
+        structs_07_string_conversion__Message { content, sender, priority: 0 }
     }
     fn with_priority(content: String, sender: String, priority: i32) -> Self {
-        return structs_07_string_conversion__Message { content: content, sender: sender, priority: priority };
+        structs_07_string_conversion__Message { content, sender, priority }
     }
 }
 
 fn main() {
+    // String literal in struct literal - converted to String::from()
     let msg1 = structs_07_string_conversion__Message { content: String::from("Hello World"), sender: String::from("Alice"), priority: 1 };
     println!("{}", msg1.content);
     println!("{}", msg1.sender);
+    // String literal passed to method - converted to String::from()
     let msg2 = structs_07_string_conversion__Message::new(String::from("Test message"), String::from("Bob"));
     println!("{}", msg2.content);
     println!("{}", msg2.sender);
-    let msg3 = structs_07_string_conversion__Message::with_priority(String::from("Urgent"), String::from("Admin"), (10) as i32);
+    // Another method with string args
+    let msg3 = structs_07_string_conversion__Message::with_priority(String::from("Urgent"), String::from("Admin"), 10);
     println!("{}", msg3.content);
     println!("{}", msg3.priority);
+    // Simple constructor
     let greeting = structs_07_string_conversion__Greeting::new(String::from("Welcome!"));
     println!("{}", greeting.text);
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
+// Test: Comprehensive integration test
+// Combines multiple struct features together
 struct structs_08_integration__BankAccount {
     _account_number: i32,
     _balance: i32,
@@ -12,23 +14,29 @@ impl Default for structs_08_integration__BankAccount {
 }
 
 impl structs_08_integration__BankAccount {
+    // Static constructor
     fn new(owner: String, account_number: i32, initial_balance: i32) -> Self {
-        return structs_08_integration__BankAccount { _account_number: account_number, _balance: initial_balance, owner: owner, bank_name: String::from("Zinc Bank") };
+        structs_08_integration__BankAccount { _account_number: account_number, _balance: initial_balance, owner, bank_name: String::from("Zinc Bank") }
     }
+    // Instance method - reads self (&self)
     fn get_balance(&self) -> i32 {
-        return self._balance;
+        self._balance
     }
+    // Instance method - writes self (&mut self)
     fn deposit(&mut self, amount: i32) {
-        self._balance = (self._balance + amount);
+        self._balance = self._balance + amount;
     }
+    // Instance method - writes self (&mut self)
     fn withdraw(&mut self, amount: i32) {
-        self._balance = (self._balance - amount);
+        self._balance = self._balance - amount;
     }
+    // Instance method with string interpolation (&self)
     fn summary(&self) -> String {
-        return format!("Account for {} at {}", self.owner, self.bank_name);
+        format!("Account for {} at {}", self.owner, self.bank_name)
     }
+    // Static utility method
     fn transfer_fee() -> i64 {
-        return 5;
+        5
     }
 }
 
@@ -47,34 +55,38 @@ impl Default for structs_08_integration__Transaction {
 
 impl structs_08_integration__Transaction {
     fn new(from: String, to: String, amount: i32) -> Self {
-        return structs_08_integration__Transaction { from_account: from, to_account: to, amount: amount, _processed: false };
+        structs_08_integration__Transaction { from_account: from, to_account: to, amount, _processed: false }
     }
     fn mark_processed(&mut self) {
         self._processed = true;
     }
-    fn is_processed(&self) -> bool {
-        return self._processed;
-    }
     fn describe(&self) -> String {
-        return format!("Transfer {} from {} to {}", self.amount, self.from_account, self.to_account);
+        format!("Transfer {} from {} to {}", self.amount, self.from_account, self.to_account)
     }
 }
 
 fn main() {
-    let mut alice_account = structs_08_integration__BankAccount::new(String::from("Alice"), (1001) as i32, (1000) as i32);
-    let mut bob_account = structs_08_integration__BankAccount::new(String::from("Bob"), (1002) as i32, (500) as i32);
+    // Create accounts
+    let mut alice_account = structs_08_integration__BankAccount::new(String::from("Alice"), 1001, 1000);
+    let mut bob_account = structs_08_integration__BankAccount::new(String::from("Bob"), 1002, 500);
+    // Print summaries
     println!("{}", alice_account.summary());
     println!("{}", bob_account.summary());
+    // Check balances
     println!("{}", alice_account.get_balance());
     println!("{}", bob_account.get_balance());
-    alice_account.deposit((200) as i32);
+    // Alice deposits money
+    alice_account.deposit(200);
     println!("{}", alice_account.get_balance());
-    let mut tx = structs_08_integration__Transaction::new(String::from("Alice"), String::from("Bob"), (100) as i32);
+    // Create and process a transaction
+    let mut tx = structs_08_integration__Transaction::new(String::from("Alice"), String::from("Bob"), 100);
     println!("{}", tx.describe());
+    // Simulate transfer
     let fee = structs_08_integration__BankAccount::transfer_fee();
-    alice_account.withdraw(((100 + fee)) as i32);
-    bob_account.deposit((100) as i32);
+    alice_account.withdraw((100 + fee) as i32);
+    bob_account.deposit(100);
     tx.mark_processed();
+    // Final balances
     println!("{}", alice_account.get_balance());
     println!("{}", bob_account.get_balance());
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+// Test: Orthogonal struct composition with disjoint fields and methods
 struct structs_09_composition_orthogonal__File {
     pub path: String,
 }
@@ -10,7 +11,7 @@ impl Default for structs_09_composition_orthogonal__File {
 
 impl structs_09_composition_orthogonal__File {
     fn file_label(&self) -> String {
-        return format!("file:{}", self.path);
+        format!("file:{}", self.path)
     }
 }
 
@@ -29,13 +30,13 @@ impl Default for structs_09_composition_orthogonal__Item {
 
 impl structs_09_composition_orthogonal__Item {
     fn file_label(&self) -> String {
-        return format!("file:{}", self.path);
+        format!("file:{}", self.path)
     }
     fn summary(&self) -> String {
-        return format!("{}@{}", self.text, self.timestamp);
+        format!("{}@{}", self.text, self.timestamp)
     }
     fn owner(&self) -> i32 {
-        return self.owner_id;
+        self.owner_id
     }
 }
 
@@ -52,7 +53,7 @@ impl Default for structs_09_composition_orthogonal__Message {
 
 impl structs_09_composition_orthogonal__Message {
     fn summary(&self) -> String {
-        return format!("{}@{}", self.text, self.timestamp);
+        format!("{}@{}", self.text, self.timestamp)
     }
 }
 
@@ -1,3 +1,4 @@
+// Test: Last-wins struct composition with local overrides
 struct structs_10_composition_merge_fields__File {
     pub id: i32,
     pub path: String,
@@ -11,7 +12,7 @@ impl Default for structs_10_composition_merge_fields__File {
 
 impl structs_10_composition_merge_fields__File {
     fn source() -> i64 {
-        return 1;
+        1
     }
 }
 
@@ -29,13 +30,13 @@ impl Default for structs_10_composition_merge_fields__Item {
 
 impl structs_10_composition_merge_fields__Item {
     fn source() -> i64 {
-        return 2;
+        2
     }
     fn label(&self) -> String {
-        return format!("local {}", self.id);
+        format!("local {}", self.id)
     }
     fn describe(&self) -> String {
-        return format!("{}:{}:{}", self.id, self.path, self.text);
+        format!("{}:{}:{}", self.id, self.path, self.text)
     }
 }
 
@@ -52,7 +53,7 @@ impl Default for structs_10_composition_merge_fields__Message {
 
 impl structs_10_composition_merge_fields__Message {
     fn source() -> i64 {
-        return 2;
+        2
     }
 }
 
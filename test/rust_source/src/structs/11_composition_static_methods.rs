@@ -1,3 +1,4 @@
+// Test: Copied static methods and constructor rewriting in composed structs
 struct structs_11_composition_static_methods__File {
     pub path: String,
 }
@@ -10,10 +11,10 @@ impl Default for structs_11_composition_static_methods__File {
 
 impl structs_11_composition_static_methods__File {
     fn new(path: String) -> Self {
-        return structs_11_composition_static_methods__File { path: path };
+        structs_11_composition_static_methods__File { path }
     }
     fn kind() -> i64 {
-        return 1;
+        1
     }
 }
 
@@ -31,13 +32,13 @@ impl Default for structs_11_composition_static_methods__Item {
 
 impl structs_11_composition_static_methods__Item {
     fn new(path: String) -> Self {
-        return structs_11_composition_static_methods__Item { path: path, text: String::new(), timestamp: 0 };
+        structs_11_composition_static_methods__Item { path, text: String::new(), timestamp: 0 }
     }
     fn kind() -> i64 {
-        return 1;
+        1
     }
     fn with_text(text: String) -> Self {
-        return structs_11_composition_static_methods__Item { path: String::new(), text: text, timestamp: 0 };
+        structs_11_composition_static_methods__Item { path: String::new(), text, timestamp: 0 }
     }
 }
 
@@ -54,7 +55,7 @@ impl Default for structs_11_composition_static_methods__Message {
 
 impl structs_11_composition_static_methods__Message {
     fn with_text(text: String) -> Self {
-        return structs_11_composition_static_methods__Message { text: text, timestamp: 0 };
+        structs_11_composition_static_methods__Message { text, timestamp: 0 }
     }
 }
 
@@ -12,7 +12,7 @@ impl Default for structs_12_composition_imported__Post {
 
 impl structs_12_composition_imported__Post {
     fn creator(&self) -> String {
-        return format!("{}", self.created_by);
+        self.created_by.to_string()
     }
 }
 
@@ -28,7 +28,7 @@ impl Default for structs__composition_parts__Audit {
 
 impl structs__composition_parts__Audit {
     fn creator(&self) -> String {
-        return format!("{}", self.created_by);
+        self.created_by.to_string()
     }
 }
 
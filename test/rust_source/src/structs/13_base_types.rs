@@ -1,3 +1,4 @@
+// Test: Explicit base-type annotations across stable primitive widths
 struct structs_13_base_types__BaseTypes {
     pub signed8: i8,
     pub signed16: i16,
@@ -23,27 +24,27 @@ impl Default for structs_13_base_types__BaseTypes {
 
 impl structs_13_base_types__BaseTypes {
     fn new(signed8: i8, signed16: i16, signed32: i32, signed64: i64, signed128: i128, unsigned8: u8, unsigned16: u16, unsigned32: u32, unsigned64: u64, unsigned128: u128, float32: f32, float64: f64, text: String, flag: bool) -> Self {
-        return structs_13_base_types__BaseTypes { signed8: signed8, signed16: signed16, signed32: signed32, signed64: signed64, signed128: signed128, unsigned8: unsigned8, unsigned16: unsigned16, unsigned32: unsigned32, unsigned64: unsigned64, unsigned128: unsigned128, float32: float32, float64: float64, text: text, flag: flag };
+        structs_13_base_types__BaseTypes { signed8, signed16, signed32, signed64, signed128, unsigned8, unsigned16, unsigned32, unsigned64, unsigned128, float32, float64, text, flag }
     }
     fn signed8_value(&self) -> i8 {
-        return self.signed8;
+        self.signed8
     }
     fn unsigned128_value(&self) -> u128 {
-        return self.unsigned128;
+        self.unsigned128
     }
     fn float32_value(&self) -> f32 {
-        return self.float32;
+        self.float32
     }
     fn float64_value(&self) -> f64 {
-        return self.float64;
+        self.float64
     }
     fn flag_value(&self) -> bool {
-        return self.flag;
+        self.flag
     }
 }
 
 fn main() {
-    let values = structs_13_base_types__BaseTypes::new((-8), (-16), (-32), (-64), (-128), 8, 16, 32, 64, 128, 3.25, 6.5, String::from("hello"), true);
+    let values = structs_13_base_types__BaseTypes::new(-8, -16, -32, -64, -128, 8, 16, 32, 64, 128, 3.25, 6.5, String::from("hello"), true);
     println!("{}", values.signed8);
     println!("{}", values.signed16);
     println!("{}", values.signed32);
@@ -16,6 +16,7 @@ struct __ZincAnonStruct_AnonStruct_meta_AnonStruct_active_bool_author_String_sta
     stats: __ZincAnonStruct_AnonStruct_likes_i64_views_i64,
 }
 
+// Test: Anonymous structs may be nested and accessed recursively.
 fn main() {
     let post = __ZincAnonStruct_AnonStruct_meta_AnonStruct_active_bool_author_String_stats_AnonStruct_likes_i64_views_i64 { meta: __ZincAnonStruct_AnonStruct_active_bool_author_String { author: String::from("alice"), active: true }, stats: __ZincAnonStruct_AnonStruct_likes_i64_views_i64 { views: 10, likes: 2 } };
     println!("{}", post.meta.author);
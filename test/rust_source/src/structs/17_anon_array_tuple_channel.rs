@@ -11,6 +11,7 @@ struct __ZincAnonStruct_AnonStruct_ready_bool {
     ready: bool,
 }
 
+// Test: Arrays, tuples, and channels can carry anonymous struct values.
 #[tokio::main]
 async fn main() {
     let items = vec![__ZincAnonStruct_AnonStruct_id_i64_name_String { id: 1, name: String::from("one") }, __ZincAnonStruct_AnonStruct_id_i64_name_String { id: 2, name: String::from("two") }];
@@ -4,6 +4,7 @@ struct __ZincAnonStruct_AnonStruct_x_i64_y_i64 {
     y: i64,
 }
 
+// Test: Named structs can contain anonymous struct-typed fields.
 struct structs_18_anon_named_struct_field__Holder {
     pub point: __ZincAnonStruct_AnonStruct_x_i64_y_i64,
     pub label: String,
@@ -15,7 +16,7 @@ impl Default for structs_18_anon_named_struct_field__Holder {
     }
 }
 
-fn structs_18_anon_named_struct_field__show_Struct_structs_18_anon_named_struct_field_Holder(holder: structs_18_anon_named_struct_field__Holder) {
+fn structs_18_anon_named_struct_field__show_Struct_structs_18_anon_named_struct_field_Holder(holder: &structs_18_anon_named_struct_field__Holder) {
     println!("{}", holder.point.x);
     println!("{}", holder.label);
 }
@@ -23,5 +24,5 @@ fn structs_18_anon_named_struct_field__show_Struct_structs_18_anon_named_struct_
 fn main() {
     let holder = structs_18_anon_named_struct_field__Holder { point: __ZincAnonStruct_AnonStruct_x_i64_y_i64 { y: 9, x: 7 }, label: String::from("origin") };
     println!("{}", holder.point.y);
-    structs_18_anon_named_struct_field__show_Struct_structs_18_anon_named_struct_field_Holder(holder);
+    structs_18_anon_named_struct_field__show_Struct_structs_18_anon_named_struct_field_Holder(&holder);
 }
\ No newline at end of file
@@ -44,7 +44,7 @@ impl Default for structs_19_struct_spread__PartialConfig {
 }
 
 fn structs_19_struct_spread__make_config() -> structs_19_struct_spread__Config {
-    return structs_19_struct_spread__Config { a: 7, name: String::from("made"), enabled: true, ratio: 4.5 };
+    structs_19_struct_spread__Config { a: 7, name: String::from("made"), enabled: true, ratio: 4.5 }
 }
 
 fn main() {
@@ -66,12 +66,12 @@ impl Default for structs_20_struct_spread_edges__Nested {
 
 fn structs_20_struct_spread_edges__ignored_config() -> structs_20_struct_spread_edges__Config {
     println!("ignore config");
-    return structs_20_struct_spread_edges__Config { a: 9, name: String::from("ignored"), enabled: true, ratio: 9.5 };
+    structs_20_struct_spread_edges__Config { a: 9, name: String::from("ignored"), enabled: true, ratio: 9.5 }
 }
 
 fn structs_20_struct_spread_edges__noisy_config() -> structs_20_struct_spread_edges__Config {
     println!("make config");
-    return structs_20_struct_spread_edges__Config { a: 8, name: String::from("made"), enabled: true, ratio: 6.5 };
+    structs_20_struct_spread_edges__Config { a: 8, name: String::from("made"), enabled: true, ratio: 6.5 }
 }
 
 fn main() {
@@ -0,0 +1,77 @@
+use std::collections::{BTreeMap};
+
+#[derive(Clone, Default)]
+struct __ZincAnonStruct_AnonStruct_size_i64_tag_String {
+    size: i64,
+    tag: String,
+}
+
+impl std::fmt::Debug for __ZincAnonStruct_AnonStruct_size_i64_tag_String {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("struct").field("size", &self.size).field("tag", &self.tag).finish()
+    }
+}
+
+#[derive(Clone)]
+enum structs_21_debug_print__Shape {
+    Empty,
+    Circle { radius: f64 },
+}
+
+impl std::fmt::Debug for structs_21_debug_print__Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => f.write_str("Shape.Empty"),
+            Self::Circle { radius } => f.debug_struct("Shape.Circle").field("radius", radius).finish(),
+        }
+    }
+}
+
+struct structs_21_debug_print__Path {
+    pub name: String,
+    pub points: Vec<structs_21_debug_print__Point>,
+}
+
+impl Default for structs_21_debug_print__Path {
+    fn default() -> Self {
+        Self { name: String::new(), points: Default::default() }
+    }
+}
+
+impl std::fmt::Debug for structs_21_debug_print__Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Path").field("name", &self.name).field("points", &self.points).finish()
+    }
+}
+
+// Test: print_debug() pretty-prints nested values with field names.
+struct structs_21_debug_print__Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Default for structs_21_debug_print__Point {
+    fn default() -> Self {
+        Self { x: 0, y: 0 }
+    }
+}
+
+impl std::fmt::Debug for structs_21_debug_print__Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Point").field("x", &self.x).field("y", &self.y).finish()
+    }
+}
+
+fn main() {
+    let path = structs_21_debug_print__Path { name: String::from("route"), points: vec![structs_21_debug_print__Point { x: 1, y: 2 }, structs_21_debug_print__Point { x: 3, y: 4 }] };
+    println!("{:#?}", path);
+    let shapes = vec![structs_21_debug_print__Shape::Empty, structs_21_debug_print__Shape::Circle { radius: 1.5 }];
+    println!("{:#?}", shapes);
+    let mut scores = BTreeMap::<String, i64>::new();
+    scores.insert(String::from("ada"), 3);
+    scores.insert(String::from("bob"), 1);
+    println!("{:#?}", scores);
+    let meta = __ZincAnonStruct_AnonStruct_size_i64_tag_String { tag: String::from("anon"), size: 2 };
+    println!("{:#?}", meta);
+    eprintln!("[structs/21_debug_print.zn:36] path.points[1].x = {:#?}", path.points[1].x);
+}
\ No newline at end of file
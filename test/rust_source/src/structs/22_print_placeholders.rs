@@ -0,0 +1,109 @@
+#[derive(Clone)]
+enum structs_22_print_placeholders__Shape {
+    Dot,
+    Circle { radius: f64 },
+}
+
+impl std::fmt::Debug for structs_22_print_placeholders__Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dot => f.write_str("Shape.Dot"),
+            Self::Circle { radius } => f.debug_struct("Shape.Circle").field("radius", radius).finish(),
+        }
+    }
+}
+
+impl std::fmt::Display for structs_22_print_placeholders__Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+struct structs_22_print_placeholders__Line {
+    pub start: structs_22_print_placeholders__Point,
+    pub end: structs_22_print_placeholders__Point,
+}
+
+impl Default for structs_22_print_placeholders__Line {
+    fn default() -> Self {
+        Self { start: Default::default(), end: Default::default() }
+    }
+}
+
+impl std::fmt::Debug for structs_22_print_placeholders__Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Line").field("start", &self.start).field("end", &self.end).finish()
+    }
+}
+
+impl std::fmt::Display for structs_22_print_placeholders__Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+struct structs_22_print_placeholders__Money {
+    pub cents: i64,
+}
+
+impl Default for structs_22_print_placeholders__Money {
+    fn default() -> Self {
+        Self { cents: 0 }
+    }
+}
+
+impl std::fmt::Debug for structs_22_print_placeholders__Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Money").field("cents", &self.cents).finish()
+    }
+}
+
+impl std::fmt::Display for structs_22_print_placeholders__Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_string())
+    }
+}
+
+impl structs_22_print_placeholders__Money {
+    // print() and "{}" use a to_string() method when the type declares one.
+    fn to_string(&self) -> String {
+        format!("${}.{}", self.cents / 100, self.cents % 100)
+    }
+}
+
+// Test: print() shows structs and enums, and fills {} placeholders with its other arguments.
+struct structs_22_print_placeholders__Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Default for structs_22_print_placeholders__Point {
+    fn default() -> Self {
+        Self { x: 0, y: 0 }
+    }
+}
+
+impl std::fmt::Debug for structs_22_print_placeholders__Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Point").field("x", &self.x).field("y", &self.y).finish()
+    }
+}
+
+impl std::fmt::Display for structs_22_print_placeholders__Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+fn main() {
+    let p = structs_22_print_placeholders__Point { x: 1, y: 2 };
+    println!("{}", p);
+    println!("{}", structs_22_print_placeholders__Line { start: structs_22_print_placeholders__Point { x: 0, y: 0 }, end: structs_22_print_placeholders__Point { x: 3, y: 4 } });
+    let price = structs_22_print_placeholders__Money { cents: 1250 };
+    println!("{}", price);
+    println!("total: {}", price);
+    println!("{}", structs_22_print_placeholders__Shape::Dot);
+    println!("{} is a {}", p, structs_22_print_placeholders__Shape::Circle { radius: 1.5 });
+    let count: i64 = 3;
+    println!("{} points cost {} each", count, price);
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+struct structs_23_constructor_shorthand__Person {
+    pub name: String,
+    pub age: i64,
+    pub city: String,
+}
+
+impl Default for structs_23_constructor_shorthand__Person {
+    fn default() -> Self {
+        Self { name: String::new(), age: 0, city: String::from("Springfield") }
+    }
+}
+
+struct structs_23_constructor_shorthand__Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Default for structs_23_constructor_shorthand__Point {
+    fn default() -> Self {
+        Self { x: 0, y: 0 }
+    }
+}
+
+impl structs_23_constructor_shorthand__Point {
+    fn new(x: i64, y: i64) -> Self {
+        structs_23_constructor_shorthand__Point { x: x * 10, y: y * 10 }
+    }
+}
+
+fn structs_23_constructor_shorthand__describe_Struct_structs_23_constructor_shorthand_Person(person: &structs_23_constructor_shorthand__Person) -> String {
+    format!("{} ({})", person.name, person.age)
+}
+
+fn main() {
+    let alice = structs_23_constructor_shorthand__Person { name: String::from("Alice"), age: 30, city: String::from("Springfield") };
+    let bob = structs_23_constructor_shorthand__Person { name: String::from("Bob"), age: 41, city: String::from("Shelbyville") };
+    let carol = structs_23_constructor_shorthand__Person { name: String::from("Carol"), age: bob.age, city: bob.city.clone() };
+    println!("{} {} {}", alice.name, alice.age, alice.city);
+    println!("{} {} {}", bob.name, bob.age, bob.city);
+    println!("{} {} {}", carol.name, carol.age, carol.city);
+    println!("{}", structs_23_constructor_shorthand__describe_Struct_structs_23_constructor_shorthand_Person(&structs_23_constructor_shorthand__Person { name: String::from("Dave"), age: 52, city: String::from("Springfield") }));
+    let p = structs_23_constructor_shorthand__Point::new(1, 2);
+    let q = structs_23_constructor_shorthand__Point::new(3, 0);
+    println!("{} {}", p.x, p.y);
+    println!("{} {}", q.x, q.y);
+}
\ No newline at end of file
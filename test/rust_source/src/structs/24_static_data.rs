@@ -0,0 +1,63 @@
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+
+// Without `static`, an UPPER_CASE field is an ordinary field of each instance
+struct structs_24_static_data__Config {
+    pub name: String,
+    pub MAX: i64,
+}
+
+impl Default for structs_24_static_data__Config {
+    fn default() -> Self {
+        Self { name: String::new(), MAX: 3 }
+    }
+}
+
+struct structs_24_static_data__Ticket {
+    pub id: i64,
+    pub holder: String,
+}
+
+impl Default for structs_24_static_data__Ticket {
+    fn default() -> Self {
+        Self { id: 0, holder: String::new() }
+    }
+}
+
+impl structs_24_static_data__Ticket {
+    fn new(holder: String) -> Self {
+        STRUCTS_24_STATIC_DATA__TICKET_ISSUED.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| Some(current + 1)).unwrap();
+        *STRUCTS_24_STATIC_DATA__TICKET_LAST_HOLDER.lock().unwrap() = holder.to_string();
+        structs_24_static_data__Ticket { id: STRUCTS_24_STATIC_DATA__TICKET_ISSUED.load(Ordering::SeqCst), holder }
+    }
+    fn sold_out() -> bool {
+        STRUCTS_24_STATIC_DATA__TICKET_ISSUED.load(Ordering::SeqCst) >= STRUCTS_24_STATIC_DATA__TICKET_LIMIT
+    }
+}
+
+static STRUCTS_24_STATIC_DATA__TICKET_ISSUED: AtomicI64 = AtomicI64::new(0);
+static STRUCTS_24_STATIC_DATA__TICKET_LAST_HOLDER: std::sync::LazyLock<std::sync::Mutex<String>> = std::sync::LazyLock::new(|| std::sync::Mutex::new(String::from("nobody")));
+static STRUCTS_24_STATIC_DATA__TICKET_PRICE: std::sync::Mutex<f64> = std::sync::Mutex::new(12.5);
+static STRUCTS_24_STATIC_DATA__TICKET_OPEN: AtomicBool = AtomicBool::new(true);
+const STRUCTS_24_STATIC_DATA__TICKET_LIMIT: i64 = 3;
+static STRUCTS_24_STATIC_DATA__TICKET_VENUE: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| String::from("Main Hall"));
+
+fn main() {
+    println!("{} tickets issued at {}", STRUCTS_24_STATIC_DATA__TICKET_ISSUED.load(Ordering::SeqCst), (*STRUCTS_24_STATIC_DATA__TICKET_VENUE).clone());
+    let a = structs_24_static_data__Ticket::new(String::from("Ada"));
+    let b = structs_24_static_data__Ticket::new(String::from("Grace"));
+    println!("{} {}, {} {}", a.id, a.holder, b.id, b.holder);
+    println!("issued {}, last to {}", STRUCTS_24_STATIC_DATA__TICKET_ISSUED.load(Ordering::SeqCst), STRUCTS_24_STATIC_DATA__TICKET_LAST_HOLDER.lock().unwrap().clone());
+    {
+        let mut __zinc_static_guard_122_124 = STRUCTS_24_STATIC_DATA__TICKET_PRICE.lock().unwrap();
+        *__zinc_static_guard_122_124 *= 2.0;
+    }
+    println!("price {}", *STRUCTS_24_STATIC_DATA__TICKET_PRICE.lock().unwrap());
+    let c = structs_24_static_data__Ticket::new(String::from("Linus"));
+    println!("{}", structs_24_static_data__Ticket::sold_out());
+    if structs_24_static_data__Ticket::sold_out() {
+        STRUCTS_24_STATIC_DATA__TICKET_OPEN.store(false, Ordering::SeqCst);
+    }
+    println!("open: {}, limit {}, last {}", STRUCTS_24_STATIC_DATA__TICKET_OPEN.load(Ordering::SeqCst), STRUCTS_24_STATIC_DATA__TICKET_LIMIT, c.holder);
+    let config = structs_24_static_data__Config { name: String::from("defaults"), MAX: 3 };
+    println!("{} allows {}", config.name, config.MAX);
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+struct structs_25_interface_defaults__Circle {
+    pub radius: f64,
+}
+
+impl Default for structs_25_interface_defaults__Circle {
+    fn default() -> Self {
+        Self { radius: 0.0 }
+    }
+}
+
+impl structs_25_interface_defaults__Shape for structs_25_interface_defaults__Circle {
+    fn area(&self) -> f64 {
+        3.0 * self.radius * self.radius
+    }
+    fn name(&self) -> String {
+        String::from("circle")
+    }
+}
+
+impl structs_25_interface_defaults__Scalable for structs_25_interface_defaults__Circle {
+    fn scale(&mut self, factor: f64) {
+        self.radius = self.radius * factor;
+    }
+}
+
+trait structs_25_interface_defaults__Scalable {
+    fn scale(&mut self, factor: f64);
+    fn double(&mut self) {
+        self.scale(2.0);
+    }
+}
+
+// Test: @interface structs declare methods; implementers inherit the ones with bodies unless they override them.
+trait structs_25_interface_defaults__Shape {
+    fn area(&self) -> f64;
+    fn name(&self) -> String;
+    fn describe(&self) -> String {
+        format!("{} with area {}", self.name(), self.area())
+    }
+    fn is_large(&self) -> bool {
+        self.area() > 10.0
+    }
+}
+
+struct structs_25_interface_defaults__Square {
+    pub side: f64,
+}
+
+impl Default for structs_25_interface_defaults__Square {
+    fn default() -> Self {
+        Self { side: 0.0 }
+    }
+}
+
+impl structs_25_interface_defaults__Shape for structs_25_interface_defaults__Square {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+    fn name(&self) -> String {
+        String::from("square")
+    }
+    fn describe(&self) -> String {
+        format!("a square of side {}", self.side)
+    }
+}
+
+fn main() {
+    let mut c = structs_25_interface_defaults__Circle { radius: 1.0 };
+    let s = structs_25_interface_defaults__Square { side: 4.0 };
+    println!("{}", c.describe());
+    println!("{}", s.describe());
+    println!("{}", c.is_large());
+    println!("{}", s.is_large());
+    c.double();
+    println!("{}", c.describe());
+    println!("{}", c.is_large());
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+// Test: for loops step through structs with a next() method, or with iterate() handing out such a struct.
+struct structs_26_custom_iterables__Countdown {
+    pub current: i64,
+}
+
+impl Default for structs_26_custom_iterables__Countdown {
+    fn default() -> Self {
+        Self { current: 0 }
+    }
+}
+
+impl Iterator for structs_26_custom_iterables__Countdown {
+    type Item = i64;
+    fn next(&mut self) -> Option<i64> {
+        if self.current == 0 {
+            return None;
+        }
+        self.current -= 1;
+        Some(self.current + 1)
+    }
+}
+
+struct structs_26_custom_iterables__Span {
+    pub start: i64,
+    pub stop: i64,
+    pub step: i64,
+}
+
+impl Default for structs_26_custom_iterables__Span {
+    fn default() -> Self {
+        Self { start: 0, stop: 0, step: 0 }
+    }
+}
+
+impl structs_26_custom_iterables__Span {
+    fn iterate(&self) -> structs_26_custom_iterables__SpanWalk {
+        structs_26_custom_iterables__SpanWalk { at: self.start, stop: self.stop, step: self.step }
+    }
+}
+
+struct structs_26_custom_iterables__SpanWalk {
+    pub at: i64,
+    pub stop: i64,
+    pub step: i64,
+}
+
+impl Default for structs_26_custom_iterables__SpanWalk {
+    fn default() -> Self {
+        Self { at: 0, stop: 0, step: 0 }
+    }
+}
+
+impl Iterator for structs_26_custom_iterables__SpanWalk {
+    type Item = i64;
+    fn next(&mut self) -> Option<i64> {
+        if self.at >= self.stop {
+            return None;
+        }
+        self.at += self.step;
+        Some(self.at - self.step)
+    }
+}
+
+fn main() {
+    for n in (structs_26_custom_iterables__Countdown { current: 3 }) {
+        println!("{}", n);
+    }
+    // Looping over an iterator advances it, so a loop that stops early leaves the rest.
+    let mut countdown = structs_26_custom_iterables__Countdown { current: 5 };
+    for n in &mut countdown {
+        if n == 3 {
+            break;
+        }
+    }
+    println!("{}", countdown.current);
+    // iterate() hands each loop a fresh iterator, so a struct can be looped over again.
+    let evens = structs_26_custom_iterables__Span { start: 0, stop: 10, step: 2 };
+    let mut total: i64 = 0;
+    for n in evens.iterate() {
+        total += n;
+    }
+    println!("{}", total);
+    for n in evens.iterate() {
+        println!("even {}", n);
+    }
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 fn main() {
     let single = (5,);
-    let paren = (5);
+    let paren: i64 = 5;
     let only = single.0;
     println!("{}", only);
     println!("{}", paren);
@@ -20,9 +20,9 @@ fn main() {
     z = 3;
     println!("{} {} {}", x, y, z);
     let __zinc_multi_assign_65_73 = 1;
-    let fx: f32 = (__zinc_multi_assign_65_73.clone() as f32);
-    let fy: f32 = (__zinc_multi_assign_65_73.clone() as f32);
-    let fz: f32 = (__zinc_multi_assign_65_73 as f32);
+    let fx: f32 = __zinc_multi_assign_65_73.clone() as f32;
+    let fy: f32 = __zinc_multi_assign_65_73.clone() as f32;
+    let fz: f32 = __zinc_multi_assign_65_73 as f32;
     println!("{} {} {}", fx, fy, fz);
     let (value, label, item) = (3.14, String::from("cat"), __ZincAnonStruct_AnonStruct_id_i64 { id: 123456 });
     println!("{}", value);
@@ -34,8 +34,8 @@ fn main() {
     let (single,) = (99,);
     println!("{}", single);
     let __zinc_destructure_143_153 = (1, 2);
-    let typed_a: f32 = (__zinc_destructure_143_153.0 as f32);
-    let typed_b: f32 = (__zinc_destructure_143_153.1 as f32);
+    let typed_a: f32 = __zinc_destructure_143_153.0 as f32;
+    let typed_b: f32 = __zinc_destructure_143_153.1 as f32;
     println!("{} {}", typed_a, typed_b);
     let __zinc_multi_assign_158_162 = "dog";
     let mut sx = __zinc_multi_assign_158_162.clone();
@@ -48,11 +48,11 @@ fn main() {
     let p1 = __zinc_multi_assign_177_186.clone();
     let p2 = __zinc_multi_assign_177_186;
     println!("{} {}", p1.id, p2.id);
-    let mut rx: f32 = (0 as f32);
-    let mut ry: f32 = (0 as f32);
+    let mut rx: f32 = 0.0;
+    let mut ry: f32 = 0.0;
     let __zinc_multi_assign_201_205 = 2;
-    rx = (__zinc_multi_assign_201_205.clone() as f32);
-    ry = (__zinc_multi_assign_201_205 as f32);
+    rx = __zinc_multi_assign_201_205.clone() as f32;
+    ry = __zinc_multi_assign_201_205 as f32;
     println!("{} {}", rx, ry);
     let (mut first, mut second) = (10, 20);
     (first, second) = (second, first);
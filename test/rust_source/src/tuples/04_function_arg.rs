@@ -1,5 +1,5 @@
 fn tuples_04_function_arg__sum_pair_Tuple_i64_i64(pair: (i64, i64)) -> i64 {
-    return (pair.0 + pair.1);
+    pair.0 + pair.1
 }
 
 fn main() {
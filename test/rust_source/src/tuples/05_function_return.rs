@@ -1,5 +1,5 @@
 fn tuples_05_function_return__make_pair_i64(seed: i64) -> (i64, i64) {
-    return (seed, (seed + 1));
+    (seed, seed + 1)
 }
 
 fn main() {
@@ -0,0 +1,95 @@
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone)]
+struct __ZincClosureEnv_unicode_identifiers___lambda_unicode_identifiers__main_129_137 {
+    zähler: Arc<Mutex<i64>>,
+    schritt_ä: Arc<Mutex<i64>>,
+    schritt_à: Arc<Mutex<i64>>,
+}
+
+#[derive(Clone)]
+enum __ZincCallable_i64_to_i64 {
+    Closed,
+    V0(__ZincClosureEnv_unicode_identifiers___lambda_unicode_identifiers__main_129_137),
+}
+
+impl Default for __ZincCallable_i64_to_i64 {
+    fn default() -> Self {
+        Self::Closed
+    }
+}
+
+impl __ZincCallable_i64_to_i64 {
+    fn call(&self, arg_0: i64) -> i64 {
+        match self {
+            Self::Closed => panic!("callable used after closed receive"),
+            Self::V0(env) => unicode_identifiers____lambda_unicode_identifiers__main_129_137_i64(env.clone(), arg_0),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum unicode_identifiers__Farbe {
+    Grün,
+    Blau { tiefe: i64 },
+}
+
+// Identifiers and strings outside ASCII
+struct unicode_identifiers__Größe {
+    pub höhe: i64,
+    pub breite: i64,
+}
+
+impl Default for unicode_identifiers__Größe {
+    fn default() -> Self {
+        Self { höhe: 0, breite: 0 }
+    }
+}
+
+impl unicode_identifiers__Größe {
+    fn fläche(&self) -> i64 {
+        self.höhe * self.breite
+    }
+}
+
+fn unicode_identifiers____lambda_unicode_identifiers__main_129_137_i64(__env: __ZincClosureEnv_unicode_identifiers___lambda_unicode_identifiers__main_129_137, x: i64) -> i64 {
+    let __zv_unicode_identifiers____lambda_unicode_identifiers__main_129_137_i64_zähler_i64 = __env.zähler.clone();
+    let __zv_unicode_identifiers____lambda_unicode_identifiers__main_129_137_i64_schritt_ä_i64 = __env.schritt_ä.clone();
+    let __zv_unicode_identifiers____lambda_unicode_identifiers__main_129_137_i64_schritt_à_i64 = __env.schritt_à.clone();
+    return x + *__zv_unicode_identifiers____lambda_unicode_identifiers__main_129_137_i64_zähler_i64.lock().unwrap() + *__zv_unicode_identifiers____lambda_unicode_identifiers__main_129_137_i64_schritt_ä_i64.lock().unwrap() + *__zv_unicode_identifiers____lambda_unicode_identifiers__main_129_137_i64_schritt_à_i64.lock().unwrap();
+}
+
+fn unicode_identifiers__verdoppeln_i64(zahl: i64) -> i64 {
+    zahl * 2
+}
+
+fn main() {
+    let café: i64 = 1;
+    let cafè: i64 = 2;
+    let 名前 = "世界";
+    println!("{} {} {}", café, cafè, 名前);
+    let g = unicode_identifiers__Größe { höhe: 3, breite: 4 };
+    println!("fläche: {}, höhe: {}", g.fläche(), g.höhe);
+    let f = unicode_identifiers__Farbe::Blau { tiefe: 7 };
+    {
+        let __zinc_match_90_117 = f;
+        match __zinc_match_90_117.clone() {
+            unicode_identifiers__Farbe::Grün => {
+                println!("grün");
+            },
+            unicode_identifiers__Farbe::Blau { tiefe } => {
+                println!("blau {}", tiefe);
+            },
+        }
+    }
+    let __zv_unicode_identifiers__main_zähler_i64 = Arc::new(Mutex::new(0));
+    let __zv_unicode_identifiers__main_schritt_ä_i64 = Arc::new(Mutex::new(3));
+    let __zv_unicode_identifiers__main_schritt_à_i64 = Arc::new(Mutex::new(4));
+    let erhöhen = __ZincCallable_i64_to_i64::V0(__ZincClosureEnv_unicode_identifiers___lambda_unicode_identifiers__main_129_137 { zähler: __zv_unicode_identifiers__main_zähler_i64.clone(), schritt_ä: __zv_unicode_identifiers__main_schritt_ä_i64.clone(), schritt_à: __zv_unicode_identifiers__main_schritt_à_i64.clone() });
+    println!("{}", erhöhen.call(unicode_identifiers__verdoppeln_i64(5)));
+    let grüße = vec!["hallo", "привет", "こんにちは", "👋"];
+    for gruß in grüße.iter().cloned() {
+        println!("{}: {}", gruß, gruß.len());
+    }
+    println!("{}", String::from("Größe"));
+}
\ No newline at end of file
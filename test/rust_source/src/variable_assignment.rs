@@ -1,6 +1,8 @@
 fn main() {
-    let x = 1;
+    // variable assignment
+    let x: i64 = 1;
     let y = 3.14;
     let z = "zinc";
+    // print it out
     println!("x: {}, y: {}, z: {}", x, y, z);
 }
\ No newline at end of file
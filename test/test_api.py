@@ -0,0 +1,78 @@
+"""Tests for the embeddable compiler API."""
+
+from pathlib import Path
+
+import pytest
+import zinc
+from zinc.api import CompileError, Diagnostic, check, compile_file, compile_str, generate, parse
+
+MATH_MODULE = "fn add(a: i64, b: i64) -> i64 {\n    return a + b\n}\n"
+
+
+def test_compile_str_returns_rust_for_multi_module_programs() -> None:
+    """Imported modules can be passed as text alongside the entry source."""
+    result = compile_str("import util/math [add]\n\nfn main() {\n    print(add(1, 2))\n}\n", modules={"util/math": MATH_MODULE})
+
+    assert result.ok
+    assert result.diagnostics == []
+    assert "fn util_math__add_i64_i64(a: i64, b: i64) -> i64" in result.rust
+    assert result.program.render() == result.rust
+
+
+def test_syntax_errors_carry_positions_relative_to_the_virtual_package() -> None:
+    """Syntax diagnostics name the file and point at the offending token."""
+    result = compile_str("fn main() {\n    print(1\n}\n")
+
+    assert not result.ok
+    assert result.rust is None
+    (diagnostic,) = result.diagnostics
+    assert (diagnostic.file, diagnostic.line, diagnostic.column) == ("main.zn", 3, 0)
+    assert str(diagnostic).startswith("main.zn:3:1: error: ")
+
+
+def test_semantic_errors_are_reported_without_raising() -> None:
+    """Type errors become diagnostics that point at the offending expression."""
+    result = compile_str("fn main() {\n    x: i64 = true\n}\n")
+
+    assert result.diagnostics == [
+        Diagnostic("variable 'x' expects a compatible 'i64' value", "main.zn", 2, 13, 17, label="expected i64, found bool", code="Z0003")
+    ]
+
+
+def test_every_error_in_a_file_is_reported() -> None:
+    """Checking and parsing both continue past the first error."""
+    source = "fn main() {\n    x: i64 = true\n    print(x)\n    if 1 {\n        print(2)\n    }\n    y: bool = 3\n}\n"
+
+    result = compile_str(source)
+
+    assert [(diagnostic.line, diagnostic.message) for diagnostic in result.diagnostics] == [
+        (2, "variable 'x' expects a compatible 'i64' value"),
+        (4, "if condition must be a bool"),
+        (7, "variable 'y' expects a compatible 'bool' value"),
+    ]
+    result = compile_str("fn main() {\n    x = (1 +\n    y = 2\n    print(y)\n    z = )\n    print(z)\n}\n")
+    assert [diagnostic.line for diagnostic in result.diagnostics] == [3, 5]
+
+
+def test_stages_run_separately(tmp_path: Path) -> None:
+    """parse, check, and generate can be driven one at a time on files with unsaved overrides."""
+    (tmp_path / "pkg.toml").write_text('[package]\nname = "tmp"\nversion = "0.1.0"\n')
+    entry = tmp_path / "main.zn"
+    entry.write_text("fn main() {\n    print(1)\n}\n")
+    edited = "fn main() {\n    print(2)\n}\n"
+
+    assert parse(edited).getText().startswith("fnmain")
+    checked = check(entry, {entry.resolve(): edited})
+    assert checked.graph.entry_module_id == "main"
+    assert 'println!("{}", 2);' in generate(checked).render()
+    assert compile_file(entry).ok
+
+    with pytest.raises(CompileError) as excinfo:
+        parse("fn main( {", filename="broken.zn")
+    assert excinfo.value.diagnostics[0].file == "broken.zn"
+
+
+def test_package_exports_the_api() -> None:
+    """`import zinc` is enough to drive the compiler."""
+    assert zinc.compile_str is compile_str
+    assert zinc.compile_str("fn main() {\n    print(1)\n}\n").ok
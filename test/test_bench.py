@@ -0,0 +1,96 @@
+"""Tests for `zinc bench` harness generation, criterion output, and reporting."""
+
+import shutil
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from zinc.bench import BenchResult, compile_benches, criterion_source, parse_summary, report, write_criterion_project
+from zinc.exceptions import ZincTypeError
+from zinc.main import _harness_codegen, main
+
+BENCH_SOURCE = """\
+fn fib(n: i64) -> i64 {
+    if n < 2 {
+        return n
+    }
+    return fib(n - 1) + fib(n - 2)
+}
+
+@bench
+fn fib_15() {
+    fib(15)
+}
+
+@test
+fn fib_is_right() {
+    assert_eq(fib(10), 55)
+}
+"""
+
+
+def test_harness_runs_benches_through_the_runtime(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Only `@bench` functions are compiled in, each wrapped in `run_bench` with argv counts."""
+    program, calls = compile_benches(write_package(tmp_path, BENCH_SOURCE, "fib.zn"), _harness_codegen)
+    rendered = program.render()
+
+    assert list(calls) == ["fib_15"]
+    assert "bench" in program.runtime_features
+    assert 'Some("fib_15") => { zinc_internal::run_bench(warmup, iterations, || {' in rendered
+    assert "fib_is_right" not in rendered
+
+
+def test_async_benches_are_rejected(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Timing an async function would need a runtime per call, so it is refused."""
+    source = write_package(tmp_path, "@bench\nasync waits() {\n    print(1)\n}\n")
+
+    with pytest.raises(ZincTypeError, match="bench function 'waits' must not be async"):
+        compile_benches(source, _harness_codegen)
+
+
+def test_criterion_project_wraps_each_bench(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """--criterion writes a harness-less bench target that calls every benchmark."""
+    project_dir = write_criterion_project(write_package(tmp_path, BENCH_SOURCE, "fib.zn"), _harness_codegen)
+    manifest = (project_dir / "Cargo.toml").read_text()
+    bench_target = (project_dir / "benches" / "zinc.rs").read_text()
+
+    assert 'criterion = "0.5"' in manifest
+    assert '[[bench]]\nname = "zinc"\nharness = false' in manifest
+    assert 'c.bench_function("fib::fib_15", |b| b.iter(|| {' in bench_target
+    assert "criterion_main!(benches);" in bench_target
+    assert "fn main()" not in bench_target
+    assert criterion_source(*compile_benches(tmp_path / "fib.zn", _harness_codegen), "fib") == bench_target
+
+
+def test_report_shows_timings_and_failures() -> None:
+    """Measured benchmarks show per-iteration times; failed ones repeat their output."""
+    measured = parse_summary("fast", "zinc-bench iterations=4 total_ns=6000 min_ns=900 max_ns=2100\n")
+    lines: list[str] = []
+
+    ok = report({Path("fib.zn"): [measured, BenchResult("broken", output="assertion failed\n")]}, write=lines.append)
+
+    assert not ok
+    assert lines == [
+        "running 2 benchmarks",
+        "bench fib.zn::fast ... 1.50 µs/iter (min 900 ns, max 2.10 µs, 4 iterations)",
+        "bench fib.zn::broken ... FAILED",
+        "\nfailures:",
+        "\n---- fib.zn::broken ----",
+        "assertion failed",
+        "\nbench result: FAILED. 1 measured; 1 failed",
+    ]
+
+
+def test_cli_measures_each_bench(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """The command builds in release mode and reports the requested iteration count."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    write_package(tmp_path, BENCH_SOURCE, "fib.zn")
+
+    result = CliRunner().invoke(main, ["bench", str(tmp_path), "--warmup", "1", "--iterations", "3"])
+
+    assert result.exit_code == 0, result.output
+    assert "::fib_15 ... " in result.output
+    assert "3 iterations)" in result.output
+    assert "bench result: ok. 1 measured; 0 failed" in result.output
@@ -0,0 +1,338 @@
+"""Tests for turning compiled Zinc programs into Cargo projects and binaries."""
+
+import dataclasses
+import json
+import os
+import shutil
+import subprocess
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from zinc.build import (
+    DEBUG,
+    BuildProfile,
+    build_binary,
+    cargo_manifest,
+    crate_name_for,
+    profile_setting,
+    run_binary,
+    write_cargo_project,
+)
+from zinc.codegen import RustProgram
+from zinc.exceptions import ZincBuildError, ZincModuleError, ZincRustcErrors
+from zinc.main import compile_program, main
+from zinc.rustc_errors import BUG_REPORT_NOTE, parse_cargo_messages
+from zinc.sourcemap import SOURCE_MAP_FILE_NAME
+
+
+def test_crate_names_are_valid_cargo_package_names() -> None:
+    """Entry stems become lowercase identifiers that Cargo accepts."""
+    assert crate_name_for(Path("hello.zn")) == "hello"
+    assert crate_name_for(Path("My-Tool.zn")) == "my_tool"
+    assert crate_name_for(Path("01_basics.zn")) == "zinc_01_basics"
+
+
+def test_manifest_only_pulls_in_needed_dependencies() -> None:
+    """Sync programs without runtime features build with no dependencies at all."""
+    manifest = cargo_manifest("hello", RustProgram())
+
+    assert 'name = "hello"' in manifest
+    assert "tokio" not in manifest
+    assert "zinc-internal" not in manifest
+    assert "[workspace]" in manifest
+
+
+def test_manifest_adds_tokio_and_scoped_runtime_features() -> None:
+    """Async programs get tokio, and runtime features are passed through sorted."""
+    program = RustProgram(uses_async=True, runtime_features={"uuid", "channel"})
+    manifest = cargo_manifest("server", program)
+
+    assert 'tokio = { version = "1", features = ["full"] }' in manifest
+    assert 'zinc-internal = { path = "zinc-internal", default-features = false, features = ["channel", "uuid"] }' in manifest
+
+
+def test_profile_settings_reach_the_matching_cargo_profile() -> None:
+    """Overrides land in [profile.release] or [profile.dev]; default profiles add no table."""
+    profile = BuildProfile(opt_level="z", lto=True, panic="abort")
+    manifest = cargo_manifest("hello", RustProgram(), profile)
+
+    assert '[profile.release]\nopt-level = "z"\nlto = true\npanic = "abort"\n\n[workspace]' in manifest
+    assert "[profile.dev]\nopt-level = 1\n" in cargo_manifest("hello", RustProgram(), BuildProfile(release=False, opt_level=1))
+    assert "[profile" not in cargo_manifest("hello", RustProgram(), DEBUG)
+
+
+def test_checked_overflow_keeps_overflow_checks_in_every_profile() -> None:
+    """Checked arithmetic relies on Rust's own checks, so release builds must not turn them off."""
+    program = RustProgram(overflow_checks=True)
+
+    assert "[profile.release]\noverflow-checks = true\n" in cargo_manifest("hello", program)
+    debug_manifest = cargo_manifest("hello", program, BuildProfile(release=False, panic="abort"))
+    assert '[profile.dev]\npanic = "abort"\noverflow-checks = true\n' in debug_manifest
+
+
+def test_profile_settings_are_validated() -> None:
+    """Command-line strings are converted to Cargo's types, and values Cargo would reject fail early."""
+    assert profile_setting("opt-level", "3") == 3
+    assert profile_setting("opt-level", "s") == "s"
+    assert profile_setting("lto", "false") is False
+    assert profile_setting("lto", "thin") == "thin"
+
+    with pytest.raises(ZincBuildError, match="invalid opt-level 4"):
+        profile_setting("opt-level", 4)
+    with pytest.raises(ZincBuildError, match="invalid lto 1"):
+        profile_setting("lto", 1)
+    with pytest.raises(ZincBuildError, match="unknown profile setting 'debug'"):
+        profile_setting("debug", True)
+
+
+def test_extern_crates_reach_the_manifest(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Crates declared in extern rust blocks become dependencies; path crates resolve from the source file."""
+    (tmp_path / "vendor").mkdir()
+    (tmp_path / "util.zn").write_text('extern rust {\n    crate rand = "0.8";\n}\n')
+    entry = write_package(
+        tmp_path,
+        'import util\n\nextern rust {\n    crate rand = { version = "0.8" };\n    crate local = { path = "vendor" };\n}\n\n'
+        "fn main() {\n    print(1)\n}\n",
+    )
+
+    program = compile_program(entry)
+    manifest = cargo_manifest("hello", program)
+
+    assert 'rand = { version = "0.8" }' in manifest
+    assert f'local = {{ path = "{(tmp_path / "vendor").resolve().as_posix()}" }}' in manifest
+
+
+def test_conflicting_crate_requirements_are_rejected(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Two modules may declare the same crate only with the same requirement."""
+    (tmp_path / "util.zn").write_text('extern rust {\n    crate rand = "0.9";\n}\n')
+    entry = write_package(
+        tmp_path, 'import util\n\nextern rust {\n    crate rand = "0.8";\n}\n\nfn main() {\n    print(1)\n}\n', "hello.zn"
+    )
+
+    with pytest.raises(ZincModuleError, match="crate 'rand' is declared as \"0.8\" in module 'hello' and as \"0.9\" in module 'util'"):
+        compile_program(entry)
+
+
+def test_cargo_project_vendors_the_runtime_when_needed(tmp_path: Path) -> None:
+    """Projects that use runtime features carry their own copy of the runtime crate."""
+    project = write_cargo_project(RustProgram(runtime_features={"uuid"}), "ids", tmp_path / "ids")
+
+    assert (project / "src" / "main.rs").read_text().startswith("fn main() {")
+    assert (project / "zinc-internal" / "Cargo.toml").exists()
+    assert (project / "zinc-internal" / "src" / "lib.rs").exists()
+
+
+def test_imported_files_become_rust_modules(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Each imported Zinc file gets its own module file, wired up with `mod` and `use` from the crate root."""
+    (tmp_path / "geo").mkdir()
+    (tmp_path / "geo" / "shapes.zn").write_text(
+        "struct Point {\n    x: 0\n    y: 0\n\n    fn sum() {\n        return self.x + self.y\n    }\n}\n\n"
+        "const SCALE = 2\n\nfn double(x) {\n    return x * SCALE\n}\n"
+    )
+    entry = write_package(
+        tmp_path, "import geo/shapes [Point, double]\n\nfn main() {\n    point = Point { x: double(2), y: 9 }\n    print(point.sum())\n}\n"
+    )
+
+    project = write_cargo_project(compile_program(entry), "hello", tmp_path / "build", rustfmt=False)
+
+    assert sorted(path.relative_to(project / "src").as_posix() for path in (project / "src").rglob("*.rs")) == [
+        "geo.rs",
+        "geo/shapes.rs",
+        "main.rs",
+    ]
+    main_rs = (project / "src" / "main.rs").read_text()
+    assert main_rs.startswith("mod geo;\nuse geo::shapes::*;\n")
+    assert "geo_shapes__double" not in main_rs.split("fn main()")[0]
+    assert (project / "src" / "geo.rs").read_text() == "pub(crate) mod shapes;\n"
+    shapes = (project / "src" / "geo" / "shapes.rs").read_text()
+    assert shapes.startswith("use crate::*;\n")
+    assert "pub(crate) const GEO_SHAPES__SCALE: i64 = 2;" in shapes
+    assert "pub(crate) struct geo_shapes__Point {\n    pub x: i64," in shapes
+    assert "    pub(crate) fn sum(&self) -> i64 {" in shapes
+    assert "pub(crate) fn geo_shapes__double_i64(x: i64) -> i64 {" in shapes
+    assert "modules" in (project / SOURCE_MAP_FILE_NAME).read_text()
+
+    if shutil.which("cargo") is not None:
+        assert subprocess.run(["cargo", "run", "--quiet"], cwd=project, capture_output=True, text=True).stdout == "13\n"
+
+
+def test_compile_cargo_writes_a_self_contained_project(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """`compile --cargo` writes the manifest next to the source, with only the dependencies the program uses."""
+    entry = write_package(tmp_path, "fn main() {\n    print(uuid())\n}\n", "hello.zn")
+    project = tmp_path / "out"
+
+    result = CliRunner().invoke(main, ["compile", str(entry), "--cargo", str(project), "--no-fmt"])
+
+    assert result.exit_code == 0, result.output
+    manifest = (project / "Cargo.toml").read_text()
+    assert 'name = "hello"' in manifest
+    assert 'features = ["uuid"]' in manifest
+    assert "tokio" not in manifest
+    assert (project / "src" / "main.rs").read_text() == compile_program(entry).render() + "\n"
+    assert (project / "zinc-internal" / "src" / "lib.rs").exists()
+
+    conflicting = CliRunner().invoke(main, ["compile", str(entry), "--cargo", str(project), "-o", str(tmp_path / "x.rs")])
+    assert conflicting.exit_code != 0
+    assert "choose one of" in conflicting.output
+
+
+def test_compile_formats_with_rustfmt_unless_asked_not_to(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """`compile` output goes through rustfmt; `--no-fmt` writes it exactly as generated."""
+    if shutil.which("rustfmt") is None:
+        pytest.skip("rustfmt is not installed")
+    source = (
+        "fn combine(first_value_in: i64, second_value_in: i64, third_value_in: i64) -> i64 {\n"
+        "    return first_value_in + second_value_in + third_value_in\n"
+        "}\n\n"
+        "fn main() {\n    print(combine(1, 2, 3))\n}\n"
+    )
+    entry = write_package(tmp_path, source, "hello.zn")
+
+    formatted = CliRunner().invoke(main, ["compile", str(entry)])
+    raw = CliRunner().invoke(main, ["compile", str(entry), "--no-fmt"])
+
+    assert formatted.exit_code == 0, formatted.output
+    assert "fn hello__combine_i64_i64_i64(\n    first_value_in: i64,\n" in formatted.output
+    assert raw.output.rstrip("\n") == compile_program(entry).render()
+    assert "fn hello__combine_i64_i64_i64(first_value_in: i64, " in raw.output
+
+
+def test_missing_rustfmt_leaves_output_unformatted(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    """A configured rustfmt that cannot be run falls back to the generated text."""
+    monkeypatch.setenv("ZINC_RUSTFMT", str(tmp_path / "no-such-rustfmt"))
+    program = RustProgram(main_body=["let x  =  1;"])
+
+    assert program.render(rustfmt=True) == program.render()
+
+
+def test_build_command_produces_a_native_binary(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """`zinc build` writes a Cargo project, runs cargo, and copies the binary out."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    entry = write_package(tmp_path, 'fn main() {\n    print("hello from zinc")\n}\n', "hello.zn")
+    output = tmp_path / "bin" / "hello"
+
+    result = CliRunner().invoke(main, ["build", str(entry), "-o", str(output), "--debug", "--panic", "abort"])
+
+    assert result.exit_code == 0, result.output
+    assert '[profile.dev]\npanic = "abort"' in (tmp_path / ".zinc-build" / "hello" / "Cargo.toml").read_text()
+    run = subprocess.run([str(output)], capture_output=True, text=True)
+    assert run.stdout == "hello from zinc\n"
+
+
+def test_extern_functions_call_into_a_declared_crate(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """A Rust crate declared next to its extern signatures is linked into the build."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    crate_dir = tmp_path / "mathx"
+    (crate_dir / "src").mkdir(parents=True)
+    (crate_dir / "Cargo.toml").write_text('[package]\nname = "mathx"\nversion = "0.1.0"\nedition = "2021"\n')
+    (crate_dir / "src" / "lib.rs").write_text("pub fn gcd(a: i64, b: i64) -> i64 {\n    if b == 0 { a } else { gcd(b, a % b) }\n}\n")
+    entry = write_package(
+        tmp_path,
+        'extern rust {\n    crate mathx = { path = "mathx" };\n    use mathx::gcd;\n\n    fn gcd(a: i64, b: i64) -> i64;\n}\n\n'
+        "fn main() {\n    print(gcd(84, 36))\n}\n",
+    )
+
+    result = CliRunner().invoke(main, ["build", str(entry), "-o", str(tmp_path / "gcd"), "--debug"])
+
+    assert result.exit_code == 0, result.output
+    assert subprocess.run([str(tmp_path / "gcd")], capture_output=True, text=True).stdout == "12\n"
+
+
+def test_included_files_are_embedded_relative_to_their_module(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """`include_str()` reads next to the module that calls it, and the binary carries the text with it."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    (tmp_path / "pages" / "templates").mkdir(parents=True)
+    (tmp_path / "pages" / "templates" / "index.html").write_text("<h1>{title}</h1>\n")
+    (tmp_path / "pages" / "index.zn").write_text('const TEMPLATE = include_str("templates/index.html")\n')
+    entry = write_package(tmp_path, "import pages/index as index\n\nfn main() {\n    print(index.TEMPLATE)\n}\n")
+
+    result = CliRunner().invoke(main, ["build", str(entry), "-o", str(tmp_path / "site"), "--debug"])
+    (tmp_path / "pages" / "templates" / "index.html").unlink()
+
+    assert result.exit_code == 0, result.output
+    assert subprocess.run([str(tmp_path / "site")], capture_output=True, text=True).stdout == "<h1>{title}</h1>\n\n"
+
+
+def test_unknown_log_levels_warn_once_and_log_at_info(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """A `ZINC_LOG` level that std/log doesn't know is reported once, naming the levels it does know."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    log_module = (Path(__file__).parent / "zinc_source" / "std" / "log.zn").read_text()
+    source = 'import std/log as log\n\nfn main() {\n    log.info("one")\n    log.debug("hidden")\n    log.warn("two")\n}\n'
+    entry = write_package(tmp_path, source, modules={"std/log.zn": log_module})
+
+    result = CliRunner().invoke(main, ["build", str(entry), "-o", str(tmp_path / "logs"), "--debug"])
+    run = subprocess.run([str(tmp_path / "logs")], capture_output=True, text=True, env={**os.environ, "ZINC_LOG": "verbose"})
+
+    assert result.exit_code == 0, result.output
+    lines = run.stderr.splitlines()
+    assert lines[0] == 'warning: unknown ZINC_LOG level "verbose"; expected debug, info, warn, error, or off; logging at info'
+    assert [line.split(maxsplit=1)[1] for line in lines[1:]] == ["INFO  one", "WARN  two"]
+
+
+def test_run_binary_forwards_arguments_and_exit_status() -> None:
+    """Arguments reach the program and its exit status comes back unchanged."""
+    assert run_binary(Path("/bin/sh"), ["-c", 'exit "$0"', "4"]) == 4
+
+
+def test_run_binary_reports_signals_like_a_shell() -> None:
+    """A program killed by a signal reports 128 + the signal number."""
+    assert run_binary(Path("/bin/sh"), ["-c", "kill -TERM $$"]) == 143
+
+
+def test_run_command_propagates_the_program_exit_code(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """`zinc run` builds the program and exits with the program's own status."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    entry = write_package(tmp_path, "fn main() {\n    exit(3)\n}\n", name="failing.zn")
+
+    result = CliRunner().invoke(main, ["run", str(entry), "--", "--ignored", "value"])
+
+    assert result.exit_code == 3, result.output
+
+
+def test_rustc_errors_in_generated_code_point_at_the_zinc_line(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Generated Rust that doesn't compile is reported as a compiler bug at the Zinc statement it came from."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    entry = write_package(tmp_path, "fn main() {\n    total = 40 + 2\n    print(total)\n}\n")
+    program = compile_program(entry)
+    broken = dataclasses.replace(program, main_body=[*program.main_body[:-1], program.main_body[-1].replace("total", "total.len()")])
+
+    with pytest.raises(ZincRustcErrors) as raised:
+        build_binary(broken, entry, build_dir=tmp_path / "build", profile=DEBUG)
+
+    [diagnostic] = raised.value.diagnostics
+    assert diagnostic.message.startswith("the Rust generated for this program doesn't compile: no method named `len`")
+    assert (Path(diagnostic.file), diagnostic.line) == (entry, 3)
+    assert diagnostic.notes[0].startswith("rustc reported error[E0599] at ")
+    assert diagnostic.notes[-1] == BUG_REPORT_NOTE
+
+
+def test_only_errors_in_the_generated_crate_count_as_compiler_bugs(tmp_path: Path) -> None:
+    """Rustc's closing summary is dropped, and errors without a span or in another package aren't in generated code."""
+
+    def message(text: str, manifest: Path, spans: list[dict]) -> str:
+        diagnostic = {"message": text, "level": "error", "spans": spans}
+        return json.dumps({"reason": "compiler-message", "manifest_path": str(manifest), "message": diagnostic})
+
+    span = {"file_name": "src/main.rs", "line_start": 3, "column_start": 5, "is_primary": True}
+    output = "\n".join(
+        [
+            message("mismatched types", tmp_path / "Cargo.toml", [span]),
+            message("mismatched types", tmp_path / "dependency" / "Cargo.toml", [span]),
+            message("linking with `cc` failed: exit status: 1", tmp_path / "Cargo.toml", []),
+            message("aborting due to 3 previous errors", tmp_path / "Cargo.toml", []),
+            '{"reason": "build-finished", "success": false}',
+        ]
+    )
+
+    errors = parse_cargo_messages(output)
+
+    assert [error.message for error in errors] == ["mismatched types", "mismatched types", "linking with `cc` failed: exit status: 1"]
+    assert [error.in_generated_source(tmp_path) for error in errors] == [True, False, False]
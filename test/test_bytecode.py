@@ -0,0 +1,136 @@
+"""Tests for the bytecode compiler and VM behind `zinc build --emit bytecode` and `zinc exec`."""
+
+import io
+import json
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from test.test_interpreter import BOUNDS_PROGRAM, MATCHING_FIXTURES, OUTPUT_DIR, ZINC_SOURCE_DIR
+from zinc.api import check
+from zinc.bytecode import BytecodeError, compile_bytecode, read_bytecode, write_bytecode
+from zinc.conditional import BuildConfig, build_config
+from zinc.exceptions import ZincError
+from zinc.main import main
+from zinc.vm import VirtualMachine
+
+PKG_TOML = '[package]\nname = "tmp"\nversion = "0.1.0"\n'
+
+
+def _execute(program) -> tuple[int, str, str]:
+    stdout, stderr = io.StringIO(), io.StringIO()
+    status = VirtualMachine(program, stdout=stdout, stderr=stderr).run()
+    return status, stdout.getvalue(), stderr.getvalue()
+
+
+def _compile(tmp_path: Path, source: str, overflow: str = "checked"):
+    (tmp_path / "pkg.toml").write_text(PKG_TOML)
+    entry = tmp_path / "main.zn"
+    entry.write_text(source)
+    return compile_bytecode(check(entry), overflow=overflow)
+
+
+@pytest.mark.parametrize("fixture", MATCHING_FIXTURES)
+def test_vm_prints_what_the_compiled_fixture_prints(fixture: str, tmp_path: Path) -> None:
+    """Fixtures print the same output when compiled to bytecode, written to a file, and loaded back."""
+    path = write_bytecode(compile_bytecode(check(ZINC_SOURCE_DIR / f"{fixture}.zn")), tmp_path / "fixture.znb")
+
+    assert _execute(read_bytecode(path)) == (0, (OUTPUT_DIR / f"{fixture}.out").read_text(), "")
+
+
+def test_recursion_too_deep_for_the_vm_is_a_runtime_error(tmp_path: Path) -> None:
+    """The VM recurses on the Python stack like the interpreter; too deep a recursion exits with 101 instead of a traceback."""
+    path = write_bytecode(compile_bytecode(check(ZINC_SOURCE_DIR / "functions/14_tail_calls.zn")), tmp_path / "fixture.znb")
+
+    assert _execute(read_bytecode(path)) == (101, "", "error: stack overflow: the program's calls nested too deeply\n")
+
+
+def test_panics_report_the_source_location(tmp_path: Path) -> None:
+    """The overflow mode is baked in at build time, and panics point at the statement that caused them."""
+    source = "fn main() {\n    x: u8 = 255\n    x += 1\n    print(x)\n}\n"
+
+    status, stdout, stderr = _execute(_compile(tmp_path, source))
+    assert (status, stdout) == (101, "")
+    assert stderr.startswith("thread 'main' panicked at main.zn:3:5:\nattempt to add with overflow\n")
+    assert _execute(_compile(tmp_path, source, overflow="wrapping")) == (0, "0\n", "")
+
+
+def test_bounds_mode_is_baked_in_at_build_time(tmp_path: Path) -> None:
+    """The bounds mode is decided when the bytecode is built: under `--bounds error` a read through a variable gives a Result."""
+    with build_config(BuildConfig.for_target(bounds="error")):
+        program = _compile(tmp_path, BOUNDS_PROGRAM)
+
+    assert _execute(program) == (0, "30\nindex out of bounds: the len is 3 but the index is 3\n", "")
+
+
+def test_try_blocks_unwind_loops(tmp_path: Path) -> None:
+    """An Err unwrapped inside a loop inside a try block ends the block, and break and continue leave it."""
+    source = """\
+fn check(n: i64) -> Result<i64, string> {
+    if n == 3 {
+        fail "three"
+    }
+    return Ok(n)
+}
+
+fn main() {
+    total = try {
+        sum = 0
+        for n in [1, 2, 3, 4] {
+            value = check(n)
+            sum += value
+        }
+        sum
+    }
+    match total {
+        Ok(value) => print(value),
+        Err(error) => print("failed: {error}"),
+    }
+    count = 0
+    while true {
+        count += 1
+        if count < 3 {
+            continue
+        }
+        break
+    }
+    print(count)
+}
+"""
+    assert _execute(_compile(tmp_path, source)) == (0, "failed: three\n3\n", "")
+
+
+def test_unsupported_features_are_rejected_at_build_time(tmp_path: Path) -> None:
+    """A program that spawns tasks is never compiled to bytecode."""
+    source = "fn work() {\n    print(1)\n}\n\nfn main() {\n    spawn work()\n}\n"
+
+    with pytest.raises(BytecodeError, match="bytecode doesn't support spawn yet; build the program without --emit bytecode"):
+        _compile(tmp_path, source)
+
+
+def test_read_bytecode_rejects_other_files_and_versions(tmp_path: Path) -> None:
+    """Only bytecode of this version loads; older bytecode asks to be rebuilt."""
+    path = write_bytecode(_compile(tmp_path, "fn main() {\n    print(1)\n}\n"), tmp_path / "main.znb")
+    document = json.loads(path.read_text())
+    document["version"] = 0
+    path.write_text(json.dumps(document))
+
+    with pytest.raises(ZincError, match="rebuild it with `zinc build --emit bytecode`"):
+        read_bytecode(path)
+    with pytest.raises(ZincError, match="is not Zinc bytecode"):
+        read_bytecode(tmp_path / "main.zn")
+
+
+def test_build_emit_bytecode_then_exec(tmp_path: Path) -> None:
+    """`zinc build --emit bytecode` writes a .znb file that `zinc exec` runs without Cargo."""
+    (tmp_path / "pkg.toml").write_text(PKG_TOML)
+    entry = tmp_path / "main.zn"
+    entry.write_text('fn main() {\n    print("from bytecode")\n    exit(3)\n}\n')
+    output = tmp_path / "app.znb"
+
+    built = CliRunner().invoke(main, ["build", "--emit", "bytecode", str(entry), "-o", str(output)])
+    assert built.exit_code == 0, built.output
+    entry.unlink()
+
+    result = CliRunner().invoke(main, ["exec", str(output)])
+    assert (result.exit_code, result.output) == (3, "from bytecode\n")
@@ -0,0 +1,58 @@
+"""Tests for reusing compiled programs across builds."""
+
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+import zinc.main
+from zinc.build import write_cargo_project
+from zinc.cache import default_cache_dir
+from zinc.main import compile_program
+
+
+UTIL = "fn double(x) {\n    return x * 2\n}\n"
+MAIN = "import util [double]\n\nfn main() {\n    print(double(21))\n}\n"
+
+
+def fail_front_end(*args, **kwargs):
+    """Stand in for the front end when a compile must come from the cache."""
+    raise AssertionError("the front end ran on a cached compile")
+
+
+def test_unchanged_sources_reuse_the_cached_program(
+    tmp_path: Path, monkeypatch: pytest.MonkeyPatch, write_package: Callable[..., Path]
+) -> None:
+    """A second compile of the same files and options skips checking and code generation."""
+    entry = write_package(tmp_path, MAIN, "main.zn", modules={"util.zn": UTIL})
+    cache_dir = default_cache_dir(entry)
+    first = compile_program(entry, cache_dir=cache_dir)
+
+    monkeypatch.setattr(zinc.main, "front_end", fail_front_end)
+    assert compile_program(entry, cache_dir=cache_dir).render() == first.render()
+    assert cache_dir == tmp_path / ".zinc-build" / "cache"
+
+
+def test_editing_an_imported_file_or_changing_options_recompiles(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Every module and manifest the compile read is part of the key, as are the codegen options."""
+    entry = write_package(tmp_path, MAIN, "main.zn", modules={"util.zn": UTIL})
+    cache_dir = default_cache_dir(entry)
+    compile_program(entry, cache_dir=cache_dir)
+
+    (tmp_path / "util.zn").write_text("fn double(x) {\n    return x + x\n}\n")
+    assert "x + x" in compile_program(entry, cache_dir=cache_dir).render()
+    assert "wrapping_add" in compile_program(entry, overflow="wrapping", cache_dir=cache_dir).render()
+
+    (tmp_path / "pkg.toml").write_text('[package]\nname = "tmp"\nversion = "0.1.0"\ndefault-int = "i32"\n')
+    assert "x: i32" in compile_program(entry, cache_dir=cache_dir).render()
+
+
+def test_unchanged_generated_files_are_not_rewritten(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Untouched sources keep their modification time, so cargo doesn't rebuild them."""
+    entry = write_package(tmp_path, MAIN, "main.zn", modules={"util.zn": UTIL})
+    project = write_cargo_project(compile_program(entry), "tmp", tmp_path / "build", rustfmt=False)
+    generated = [project / "Cargo.toml", *(project / "src").rglob("*.rs")]
+    mtimes = {path: path.stat().st_mtime_ns for path in generated}
+
+    write_cargo_project(compile_program(entry), "tmp", project, rustfmt=False)
+
+    assert {path: path.stat().st_mtime_ns for path in generated} == mtimes
@@ -1,5 +1,6 @@
 """Parameterized tests for Zinc compilation."""
 
+import re
 import subprocess
 import tempfile
 from collections import Counter
@@ -9,7 +10,7 @@ import click
 import pytest
 from zinc.atlas import AtlasBuilder
 from zinc.codegen import CodeGenVisitor, RustProgram
-from zinc.exceptions import ZincModuleError, ZincTypeError
+from zinc.exceptions import ZincError, ZincModuleError
 from zinc.modules import build_module_graph
 from zinc.struct_logging import configure_logging, get_logger
 from zinc.symbols import SymbolTableVisitor
@@ -22,7 +23,14 @@ RUST_SRC_DIR = RUST_SOURCE_DIR / "src"  # Cargo src directory
 OUTPUT_DIR = TEST_DIR / "output"
 CARGO_TOML = RUST_SOURCE_DIR / "Cargo.toml"
 RUNTIME_CRATE_PATH = "../../rust_runtime/zinc-internal"
+RUNTIME_CRATE_DIR = (TEST_DIR.parent / "rust_runtime" / "zinc-internal").resolve()
 NON_DETERMINISTIC_FOLDER = "non_deterministic"
+COMPARE_PREFIX = "// compare:"
+# exact: byte for byte; sorted and multiset: lines in any order, kept sorted or as written; regex: one pattern per line.
+COMPARE_MODES = ("exact", "sorted", "multiset", "regex")
+EXIT_PREFIX = "// exit:"
+# How a compile-fail fixture names the diagnostics it expects: a regex, a stable error code, or a message substring.
+EXPECTED_DIAGNOSTIC_PREFIXES = {"// expected-error:": "error", "// expected-code:": "code", "// expected-message:": "message"}
 COMPILE_ERROR_GROUPS = (
     "error_handling",
     "annotations",
@@ -56,6 +64,11 @@ def is_entry_fixture(relative: Path) -> bool:
     return all(not part.startswith("_") and "." not in part for part in relative.parts)
 
 
+def is_compile_fail_case(test_path: str) -> bool:
+    """Return True for fixtures the compiler must reject, which live under compile_errors/."""
+    return Path(test_path).parts[0] == COMPILE_ERROR_DIR.name
+
+
 def get_test_cases() -> list[str]:
     """Discover test cases by finding .zn files in source directory (including subdirs).
 
@@ -67,7 +80,7 @@ def get_test_cases() -> list[str]:
     for f in ZINC_SOURCE_DIR.glob("**/*.zn"):
         # Get path relative to ZINC_SOURCE_DIR, without extension
         relative = f.relative_to(ZINC_SOURCE_DIR).with_suffix("")
-        if relative.parts and relative.parts[0] == COMPILE_ERROR_DIR.name:
+        if is_compile_fail_case(str(relative)):
             continue
         if not is_entry_fixture(relative):
             continue
@@ -83,22 +96,59 @@ def get_compile_error_files(group: str) -> list[Path]:
     return sorted(group_dir.glob("*.zn"))
 
 
-def read_expected_error(source_path: Path) -> str:
-    """Read the expected error regex from a compile-error fixture."""
-    prefix = "// expected-error:"
+def read_expected_diagnostics(source_path: Path) -> list[tuple[str, str]]:
+    """Read what a compile-fail fixture expects its diagnostics to contain, as `(kind, value)` pairs.
+
+    `// expected-error:` is a regex searched for in the error, `// expected-code:`
+    an error code some diagnostic must carry, and `// expected-message:` text the
+    error must contain. A fixture may list several, and all must match.
+    """
+    expected = []
     for line in source_path.read_text().splitlines():
         stripped = line.strip()
-        if stripped.startswith(prefix):
-            return stripped[len(prefix) :].strip()
-    raise AssertionError(f"Missing {prefix} comment in {source_path}")
-
-
-def generate_cargo_toml(test_paths: list[str], runtime_features: set[str] | None = None) -> str:
+        for prefix, kind in EXPECTED_DIAGNOSTIC_PREFIXES.items():
+            if stripped.startswith(prefix):
+                expected.append((kind, stripped[len(prefix) :].strip()))
+    if not expected:
+        raise AssertionError(f"Missing {' or '.join(EXPECTED_DIAGNOSTIC_PREFIXES)} comment in {source_path}")
+    return expected
+
+
+def error_codes(exc: ZincError) -> set[str]:
+    """The error codes carried by a compile error and its diagnostics."""
+    diagnostics = getattr(exc, "diagnostics", [])
+    return {code for code in [getattr(exc, "code", None), *(diagnostic.code for diagnostic in diagnostics)] if code}
+
+
+def compile_fail_problems(source_path: Path) -> list[str]:
+    """Compile a compile-fail fixture and return how its diagnostics differ from what it expects; empty when they match."""
+    expected = read_expected_diagnostics(source_path)
+    try:
+        compile_zinc(source_path)
+    except ZincError as exc:
+        error, codes = str(exc), error_codes(exc)
+    else:
+        return [f"compiled without errors, but expected {kind} {value!r}" for kind, value in expected]
+    problems = []
+    for kind, value in expected:
+        if kind == "error" and re.search(value, error) is None:
+            problems.append(f"error doesn't match /{value}/:\n{error}")
+        elif kind == "code" and value not in codes:
+            problems.append(f"no diagnostic has code {value} (found {', '.join(sorted(codes)) or 'none'}):\n{error}")
+        elif kind == "message" and value not in error:
+            problems.append(f"error doesn't mention {value!r}:\n{error}")
+    return problems
+
+
+def generate_cargo_toml(
+    test_paths: list[str], runtime_features: set[str] | None = None, runtime_path: str | Path = RUNTIME_CRATE_PATH
+) -> str:
     """Generate Cargo.toml content with binary entries for each test.
 
     Args:
         test_paths: Relative paths without extension, e.g., "arithmetic" or "structs/01_basic_fields"
         runtime_features: Runtime crate features required by the generated programs.
+        runtime_path: Path to the runtime crate, relative to the manifest or absolute.
     """
     runtime_features = runtime_features or set()
     lines = [
@@ -112,7 +162,7 @@ def generate_cargo_toml(test_paths: list[str], runtime_features: set[str] | None
     ]
     if runtime_features:
         feature_list = ", ".join(f'"{feature}"' for feature in sorted(runtime_features))
-        lines.append(f'zinc-internal = {{ path = "{RUNTIME_CRATE_PATH}", default-features = false, features = [{feature_list}] }}')
+        lines.append(f'zinc-internal = {{ path = "{runtime_path}", default-features = false, features = [{feature_list}] }}')
     lines.append("")
 
     for test_path in sorted(test_paths):
@@ -127,17 +177,6 @@ def generate_cargo_toml(test_paths: list[str], runtime_features: set[str] | None
             ]
         )
 
-    # Add run_all binary for main.rs if it exists
-    if (RUST_SRC_DIR / "main.rs").exists():
-        lines.extend(
-            [
-                "[[bin]]",
-                'name = "run_all"',
-                'path = "src/main.rs"',
-                "",
-            ]
-        )
-
     return "\n".join(lines)
 
 
@@ -210,11 +249,77 @@ def format_multiset_diff(expected: str, observed: str) -> str:
     return "\n".join(lines)
 
 
-def run_cargo_bin(test_path: str) -> str:
-    """Run a test binary using cargo and return its output.
+# === Output comparison modes ===
+
+
+def read_compare_mode(source_path: Path, test_path: str) -> str:
+    """Read how a fixture's stdout is compared from its `// compare:` line.
+
+    Without one, fixtures under non_deterministic/ compare as multisets and the rest exactly.
+    """
+    if source_path.exists():
+        for line in source_path.read_text().splitlines():
+            stripped = line.strip()
+            if stripped.startswith(COMPARE_PREFIX):
+                mode = stripped[len(COMPARE_PREFIX) :].strip()
+                if mode not in COMPARE_MODES:
+                    raise ValueError(f"{source_path}: unknown compare mode {mode!r}; expected one of {', '.join(COMPARE_MODES)}")
+                return mode
+    return "multiset" if is_nondeterministic_test(test_path) else "exact"
+
+
+def read_expected_exit(source_path: Path) -> int:
+    """Read the exit status a fixture's program should end with from its `// exit:` line; 0 without one."""
+    for line in source_path.read_text().splitlines():
+        stripped = line.strip()
+        if stripped.startswith(EXIT_PREFIX):
+            status = stripped[len(EXIT_PREFIX) :].strip()
+            if not status.isdigit():
+                raise ValueError(f"{source_path}: exit status {status!r} is not a non-negative integer")
+            return int(status)
+    return 0
+
+
+def sorted_lines(text: str) -> str:
+    """Return the lines of `text` in sorted order."""
+    return "".join(line + "\n" for line in sorted(text.splitlines()))
+
+
+def regex_mismatch(expected: str, observed: str) -> str | None:
+    """Check output against a snapshot of one regex per line; return what doesn't match, or None."""
+    patterns, lines = expected.splitlines(), observed.splitlines()
+    for number, (pattern, line) in enumerate(zip(patterns, lines), start=1):
+        if re.fullmatch(pattern, line) is None:
+            return f"line {number}: {line!r} doesn't match /{pattern}/"
+    if len(patterns) != len(lines):
+        return f"expected {len(patterns)} lines, got {len(lines)}"
+    return None
+
+
+def outputs_match(expected: str, observed: str, mode: str) -> bool:
+    """Compare program output with its snapshot using a fixture's compare mode."""
+    if mode == "regex":
+        return regex_mismatch(expected, observed) is None
+    if mode in ("sorted", "multiset"):
+        return compare_outputs_as_multisets(expected, observed)
+    return expected == observed
+
+
+def blessed_output(expected: str | None, observed: str, mode: str) -> str | None:
+    """Return the snapshot to write for `observed` output, or None when a regex snapshot must be edited by hand."""
+    if mode == "sorted":
+        return sorted_lines(observed)
+    if mode == "regex":
+        return None if expected is not None else "".join(re.escape(line) + "\n" for line in observed.splitlines())
+    return observed
+
+
+def run_cargo_bin(test_path: str, expected_exit: int = 0) -> tuple[str, str]:
+    """Run a test binary using cargo and return its stdout and stderr.
 
     Args:
         test_path: Relative path without extension, e.g., "arithmetic" or "structs/01_basic_fields"
+        expected_exit: The exit status the program should end with
     """
     # Binary name uses underscores for path separators
     bin_name = test_path.replace("/", "_")
@@ -238,9 +343,9 @@ def run_cargo_bin(test_path: str) -> str:
         )
         stdout_file.seek(0)
         stdout = stdout_file.read()
-    if result.returncode != 0:
-        raise RuntimeError(f"Cargo run failed for {bin_name}:\n{result.stderr}")
-    return stdout
+    if result.returncode != expected_exit:
+        raise RuntimeError(f"{bin_name} exited with status {result.returncode}, expected {expected_exit}:\n{result.stderr}")
+    return stdout, result.stderr
 
 
 def build_cargo_project() -> None:
@@ -260,12 +365,8 @@ def assert_compile_error_files(group: str) -> None:
     source_paths = get_compile_error_files(group)
     assert source_paths, f"No compile-error fixtures found for group: {group}"
     for source_path in source_paths:
-        expected_error = read_expected_error(source_path)
-        try:
-            with pytest.raises((ZincTypeError, ZincModuleError), match=expected_error):
-                compile_zinc(source_path)
-        except AssertionError as exc:
-            raise AssertionError(f"{source_path}: {exc}") from exc
+        problems = compile_fail_problems(source_path)
+        assert not problems, f"{source_path}: " + "\n".join(problems)
 
 
 @pytest.mark.parametrize("group", COMPILE_ERROR_GROUPS, ids=list(COMPILE_ERROR_GROUPS))
@@ -291,6 +392,50 @@ def test_missing_pkg_toml(tmp_path: Path) -> None:
         compile_zinc(entry)
 
 
+def test_default_int_sets_the_width_of_inferred_integers(tmp_path: Path) -> None:
+    """With `default-int = "i32"`, fields, locals, and method returns agree without bridging casts."""
+    (tmp_path / "pkg.toml").write_text('[package]\nname = "counter"\nversion = "0.1.0"\ndefault-int = "i32"\n')
+    entry = tmp_path / "main.zn"
+    entry.write_text(
+        "\n".join(
+            [
+                "struct Counter {",
+                "    count: 0",
+                "    fn bump(by: i32) {",
+                "        self.count = self.count + by",
+                "    }",
+                "    fn next(x: i32) {",
+                "        return 1 + x",
+                "    }",
+                "}",
+                "",
+                "fn main() {",
+                "    c = Counter { count: 2 }",
+                "    c.bump(3)",
+                "    total = c.count * 2",
+                "    print(Counter.next(total))",
+                "}",
+            ]
+        )
+    )
+
+    rust_code = compile_zinc(entry)
+    assert "count: i32" in rust_code
+    assert "fn next(x: i32) -> i32" in rust_code
+    assert "i64" not in rust_code
+    assert " as " not in rust_code
+
+
+def test_default_int_rejects_unknown_widths(tmp_path: Path) -> None:
+    """Only signed integer types can be the package default."""
+    (tmp_path / "pkg.toml").write_text('[package]\nname = "counter"\nversion = "0.1.0"\ndefault-int = "u8"\n')
+    entry = tmp_path / "main.zn"
+    entry.write_text("fn main() {\n    print(1)\n}\n")
+
+    with pytest.raises(ZincModuleError, match="package.default-int must be one of i8, i16, i32, i64, i128, isize"):
+        compile_zinc(entry)
+
+
 def assert_no_inline_runtime_helpers(rust_code: str) -> None:
     """Assert generated Rust references the runtime crate instead of inlining helpers."""
     for definition in INLINE_RUNTIME_DEFINITIONS:
@@ -324,6 +469,36 @@ def test_runtime_imports_are_feature_scoped() -> None:
     assert "use zinc_internal::{" in metadata_code
     assert_no_inline_runtime_helpers(metadata_code)
 
+    hashing = compile_zinc_program(ZINC_SOURCE_DIR / "std_hash.zn")
+    hashing_code = hashing.render()
+    assert hashing.runtime_features == {"hash"}
+    assert "use zinc_internal::{hmac_sha256_hex, md5_hex, sha256_hex};" in hashing_code
+
+    uuid = compile_zinc_program(ZINC_SOURCE_DIR / "strings/02_uuid.zn")
+    uuid_code = uuid.render()
+    assert uuid.runtime_features == {"uuid"}
+    assert "use zinc_internal::{uuid_v4};" in uuid_code
+
+    number_format = compile_zinc_program(ZINC_SOURCE_DIR / "strings/04_format_number.zn")
+    number_format_code = number_format.render()
+    assert number_format.runtime_features == {"format"}
+    assert "use zinc_internal::{format_number};" in number_format_code
+
+    tempfile = compile_zinc_program(ZINC_SOURCE_DIR / "std_tempfile.zn")
+    tempfile_code = tempfile.render()
+    assert tempfile.runtime_features == {"temp"}
+    assert "use zinc_internal::{TempPath};" in tempfile_code
+
+    interrupt = compile_zinc_program(ZINC_SOURCE_DIR / "functions/09_on_interrupt.zn")
+    interrupt_code = interrupt.render()
+    assert interrupt.runtime_features == {"signal"}
+    assert "use zinc_internal::{on_interrupt};" in interrupt_code
+
+    async_interrupt = compile_zinc_program(ZINC_SOURCE_DIR / "concurrency/spawn/05_on_interrupt.zn")
+    async_interrupt_code = async_interrupt.render()
+    assert async_interrupt.runtime_features == {"channel"}
+    assert "tokio::signal::ctrl_c()" in async_interrupt_code
+
 
 @pytest.mark.parametrize("test_path", get_test_cases())
 def test_compile(test_path: str) -> None:
@@ -346,14 +521,25 @@ def test_compile(test_path: str) -> None:
     )
 
     # Run the binary using cargo
-    output = run_cargo_bin(test_path)
+    output, stderr = run_cargo_bin(test_path, read_expected_exit(zinc_file))
     # Output file path mirrors the test path structure
     expected_output_file = OUTPUT_DIR / f"{test_path}.out"
     expected_output = expected_output_file.read_text()
+    # Without a .err snapshot, the program must not write to stderr.
+    expected_stderr_file = OUTPUT_DIR / f"{test_path}.err"
+    expected_stderr = expected_stderr_file.read_text() if expected_stderr_file.exists() else ""
 
-    if is_nondeterministic_test(test_path):
-        assert compare_outputs_as_multisets(expected_output, output), (
-            f"Execution output mismatch for {test_path} (non-deterministic comparison)\n{format_multiset_diff(expected_output, output)}"
+    mode = read_compare_mode(zinc_file, test_path)
+    assert outputs_match(expected_stderr, stderr, mode), (
+        f"Stderr mismatch for {test_path}\nExpected:\n{expected_stderr}\nObserved:\n{stderr}"
+    )
+    if mode == "regex":
+        assert outputs_match(expected_output, output, mode), (
+            f"Execution output mismatch for {test_path}: {regex_mismatch(expected_output, output)}"
+        )
+    elif mode != "exact":
+        assert outputs_match(expected_output, output, mode), (
+            f"Execution output mismatch for {test_path} ({mode} comparison)\n{format_multiset_diff(expected_output, output)}"
         )
     else:
         assert output == expected_output, f"Execution output mismatch for {test_path}\nExpected:\n{expected_output}\nObserved:\n{output}"
@@ -415,22 +601,32 @@ def main(update_output: bool) -> None:
         # Run each binary and capture output
         for test_path in test_paths:
             # run the rust binary and capture the output
-            output = run_cargo_bin(test_path)
-
-            # write the output to the expected output file (create subdirs as needed)
-            expected_output_file = OUTPUT_DIR / f"{test_path}.out"
-            expected_output_file.parent.mkdir(parents=True, exist_ok=True)
-            expected_output_file.write_text(output)
-
-            logger.info(
-                event="updated_test",
-                ctx={
-                    "zinc": str(ZINC_SOURCE_DIR / f"{test_path}.zn"),
-                    "rust": str(RUST_SRC_DIR / f"{test_path}.rs"),
-                    "output": str(expected_output_file),
-                },
-            )
-
+            zinc_file = ZINC_SOURCE_DIR / f"{test_path}.zn"
+            output, stderr = run_cargo_bin(test_path, read_expected_exit(zinc_file))
+            mode = read_compare_mode(zinc_file, test_path)
+
+            # write each stream to its expected output file (create subdirs as needed); stderr only when there is some
+            for expected_output_file, observed in ((OUTPUT_DIR / f"{test_path}.out", output), (OUTPUT_DIR / f"{test_path}.err", stderr)):
+                expected = expected_output_file.read_text() if expected_output_file.exists() else None
+                if expected_output_file.suffix == ".err" and expected is None and not observed:
+                    continue
+                if expected is not None and outputs_match(expected, observed, mode):
+                    continue
+                snapshot = blessed_output(expected, observed, mode)
+                if snapshot is None:
+                    logger.warning(event="regex_output_mismatch", ctx={"output": str(expected_output_file)})
+                    continue
+                expected_output_file.parent.mkdir(parents=True, exist_ok=True)
+                expected_output_file.write_text(snapshot)
+
+                logger.info(
+                    event="updated_test",
+                    ctx={
+                        "zinc": str(zinc_file),
+                        "rust": str(RUST_SRC_DIR / f"{test_path}.rs"),
+                        "output": str(expected_output_file),
+                    },
+                )
 
 if __name__ == "__main__":
     main()
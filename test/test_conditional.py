@@ -0,0 +1,59 @@
+"""Tests for conditional compilation with `when` blocks."""
+
+from zinc.api import compile_str
+from zinc.conditional import BuildConfig, build_config
+from zinc.formatter import format_source
+
+PLATFORM_SOURCE = """\
+when os == "windows" {
+    const SEPARATOR = "\\\\"
+} else when family == "unix" and not release {
+    const SEPARATOR = "/"
+} else {
+    const SEPARATOR = ":"
+}
+
+fn main() {
+    when = 2
+    print("{SEPARATOR} {when}")
+}
+"""
+
+
+def test_when_chooses_the_branch_for_the_build_configuration() -> None:
+    """Only the first branch whose condition holds is compiled; `when` stays usable as a name elsewhere."""
+    builds = {
+        "windows": BuildConfig("windows", "x86_64", "windows"),
+        "linux": BuildConfig("linux", "x86_64", "unix"),
+        "release": BuildConfig("linux", "x86_64", "unix", debug=False),
+    }
+    rust = {}
+    for name, config in builds.items():
+        with build_config(config):
+            result = compile_str(PLATFORM_SOURCE)
+        assert result.ok, result.diagnostics
+        rust[name] = result.rust
+
+    assert '"\\\\"' in rust["windows"]
+    assert '"/"' in rust["linux"]
+    assert '":"' in rust["release"]
+    assert all("let when: i64 = 2;" in text for text in rust.values())
+
+
+def test_unknown_condition_is_reported_with_a_suggestion() -> None:
+    """A misspelled condition name is an error at the name, not a silently false branch."""
+    source = 'fn main() {\n    when arhc == "x86_64" {\n        print("x86")\n    }\n}\n'
+
+    result = compile_str(source)
+
+    assert not result.ok
+    assert [(d.message, d.line, d.column, d.suggestions) for d in result.diagnostics] == [
+        ("unknown condition 'arhc'", 2, 9, ("did you mean 'arch'?",)),
+    ]
+
+
+def test_formatter_keeps_every_branch_as_written() -> None:
+    """Formatting never drops the branches that were not chosen for the host."""
+    source = 'when os == "windows" {\nconst A = 1\n} else {\n  const A = 2\n}\n\nfn main() {\n  print( A )\n}\n'
+
+    assert format_source(source) == 'when os == "windows" {\nconst A = 1\n} else {\n  const A = 2\n}\n\nfn main() {\n    print(A)\n}\n'
@@ -0,0 +1,69 @@
+"""Tests for evaluating constant expressions at compile time."""
+
+from pathlib import Path
+
+from click.testing import CliRunner
+from zinc.main import main
+
+
+def compile_source(root: Path, source: str) -> str:
+    """Compile a single-file package and return the generated Rust."""
+    (root / "pkg.toml").write_text('[package]\nname = "tmp"\nversion = "0.1.0"\n')
+    entry = root / "prog.zn"
+    entry.write_text(source)
+    result = CliRunner().invoke(main, ["compile", str(entry)])
+    assert result.exit_code == 0, result.output
+    return result.output
+
+
+def test_arithmetic_strings_and_booleans_fold_to_their_value(tmp_path: Path) -> None:
+    """Operators over literals and consts become the literal they compute."""
+    source = (
+        'const GREETING = "hello"\n'
+        "const RADIUS = 5.0\n\n"
+        "fn main() {\n"
+        "    a = (2 + 3) * 4\n"
+        "    b = RADIUS * RADIUS / 2.0\n"
+        '    c = GREETING + ", world"\n'
+        "    d = 3 > 2 and not false\n"
+        "    print(a)\n    print(b)\n    print(c)\n    print(d)\n"
+        "}\n"
+    )
+    rust_code = compile_source(tmp_path, source)
+
+    assert "let a: i64 = 20;" in rust_code
+    assert "let b = 12.5;" in rust_code
+    assert 'let c = String::from("hello, world");' in rust_code
+    assert "let d = true;" in rust_code
+
+
+def test_integer_division_follows_rust(tmp_path: Path) -> None:
+    """Division truncates toward zero and the remainder takes the dividend's sign."""
+    rust_code = compile_source(tmp_path, "fn main() {\n    q = -7 / 2\n    r = -7 % 2\n    print(q)\n    print(r)\n}\n")
+
+    assert "let q: i64 = -3;" in rust_code
+    assert "let r: i64 = -1;" in rust_code
+
+
+def test_locals_propagate_only_when_assigned_once(tmp_path: Path) -> None:
+    """A local assigned once carries its value; one that changes is read at run time."""
+    source = (
+        "fn main() {\n"
+        "    limit = 10\n"
+        "    count = 0\n"
+        "    count += 1\n"
+        "    print(limit * 2)\n"
+        "    print(count * 2)\n"
+        "}\n"
+    )
+    rust_code = compile_source(tmp_path, source)
+
+    assert 'println!("{}", 20);' in rust_code
+    assert 'println!("{}", count * 2);' in rust_code
+
+
+def test_overflowing_results_are_left_to_run_time(tmp_path: Path) -> None:
+    """A result outside its type's range keeps the expression, so the program still reports the overflow."""
+    rust_code = compile_source(tmp_path, "fn main() {\n    big = 9223372036854775807 + 1\n    print(big)\n}\n")
+
+    assert "let big = 9223372036854775807 + 1;" in rust_code
@@ -0,0 +1,83 @@
+"""Tests for the statement and branch coverage behind `zinc test --coverage`."""
+
+import shutil
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from zinc.api import check
+from zinc.coverage import CoverageOptions, CoveragePoint, CoverageReport, read_counts
+from zinc.main import main
+
+SOURCE = """\
+fn classify(n: i64) -> string {
+    if n < 0 {
+        return "negative"
+    } else if n == 0 {
+        return "zero"
+    }
+    return "positive"
+}
+
+@test
+fn test_classify() {
+    assert_eq(classify(5), "positive")
+    assert_eq(classify(0), "zero")
+}
+
+fn main() {
+    print(classify(-1))
+}
+"""
+
+
+def test_covered_programs_count_statements_and_every_way_out_of_a_branch(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Each statement gets a counter, and an if without an else gains one to count falling through."""
+    entry = write_package(tmp_path, SOURCE, "hello.zn")
+
+    program = check(entry).codegen.generate(coverage=CoverageOptions(tmp_path / "counts"))
+    statements = sorted(point.line for point in program.coverage_points if point.branch is None)
+    branches = sorted((point.line, point.column, point.branch) for point in program.coverage_points if point.branch is not None)
+    assert (statements, branches) == ([2, 3, 5, 7, 17], [(2, 4, 0), (2, 4, 1), (2, 4, 2)])
+    assert "} else {\n        __zinc_coverage::hit(" in program.render()
+    assert check(entry).codegen.generate().coverage_points == []
+
+
+def test_report_merges_runs_into_lcov(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Runs add up, a line counts as its most-run statement, and unreached branch points report `-`."""
+    source = write_package(tmp_path, "fn main() {\n}\n")
+    (tmp_path / "counts").write_text("1 0 2 0 0\n1 0 0 0 0\n")
+    points = [
+        CoveragePoint(str(source), 1),
+        CoveragePoint(str(source), 1),
+        CoveragePoint(str(source), 2, 4, 0),
+        CoveragePoint(str(source), 2, 4, 1),
+        CoveragePoint(str(source), 3, 4, 0),
+    ]
+    report = CoverageReport()
+    report.add(points, read_counts(tmp_path / "counts", len(points)))
+
+    branches = ["BRDA:2,4,0,2", "BRDA:2,4,1,0", "BRDA:3,4,0,-", "BRF:3", "BRH:1"]
+    lines = ["DA:1,2", "LF:1", "LH:1"]
+    assert report.lcov() == "\n".join(["TN:", f"SF:{source.resolve()}", *branches, *lines, "end_of_record", ""])
+    assert report.summary() == "coverage: 100.0% of lines (1/1), 33.3% of branches (1/3)"
+
+
+def test_coverage_flag_writes_lcov_and_html(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """`zinc test --coverage` reports what the tests ran, mapped back to the Zinc file."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    entry = write_package(tmp_path, SOURCE, "hello.zn")
+    coverage_dir = tmp_path / "coverage"
+
+    result = CliRunner().invoke(main, ["test", str(entry), "--coverage", "--coverage-dir", str(coverage_dir)])
+
+    assert result.exit_code == 0, result.output
+    assert "coverage: 83.3% of lines (5/6), 66.7% of branches (2/3)" in result.output
+    lcov = (coverage_dir / "lcov.info").read_text()
+    assert f"SF:{entry.resolve()}\n" in lcov
+    assert "DA:3,0\n" in lcov and "DA:7,1\n" in lcov and "BRDA:2,4,0,0\n" in lcov
+    page = next(coverage_dir.glob("*hello.zn.html"))
+    assert '<tr class="miss"><td>3</td>' in page.read_text()
+    assert f'href="{page.name}"' in (coverage_dir / "index.html").read_text()
@@ -0,0 +1,67 @@
+"""Tests for leaving methods nothing calls out of the generated Rust."""
+
+from pathlib import Path
+
+from click.testing import CliRunner
+from zinc.main import main
+
+PROGRAM = """\
+struct Counter {
+    count: 0
+
+    fn total() {
+        return self.count + self.bonus()
+    }
+
+    fn bonus() {
+        return self.count * 2
+    }
+
+    fn reset() {
+        return self.orphan()
+    }
+
+    fn orphan() {
+        return self.count - 1
+    }
+
+    fn describe() {
+        return self.count + 1
+    }
+}
+
+fn main() {
+    c = Counter { count: 2 }
+    print(c.total())
+    print("{c.describe()}")
+}
+"""
+
+
+def compile_program(root: Path, *options: str) -> str:
+    """Compile PROGRAM as a single-file package and return the generated Rust."""
+    (root / "pkg.toml").write_text('[package]\nname = "tmp"\nversion = "0.1.0"\n')
+    entry = root / "prog.zn"
+    entry.write_text(PROGRAM)
+    result = CliRunner().invoke(main, ["compile", str(entry), *options])
+    assert result.exit_code == 0, result.output
+    return result.output
+
+
+def test_methods_are_emitted_only_when_reachable_code_calls_them(tmp_path: Path) -> None:
+    """Calls from main, from live methods, and from interpolations keep a method; the rest are dropped."""
+    rust_code = compile_program(tmp_path)
+
+    assert "fn total(&self)" in rust_code
+    assert "fn bonus(&self)" in rust_code
+    assert "fn describe(&self)" in rust_code
+    assert "fn reset(" not in rust_code
+    assert "fn orphan(" not in rust_code
+
+
+def test_keep_unused_emits_every_method(tmp_path: Path) -> None:
+    """--keep-unused is for Rust that other code will call into."""
+    rust_code = compile_program(tmp_path, "--keep-unused")
+
+    assert "fn reset(&self)" in rust_code
+    assert "fn orphan(&self)" in rust_code
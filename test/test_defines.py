@@ -0,0 +1,60 @@
+"""Tests for build-time constants set with `--define`."""
+
+from pathlib import Path
+
+from click.testing import CliRunner
+from zinc.api import compile_str
+from zinc.conditional import BuildConfig, build_config
+from zinc.main import main
+
+SOURCE = """\
+const VERSION = "dev"
+const BUILD = 0
+const FAST = false
+
+fn main() {
+    print("{VERSION} {BUILD} {FAST}")
+}
+"""
+
+
+def _compile(*defines: tuple[str, str]):
+    with build_config(BuildConfig("linux", "x86_64", "unix", defines=defines)):
+        return compile_str(SOURCE)
+
+
+def test_defines_replace_const_values_and_keep_their_types() -> None:
+    """Strings are taken verbatim, braces and all; numbers and bools are read as literals of the default's kind."""
+    result = _compile(("VERSION", "1.2.{3}"), ("BUILD", "-42"), ("FAST", "true"))
+
+    assert result.ok, result.diagnostics
+    assert 'String::from(r"1.2.{3}")' in result.rust
+    assert "MAIN__BUILD: i64 = -42;" in result.rust
+    assert "MAIN__FAST: bool = true;" in result.rust
+    assert 'String::from("dev")' in _compile().rust
+
+
+def test_define_errors_name_the_const() -> None:
+    """A value of the wrong kind points at the default; an unknown const suggests the closest one."""
+    mistyped = _compile(("BUILD", "1.5"))
+    assert [(d.message, d.line, d.column) for d in mistyped.diagnostics] == [
+        ("--define BUILD=1.5: the value must be an integer, like the const's default", 2, 14),
+    ]
+
+    misspelled = _compile(("VERSON", "1"))
+    assert [d.message for d in misspelled.diagnostics] == ["--define VERSON: module 'main' has no const 'VERSON'; did you mean 'VERSION'?"]
+
+
+def test_define_option_must_be_name_equals_value(tmp_path: Path) -> None:
+    """The CLI rejects defines without a value before compiling anything."""
+    (tmp_path / "pkg.toml").write_text('[package]\nname = "tmp"\nversion = "0.1.0"\n')
+    entry = tmp_path / "main.zn"
+    entry.write_text(SOURCE)
+
+    result = CliRunner().invoke(main, ["compile", str(entry), "--define", "BUILD=7"])
+    assert result.exit_code == 0, result.output
+    assert "MAIN__BUILD: i64 = 7;" in result.output
+
+    invalid = CliRunner().invoke(main, ["compile", str(entry), "--define", "BUILD"])
+    assert invalid.exit_code == 2
+    assert "expected NAME=VALUE, got 'BUILD'" in invalid.output
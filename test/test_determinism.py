@@ -0,0 +1,75 @@
+"""Tests that generated Rust is the same on every run and in every checkout."""
+
+import os
+import shutil
+import subprocess
+import sys
+from collections.abc import Callable
+from pathlib import Path
+
+from zinc.main import compile_program
+
+UTIL = """\
+struct Pair {
+    left: 0
+    right: 0
+
+    fn total() {
+        return self.left + self.right
+    }
+}
+
+fn pick(a, b) {
+    return a
+}
+"""
+
+MAIN = """\
+import util [Pair, pick]
+
+fn main() {
+    pair = Pair { left: 1, right: 2 }
+    counts = {"a": 1, "b": 2, "c": 3}
+    seen = {"x", "y", "z"}
+    print(pair.total())
+    print(pick(1, 2))
+    print(pick("one", "two"))
+    print(pick(1.5, 2.5))
+    print(counts["b"])
+    print(seen.contains("y"))
+    print(meta(Pair).file)
+}
+"""
+
+
+def compile_with_hash_seed(entry: Path, seed: str) -> str:
+    """Compile in a fresh interpreter whose set and dict hashing is seeded differently."""
+    result = subprocess.run(
+        [sys.executable, "-m", "zinc.main", "compile", str(entry)],
+        capture_output=True,
+        text=True,
+        env={**os.environ, "PYTHONHASHSEED": seed},
+        check=True,
+    )
+    return result.stdout
+
+
+def test_output_does_not_depend_on_hash_seed(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Symbol tables and specializations are emitted in source order, not hash order."""
+    entry = write_package(tmp_path, MAIN, "main.zn", modules={"util.zn": UTIL})
+
+    outputs = {compile_with_hash_seed(entry, seed) for seed in ("0", "1", "2")}
+
+    assert len(outputs) == 1
+
+
+def test_output_does_not_depend_on_checkout_location(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Source paths embedded in the program are relative to the package root."""
+    entry = write_package(tmp_path / "first", MAIN, "main.zn", modules={"util.zn": UTIL})
+    moved = tmp_path / "elsewhere" / "second"
+    shutil.copytree(entry.parent, moved)
+
+    rust_code = compile_program(entry, warn=False).render()
+
+    assert rust_code == compile_program(moved / "main.zn", warn=False).render()
+    assert str(tmp_path) not in rust_code
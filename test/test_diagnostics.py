@@ -0,0 +1,318 @@
+"""Tests for rendering compiler diagnostics."""
+
+import json
+from collections.abc import Callable
+from pathlib import Path
+
+import click
+import pytest
+from click.testing import CliRunner
+from zinc.diagnostics import Diagnostic, render_diagnostic
+from zinc.main import main
+
+SOURCE = """\
+fn main() {
+    count = 3
+    if count {
+        print(count)
+    }
+    y: i64 = 1
+    y = "text"
+}
+"""
+
+
+def test_render_shows_the_line_a_caret_span_and_notes() -> None:
+    """The caret covers the span and keeps tabs so it lines up with the excerpt."""
+    diagnostic = Diagnostic(
+        "variable 'y' expects a compatible 'i64' value",
+        "prog.zn",
+        12,
+        5,
+        11,
+        label="expected i64, found String",
+        notes=("'y' is declared as 'i64'",),
+        suggestions=("store a value of a different type under a new name",),
+    )
+    source = "\n" * 11 + '\ty = "text"\n'
+
+    assert render_diagnostic(diagnostic, source) == (
+        "error: variable 'y' expects a compatible 'i64' value\n"
+        "  --> prog.zn:12:6\n"
+        "   |\n"
+        '12 | \ty = "text"\n'
+        "   | \t    ^^^^^^ expected i64, found String\n"
+        "   |\n"
+        "   = note: 'y' is declared as 'i64'\n"
+        "   = help: store a value of a different type under a new name"
+    )
+    assert render_diagnostic(Diagnostic("no main function"), None) == "error: no main function"
+    assert "\x1b[" in render_diagnostic(diagnostic, source, color=True)
+
+
+def test_cli_prints_every_error_with_its_source_line(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """Commands print diagnostics without color when the output is not a terminal."""
+    monkeypatch.chdir(tmp_path)
+    write_package(tmp_path, SOURCE)
+
+    result = CliRunner().invoke(main, ["check", "prog.zn"])
+
+    assert result.exit_code == 1
+    assert "\x1b[" not in result.output
+    assert (
+        "error: if condition must be a bool\n"
+        " --> prog.zn:3:8\n"
+        "  |\n"
+        "3 |     if count {\n"
+        "  |        ^^^^^ expected bool, found i64\n"
+        "  |\n"
+        "  = help: compare explicitly, e.g. `count != 0`\n"
+    ) in result.output
+    assert " --> prog.zn:7:9\n" in result.output
+    assert result.output.endswith(
+        "error: aborting due to 2 previous errors\n\nFor more information about this error, try `zinc explain Z0003`.\n"
+    )
+
+
+@pytest.mark.parametrize(
+    "args, no_color, colored",
+    [
+        (["check", "--color", "always"], "", True),
+        (["--color", "always", "check"], "1", True),
+        (["check", "--color", "never"], "", False),
+        (["check", "--color", "auto"], "1", False),
+    ],
+)
+def test_color_follows_the_flag_then_no_color(
+    tmp_path: Path, monkeypatch, args: list[str], no_color: str, colored: bool, write_package: Callable[..., Path]
+) -> None:
+    """`--color=always` and `--color=never` win, on the command or before it; otherwise NO_COLOR turns color off."""
+    monkeypatch.chdir(tmp_path)
+    monkeypatch.setenv("NO_COLOR", no_color)
+    write_package(tmp_path, SOURCE)
+
+    result = CliRunner().invoke(main, [*args, "prog.zn"], color=True)
+
+    assert result.exit_code == 1
+    assert ("\x1b[" in result.output) is colored
+    assert "error: if condition must be a bool" in click.unstyle(result.output)
+
+
+def test_syntax_errors_label_the_offending_token(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """ANTLR messages keep their wording and gain a caret label."""
+    monkeypatch.chdir(tmp_path)
+    write_package(tmp_path, "fn helper(a: i64 -> i64 {\n    return a\n}\n\nfn main() {\n    print(helper(1))\n}\n")
+
+    result = CliRunner().invoke(main, ["compile", "prog.zn"])
+
+    assert result.exit_code == 1
+    assert "1 | fn helper(a: i64 -> i64 {\n  |                  ^^ expected ')' here\n" in result.output
+
+
+def test_columns_count_characters_and_carets_follow_display_width(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """Non-ASCII identifiers lex, columns count characters, and wide text gets two carets per character."""
+    monkeypatch.chdir(tmp_path)
+    write_package(tmp_path, 'fn main() {\n    名前: i64 = "世界"\n    café = missing(名前)\n}\n')
+
+    result = CliRunner().invoke(main, ["check", "prog.zn"])
+
+    assert result.exit_code == 1
+    assert ' --> prog.zn:2:15\n  |\n2 |     名前: i64 = "世界"\n  |                 ^^^^^^ expected i64, found String\n' in result.output
+    assert " --> prog.zn:3:12\n  |\n3 |     café = missing(名前)\n  |            ^^^^^^^\n" in result.output
+
+
+def test_json_error_format_prints_one_object_per_diagnostic(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """--error-format json carries the same information as the human form, with 1-based columns."""
+    monkeypatch.chdir(tmp_path)
+    write_package(tmp_path, SOURCE)
+
+    result = CliRunner().invoke(main, ["check", "--error-format", "json", "prog.zn"])
+
+    assert result.exit_code == 1
+    first, second = (json.loads(line) for line in result.output.splitlines())
+    assert first == {
+        "file": "prog.zn",
+        "span": {"line": 3, "column": 8, "end_column": 13},
+        "severity": "error",
+        "code": None,
+        "message": "if condition must be a bool",
+        "label": "expected bool, found i64",
+        "notes": [],
+        "suggestions": ["compare explicitly, e.g. `count != 0`"],
+    }
+    assert (second["span"]["line"], second["notes"]) == (7, ["'y' is declared as 'i64'"])
+
+
+def test_errors_without_a_position_are_diagnostics_too(tmp_path: Path) -> None:
+    """Module loading errors use the same formats, without a span."""
+    entry = tmp_path / "loose.zn"
+    entry.write_text("fn main() {\n    print(1)\n}\n")
+
+    human = CliRunner().invoke(main, ["compile", str(entry)])
+    machine = CliRunner().invoke(main, ["compile", str(entry), "--error-format", "json"])
+
+    assert human.exit_code == machine.exit_code == 1
+    assert human.output == f"error: missing pkg.toml or zinc.toml for {entry}\n"
+    assert json.loads(machine.output)["span"] is None
+
+
+def test_unknown_names_suggest_the_closest_match(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """Misspelled variables, fields, and methods point at what was probably meant."""
+    monkeypatch.chdir(tmp_path)
+    write_package(
+        tmp_path,
+        "struct Point {\n    x: i64\n    y: i64\n}\n\n"
+        "fn main() {\n    count = 3\n    print(coutn)\n    p = Point { x: 1, y: 2 }\n    print(p.xx)\n"
+        "    items = [1, 2]\n    items.psh(3)\n    print(unrelated)\n}\n",
+    )
+
+    result = CliRunner().invoke(main, ["check", "--error-format", "json", "prog.zn"])
+
+    errors = [json.loads(line) for line in result.output.splitlines()]
+    assert [(error["message"], error["suggestions"]) for error in errors] == [
+        ("unknown variable 'coutn'", ["did you mean 'count'?"]),
+        ("struct 'Point' has no member 'xx'", ["did you mean 'x'?"]),
+        ("no member 'psh' and no visible function 'psh' for UFCS", ["did you mean 'push'?"]),
+        ("unknown variable 'unrelated'", []),
+    ]
+
+
+def test_unused_bindings_and_private_functions_are_warnings(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """Warnings don't fail the command; a leading underscore marks a binding as intentionally unused."""
+    monkeypatch.chdir(tmp_path)
+    write_package(
+        tmp_path,
+        "fn _spare() -> i64 {\n    return 1\n}\n\n"
+        "fn add(a: i64, b: i64) -> i64 {\n    return a\n}\n\n"
+        "fn main() {\n    total = 0\n    _ignored = 2\n    for i in 0..2 {\n        total += 1\n    }\n"
+        '    shown = 3\n    print("{shown}")\n    print(add(1, 2))\n}\n',
+    )
+
+    result = CliRunner().invoke(main, ["check", "--error-format", "json", "prog.zn"])
+
+    assert result.exit_code == 0
+    *lines, ok = result.output.splitlines()
+    assert ok == "prog.zn: OK"
+    warnings = [json.loads(line) for line in lines]
+    assert [(warning["severity"], warning["message"], warning["span"]["line"]) for warning in warnings] == [
+        ("warning", "function '_spare' is never used", 1),
+        ("warning", "unused variable 'b'", 5),
+        ("warning", "unused variable 'total'", 10),
+        ("warning", "unused variable 'i'", 12),
+    ]
+    assert warnings[1]["suggestions"] == ["if this is intentional, prefix it with an underscore: '_b'"]
+
+
+def test_unreachable_statements_and_branches_are_warnings(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """Code after a diverging statement and branches behind literal conditions are reported once each."""
+    monkeypatch.chdir(tmp_path)
+    write_package(
+        tmp_path,
+        "fn sign(n: i64) -> i64 {\n    if n < 0 {\n        return -1\n    } else {\n        return 1\n    }\n    print(\"done\")\n}\n\n"
+        "fn main() {\n    for i in 0..3 {\n        continue\n        print(i)\n    }\n"
+        '    if false {\n        print("off")\n    }\n    while false {\n        print("never")\n    }\n    print(sign(2))\n}\n',
+    )
+
+    result = CliRunner().invoke(main, ["check", "--error-format", "json", "prog.zn"])
+
+    assert result.exit_code == 0
+    warnings = [json.loads(line) for line in result.output.splitlines()[:-1]]
+    assert [(warning["message"], warning["span"]["line"], warning["notes"]) for warning in warnings] == [
+        ("unreachable statement", 7, ["any code following this `if` on line 2 is unreachable"]),
+        ("unreachable statement", 13, ["any code following this `continue` on line 12 is unreachable"]),
+        ("unreachable branch", 15, ["its condition is always false"]),
+        ("unreachable loop body", 18, ["the loop condition is always false"]),
+    ]
+
+
+def test_match_arms_report_shadowing_and_redundant_wildcards(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """A shadowed arm points back at the arm that covers it; a `_` arm nothing reaches is only a warning."""
+    monkeypatch.chdir(tmp_path)
+    enum = "enum Light {\n    Red\n    Green\n}\n\n"
+    write_package(
+        tmp_path,
+        enum + "fn main() {\n    light = Light.Red\n    match light {\n        Light.Red => print(\"stop\"),\n"
+        '        Light.Red => print("again"),\n        _ => print("go"),\n    }\n}\n',
+    )
+
+    result = CliRunner().invoke(main, ["check", "--error-format", "json", "prog.zn"])
+
+    assert result.exit_code == 1
+    [error] = [json.loads(line) for line in result.output.splitlines()]
+    assert (error["message"], error["span"]["line"]) == ("duplicate match arm for variant 'Light.Red'", 10)
+    assert error["notes"] == ["the arm on line 9 already matches it, so this arm is unreachable"]
+
+    write_package(
+        tmp_path,
+        enum + "fn main() {\n    light = Light.Red\n    match light {\n        Light.Red => print(\"stop\"),\n"
+        '        Light.Green => print("go"),\n        _ => print("blink"),\n    }\n}\n',
+    )
+
+    result = CliRunner().invoke(main, ["check", "--error-format", "json", "prog.zn"])
+
+    assert result.exit_code == 0
+    [warning, ok] = result.output.splitlines()
+    assert ok == "prog.zn: OK"
+    warning = json.loads(warning)
+    assert (warning["severity"], warning["message"], warning["span"]["line"]) == ("warning", "unreachable match arm", 11)
+    assert warning["notes"] == ["every variant of enum 'Light' already has an arm"]
+
+
+def test_reads_of_unassigned_variables_point_at_the_assignment(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """Reading a name the function assigns later, or only inside a finished block, explains which one it is."""
+    monkeypatch.chdir(tmp_path)
+    write_package(
+        tmp_path,
+        "fn main() {\n    total = total + 1\n    if total > 0 {\n        doubled = total * 2\n    }\n"
+        '    print("{doubled}")\n}\n',
+    )
+
+    result = CliRunner().invoke(main, ["check", "--error-format", "json", "prog.zn"])
+
+    assert result.exit_code == 1
+    errors = [json.loads(line) for line in result.output.splitlines()]
+    assert [(error["message"], error["span"]["line"], error["notes"]) for error in errors] == [
+        ("variable 'total' is read before it is assigned", 2, ["'total' is first assigned on line 2"]),
+        (
+            "variable 'doubled' is only assigned inside an earlier block",
+            6,
+            ["'doubled' is assigned on line 4, and a variable first assigned in a block goes away when the block ends"],
+        ),
+    ]
+
+
+def test_private_fields_name_the_accessor_to_use(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """Reading a private field outside its struct suggests the getter; interpolated reads are checked too."""
+    monkeypatch.chdir(tmp_path)
+    write_package(
+        tmp_path,
+        "struct Account {\n    _balance: i64\n\n    fn balance() {\n        return self._balance\n    }\n}\n\n"
+        'fn main() {\n    account = Account { _balance: 10 }\n    print("{account._balance}")\n    print(account.balance())\n}\n',
+    )
+
+    result = CliRunner().invoke(main, ["check", "--error-format", "json", "prog.zn"])
+
+    assert result.exit_code == 1
+    [error] = [json.loads(line) for line in result.output.splitlines()]
+    assert (error["message"], error["span"]["line"]) == ("field '_balance' of struct 'Account' is private", 11)
+    assert error["suggestions"] == ["use the accessor instead: 'account.balance()'"]
+
+
+def test_annotation_mismatches_explain_inferred_types(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """An unannotated value rejected by an annotation gets a note saying where its type was inferred."""
+    monkeypatch.chdir(tmp_path)
+    write_package(
+        tmp_path,
+        "fn scale(value: i64) -> i64 {\n    return value * 2\n}\n\n"
+        "fn relay(n) {\n    count: i64 = n\n    return count\n}\n\n"
+        'fn main() {\n    x = 1.5\n    print(scale(x))\n    print(relay("two"))\n}\n',
+    )
+
+    result = CliRunner().invoke(main, ["check", "--error-format", "json", "prog.zn"])
+
+    assert result.exit_code == 1
+    errors = [json.loads(line) for line in result.output.splitlines()]
+    assert [(error["span"]["line"], error["notes"]) for error in errors] == [
+        (6, ["'n' has no type annotation, so it takes the type of its argument, 'String'"]),
+        (12, ["'x' has no type annotation; it was inferred as 'f64' from its assignment on line 11"]),
+    ]
@@ -0,0 +1,53 @@
+"""Tests for `zinc doc` documentation extraction and rendering."""
+
+from zinc.docs import document_module, render_html, render_markdown
+
+SOURCE = """\
+//! Shapes on the plane.
+
+/// A point.
+struct Point {
+    /// Horizontal offset.
+    x: i64
+    _cache: i64
+
+    /// Distance from the origin along both axes.
+    fn manhattan() -> i64 {
+        return self.x
+    }
+}
+
+/// Adds `a` and <b>.
+fn add(a: i64, b: i64) -> i64 {
+    return a + b
+}
+
+//// Four slashes are an ordinary comment.
+fn _helper() {
+}
+"""
+
+
+def test_public_declarations_are_documented_with_signatures_and_doc_comments() -> None:
+    """`///` comments attach to the declaration below; private names are left out."""
+    module = document_module(SOURCE, "geometry")
+
+    assert module.doc == "Shapes on the plane."
+    point, add = module.items
+    assert (point.kind, point.signature, point.doc) == ("struct", "struct Point", "A point.")
+    assert [(member.signature, member.doc) for member in point.members] == [
+        ("x: i64", "Horizontal offset."),
+        ("fn manhattan() -> i64", "Distance from the origin along both axes."),
+    ]
+    assert (add.signature, add.doc) == ("fn add(a: i64, b: i64) -> i64", "Adds `a` and <b>.")
+
+
+def test_markdown_and_html_pages() -> None:
+    """Markdown keeps doc text as written; HTML escapes it."""
+    module = document_module(SOURCE, "geometry")
+
+    markdown = render_markdown(module)
+    page = render_html(module)
+
+    assert "## Functions\n\n### add\n\n```zinc\nfn add(a: i64, b: i64) -> i64\n```\n\nAdds `a` and <b>." in markdown
+    assert "<p>Adds `a` and &lt;b&gt;.</p>" in page and "<pre><code>fn add(a: i64, b: i64) -&gt; i64</code></pre>" in page
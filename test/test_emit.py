@@ -0,0 +1,174 @@
+"""Tests for dumping compiler stages with `zinc compile --emit`."""
+
+import json
+from collections.abc import Callable
+from pathlib import Path
+
+from click.testing import CliRunner
+from zinc.main import main
+
+PROGRAM = """\
+fn twice(x) {
+    return x + x
+}
+
+fn main() {
+    print(twice(2))
+    print(twice(1.5))
+}
+"""
+
+
+def emit(entry: Path, *options: str) -> str:
+    """Run `zinc compile` with the given options and return its output."""
+    result = CliRunner().invoke(main, ["compile", str(entry), *options])
+    assert result.exit_code == 0, result.output
+    return result.output
+
+
+def test_tokens_and_ast_stop_after_parsing(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Lexer and parser dumps work even when the program does not type-check."""
+    entry = write_package(tmp_path, 'fn main() {\n    x: i64 = "text"\n}\n')
+
+    tokens = emit(entry, "--emit", "tokens").splitlines()
+    assert tokens[0].split() == ["1:0", "FN", '"fn"']
+    assert tokens[-1].split() == ["3:0", "RBRACE", '"}"']
+
+    ast = emit(entry, "--emit", "ast").splitlines()
+    assert ast[:3] == ["program @1:0", "  statement @1:0", "    functionDeclaration @1:0"]
+    assert '                  STRING "\\"text\\"" @2:13' in ast
+
+    tree = json.loads(emit(entry, "--emit", "ast", "--json"))
+    assert tree["node"] == "program"
+    assert tree["children"][0]["children"][0]["children"][0] == {"token": "FN", "text": "fn", "line": 1, "column": 0}
+
+
+def test_typed_ast_lists_each_specialization(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """A generic function appears once per instantiation, with the types resolved for that instance."""
+    entry = write_package(tmp_path, PROGRAM)
+
+    functions = json.loads(emit(entry, "--emit", "typed-ast", "--json"))
+
+    assert [function["rust_name"] for function in functions] == ["prog__twice_f64", "prog__twice_i64", "prog__main"]
+    parameter = functions[0]["tree"]["children"][3]["children"][0]
+    assert (parameter["node"], parameter["type"]) == ("parameter", "f64")
+
+    text = emit(entry, "--emit", "typed-ast")
+    assert text.startswith(f"// prog::twice (prog__twice_f64) in {entry.resolve()}\nfunctionDeclaration @1:0\n")
+    assert "        additiveExpr : f64 @2:11" in text
+    assert "        additiveExpr : i64 @2:11" in text
+
+
+def test_rust_stage_as_json_reports_dependencies(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """The JSON form of the Rust stage carries what a Cargo project needs."""
+    entry = write_package(tmp_path, 'fn main() {\n    print(uuid())\n}\n')
+
+    data = json.loads(emit(entry, "--emit", "rust", "--json"))
+
+    assert "fn main() {" in data["rust"]
+    assert data["runtime_features"] == ["uuid"]
+    assert data["crates"] == {}
+    assert data["rust"] + "\n" == emit(entry, "--emit", "rust").split("\n", 1)[1]
+
+
+def test_tree_command_prints_the_parse_tree(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """`zinc tree` is shorthand for `compile --emit ast`."""
+    entry = write_package(tmp_path, PROGRAM)
+
+    result = CliRunner().invoke(main, ["tree", str(entry)])
+
+    assert result.exit_code == 0, result.output
+    assert result.output == emit(entry, "--emit", "ast")
+
+
+def test_out_dir_writes_one_file_per_source(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Several files can be compiled at once into a directory that is created on demand."""
+    entry = write_package(tmp_path, PROGRAM)
+    other = tmp_path / "other.zn"
+    other.write_text("fn main() {\n    print(1)\n}\n")
+    out_dir = tmp_path / "gen" / "rust"
+
+    emit(entry, str(other), "--out-dir", str(out_dir))
+    emit(entry, "--out-dir", str(out_dir), "--emit", "ast", "--json")
+
+    assert sorted(path.name for path in out_dir.iterdir()) == ["other.rs", "prog.json", "prog.rs"]
+    assert (out_dir / "other.rs").read_text() == 'fn main() {\n    println!("{}", 1);\n}\n'
+    emit(entry, "-o", str(tmp_path / "nested" / "prog.rs"))
+    assert (tmp_path / "nested" / "prog.rs").read_text() == (out_dir / "prog.rs").read_text()
+
+
+def test_stdout_mode_and_conflicting_destinations(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """--stdout is explicit about the default and can't be mixed with file destinations."""
+    entry = write_package(tmp_path, PROGRAM)
+
+    assert emit(entry, "--stdout") == emit(entry)
+
+    for options in (["--stdout", "-o", "x.rs"], ["-o", "x.rs", "--out-dir", "out"]):
+        result = CliRunner().invoke(main, ["compile", str(entry), *options])
+        assert result.exit_code == 2
+        assert "choose one of -o, --out-dir, --stdout, and --cargo" in result.output
+    result = CliRunner().invoke(main, ["compile", str(entry), str(entry), "-o", "x.rs"])
+    assert "compiling several files needs --out-dir" in result.output
+
+
+def test_overflow_mode_selects_the_integer_arithmetic(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Checked arithmetic keeps Rust's operators; wrapping and saturating become method calls on every integer operation."""
+    source = "fn main() {\n    x = 120\n    x += 10\n    y = -x * x ** 2\n    for i in 0..3 {\n        print(i - y)\n    }\n}\n"
+    entry = write_package(tmp_path, source)
+
+    assert "x += 10;" in emit(entry)
+    wrapping = emit(entry, "--overflow", "wrapping")
+    assert "x = x.wrapping_add(10);" in wrapping
+    assert "let y = x.wrapping_neg().wrapping_mul(x.wrapping_pow(2));" in wrapping
+    assert "for i in 0_i64..3 {" in wrapping
+    assert "i.wrapping_sub(y)" in wrapping
+    assert "x.saturating_neg().saturating_mul(x.saturating_pow(2))" in emit(entry, "--overflow", "saturating")
+
+
+def test_bounds_mode_selects_the_fixed_array_reads(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Fixed-size arrays index like Rust arrays; under --bounds error a read through a variable gives a Result instead."""
+    panicking = emit(write_package(tmp_path, "fn main() {\n    xs: [i64; 3] = [1, 2, 3]\n    i = 2\n    print(xs[i] + xs[0])\n}\n"))
+    assert "let xs: [i64; 3] = [1, 2, 3];" in panicking
+    assert "xs[i as usize] + xs[0]" in panicking
+
+    source = (
+        "fn pick(xs: [i64; 3], i: i64) -> Result<i64, string> {\n    return try {\n        xs[i] + xs[0]\n    }\n}\n\n"
+        "fn main() {\n    match pick([0; 3], 2) {\n        Ok(v) => print(v),\n        Err(e) => print(e)\n    }\n}\n"
+    )
+    checked = emit(write_package(tmp_path, source), "--bounds", "error")
+    assert "usize::try_from(__index)" in checked
+    assert ".and_then(|__i| xs.get(__i))" in checked
+    assert "+ xs[0]" in checked
+
+
+def test_bounds_error_reads_need_a_try_block(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Under --bounds error a computed read is a Result, so using it outside a `try` block is rejected instead of emitting `Result + 1`."""
+    entry = write_package(tmp_path, "fn main() {\n    xs: [i64; 3] = [1, 2, 3]\n    k = 2\n    x = xs[k]\n    y = x + 1\n    print(y)\n}\n")
+    assert "let y = x + 1;" in emit(entry)
+
+    result = CliRunner().invoke(main, ["compile", str(entry), "--bounds", "error"])
+    assert result.exit_code != 0
+    assert "under --bounds error, reading a fixed-size array through a computed index gives a Result" in result.output
+
+    source = (
+        "fn pick(xs: [i64; 3], k: i64) -> Result<i64, string> {\n    return try {\n        x = xs[k]\n        y = x + 1\n"
+        "        y * 2\n    }\n}\n\nfn main() {\n    xs: [i64; 3] = [1, 2, 3]\n    match pick(xs, 1) {\n        Ok(v) => print(v),\n"
+        "        Err(e) => print(e)\n    }\n}\n"
+    )
+    checked = emit(write_package(tmp_path, source), "--bounds", "error")
+    assert "})?;" in checked
+    assert "let y = x + 1;" in checked
+
+
+def test_shadowing_mode_decides_whether_assignment_may_change_type(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """`=` with a value of a new type shadows implicitly, unless --shadowing strict asks for an explicit `let`."""
+    entry = write_package(tmp_path, 'fn main() {\n    x = 1\n    x = "one"\n    print(x)\n}\n')
+    assert 'let x = "one";' in emit(entry)
+
+    result = CliRunner().invoke(main, ["compile", str(entry), "--shadowing", "strict"])
+    assert result.exit_code != 0
+    assert "'x' holds 'i64', and `=` can't change its type under --shadowing strict" in result.output
+    assert "write `let x = ...` to declare a new 'x' of the new type" in result.output
+
+    entry.write_text('fn main() {\n    x = 1\n    let x = "one"\n    print(x)\n}\n')
+    assert 'let x = "one";' in emit(entry, "--shadowing", "strict")
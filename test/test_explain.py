@@ -0,0 +1,70 @@
+"""Tests for error codes and `zinc explain`."""
+
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from zinc.api import front_end
+from zinc.diagnostics import Diagnostic, render_diagnostics
+from zinc.exceptions import ZincError
+from zinc.explain import ERROR_CODES, lookup_code
+from zinc.main import main
+
+
+def _reported_codes(entry: Path) -> set[str]:
+    try:
+        diagnostics = front_end(entry).warnings
+    except ZincError as exc:
+        diagnostics = getattr(exc, "diagnostics", [Diagnostic(str(exc), code=getattr(exc, "code", None))])
+    return {diagnostic.code for diagnostic in diagnostics if diagnostic.code}
+
+
+@pytest.mark.parametrize("code", list(ERROR_CODES))
+def test_examples_report_their_code_and_fixes_do_not(tmp_path: Path, code: str, write_package: Callable[..., Path]) -> None:
+    """Each explanation's broken example still produces its code, and the fix compiles without any."""
+    entry = ERROR_CODES[code]
+
+    assert _reported_codes(write_package(tmp_path, entry.example)) == {code}
+    assert _reported_codes(write_package(tmp_path, entry.fixed)) == set()
+
+
+def test_codes_are_looked_up_loosely() -> None:
+    """Case and leading zeros don't matter, and unknown codes find nothing."""
+    assert lookup_code("z12") is lookup_code("Z0012") is ERROR_CODES["Z0012"]
+    assert lookup_code("Z9999") is None
+    assert lookup_code("E0308") is None
+
+
+def test_rendered_errors_show_their_code_and_point_at_explain() -> None:
+    """The code goes next to the severity, and the closing hint lists every code the errors carry."""
+    diagnostics = [
+        Diagnostic("unknown variable 'x'", code="Z0001"),
+        Diagnostic("unused variable 'y'", severity="warning", code="Z0011"),
+        Diagnostic("unknown function 'f'", code="Z0009"),
+    ]
+
+    rendered = render_diagnostics(diagnostics)
+
+    assert rendered.startswith("error[Z0001]: unknown variable 'x'\n\nwarning[Z0011]: unused variable 'y'\n")
+    assert rendered.endswith(
+        "Some errors have detailed explanations: Z0001, Z0009.\nFor more information about an error, try `zinc explain Z0001`."
+    )
+    assert render_diagnostics(diagnostics[:1]).endswith("\n\nFor more information about this error, try `zinc explain Z0001`.")
+
+
+def test_explain_prints_an_explanation_or_lists_the_codes() -> None:
+    """`zinc explain CODE` prints the entry, no code lists them all, and an unknown code is a usage error."""
+    runner = CliRunner()
+
+    explained = runner.invoke(main, ["explain", "Z0011"])
+    assert explained.exit_code == 0, explained.output
+    assert explained.output.startswith("Z0011: unused variable\n\nThis warning means")
+    assert "Erroneous code example:\n\n    fn main() {\n        result = 42\n    }\n" in explained.output
+
+    listed = runner.invoke(main, ["explain"])
+    assert listed.output.splitlines()[:2] == ["Z0001  unknown variable", "Z0002  variable read before it is assigned"]
+
+    unknown = runner.invoke(main, ["explain", "Z0999"])
+    assert unknown.exit_code == 2
+    assert "no error code 'Z0999'" in unknown.output
@@ -0,0 +1,123 @@
+"""Tests for the `zinc fmt` source formatter."""
+
+from pathlib import Path
+
+from click.testing import CliRunner
+from zinc.formatter import collect_sources, format_source
+from zinc.main import main
+
+CANONICAL = """\
+import std/encoding [utf8_encode]
+
+// Entry point.
+struct Point [Base | Detail] {
+    x: i64   // aligned trailing comments keep their column
+    y: i64
+
+    fn operator+(rhs) -> Self {
+        return Point { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+fn main() {
+    values: list<i64> = [1, -2, 3]
+    scores = {"a": 1, "b": 2}
+    ch = chan()
+    got = <-ch
+    for i in 0..3 {
+        print(values[i])
+    }
+    total = values[0] +
+        values[1]
+    print(add(right=3, left=values[2]))
+}
+"""
+
+
+def test_canonical_source_is_unchanged() -> None:
+    """Already formatted code, including unary, generic, and range spacing, is a fixed point."""
+    assert format_source(CANONICAL) == CANONICAL
+
+
+def test_indentation_and_spacing_are_normalized() -> None:
+    """Indentation follows bracket nesting and operators get single spaces."""
+    source = "fn main(){\nx=1+2\n  if x>2{\n        print( x )\n}\n}"
+
+    assert format_source(source) == "fn main() {\n    x = 1 + 2\n    if x > 2 {\n        print(x)\n    }\n}\n"
+
+
+def test_calls_and_indexing_stay_attached_to_literals() -> None:
+    """A postfix bracket is never spaced off, even after a number or a string."""
+    source = 'fn main() {\n    x = "abc" [0] + 42 (1) + (1, 2)[0]\n}\n'
+
+    assert format_source(source) == 'fn main() {\n    x = "abc"[0] + 42(1) + (1, 2)[0]\n}\n'
+
+
+def test_fixed_array_lengths_close_their_bracket() -> None:
+    """`; N]` is spaced like a closing bracket, and indentation after it is unaffected."""
+    source = "fn main() {\n    xs: [i64 ;4] = [1, 2, 3, 4]\n    if xs[0] > 0 {\n        ys = [0 ;  4]\n    }\n}\n"
+
+    assert format_source(source) == "fn main() {\n    xs: [i64; 4] = [1, 2, 3, 4]\n    if xs[0] > 0 {\n        ys = [0; 4]\n    }\n}\n"
+
+
+def test_let_rebindings_keep_their_let() -> None:
+    """`let` is written back in front of the name it binds, while a variable named `let` is left alone."""
+    source = "fn main() {\n    x = 1\n    let   x = \"a\"\n    let y: i64 = 2\n    let = 3\n    print(x, y, let)\n}\n"
+
+    assert format_source(source) == "fn main() {\n    x = 1\n    let x = \"a\"\n    let y: i64 = 2\n    let = 3\n    print(x, y, let)\n}\n"
+
+
+def test_static_fields_keep_their_static() -> None:
+    """`static` is written back in front of a static field, including a `static const` one."""
+    source = "struct Ticket {\n    id: i64\n    static   ISSUED: 0\n    static const LIMIT: 3\n}\n"
+
+    assert format_source(source) == "struct Ticket {\n    id: i64\n    static ISSUED: 0\n    static const LIMIT: 3\n}\n"
+
+
+def test_comments_survive_and_blank_lines_collapse() -> None:
+    """Comments are kept in place; blank-line runs shrink to one and vanish next to braces."""
+    source = "fn main() {\n\n    x = 1 /* inline */\n\n\n\n    // note\n    y = 2\n\n}\n\n\n"
+
+    assert format_source(source) == "fn main() {\n    x = 1 /* inline */\n\n    // note\n    y = 2\n}\n"
+
+
+def test_extern_rust_blocks_are_verbatim() -> None:
+    """Rust inside extern blocks is not Zinc and is left exactly as written."""
+    source = "extern rust {\n  fn  odd_spacing( x:i64 )->i64;\n}\n\nfn main() {\n  print(1)\n}\n"
+
+    assert format_source(source) == (
+        "extern rust {\n  fn  odd_spacing( x:i64 )->i64;\n}\n\nfn main() {\n    print(1)\n}\n"
+    )
+
+
+def test_formatting_is_idempotent() -> None:
+    """Formatting formatted output changes nothing."""
+    source = "fn main(){\n// c\nx=[1,2,\n3]\nprint(x[0])}"
+    formatted = format_source(source)
+
+    assert format_source(formatted) == formatted
+
+
+def test_collect_sources_skips_build_output(tmp_path: Path) -> None:
+    """Directories expand to their .zn files without descending into build output."""
+    (tmp_path / "a.zn").write_text("")
+    (tmp_path / ".zinc-build").mkdir()
+    (tmp_path / ".zinc-build" / "b.zn").write_text("")
+
+    assert collect_sources([tmp_path]) == [tmp_path / "a.zn"]
+
+
+def test_fmt_check_reports_without_rewriting(tmp_path: Path) -> None:
+    """--check exits 1 for unformatted files and leaves them untouched; plain fmt rewrites them."""
+    source = tmp_path / "main.zn"
+    source.write_text("fn main(){\nprint(1)\n}\n")
+
+    checked = CliRunner().invoke(main, ["fmt", "--check", str(tmp_path)])
+    assert checked.exit_code == 1
+    assert f"would reformat {source}" in checked.output
+    assert source.read_text() == "fn main(){\nprint(1)\n}\n"
+
+    formatted = CliRunner().invoke(main, ["fmt", str(source)])
+    assert formatted.exit_code == 0, formatted.output
+    assert source.read_text() == "fn main() {\n    print(1)\n}\n"
+    assert CliRunner().invoke(main, ["fmt", "--check", str(source)]).exit_code == 0
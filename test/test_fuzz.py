@@ -0,0 +1,71 @@
+"""Tests for the front-end fuzzer behind `zinc fuzz`."""
+
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from test.test_interpreter import ZINC_SOURCE_DIR
+from zinc import fuzz as fuzz_module
+from zinc.fuzz import MAX_INPUT_LENGTH, FrontEndTarget, fuzz, mutants, shrink
+from zinc.main import main
+
+SEED = 'fn main() {\n    total = 1 + 2\n    boom(total)\n    print("done")\n}\n'
+
+
+def _crash_on_boom(entry: Path, sources: dict[Path, str]) -> None:
+    if "boom" in sources[entry]:
+        raise KeyError("boom")
+
+
+def test_mutants_are_reproducible() -> None:
+    """The same seed yields the same inputs, and no input outgrows the length cap."""
+    seeds = [SEED, "fn main() {\n}\n"]
+
+    first = list(mutants(seeds, 50, seed=7))
+    assert first == list(mutants(seeds, 50, seed=7))
+    assert first != list(mutants(seeds, 50, seed=8))
+    assert all(len(text) <= MAX_INPUT_LENGTH for text in first)
+
+
+def test_shrink_keeps_only_what_the_crash_needs() -> None:
+    """Lines and then characters are dropped while the input still crashes."""
+    assert shrink(SEED, lambda text: "boom" in text) == "boom"
+
+
+def test_compile_errors_are_not_crashes(tmp_path: Path) -> None:
+    """Rejected programs are expected; only other exceptions count as crashes."""
+    target = FrontEndTarget(tmp_path)
+
+    assert target.crash("fn main( {") is None
+    assert target.crash('fn main() {\n    x: i64 = "text"\n}\n') is None
+
+
+def test_fuzz_reports_one_shrunk_crash_per_signature(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    """Every mutant that keeps `boom` crashes the same way, so they come back as one minimal input."""
+    monkeypatch.setattr(fuzz_module, "front_end", _crash_on_boom)
+
+    crashes = fuzz([SEED], 30)
+    assert [(crash.text, crash.error) for crash in crashes] == [("boom", "KeyError: 'boom'")]
+    assert crashes[0].signature.startswith("KeyError at File ")
+
+
+def test_fuzz_command_writes_crashing_inputs(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    """`zinc fuzz` saves each shrunk crash and exits with status 1."""
+    monkeypatch.setattr(fuzz_module, "front_end", _crash_on_boom)
+    seed = tmp_path / "seed.zn"
+    seed.write_text(SEED)
+    out_dir = tmp_path / "crashes"
+
+    result = CliRunner().invoke(main, ["fuzz", str(seed), "--iterations", "20", "--out-dir", str(out_dir)])
+
+    assert result.exit_code == 1, result.output
+    assert "KeyError: 'boom'" in result.output
+    assert result.output.endswith("checked 20 inputs from 1 seed(s): 1 crash(es)\n")
+    assert (out_dir / "crash-1.zn").read_text() == "boom"
+
+
+def test_fixture_mutants_do_not_crash_the_front_end() -> None:
+    """A short run over the fixtures stays clean; `zinc fuzz` runs longer campaigns."""
+    seeds = [path.read_text() for path in sorted(ZINC_SOURCE_DIR.glob("*.zn"))]
+
+    assert fuzz(seeds, 60) == []
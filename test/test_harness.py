@@ -0,0 +1,224 @@
+"""Tests for the end-to-end harness in `test/harness.py`."""
+
+import json
+import shutil
+import tempfile
+import xml.etree.ElementTree as ET
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+from test.harness import CaseResult, Layout, check_snapshot, json_report, junit_report, run_cases, select_cases
+from test.test_compile import compile_zinc, read_compare_mode, read_expected_diagnostics, read_expected_exit
+
+
+# Shared by every test and kept between runs, so tokio and the runtime are built once.
+TARGET_DIR = Path(tempfile.gettempdir()) / "zinc-harness-test-target"
+
+
+def _layout(tmp_path: Path) -> Layout:
+    for name in ("zinc", "rust", "output", "build"):
+        (tmp_path / name).mkdir()
+    return Layout(tmp_path / "zinc", tmp_path / "rust", tmp_path / "output", tmp_path / "build", TARGET_DIR)
+
+
+def test_compile_errors_fail_the_case_before_building(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Fixtures that are rejected or missing fail up front, and nothing is handed to cargo."""
+    layout = _layout(tmp_path)
+    write_package(layout.zinc_dir, 'fn main() {\n    x: i64 = "text"\n}\n', "hello.zn")
+
+    rejected, missing = run_cases(["hello", "missing"], layout)
+
+    assert rejected.problems[0].startswith("compile error: ")
+    assert missing.problems == [f"no fixture at {layout.zinc_dir / 'missing.zn'}"]
+    assert not (layout.build_dir / "Cargo.toml").exists()
+
+
+def test_cases_fail_when_generated_rust_or_stdout_drift(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """The freshly generated Rust is built and run, and both it and its stdout must match their snapshots."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    layout = _layout(tmp_path)
+    entry = write_package(layout.zinc_dir, 'fn main() {\n    print("hi")\n}\n', "drift.zn")
+    (layout.rust_dir / "drift.rs").write_text(compile_zinc(entry))
+    (layout.output_dir / "drift.out").write_text("hi\n")
+
+    [result] = run_cases(["drift"], layout)
+    assert (result.passed, result.stdout) == (True, "hi\n"), result.problems
+
+    (layout.rust_dir / "drift.rs").write_text("fn main() {}\n")
+    (layout.output_dir / "drift.out").write_text("bye\n")
+    [result] = run_cases(["drift"], layout)
+    rust_problem, stdout_problem = result.problems
+    assert rust_problem.startswith(f"generated Rust differs from {layout.rust_dir / 'drift.rs'}\n--- ")
+    assert "\n-fn main() {}\n+fn main() {\n" in rust_problem
+    assert stdout_problem.endswith("@@ -1 +1 @@\n-bye\n+hi")
+
+
+def test_bless_rewrites_snapshots_that_do_not_match(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Blessing writes missing and drifted snapshots, and the case passes."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    layout = _layout(tmp_path)
+    entry = write_package(layout.zinc_dir, 'fn main() {\n    print("hi")\n}\n', "blessed.zn")
+    (layout.output_dir / "blessed.out").write_text("bye\n")
+
+    [result] = run_cases(["blessed"], layout, bless=True)
+
+    assert result.passed, result.problems
+    assert result.blessed == [layout.rust_dir / "blessed.rs", layout.output_dir / "blessed.out"]
+    assert (layout.rust_dir / "blessed.rs").read_text() == compile_zinc(entry)
+    assert (layout.output_dir / "blessed.out").read_text() == "hi\n"
+    assert run_cases(["blessed"], layout)[0].blessed == []
+
+
+def test_every_fixture_and_snapshot_is_a_case(tmp_path: Path) -> None:
+    """Entry fixtures in any folder are found, compile-fail ones included, helper modules aren't, and orphaned snapshots are."""
+    layout = _layout(tmp_path)
+    for path in ("arithmetic.zn", "structs/01_fields.zn", "structs/_helper.zn", "compile_errors/enums/bad.zn"):
+        (layout.zinc_dir / path).parent.mkdir(parents=True, exist_ok=True)
+        (layout.zinc_dir / path).write_text("fn main() {\n}\n")
+    (layout.rust_dir / "removed.rs").write_text("fn main() {}\n")
+
+    assert layout.discover() == ["arithmetic", "compile_errors/enums/bad", "removed", "structs/01_fields"]
+
+
+def test_cases_are_selected_by_name_and_tag(tmp_path: Path) -> None:
+    """Folders and `// tags:` lines tag a case; filters match names, and each selector keeps cases matching any of its values."""
+    layout = _layout(tmp_path)
+    for path, source in [
+        ("concurrency/channels/01_round_trip.zn", "fn main() {\n}\n"),
+        ("concurrency/spawn/01_ack.zn", "// tags: slow, channels\nfn main() {\n}\n"),
+        ("structs/01_fields.zn", "fn main() {\n}\n"),
+    ]:
+        (layout.zinc_dir / path).parent.mkdir(parents=True, exist_ok=True)
+        (layout.zinc_dir / path).write_text(source)
+    cases = layout.discover()
+
+    assert layout.tags("concurrency/spawn/01_ack") == {"concurrency", "spawn", "slow", "channels"}
+    assert select_cases(cases, layout, tags=("channels",)) == ["concurrency/channels/01_round_trip", "concurrency/spawn/01_ack"]
+    assert select_cases(cases, layout, filters=("01_f", "ack")) == ["concurrency/spawn/01_ack", "structs/01_fields"]
+    assert select_cases(cases, layout, filters=("01",), tags=("structs",)) == ["structs/01_fields"]
+    assert select_cases(cases, layout) == cases
+
+
+def test_cases_that_hang_are_killed_and_fail(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """A case still running at the timeout fails with the output it got to, and the cases beside it still pass."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    layout = _layout(tmp_path)
+    write_package(layout.zinc_dir, 'fn main() {\n    print("started")\n    loop {\n    }\n}\n', "spin.zn")
+    write_package(layout.zinc_dir, 'fn main() {\n    print("hi")\n}\n', "quick.zn")
+
+    spin, quick = run_cases(["spin", "quick"], layout, bless=True, jobs=2, timeout=2)
+
+    assert spin.problems == ["timed out after 2s and was killed; stdout so far:\nstarted\n"]
+    assert quick.passed, quick.problems
+
+
+def test_compare_modes_let_output_vary(tmp_path: Path) -> None:
+    """Sorted and multiset output may come in any order, and a regex snapshot holds one pattern per line."""
+    snapshot = tmp_path / "case.out"
+
+    def check(expected: str, observed: str, mode: str, bless: bool = False) -> list[str]:
+        snapshot.write_text(expected)
+        result = CaseResult("case")
+        check_snapshot(result, "stdout", snapshot, observed, bless, mode)
+        return result.problems
+
+    assert check("a\nb\n", "b\na\n", "exact")
+    assert check("a\nb\n", "b\na\n", "sorted") == check("b\na\n", "a\nb\n", "multiset") == []
+    assert "Extra lines (found but not expected):\n  'a' x1\n" in check("a\nb\n", "b\na\na\n", "multiset")[0]
+    assert check("worker \\d+ done\n", "worker 3 done\n", "regex") == []
+    assert check("worker \\d+ done\n", "worker x done\n", "regex", bless=True) == [
+        f"stdout doesn't match the patterns in {snapshot}: line 1: 'worker x done' doesn't match /worker \\d+ done/"
+    ]
+    assert check("a\n", "b\nc\na\n", "sorted", bless=True) == [] and snapshot.read_text() == "a\nb\nc\n"
+    snapshot.unlink()
+    check_snapshot(CaseResult("case"), "stdout", snapshot, "took 1.5s\n", True, "regex")
+    assert snapshot.read_text() == "took\\ 1\\.5s\n"
+
+
+def test_fixtures_declare_their_compare_mode(tmp_path: Path) -> None:
+    """A `// compare:` line sets the mode; otherwise non_deterministic/ fixtures compare as multisets."""
+    source = tmp_path / "case.zn"
+    source.write_text("// compare: regex\nfn main() {\n}\n")
+    assert read_compare_mode(source, "concurrency/non_deterministic/case") == "regex"
+
+    source.write_text("fn main() {\n}\n")
+    assert read_compare_mode(source, "concurrency/non_deterministic/case") == "multiset"
+    assert read_compare_mode(source, "concurrency/spawn/case") == "exact"
+
+    source.write_text("// compare: fuzzy\nfn main() {\n}\n")
+    with pytest.raises(ValueError, match="unknown compare mode 'fuzzy'"):
+        read_compare_mode(source, "case")
+
+
+def test_results_are_reported_as_junit_xml_and_json() -> None:
+    """Each case becomes a test case with its duration, output, and problems, and control characters can't break the XML."""
+    results = [
+        CaseResult("structs/01_fields", stdout="\x1b[1mbold\x1b[0m\n", duration=0.25),
+        CaseResult("arithmetic", problems=["stdout differs\n-1\n+2", "exited with status 1"], stderr="panic\n", duration=1.5),
+    ]
+
+    suite = ET.fromstring(junit_report(results, 2.0)).find("testsuite")
+    assert (suite.get("tests"), suite.get("failures"), suite.get("time")) == ("2", "1", "2.000")
+    fields, arithmetic = suite.findall("testcase")
+    assert (fields.get("classname"), fields.get("name"), fields.get("time")) == ("structs", "01_fields", "0.250")
+    assert fields.find("failure") is None and fields.findtext("system-out") == "\ufffd[1mbold\ufffd[0m\n"
+    assert (arithmetic.get("classname"), arithmetic.find("failure").get("message")) == ("harness", "stdout differs")
+    assert arithmetic.findtext("failure") == "stdout differs\n-1\n+2\n\nexited with status 1"
+    assert arithmetic.findtext("system-err") == "panic\n"
+
+    report = json.loads(json_report(results, 2.0))
+    assert (report["passed"], report["failed"], report["duration"]) == (1, 1, 2.0)
+    assert report["cases"][1] == {
+        "case": "arithmetic",
+        "passed": False,
+        "duration": 1.5,
+        "problems": ["stdout differs\n-1\n+2", "exited with status 1"],
+        "stdout": "",
+        "stderr": "panic\n",
+        "blessed": [],
+    }
+
+
+def test_exit_status_and_stderr_are_checked(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """A case passes only with the exit status its fixture expects, and its stderr must match a `.err` snapshot or be empty."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    layout = _layout(tmp_path)
+    write_package(layout.zinc_dir, '// exit: 101\nfn main() {\n    print("checking")\n    assert(1 > 2)\n}\n', "failing.zn")
+    write_package(layout.zinc_dir, 'fn main() {\n    exit(2)\n}\n', "unexpected.zn")
+
+    failing, unexpected = run_cases(["failing", "unexpected"], layout, bless=True)
+    assert failing.passed, failing.problems
+    assert (layout.output_dir / "failing.err").read_text() == "assertion failed at failing.zn:4: 1 > 2\n"
+    assert unexpected.problems == ["exited with status 2, expected 0\n"]
+
+    (layout.output_dir / "failing.err").write_text("assertion failed\n")
+    [failing] = run_cases(["failing"], layout)
+    assert failing.problems[0].startswith(f"stderr differs from {layout.output_dir / 'failing.err'}\n")
+
+    (layout.zinc_dir / "failing.zn").write_text("// exit: -1\nfn main() {\n}\n")
+    with pytest.raises(ValueError, match="exit status '-1' is not a non-negative integer"):
+        read_expected_exit(layout.zinc_dir / "failing.zn")
+
+
+def test_compile_fail_cases_pass_when_the_expected_diagnostics_are_reported(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Compile-fail fixtures match their errors by regex, code, or message text, and fail if the compiler accepts them."""
+    layout = _layout(tmp_path)
+    (layout.zinc_dir / "compile_errors").mkdir()
+    source = 'fn main() {\n    x: i64 = "text"\n}\n'
+    write_package(layout.zinc_dir / "compile_errors", f"// expected-message: expects a compatible 'i64'\n{source}", "rejected.zn")
+    write_package(layout.zinc_dir / "compile_errors", f"// expected-error: ^nothing$\n// expected-code: Z9999\n{source}", "wrong.zn")
+    write_package(layout.zinc_dir / "compile_errors", "// expected-error: anything\nfn main() {\n}\n", "accepted.zn")
+
+    rejected, wrong, accepted = run_cases(["compile_errors/rejected", "compile_errors/wrong", "compile_errors/accepted"], layout)
+
+    assert rejected.passed, rejected.problems
+    assert wrong.problems[0].startswith("error doesn't match /^nothing$/:\n")
+    assert wrong.problems[1].startswith("no diagnostic has code Z9999 (found Z0003):\n")
+    assert accepted.problems == ["compiled without errors, but expected error 'anything'"]
+    assert not (layout.build_dir / "Cargo.toml").exists()
+    assert read_expected_diagnostics(layout.zinc_dir / "compile_errors" / "wrong.zn") == [("error", "^nothing$"), ("code", "Z9999")]
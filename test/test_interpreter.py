@@ -0,0 +1,178 @@
+"""Tests for the tree-walking interpreter behind `zinc run --interpret`."""
+
+import io
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from zinc.api import check
+from zinc.conditional import BuildConfig, build_config
+from zinc.interpreter import Interpreter, InterpreterError
+from zinc.main import main
+
+TEST_DIR = Path(__file__).parent
+ZINC_SOURCE_DIR = TEST_DIR / "zinc_source"
+OUTPUT_DIR = TEST_DIR / "output"
+
+# Fixtures whose output the interpreter must reproduce exactly, one from each area of the language.
+MATCHING_FIXTURES = (
+    "arithmetic",
+    "closures/09_loop_fresh_capture",
+    "collections/05_sorted_collections",
+    "collections/09_fixed_arrays",
+    "collections/10_nested_lists",
+    "collections/11_list_methods",
+    "decorators/01_stacked_and_generic",
+    "decorators/06_memoize",
+    "dynamic_typing/21_let_rebinding",
+    "enums/02_payload_match",
+    "error_handling/02_main_result",
+    "functions/02_argument_spread",
+    "functions/13_forwarded_borrows",
+    "operators/03_overloading",
+    "strings/01_raw_multiline",
+    "strings/03_string_builder",
+    "strings/04_format_number",
+    "structs/11_composition_static_methods",
+    "structs/22_print_placeholders",
+    "structs/23_constructor_shorthand",
+    "structs/24_static_data",
+    "structs/25_interface_defaults",
+    "structs/26_custom_iterables",
+    "tuples/03_destructuring",
+)
+
+BOUNDS_PROGRAM = """\
+fn pick(xs: [i64; 3], i: i64) -> Result<i64, string> {
+    return try {
+        x = xs[i]
+        y = x + 1
+        y * 10
+    }
+}
+
+fn main() {
+    xs: [i64; 3] = [1, 2, 3]
+    for i in [1, 3] {
+        match pick(xs, i) {
+            Ok(v) => print(v),
+            Err(e) => print(e)
+        }
+    }
+}
+"""
+
+
+def _interpret(tmp_path: Path, source: str, overflow: str = "checked") -> tuple[int, str, str]:
+    (tmp_path / "pkg.toml").write_text('[package]\nname = "tmp"\nversion = "0.1.0"\n')
+    entry = tmp_path / "main.zn"
+    entry.write_text(source)
+    stdout, stderr = io.StringIO(), io.StringIO()
+    status = Interpreter(check(entry), stdout=stdout, stderr=stderr, overflow=overflow).run()
+    return status, stdout.getvalue(), stderr.getvalue()
+
+
+@pytest.mark.parametrize("fixture", MATCHING_FIXTURES)
+def test_interpreter_prints_what_the_compiled_fixture_prints(fixture: str) -> None:
+    """Fixtures that pass through cargo print the same output when interpreted."""
+    stdout = io.StringIO()
+    status = Interpreter(check(ZINC_SOURCE_DIR / f"{fixture}.zn"), stdout=stdout, stderr=io.StringIO()).run()
+
+    assert status == 0
+    assert stdout.getvalue() == (OUTPUT_DIR / f"{fixture}.out").read_text()
+
+
+def test_recursion_too_deep_for_the_interpreter_is_a_runtime_error() -> None:
+    """Tail calls run in constant stack only when compiled; interpreted, too deep a recursion exits with 101 instead of a traceback."""
+    stdout, stderr = io.StringIO(), io.StringIO()
+    status = Interpreter(check(ZINC_SOURCE_DIR / "functions/14_tail_calls.zn"), stdout=stdout, stderr=stderr).run()
+
+    assert (status, stdout.getvalue()) == (101, "")
+    assert stderr.getvalue() == "error: stack overflow: the program's calls nested too deeply\n"
+
+
+def test_values_are_copied_on_assignment_and_shared_with_callees(tmp_path: Path) -> None:
+    """Assignment copies a struct; a function that updates its argument updates the caller's value."""
+    source = """\
+struct Counter {
+    count: i64
+}
+
+fn bump(counter: Counter) {
+    counter.count += 1
+}
+
+fn main() {
+    a = Counter { count: 1 }
+    b = a
+    bump(a)
+    print("{a.count} {b.count}")
+}
+"""
+    assert _interpret(tmp_path, source) == (0, "2 1\n", "")
+
+
+def test_integer_overflow_follows_the_overflow_mode(tmp_path: Path) -> None:
+    """Checked arithmetic panics like Rust at the statement's location; wrapping arithmetic wraps at the type's width."""
+    source = "fn main() {\n    x: u8 = 255\n    x += 1\n    print(x)\n}\n"
+
+    status, stdout, stderr = _interpret(tmp_path, source)
+    assert (status, stdout) == (101, "")
+    assert stderr.startswith("thread 'main' panicked at main.zn:3:5:\nattempt to add with overflow\n")
+    assert _interpret(tmp_path, source, overflow="wrapping") == (0, "0\n", "")
+
+
+def test_fixed_array_reads_follow_the_bounds_mode(tmp_path: Path) -> None:
+    """An out-of-range read panics like Rust; under `--bounds error` it gives an Err that a try block propagates."""
+    status, stdout, stderr = _interpret(tmp_path, "fn main() {\n    xs = [0; 3]\n    i = 3\n    print(xs[i])\n}\n")
+    assert (status, stdout) == (101, "")
+    assert "index out of bounds: the len is 3 but the index is 3" in stderr
+
+    with build_config(BuildConfig.for_target(bounds="error")):
+        assert _interpret(tmp_path, BOUNDS_PROGRAM) == (0, "30\nindex out of bounds: the len is 3 but the index is 3\n", "")
+
+
+def test_main_returning_err_reports_the_error(tmp_path: Path) -> None:
+    """`fail` inside a try block makes its value an Err; an Err returned from main exits with status 1."""
+    source = """\
+fn parse(text: string) -> Result<i64, string> {
+    if text == "" {
+        fail "empty"
+    }
+    return Ok(1)
+}
+
+fn main() -> Result<(), string> {
+    total = try {
+        parse("x") + parse("")
+    }
+    match total {
+        Ok(value) => print(value),
+        Err(error) => print("failed: {error}"),
+    }
+    fail "done"
+}
+"""
+    assert _interpret(tmp_path, source) == (1, "failed: empty\n", "done\n")
+
+
+def test_unsupported_features_are_rejected_before_running(tmp_path: Path) -> None:
+    """A program that spawns tasks prints nothing at all, since the interpreter can't finish it."""
+    source = "fn work() {\n    print(1)\n}\n\nfn main() {\n    print(0)\n    spawn work()\n}\n"
+
+    with pytest.raises(InterpreterError, match="doesn't support spawn yet; run the program without --interpret"):
+        _interpret(tmp_path, source)
+
+
+def test_run_interpret_skips_cargo(tmp_path: Path) -> None:
+    """`zinc run --interpret` exits with the program's status, and can't watch for changes."""
+    (tmp_path / "pkg.toml").write_text('[package]\nname = "tmp"\nversion = "0.1.0"\n')
+    entry = tmp_path / "main.zn"
+    entry.write_text('fn main() {\n    print("interpreted")\n    exit(3)\n}\n')
+
+    result = CliRunner().invoke(main, ["run", "--interpret", str(entry)])
+    assert (result.exit_code, result.output) == (3, "interpreted\n")
+
+    watched = CliRunner().invoke(main, ["run", "--interpret", "--watch", str(entry)])
+    assert watched.exit_code == 2
+    assert "--interpret and --watch can't be combined" in watched.output
@@ -0,0 +1,15 @@
+"""Tests for how Zinc source is tokenized."""
+
+from zinc.modules import lex_source
+
+
+def test_names_and_string_literals_are_stored_once_per_spelling() -> None:
+    """Every token spelled the same way shares one interned text object."""
+    tokens = lex_source('fn main() {\n    count = 1\n    count = count + 1\n    print("hi", "hi")\n}\n')
+
+    names = [token for token in tokens if token.text == "count"]
+    strings = [token for token in tokens if token.text == '"hi"']
+
+    assert len(names) == 3 and len(strings) == 2
+    assert all(token.text is names[0].text for token in names)
+    assert strings[0].text is strings[1].text
@@ -0,0 +1,80 @@
+"""Tests for lint levels set on the command line and in source pragmas."""
+
+import json
+from collections.abc import Callable
+from pathlib import Path
+
+from click.testing import CliRunner
+from zinc.diagnostics import Diagnostic
+from zinc.lints import LintLevels, apply_lint_levels, parse_pragmas
+from zinc.main import main
+
+SOURCE = """\
+fn main() {
+    unused = 1
+    for i in 0..2 {
+        break
+        print(i)
+    }
+}
+"""
+
+
+def _unused(file: Path, line: int = 2) -> Diagnostic:
+    return Diagnostic("unused variable 'unused'", str(file), line, 4, severity="warning", code="Z0011")
+
+
+def test_pragmas_cover_their_file_or_their_line() -> None:
+    """A pragma on its own line is file-wide, one after code covers that line, and unknown lints are collected."""
+    pragmas = parse_pragmas(
+        "// lint: allow(unused-variable, unused-function), deny(warnings)\n"
+        "x = 1 // lint: warn(unused-variable, typo)\n"
+    )
+
+    assert pragmas.file_levels.levels == {"unused-variable": "allow", "unused-function": "allow", "warnings": "deny"}
+    assert pragmas.line_levels[2].levels == {"unused-variable": "warn"}
+    assert pragmas.unknown == [(2, "typo")]
+
+
+def test_the_narrowest_setting_decides(tmp_path: Path) -> None:
+    """Line pragmas beat file pragmas, which beat the command line; a named lint beats the group, and the strictest wins."""
+    source = tmp_path / "prog.zn"
+    command_line = LintLevels()
+    command_line.set("warnings", "deny")
+    command_line.set("unused-variable", "allow")
+    source.write_text("")
+    assert apply_lint_levels([_unused(source)], command_line) == ([], [])
+
+    command_line.set("unused-variable", "deny")
+    _, [denied] = apply_lint_levels([_unused(source)], command_line)
+    assert denied.severity == "error"
+    assert denied.notes == ("'unused-variable' is denied, which turns this warning into an error",)
+
+    source.write_text("// lint: warn(warnings)\nunused = 1\n")
+    assert apply_lint_levels([_unused(source)], command_line) == ([_unused(source)], [])
+
+    source.write_text("// lint: warn(warnings)\nunused = 1 // lint: allow(unused-variable)\n")
+    assert apply_lint_levels([_unused(source)], command_line) == ([], [])
+
+
+def test_denied_warnings_fail_the_command(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """`--deny warnings` turns every warning into an error, and -A carves lints back out of it."""
+    monkeypatch.chdir(tmp_path)
+    write_package(tmp_path, SOURCE)
+    runner = CliRunner()
+
+    warned = runner.invoke(main, ["check", "--error-format", "json", "prog.zn"])
+    assert warned.exit_code == 0
+    assert [json.loads(line)["code"] for line in warned.output.splitlines()[:-1]] == ["Z0011", "Z0013"]
+
+    denied = runner.invoke(main, ["check", "--error-format", "json", "--deny", "warnings", "-A", "unreachable-code", "prog.zn"])
+    assert denied.exit_code == 1
+    [error] = [json.loads(line) for line in denied.output.splitlines()]
+    assert (error["severity"], error["code"]) == ("error", "Z0011")
+
+    allowed = runner.invoke(main, ["check", "-A", "warnings", "prog.zn"])
+    assert allowed.output == "prog.zn: OK\n"
+
+    unknown = runner.invoke(main, ["check", "-D", "unused-varaible", "prog.zn"])
+    assert unknown.exit_code == 2
+    assert "unknown lint 'unused-varaible'" in unknown.output
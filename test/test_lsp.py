@@ -0,0 +1,130 @@
+"""Tests for the `zinc lsp` language server."""
+
+import io
+from collections.abc import Callable
+from pathlib import Path
+
+from zinc.lsp import LanguageServer, read_message, serve, write_message
+
+MAIN_SOURCE = """\
+import lib/math as math
+
+fn double(n: i64) -> i64 {
+    return n * 2
+}
+
+fn main() {
+    total = double(4)
+    print(math.add(total, 1))
+}
+"""
+
+
+MATH_SOURCE = "fn add(x: i64, y: i64) -> i64 {\n    return x + y\n}\n"
+
+
+def open_document(server: LanguageServer, entry: Path, text: str = MAIN_SOURCE) -> list[dict]:
+    params = {"textDocument": {"uri": entry.as_uri(), "languageId": "zinc", "version": 1, "text": text}}
+    return server.handle({"jsonrpc": "2.0", "method": "textDocument/didOpen", "params": params})
+
+
+def request(server: LanguageServer, method: str, entry: Path, line: int, character: int):
+    params = {"textDocument": {"uri": entry.resolve().as_uri()}, "position": {"line": line, "character": character}}
+    (response,) = server.handle({"jsonrpc": "2.0", "id": 1, "method": method, "params": params})
+    return response["result"]
+
+
+def test_messages_round_trip_through_framing() -> None:
+    """Messages are framed with a Content-Length header."""
+    stream = io.BytesIO()
+    write_message(stream, {"jsonrpc": "2.0", "id": 1, "result": "ü"})
+    stream.seek(0)
+
+    assert stream.getvalue().startswith(b"Content-Length: ")
+    assert read_message(stream) == {"jsonrpc": "2.0", "id": 1, "result": "ü"}
+    assert read_message(stream) is None
+
+
+def test_diagnostics_follow_edits(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Syntax errors point at the offending token; fixing the buffer clears them."""
+    entry = write_package(tmp_path, MAIN_SOURCE, "main.zn", modules={"lib/math.zn": MATH_SOURCE})
+    server = LanguageServer()
+
+    (clean,) = open_document(server, entry)
+    assert clean["params"]["diagnostics"] == []
+
+    broken = MAIN_SOURCE.replace("double(4)", "double(4")
+    change = {"textDocument": {"uri": entry.as_uri(), "version": 2}, "contentChanges": [{"text": broken}]}
+    (published,) = server.handle({"jsonrpc": "2.0", "method": "textDocument/didChange", "params": change})
+    (diagnostic,) = published["params"]["diagnostics"]
+    assert diagnostic["range"]["start"] == {"line": 8, "character": 4}
+
+    mistyped = MAIN_SOURCE.replace("double(4)", "double(true)")
+    change["contentChanges"] = [{"text": mistyped}]
+    (published,) = server.handle({"jsonrpc": "2.0", "method": "textDocument/didChange", "params": change})
+    (diagnostic,) = published["params"]["diagnostics"]
+    assert "expects a compatible 'i64' value" in diagnostic["message"]
+    assert diagnostic["range"]["start"]["line"] == 7
+
+
+def test_hover_shows_inferred_types_and_signatures(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Locals show their resolved type; declarations show their signature, across modules."""
+    entry = write_package(tmp_path, MAIN_SOURCE, "main.zn", modules={"lib/math.zn": MATH_SOURCE})
+    server = LanguageServer()
+    open_document(server, entry)
+
+    assert request(server, "textDocument/hover", entry, 7, 5)["contents"]["value"] == "```zinc\ntotal: i64\n```"
+    assert request(server, "textDocument/hover", entry, 3, 12)["contents"]["value"] == "```zinc\nn: i64\n```"
+    assert request(server, "textDocument/hover", entry, 8, 16)["contents"]["value"] == "```zinc\nfn add(x: i64, y: i64) -> i64\n```"
+
+
+def test_definition_resolves_locals_and_imports(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Go-to-definition finds local bindings, same-module functions, and imported functions."""
+    entry = write_package(tmp_path, MAIN_SOURCE, "main.zn", modules={"lib/math.zn": MATH_SOURCE})
+    server = LanguageServer()
+    open_document(server, entry)
+
+    local = request(server, "textDocument/definition", entry, 8, 20)
+    assert local["range"]["start"] == {"line": 7, "character": 4}
+
+    same_module = request(server, "textDocument/definition", entry, 7, 14)
+    assert same_module["range"]["start"] == {"line": 2, "character": 3}
+
+    imported = request(server, "textDocument/definition", entry, 8, 16)
+    assert imported["uri"] == (tmp_path / "lib" / "math.zn").resolve().as_uri()
+    assert imported["range"]["start"] == {"line": 0, "character": 3}
+
+
+def test_completion_offers_scope_names_and_module_members(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Completion lists locals, declarations, builtins, and keywords, or a module's exports after `alias.`."""
+    entry = write_package(tmp_path, MAIN_SOURCE, "main.zn", modules={"lib/math.zn": MATH_SOURCE})
+    server = LanguageServer()
+    open_document(server, entry)
+
+    labels = [item["label"] for item in request(server, "textDocument/completion", entry, 8, 10)]
+    assert {"total", "double", "math", "print", "return"} <= set(labels)
+
+    members = [item["label"] for item in request(server, "textDocument/completion", entry, 8, 15)]
+    assert members == ["add"]
+
+
+def test_serve_runs_until_exit() -> None:
+    """The stdio loop answers requests and exits cleanly after shutdown."""
+    incoming = io.BytesIO()
+    for message in [
+        {"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}},
+        {"jsonrpc": "2.0", "id": 2, "method": "workspace/symbol", "params": {}},
+        {"jsonrpc": "2.0", "id": 3, "method": "shutdown"},
+        {"jsonrpc": "2.0", "method": "exit"},
+    ]:
+        write_message(incoming, message)
+    incoming.seek(0)
+    outgoing = io.BytesIO()
+
+    assert serve(incoming, outgoing) == 0
+
+    outgoing.seek(0)
+    initialize, unsupported, shutdown = read_message(outgoing), read_message(outgoing), read_message(outgoing)
+    assert initialize["result"]["capabilities"]["hoverProvider"] is True
+    assert unsupported["error"]["code"] == -32601
+    assert shutdown == {"jsonrpc": "2.0", "id": 3, "result": None}
@@ -0,0 +1,55 @@
+"""Tests for compile-time macro expansion."""
+
+from zinc.api import compile_str
+from zinc.formatter import format_source
+
+
+def test_macro_locals_are_renamed_so_they_do_not_capture_caller_variables() -> None:
+    """A variable the macro assigns is its own, even when the caller has one with the same name."""
+    source = """\
+macro reset(target: name) {
+    old = $target
+    $target = 0
+    print("was {old}")
+}
+
+fn main() {
+    old = 7
+    count = 3
+    reset!(count)
+    print("{old} {count}")
+}
+"""
+    result = compile_str(source)
+
+    assert result.ok, result.diagnostics
+    assert 'println!("was {}", _old_1);' in result.rust
+    assert 'println!("{} {}", old, count);' in result.rust
+
+
+def test_macro_errors_point_at_the_invocation() -> None:
+    """Unknown macros and wrong argument counts are reported where the macro is used."""
+    source = """\
+macro twice(x: expr) {
+    $x + $x
+}
+
+fn main() {
+    print(twise!(1))
+    print(twice!(1, 2))
+}
+"""
+    result = compile_str(source)
+
+    assert not result.ok
+    assert [(d.message, d.line, d.column, d.suggestions) for d in result.diagnostics] == [
+        ("unknown macro 'twise'", 6, 10, ("did you mean 'twice'?",)),
+        ("macro 'twice' takes 1 argument(s), but 2 were given", 7, 10, ()),
+    ]
+
+
+def test_formatter_keeps_macro_declarations_and_invocations_as_written() -> None:
+    """Expansions never leak into formatted source; invocations are spaced like calls."""
+    source = "macro sq(v: expr) {\n    $v*$v\n}\n\nfn main() {\n  print( sq!(2)  +1)\n}\n"
+
+    assert format_source(source) == "macro sq(v: expr) {\n    $v*$v\n}\n\nfn main() {\n    print(sq!(2) + 1)\n}\n"
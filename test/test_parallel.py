@@ -0,0 +1,37 @@
+"""Tests for compiling several entry files at once."""
+
+from collections.abc import Callable
+from pathlib import Path
+
+from click.testing import CliRunner
+from zinc.main import compile_program, compile_programs, main
+
+
+UTIL = "fn double(x) {\n    return x * 2\n}\n"
+FIRST = "import util [double]\n\nfn main() {\n    print(double(21))\n}\n"
+SECOND = 'import util [double]\n\nfn main() {\n    print(double(1.5))\n    print("two")\n}\n'
+
+
+def write_entries(root: Path, write_package: Callable[..., Path]) -> list[Path]:
+    """Write a package with two entry files sharing a module and return the entries."""
+    return [write_package(root, FIRST, "first.zn", modules={"util.zn": UTIL}), write_package(root, SECOND, "second.zn")]
+
+
+def test_parallel_compiles_match_sequential_ones(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Each entry is still checked as a whole program, so worker processes produce the same Rust."""
+    entries = write_entries(tmp_path, write_package)
+
+    parallel = compile_programs(entries, jobs=2)
+
+    assert [program.render() for program in parallel] == [compile_program(entry).render() for entry in entries]
+
+
+def test_errors_from_a_worker_are_reported_with_their_position(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Diagnostics come back from the worker process and fail the command like a sequential compile."""
+    entries = write_entries(tmp_path, write_package)
+    entries[1].write_text("fn main() {\n    x = 1\n    print(missing(x))\n}\n")
+
+    result = CliRunner().invoke(main, ["compile", *map(str, entries), "--out-dir", str(tmp_path / "out"), "-j", "2"])
+
+    assert result.exit_code != 0
+    assert "second.zn:3" in result.output
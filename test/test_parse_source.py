@@ -0,0 +1,22 @@
+"""Tests for parsing whole Zinc programs."""
+
+import pytest
+from zinc.exceptions import ZincSyntaxError
+from zinc.modules import parse_source
+
+
+def test_programs_that_need_full_context_still_parse() -> None:
+    """A statement after `spawn f(x)` makes SLL prediction bail, and the full LL parse accepts it."""
+    tree, _ = parse_source("fn main() {\n    jobs = chan()\n    spawn worker(jobs)\n    f = <-jobs\n    print(f(5))\n}\n", "main.zn")
+
+    body = tree.statement(0).functionDeclaration().block().statement()
+
+    assert [statement.getText() for statement in body] == ["jobs=chan()", "spawnworker(jobs)", "f=<-jobs", "print(f(5))"]
+
+
+def test_syntax_errors_are_reported_once_per_broken_statement() -> None:
+    """When the fast parse fails, the retry reports every error with statement recovery."""
+    with pytest.raises(ZincSyntaxError) as excinfo:
+        parse_source("fn main() {\n    x = (1 +\n    y = 2\n    z = ]\n    print(y)\n}\n", "main.zn")
+
+    assert [diagnostic.line for diagnostic in excinfo.value.diagnostics] == [3, 4]
@@ -0,0 +1,58 @@
+"""Tests for compiler passes registered by plugins."""
+
+from collections.abc import Callable
+from pathlib import Path
+
+from zinc.api import compile_file
+from zinc.passes import PassManager
+
+PROGRAM = "fn add(a: i64, b: i64) -> i64 {\n    return a + b\n}\n\nfn main() {\n    print(add(1, 2))\n}\n"
+
+
+def _functions(context):
+    return [statement.functionDeclaration() for statement in context.module.tree.statement() if statement.functionDeclaration()]
+
+
+def test_syntax_passes_rewrite_source_before_type_checking(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Text inserted by a pass is parsed, checked, and compiled like the rest of the module."""
+    passes = PassManager()
+
+    @passes.syntax_pass
+    def trace(context):
+        for function in _functions(context):
+            context.insert_after(function.block().getChild(0), f' print("enter {function.functionName().getText()}")')
+
+    result = compile_file(write_package(tmp_path, PROGRAM, "main.zn"), passes=passes)
+
+    assert result.ok and 'println!("enter add");' in result.rust
+
+
+def test_errors_reported_by_a_typed_pass_fail_the_compile(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Typed passes see the checked program, and an error they report is a compile error."""
+    passes = PassManager()
+
+    @passes.typed_pass
+    def short_names(context):
+        assert context.checked is not None and context.checked.symbols is not None
+        for function in _functions(context):
+            if len(function.functionName().getText()) < 4:
+                context.error("function names need at least four letters", function.functionName())
+
+    result = compile_file(write_package(tmp_path, PROGRAM, "main.zn"), passes=passes)
+
+    assert not result.ok
+    assert [(d.message, d.line, d.column) for d in result.diagnostics] == [("function names need at least four letters", 1, 3)]
+
+
+def test_manifest_plugins_register_passes(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """`[build] plugins` loads .py files from the package and calls their register function."""
+    (tmp_path / "house_rules.py").write_text(
+        "def register(passes):\n"
+        "    passes.syntax_pass(lambda context: context.warning('main must be documented', context.module.tree.statement(1)))\n"
+    )
+    entry = write_package(tmp_path, PROGRAM, "main.zn", manifest='\n[build]\nplugins = ["house_rules.py"]\n')
+
+    result = compile_file(entry)
+
+    assert result.ok
+    assert [(d.message, d.line) for d in result.diagnostics if d.message.startswith("main")] == [("main must be documented", 5)]
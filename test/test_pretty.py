@@ -0,0 +1,49 @@
+"""Round-trip property tests for the parser, the pretty-printer, and `zinc fmt`."""
+
+import random
+
+import pytest
+from zinc.formatter import format_source
+from zinc.pretty import Binary, Call, Index, Literal, Member, Name, TupleLiteral, Unary, parse_program, pretty, pretty_expr, random_program
+
+
+def _parse_expr(text: str):
+    return parse_program(f"fn main() {{\n    x = {text}\n}}\n").functions[0].body[0].value
+
+
+@pytest.mark.parametrize(
+    "text, expected",
+    [
+        ("a - b - c", Binary("-", Binary("-", Name("a"), Name("b")), Name("c"))),
+        ("a ** b ** c", Binary("**", Name("a"), Binary("**", Name("b"), Name("c")))),
+        ("-a ** b", Binary("**", Unary("-", Name("a")), Name("b"))),
+        ("not a == b", Binary("==", Unary("not", Name("a")), Name("b"))),
+        ("a or b and c", Binary("or", Name("a"), Binary("and", Name("b"), Name("c")))),
+        ("1..n + 1", Binary("..", Literal("1"), Binary("+", Name("n"), Literal("1")))),
+        ("-a.b[0](c)", Unary("-", Call(Index(Member(Name("a"), "b"), Literal("0")), (Name("c"),)))),
+        ("((a, b))", TupleLiteral((Name("a"), Name("b")))),
+    ],
+)
+def test_parser_precedence(text: str, expected) -> None:
+    """Trees read back the way the grammar's precedence and associativity say, with parentheses dropped."""
+    assert _parse_expr(text) == expected
+
+
+def test_printer_adds_only_the_parentheses_precedence_needs() -> None:
+    """Operands that bind looser than their place are wrapped; everything else is printed bare."""
+    assert pretty_expr(Binary("-", Name("a"), Binary("-", Name("b"), Name("c")))) == "a - (b - c)"
+    assert pretty_expr(Binary("**", Binary("**", Name("a"), Name("b")), Name("c"))) == "(a ** b) ** c"
+    assert pretty_expr(Unary("-", Binary("**", Name("a"), Name("b")))) == "-(a ** b)"
+    assert pretty_expr(Member(Binary("+", Name("a"), Literal("1")), "b")) == "(a + 1).b"
+    assert pretty_expr(Binary("..", Literal("0"), Name("n"))) == "0..n"
+    assert pretty_expr(TupleLiteral((Name("a"),))) == "(a,)"
+
+
+@pytest.mark.parametrize("seed", range(15))
+def test_random_programs_round_trip(seed: int) -> None:
+    """Printing a tree and parsing it back gives the same tree, and the printed form is already formatted."""
+    program = random_program(random.Random(seed), functions=1)
+    source = pretty(program)
+
+    assert parse_program(source) == program, source
+    assert format_source(source) == source
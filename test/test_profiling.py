@@ -0,0 +1,77 @@
+"""Tests for the per-function timing behind `zinc build --profile`."""
+
+import json
+import shutil
+import subprocess
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from zinc.main import compile_program, main
+from zinc.profiling import ProfilingOptions
+
+SOURCE = """\
+struct Counter {
+    count: 0
+
+    fn increment() {
+        self.count = self.count + 1
+    }
+}
+
+fn fib(n: i64) -> i64 {
+    if n < 2 {
+        return n
+    }
+    return fib(n - 1) + fib(n - 2)
+}
+
+fn main() {
+    counter = Counter { count: 0 }
+    counter.increment()
+    print(fib(10))
+    if counter.count == 1 {
+        exit(3)
+    }
+}
+"""
+
+
+def test_profiled_programs_time_functions_and_methods(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Each function and method starts a timed call, and exiting reports first; unprofiled programs are untouched."""
+    entry = write_package(tmp_path, SOURCE, "hello.zn")
+
+    rust = compile_program(entry, profiling=ProfilingOptions()).render()
+    assert "mod __zinc_profile {" in rust
+    assert '("Counter.increment", "hello.zn:4"),' in rust
+    assert '("fib", "hello.zn:9"),' in rust
+    assert "let __zinc_profile_session = __zinc_profile::Session;" in rust
+    assert "__zinc_profile::report(); std::process::exit(3)" in rust
+    assert "__zinc_profile" not in compile_program(entry).render()
+
+
+def test_profile_reports_hot_functions_on_exit(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """The report counts every call, including those still running when the program exits."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    entry = write_package(tmp_path, SOURCE, "hello.zn")
+    report = tmp_path / "profile.json"
+
+    result = CliRunner().invoke(main, ["build", str(entry), "-o", str(tmp_path / "hello"), "--debug", "--profile"])
+    assert result.exit_code == 0, result.output
+    run = subprocess.run([str(tmp_path / "hello")], capture_output=True, text=True)
+    assert (run.returncode, run.stdout) == (3, "55\n")
+    header, *rows = run.stderr.splitlines()
+    assert header.split() == ["self", "ms", "total", "ms", "calls", "function"]
+    assert sorted(row.split(maxsplit=3)[2:] for row in rows) == [
+        ["1", "Counter.increment (hello.zn:4)"],
+        ["1", "main (hello.zn:16)"],
+        ["177", "fib (hello.zn:9)"],
+    ]
+
+    result = CliRunner().invoke(main, ["run", str(entry), "--profile-json", str(report)])
+    assert result.exit_code == 3, result.output
+    functions = {function["name"]: function for function in json.loads(report.read_text())["functions"]}
+    assert functions["fib"]["calls"] == 177
+    assert functions["main"]["total_ns"] >= functions["fib"]["total_ns"]
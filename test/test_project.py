@@ -0,0 +1,137 @@
+"""Tests for zinc.toml project manifests, path dependencies, and project builds."""
+
+import shutil
+import subprocess
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from zinc.exceptions import ZincModuleError
+from zinc.main import compile_program, main
+from zinc.modules import build_module_graph
+from zinc.build import BuildProfile
+from zinc.project import BinTarget, load_project
+
+APP_MANIFEST = """\
+[package]
+name = "app"
+version = "0.1.0"
+
+[[bin]]
+name = "hello"
+path = "hello.zn"
+
+[[bin]]
+name = "count"
+path = "tools/count.zn"
+
+[dependencies]
+util = { path = "../util" }
+
+[build]
+out-dir = "out"
+release = false
+
+[profile.release]
+lto = "thin"
+panic = "abort"
+"""
+
+
+def write_workspace(root: Path) -> Path:
+    """Write an app project with two binaries and a path dependency; return the app root."""
+    util = root / "util"
+    (util / "text").mkdir(parents=True)
+    (util / "pkg.toml").write_text('[package]\nname = "util"\nversion = "0.1.0"\n')
+    (util / "lib.zn").write_text("import text/shout\n\nfn greet(name: string) -> string {\n    return shout(name)\n}\n")
+    (util / "text" / "shout.zn").write_text('fn shout(text: string) -> string {\n    return text + "!"\n}\n')
+
+    app = root / "app"
+    (app / "tools").mkdir(parents=True)
+    (app / "zinc.toml").write_text(APP_MANIFEST)
+    (app / "hello.zn").write_text('import util/lib [greet]\n\nfn main() {\n    print(greet("zinc"))\n}\n')
+    (app / "tools" / "count.zn").write_text('import util/text/shout as t\n\nfn main() {\n    print(t.shout("one"))\n}\n')
+    return app
+
+
+def test_manifest_declares_targets_dependencies_and_output(tmp_path: Path) -> None:
+    """Binaries, dependencies, and build settings come from zinc.toml."""
+    app = write_workspace(tmp_path)
+
+    project = load_project(app)
+
+    assert (project.name, project.version) == ("app", "0.1.0")
+    assert project.bins == [BinTarget("hello", (app / "hello.zn").resolve()), BinTarget("count", (app / "tools" / "count.zn").resolve())]
+    assert project.lib is None
+    assert project.dependencies == {"util": (tmp_path / "util").resolve()}
+    assert project.out_dir == app.resolve() / "out"
+    assert project.release is False
+    assert project.profile() == BuildProfile(release=False)
+    assert project.profile(release=True) == BuildProfile(lto="thin", panic="abort")
+    assert load_project(app / "zinc.toml") == project
+
+
+def test_conventional_layout_needs_no_target_tables(tmp_path: Path) -> None:
+    """main.zn is a binary named after the package and lib.zn is the library."""
+    (tmp_path / "zinc.toml").write_text('[package]\nname = "tool"\nversion = "1.0.0"\n')
+    (tmp_path / "main.zn").write_text("fn main() {\n    print(1)\n}\n")
+    (tmp_path / "lib.zn").write_text("fn one() -> i64 {\n    return 1\n}\n")
+
+    project = load_project(tmp_path)
+
+    assert project.bin() == BinTarget("tool", (tmp_path / "main.zn").resolve())
+    assert project.lib == (tmp_path / "lib.zn").resolve()
+    assert project.out_dir == tmp_path.resolve() / "bin"
+    assert project.release is True
+
+
+def test_invalid_targets_are_rejected(tmp_path: Path) -> None:
+    """Targets must be uniquely named .zn files inside the package, and ambiguous selections fail."""
+    app = write_workspace(tmp_path)
+    with pytest.raises(ZincModuleError, match="has 2 binaries \\(hello, count\\); choose one with --bin"):
+        load_project(app).bin()
+
+    (app / "zinc.toml").write_text(APP_MANIFEST.replace('"tools/count.zn"', '"../util/lib.zn"'))
+    with pytest.raises(ZincModuleError, match="bin 'count' path must be a .zn file inside the package"):
+        load_project(app)
+
+    (app / "zinc.toml").write_text(APP_MANIFEST.replace('name = "count"', 'name = "hello"'))
+    with pytest.raises(ZincModuleError, match="duplicate binary name 'hello'"):
+        load_project(app)
+
+    (app / "zinc.toml").write_text(APP_MANIFEST.replace('panic = "abort"', 'panic = "halt"'))
+    with pytest.raises(ZincModuleError, match="\\[profile.release\\] invalid panic 'halt'"):
+        load_project(app)
+
+
+def test_path_dependencies_are_namespaced_by_name(tmp_path: Path) -> None:
+    """Dependency modules load from their own package and keep their internal imports package-relative."""
+    app = write_workspace(tmp_path)
+
+    graph = build_module_graph(app / "hello.zn")
+
+    assert set(graph.modules) == {"hello", "util/lib", "util/text/shout"}
+    assert graph.path_for_module("util/text/shout") == (tmp_path / "util" / "text" / "shout.zn").resolve()
+    assert "fn util_text_shout__shout" in compile_program(app / "hello.zn").render()
+
+
+def test_missing_dependency_package_is_reported(tmp_path: Path) -> None:
+    """A dependency path must point at a Zinc package."""
+    app = write_workspace(tmp_path)
+    (app / "zinc.toml").write_text(APP_MANIFEST.replace("../util", "../nowhere"))
+
+    with pytest.raises(ZincModuleError, match="dependency 'util' has no zinc.toml or pkg.toml"):
+        build_module_graph(app / "hello.zn")
+
+
+def test_build_command_builds_every_project_binary(tmp_path: Path) -> None:
+    """Building a project directory puts each binary in the configured output directory."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    app = write_workspace(tmp_path)
+
+    result = CliRunner().invoke(main, ["build", str(app)])
+
+    assert result.exit_code == 0, result.output
+    assert subprocess.run([str(app / "out" / "hello")], capture_output=True, text=True).stdout == "zinc!\n"
+    assert subprocess.run([str(app / "out" / "count")], capture_output=True, text=True).stdout == "one!\n"
@@ -0,0 +1,68 @@
+"""Tests for the interactive REPL session."""
+
+import shutil
+from pathlib import Path
+
+import pytest
+from zinc.main import compile_program
+from zinc.repl import ReplSession, is_complete, is_declaration, run_repl
+
+
+def test_inputs_continue_until_brackets_close() -> None:
+    """Multi-line blocks keep reading; brackets inside strings do not count."""
+    assert is_complete("x = 1")
+    assert not is_complete("fn double(n: i64) -> i64 {")
+    assert is_complete("fn double(n: i64) -> i64 {\n    return n * 2\n}")
+    assert is_complete('print("{")')
+    assert not is_complete('print("unterminated')
+
+
+def test_declarations_are_hoisted_out_of_main() -> None:
+    """Functions, types, and imports go to module level; everything else runs in main()."""
+    assert is_declaration("fn f() {}")
+    assert is_declaration("struct Point {\n    x: i64\n}")
+    assert is_declaration("import std/hash")
+    assert is_declaration("@inline\nfn f() {}")
+    assert not is_declaration("x = 1")
+    assert not is_declaration("format_total(1)")
+
+
+def test_session_renders_declarations_before_main(tmp_path: Path) -> None:
+    """Session state becomes a regular Zinc program in a scratch package."""
+    session = ReplSession(tmp_path, compile_program=compile_program)
+    source = session.render_source(["fn one() -> i64 {\n    return 1\n}"], ["x = one()", "print(x)"])
+
+    assert source == "fn one() -> i64 {\n    return 1\n}\n\nfn main() {\n    x = one()\n    print(x)\n}\n"
+    assert (tmp_path / "pkg.toml").exists()
+
+
+def test_session_keeps_state_and_echoes_expressions(tmp_path: Path) -> None:
+    """Values persist between inputs, expressions echo, and failed inputs are dropped."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    session = ReplSession(tmp_path, compile_program=compile_program)
+
+    assert session.submit("x = 40").output == ""
+    assert session.submit("fn double(n: i64) -> i64 {\n    return n * 2\n}").output == ""
+    assert session.submit("double(x) + 1").output == "81\n"
+    assert session.submit('print("x is {x}")').output == "x is 40\n"
+    assert session.submit("assert(x == 0)").error is not None
+    assert session.statements == ["x = 40", "print_debug(double(x) + 1)", 'print("x is {x}")']
+
+
+def test_repl_loop_reports_errors_and_stops_at_eof(tmp_path: Path) -> None:
+    """Compile errors are printed and the loop ends cleanly on EOF."""
+    session = ReplSession(tmp_path, compile_program=compile_program)
+    inputs = iter(["x = ", "exit(\"no\")"])
+    lines: list[str] = []
+
+    def read(prompt: str) -> str:
+        try:
+            return next(inputs)
+        except StopIteration:
+            raise EOFError from None
+
+    run_repl(session, read=read, write=lines.append)
+
+    assert any(line.startswith("error: ") for line in lines)
+    assert session.statements == []
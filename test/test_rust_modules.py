@@ -0,0 +1,39 @@
+"""Focused unit tests for laying out generated Rust as one module per Zinc file."""
+
+from zinc.rust_modules import crate_visible, module_declarations, module_path
+
+
+def test_module_ids_become_valid_rust_paths() -> None:
+    """Segments that start with a digit or would shadow a crate or keyword get a prefix."""
+    assert module_path("geo/shapes") == ("geo", "shapes")
+    assert module_path("std/log") == ("zn_std", "log")
+    assert module_path("modules/01-intro") == ("modules", "zn_01_intro")
+    assert module_path("rand/core", reserved={"rand"}) == ("zn_rand", "core")
+
+
+def test_every_directory_declares_the_modules_below_it() -> None:
+    """The root uses plain `mod`; nested files declare children `pub(crate)` so sibling modules can reach them."""
+    assert module_declarations([("net", "http", "client"), ("net", "tcp"), ("util",)]) == {
+        (): ["mod net;", "mod util;"],
+        ("net",): ["pub(crate) mod http;", "pub(crate) mod tcp;"],
+        ("net", "http"): ["pub(crate) mod client;"],
+        ("net", "http", "client"): [],
+        ("net", "tcp"): [],
+        ("util",): [],
+    }
+
+
+def test_items_fields_and_inherent_methods_become_crate_visible() -> None:
+    """Trait impl methods keep no visibility, and fields that are already public are left alone."""
+    item = (
+        "struct Account {\n    pub owner: String,\n    balance: i64,\n}\n\n"
+        "impl Account {\n    fn deposit(&mut self, amount: i64) {\n        self.balance += amount;\n    }\n}\n\n"
+        "impl Default for Account {\n    fn default() -> Self {\n        todo!()\n    }\n}"
+    )
+
+    assert crate_visible(item) == (
+        "pub(crate) struct Account {\n    pub owner: String,\n    pub(crate) balance: i64,\n}\n\n"
+        "impl Account {\n    pub(crate) fn deposit(&mut self, amount: i64) {\n        self.balance += amount;\n    }\n}\n\n"
+        "impl Default for Account {\n    fn default() -> Self {\n        todo!()\n    }\n}"
+    )
+    assert crate_visible("async fn fetch() -> i64 {\n    1\n}") == "pub(crate) async fn fetch() -> i64 {\n    1\n}"
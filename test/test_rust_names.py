@@ -0,0 +1,19 @@
+"""Focused unit tests for spelling Zinc identifiers in generated Rust."""
+
+from zinc.rust_names import rust_identifier
+
+
+def test_rust_keywords_become_raw_identifiers() -> None:
+    """Names Rust reserves are written with the `r#` prefix; everything else is unchanged."""
+    assert rust_identifier("let") == "r#let"
+    assert rust_identifier("mut") == "r#mut"
+    assert rust_identifier("yield") == "r#yield"
+    assert rust_identifier("counter") == "counter"
+
+
+def test_keywords_that_cannot_be_raw_are_renamed_around_taken_names() -> None:
+    """`crate`, `super`, and `Self` take trailing underscores until the name is free."""
+    assert rust_identifier("crate") == "crate_"
+    assert rust_identifier("crate", {"crate_"}) == "crate__"
+    assert rust_identifier("super", {"super_", "super__"}) == "super___"
+    assert rust_identifier("Self") == "Self_"
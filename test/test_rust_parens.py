@@ -0,0 +1,50 @@
+"""Focused unit tests for removing redundant parentheses from generated Rust."""
+
+from zinc.rust_parens import remove_redundant_parens
+
+
+def test_whole_expressions_lose_their_outer_parentheses() -> None:
+    """Assignment, return, and argument positions never need the wrapper."""
+    assert remove_redundant_parens("self._balance = (self._balance + amount);") == "self._balance = self._balance + amount;"
+    assert remove_redundant_parens("return (x * 2);") == "return x * 2;"
+    assert remove_redundant_parens("account.withdraw(((100 + fee) as i32));") == "account.withdraw((100 + fee) as i32);"
+    assert remove_redundant_parens('println!("{}", (a == b));') == 'println!("{}", a == b);'
+
+
+def test_operands_keep_parentheses_that_change_the_parse() -> None:
+    """Lower-precedence and right-hand operands of the same precedence stay grouped."""
+    assert remove_redundant_parens("let g = ((2 + 3) * 4);") == "let g = (2 + 3) * 4;"
+    assert remove_redundant_parens("let h = (10 - (2 * 3));") == "let h = 10 - 2 * 3;"
+    assert remove_redundant_parens("let r = (a - (b - c));") == "let r = a - (b - c);"
+    assert remove_redundant_parens("let q = ((a == b) == c);") == "let q = (a == b) == c;"
+    assert remove_redundant_parens("let x = (-(a + b));") == "let x = -(a + b);"
+
+
+def test_casts_before_less_than_keep_their_parentheses() -> None:
+    """`x as i64 < y` would read `<` as the start of generic arguments."""
+    assert remove_redundant_parens("let a = ((b as i64) < c);") == "let a = (b as i64) < c;"
+    assert remove_redundant_parens("let a = ((b as u32) << 8);") == "let a = (b as u32) << 8;"
+
+
+def test_arithmetic_inside_shifts_stays_grouped() -> None:
+    """Mixing arithmetic with shifts or bitwise operators keeps the explicit grouping clippy asks for."""
+    assert remove_redundant_parens("let m = (n >> (18 - i));") == "let m = n >> (18 - i);"
+    assert remove_redundant_parens("let p = ((1 << 2) | 1);") == "let p = 1 << 2 | 1;"
+
+
+def test_conditions_and_method_receivers() -> None:
+    """Conditions drop their wrapper unless a struct literal could end them early; receivers must be atomic."""
+    assert remove_redundant_parens("if (x < 0.0) { }") == "if x < 0.0 { }"
+    assert remove_redundant_parens("if (S { a: 1 } == x) { }") == "if (S { a: 1 } == x) { }"
+    assert remove_redundant_parens("for i in (0..n).rev() { }") == "for i in (0..n).rev() { }"
+    assert remove_redundant_parens("let z = (2_i64.pow(3)).pow(2);") == "let z = 2_i64.pow(3).pow(2);"
+    assert remove_redundant_parens("let y = (2.5).floor();") == "let y = (2.5).floor();"
+    assert remove_redundant_parens("(self.f)(x);") == "(self.f)(x);"
+
+
+def test_tuples_strings_and_closures_are_left_alone() -> None:
+    """Tuples keep their parentheses and string contents are never touched."""
+    assert remove_redundant_parens("return (x, y);") == "return (x, y);"
+    assert remove_redundant_parens('let s = ("a(" == x);') == 'let s = "a(" == x;'
+    assert remove_redundant_parens("let g = move || (a || b);") == "let g = move || (a || b);"
+    assert remove_redundant_parens("let f = |x| (x * 2);") == "let f = |x| (x * 2);"
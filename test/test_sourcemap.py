@@ -0,0 +1,122 @@
+"""Tests for mapping generated Rust back to Zinc source lines."""
+
+import shutil
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from zinc.build import write_cargo_project
+from zinc.main import compile_program, main
+from zinc.sourcemap import SOURCE_MAP_FILE_NAME, SOURCE_MARKER, SourceLocation, SourceMap
+
+PROGRAM = """\
+fn add(a: i64, b: i64) -> i64 {
+    c = a + b
+    return c
+}
+
+fn main() {
+    x = add(1, 2)
+    if x > 2 {
+        print("big")
+    }
+    items = [1, 2]
+    print(items[x])
+}
+"""
+
+
+def rust_line(rendered: str, fragment: str) -> int:
+    """1-based line of the first generated line containing `fragment`."""
+    return next(number for number, line in enumerate(rendered.split("\n"), start=1) if fragment in line)
+
+
+def test_each_generated_statement_maps_to_its_zinc_line(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Statements in every function map back; signatures and closing braces of functions do not."""
+    entry = write_package(tmp_path, PROGRAM)
+    rendered, source_map = compile_program(entry).render_with_source_map()
+    file = str(entry.resolve())
+
+    assert SOURCE_MARKER not in rendered
+    assert source_map.lookup(rust_line(rendered, "let c = ")) == SourceLocation(file, 2)
+    assert source_map.lookup(rust_line(rendered, "let x = ")) == SourceLocation(file, 7)
+    assert source_map.lookup(rust_line(rendered, 'println!("big")')) == SourceLocation(file, 9)
+    assert source_map.lookup(rust_line(rendered, "items[")) == SourceLocation(file, 12)
+    assert source_map.lookup(rust_line(rendered, "fn main() {")) is None
+    assert rendered == compile_program(entry).render()
+
+
+def test_rewrite_points_compiler_and_panic_output_at_zinc_source() -> None:
+    """rustc arrows, panic headers, and backtrace frames are rewritten; unmapped locations are kept."""
+    source_map = SourceMap({12: SourceLocation("/elsewhere/prog.zn", 7)})
+    output = (
+        "  --> src/main.rs:12:5\n"
+        "thread 'main' panicked at src/main.rs:12:25:\n"
+        "             at /tmp/build/.zinc-build/prog/src/main.rs:12:25\n"
+        "  --> src/main.rs:3:1\n"
+    )
+
+    assert source_map.rewrite(output) == (
+        "  --> /elsewhere/prog.zn:7 (src/main.rs:12:5)\n"
+        "thread 'main' panicked at /elsewhere/prog.zn:7 (src/main.rs:12:25):\n"
+        "             at /elsewhere/prog.zn:7 (src/main.rs:12:25)\n"
+        "  --> src/main.rs:3:1\n"
+    )
+
+
+def test_module_files_have_their_own_line_tables() -> None:
+    """Locations in a module file under src/ are looked up in that file's table and survive a JSON round trip."""
+    source_map = SourceMap({3: SourceLocation("prog.zn", 1)}, {"util/strings.rs": {3: SourceLocation("util/strings.zn", 8)}})
+    output = "  --> src/util/strings.rs:3:9\n  --> src/main.rs:3:1\n  --> src/util.rs:3:1\n"
+
+    assert source_map.rewrite(output) == (
+        "  --> util/strings.zn:8 (src/util/strings.rs:3:9)\n  --> prog.zn:1 (src/main.rs:3:1)\n  --> src/util.rs:3:1\n"
+    )
+    assert SourceMap.from_json(source_map.to_json()) == source_map
+
+
+def test_builds_save_the_map_next_to_the_generated_project(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """The sidecar written by a build loads back into the same map."""
+    program = compile_program(write_package(tmp_path, PROGRAM))
+    project_dir = write_cargo_project(program, "prog", tmp_path / "build")
+
+    assert SourceMap.load(project_dir) == program.render_with_source_map(rustfmt=True)[1]
+    assert (project_dir / SOURCE_MAP_FILE_NAME).read_text().startswith('{"version": 1, "files": [')
+    assert SourceMap.load(tmp_path) is None
+
+
+def test_compile_writes_the_map_on_request(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """`compile --source-map` writes the same JSON a build saves."""
+    entry = write_package(tmp_path, PROGRAM)
+
+    result = CliRunner().invoke(main, ["compile", str(entry), "-o", str(tmp_path / "prog.rs"), "--source-map", str(tmp_path / "prog.map")])
+
+    assert result.exit_code == 0, result.output
+    assert SourceMap.from_json((tmp_path / "prog.map").read_text()) == compile_program(entry).render_with_source_map(rustfmt=True)[1]
+
+
+def test_rustfmt_keeps_statements_mapped_to_their_zinc_lines(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Markers survive formatting, so reflowed output still maps each statement back."""
+    if shutil.which("rustfmt") is None:
+        pytest.skip("rustfmt is not installed")
+    entry = write_package(tmp_path, PROGRAM)
+    rendered, source_map = compile_program(entry).render_with_source_map(rustfmt=True)
+    file = str(entry.resolve())
+
+    assert SOURCE_MARKER not in rendered
+    assert source_map.lookup(rust_line(rendered, "let x = ")) == SourceLocation(file, 7)
+    assert source_map.lookup(rust_line(rendered, 'println!("big")')) == SourceLocation(file, 9)
+    assert source_map.lookup(rust_line(rendered, "items[")) == SourceLocation(file, 12)
+
+
+def test_run_reports_panics_at_the_zinc_line(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """An out-of-bounds index panics with the Zinc statement's location."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    entry = write_package(tmp_path, PROGRAM)
+
+    result = CliRunner().invoke(main, ["run", str(entry)])
+
+    assert result.exit_code == 101
+    assert "prog.zn:12 (src/main.rs:" in result.output
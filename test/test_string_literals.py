@@ -35,3 +35,8 @@ def test_non_double_quoted_strings_lower_to_valid_rust_strings() -> None:
     assert to_rust_string_literal("'hello'") == 'r"hello"'
     assert to_rust_string_literal("`hello`") == 'r"hello"'
     assert to_rust_string_literal('`say "hi" and "#`').startswith('r##"')
+
+
+def test_multiline_raw_strings_lower_onto_one_line() -> None:
+    """Generated statements are re-indented line by line, so a raw string's newlines are escaped to keep its contents exact."""
+    assert to_rust_string_literal('`line 1\n    "line 2" \\tmp`') == '"line 1\\n    \\"line 2\\" \\\\tmp"'
@@ -0,0 +1,114 @@
+"""Tests for cross-compilation and WebAssembly targets."""
+
+import os
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from zinc.build import cargo_manifest, run_binary, write_cargo_project
+from zinc.codegen import RustProgram
+from zinc.exceptions import ZincBuildError
+from zinc.main import main
+from zinc.targets import BROWSER_TRIPLE, HOST, WASI_TRIPLE, Target, cross_linker, linker_env_var, resolve_target
+
+WASI = Target(WASI_TRIPLE)
+BROWSER = Target(BROWSER_TRIPLE)
+
+
+def test_target_aliases_resolve_to_wasm_triples() -> None:
+    """`wasm` means WASI, `wasm-browser` means wasm-bindgen; values that aren't triples are rejected."""
+    assert resolve_target(None) == HOST
+    assert resolve_target("native") == HOST
+    assert resolve_target("wasm") == WASI
+    assert resolve_target("wasm-browser") == BROWSER
+    assert resolve_target("wasm32-wasip2").is_wasi
+
+    with pytest.raises(ZincBuildError, match="unsupported target 'mips'"):
+        resolve_target("mips")
+    with pytest.raises(ZincBuildError, match="unsupported target"):
+        resolve_target("aarch64-")
+
+
+def test_cross_targets_pass_through_with_their_conventions(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    """Any triple is accepted; Windows binaries get .exe and Linux triples find Debian-style cross linkers."""
+    pi = resolve_target("armv7-unknown-linux-gnueabihf")
+    assert pi.is_cross
+    assert pi.artifact_suffix == ""
+    assert resolve_target("x86_64-pc-windows-gnu").artifact_suffix == ".exe"
+    assert linker_env_var(pi) == "CARGO_TARGET_ARMV7_UNKNOWN_LINUX_GNUEABIHF_LINKER"
+
+    linker = tmp_path / "arm-linux-gnueabihf-gcc"
+    linker.write_text("#!/bin/sh\n")
+    linker.chmod(0o755)
+    monkeypatch.setenv("PATH", f"{tmp_path}{os.pathsep}{os.environ['PATH']}")
+    assert cross_linker(pi) == str(linker)
+    assert cross_linker(HOST) is None
+
+
+def test_windows_rejects_the_blocking_interrupt_handler(tmp_path: Path) -> None:
+    """The synchronous on_interrupt() runtime is Unix-only; async programs use tokio's ctrl_c instead."""
+    windows = resolve_target("x86_64-pc-windows-msvc")
+    with pytest.raises(ZincBuildError, match="make main\\(\\) async"):
+        write_cargo_project(RustProgram(runtime_features={"signal"}, target=windows), "app", tmp_path / "app")
+
+    write_cargo_project(RustProgram(uses_async=True, uses_interrupt=True, target=windows), "app", tmp_path / "async")
+
+
+def test_async_wasm_programs_use_a_current_thread_runtime() -> None:
+    """WebAssembly has no threads, so tokio runs on the calling thread with a minimal feature set."""
+    program = RustProgram(uses_async=True, target=WASI)
+
+    assert '#[tokio::main(flavor = "current_thread")]' in program.render()
+    assert 'tokio = { version = "1", features = ["macros", "rt", "sync"] }' in cargo_manifest("app", program)
+    assert "#[tokio::main]\n" in RustProgram(uses_async=True).render()
+
+
+def test_browser_programs_start_through_wasm_bindgen() -> None:
+    """Browser builds are cdylibs that print to the console and call main() from a start function."""
+    program = RustProgram(main_body=['println!("{}", 1);'], runtime_features={"uuid"}, target=BROWSER)
+    rendered = program.render()
+    manifest = cargo_manifest("app", program)
+
+    assert rendered.startswith("use wasm_bindgen::prelude::*;")
+    assert "macro_rules! println {" in rendered
+    assert rendered.endswith("#[wasm_bindgen(start)]\npub fn __zinc_start() {\n    main();\n}")
+    assert '[lib]\ncrate-type = ["cdylib"]' in manifest
+    assert 'wasm-bindgen = "0.2"' in manifest
+    assert 'getrandom = { version = "0.2", features = ["js"] }' in manifest
+
+
+def test_browser_projects_replace_the_binary_root(tmp_path: Path) -> None:
+    """Switching an existing build directory to the browser leaves only lib.rs."""
+    project_dir = write_cargo_project(RustProgram(), "app", tmp_path / "app")
+    write_cargo_project(RustProgram(target=BROWSER), "app", project_dir)
+
+    assert (project_dir / "src" / "lib.rs").exists()
+    assert not (project_dir / "src" / "main.rs").exists()
+
+
+def test_wasm_rejects_signal_handlers(tmp_path: Path) -> None:
+    """on_interrupt() has nothing to hook on WebAssembly."""
+    with pytest.raises(ZincBuildError, match="on_interrupt\\(\\) is not supported on wasm32-wasip1"):
+        write_cargo_project(RustProgram(uses_interrupt=True, target=WASI), "app", tmp_path / "app")
+
+    with pytest.raises(ZincBuildError, match="builds run in a browser"):
+        run_binary(tmp_path / "app.wasm", target=BROWSER)
+
+
+def test_compile_command_renders_for_the_requested_target(tmp_path: Path) -> None:
+    """`compile --target` applies the target's entry point; unknown targets are usage errors."""
+    (tmp_path / "pkg.toml").write_text('[package]\nname = "tmp"\nversion = "0.1.0"\n')
+    entry = tmp_path / "hello.zn"
+    entry.write_text('fn main() {\n    print("hi")\n}\n')
+
+    result = CliRunner().invoke(main, ["compile", str(entry), "--target", "wasm-browser"])
+    assert result.exit_code == 0, result.output
+    assert "#[wasm_bindgen(start)]" in result.output
+
+    invalid = CliRunner().invoke(main, ["compile", str(entry), "--target", "mips"])
+    assert invalid.exit_code == 2
+    assert "unsupported target 'mips'" in invalid.output
+
+    cross = CliRunner().invoke(main, ["run", str(entry), "--target", "riscv64gc-unknown-linux-gnu"])
+    assert cross.exit_code == 2
+    assert "can't run riscv64gc-unknown-linux-gnu binaries on this machine" in cross.output
@@ -0,0 +1,93 @@
+"""Tests for `zinc test` discovery, harness generation, and reporting."""
+
+import shutil
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+from click.testing import CliRunner
+from zinc.exceptions import ZincTypeError
+from zinc.main import _compile_pipeline, main
+from zinc.testing import TestResult, compile_tests, discover_tests, harness_source, report
+
+TEST_SOURCE = """\
+fn add(x: i64, y: i64) -> i64 {
+    return x + y
+}
+
+@test
+fn adds_small_numbers() {
+    assert_eq(add(2, 3), 5)
+}
+
+@test
+fn catches_mistakes() {
+    total = add(1, 1)
+    assert_eq(total, 3, "one plus one")
+}
+"""
+
+
+def test_discovery_finds_marked_functions_in_order(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Only `@test` functions are tests, and files without `main()` are fine."""
+    graph, tests = discover_tests(write_package(tmp_path, TEST_SOURCE, "math.zn"))
+
+    assert graph.entry_module_id == "math"
+    assert tests == ["adds_small_numbers", "catches_mistakes"]
+
+
+def test_private_tests_are_rejected(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """The harness imports tests, so they must be exported."""
+    source = write_package(tmp_path, "@test\nfn _hidden() {\n    assert(true)\n}\n")
+
+    with pytest.raises(ZincTypeError, match="test function '_hidden' must be public"):
+        discover_tests(source)
+
+
+def test_harness_dispatches_on_test_name(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """The harness calls every test from Zinc; the Rust main runs only the one named by argv[1]."""
+    assert harness_source("math", ["a", "b"]) == "import math [a, b]\n\nfn main() {\n    a()\n    b()\n}\n"
+
+    source = write_package(tmp_path, TEST_SOURCE, "math.zn")
+    program, tests = compile_tests(source, lambda entry, sources: _compile_pipeline(entry, sources)[3])
+    rendered = program.render()
+
+    assert tests == ["adds_small_numbers", "catches_mistakes"]
+    assert "match std::env::args().nth(1).as_deref() {" in rendered
+    assert 'Some("adds_small_numbers") => {' in rendered
+    assert 'Some("catches_mistakes") => {' in rendered
+
+
+def test_report_lists_results_and_failure_output() -> None:
+    """Each test gets a status line; failures repeat their captured output."""
+    lines: list[str] = []
+    passed = report(
+        {Path("math.zn"): [TestResult("ok_case", True), TestResult("bad_case", False, "assertion failed at math.zn:9: x == 1\n")]},
+        write=lines.append,
+    )
+
+    assert not passed
+    assert lines == [
+        "running 2 tests",
+        "test math.zn::ok_case ... ok",
+        "test math.zn::bad_case ... FAILED",
+        "\nfailures:",
+        "\n---- math.zn::bad_case ----",
+        "assertion failed at math.zn:9: x == 1",
+        "\ntest result: FAILED. 1 passed; 1 failed",
+    ]
+
+
+def test_cli_runs_each_test_in_its_own_process(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """A failing assertion fails only its own test and is reported with its source location."""
+    if shutil.which("cargo") is None:
+        pytest.skip("cargo is not installed")
+    write_package(tmp_path, TEST_SOURCE, "math.zn")
+
+    result = CliRunner().invoke(main, ["test", str(tmp_path)])
+
+    assert result.exit_code == 1, result.output
+    assert "::adds_small_numbers ... ok" in result.output
+    assert "::catches_mistakes ... FAILED" in result.output
+    assert "assertion failed at math.zn:13: total == 3: one plus one" in result.output
+    assert "test result: FAILED. 1 passed; 1 failed" in result.output
@@ -0,0 +1,79 @@
+"""Tests for `--timings` and the compiler phase spans behind it."""
+
+import json
+from collections.abc import Callable
+from pathlib import Path
+
+from click.testing import CliRunner
+from zinc.api import front_end
+from zinc.main import main
+from zinc.timings import Span, Timings, active_timings, phase, record_timings
+
+
+def test_phases_are_recorded_only_while_timings_are() -> None:
+    """Outside `record_timings` a phase is a no-op; inside, each run becomes a span."""
+    with phase("parse"):
+        pass
+    assert active_timings() is None
+
+    with record_timings() as timings:
+        with phase("typecheck", "main.zn"):
+            pass
+        with phase("parse", "main.zn"):
+            pass
+    assert active_timings() is None
+    assert [(span.phase, span.detail) for span in timings.spans] == [("typecheck", "main.zn"), ("parse", "main.zn")]
+
+
+def test_reports_sum_each_phase_in_pipeline_order() -> None:
+    """The table, JSON, and Chrome trace all describe the same spans."""
+    timings = Timings(
+        [Span("codegen", 10.5, 0.25, "a.zn", 7), Span("parse", 10.0, 0.5, "a.zn", 7), Span("parse", 10.1, 0.25, "b.zn", 8)],
+        started=10.0,
+    )
+
+    assert timings.totals() == {"parse": (0.75, 2), "codegen": (0.25, 1)}
+    assert timings.render(elapsed=1.0) == (
+        "phase          time   share  runs\n"
+        "parse       750.0ms   75.0%     2\n"
+        "codegen     250.0ms   25.0%     1\n"
+        "total      1000.0ms"
+    )
+    assert json.loads(timings.to_json(elapsed=1.0))["phases"] == [
+        {"phase": "parse", "seconds": 0.75, "runs": 2},
+        {"phase": "codegen", "seconds": 0.25, "runs": 1},
+    ]
+    first, *_ = json.loads(timings.to_chrome_trace())["traceEvents"]
+    assert first == {"name": "parse", "cat": "zinc", "ph": "X", "ts": 0, "dur": 500000, "pid": 7, "tid": 7, "args": {"detail": "a.zn"}}
+
+
+def test_the_front_end_times_each_module(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Every module is lexed and parsed once, and the program is checked once."""
+    entry = write_package(tmp_path, "import helper\n\nfn main() {\n    print(helper.twice(2))\n}\n")
+    (tmp_path / "helper.zn").write_text("fn twice(n: i64) -> i64 {\n    return n * 2\n}\n")
+
+    with record_timings() as timings:
+        front_end(entry)
+
+    assert {phase: count for phase, (_, count) in timings.totals().items()} == {"lex": 2, "parse": 2, "typecheck": 1}
+
+
+def test_timings_are_reported_when_the_command_ends(tmp_path: Path, monkeypatch, write_package: Callable[..., Path]) -> None:
+    """`--timings` prints the table to stderr and the file options write JSON and a trace, even when compiling fails."""
+    monkeypatch.chdir(tmp_path)
+    write_package(tmp_path, "fn main() {\n    print(1)\n}\n")
+
+    result = CliRunner().invoke(main, ["check", "--timings", "--timings-trace", "trace.json", "prog.zn"])
+
+    assert result.exit_code == 0, result.output
+    ok, *table = result.output.splitlines()
+    assert ok == "prog.zn: OK"
+    assert [line.split()[0] for line in table] == ["phase", "lex", "parse", "typecheck", "total"]
+    assert {event["name"] for event in json.loads(Path("trace.json").read_text())["traceEvents"]} == {"lex", "parse", "typecheck"}
+
+    write_package(tmp_path, "fn main() {\n    print(missing)\n}\n")
+    failed = CliRunner().invoke(main, ["check", "--timings-json", "timings.json", "prog.zn"])
+
+    assert failed.exit_code == 1
+    assert "phase" not in failed.output
+    assert [entry["phase"] for entry in json.loads(Path("timings.json").read_text())["phases"]] == ["lex", "parse", "typecheck"]
@@ -0,0 +1,43 @@
+"""Tests for recovering comments from between tokens."""
+
+from zinc.modules import parse_source
+from zinc.trivia import Comment, Trivia
+
+SOURCE = """\
+// header
+fn main() {
+    x = 1 // one
+    /* before y */
+    y = x
+}
+"""
+
+
+def test_comments_lead_the_next_token_or_trail_the_previous_one() -> None:
+    """A comment on its own line leads what follows; one after code trails that code."""
+    tree, _ = parse_source(SOURCE, "main.zn")
+    trivia = Trivia.of(tree)
+    first, second = tree.statement(0).functionDeclaration().block().statement()
+
+    assert trivia.leading(tree.start) == [Comment("// header", 1)]
+    assert trivia.trailing(first.stop) == [Comment("// one", 3)]
+    assert trivia.leading(second.start) == [Comment("/* before y */", 4)]
+    assert trivia.leading(first.start) == [] and trivia.trailing(second.stop) == []
+
+
+def test_block_comments_become_line_comments_in_rust() -> None:
+    """Rust nests block comments, so they are rewritten as `//` lines."""
+    comment = Comment("/*\n * first /* not nested in Zinc\n * second\n */", 1)
+
+    assert comment.rust_lines() == ["// first /* not nested in Zinc", "// second"]
+    assert Comment("// kept as is", 1).rust_lines() == ["// kept as is"]
+
+
+def test_doc_comments_stay_docs_only_on_declarations() -> None:
+    """`///` is a Rust doc comment in front of an item and a plain comment elsewhere; `//!` is never kept."""
+    doc = Comment("/// Adds.", 1)
+
+    assert doc.rust_lines(doc=True) == ["/// Adds."]
+    assert doc.rust_lines() == ["// Adds."]
+    assert Comment("//! Module.", 1).rust_lines(doc=True) == ["// Module."]
+    assert Comment("//// Not a doc.", 1).rust_lines(doc=True) == ["//// Not a doc."]
@@ -0,0 +1,41 @@
+"""Tests for the source watcher behind `zinc run --watch`."""
+
+import os
+from collections.abc import Callable
+from pathlib import Path
+
+from zinc.watch import SourceWatcher, source_snapshot
+
+
+SOURCE = "fn main() {\n    print(1)\n}\n"
+
+
+def test_snapshot_tracks_sources_and_manifest_but_not_build_output(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Generated Cargo projects under .zinc-build never trigger rebuilds."""
+    write_package(tmp_path, SOURCE, "main.zn")
+    build_dir = tmp_path / ".zinc-build" / "main"
+    build_dir.mkdir(parents=True)
+    (build_dir / "copy.zn").write_text("")
+
+    assert set(source_snapshot(tmp_path)) == {tmp_path / "pkg.toml", tmp_path / "main.zn"}
+
+
+def test_watcher_reports_modified_added_and_removed_sources(tmp_path: Path, write_package: Callable[..., Path]) -> None:
+    """Each kind of change is reported exactly once."""
+    entry = write_package(tmp_path, SOURCE, "main.zn")
+    watcher = SourceWatcher(tmp_path)
+    assert not watcher.poll()
+
+    stat = entry.stat()
+    os.utime(entry, ns=(stat.st_atime_ns, stat.st_mtime_ns + 1_000_000_000))
+    assert watcher.poll()
+    assert not watcher.poll()
+
+    helper = tmp_path / "lib" / "helper.zn"
+    helper.parent.mkdir()
+    helper.write_text("fn helper() {}\n")
+    assert watcher.poll()
+
+    helper.unlink()
+    assert watcher.poll()
+    assert not watcher.poll()
@@ -0,0 +1,17 @@
+"""The Zinc compiler; see `zinc.api` for embedding it in other tools."""
+
+from zinc.api import CheckedProgram, CompileError, CompileResult, Diagnostic, check, compile_file, compile_str, generate, parse
+from zinc.passes import PassManager
+
+__all__ = [
+    "CheckedProgram",
+    "CompileError",
+    "CompileResult",
+    "Diagnostic",
+    "PassManager",
+    "check",
+    "compile_file",
+    "compile_str",
+    "generate",
+    "parse",
+]
@@ -0,0 +1,203 @@
+"""Programmatic interface to the compiler for tools that embed it.
+
+Playgrounds, build systems, and editors can drive the compiler without
+shelling out to the CLI. The stages can be run separately:
+
+    tree = parse(source)                     # syntax only
+    checked = check(Path("main.zn"))         # modules, imports, and types
+    program = generate(checked)              # structured Rust output
+
+Each stage raises `CompileError`, whose `diagnostics` carry file and position
+information where the compiler has it. `compile_str` and `compile_file` run
+every stage and return a `CompileResult` instead of raising.
+"""
+
+import dataclasses
+import tempfile
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from antlr4.error.ErrorListener import ErrorListener
+from zinc.atlas import Atlas, AtlasBuilder
+from zinc.codegen import CodeGenVisitor, RustProgram
+from zinc.diagnostics import Diagnostic
+from zinc.exceptions import ZincError, ZincSyntaxError, ZincTypeErrors
+from zinc.modules import PKG_FILE_NAME, ModuleGraph, build_module_graph, parse_source
+from zinc.passes import PassManager, package_passes
+from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.symbols import SymbolTable, SymbolTableVisitor
+from zinc.timings import phase
+
+STRING_ORIGIN = "<string>"
+
+
+class CompileError(ZincError):
+    """Raised by a compiler stage; carries every diagnostic it produced."""
+
+    def __init__(self, diagnostics: list[Diagnostic]):
+        super().__init__("\n".join(str(diagnostic) for diagnostic in diagnostics))
+        self.diagnostics = diagnostics
+
+
+@dataclass
+class CheckedProgram:
+    """Front-end results, ready for code generation."""
+
+    graph: ModuleGraph
+    atlas: Atlas
+    symbols: SymbolTable
+    codegen: CodeGenVisitor
+    warnings: list[Diagnostic] = field(default_factory=list)
+    passes: PassManager = field(default_factory=PassManager)
+
+
+@dataclass
+class CompileResult:
+    """Outcome of a full compilation; `rust` is None when there are errors.
+
+    `diagnostics` holds the errors, or the warnings of a successful compilation.
+    """
+
+    rust: str | None = None
+    program: RustProgram | None = None
+    diagnostics: list[Diagnostic] = field(default_factory=list)
+
+    @property
+    def ok(self) -> bool:
+        """True when compilation produced Rust."""
+        return self.rust is not None
+
+
+def _compile_error(exc: ZincError, file: str | None = None) -> CompileError:
+    """Prefer positioned syntax and type diagnostics over the summary message."""
+    if isinstance(exc, CompileError):
+        return exc
+    if isinstance(exc, (ZincSyntaxError, ZincTypeErrors)):
+        return CompileError([dataclasses.replace(diagnostic, file=diagnostic.file or file) for diagnostic in exc.diagnostics])
+    return CompileError([Diagnostic(str(exc), file)])
+
+
+def parse(source: str, filename: str = STRING_ORIGIN) -> ZincParser.ProgramContext:
+    """Parse source text into a parse tree without loading imports."""
+    try:
+        tree, _ = parse_source(source, filename)
+    except ZincError as exc:
+        raise _compile_error(exc, filename) from exc
+    return tree
+
+
+def front_end(
+    entry: Path,
+    sources: dict[Path, str] | None = None,
+    error_listener: ErrorListener | None = None,
+    passes: PassManager | None = None,
+) -> CheckedProgram:
+    """Run the front end, raising the compiler's own errors; the CLI uses this directly.
+
+    `passes` defaults to the ones registered by the plugins in the package manifest.
+    """
+    sources = dict(sources or {})
+    graph = build_module_graph(entry, sources, error_listener=error_listener)
+    passes = passes if passes is not None else package_passes(entry)
+    warnings = []
+    if passes.syntax_passes:
+        syntax = passes.run_syntax(graph, sources)
+        warnings += _pass_warnings(syntax.diagnostics)
+        if syntax.rewritten:
+            sources.update(syntax.rewritten)
+            graph = build_module_graph(entry, sources, error_listener=error_listener)
+    checked = _check_graph(graph)
+    if passes.typed_passes:
+        typed = passes.run_typed(checked, sources)
+        warnings += _pass_warnings(typed.diagnostics)
+        if typed.rewritten:
+            sources.update(typed.rewritten)
+            checked = _check_graph(build_module_graph(entry, sources, error_listener=error_listener))
+    checked.warnings.extend(warnings)
+    checked.passes = passes
+    return checked
+
+
+def _pass_warnings(diagnostics: list[Diagnostic]) -> list[Diagnostic]:
+    """The warnings compiler passes reported; their errors stop the compile."""
+    if errors := [diagnostic for diagnostic in diagnostics if diagnostic.severity == "error"]:
+        raise ZincTypeErrors(errors)
+    return diagnostics
+
+
+def _check_graph(graph: ModuleGraph) -> CheckedProgram:
+    """Resolve names and types for a loaded module graph."""
+    with phase("typecheck", str(graph.entry_file)):
+        atlas = AtlasBuilder(graph).build()
+        symbol_visitor = SymbolTableVisitor(atlas)
+        symbols = symbol_visitor.resolve()
+    codegen = CodeGenVisitor(
+        atlas,
+        symbols,
+        symbol_visitor.specialization_map,
+        symbol_visitor._channel_infos,
+        symbol_visitor.lexical_functions,
+        symbol_visitor.bound_call_args,
+        symbol_visitor.bound_struct_fields,
+        symbol_visitor.callable_call_specialization_map,
+        symbol_visitor.ufcs_extern_call_map,
+        symbol_visitor.operator_calls,
+    )
+    return CheckedProgram(graph, atlas, symbols, codegen, symbol_visitor.warnings)
+
+
+def check(entry: Path, sources: dict[Path, str] | None = None, passes: PassManager | None = None) -> CheckedProgram:
+    """Load an entry file and its imports, then resolve names and types.
+
+    `sources` maps resolved paths to text that takes precedence over the files
+    on disk. `passes` replaces the compiler passes of the package's plugins.
+    """
+    try:
+        return front_end(entry, sources, passes=passes)
+    except ZincError as exc:
+        raise _compile_error(exc, str(entry)) from exc
+
+
+def generate(checked: CheckedProgram) -> RustProgram:
+    """Generate the Rust program for a checked entry file."""
+    try:
+        return checked.codegen.generate()
+    except ZincError as exc:
+        raise CompileError([Diagnostic(str(exc), str(checked.graph.entry_file))]) from exc
+
+
+def compile_file(entry: Path, sources: dict[Path, str] | None = None, passes: PassManager | None = None) -> CompileResult:
+    """Compile an entry file (inside a package) to Rust, collecting diagnostics instead of raising."""
+    try:
+        checked = check(entry, sources, passes)
+        program = generate(checked)
+    except CompileError as exc:
+        return CompileResult(diagnostics=exc.diagnostics)
+    return CompileResult(rust=program.render(), program=program, diagnostics=checked.warnings)
+
+
+def compile_str(source: str, modules: dict[str, str] | None = None, filename: str = "main.zn") -> CompileResult:
+    """Compile a program given as text, collecting diagnostics instead of raising.
+
+    `modules` maps import paths such as `"util/math"` to their source, so
+    multi-module programs need no files on disk. Diagnostics name files
+    relative to that virtual package.
+    """
+    with tempfile.TemporaryDirectory(prefix="zinc-api-") as workdir:
+        root = Path(workdir).resolve()
+        (root / PKG_FILE_NAME).write_text('[package]\nname = "main"\nversion = "0.1.0"\n')
+        files = {filename: source, **{f"{module_path}.zn": text for module_path, text in (modules or {}).items()}}
+        for relative, text in files.items():
+            path = root / relative
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(text)
+        result = compile_file(root / filename)
+    result.diagnostics = [_relative_to(diagnostic, root) for diagnostic in result.diagnostics]
+    return result
+
+
+def _relative_to(diagnostic: Diagnostic, root: Path) -> Diagnostic:
+    file = diagnostic.file
+    if file is not None and Path(file).is_relative_to(root):
+        file = Path(file).relative_to(root).as_posix()
+    return dataclasses.replace(diagnostic, message=diagnostic.message.replace(f"{root}/", ""), file=file)
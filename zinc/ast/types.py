@@ -2,11 +2,29 @@
 
 from __future__ import annotations
 
+from collections.abc import Iterator
+from contextlib import contextmanager
+from contextvars import ContextVar
 from dataclasses import dataclass, field
 from enum import Enum, auto
 
 from zinc.string_literals import is_string_literal
 
+DEFAULT_INTEGER_TYPE = "i64"
+# Widths a package may choose for unannotated integers with `default-int` in its manifest.
+DEFAULT_INTEGER_CHOICES = ("i8", "i16", "i32", "i64", "i128", "isize")
+_default_integer_type: ContextVar[str] = ContextVar("default_integer_type", default=DEFAULT_INTEGER_TYPE)
+
+
+@contextmanager
+def default_integer_type(exact_type: str) -> Iterator[None]:
+    """Give unannotated integers `exact_type` while one package is checked and generated."""
+    token = _default_integer_type.set(exact_type)
+    try:
+        yield
+    finally:
+        _default_integer_type.reset(token)
+
 
 class BaseType(Enum):
     """Base types supported by Zinc."""
@@ -85,7 +103,7 @@ def parse_literal(literal_text: str) -> BaseType:
 def type_to_rust(base_type: BaseType) -> str:
     """Convert a BaseType to its Rust type name."""
     mapping = {
-        BaseType.INTEGER: "i64",
+        BaseType.INTEGER: _default_integer_type.get(),
         BaseType.FLOAT: "f64",
         BaseType.STRING: "String",
         BaseType.BOOLEAN: "bool",
@@ -169,7 +187,7 @@ def exact_type_to_base(type_name: str | None) -> BaseType:
 def default_exact_type(base_type: BaseType) -> str | None:
     """Return the default exact scalar type used for unannotated primitive values."""
     mapping = {
-        BaseType.INTEGER: "i64",
+        BaseType.INTEGER: _default_integer_type.get(),
         BaseType.FLOAT: "f64",
         BaseType.STRING: "String",
         BaseType.BOOLEAN: "bool",
@@ -498,7 +516,9 @@ class ArrayTypeInfo:
     element_anonymous_struct_info: AnonymousStructTypeInfo | None = None
     element_result_info: ResultTypeInfo | None = None
     element_option_info: OptionTypeInfo | None = None
+    element_array_info: ArrayTypeInfo | None = None  # The rows of a nested list, as in [[i64]]
     is_mutated: bool = False  # True if array is modified (push, pop, etc.)
+    length: int | None = None  # The element count of a fixed-size array; None for a growable list
 
     def element_rust_type(self) -> str:
         """Generate Rust type for the array element."""
@@ -514,20 +534,23 @@ class ArrayTypeInfo:
             return self.element_result_info.to_rust_type()
         if self.element_type == BaseType.OPTION and self.element_option_info:
             return self.element_option_info.to_rust_type()
+        if self.element_type == BaseType.ARRAY and self.element_array_info:
+            return self.element_array_info.to_rust_type(as_reference=False)
         return exact_type_to_rust(self.element_exact_type, self.element_type)
 
     def to_rust_type(self, as_reference: bool = True) -> str:
-        """Generate Rust type string (always Vec in Zinc).
+        """Generate Rust type string: a Vec, or an array for a fixed-size array.
 
         Args:
             as_reference: If True, generate &Vec<T> or &mut Vec<T> for parameters
         """
         elem = self.element_rust_type()
+        owned = f"Vec<{elem}>" if self.length is None else f"[{elem}; {self.length}]"
         if as_reference:
             if self.is_mutated:
-                return f"&mut Vec<{elem}>"
-            return f"&Vec<{elem}>"
-        return f"Vec<{elem}>"
+                return f"&mut {owned}"
+            return f"&{owned}"
+        return owned
 
     def to_rust_type_suffix(self) -> str:
         """Generate type suffix for mangled names."""
@@ -543,9 +566,11 @@ class ArrayTypeInfo:
             elem = self.element_result_info.to_rust_type_suffix()
         elif self.element_type == BaseType.OPTION and self.element_option_info:
             elem = self.element_option_info.to_rust_type_suffix()
+        elif self.element_type == BaseType.ARRAY and self.element_array_info:
+            elem = self.element_array_info.to_rust_type_suffix()
         else:
             elem = exact_type_to_rust(self.element_exact_type, self.element_type)
-        return f"Vec_{elem}"
+        return f"Vec_{elem}" if self.length is None else f"Array{self.length}_{elem}"
 
     def copy(self) -> ArrayTypeInfo:
         """Deep-copy array metadata."""
@@ -558,7 +583,9 @@ class ArrayTypeInfo:
             element_anonymous_struct_info=self.element_anonymous_struct_info.copy() if self.element_anonymous_struct_info else None,
             element_result_info=self.element_result_info.copy() if self.element_result_info else None,
             element_option_info=self.element_option_info.copy() if self.element_option_info else None,
+            element_array_info=self.element_array_info.copy() if self.element_array_info else None,
             is_mutated=self.is_mutated,
+            length=self.length,
         )
 
 
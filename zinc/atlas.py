@@ -35,6 +35,9 @@ from zinc.parser.zincParser import zincParser as ZincParser
 from zinc.string_literals import is_string_literal, to_rust_string_literal
 
 CompositionMode = str
+DISPLAY_METHOD = "to_string"
+ITERATE_METHOD = "iterate"
+NEXT_METHOD = "next"
 NUMERIC_TYPE_ALTERNATIVES = (
     "i8",
     "i16",
@@ -51,6 +54,34 @@ NUMERIC_TYPE_ALTERNATIVES = (
     "f32",
     "f64",
 )
+BUILTIN_FUNCTIONS = frozenset(
+    {
+        "print",
+        "chan",
+        "close",
+        "dict",
+        "sort_dict",
+        "set",
+        "sort_set",
+        "meta",
+        "type",
+        "line",
+        "has_component",
+        "implements",
+        "assert",
+        "assert_eq",
+        "exit",
+        "dbg",
+        "print_debug",
+        "uuid",
+        "to_json",
+        "temp_file",
+        "temp_dir",
+        "include_str",
+        "matrix",
+        "on_interrupt",
+    }
+)
 
 
 @dataclass
@@ -85,7 +116,10 @@ class FunctionInstance:
     # Struct identity metadata for arguments
     arg_struct_qualified_names: dict[int, str] = field(default_factory=dict)
     arg_anonymous_struct_infos: dict[int, AnonymousStructTypeInfo] = field(default_factory=dict)
+    # String/struct arguments the body only reads or updates in place (arg_index -> "&" or "&mut")
+    arg_borrows: dict[int, str] = field(default_factory=dict)
     # Rich type info for collection return values
+    return_array_info: ArrayTypeInfo | None = None
     return_dict_info: DictTypeInfo | None = None
     return_set_info: SetTypeInfo | None = None
     return_tuple_info: TupleTypeInfo | None = None
@@ -175,6 +209,8 @@ class StructFieldInfo:
             if self.rust_type() == "String" and is_string_literal(self.default_value):
                 return f"String::from({to_rust_string_literal(self.default_value)})"
             return self.default_value
+        if self.array_info is not None and self.array_info.length is not None:
+            return "std::array::from_fn(|_| Default::default())"
         defaults = {
             "i8": "0",
             "i16": "0",
@@ -231,11 +267,14 @@ class StructInstance:
     ctx: ParserRuleContext
     methods_used: SortedSet[str] = field(default_factory=SortedSet)
     fields: list[StructFieldInfo] = field(default_factory=list)
+    static_fields: list[StructFieldInfo] = field(default_factory=list)
     methods: list[StructMethodInfo] = field(default_factory=list)
     composition_mode: CompositionMode | None = None
     composition_sources: tuple[str, ...] = ()
     infer_slot_names: tuple[str, ...] = ()
     has_decorators: bool = False
+    is_interface: bool = False
+    interfaces: tuple[str, ...] = ()
 
 
 @dataclass
@@ -267,6 +306,46 @@ class EnumInstance:
     has_decorators: bool = False
 
 
+def is_required_method(method: StructMethodInfo) -> bool:
+    """Return True for an interface method declared without a body, which every implementing struct must provide."""
+    return method.body_ctx is not None and not method.body_ctx.statement()
+
+
+def iterator_next_method(methods: list[StructMethodInfo]) -> StructMethodInfo | None:
+    """The `next()` method that makes a struct an iterator `for` can loop over, when the struct declares one that fits.
+
+    It must take no arguments and return an `Option`; the loop ends at the first `None`.
+    """
+    for method in methods:
+        if method.name != NEXT_METHOD or method.is_static or method.parameters:
+            continue
+        if (method.return_type or "").startswith("Option<"):
+            return method
+    return None
+
+
+def iterate_method(methods: list[StructMethodInfo]) -> StructMethodInfo | None:
+    """The `iterate()` method a struct declares to hand `for` a fresh iterator over its contents, if any."""
+    for method in methods:
+        if method.name == ITERATE_METHOD and not method.is_static and not method.parameters and method.return_type is not None:
+            return method
+    return None
+
+
+def display_method(methods: list[StructMethodInfo]) -> StructMethodInfo | None:
+    """The `to_string()` method that `print` and `{}` use to show a struct or enum, when the type declares one that fits.
+
+    It must take no arguments, return a string, and leave the value alone;
+    types without one print the way `print_debug` shows them on one line.
+    """
+    for method in methods:
+        if method.name != DISPLAY_METHOD or method.parameters or method.self_mutability == "&mut self":
+            continue
+        if method.return_type in {"string", "String"}:
+            return method
+    return None
+
+
 @dataclass
 class ConstInstance:
     """A global constant declaration."""
@@ -292,6 +371,10 @@ class Atlas:
     enum_usages: SortedDict[str, SortedSet[str]] = field(default_factory=SortedDict)
     const_usages: SortedDict[str, SortedSet[str]] = field(default_factory=SortedDict)
     function_defs: SortedDict[str, ParserRuleContext] = field(default_factory=SortedDict)
+    uses_debug_print: bool = False  # dbg()/print_debug() need Debug on generated types
+    uses_display: bool = False  # print() and "{value}" of a struct or enum need Display on generated types
+    uses_iteration: bool = False  # for loops over structs call their iterate() and next() methods
+    interrupt_handlers: list[CallableTypeInfo] = field(default_factory=list)  # on_interrupt() handler signatures
 
     def is_reachable(self, name: str) -> bool:
         """Check if a function, struct, enum, or const is reachable."""
@@ -477,7 +560,7 @@ class Atlas:
             elif base_type == BaseType.STRUCT and arg_anonymous_struct_infos and i in arg_anonymous_struct_infos:
                 type_parts.append(arg_anonymous_struct_infos[i].to_rust_type_suffix())
             elif base_type == BaseType.STRUCT and arg_struct_qualified_names and i in arg_struct_qualified_names:
-                type_parts.append(f"Struct_{re.sub(r'[^0-9A-Za-z]+', '_', arg_struct_qualified_names[i])}")
+                type_parts.append("Struct_" + re.sub(r"[\W_]+", "_", arg_struct_qualified_names[i]))
             elif base_type == BaseType.ENUM:
                 type_parts.append(f"Enum_{exact_type_to_rust(exact_type, base_type)}")
             else:
@@ -508,20 +591,7 @@ class Atlas:
 class AtlasBuilder:
     """Build the Atlas from a module graph."""
 
-    BUILTIN_FUNCTIONS = {
-        "print",
-        "chan",
-        "close",
-        "dict",
-        "sort_dict",
-        "set",
-        "sort_set",
-        "meta",
-        "type",
-        "line",
-        "has_component",
-        "implements",
-    }
+    BUILTIN_FUNCTIONS = BUILTIN_FUNCTIONS
 
     def __init__(self, module_graph: ModuleGraph):
         """Initialize an atlas builder for the resolved module graph."""
@@ -644,13 +714,17 @@ class AtlasBuilder:
         if isinstance(ctx, ZincParser.LiteralContext) and ctx.STRING():
             text = ctx.STRING().getText()[1:-1]
             for expr in re.findall(r"\{([^}]+)\}", text):
-                for token in re.findall(r"\b[A-Za-z_][A-Za-z0-9_]*(?:\.[A-Za-z_][A-Za-z0-9_]*)*\b", expr):
+                for token in re.findall(r"\b[^\W\d]\w*(?:\.[^\W\d]\w*)*\b", expr):
                     path = token.split(".")
                     const_symbol = self.module_graph.resolve_const_path(self._current_module, path)
                     if const_symbol:
                         self._add_const_usage(const_symbol.qualified_name)
+                    static_target = self.module_graph.resolve_static_method_target(self._current_module, path)
+                    if static_target:
+                        type_symbol, member_name = static_target
+                        self._add_type_usage(type_symbol.qualified_name, member_name)
 
-        if isinstance(ctx, ZincParser.MemberAccessExprContext):
+        if isinstance(ctx, (ZincParser.MemberAccessExprContext, ZincParser.MemberAccessContext)):
             path = extract_identifier_path(ctx)
             if path:
                 const_symbol = self.module_graph.resolve_const_path(self._current_module, path)
@@ -673,9 +747,13 @@ class AtlasBuilder:
                     self._calls[self._current_function].add(func_symbol.qualified_name)
                 else:
                     static_target = self.module_graph.resolve_static_method_target(self._current_module, path)
+                    struct_symbol = self.module_graph.resolve_struct_path(self._current_module, path)
                     if static_target:
                         type_symbol, method_name = static_target
                         self._add_type_usage(type_symbol.qualified_name, method_name)
+                    elif struct_symbol:
+                        # `Name(args...)` constructs through the struct's `new` when it declares one.
+                        self._add_struct_usage(struct_symbol.qualified_name, "new")
             if isinstance(ctx.expression(), ZincParser.MemberAccessExprContext):
                 member_name = ctx.expression().IDENTIFIER().getText()
                 func_symbol = self.module_graph.resolve_function_path(self._current_module, [member_name])
@@ -723,7 +801,8 @@ class AtlasBuilder:
         if struct is None:
             return
 
-        if qualified_name not in self._reachable_structs:
+        newly_reachable = qualified_name not in self._reachable_structs
+        if newly_reachable:
             self._reachable_structs[qualified_name] = StructInstance(
                 name=struct.name,
                 qualified_name=struct.qualified_name,
@@ -740,6 +819,18 @@ class AtlasBuilder:
             self._struct_usages[self._current_function].add(qualified_name)
 
         self._add_composition_source_usages(qualified_name, set())
+        if newly_reachable:
+            self._add_iterator_usage(struct)
+
+    def _add_iterator_usage(self, struct: StructInstance) -> None:
+        """Mark the struct a reachable struct's iterate() method returns, which for loops step through."""
+        for member in struct.ctx.structBody().structMember():
+            method_ctx = member.functionDeclaration()
+            if method_ctx is None or method_ctx.functionName().getText() != ITERATE_METHOD or method_ctx.type_() is None:
+                continue
+            iterator_symbol = self.module_graph.resolve_struct_path(struct.module_id, method_ctx.type_().getText().split("."))
+            if iterator_symbol is not None:
+                self._add_struct_usage(iterator_symbol.qualified_name, None)
 
     def _add_enum_usage(self, qualified_name: str, method_name: str | None) -> None:
         """Record that an enum is used, optionally with a specific method."""
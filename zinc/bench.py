@@ -0,0 +1,177 @@
+"""Benchmark discovery and execution for `zinc bench`.
+
+Top-level functions marked `@bench` are benchmarks. They are compiled through
+the same harness as `@test` functions; the harness's Rust `main` dispatches on
+the benchmark name and hands the call to the runtime's `run_bench`, which does
+the warmup and timed iterations and prints one summary line. Every benchmark
+runs in its own release-mode process. With `--criterion` the harness is
+written out as a criterion bench target instead and run with `cargo bench`.
+"""
+
+import dataclasses
+import re
+import subprocess
+from collections.abc import Callable
+from dataclasses import dataclass
+from pathlib import Path
+
+from zinc.build import build_binary, cargo_manifest, crate_name_for, default_build_dir, write_cargo_project
+from zinc.codegen import CodeGenVisitor, RustProgram
+from zinc.exceptions import ZincBuildError
+from zinc.testing import compile_harness, dispatch_main_body
+
+BENCH_FEATURE = "bench"
+CRITERION_DEPENDENCY = 'criterion = "0.5"'
+CRITERION_BENCH_NAME = "zinc"
+SUMMARY_PATTERN = re.compile(r"^zinc-bench iterations=(\d+) total_ns=(\d+) min_ns=(\d+) max_ns=(\d+)$", re.MULTILINE)
+
+# argv[2] and argv[3] carry the warmup and iteration counts.
+COUNT_PREAMBLE = (
+    "let counts: Vec<u64> = std::env::args().skip(2).map(|arg| arg.parse().unwrap_or(0)).collect();",
+    "let warmup = counts.first().copied().unwrap_or(0);",
+    "let iterations = counts.get(1).copied().unwrap_or(1);",
+)
+
+
+@dataclass
+class BenchResult:
+    """Timings from one benchmark process; `output` holds the process output when it failed."""
+
+    name: str
+    iterations: int = 0
+    total_ns: int = 0
+    min_ns: int = 0
+    max_ns: int = 0
+    output: str = ""
+
+    @property
+    def passed(self) -> bool:
+        """True when the benchmark ran to completion."""
+        return self.iterations > 0
+
+    @property
+    def mean_ns(self) -> float:
+        """Mean time per iteration."""
+        return self.total_ns / self.iterations
+
+
+def compile_benches(
+    file: Path, compile_pipeline: Callable[[Path, dict[Path, str]], CodeGenVisitor]
+) -> tuple[RustProgram, dict[str, str]]:
+    """Compile the bench harness for a source file; returns the program and each benchmark's Rust call."""
+    program, calls = compile_harness(file, compile_pipeline, marker="bench")
+    if not calls:
+        return program, calls
+    arms = {name: f"zinc_internal::run_bench(warmup, iterations, || {{ {call}; }});" for name, call in calls.items()}
+    program = dataclasses.replace(
+        program,
+        main_body=dispatch_main_body(arms, preamble=COUNT_PREAMBLE),
+        runtime_features=program.runtime_features | {BENCH_FEATURE},
+    )
+    return program, calls
+
+
+def bench_build_dir(file: Path) -> Path:
+    """Keep bench builds apart from the regular and test builds of the same file."""
+    return default_build_dir(file).with_name(f"{crate_name_for(file)}-bench")
+
+
+def parse_summary(name: str, output: str) -> BenchResult:
+    """Read the `zinc-bench` summary line printed by the runtime."""
+    match = SUMMARY_PATTERN.search(output)
+    if match is None:
+        return BenchResult(name, output=output)
+    iterations, total_ns, min_ns, max_ns = (int(group) for group in match.groups())
+    return BenchResult(name, iterations, total_ns, min_ns, max_ns)
+
+
+def run_benches(binary: Path, names: list[str], warmup: int, iterations: int) -> list[BenchResult]:
+    """Run each benchmark in its own process."""
+    results = []
+    for name in names:
+        result = subprocess.run([str(binary), name, str(warmup), str(iterations)], capture_output=True, text=True)
+        output = result.stdout + result.stderr
+        results.append(parse_summary(name, output) if result.returncode == 0 else BenchResult(name, output=output))
+    return results
+
+
+def build_and_run_benches(
+    file: Path, compile_pipeline: Callable[[Path, dict[Path, str]], CodeGenVisitor], warmup: int, iterations: int
+) -> list[BenchResult]:
+    """Compile, build in release mode, and run every benchmark in a source file."""
+    program, calls = compile_benches(file, compile_pipeline)
+    if not calls:
+        return []
+    binary = build_binary(program, file, build_dir=bench_build_dir(file))
+    return run_benches(binary, list(calls), warmup, iterations)
+
+
+def criterion_source(program: RustProgram, calls: dict[str, str], group: str) -> str:
+    """Render a criterion bench target holding the program's items and one `bench_function` per benchmark."""
+    lines = ["use criterion::{criterion_group, criterion_main, Criterion};", "", *program.render_items()]
+    lines.append("fn zinc_benches(c: &mut Criterion) {")
+    for name, call in calls.items():
+        lines.append(f'    c.bench_function("{group}::{name}", |b| b.iter(|| {{ {call}; }}));')
+    lines.extend(["}", "", "criterion_group!(benches, zinc_benches);", "criterion_main!(benches);", ""])
+    return "\n".join(lines)
+
+
+def criterion_manifest(crate_name: str, program: RustProgram) -> str:
+    """Extend the regular manifest with criterion and a harness-less bench target."""
+    return cargo_manifest(crate_name, program) + "\n".join(
+        ["", "[dev-dependencies]", CRITERION_DEPENDENCY, "", "[[bench]]", f'name = "{CRITERION_BENCH_NAME}"', "harness = false", ""]
+    )
+
+
+def write_criterion_project(file: Path, compile_pipeline: Callable[[Path, dict[Path, str]], CodeGenVisitor]) -> Path | None:
+    """Write a Cargo project whose `cargo bench` runs a file's benchmarks under criterion."""
+    program, calls = compile_benches(file, compile_pipeline)
+    if not calls:
+        return None
+    crate_name = crate_name_for(file)
+    project_dir = write_cargo_project(program, crate_name, bench_build_dir(file))
+    (project_dir / "Cargo.toml").write_text(criterion_manifest(crate_name, program))
+    benches_dir = project_dir / "benches"
+    benches_dir.mkdir(exist_ok=True)
+    (benches_dir / f"{CRITERION_BENCH_NAME}.rs").write_text(criterion_source(program, calls, file.stem))
+    return project_dir
+
+
+def run_criterion(project_dir: Path) -> int:
+    """Run `cargo bench` on a criterion project with inherited stdio."""
+    try:
+        return subprocess.run(["cargo", "bench"], cwd=project_dir).returncode
+    except FileNotFoundError as exc:
+        raise ZincBuildError("cargo was not found on PATH; install a Rust toolchain from https://rustup.rs") from exc
+
+
+def format_duration(nanoseconds: float) -> str:
+    """Render a duration with a readable unit."""
+    for unit, scale in (("s", 1e9), ("ms", 1e6), ("µs", 1e3)):
+        if nanoseconds >= scale:
+            return f"{nanoseconds / scale:.2f} {unit}"
+    return f"{nanoseconds:.0f} ns"
+
+
+def report(results: dict[Path, list[BenchResult]], write: Callable[[str], None] = print) -> bool:
+    """Print a per-benchmark timing report plus failure output; return True when every benchmark ran."""
+    total = sum(len(file_results) for file_results in results.values())
+    write(f"running {total} benchmark{'s' if total != 1 else ''}")
+    failures = []
+    for file, file_results in results.items():
+        for result in file_results:
+            if result.passed:
+                write(
+                    f"bench {file}::{result.name} ... {format_duration(result.mean_ns)}/iter "
+                    f"(min {format_duration(result.min_ns)}, max {format_duration(result.max_ns)}, {result.iterations} iterations)"
+                )
+            else:
+                write(f"bench {file}::{result.name} ... FAILED")
+                failures.append((file, result))
+    if failures:
+        write("\nfailures:")
+        for file, result in failures:
+            write(f"\n---- {file}::{result.name} ----")
+            write(result.output.rstrip("\n") or "(no output)")
+    write(f"\nbench result: {'FAILED' if failures else 'ok'}. {total - len(failures)} measured; {len(failures)} failed")
+    return not failures
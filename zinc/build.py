@@ -0,0 +1,243 @@
+"""Cargo project generation and native builds for compiled Zinc programs."""
+
+import os
+import re
+import shutil
+import subprocess
+import sys
+from dataclasses import dataclass, replace
+from pathlib import Path
+
+from zinc.codegen import RustProgram
+from zinc.exceptions import ZincBuildError, ZincRustcErrors
+from zinc.rust_runtime import RUNTIME_MODULE_NAME, RUNTIME_PACKAGE_NAME, sync_runtime
+from zinc.rustc_errors import parse_cargo_messages, zinc_diagnostic
+from zinc.sourcemap import SOURCE_MAP_FILE_NAME, SourceMap
+from zinc.targets import BROWSER_GETRANDOM_DEPENDENCY, HOST, WASM_BINDGEN_DEPENDENCY, Target, cross_linker, linker_env_var
+from zinc.timings import phase
+
+BUILD_DIR_NAME = ".zinc-build"
+TOKIO_DEPENDENCY = 'tokio = { version = "1", features = ["full"] }'
+# Without threads, signals, or an OS reactor, wasm builds get only the current-thread runtime.
+WASM_TOKIO_DEPENDENCY = 'tokio = { version = "1", features = ["macros", "rt", "sync"] }'
+
+
+OPT_LEVELS = (0, 1, 2, 3, "s", "z")
+LTO_SETTINGS = (False, True, "thin", "fat", "off")
+PANIC_STRATEGIES = ("unwind", "abort")
+
+
+@dataclass(frozen=True)
+class BuildProfile:
+    """Cargo profile for a build; unset settings keep Cargo's defaults for that profile."""
+
+    release: bool = True
+    opt_level: int | str | None = None
+    lto: bool | str | None = None
+    panic: str | None = None
+    overflow_checks: bool | None = None
+
+    @property
+    def cargo_name(self) -> str:
+        """Name of the Cargo profile these settings apply to."""
+        return "release" if self.release else "dev"
+
+    def manifest_lines(self) -> list[str]:
+        """Render the `[profile.*]` table, or nothing when every setting is Cargo's default."""
+        settings = {"opt-level": self.opt_level, "lto": self.lto, "panic": self.panic, "overflow-checks": self.overflow_checks}
+        lines = [f"{key} = {_toml_value(value)}" for key, value in settings.items() if value is not None]
+        return [f"[profile.{self.cargo_name}]", *lines, ""] if lines else []
+
+
+RELEASE = BuildProfile()
+DEBUG = BuildProfile(release=False)
+
+
+def _toml_value(value: bool | int | str) -> str:
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    return str(value) if isinstance(value, int) else f'"{value}"'
+
+
+def profile_setting(key: str, value: object) -> bool | int | str:
+    """Validate a profile setting from zinc.toml or the command line, where every value arrives as a string."""
+    if isinstance(value, str):
+        if key == "opt-level" and value.isdigit():
+            value = int(value)
+        elif key == "lto" and value in ("true", "false"):
+            value = value == "true"
+    choices = {"opt-level": OPT_LEVELS, "lto": LTO_SETTINGS, "panic": PANIC_STRATEGIES}.get(key)
+    if choices is None:
+        raise ZincBuildError(f"unknown profile setting '{key}'; expected one of: opt-level, lto, panic")
+    # `True in (1, ...)` holds in Python, so compare types as well as values.
+    if not any(value == choice and type(value) is type(choice) for choice in choices):
+        expected = ", ".join(_toml_value(choice) for choice in choices)
+        raise ZincBuildError(f"invalid {key} {value!r}; expected one of: {expected}")
+    return value
+
+
+def crate_name_for(entry: Path) -> str:
+    """Return a Cargo package name derived from a Zinc entry file."""
+    name = re.sub(r"[^a-z0-9_]", "_", entry.stem.lower())
+    if not name or name[0].isdigit():
+        name = f"zinc_{name}"
+    return name
+
+
+def default_build_dir(entry: Path) -> Path:
+    """Return the Cargo project directory used when no --build-dir is given."""
+    return entry.resolve().parent / BUILD_DIR_NAME / crate_name_for(entry)
+
+
+def cargo_manifest(crate_name: str, program: RustProgram, profile: BuildProfile = RELEASE) -> str:
+    """Render the Cargo.toml for a generated program."""
+    lines = [
+        "[package]",
+        f'name = "{crate_name}"',
+        'version = "0.1.0"',
+        'edition = "2021"',
+        "",
+    ]
+    if program.target.is_browser:
+        lines.extend(["[lib]", 'crate-type = ["cdylib"]', ""])
+    lines.append("[dependencies]")
+    if program.uses_async:
+        lines.append(WASM_TOKIO_DEPENDENCY if program.target.is_wasm else TOKIO_DEPENDENCY)
+    if program.target.is_browser:
+        lines.append(WASM_BINDGEN_DEPENDENCY)
+        if "uuid" in program.runtime_features:
+            lines.append(BROWSER_GETRANDOM_DEPENDENCY)
+    if program.runtime_features:
+        feature_list = ", ".join(f'"{feature}"' for feature in sorted(program.runtime_features))
+        lines.append(f'{RUNTIME_PACKAGE_NAME} = {{ path = "{RUNTIME_MODULE_NAME}", default-features = false, features = [{feature_list}] }}')
+    for name, requirement in sorted(program.crates.items()):
+        lines.append(f"{name} = {requirement}")
+    lines.append("")
+    if program.overflow_checks:
+        profile = replace(profile, overflow_checks=True)
+    lines.extend(profile.manifest_lines())
+    # Keep the generated project out of any enclosing Cargo workspace.
+    lines.extend(["[workspace]", ""])
+    return "\n".join(lines)
+
+
+def check_target_support(program: RustProgram) -> None:
+    """Reject programs that use features their target cannot provide."""
+    if program.target.is_wasm and (program.uses_interrupt or "signal" in program.runtime_features):
+        raise ZincBuildError(f"on_interrupt() is not supported on {program.target.triple}: WebAssembly has no signals")
+    if program.target.is_windows and "signal" in program.runtime_features:
+        # The blocking handler is built on Unix signals; tokio's ctrl_c also covers Windows consoles.
+        raise ZincBuildError("on_interrupt() in a synchronous program is only supported on Unix; make main() async to use it on Windows")
+
+
+def write_cargo_project(
+    program: RustProgram, crate_name: str, project_dir: Path, profile: BuildProfile = RELEASE, *, rustfmt: bool = True
+) -> Path:
+    """Write a standalone Cargo project for a program and return its directory; `rustfmt` formats its source."""
+    check_target_support(program)
+    src_dir = project_dir / "src"
+    src_dir.mkdir(parents=True, exist_ok=True)
+    files, source_map = program.render_files(rustfmt=rustfmt)
+    # Drop sources left over from a previous build, such as the other crate root or a module that is no longer imported.
+    for stale in src_dir.rglob("*.rs"):
+        if stale.relative_to(src_dir).as_posix() not in files:
+            stale.unlink()
+    for name, rendered in files.items():
+        (src_dir / name).parent.mkdir(parents=True, exist_ok=True)
+        _write_if_changed(src_dir / name, rendered + "\n")
+    _write_if_changed(project_dir / SOURCE_MAP_FILE_NAME, source_map.to_json())
+    if program.runtime_features:
+        sync_runtime(project_dir)
+    _write_if_changed(project_dir / "Cargo.toml", cargo_manifest(crate_name, program, profile))
+    return project_dir
+
+
+def _write_if_changed(path: Path, text: str) -> None:
+    """Write a file unless it already holds `text`, so cargo sees untouched modules as up to date."""
+    if path.exists() and path.read_text() == text:
+        return
+    path.write_text(text)
+
+
+def cargo_build(project_dir: Path, crate_name: str, release: bool = True, target: Target = HOST) -> Path:
+    """Run cargo on a generated project and return the path of the built binary."""
+    command = ["cargo", "build", "--quiet", "--message-format", "json"]
+    if release:
+        command.append("--release")
+    if target.triple is not None:
+        command.extend(["--target", target.triple])
+    # Generated code is not meant to be read, so only surface real errors.
+    env = {**os.environ, "RUSTFLAGS": os.environ.get("RUSTFLAGS", "-A warnings")}
+    if target.is_cross and linker_env_var(target) not in env:
+        linker = cross_linker(target)
+        if linker is not None:
+            env[linker_env_var(target)] = linker
+    try:
+        with phase("cargo build", crate_name):
+            result = subprocess.run(command, cwd=project_dir, capture_output=True, text=True, env=env)
+    except FileNotFoundError as exc:
+        raise ZincBuildError("cargo was not found on PATH; install a Rust toolchain from https://rustup.rs") from exc
+    if result.returncode != 0:
+        source_map = SourceMap.load(project_dir)
+        rustc_errors = parse_cargo_messages(result.stdout)
+        if rustc_errors and all(error.in_generated_source(project_dir) for error in rustc_errors):
+            raise ZincRustcErrors([zinc_diagnostic(error, source_map, project_dir) for error in rustc_errors])
+        output = "".join(error.rendered for error in rustc_errors) + result.stderr
+        hint = ""
+        if target.triple is not None and "target may not be installed" in output:
+            hint = f"\nhint: install the target with `rustup target add {target.triple}`"
+        elif target.is_cross and "linking with" in output:
+            hint = f"\nhint: install a linker for {target.triple} and point {linker_env_var(target)} at it"
+        output = source_map.rewrite(output) if source_map is not None else output
+        raise ZincBuildError(f"cargo build failed in {project_dir}:\n{output}{hint}")
+    profile_dir = project_dir / "target" / (target.triple or "") / ("release" if release else "debug")
+    return profile_dir / f"{crate_name}{target.artifact_suffix}"
+
+
+def build_binary(
+    program: RustProgram, entry: Path, build_dir: Path | None = None, profile: BuildProfile = RELEASE, *, rustfmt: bool = True
+) -> Path:
+    """Write and build the Cargo project for an entry file, returning the binary in the build directory."""
+    crate_name = crate_name_for(entry)
+    project_dir = write_cargo_project(program, crate_name, build_dir or default_build_dir(entry), profile, rustfmt=rustfmt)
+    return cargo_build(project_dir, crate_name, release=profile.release, target=program.target)
+
+
+def copy_binary(binary: Path, output: Path) -> Path:
+    """Copy a built binary to its requested location, keeping it executable."""
+    if output.parent != Path():
+        output.parent.mkdir(parents=True, exist_ok=True)
+    shutil.copy2(binary, output)
+    return output
+
+
+def run_binary(
+    binary: Path, args: list[str] | tuple[str, ...] = (), target: Target = HOST, source_map: SourceMap | None = None
+) -> int:
+    """Run a built program with inherited stdio and return a shell-style exit status.
+
+    With a `source_map`, stderr is relayed line by line so panic locations
+    and backtraces name Zinc source instead of the generated Rust.
+    """
+    command = [str(binary), *args]
+    if target.is_browser:
+        raise ZincBuildError(f"{target.triple} builds run in a browser; load {binary.name} with wasm-bindgen instead")
+    if target.is_cross:
+        raise ZincBuildError(f"can't run {target.triple} binaries on this machine; use `zinc build` and copy {binary.name} to the target")
+    if target.is_wasi:
+        runtime = shutil.which("wasmtime")
+        if runtime is None:
+            raise ZincBuildError("running WASI programs needs wasmtime on PATH; install it from https://wasmtime.dev")
+        command = [runtime, "run", str(binary), *args]
+    if source_map is None:
+        returncode = subprocess.run(command).returncode
+    else:
+        with subprocess.Popen(command, stderr=subprocess.PIPE) as process:
+            for line in process.stderr:
+                sys.stderr.write(source_map.rewrite(line.decode(errors="replace")))
+                sys.stderr.flush()
+        returncode = process.returncode
+    if returncode < 0:
+        # Killed by a signal: report it the way shells do (128 + signal number).
+        return 128 - returncode
+    return returncode
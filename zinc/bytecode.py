@@ -0,0 +1,1129 @@
+"""Bytecode behind `zinc build --emit bytecode` and `zinc exec`.
+
+    $ zinc build --emit bytecode main.zn
+    $ zinc exec main.znb
+
+A checked program compiles to a `Program`: a `Code` object for every function,
+method, lambda, const, and default value it can reach, plus the layouts of its
+structs and enums. Code runs on the stack machine in `zinc.vm`, which shares
+the interpreter's runtime, so values, operators, and output behave exactly as
+with `zinc run --interpret`. Top-level names are resolved while compiling;
+only local variables are looked up as the program runs.
+
+A `.znb` file is the program as JSON, tagged with the format version. It runs
+without the sources or a Rust toolchain, which also makes it the way to embed
+Zinc in a Python application:
+
+    from zinc.bytecode import read_bytecode
+    from zinc.vm import run_bytecode
+
+    status = run_bytecode(read_bytecode(Path("plugin.znb")))
+"""
+
+import json
+from collections.abc import Iterator
+from contextlib import contextmanager
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+
+from antlr4 import CommonTokenStream, ParserRuleContext
+from zinc.api import CheckedProgram
+from zinc.ast.types import BaseType
+from zinc.atlas import StructFieldInfo, StructMethodInfo
+from zinc.codegen import DEFAULT_OVERFLOW_MODE
+from zinc.decorators import decorators_from_ctx, has_marker_decorator
+from zinc.exceptions import ZincError
+from zinc.fixed_arrays import array_length
+from zinc.interpreter import BUILTINS, INTEGER_BOUNDS, contains_node, function_parameters, source_text, unsupported_feature
+from zinc.modules import (
+    TopLevelSymbol,
+    enum_variant_path_from_ctx,
+    extract_identifier_path,
+    format_string_literal,
+    qualified_name_path,
+    source_lexer,
+    struct_path_from_ctx,
+)
+from zinc.numeric_literals import parse_numeric_literal
+from zinc.operators import ASSIGNMENT_TO_BINARY_OPERATOR
+from zinc.string_literals import decode_string_literal, is_interpolated_string_literal
+from zinc.symbols import included_file
+from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.parser.zincVisitor import zincVisitor
+from zinc.shadowing import is_let_binding
+
+BYTECODE_FORMAT = "zinc-bytecode"
+BYTECODE_VERSION = 4
+BYTECODE_SUFFIX = ".znb"
+
+# Every instruction and what it does with the stack; jump targets are instruction indexes.
+OPCODES = {
+    "LOAD_CONST": "push a constant",
+    "LOAD_NAME": "push a variable, or the top-level value the name was resolved to",
+    "LOAD_GLOBAL": "push a top-level function, const, type, or module",
+    "LOAD_SELF": "push the receiver of the running method",
+    "STORE_ASSIGN": "pop into a variable the way `name = value` does",
+    "STORE_DEFINE": "pop into a new variable of the innermost scope",
+    "STORE_LET": "pop into a new variable that shadows any of the same name, as `let` does",
+    "STORE_OUTER": "pop into the variable wherever it is visible",
+    "ASSIGN_TUPLE": "pop a tuple and destructure it into several variables",
+    "POP": "discard the top value",
+    "POP_BELOW": "discard the value under the top one",
+    "DUP": "push the top value again",
+    "DUP2": "push the top two values again",
+    "COPY": "replace the top value with a copy that shares nothing with it",
+    "CONVERT": "give the top value the type an annotation names",
+    "PUSH_SCOPE": "enter a block",
+    "POP_SCOPE": "leave a block",
+    "JUMP": "continue at the target",
+    "JUMP_IF_FALSE": "pop a condition and jump when it is false",
+    "SETUP_LOOP": "enter a loop; break continues at its exit, continue at its start",
+    "POP_BLOCK": "leave a loop normally",
+    "BREAK": "leave the innermost loop",
+    "CONTINUE": "start the next iteration of the innermost loop",
+    "GET_ITER": "replace the top value with an iterator over it",
+    "FOR_ITER": "push the iterator's next item, or jump when it has none",
+    "SETUP_TRY": "enter a try block; an Err or None unwrapped inside jumps to its end",
+    "END_TRY": "leave a try block, wrapping its value in Ok or Some",
+    "UNWRAP_TRY": "unwrap the top value inside a try block",
+    "UNWRAP_UNLESS_WRAPPED": "unwrap the top value unless the variable it is assigned to holds a Result or Option",
+    "FAIL": "pop an error and end the try block or function with Err",
+    "RETURN": "pop the return value and leave the function",
+    "MATCH": "match the top value against a pattern, entering a scope with its bindings, or jump",
+    "NO_MATCH": "panic because no arm matched",
+    "BUILD_LIST": "pop items into an array",
+    "BUILD_REPEAT": "pop a value into a fixed-size array of that many copies",
+    "BUILD_TUPLE": "pop items into a tuple",
+    "BUILD_DICT": "pop keys and values into a dict",
+    "BUILD_SET": "pop items into a set",
+    "BUILD_RANGE": "pop a start and stop into a range",
+    "BUILD_ANON": "pop fields and spreads into an anonymous struct",
+    "FORMAT": "pop the values of an interpolated string and join them with its literal pieces",
+    "WRAP": "wrap the top value in Ok, Err, or Some",
+    "NEW_STRUCT": "pop fields and spreads into a struct, filling in the others",
+    "CHECK_FIELD_SPREAD": "skip a struct spread whose fields are all given again after it",
+    "NEW_VARIANT": "pop fields into an enum variant",
+    "MAKE_FUNCTION": "push a function, closing over the current scope unless it is top-level",
+    "DECORATE": "pop a decorator and a function and push the decorated function",
+    "MEMOIZE": "give the function on the stack a cache of its results, keyed by its arguments",
+    "LOAD_ATTR": "replace the top value with one of its members",
+    "STORE_ATTR": "pop a value and a struct and set the struct's field",
+    "INDEX": "pop an index and a container and push the element",
+    "INDEX_CHECKED": "pop an index and a fixed-size array and push Ok with the element, or Err when it is out of range",
+    "STORE_INDEX": "pop a value, an index, and a container and set the element",
+    "UNARY": "apply a unary operator",
+    "BINARY": "apply a binary operator",
+    "CUSTOM_OP": "apply a user-defined operator",
+    "CONTAINS": "pop a collection and an item and push whether it holds the item",
+    "SHORT_CIRCUIT": "decide `and` or `or` from the left operand alone when possible, or jump",
+    "LOGIC": "finish `and` or `or` with the right operand",
+    "PUSH_NOTHING": "push an empty receiver slot for a call",
+    "LOAD_METHOD": "replace the top value with a method and its receiver slot",
+    "CHECK_SPREAD": "skip an argument spread whose parameters are all named again after it",
+    "CALL": "pop arguments, a receiver slot, and a function and push the result",
+    "CALL_BUILTIN": "pop arguments and call a builtin function",
+}
+
+UNIT = ["unit"]
+
+
+class BytecodeError(ZincError):
+    """Raised when a program uses something the bytecode VM doesn't support."""
+
+    def __init__(self, feature: str):
+        super().__init__(f"bytecode doesn't support {feature} yet; build the program without --emit bytecode")
+
+
+@dataclass
+class Code:
+    """One function body, lambda, const value, or default value, compiled.
+
+    `module_id` is the module whose names the code uses; `source` is the module
+    whose file `positions` refer to, which differs for methods copied from a
+    composed struct.
+    """
+
+    name: str
+    module_id: str
+    source: str
+    parameters: list[list] = field(default_factory=list)  # [name, annotation, index of the default's code or None]
+    return_type: str | None = None
+    instructions: list[list] = field(default_factory=list)  # [opcode, argument]
+    positions: list[list[int]] = field(default_factory=list)  # [line, column] of each instruction
+
+
+@dataclass
+class FieldLayout:
+    """A field of a struct or an enum variant; a struct field left out gets its `default` code's value or `zero`."""
+
+    name: str
+    exact_type: str | None
+    default: int | None = None
+    zero: list | None = None
+
+
+@dataclass
+class MethodLayout:
+    """A method: its code, and the struct it builds when it instantiates the one it was declared in."""
+
+    name: str
+    code: int
+    module_id: str
+    is_static: bool
+    operator: str | None = None
+    constructs: str | None = None
+
+
+@dataclass
+class TypeLayout:
+    """A struct's fields or an enum's variants, its methods, and the static fields a struct's instances share."""
+
+    kind: str
+    qualified_name: str
+    name: str
+    fields: list[FieldLayout] = field(default_factory=list)
+    variants: dict[str, list[FieldLayout]] = field(default_factory=dict)
+    methods: list[MethodLayout] = field(default_factory=list)
+    statics: list[FieldLayout] = field(default_factory=list)
+
+
+@dataclass
+class Program:
+    """A compiled program; `functions` and `consts` map qualified names to the code that computes their values."""
+
+    default_int: str
+    overflow: str
+    main: str
+    codes: list[Code] = field(default_factory=list)
+    functions: dict[str, int] = field(default_factory=dict)
+    consts: dict[str, int] = field(default_factory=dict)
+    types: dict[str, TypeLayout] = field(default_factory=dict)
+
+
+def write_bytecode(program: Program, path: Path) -> Path:
+    """Write `program` to a `.znb` file."""
+    document = {"format": BYTECODE_FORMAT, "version": BYTECODE_VERSION, **asdict(program)}
+    path.write_text(json.dumps(document, separators=(",", ":"), ensure_ascii=False))
+    return path
+
+
+def read_bytecode(path: Path) -> Program:
+    """Load a program written by `write_bytecode`, checking that this version of zinc can run it."""
+    try:
+        document = json.loads(path.read_text())
+    except (UnicodeDecodeError, json.JSONDecodeError):
+        document = None
+    if not isinstance(document, dict) or document.get("format") != BYTECODE_FORMAT:
+        raise ZincError(f"{path} is not Zinc bytecode")
+    if document.get("version") != BYTECODE_VERSION:
+        raise ZincError(
+            f"{path} is bytecode version {document.get('version')}, but this zinc runs version {BYTECODE_VERSION}; "
+            "rebuild it with `zinc build --emit bytecode`"
+        )
+    codes = [Code(**code) for code in document["codes"]]
+    unknown = {instruction[0] for code in codes for instruction in code.instructions} - OPCODES.keys()
+    if unknown:
+        raise ZincError(f"{path} uses unknown instructions: {', '.join(sorted(unknown))}")
+    types = {
+        qualified_name: TypeLayout(
+            layout["kind"],
+            layout["qualified_name"],
+            layout["name"],
+            [FieldLayout(**info) for info in layout["fields"]],
+            {variant: [FieldLayout(**info) for info in fields] for variant, fields in layout["variants"].items()},
+            [MethodLayout(**method) for method in layout["methods"]],
+            [FieldLayout(**info) for info in layout["statics"]],
+        )
+        for qualified_name, layout in document["types"].items()
+    }
+    return Program(
+        document["default_int"], document["overflow"], document["main"], codes, document["functions"], document["consts"], types
+    )
+
+
+def compile_bytecode(checked: CheckedProgram, *, overflow: str = DEFAULT_OVERFLOW_MODE) -> Program:
+    """Compile a checked program to bytecode; integers overflow per `overflow` when it runs."""
+    return BytecodeCompiler(checked, overflow=overflow).compile()
+
+
+class BytecodeCompiler(zincVisitor):
+    """Compiles the code a checked program can reach, starting from `main()`.
+
+    Statements leave the stack as they found it; expressions push one value.
+    """
+
+    def __init__(self, checked: CheckedProgram, *, overflow: str = DEFAULT_OVERFLOW_MODE):
+        """Prepare to compile `checked`, rejecting it if it uses what only compiled programs support."""
+        feature = unsupported_feature(checked)
+        if feature is not None:
+            raise BytecodeError(feature)
+        self.graph = checked.graph
+        self.atlas = checked.atlas
+        self.symbols = checked.symbols
+        self.program = Program(self.graph.default_int, overflow, self.atlas.main.qualified_name)
+        self._pending: list[TopLevelSymbol] = []
+        self._queued: set[str] = set()
+        self._code: Code | None = None
+        self._module_id = ""
+        self._try_depth = 0
+        self._position: ParserRuleContext | None = None
+        self._files = {str(module.path): module.module_id for module in self.graph.modules.values()}
+
+    def compile(self) -> Program:
+        """Compile `main()` and everything it uses."""
+        self._reference(self.graph.top_level_symbols[self.atlas.main.qualified_name])
+        while self._pending:
+            symbol = self._pending.pop()
+            if symbol.kind == "function":
+                self.program.functions[symbol.qualified_name] = self._function_value(symbol)
+            elif symbol.kind == "const":
+                self.program.consts[symbol.qualified_name] = self._value_code(symbol.name, symbol.ctx.expression(), symbol.module_id)
+            else:
+                self.program.types[symbol.qualified_name] = self._type_layout(symbol)
+        return self.program
+
+    # Code objects
+
+    @contextmanager
+    def _compiling(self, name: str, module_id: str, ctx: ParserRuleContext) -> Iterator[int]:
+        """Emit into a new code object, yielding its index."""
+        saved = (self._code, self._module_id, self._try_depth)
+        self._code = Code(name, module_id, self._module_of(ctx, module_id))
+        self._module_id = module_id
+        self._try_depth = 0
+        self.program.codes.append(self._code)
+        try:
+            yield len(self.program.codes) - 1
+        finally:
+            self._code, self._module_id, self._try_depth = saved
+
+    def _module_of(self, ctx: ParserRuleContext, default: str) -> str:
+        return self._files.get(ctx.start.getInputStream().name, default)
+
+    def _emit(self, opcode: str, argument: object, ctx: ParserRuleContext) -> int:
+        position = self._position or ctx
+        self._code.instructions.append([opcode, argument])
+        self._code.positions.append([position.start.line, position.start.column])
+        return len(self._code.instructions) - 1
+
+    def _here(self) -> int:
+        return len(self._code.instructions)
+
+    def _patch(self, index: int, target: int) -> None:
+        """Point the jump at `index` to `target`; a jump with several operands takes its target last."""
+        instruction = self._code.instructions[index]
+        if isinstance(instruction[1], list):
+            instruction[1][-1] = target
+        else:
+            instruction[1] = target
+
+    def _function_code(self, name: str, ctx: ParserRuleContext, module_id: str) -> int:
+        """Compile a function, method, or lambda; its body runs in the scope that holds its parameters."""
+        parameters = [
+            [parameter, annotation, self._value_code(f"{name}.{parameter}", default, module_id) if default is not None else None]
+            for parameter, annotation, default in function_parameters(ctx)
+        ]
+        with self._compiling(name, module_id, ctx) as index:
+            self._code.parameters = parameters
+            if isinstance(ctx, ZincParser.FunctionDeclarationContext) and ctx.type_() is not None:
+                self._code.return_type = ctx.type_().getText()
+            if isinstance(ctx, ZincParser.LambdaExpressionContext) and ctx.expression() is not None:
+                self.visit(ctx.expression())
+            else:
+                self._statements(ctx.block())
+                self._emit("LOAD_CONST", UNIT, ctx)
+            self._emit("RETURN", None, ctx)
+        return index
+
+    def _value_code(self, name: str, expression: ParserRuleContext, module_id: str) -> int:
+        """Compile an expression evaluated in a fresh frame of its module, like a const or a default value."""
+        with self._compiling(name, module_id, expression) as index:
+            self.visit(expression)
+            self._emit("RETURN", None, expression)
+        return index
+
+    def _function_value(self, symbol: TopLevelSymbol) -> int:
+        """Compile code that makes a top-level function's value, applying its decorators."""
+        body = self._function_code(symbol.name, symbol.ctx, symbol.module_id)
+        with self._compiling(symbol.name, symbol.module_id, symbol.ctx) as index:
+            self._emit("MAKE_FUNCTION", [body, symbol.name, False], symbol.ctx)
+            if has_marker_decorator(symbol.ctx, "memoize"):
+                self._emit("MEMOIZE", None, symbol.ctx)
+            self._decorate(symbol.ctx)
+            self._emit("RETURN", None, symbol.ctx)
+        return index
+
+    def _decorate(self, ctx: ParserRuleContext) -> None:
+        """Apply the decorators of the function on the stack, innermost first."""
+        for decorator in reversed(decorators_from_ctx(ctx)):
+            path = list(decorator.path)
+            self._emit("LOAD_GLOBAL", [self._path_reference(path), ".".join(path)], decorator.ctx)
+            if decorator.has_call:
+                self._emit("PUSH_NOTHING", None, decorator.ctx)
+                kinds = self._arguments(decorator.argument_list_ctx, skippable=False)
+                self._emit("CALL", kinds, decorator.ctx)
+            self._emit("DECORATE", None, decorator.ctx)
+
+    # Types
+
+    def _type_layout(self, symbol: TopLevelSymbol) -> TypeLayout:
+        if symbol.kind == "enum":
+            enum = self.atlas.enums.get(symbol.qualified_name)
+            if enum is None:
+                raise BytecodeError(f"the enum '{symbol.name}'")
+            variants = {variant.name: [FieldLayout(info.name, info.exact_type) for info in variant.fields] for variant in enum.variants}
+            return TypeLayout("enum", enum.qualified_name, enum.name, variants=variants, methods=self._methods(enum))
+        struct = self.atlas.structs.get(symbol.qualified_name)
+        if struct is None:
+            raise BytecodeError(f"the struct '{symbol.name}'")
+        fields = [
+            FieldLayout(info.name, info.exact_type, self._field_default(struct.qualified_name, info), _zero(info, self.graph.default_int))
+            for info in struct.fields
+        ]
+        statics = [
+            FieldLayout(info.name, info.exact_type, self._field_default(struct.qualified_name, info)) for info in struct.static_fields
+        ]
+        return TypeLayout("struct", struct.qualified_name, struct.name, fields=fields, methods=self._methods(struct), statics=statics)
+
+    def _methods(self, owner) -> list[MethodLayout]:
+        return [self._method(owner, method) for method in owner.methods]
+
+    def _method(self, owner, method: StructMethodInfo) -> MethodLayout:
+        module_id = method.source_module_id or owner.module_id
+        return MethodLayout(
+            method.name,
+            self._function_code(method.name, method.body_ctx.parentCtx, module_id),
+            module_id,
+            method.is_static,
+            method.operator_symbol,
+            method.constructor_owner_qualified_name,
+        )
+
+    def _field_default(self, qualified_name: str, info: StructFieldInfo) -> int | None:
+        if info.default_value is None:
+            return None
+        symbol = self.graph.top_level_symbols[info.source_struct_qualified_name or qualified_name]
+        for member in symbol.ctx.structBody().structMember():
+            field_ctx = member.structField()
+            if field_ctx is not None and field_ctx.IDENTIFIER().getText() == info.name and field_ctx.expression() is not None:
+                return self._value_code(f"{symbol.name}.{info.name}", field_ctx.expression(), symbol.module_id)
+        return None
+
+    # Names
+
+    def _reference(self, symbol: TopLevelSymbol) -> list:
+        """A reference to a top-level symbol, which gets compiled too."""
+        if symbol.qualified_name not in self._queued:
+            self._queued.add(symbol.qualified_name)
+            self._pending.append(symbol)
+        return [symbol.kind, symbol.qualified_name]
+
+    def _path_reference(self, path: list[str]) -> list | None:
+        """What a top-level path names in the current module: a module alias, a declaration, or nothing."""
+        if len(path) == 1:
+            alias = self.graph.resolve_alias(self._module_id, path[0])
+            if alias is not None:
+                return ["module", alias]
+        symbol = self.graph.resolve_top_level_path(self._module_id, path)
+        return self._reference(symbol) if symbol is not None else None
+
+    def _alias_member(self, ctx: ZincParser.MemberAccessExprContext) -> list | None:
+        """The declaration `alias.name` names when `alias` is an imported module."""
+        path = extract_identifier_path(ctx)
+        if path is None or len(path) < 2 or self.graph.resolve_alias(self._module_id, path[0]) is None:
+            return None
+        symbol = self.graph.resolve_top_level_path(self._module_id, path)
+        return self._reference(symbol) if symbol is not None else None
+
+    # Blocks
+
+    def _block(self, ctx: ZincParser.BlockContext, *, as_value: bool = False) -> None:
+        self._emit("PUSH_SCOPE", None, ctx)
+        self._statements(ctx, as_value=as_value)
+        self._emit("POP_SCOPE", None, ctx)
+
+    def _statements(self, ctx: ParserRuleContext, *, as_value: bool = False) -> None:
+        """Compile the statements of a block; when it is used as a value, the last one's value is pushed."""
+        statements = ctx.statement()
+        for statement in statements[:-1]:
+            self.visit(statement)
+        if not statements:
+            if as_value:
+                self._emit("LOAD_CONST", UNIT, ctx)
+            return
+        last = statements[-1]
+        if not as_value:
+            self.visit(last)
+            return
+        self._tail_value(last)
+        self._unwrap(last)
+
+    def _tail_value(self, ctx: ZincParser.StatementContext) -> None:
+        if ctx.expressionStatement() is not None:
+            self.visit(ctx.expressionStatement().expression())
+        elif ctx.block() is not None:
+            self._block(ctx.block(), as_value=True)
+        elif ctx.ifStatement() is not None:
+            self._if(ctx.ifStatement(), as_value=True)
+        elif ctx.matchStatement() is not None:
+            self._match(ctx.matchStatement(), as_value=True)
+        else:
+            self.visit(ctx)
+            self._emit("LOAD_CONST", UNIT, ctx)
+
+    def _value(self, ctx: ParserRuleContext) -> None:
+        """Compile an expression whose Result or Option is unwrapped inside a try block."""
+        self.visit(ctx)
+        self._unwrap(ctx)
+
+    def _unwrap(self, ctx: ParserRuleContext) -> None:
+        if self._try_depth:
+            self._emit("UNWRAP_TRY", None, ctx)
+
+    def visitChildren(self, node):
+        """Reject syntax the compiler has no rule for, rather than emitting nothing for it."""
+        raise BytecodeError(f"'{source_text(node)}'")
+
+    # Statements
+
+    def visitStatement(self, ctx: ZincParser.StatementContext):
+        """Compile one statement; top-level declarations are compiled when they are used."""
+        child = ctx.getChild(0)
+        declarations = (
+            ZincParser.ImportStatementContext,
+            ZincParser.ConstDeclarationContext,
+            ZincParser.StructDeclarationContext,
+            ZincParser.EnumDeclarationContext,
+        )
+        if isinstance(child, declarations):
+            return
+        if isinstance(child, ZincParser.FunctionDeclarationContext):
+            name = child.functionName().getText()
+            self._emit("MAKE_FUNCTION", [self._function_code(name, child, self._module_id), name, True], child)
+            self._decorate(child)
+            self._emit("STORE_DEFINE", name, child)
+        elif isinstance(child, ZincParser.BlockContext):
+            self._block(child)
+        else:
+            self.visit(child)
+
+    def visitExpressionStatement(self, ctx: ZincParser.ExpressionStatementContext):
+        """Evaluate an expression for its effects."""
+        self.visit(ctx.expression())
+        self._emit("POP", None, ctx)
+
+    def visitBlock(self, ctx: ZincParser.BlockContext):
+        """Compile a nested block."""
+        self._block(ctx)
+
+    def visitVariableAssignment(self, ctx: ZincParser.VariableAssignmentContext):
+        """Compile `target = value` or a compound assignment like `target += value`."""
+        operator = ctx.assignmentOperator().getText()
+        target = ctx.assignmentTarget()
+        if operator != "=":
+            self._compound_assign(target, ASSIGNMENT_TO_BINARY_OPERATOR[operator], ctx.expression(), ctx)
+        elif target.IDENTIFIER() is not None:
+            name = target.IDENTIFIER().getText()
+            rebinds = is_let_binding(target)
+            self.visit(ctx.expression())
+            if self._try_depth:
+                # A `let` starts a new variable, so there is no earlier Result or Option to keep.
+                self._emit("UNWRAP_TRY" if rebinds else "UNWRAP_UNLESS_WRAPPED", None if rebinds else name, ctx.expression())
+            self._emit("COPY", None, ctx)
+            self._emit("STORE_LET" if rebinds else "STORE_ASSIGN", name, ctx)
+        elif target.tupleAssignmentTarget() is not None:
+            self._value(ctx.expression())
+            self._emit("ASSIGN_TUPLE", [_tuple_names(target.tupleAssignmentTarget()), False, None], ctx)
+        elif target.memberAccess() is not None:
+            access = target.memberAccess()
+            self.visit(access.expression())
+            self._value(ctx.expression())
+            self._emit("COPY", None, ctx)
+            self._emit("STORE_ATTR", access.IDENTIFIER().getText(), access)
+        else:
+            access = target.indexAccess()
+            self.visit(access.expression(0))
+            self._value(access.expression(1))
+            self._value(ctx.expression())
+            self._emit("COPY", None, ctx)
+            self._emit("STORE_INDEX", None, access)
+
+    def _compound_assign(self, target: ZincParser.AssignmentTargetContext, operator: str, expr_ctx, ctx) -> None:
+        if target.IDENTIFIER() is not None:
+            name = target.IDENTIFIER().getText()
+            self._emit("LOAD_NAME", [name, self._path_reference([name])], target)
+            self._value(expr_ctx)
+            self._emit("BINARY", operator, ctx)
+            self._emit("STORE_OUTER", name, ctx)
+        elif target.memberAccess() is not None:
+            access = target.memberAccess()
+            self.visit(access.expression())
+            self._emit("DUP", None, access)
+            self._emit("LOAD_ATTR", access.IDENTIFIER().getText(), access)
+            self._value(expr_ctx)
+            self._emit("BINARY", operator, ctx)
+            self._emit("STORE_ATTR", access.IDENTIFIER().getText(), access)
+        elif target.indexAccess() is not None:
+            access = target.indexAccess()
+            self.visit(access.expression(0))
+            self._value(access.expression(1))
+            self._emit("DUP2", None, access)
+            self._emit("INDEX", None, access)
+            self._value(expr_ctx)
+            self._emit("BINARY", operator, ctx)
+            self._emit("STORE_INDEX", None, access)
+        else:
+            raise BytecodeError("compound assignment to several variables")
+
+    def visitOutAssignment(self, ctx: ZincParser.OutAssignmentContext):
+        """Compile `out name = value`, which assigns a variable captured from the enclosing function."""
+        name = ctx.IDENTIFIER(1).getText()
+        operator = ctx.assignmentOperator().getText()
+        if operator == "=":
+            self._value(ctx.expression())
+            self._emit("COPY", None, ctx)
+        else:
+            self._emit("LOAD_NAME", [name, self._path_reference([name])], ctx)
+            self._value(ctx.expression())
+            self._emit("BINARY", ASSIGNMENT_TO_BINARY_OPERATOR[operator], ctx)
+        self._emit("STORE_OUTER", name, ctx)
+
+    def visitTypedVariableAssignment(self, ctx: ZincParser.TypedVariableAssignmentContext):
+        """Compile `name: Type = value`, converting the value to the annotated type."""
+        annotation = ctx.type_().getText()
+        if annotation.startswith(("Result<", "Option<")):
+            self.visit(ctx.expression())
+        else:
+            self._value(ctx.expression())
+        self._emit("COPY", None, ctx)
+        self._emit("CONVERT", annotation, ctx)
+        target = ctx.typedAssignmentTarget()
+        if target.IDENTIFIER() is not None:
+            self._emit("STORE_DEFINE", target.IDENTIFIER().getText(), ctx)
+        else:
+            self._emit("ASSIGN_TUPLE", [_tuple_names(target.tupleAssignmentTarget()), True, annotation], ctx)
+
+    def visitIfStatement(self, ctx: ZincParser.IfStatementContext):
+        """Compile an if statement."""
+        self._if(ctx)
+
+    def _if(self, ctx: ZincParser.IfStatementContext, *, as_value: bool = False) -> None:
+        conditions = ctx.expression()
+        blocks = ctx.block()
+        exits = []
+        for index, condition in enumerate(conditions):
+            self._value(condition)
+            skip = self._emit("JUMP_IF_FALSE", None, condition)
+            self._block(blocks[index], as_value=as_value)
+            exits.append(self._emit("JUMP", None, ctx))
+            self._patch(skip, self._here())
+        if len(blocks) > len(conditions):
+            self._block(blocks[-1], as_value=as_value)
+        elif as_value:
+            self._emit("LOAD_CONST", UNIT, ctx)
+        for jump in exits:
+            self._patch(jump, self._here())
+
+    def visitWhileStatement(self, ctx: ZincParser.WhileStatementContext):
+        """Compile a while loop."""
+        setup = self._emit("SETUP_LOOP", [None, None], ctx)
+        start = self._here()
+        self._value(ctx.expression())
+        done = self._emit("JUMP_IF_FALSE", None, ctx.expression())
+        self._block(ctx.block())
+        self._emit("JUMP", start, ctx)
+        self._patch(done, self._here())
+        self._emit("POP_BLOCK", None, ctx)
+        self._code.instructions[setup][1] = [self._here(), start]
+
+    def visitLoopStatement(self, ctx: ZincParser.LoopStatementContext):
+        """Compile a loop that runs until it breaks."""
+        setup = self._emit("SETUP_LOOP", [None, None], ctx)
+        start = self._here()
+        self._block(ctx.block())
+        self._emit("JUMP", start, ctx)
+        self._code.instructions[setup][1] = [self._here(), start]
+
+    def visitForStatement(self, ctx: ZincParser.ForStatementContext):
+        """Compile a for loop; every iteration binds copies of the elements in a fresh scope."""
+        binding = ctx.forBinding()
+        self._value(ctx.expression())
+        self._emit("GET_ITER", None, ctx.expression())
+        setup = self._emit("SETUP_LOOP", [None, None], ctx)
+        start = self._here()
+        done = self._emit("FOR_ITER", None, ctx)
+        self._emit("PUSH_SCOPE", None, ctx)
+        if binding.IDENTIFIER() is not None:
+            self._emit("COPY", None, binding)
+            self._emit("STORE_DEFINE", binding.IDENTIFIER().getText(), binding)
+        else:
+            self._emit("ASSIGN_TUPLE", [_tuple_names(binding.tupleAssignmentTarget()), True, None], binding)
+        self._statements(ctx.block())
+        self._emit("POP_SCOPE", None, ctx)
+        self._emit("JUMP", start, ctx)
+        self._patch(done, self._here())
+        self._emit("POP_BLOCK", None, ctx)
+        self._code.instructions[setup][1] = [self._here(), start]
+        self._emit("POP", None, ctx)
+
+    def visitBreakStatement(self, ctx):
+        """Leave the innermost loop."""
+        self._emit("BREAK", None, ctx)
+
+    def visitContinueStatement(self, ctx):
+        """Start the next iteration of the innermost loop."""
+        self._emit("CONTINUE", None, ctx)
+
+    def visitReturnStatement(self, ctx: ZincParser.ReturnStatementContext):
+        """Return from the current function."""
+        if ctx.expression() is not None:
+            self.visit(ctx.expression())
+        else:
+            self._emit("LOAD_CONST", UNIT, ctx)
+        self._emit("RETURN", None, ctx)
+
+    def visitFailStatement(self, ctx: ZincParser.FailStatementContext):
+        """End the innermost try block, or the function, with `Err(error)`."""
+        self.visit(ctx.expression())
+        self._emit("FAIL", None, ctx)
+
+    def visitMatchStatement(self, ctx: ZincParser.MatchStatementContext):
+        """Compile a match statement."""
+        self._match(ctx)
+
+    def _match(self, ctx: ZincParser.MatchStatementContext, *, as_value: bool = False) -> None:
+        self.visit(ctx.expression())
+        exits = []
+        for arm in ctx.matchArm():
+            test = self._emit("MATCH", [self._pattern(arm.pattern()), None], arm)
+            if arm.block() is not None:
+                self._statements(arm.block(), as_value=as_value)
+            else:
+                self.visit(arm.expression())
+                if not as_value:
+                    self._emit("POP", None, arm)
+            self._emit("POP_SCOPE", None, arm)
+            exits.append(self._emit("JUMP", None, arm))
+            self._patch(test, self._here())
+        self._emit("NO_MATCH", None, ctx)
+        for jump in exits:
+            self._patch(jump, self._here())
+        self._emit("POP_BELOW" if as_value else "POP", None, ctx)
+
+    def _pattern(self, ctx: ZincParser.PatternContext) -> list:
+        """A pattern as data for the VM to match against."""
+        if ctx.getText() == "_":
+            return ["_"]
+        if ctx.literal() is not None:
+            return ["literal", self._constant(ctx.literal())]
+        if ctx.resultOptionPattern() is not None:
+            pattern = ctx.resultOptionPattern()
+            head = pattern.getChild(0).getText()
+            return ["none"] if head == "None" else ["wrap", head, self._pattern(pattern.pattern())]
+        if ctx.enumVariantPattern() is not None:
+            variant_ctx = ctx.enumVariantPattern()
+            path = variant_ctx.enumVariantPath()
+            resolved = self.graph.resolve_enum_variant_path(
+                self._module_id, [*qualified_name_path(path.qualifiedName()), path.IDENTIFIER().getText()]
+            )
+            if resolved is None:
+                raise BytecodeError(f"the pattern '{variant_ctx.getText()}'")
+            enum_symbol, variant = resolved
+            bindings = []
+            for field_pattern in variant_ctx.enumVariantFieldPattern():
+                names = [token.getText() for token in field_pattern.IDENTIFIER()]
+                bindings.append([names[0], names[-1]])
+            return ["variant", self._reference(enum_symbol)[1], variant, bindings]
+        if ctx.rangePattern() is not None:
+            low, high = (parse_numeric_literal(token.getText()).value for token in ctx.rangePattern().INTEGER())
+            return ["range", low, high, ctx.rangePattern().getChild(1).getText() == "..="]
+        if ctx.fieldPattern():
+            fields = []
+            for field_pattern in ctx.fieldPattern():
+                sub = field_pattern.pattern()
+                fields.append([field_pattern.IDENTIFIER().getText(), self._pattern(sub) if sub is not None else None])
+            return ["struct", ctx.IDENTIFIER().getText(), fields]
+        if ctx.pattern():
+            return ["tuple", [self._pattern(pattern) for pattern in ctx.pattern()]]
+        return ["bind", ctx.IDENTIFIER().getText()]
+
+    # Expressions
+
+    def visitParenExpr(self, ctx: ZincParser.ParenExprContext):
+        """Compile a parenthesized expression."""
+        self.visit(ctx.expression())
+
+    def visitPrimaryExpr(self, ctx: ZincParser.PrimaryExprContext):
+        """Compile a literal, name, or constructor."""
+        self.visit(ctx.primaryExpression())
+
+    def visitPrimaryExpression(self, ctx: ZincParser.PrimaryExpressionContext):
+        """Compile the one alternative of a primary expression."""
+        if ctx.IDENTIFIER() is not None:
+            name = ctx.IDENTIFIER().getText()
+            self._emit("LOAD_NAME", [name, self._path_reference([name])], ctx)
+        elif ctx.SELF() is not None:
+            self._emit("LOAD_SELF", None, ctx)
+        elif ctx.TYPE_KW() is not None:
+            raise BytecodeError("compile-time metadata")
+        else:
+            self.visit(ctx.getChild(0))
+
+    def visitLiteral(self, ctx: ZincParser.LiteralContext):
+        """Compile a number, string, or bool literal."""
+        text = ctx.getText()
+        if ctx.STRING() is not None and is_interpolated_string_literal(text):
+            self._interpolate(text, ctx)
+        else:
+            self._emit("LOAD_CONST", self._constant(ctx), ctx)
+
+    def _constant(self, ctx: ZincParser.LiteralContext) -> list:
+        text = ctx.getText()
+        if ctx.STRING() is not None:
+            if is_interpolated_string_literal(text):
+                raise BytecodeError(f"the pattern {text}")
+            return ["str", decode_string_literal(text)]
+        if ctx.booleanLiteral() is not None:
+            return ["bool", text == "true"]
+        if text == "nil":
+            return UNIT
+        literal = parse_numeric_literal(text)
+        return ["number", literal.value, literal.exact_type]
+
+    def visitUnitLiteral(self, ctx):
+        """Compile `()`."""
+        self._emit("LOAD_CONST", UNIT, ctx)
+
+    def _interpolate(self, text: str, ctx: ParserRuleContext, placeholders: Iterator[ParserRuleContext] | None = None) -> None:
+        """Compile an interpolated string; its pieces are parsed as expressions here and located at the literal.
+
+        With `placeholders`, as for print("...", values...), each empty `{}` compiles the next of those expressions.
+        """
+        quote, inner = text[0], text[1:-1]
+        pieces = []
+        position = 0
+        saved = self._position
+        self._position = saved or ctx
+        try:
+            while (start := inner.find("{", position)) != -1:
+                stop = inner.find("}", start)
+                if stop == -1:
+                    break
+                pieces.append(decode_string_literal(quote + inner[position:start] + quote))
+                expression = inner[start + 1 : stop]
+                self.visit(next(placeholders) if placeholders is not None and not expression else _interpolation(expression))
+                position = stop + 1
+        finally:
+            self._position = saved
+        pieces.append(decode_string_literal(quote + inner[position:] + quote))
+        self._emit("FORMAT", pieces, ctx)
+
+    def visitArrayLiteral(self, ctx: ZincParser.ArrayLiteralContext):
+        """Compile `[a, b, c]`, or `[value; n]`."""
+        self._items(ctx.expression())
+        length = array_length(ctx)
+        if length is not None:
+            self._emit("BUILD_REPEAT", length, ctx)
+            return
+        self._emit("BUILD_LIST", len(ctx.expression()), ctx)
+
+    def visitTupleLiteral(self, ctx: ZincParser.TupleLiteralContext):
+        """Compile `(a, b)`."""
+        self._items(ctx.expression())
+        self._emit("BUILD_TUPLE", len(ctx.expression()), ctx)
+
+    def _items(self, expressions: list) -> None:
+        for expr in expressions:
+            self._value(expr)
+            self._emit("COPY", None, expr)
+
+    def visitCollectionLiteral(self, ctx: ZincParser.CollectionLiteralContext):
+        """Compile a dict literal `{k: v}` or a set literal `{a, b}`."""
+        if ctx.dictEntry():
+            for entry in ctx.dictEntry():
+                self._value(entry.expression(0))
+                self._value(entry.expression(1))
+                self._emit("COPY", None, entry)
+            self._emit("BUILD_DICT", len(ctx.dictEntry()), ctx)
+        elif ctx.expression():
+            for expr in ctx.expression():
+                self._value(expr)
+            self._emit("BUILD_SET", len(ctx.expression()), ctx)
+        else:
+            self._emit("BUILD_DICT", 0, ctx)
+
+    def visitBuiltinResultOptionConstructor(self, ctx: ZincParser.BuiltinResultOptionConstructorContext):
+        """Compile `Ok(x)`, `Err(e)`, `Some(x)`, or `None`."""
+        head = ctx.getChild(0).getText()
+        if head == "None":
+            self._emit("LOAD_CONST", ["none"], ctx)
+            return
+        self._value(ctx.expression())
+        self._emit("COPY", None, ctx)
+        self._emit("WRAP", head, ctx)
+
+    def visitAnonymousStructLiteral(self, ctx: ZincParser.AnonymousStructLiteralContext):
+        """Compile `struct { name: value }`."""
+        self._emit("BUILD_ANON", self._field_entries(ctx.structFieldEntry()), ctx)
+
+    def _field_entries(self, entries: list, qualified_name: str | None = None) -> list:
+        """Push the values of field entries, returning each one's name, or None for a spread.
+
+        A struct spread is skipped when every field it could supply is given again after it.
+        """
+        names = []
+        for index, entry in enumerate(entries):
+            if entry.fieldSpread() is not None:
+                check = None
+                if qualified_name is not None:
+                    later = [following.fieldInit().IDENTIFIER().getText() for following in entries[index + 1 :] if following.fieldInit()]
+                    check = self._emit("CHECK_FIELD_SPREAD", [qualified_name, later, None], entry)
+                self._value(entry.fieldSpread().expression())
+                if check is not None:
+                    self._patch(check, self._here())
+                names.append(None)
+            else:
+                init = entry.fieldInit()
+                self._value(init.expression())
+                self._emit("COPY", None, init)
+                names.append(init.IDENTIFIER().getText())
+        return names
+
+    def visitStructInstantiation(self, ctx: ZincParser.StructInstantiationContext):
+        """Compile `Name { field: value, ..spread }`."""
+        symbol = self.graph.resolve_struct_path(self._module_id, struct_path_from_ctx(ctx))
+        if symbol is None:
+            raise BytecodeError(f"the struct '{ctx.qualifiedName().getText()}'")
+        qualified_name = self._reference(symbol)[1]
+        self._emit("NEW_STRUCT", [qualified_name, self._field_entries(ctx.structFieldEntry(), qualified_name), True], ctx)
+
+    def visitEnumVariantConstruction(self, ctx: ZincParser.EnumVariantConstructionContext):
+        """Compile `Enum.Variant { field: value }`."""
+        path = enum_variant_path_from_ctx(ctx)
+        names = []
+        for init in ctx.fieldInit():
+            self._value(init.expression())
+            self._emit("COPY", None, init)
+            names.append(init.IDENTIFIER().getText())
+        resolved = self.graph.resolve_enum_variant_path(self._module_id, path)
+        if resolved is not None:
+            enum_symbol, variant = resolved
+            self._emit("NEW_VARIANT", [self._reference(enum_symbol)[1], variant, names], ctx)
+            return
+        # `alias.Name { ... }` parses like a variant but names a struct of an imported module.
+        symbol = self.graph.resolve_struct_path(self._module_id, path)
+        if symbol is None:
+            raise BytecodeError(f"the enum variant '{ctx.enumVariantPath().getText()}'")
+        self._emit("NEW_STRUCT", [self._reference(symbol)[1], names, False], ctx)
+
+    def visitIfExpr(self, ctx: ZincParser.IfExprContext):
+        """Compile an if expression."""
+        self._if_expression(ctx.ifExpression())
+
+    def _if_expression(self, ctx: ZincParser.IfExpressionContext) -> None:
+        self._value(ctx.expression())
+        skip = self._emit("JUMP_IF_FALSE", None, ctx.expression())
+        self._block(ctx.block(0), as_value=True)
+        done = self._emit("JUMP", None, ctx)
+        self._patch(skip, self._here())
+        if ctx.ifExpression() is not None:
+            self._if_expression(ctx.ifExpression())
+        elif len(ctx.block()) > 1:
+            self._block(ctx.block(1), as_value=True)
+        else:
+            self._emit("LOAD_CONST", UNIT, ctx)
+        self._patch(done, self._here())
+
+    def visitBlockExpr(self, ctx: ZincParser.BlockExprContext):
+        """Compile a block expression, whose value is that of its last statement."""
+        block = ctx.blockExpression()
+        self._emit("PUSH_SCOPE", None, ctx)
+        self._statements(block, as_value=True)
+        self._emit("POP_SCOPE", None, ctx)
+
+    def visitTryExpr(self, ctx: ZincParser.TryExprContext):
+        """Compile `try { ... }`; when nothing inside unwraps, the checker's family decides between Ok and Some."""
+        block = ctx.tryExpression().block()
+        families = self.symbols.auto_unwrap_families_within(block.getSourceInterval())
+        if contains_node(block, ZincParser.FailStatementContext):
+            families.add(BaseType.RESULT)
+        family = families.pop().name if len(families) == 1 else None
+        setup = self._emit("SETUP_TRY", [family, None], ctx)
+        self._try_depth += 1
+        self._block(block, as_value=True)
+        self._try_depth -= 1
+        self._emit("END_TRY", None, ctx)
+        self._patch(setup, self._here())
+
+    def visitLambdaExpr(self, ctx: ZincParser.LambdaExprContext):
+        """Compile a closure over the current scope."""
+        code = self._function_code("<lambda>", ctx.lambdaExpression(), self._module_id)
+        self._emit("MAKE_FUNCTION", [code, "<lambda>", True], ctx)
+
+    def visitMemberAccessExpr(self, ctx: ZincParser.MemberAccessExprContext):
+        """Compile `value.name`: a field, a bound method, an enum variant, or a module's export."""
+        reference = self._alias_member(ctx)
+        if reference is not None:
+            self._emit("LOAD_GLOBAL", [reference, ctx.getText()], ctx)
+            return
+        self.visit(ctx.expression())
+        self._emit("LOAD_ATTR", ctx.IDENTIFIER().getText(), ctx)
+
+    def visitIndexAccessExpr(self, ctx: ZincParser.IndexAccessExprContext):
+        """Compile `value[index]`."""
+        self._value(ctx.expression(0))
+        self._value(ctx.expression(1))
+        self._emit("INDEX_CHECKED" if self.symbols.is_checked_index(ctx.getSourceInterval()) else "INDEX", None, ctx)
+
+    def visitUnaryExpr(self, ctx: ZincParser.UnaryExprContext):
+        """Compile `-x`, `!x`, `not x`, or `~x`."""
+        self._value(ctx.expression())
+        self._emit("UNARY", ctx.getChild(0).getText(), ctx)
+
+    def _binary_expr(self, ctx) -> None:
+        self._value(ctx.expression(0))
+        self._value(ctx.expression(1))
+        self._emit("BINARY", ctx.getChild(1).getText(), ctx)
+
+    visitPowerExpr = _binary_expr
+    visitMultiplicativeExpr = _binary_expr
+    visitAdditiveExpr = _binary_expr
+    visitShiftExpr = _binary_expr
+    visitBitwiseAndExpr = _binary_expr
+    visitBitwiseXorExpr = _binary_expr
+    visitBitwiseOrExpr = _binary_expr
+    visitRelationalExpr = _binary_expr
+    visitEqualityExpr = _binary_expr
+
+    def visitCustomOperatorExpr(self, ctx: ZincParser.CustomOperatorExprContext):
+        """Compile a user-defined operator such as `a <+> b`."""
+        self._value(ctx.expression(0))
+        self._value(ctx.expression(1))
+        self._emit("CUSTOM_OP", ctx.getChild(1).getText(), ctx)
+
+    def visitRangeExpr(self, ctx: ZincParser.RangeExprContext):
+        """Compile `a..b` or `a..=b`."""
+        self._value(ctx.expression(0))
+        self._value(ctx.expression(1))
+        self._emit("BUILD_RANGE", ctx.getChild(1).getText() == "..=", ctx)
+
+    def visitMembershipExpr(self, ctx: ZincParser.MembershipExprContext):
+        """Compile `item in collection`."""
+        self._value(ctx.expression(0))
+        self._value(ctx.expression(1))
+        self._emit("CONTAINS", None, ctx)
+
+    def _logical(self, ctx, operator: str) -> None:
+        self._value(ctx.expression(0))
+        decided = self._emit("SHORT_CIRCUIT", [operator, None], ctx)
+        self._value(ctx.expression(1))
+        self._emit("LOGIC", operator, ctx)
+        self._patch(decided, self._here())
+
+    def visitLogicalAndExpr(self, ctx: ZincParser.LogicalAndExprContext):
+        """Compile `a and b`, skipping `b` when `a` is false."""
+        self._logical(ctx, "&&")
+
+    def visitLogicalOrExpr(self, ctx: ZincParser.LogicalOrExprContext):
+        """Compile `a or b`, skipping `b` when `a` is true."""
+        self._logical(ctx, "||")
+
+    # Calls
+
+    def visitFunctionCallExpr(self, ctx: ZincParser.FunctionCallExprContext):
+        """Compile a call of a function, method, builtin, or callable value."""
+        callee_ctx = ctx.expression()
+        if isinstance(callee_ctx, ZincParser.MemberAccessExprContext) and self._alias_member(callee_ctx) is None:
+            self.visit(callee_ctx.expression())
+            name = callee_ctx.IDENTIFIER().getText()
+            function = self.graph.resolve_function_path(self._module_id, [name])
+            self._emit("LOAD_METHOD", [name, self._reference(function) if function is not None else None], callee_ctx)
+            self._emit("CALL", self._arguments(ctx.argumentList()), ctx)
+            return
+        path = extract_identifier_path(callee_ctx)
+        if path is not None and len(path) == 1 and path[0] in BUILTINS and self.graph.resolve_top_level_path(self._module_id, path) is None:
+            self._builtin(path[0], ctx)
+            return
+        self.visit(callee_ctx)
+        self._emit("PUSH_NOTHING", None, ctx)
+        self._emit("CALL", self._arguments(ctx.argumentList()), ctx)
+
+    def _builtin(self, name: str, ctx: ZincParser.FunctionCallExprContext) -> None:
+        arguments = ctx.argumentList().argument() if ctx.argumentList() is not None else []
+        if name == "include_str":
+            # Embedded while compiling, like the compiled program's include_str!.
+            module_file = self.graph.path_for_module(self._module_id)
+            self._emit("LOAD_CONST", ["str", included_file(module_file, arguments[0].expression()).read_text()], ctx)
+            return
+        location = f"{self._module_of(ctx, self._module_id)}.zn:{ctx.start.line}"
+        if name == "print" and len(arguments) > 1:
+            # The values fill the format string's placeholders, so print() gets one finished line.
+            literal = format_string_literal(arguments[0].expression())
+            self._interpolate(literal.getText(), literal, (argument.expression() for argument in arguments[1:]))
+            self._emit("CALL_BUILTIN", [name, [None], location, [source_text(arguments[0])]], ctx)
+            return
+        kinds = self._arguments(ctx.argumentList(), skippable=False)
+        self._emit("CALL_BUILTIN", [name, kinds, location, [source_text(argument) for argument in arguments]], ctx)
+
+    def _arguments(self, ctx: ZincParser.ArgumentListContext | None, *, skippable: bool = True) -> list:
+        """Push call arguments in source order, returning each one's kind: None, a parameter name, or `..` for a spread.
+
+        The function and its receiver slot are already on the stack. With
+        `skippable`, a spread whose parameters are all named again after it is
+        never evaluated.
+        """
+        arguments = ctx.argument() if ctx is not None else []
+        kinds: list[str | None] = []
+        positional = 0
+        for index, argument in enumerate(arguments):
+            if argument.IDENTIFIER() is not None:
+                self.visit(argument.expression())
+                kinds.append(argument.IDENTIFIER().getText())
+            elif argument.getChild(0).getText() == "..":
+                check = None
+                if skippable:
+                    later = [following.IDENTIFIER().getText() for following in arguments[index + 1 :] if following.IDENTIFIER() is not None]
+                    check = self._emit("CHECK_SPREAD", [len(kinds) + 2, positional, later, None], argument)
+                self._value(argument.expression())
+                if check is not None:
+                    self._patch(check, self._here())
+                kinds.append("..")
+            else:
+                self.visit(argument.expression())
+                kinds.append(None)
+                positional += 1
+        return kinds
+
+
+_interpolations: dict[str, ParserRuleContext] = {}
+
+
+def _interpolation(text: str) -> ParserRuleContext:
+    if text not in _interpolations:
+        parser = ZincParser(CommonTokenStream(source_lexer(text)))
+        parser.removeErrorListeners()
+        _interpolations[text] = parser.expression()
+    return _interpolations[text]
+
+
+def _tuple_names(ctx: ZincParser.TupleAssignmentTargetContext) -> list[str]:
+    return [token.getText() for token in ctx.IDENTIFIER()]
+
+
+def _zero(info: StructFieldInfo, default_int: str) -> list | None:
+    """The constant for a field left out of an instantiation: Rust's `Default::default()` for its type."""
+    if info.array_info is not None:
+        return ["list"]
+    if info.dict_info is not None:
+        return ["dict"]
+    if info.set_info is not None:
+        return ["set"]
+    if info.option_info is not None:
+        return ["none"]
+    rust_type = info.rust_type()
+    zeros = {"String": ["str", ""], "bool": ["bool", False], "f64": ["number", 0.0, "f64"], "f32": ["number", 0.0, "f32"]}
+    if rust_type in zeros:
+        return zeros[rust_type]
+    if rust_type in INTEGER_BOUNDS:
+        return ["number", 0, rust_type]
+    return None
@@ -0,0 +1,96 @@
+"""Cache of compiled programs for `zinc build` and `zinc run`.
+
+Type checking is whole-program, so a program is cached as a unit: the entry
+file and options name the entry, and the entry records a content hash of every
+file the compile read (each module, each package manifest, and each compiler
+plugin file). A rebuild whose files all hash the same reuses the stored
+program and skips the front end and code generation. Entries also carry a
+fingerprint of the compiler itself, so upgrading Zinc never serves output from
+an older version.
+"""
+
+import functools
+import hashlib
+import pickle
+from collections.abc import Iterable
+from dataclasses import dataclass
+from pathlib import Path
+
+from zinc.build import BUILD_DIR_NAME
+from zinc.codegen import RustProgram
+from zinc.diagnostics import Diagnostic
+from zinc.modules import ModuleGraph, find_package_root, package_manifest
+
+CACHE_DIR_NAME = "cache"
+
+
+@dataclass
+class CachedCompile:
+    """A compiled program and the warnings its compile reported."""
+
+    inputs: dict[str, str]
+    program: RustProgram
+    warnings: list[Diagnostic]
+
+
+@functools.cache
+def compiler_fingerprint() -> str:
+    """Hash of the compiler's own sources."""
+    digest = hashlib.sha256()
+    package_dir = Path(__file__).resolve().parent
+    for path in sorted(package_dir.rglob("*.py")):
+        digest.update(path.relative_to(package_dir).as_posix().encode())
+        digest.update(path.read_bytes())
+    return digest.hexdigest()
+
+
+def default_cache_dir(entry: Path) -> Path:
+    """The cache directory shared by every entry file of a package."""
+    return find_package_root(entry.resolve()) / BUILD_DIR_NAME / CACHE_DIR_NAME
+
+
+def _file_hash(path: Path) -> str | None:
+    try:
+        return hashlib.sha256(path.read_bytes()).hexdigest()
+    except OSError:
+        return None
+
+
+def compile_inputs(graph: ModuleGraph, plugin_files: Iterable[Path] = ()) -> dict[str, str]:
+    """Content hashes of every module, package manifest, and compiler plugin file a compile read, keyed by path."""
+    paths = {module.path for module in graph.modules.values()}
+    paths.update(manifest for manifest in (package_manifest(find_package_root(path)) for path in list(paths)) if manifest)
+    paths.update(plugin_files)
+    return {str(path): _file_hash(path) for path in sorted(paths)}
+
+
+class CompileCache:
+    """Compiled programs stored under one directory, one file per entry and option set."""
+
+    def __init__(self, directory: Path):
+        self.directory = directory
+
+    def _entry_file(self, entry: Path, options: tuple) -> Path:
+        key = hashlib.sha256(repr((compiler_fingerprint(), str(entry.resolve()), options)).encode()).hexdigest()
+        return self.directory / f"{key}.pickle"
+
+    def load(self, entry: Path, options: tuple) -> CachedCompile | None:
+        """The stored compile of `entry`, or None when there is none or any file it read has changed."""
+        try:
+            cached = pickle.loads(self._entry_file(entry, options).read_bytes())
+        except (OSError, pickle.UnpicklingError, EOFError, AttributeError, TypeError):
+            return None
+        if not isinstance(cached, CachedCompile):
+            return None
+        if any(_file_hash(Path(path)) != digest for path, digest in cached.inputs.items()):
+            return None
+        return cached
+
+    def store(self, entry: Path, options: tuple, cached: CachedCompile) -> None:
+        """Save a compile, replacing any earlier one of the same entry and options."""
+        self.directory.mkdir(parents=True, exist_ok=True)
+        target = self._entry_file(entry, options)
+        partial = target.with_suffix(".tmp")
+        partial.write_bytes(pickle.dumps(cached))
+        # Readers never see a half-written entry.
+        partial.replace(target)
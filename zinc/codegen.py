@@ -1,9 +1,11 @@
 """Code generation for the Zinc compiler."""
 
+import dataclasses
 import re
 from dataclasses import dataclass, field
 
 from antlr4 import ParserRuleContext
+from antlr4.tree.Tree import TerminalNode
 from zinc.ast.types import (
     AnonymousStructTypeInfo,
     ArrayTypeInfo,
@@ -17,12 +19,16 @@ from zinc.ast.types import (
     SetTypeInfo,
     TupleTypeInfo,
     ValueTypeSpec,
+    default_exact_type,
+    default_integer_type,
     exact_type_to_base,
     exact_type_to_rust,
     normalize_exact_type,
+    promote_exact_numeric,
     type_to_rust,
 )
 from zinc.atlas import (
+    ITERATE_METHOD,
     Atlas,
     ConstInstance,
     EnumInstance,
@@ -30,8 +36,17 @@ from zinc.atlas import (
     StructFieldInfo,
     StructInstance,
     StructMethodInfo,
+    display_method,
+    is_required_method,
+    iterate_method,
+    iterator_next_method,
 )
+from zinc.const_fold import Constant, ConstantFolder
+from zinc.coverage import CoverageOptions, CoveragePoint, coverage_flush, coverage_hit, coverage_module, coverage_session
+from zinc.dead_code import used_method_names
+from zinc.decorators import derives_from_ctx, has_marker_decorator
 from zinc.exceptions import ZincTypeError
+from zinc.fixed_arrays import array_length
 from zinc.meta_runtime import (
     COMPONENT_ORDER_QNAME,
     MetaListValue,
@@ -39,12 +54,26 @@ from zinc.meta_runtime import (
     is_meta_struct_qname,
     meta_struct_rust_name,
 )
-from zinc.modules import RustExternFunction, extract_identifier_path, struct_path_from_ctx
-from zinc.numeric_literals import is_numeric_literal, numeric_literal_value
+from zinc.modules import (
+    RustExternFunction,
+    appended_pieces,
+    extract_identifier_path,
+    format_string_literal,
+    split_top_level_commas,
+    struct_path_from_ctx,
+)
+from zinc.numeric_literals import is_numeric_literal, is_unsuffixed_numeric_literal, numeric_literal_value, parse_numeric_literal
 from zinc.operators import ResolvedOperatorCall
+from zinc.profiling import ProfilingOptions, profile_call, profile_module, profile_report, profile_session
+from zinc.rust_modules import RESERVED_MODULE_NAMES, crate_visible, module_declarations, module_file_name, module_path
+from zinc.rust_names import rust_identifier
+from zinc.rust_parens import remove_redundant_parens
+from zinc.rustfmt import format_rust
+from zinc.timings import phase
 from zinc.parser.zincParser import zincParser as ZincParser
 from zinc.parser.zincVisitor import zincVisitor
-from zinc.string_literals import is_interpolated_string_literal, is_string_literal, to_rust_string_literal
+from zinc.sourcemap import SourceLocation, SourceMap, source_marker, strip_source_markers
+from zinc.string_literals import is_interpolated_string_literal, is_string_literal, to_rust_raw_string, to_rust_string_literal
 from zinc.symbols import (
     BoundArgument,
     BoundStructField,
@@ -53,14 +82,63 @@ from zinc.symbols import (
     SymbolTable,
     arrow_lambda_body_expression,
     function_parameters,
+    included_file,
+    is_assigned_string_literal,
+    is_read_after,
+    number_format_spec,
 )
+from zinc.targets import BROWSER_PRELUDE, BROWSER_START, HOST, Target
+from zinc.trivia import Trivia
 
 BITWISE_VALUE_ASSIGNMENT_OPERATORS = frozenset({"&=", "|=", "^="})
+# What integer arithmetic does when the result doesn't fit: panic, wrap around, or clamp to the type's bounds.
+OVERFLOW_MODES = ("checked", "wrapping", "saturating")
+DEFAULT_OVERFLOW_MODE = "checked"
+# Rust has no saturating remainder; the only overflowing case (MIN % -1) is 0 either way.
+OVERFLOW_METHODS = {"+": "add", "-": "sub", "*": "mul", "/": "div", "%": "rem"}
+# The loop a function that calls itself in tail position runs in, and the arguments of its next pass
+TAIL_CALL_LABEL = "'__zinc_tail"
+TAIL_CALL_ARGS = "__zinc_tail_args"
+# Nodes whose `return`s and calls don't end the function they sit in
+TAIL_CALL_BARRIERS = (
+    ZincParser.LambdaExpressionContext,
+    ZincParser.TryExpressionContext,
+    ZincParser.FunctionDeclarationContext,
+    ZincParser.AsyncFunctionDeclarationContext,
+)
+# List methods beyond push, each mapped onto its Vec counterpart
+LIST_METHODS = frozenset({"insert", "remove", "pop", "index_of", "reverse", "contains"})
+# Static struct fields of these types live in atomics; the rest sit behind a Mutex.
+ATOMIC_STATIC_TYPES = {
+    "i8": "AtomicI8",
+    "i16": "AtomicI16",
+    "i32": "AtomicI32",
+    "i64": "AtomicI64",
+    "isize": "AtomicIsize",
+    "u8": "AtomicU8",
+    "u16": "AtomicU16",
+    "u32": "AtomicU32",
+    "u64": "AtomicU64",
+    "usize": "AtomicUsize",
+    "bool": "AtomicBool",
+}
+# Calls whose result borrows its receiver through a guard with a destructor.
+TEMPORARY_GUARD_CALLS = (".lock()", ".borrow()", ".borrow_mut()", ".read()", ".write()", ".drain(")
 RUNTIME_SYMBOL_FEATURES = {
     "Channel": "channel",
     "TryRecv": "channel",
     "TrySend": "channel",
     "Context": "context",
+    "sha256_hex": "hash",
+    "md5_hex": "hash",
+    "hmac_sha256_hex": "hash",
+    "on_interrupt": "signal",
+    "TempPath": "temp",
+    "uuid_v4": "uuid",
+    "format_number": "format",
+    "ToJson": "json",
+    "to_json": "json",
+    "write_json_key": "json",
     "TypeMeta": "metadata",
     "StructMeta": "metadata",
     "EnumMeta": "metadata",
@@ -86,13 +164,77 @@ class RustProgram:
     structs: list[str] = field(default_factory=list)
     functions: list[str] = field(default_factory=list)
     main_body: list[str] = field(default_factory=list)
+    main_comments: list[str] = field(default_factory=list)
     uses_async: bool = False
     runtime_features: set[str] = field(default_factory=set)
-
-    def render(self) -> str:
-        """Assemble final Rust code."""
+    crates: dict[str, str] = field(default_factory=dict)
+    uses_interrupt: bool = False
+    target: Target = HOST
+    source_locations: list[SourceLocation] = field(default_factory=list)
+    # Items generated from imported Zinc files, keyed by their rendered text, with the module id they came from.
+    item_modules: dict[str, str] = field(default_factory=dict)
+    # Checked arithmetic is emitted as plain operators, so the build has to keep Rust's overflow checks on.
+    overflow_checks: bool = False
+    # What each `__zinc_coverage` counter of a covered build counts, by counter number.
+    coverage_points: list[CoveragePoint] = field(default_factory=list)
+
+    def render(self, *, rustfmt: bool = False) -> str:
+        """Assemble final Rust code, optionally formatted with rustfmt."""
+        return self.render_with_source_map(rustfmt=rustfmt)[0]
+
+    def render_with_source_map(self, *, rustfmt: bool = False) -> tuple[str, SourceMap]:
+        """Assemble final Rust code and map its lines back to Zinc statements."""
+        rendered = "\n".join(self.render_items() + self.render_main())
+        if rustfmt:
+            rendered = format_rust(rendered)
+        return strip_source_markers(rendered, self.source_locations)
+
+    def render_files(self, *, rustfmt: bool = False) -> tuple[dict[str, str], SourceMap]:
+        """Assemble one Rust file per Zinc module, keyed by path under `src/`, with the crate root first."""
+        root_name = "lib.rs" if self.target.is_browser else "main.rs"
+        if not self.item_modules:
+            rendered, source_map = self.render_with_source_map(rustfmt=rustfmt)
+            return {root_name: rendered}, source_map
+
+        reserved = RESERVED_MODULE_NAMES | {name.replace("-", "_") for name in self.crates}
+        paths = {module_id: module_path(module_id, reserved) for module_id in sorted(set(self.item_modules.values()))}
+        declarations = module_declarations(list(paths.values()))
+        glob_imports = [f"use {'::'.join(path)}::*;" for path in sorted(paths.values())]
+        root = dataclasses.replace(
+            self,
+            imports=[*declarations[()], *glob_imports, *(["", *self.imports] if self.imports else [])],
+            consts=[item for item in self.consts if item not in self.item_modules],
+            structs=[item for item in self.structs if item not in self.item_modules],
+            functions=[item for item in self.functions if item not in self.item_modules],
+        )
+        texts = {root_name: "\n".join(root.render_items() + root.render_main())}
+        owners = {path: module_id for module_id, path in paths.items()}
+        for path, mod_lines in sorted(declarations.items()):
+            if not path:
+                continue
+            owner = owners.get(path)
+            items = [crate_visible(item) for item in (*self.consts, *self.structs, *self.functions) if owner and self.item_modules.get(item) == owner]
+            sections = ["use crate::*;" if items else "", "\n".join(mod_lines), *items]
+            texts[module_file_name(path)] = "\n\n".join(section for section in sections if section)
+
+        files: dict[str, str] = {}
+        source_map = SourceMap()
+        for name, text in texts.items():
+            rendered, file_map = strip_source_markers(format_rust(text) if rustfmt else text, self.source_locations)
+            files[name] = rendered
+            if name == root_name:
+                source_map.lines = file_map.lines
+            elif file_map.lines:
+                source_map.modules[name] = file_map.lines
+        return files, source_map
+
+    def render_items(self) -> list[str]:
+        """Render everything except `main` as lines."""
         parts = []
 
+        if self.target.is_browser:
+            parts.append(BROWSER_PRELUDE)
+
         if self.imports:
             parts.extend(self.imports)
             parts.append("")
@@ -111,8 +253,14 @@ class RustProgram:
                 parts.append(func)
                 parts.append("")
 
+        return parts
+
+    def render_main(self) -> list[str]:
+        """Render the `main` function as lines."""
+        parts = list(self.main_comments)
         if self.uses_async:
-            parts.append("#[tokio::main]")
+            # WebAssembly has no threads for tokio's default multi-threaded runtime.
+            parts.append('#[tokio::main(flavor = "current_thread")]' if self.target.is_wasm else "#[tokio::main]")
             parts.append("async fn main() {")
         else:
             parts.append("fn main() {")
@@ -122,8 +270,9 @@ class RustProgram:
             for line in stmt.split("\n"):
                 parts.append(f"    {line}")
         parts.append("}")
-
-        return "\n".join(parts)
+        if self.target.is_browser:
+            parts.extend(["", BROWSER_START])
+        return parts
 
 
 class CodeGenVisitor(zincVisitor):
@@ -155,6 +304,7 @@ class CodeGenVisitor(zincVisitor):
         self._ufcs_extern_call_map = ufcs_extern_call_map or {}
         self._operator_calls = operator_calls or {}
         self._uses_async = False
+        self._uses_interrupt = False
         self._current_function: str | None = None
         self._current_module: str | None = None
         self._declared_vars: set[str] = set()
@@ -165,6 +315,7 @@ class CodeGenVisitor(zincVisitor):
         self._current_struct: str | None = None  # When generating struct method
         self._current_struct_fields: dict[str, StructFieldInfo] | None = None
         self._current_constructor_owner: str | None = None
+        self._current_method_return_type: str | None = None
         # Track variables that hold compile-time literal values
         self._literal_vars: set[str] = set()
         self._expected_result_type: BaseType | None = None
@@ -173,21 +324,49 @@ class CodeGenVisitor(zincVisitor):
         self._expected_set_info: SetTypeInfo | None = None
         self._expected_tuple_info: TupleTypeInfo | None = None
         self._spawn_handles_var: str | None = None
+        self._tail_call_sites: set[tuple[int, int]] = set()
         self._select_counter = 0
         self._current_channel_params: set[str] = set()
         self._boxed_struct_vars: set[tuple[str | None, str]] = set()
         self._callable_signatures: dict[str, CallableTypeInfo] = {}
         self._anonymous_structs: dict[tuple, AnonymousStructTypeInfo] = {}
         self._captured_binding_names: set[str] = set()
+        self._static_atomic_types: set[str] = set()
         self._runtime_symbols: set[str] = set()
         self._runtime_features: set[str] = set()
         self._spread_temp_stack: list[dict[tuple[int, int], str]] = []
+        self._source_locations: list[SourceLocation] = []
+        self._item_modules: dict[str, str] = {}
+        self._overflow = DEFAULT_OVERFLOW_MODE
+        self._profiling: ProfilingOptions | None = None
+        # (name, location) of every timed function, numbered in the order they were generated.
+        self._profiled_functions: dict[tuple[str, str], int] = {}
+        self._coverage: CoverageOptions | None = None
+        self._coverage_points: list[CoveragePoint] = []
+        self._constant_folder = ConstantFolder(atlas, symbols)
+        # None keeps every method of an emitted type.
+        self._used_methods: set[str] | None = None
+        # Every identifier the program spells, so a renamed Rust keyword can't take one of them.
+        self._identifier_names = {
+            token.text
+            for module in self.module_graph.modules.values()
+            for token in module.tree.parser.getTokenStream().tokens
+            if token.type == ZincParser.IDENTIFIER
+        }
+        # Comments of every module, keyed by the file name its tokens carry.
+        self._trivia = {module.tree.start.getInputStream().name: Trivia.of(module.tree) for module in self.module_graph.modules.values()}
 
     def visit(self, tree):
-        """Visit one parse node and post-process try-propagation sites."""
-        rendered = super().visit(tree)
+        """Visit one parse node, emitting constant expressions as their value, and post-process try-propagation sites."""
+        folded = self._constant_folder.fold(tree, self._current_function, self._current_module)
+        rendered = self._render_folded_constant(folded, tree) if folded is not None else super().visit(tree)
         if not isinstance(tree, ParserRuleContext) or not isinstance(rendered, str):
             return rendered
+        if isinstance(tree, ZincParser.StatementContext) and rendered:
+            # Stripped again at render time, leaving a map from Rust lines to Zinc statements.
+            self._source_locations.append(SourceLocation(tree.start.getInputStream().name, tree.start.line))
+            counter = self._coverage_counter(tree) if self._current_function is not None or self._current_struct is not None else []
+            return "\n".join([source_marker(len(self._source_locations) - 1), *self._comment_lines(tree), *counter, rendered])
         if not isinstance(tree, ZincParser.ExpressionContext):
             return rendered
         family = self.symbols.auto_unwrap_family(tree.getSourceInterval(), self._current_function)
@@ -195,6 +374,18 @@ class CodeGenVisitor(zincVisitor):
             return f"({rendered})?"
         return rendered
 
+    def _comment_lines(self, ctx: ParserRuleContext | None, *, doc: bool = False) -> list[str]:
+        """Rust lines for the comments above a statement or declaration and at the end of its last line."""
+        trivia = self._trivia.get(ctx.start.getInputStream().name) if ctx is not None else None
+        if trivia is None:
+            return []
+        comments = (*trivia.leading(ctx.start), *trivia.trailing(ctx.stop))
+        return [line for comment in comments for line in comment.rust_lines(doc=doc)]
+
+    def _with_comments(self, ctx: ParserRuleContext | None, item: str) -> str:
+        """Put the comments written above a declaration in front of its generated item, `///` docs as Rust docs."""
+        return "\n".join([*self._comment_lines(ctx, doc=True), item])
+
     def _require_runtime_symbol(self, rust_name: str) -> None:
         """Record a Zinc runtime symbol that generated Rust references."""
         feature = RUNTIME_SYMBOL_FEATURES[rust_name]
@@ -223,8 +414,33 @@ class CodeGenVisitor(zincVisitor):
         if self._channel_infos:
             self._require_runtime_symbol("Channel")
 
-    def generate(self) -> RustProgram:
-        """Main entry point - generate Rust code for all reachable code."""
+    def generate(
+        self,
+        overflow: str = DEFAULT_OVERFLOW_MODE,
+        *,
+        keep_unused: bool = False,
+        profiling: ProfilingOptions | None = None,
+        coverage: CoverageOptions | None = None,
+    ) -> RustProgram:
+        """Main entry point - generate Rust code for all reachable code, with integer overflow handled per `overflow`.
+
+        Methods nothing reachable calls are dropped unless `keep_unused` is set, as
+        for Rust that will be linked into another crate. With `profiling`, every
+        function and method is timed and the program reports its hot functions on exit.
+        With `coverage`, statements and branches of the program's own modules count
+        how often they run.
+        """
+        self._overflow = overflow
+        self._profiling = profiling
+        self._profiled_functions = {}
+        self._coverage = coverage
+        self._coverage_points = []
+        self._used_methods = None if keep_unused else used_method_names(self.atlas)
+        with default_integer_type(self.module_graph.default_int), phase("codegen", str(self.module_graph.entry_file)):
+            return self._generate_program()
+
+    def _generate_program(self) -> RustProgram:
+        """Generate the program's items and `main` body; see `generate`."""
         # Pre-scan to determine which struct vars need mut
         self._prescan_for_mut_vars()
         self._collect_captured_binding_names()
@@ -235,7 +451,7 @@ class CodeGenVisitor(zincVisitor):
         self._mark_async_functions()
         self._require_runtime_for_builtin_types()
 
-        consts = [self._generate_const(c) for c in self.atlas.consts.values()]
+        consts = [self._top_level_item(c.module_id, self._with_comments(c.ctx, self._generate_const(c))) for c in self.atlas.consts.values()]
         callable_enums = [self._generate_callable_enum(info) for _, info in sorted(self._callable_signatures.items())]
         closure_envs = [self._generate_closure_env_struct(info) for _, info in sorted(self._lexical_functions.items()) if info.finalized]
         anonymous_structs = [
@@ -245,31 +461,39 @@ class CodeGenVisitor(zincVisitor):
                 key=lambda item: item[1].rust_type_name(),
             )
         ]
-        functions = self._generate_std_module_helpers()
+        functions = [remove_redundant_parens(item) for item in self._generate_std_module_helpers()]
         main_body = []
+        main_comments = []
 
         for func_name in self.atlas.topological_order():
             func = self.atlas.functions[func_name]
             if func.name == "main":
+                main_comments = self._comment_lines(func.ctx, doc=True)
                 if func.return_type == BaseType.RESULT and func.return_result_info is not None:
-                    functions.append(self._generate_function_with_name(func, "__zinc_main", force_async=self._uses_async))
+                    main_fn = self._generate_function_with_name(func, "__zinc_main", force_async=self._uses_async)
+                    functions.append(remove_redundant_parens(main_fn))
                     main_body = self._generate_result_main_wrapper_body()
                 else:
                     self._current_function = func.mangled_name
                     self._current_module = func.module_id
                     self._declared_vars.clear()
                     self._current_channel_params = set()
-                    main_body = self._generate_function_body(func)
+                    guard = [] if self._uses_async else self._profile_guard(func.name, self._source_location(func.ctx))
+                    main_body = self._with_tail_expression([*guard, *self._generate_function_body(func)])
             else:
-                functions.append(self._generate_function(func))
+                functions.append(self._top_level_item(func.module_id, self._with_comments(func.ctx, self._generate_function(func))))
 
         structs = [
-            *closure_envs,
-            *callable_enums,
-            *anonymous_structs,
-            *[self._generate_enum(e) for e in self.atlas.enums.values()],
-            *[self._generate_struct(s) for s in self.atlas.structs.values()],
+            *[remove_redundant_parens(item) for item in (*closure_envs, *callable_enums, *anonymous_structs)],
+            *[self._top_level_item(e.module_id, self._with_comments(e.ctx, self._generate_enum(e))) for e in self.atlas.enums.values()],
+            *[self._top_level_item(s.module_id, self._with_comments(s.ctx, self._generate_struct(s))) for s in self.atlas.structs.values()],
         ]
+        if self._profiling is not None:
+            main_body = [profile_session(), *main_body]
+            functions.append(profile_module(list(self._profiled_functions), self._profiling))
+        if self._coverage is not None:
+            main_body = [coverage_session(), *main_body]
+            functions.append(coverage_module(len(self._coverage_points), self._coverage))
         imports = self._generate_imports()
 
         return RustProgram(
@@ -277,18 +501,280 @@ class CodeGenVisitor(zincVisitor):
             consts=consts,
             structs=structs,
             functions=functions,
-            main_body=main_body,
+            main_body=[remove_redundant_parens(stmt) for stmt in main_body],
+            main_comments=main_comments,
             uses_async=self._uses_async,
             runtime_features=set(self._runtime_features),
+            crates={name: crate.requirement for name, crate in self.module_graph.rust_crates().items()},
+            uses_interrupt=self._uses_interrupt,
+            source_locations=list(self._source_locations),
+            item_modules=dict(self._item_modules),
+            overflow_checks=self._overflow == "checked",
+            coverage_points=list(self._coverage_points),
         )
 
+    def _before_exit(self) -> list[str]:
+        """Statements that save profiling and coverage data before the program exits without unwinding."""
+        return [
+            *([profile_report()] if self._profiling is not None else []),
+            *([coverage_flush()] if self._coverage is not None else []),
+        ]
+
+    def _coverage_counter(self, ctx: ParserRuleContext, branch: int | None = None) -> list[str]:
+        """The statement counting a pass through `ctx`, or one way out of the branch point `ctx`, in a covered build."""
+        if self._coverage is None or (self._current_module or "").startswith("std/"):
+            return []
+        point = CoveragePoint(ctx.start.getInputStream().name, ctx.start.line, ctx.start.column, branch)
+        self._coverage_points.append(point)
+        return [coverage_hit(len(self._coverage_points) - 1)]
+
+    def _profile_guard(self, name: str, location: str) -> list[str]:
+        """The statement timing a call of the function `name` in a profiled build, or nothing."""
+        if self._profiling is None:
+            return []
+        function_id = self._profiled_functions.setdefault((name, location), len(self._profiled_functions))
+        return [profile_call(function_id)]
+
+    def _top_level_item(self, module_id: str, item: str) -> str:
+        """Tidy a generated item and remember the imported Zinc file it came from."""
+        item = remove_redundant_parens(item)
+        if module_id != self.module_graph.entry_module_id:
+            self._item_modules[item] = module_id
+        return item
+
     def _generate_std_module_helpers(self) -> list[str]:
         """Generate Rust helpers required by Zinc std modules."""
         helpers: list[str] = []
         if "std/filesystem" in self.module_graph.modules:
             helpers.extend(self._generate_std_filesystem_helpers())
+        if "std/log" in self.module_graph.modules:
+            helpers.extend(self._generate_std_log_helpers())
+        if "std/encoding" in self.module_graph.modules:
+            helpers.extend(self._generate_std_encoding_helpers())
+        if "std/hash" in self.module_graph.modules:
+            helpers.extend(self._generate_std_hash_helpers())
         return helpers
 
+    def _generate_std_hash_helpers(self) -> list[str]:
+        """Generate helpers backing std/hash.zn on top of the runtime `hash` feature."""
+        for rust_name in ("sha256_hex", "md5_hex", "hmac_sha256_hex"):
+            self._require_runtime_symbol(rust_name)
+        return [
+            "\n".join(
+                [
+                    "fn __zinc_hash_sha256(data: &Vec<u8>) -> String {",
+                    "    sha256_hex(data)",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_hash_md5(data: &Vec<u8>) -> String {",
+                    "    md5_hex(data)",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_hash_hmac_sha256(key: &Vec<u8>, message: &Vec<u8>) -> String {",
+                    "    hmac_sha256_hex(key, message)",
+                    "}",
+                ]
+            ),
+        ]
+
+    def _generate_std_encoding_helpers(self) -> list[str]:
+        """Generate helpers backing std/encoding.zn.
+
+        Base64 uses the standard alphabet with `=` padding. Decoders return
+        Err with the offending position instead of panicking on bad input.
+        """
+        return [
+            "\n".join(
+                [
+                    "fn __zinc_encoding_utf8_encode(text: String) -> Vec<u8> {",
+                    "    text.into_bytes()",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_encoding_utf8_decode(data: &Vec<u8>) -> Result<String, String> {",
+                    "    String::from_utf8(data.clone()).map_err(|err| err.to_string())",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_encoding_base64_encode(data: &Vec<u8>) -> String {",
+                    '    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";',
+                    "    let mut out = String::with_capacity((data.len() + 2) / 3 * 4);",
+                    "    for chunk in data.chunks(3) {",
+                    "        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];",
+                    "        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;",
+                    "        for i in 0..4 {",
+                    "            if i <= chunk.len() {",
+                    "                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);",
+                    "            } else {",
+                    "                out.push('=');",
+                    "            }",
+                    "        }",
+                    "    }",
+                    "    out",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_encoding_base64_decode(text: String) -> Result<Vec<u8>, String> {",
+                    "    let input = text.trim_end_matches('=').as_bytes();",
+                    "    if input.len() % 4 == 1 || text.len() - input.len() > 2 {",
+                    '        return Err(format!("invalid base64 length {}", text.len()));',
+                    "    }",
+                    "    let mut out = Vec::with_capacity(input.len() * 3 / 4);",
+                    "    let (mut acc, mut bits) = (0u32, 0u32);",
+                    "    for (index, &byte) in input.iter().enumerate() {",
+                    "        let value = match byte {",
+                    "            b'A'..=b'Z' => byte - b'A',",
+                    "            b'a'..=b'z' => byte - b'a' + 26,",
+                    "            b'0'..=b'9' => byte - b'0' + 52,",
+                    "            b'+' => 62,",
+                    "            b'/' => 63,",
+                    '            _ => return Err(format!("invalid base64 character at position {}", index)),',
+                    "        };",
+                    "        acc = acc << 6 | value as u32;",
+                    "        bits += 6;",
+                    "        if bits >= 8 {",
+                    "            bits -= 8;",
+                    "            out.push((acc >> bits & 0xff) as u8);",
+                    "        }",
+                    "    }",
+                    "    Ok(out)",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_encoding_hex_encode(data: &Vec<u8>) -> String {",
+                    '    data.iter().map(|byte| format!("{:02x}", byte)).collect()',
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_encoding_hex_decode(text: String) -> Result<Vec<u8>, String> {",
+                    "    if text.len() % 2 != 0 {",
+                    '        return Err(format!("invalid hex length {}", text.len()));',
+                    "    }",
+                    "    (0..text.len())",
+                    "        .step_by(2)",
+                    "        .map(|index| {",
+                    "            text.get(index..index + 2)",
+                    "                .and_then(|pair| u8::from_str_radix(pair, 16).ok())",
+                    '                .ok_or_else(|| format!("invalid hex digit at position {}", index))',
+                    "        })",
+                    "        .collect()",
+                    "}",
+                ]
+            ),
+        ]
+
+    def _generate_std_log_helpers(self) -> list[str]:
+        """Generate helpers backing std/log.zn.
+
+        Records go to stderr with a UTC timestamp. The threshold starts from the
+        ZINC_LOG environment variable (default "info") and can be changed at
+        runtime with set_level(). An unknown ZINC_LOG level is reported once on
+        stderr and logs at "info".
+        """
+        return [
+            "\n".join(
+                [
+                    "fn __zinc_log_rank(level: &str) -> Option<u8> {",
+                    "    match level.to_ascii_lowercase().as_str() {",
+                    '        "debug" => Some(0),',
+                    '        "info" => Some(1),',
+                    '        "warn" | "warning" => Some(2),',
+                    '        "error" => Some(3),',
+                    '        "off" => Some(4),',
+                    "        _ => None,",
+                    "    }",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_log_threshold() -> &'static std::sync::atomic::AtomicU8 {",
+                    "    static THRESHOLD: std::sync::OnceLock<std::sync::atomic::AtomicU8> = std::sync::OnceLock::new();",
+                    "    THRESHOLD.get_or_init(|| {",
+                    '        let level = std::env::var("ZINC_LOG").map_or(1, |value| {',
+                    "            __zinc_log_rank(&value).unwrap_or_else(|| {",
+                    (
+                        '                eprintln!("warning: unknown ZINC_LOG level {value:?}; '
+                        'expected debug, info, warn, error, or off; logging at info");'
+                    ),
+                    "                1",
+                    "            })",
+                    "        });",
+                    "        std::sync::atomic::AtomicU8::new(level)",
+                    "    })",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_log_set_level(level: String) {",
+                    "    __zinc_log_threshold().store(__zinc_log_rank(&level).unwrap_or(1), std::sync::atomic::Ordering::Relaxed);",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_log_enabled(level: String) -> bool {",
+                    "    let rank = __zinc_log_rank(&level).unwrap_or(1);",
+                    "    rank < 4 && rank >= __zinc_log_threshold().load(std::sync::atomic::Ordering::Relaxed)",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_log_timestamp() -> String {",
+                    "    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default();",
+                    "    let secs = now.as_secs() as i64;",
+                    "    let (days, rem) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));",
+                    "    let z = days + 719_468;",
+                    "    let era = z.div_euclid(146_097);",
+                    "    let doe = z.rem_euclid(146_097);",
+                    "    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;",
+                    "    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);",
+                    "    let mp = (5 * doy + 2) / 153;",
+                    "    let day = doy - (153 * mp + 2) / 5 + 1;",
+                    "    let month = if mp < 10 { mp + 3 } else { mp - 9 };",
+                    "    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };",
+                    "    format!(",
+                    '        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",',
+                    "        year,",
+                    "        month,",
+                    "        day,",
+                    "        rem / 3_600,",
+                    "        rem % 3_600 / 60,",
+                    "        rem % 60,",
+                    "        now.subsec_millis()",
+                    "    )",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_log_emit(level: String, message: String) {",
+                    "    if __zinc_log_enabled(level.clone()) {",
+                    '        eprintln!("{} {:<5} {}", __zinc_log_timestamp(), level.to_uppercase(), message);',
+                    "    }",
+                    "}",
+                ]
+            ),
+        ]
+
     def _generate_std_filesystem_helpers(self) -> list[str]:
         """Generate helpers backing std/filesystem.zn."""
         return [
@@ -306,6 +792,13 @@ class CodeGenVisitor(zincVisitor):
                     "}",
                 ]
             ),
+            "\n".join(
+                [
+                    "fn __zinc_fs_remove_dir_all(path: String) -> Result<(), String> {",
+                    "    std::fs::remove_dir_all(path).map_err(|error| error.to_string())",
+                    "}",
+                ]
+            ),
             "\n".join(
                 [
                     "fn __zinc_fs_read_text(path: String) -> Result<String, String> {",
@@ -336,6 +829,102 @@ class CodeGenVisitor(zincVisitor):
                     "}",
                 ]
             ),
+            "\n".join(
+                [
+                    "fn __zinc_fs_entry_names(dir: &str) -> Result<Vec<String>, String> {",
+                    '    let dir = if dir.is_empty() { "." } else { dir };',
+                    "    let mut names = Vec::new();",
+                    "    for entry in std::fs::read_dir(dir).map_err(|error| error.to_string())? {",
+                    "        let entry = entry.map_err(|error| error.to_string())?;",
+                    "        names.push(entry.file_name().to_string_lossy().to_string());",
+                    "    }",
+                    "    names.sort();",
+                    "    Ok(names)",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_fs_join(base: &str, name: &str) -> String {",
+                    '    if base.is_empty() {',
+                    "        name.to_string()",
+                    "    } else if base.ends_with('/') {",
+                    '        format!("{}{}", base, name)',
+                    "    } else {",
+                    '        format!("{}/{}", base, name)',
+                    "    }",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_fs_list_dir(path: String) -> Result<Vec<String>, String> {",
+                    "    Ok(__zinc_fs_entry_names(&path)?.iter().map(|name| __zinc_fs_join(&path, name)).collect())",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_fs_glob_match(pattern: &[char], name: &[char]) -> bool {",
+                    "    match pattern.split_first() {",
+                    "        None => name.is_empty(),",
+                    "        Some(('*', rest)) => (0..=name.len()).any(|skip| __zinc_fs_glob_match(rest, &name[skip..])),",
+                    "        Some(('?', rest)) => !name.is_empty() && __zinc_fs_glob_match(rest, &name[1..]),",
+                    "        Some((ch, rest)) => name.first() == Some(ch) && __zinc_fs_glob_match(rest, &name[1..]),",
+                    "    }",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_fs_glob_walk(base: &str, parts: &[&str], matches: &mut Vec<String>) -> Result<(), String> {",
+                    "    let Some((part, rest)) = parts.split_first() else {",
+                    "        if !base.is_empty() {",
+                    "            matches.push(base.to_string());",
+                    "        }",
+                    "        return Ok(());",
+                    "    };",
+                    '    if *part == "**" {',
+                    "        __zinc_fs_glob_walk(base, rest, matches)?;",
+                    "        for name in __zinc_fs_entry_names(base)? {",
+                    "            let child = __zinc_fs_join(base, &name);",
+                    "            if std::path::Path::new(&child).is_dir() {",
+                    "                __zinc_fs_glob_walk(&child, parts, matches)?;",
+                    "            }",
+                    "        }",
+                    "        return Ok(());",
+                    "    }",
+                    "    if !part.contains(['*', '?']) {",
+                    "        let child = __zinc_fs_join(base, part);",
+                    "        if std::fs::metadata(&child).is_ok() {",
+                    "            __zinc_fs_glob_walk(&child, rest, matches)?;",
+                    "        }",
+                    "        return Ok(());",
+                    "    }",
+                    "    let pattern: Vec<char> = part.chars().collect();",
+                    "    for name in __zinc_fs_entry_names(base)? {",
+                    "        let chars: Vec<char> = name.chars().collect();",
+                    "        if __zinc_fs_glob_match(&pattern, &chars) {",
+                    "            __zinc_fs_glob_walk(&__zinc_fs_join(base, &name), rest, matches)?;",
+                    "        }",
+                    "    }",
+                    "    Ok(())",
+                    "}",
+                ]
+            ),
+            "\n".join(
+                [
+                    "fn __zinc_fs_glob(pattern: String) -> Result<Vec<String>, String> {",
+                    '    let root = if pattern.starts_with(\'/\') { "/" } else { "" };',
+                    "    let parts: Vec<&str> = pattern.split('/').filter(|part| !part.is_empty()).collect();",
+                    "    let mut matches = Vec::new();",
+                    "    __zinc_fs_glob_walk(root, &parts, &mut matches)?;",
+                    "    matches.sort();",
+                    "    matches.dedup();",
+                    "    Ok(matches)",
+                    "}",
+                ]
+            ),
         ]
 
     def _callable_info_is_concrete(self, info: CallableTypeInfo | None) -> bool:
@@ -741,12 +1330,12 @@ class CodeGenVisitor(zincVisitor):
 
     def _channel_sender_expr(self, name: str, clone: bool = False) -> str:
         """Render the Rust channel value used for sends."""
-        base = name
+        base = self._rust_ident(name)
         return f"{base}.clone()" if clone else base
 
     def _channel_receiver_expr(self, name: str) -> str:
         """Render the Rust channel value used for receives."""
-        return name
+        return self._rust_ident(name)
 
     def _render_channel_value(self, channel_name: str, expr_ctx) -> str:
         """Render a channel payload with the channel element type's ownership rules."""
@@ -858,7 +1447,7 @@ class CodeGenVisitor(zincVisitor):
             return self._coerce_owned(value, BaseType.STRING, expr_ctx)
         if resolved_expected_type == BaseType.FLOAT and expr_ctx is not None:
             if self._get_expr_type(expr_ctx) == BaseType.INTEGER:
-                return f"({value} as f64)"
+                return self._numeric_cast(value, expr_ctx, "f64")
         return value
 
     def _visit_expression_with_expectations(
@@ -959,20 +1548,26 @@ class CodeGenVisitor(zincVisitor):
     ) -> str:
         """Render an if/else-if chain in statement or expression position."""
         lines: list[str] = []
+        branch_point = conditions[0].parentCtx
         for i, expr_ctx in enumerate(conditions):
             cond = self.visit(expr_ctx)
             body_stmts = self._render_scoped_value_block(blocks[i]) if as_expression else self._render_scoped_block(blocks[i])
             keyword = "if" if i == 0 else "} else if"
             lines.append(f"{keyword} {cond} {{")
-            self._append_block_lines(lines, body_stmts, 1)
+            self._append_block_lines(lines, [*self._coverage_counter(branch_point, i), *body_stmts], 1)
 
+        # Without an else, the counter for falling through needs one of its own.
+        else_counter = self._coverage_counter(branch_point, len(conditions))
         if else_block is not None:
             lines.append("} else {")
             else_stmts = self._render_scoped_value_block(else_block) if as_expression else self._render_scoped_block(else_block)
-            self._append_block_lines(lines, else_stmts, 1)
+            self._append_block_lines(lines, [*else_counter, *else_stmts], 1)
         elif as_expression:
             lines.append("} else {")
-            lines.append("    ()")
+            self._append_block_lines(lines, [*else_counter, "()"], 1)
+        elif else_counter:
+            lines.append("} else {")
+            self._append_block_lines(lines, else_counter, 1)
 
         lines.append("}")
         return "\n".join(lines)
@@ -1027,6 +1622,8 @@ class CodeGenVisitor(zincVisitor):
             imports.append("use std::rc::Rc;")
         if needs_arc_mutex:
             imports.append("use std::sync::{Arc, Mutex};")
+        if self._static_atomic_types:
+            imports.append(f"use std::sync::atomic::{{{', '.join(sorted(self._static_atomic_types))}, Ordering}};")
         return imports
 
     def _constant_value_for_expr(self, ctx):
@@ -1036,6 +1633,18 @@ class CodeGenVisitor(zincVisitor):
             return None
         return symbol.constant_value
 
+    def _render_folded_constant(self, value: Constant, ctx) -> str:
+        """Render a value the constant folder computed, keeping the expression's type where a bare literal would lose it."""
+        if isinstance(value, (bool, str)):
+            return self._render_constant_value(value)
+        base_type = BaseType.FLOAT if isinstance(value, float) else BaseType.INTEGER
+        exact_type = exact_type_to_rust(self._get_expr_exact_type(ctx), base_type)
+        text = repr(value)
+        if base_type == BaseType.INTEGER and (exact_type != default_exact_type(BaseType.INTEGER) or not -(2**31) <= value < 2**31):
+            # Unconstrained integer literals are i32 in Rust.
+            text = f"{text}_{exact_type}"
+        return f"({text})" if value < 0 else text
+
     def _render_constant_value(self, value) -> str:
         """Render a compile-time constant directly into Rust."""
         if isinstance(value, MetaValue):
@@ -1212,6 +1821,8 @@ class CodeGenVisitor(zincVisitor):
                     self._register_callable_info(nested)
         for channel_info in self._channel_infos.values():
             self._register_callable_info(channel_info.element_callable_info)
+        for handler_info in self.atlas.interrupt_handlers:
+            self._register_callable_info(handler_info)
         self._prune_abstract_callable_signatures()
 
     def _register_anonymous_struct_info(self, info: AnonymousStructTypeInfo | None) -> None:
@@ -1364,6 +1975,8 @@ class CodeGenVisitor(zincVisitor):
                     element_callable_info=symbol.callable_info if symbol.element_type == BaseType.CALLABLE else None,
                     element_struct_qualified_name=symbol.element_struct_qualified_name,
                     element_anonymous_struct_info=symbol.element_anonymous_struct_info,
+                    element_array_info=symbol.element_array_info,
+                    length=symbol.array_length,
                 )
                 if symbol.resolved_type == BaseType.ARRAY and symbol.element_type is not None
                 else None,
@@ -1473,6 +2086,8 @@ class CodeGenVisitor(zincVisitor):
                 element_anonymous_struct_info=symbol.element_anonymous_struct_info,
                 element_result_info=symbol.result_info if symbol.element_type == BaseType.RESULT else None,
                 element_option_info=symbol.option_info if symbol.element_type == BaseType.OPTION else None,
+                element_array_info=symbol.element_array_info,
+                length=symbol.array_length,
             )
         return self._type_with_metadata_to_rust(
             symbol.resolved_type,
@@ -1561,7 +2176,7 @@ class CodeGenVisitor(zincVisitor):
             )
         if target.kind == "static_method":
             owner_qualified_name = target.receiver_struct_qualified_name or target.qualified_name.rpartition("::")[0]
-            method_name = target.qualified_name.rpartition("::")[2]
+            method_name = self._rust_ident(target.qualified_name.rpartition("::")[2])
             if owner_qualified_name in self.atlas.structs:
                 return f"{self._struct_rust_name(self.atlas.structs[owner_qualified_name])}::{method_name}"
             if owner_qualified_name in self.atlas.enums:
@@ -1613,8 +2228,25 @@ class CodeGenVisitor(zincVisitor):
         lines.append("    }")
         lines.append("}")
         lines.append("")
+        if self.atlas.uses_debug_print:
+            lines.append(f"impl std::fmt::Debug for {info.rust_type_name()} {{")
+            lines.append("    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {")
+            lines.append('        f.write_str("<fn>")')
+            lines.append("    }")
+            lines.append("}")
+            lines.append("")
         lines.append(f"impl {info.rust_type_name()} {{")
-        params = [f"arg_{index}: {self._callable_param_rust_type(info, index)}" for index in range(len(info.param_types))]
+        mutably_borrowed: set[int] = set()
+        for target in ordered_targets:
+            func = None
+            if target.kind in {"function", "lambda", "closure"}:
+                func = self.atlas.functions.get(self._callable_dispatch_target(info, target))
+            if func is not None:
+                mutably_borrowed.update(index for index, borrow in func.arg_borrows.items() if borrow == "&mut")
+        params = [
+            f"{'mut ' if index in mutably_borrowed else ''}arg_{index}: {self._callable_param_rust_type(info, index)}"
+            for index in range(len(info.param_types))
+        ]
         ret_type = self._callable_return_rust_type(info)
         ret_suffix = "" if ret_type == "()" else f" -> {ret_type}"
         lines.append(f"    fn call(&self, {', '.join(params)}){ret_suffix} {{")
@@ -1639,6 +2271,11 @@ class CodeGenVisitor(zincVisitor):
                     lines.append(f"            Self::{variant_name}(ctx) => {call_expr},")
             else:
                 callee = self._callable_dispatch_target(info, target)
+                borrows = self.atlas.functions[callee].arg_borrows if callee in self.atlas.functions else {}
+                args = ", ".join(
+                    f"{self._borrow_prefix(borrows[index])}arg_{index}" if index in borrows else f"arg_{index}"
+                    for index in range(len(info.param_types))
+                )
                 if target.kind == "closure":
                     call_expr = f"{callee}(env.clone(){', ' if args else ''}{args})"
                     if ret_type == "()":
@@ -1754,7 +2391,7 @@ class CodeGenVisitor(zincVisitor):
                 param_type = callable_info.param_types[i]
                 arg_ctx = arg_ctxs[i] if arg_ctxs and i < len(arg_ctxs) else None
                 if param_type == BaseType.STRING and (self._expr_is_string_literal(arg_ctx) or self._looks_like_rust_string_literal(arg)):
-                    processed.append(f"String::from({arg})")
+                    processed.append(self._owned_string(arg))
                 elif param_type == BaseType.ARRAY and i in callable_info.param_array_infos:
                     arr_info = callable_info.param_array_infos[i]
                     processed.append(f"&mut {arg}" if arr_info.is_mutated else f"&{arg}")
@@ -1870,13 +2507,24 @@ class CodeGenVisitor(zincVisitor):
 
     def _sanitize_rust_identifier(self, text: str) -> str:
         """Return a Rust-safe identifier fragment."""
-        cleaned = re.sub(r"[^0-9A-Za-z_]+", "_", text).strip("_")
+        cleaned = re.sub(r"\W+", "_", text).strip("_")
         if not cleaned:
             cleaned = "value"
         if cleaned[0].isdigit():
             cleaned = f"v_{cleaned}"
         return cleaned
 
+    def _rust_ident(self, name: str) -> str:
+        """Spell a Zinc local, parameter, field, or method name so Rust doesn't read it as a keyword."""
+        return rust_identifier(name, self._identifier_names)
+
+    def _rust_text(self, ctx) -> str:
+        """A node's source text with each identifier in it spelled as `_rust_ident` spells it."""
+        if isinstance(ctx, TerminalNode):
+            text = ctx.getText()
+            return self._rust_ident(text) if ctx.symbol.type == ZincParser.IDENTIFIER else text
+        return "".join(self._rust_text(child) for child in ctx.getChildren())
+
     def _rust_binding_name(self, unique_name: str) -> str:
         """Render the Rust variable name for a symbol unique name."""
         return f"__zv_{self._sanitize_rust_identifier(unique_name)}"
@@ -1891,7 +2539,7 @@ class CodeGenVisitor(zincVisitor):
 
     def _closure_capture_field_name(self, capture) -> str:
         """Return the env-struct field name for a capture."""
-        return self._sanitize_rust_identifier(capture.name)
+        return self._rust_ident(self._sanitize_rust_identifier(capture.name))
 
     def _closure_capture_inner_type(self, capture) -> str:
         """Return the Rust type stored inside a captured binding cell."""
@@ -1903,6 +2551,8 @@ class CodeGenVisitor(zincVisitor):
                 element_callable_info=capture.callable_info if capture.element_type == BaseType.CALLABLE else None,
                 element_struct_qualified_name=capture.element_struct_qualified_name,
                 element_anonymous_struct_info=capture.element_anonymous_struct_info,
+                element_array_info=capture.element_array_info,
+                length=capture.array_length,
             )
             if capture.resolved_type == BaseType.ARRAY and capture.element_type is not None
             else None,
@@ -2000,6 +2650,8 @@ class CodeGenVisitor(zincVisitor):
                     element_callable_info=symbol.callable_info if symbol.element_type == BaseType.CALLABLE else None,
                     element_struct_qualified_name=symbol.element_struct_qualified_name,
                     element_anonymous_struct_info=symbol.element_anonymous_struct_info,
+                    element_array_info=symbol.element_array_info,
+                    length=symbol.array_length,
                 )
                 if symbol.resolved_type == BaseType.ARRAY and symbol.element_type is not None
                 else None,
@@ -2011,7 +2663,7 @@ class CodeGenVisitor(zincVisitor):
             )
             if type_str == "String":
                 if self._expr_is_string_literal(ctx.expression()) or self._looks_like_rust_string_literal(value):
-                    value = f"String::from({value})"
+                    value = self._owned_string(value)
                 return f"static {name}: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| {value});"
             return f"const {name}: {type_str} = {value};"
         return f"const {name} = {value};"
@@ -2034,6 +2686,12 @@ class CodeGenVisitor(zincVisitor):
             )
             lines.append(f"    {anon_field.name}: {rust_type},")
         lines.append("}")
+        if self.atlas.uses_debug_print:
+            lines.append("")
+            lines.extend(self._generate_struct_debug_impl(info.rust_type_name(), "struct", [f.name for f in info.canonical_fields()]))
+        if self.atlas.uses_display:
+            lines.append("")
+            lines.extend(self._generate_display_impl(info.rust_type_name(), []))
         return "\n".join(lines)
 
     def _generate_closure_env_struct(self, info: LexicalFunctionInfo) -> str:
@@ -2048,10 +2706,15 @@ class CodeGenVisitor(zincVisitor):
         """Generate a struct definition and impl block."""
         if any(field.is_infer for field in struct.fields):
             return f"// infer-backed struct family {self._struct_rust_name(struct)} uses synthesized concrete shapes"
+        if struct.is_interface:
+            return self._generate_interface(struct)
         lines = []
         rust_name = self._struct_rust_name(struct)
+        derives = derives_from_ctx(struct.ctx)
 
         # Struct definition
+        if "eq" in derives:
+            lines.append("#[derive(PartialEq)]")
         lines.append(f"struct {rust_name} {{")
         for f in struct.fields:
             vis = "" if f.is_private else "pub "
@@ -2069,31 +2732,183 @@ class CodeGenVisitor(zincVisitor):
                 option_info=f.option_info,
                 as_reference=False,
             )
-            lines.append(f"    {vis}{f.name}: {rust_type},")
+            lines.append(f"    {vis}{self._rust_ident(f.name)}: {rust_type},")
         lines.append("}")
         lines.append("")
         lines.append(f"impl Default for {rust_name} {{")
         lines.append("    fn default() -> Self {")
-        field_defaults = ", ".join(f"{field.name}: {field.rust_default()}" for field in struct.fields)
+        field_defaults = ", ".join(f"{self._rust_ident(field.name)}: {field.rust_default()}" for field in struct.fields)
         lines.append(f"        Self {{ {field_defaults} }}")
         lines.append("    }")
         lines.append("}")
+        if self.atlas.uses_debug_print:
+            lines.append("")
+            lines.extend(self._generate_struct_debug_impl(rust_name, struct.name, [f.name for f in struct.fields]))
+        if self.atlas.uses_display:
+            lines.append("")
+            lines.extend(self._generate_display_impl(rust_name, struct.methods))
+        if "json" in derives:
+            lines.append("")
+            lines.extend(self._generate_struct_json_impl(rust_name, [f.name for f in struct.fields if not f.is_private]))
 
         # Impl block (only if there are methods)
-        if struct.methods:
+        methods = self._emitted_methods(struct.methods)
+        interfaces = [self.atlas.structs[qualified_name] for qualified_name in struct.interfaces]
+        interface_method_names = {method.name for interface in interfaces for method in interface.methods}
+        next_method = iterator_next_method(methods)
+        if next_method is not None and next_method.name in interface_method_names:
+            next_method = None
+        inherent_methods = [method for method in methods if method.name not in interface_method_names and method is not next_method]
+        if inherent_methods:
             lines.append("")
             lines.append(f"impl {rust_name} {{")
-            for method in struct.methods:
-                method_code = self._generate_struct_method(method, struct)
-                for line in method_code.split("\n"):
-                    lines.append(f"    {line}")
+            lines.extend(self._generate_struct_methods(inherent_methods, struct))
+            lines.append("}")
+        for interface in interfaces:
+            # Methods the struct inherits unchanged come from the trait's default bodies.
+            names = {method.name for method in interface.methods}
+            implemented = [
+                method for method in methods if method.name in names and method.source_struct_qualified_name != interface.qualified_name
+            ]
+            lines.append("")
+            lines.append(f"impl {self._struct_rust_name(interface)} for {rust_name} {{")
+            lines.extend(self._generate_struct_methods(implemented, struct))
             lines.append("}")
+        if next_method is not None:
+            # next() returns Option<Item>, so the Item type sits inside its Option.
+            previous_module = self._current_module
+            self._current_module = next_method.source_module_id or struct.module_id
+            item_type = self._zinc_type_to_rust(next_method.return_type)[len("Option<") : -1]
+            self._current_module = previous_module
+            lines.append("")
+            lines.append(f"impl Iterator for {rust_name} {{")
+            lines.append(f"    type Item = {item_type};")
+            lines.extend(self._generate_struct_methods([next_method], struct))
+            lines.append("}")
+
+        if struct.static_fields:
+            lines.append("")
+            lines.extend(self._generate_static_field(struct, field) for field in struct.static_fields)
 
         return "\n".join(lines)
 
+    def _generate_struct_methods(self, methods: list[StructMethodInfo], struct: StructInstance) -> list[str]:
+        """Generate the indented methods of one impl block, with their comments."""
+        lines = []
+        for method in methods:
+            declaration = method.body_ctx.parentCtx if method.body_ctx is not None else None
+            method_code = self._with_comments(declaration, self._generate_struct_method(method, struct))
+            lines.extend(f"    {line}" for line in method_code.split("\n"))
+        return lines
+
+    def _generate_interface(self, interface: StructInstance) -> str:
+        """Generate the Rust trait for an interface: bodiless methods as required signatures, the rest as default methods."""
+        lines = [f"trait {self._struct_rust_name(interface)} {{"]
+        for method in self._emitted_methods(interface.methods):
+            if not is_required_method(method):
+                lines.extend(self._generate_struct_methods([method], interface))
+                continue
+            previous_module = self._current_module
+            self._current_module = method.source_module_id or interface.module_id
+            signature = self._struct_method_signature(method)
+            self._current_module = previous_module
+            declaration = method.body_ctx.parentCtx
+            method_code = self._with_comments(declaration, "\n".join([*self._rust_attributes(declaration), f"{signature};"]))
+            lines.extend(f"    {line}" for line in method_code.split("\n"))
+        lines.append("}")
+        return "\n".join(lines)
+
+    def _static_field_rust_name(self, struct: StructInstance, field: StructFieldInfo) -> str:
+        """Return the flattened Rust name for a struct's static field."""
+        return f"{self._struct_rust_name(struct).upper()}_{field.name}"
+
+    def _generate_static_field(self, struct: StructInstance, field: StructFieldInfo) -> str:
+        """Generate the Rust static backing one static struct field."""
+        name = self._static_field_rust_name(struct, field)
+        rust_type = field.rust_type()
+        value = field.rust_default()
+        if field.is_const:
+            if rust_type == "String":
+                return f"static {name}: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| {value});"
+            return f"const {name}: {rust_type} = {value};"
+        atomic_type = ATOMIC_STATIC_TYPES.get(rust_type)
+        if atomic_type is not None:
+            self._static_atomic_types.add(atomic_type)
+            return f"static {name}: {atomic_type} = {atomic_type}::new({value});"
+        if rust_type == "String":
+            return (
+                f"static {name}: std::sync::LazyLock<std::sync::Mutex<String>> = "
+                f"std::sync::LazyLock::new(|| std::sync::Mutex::new({value}));"
+            )
+        return f"static {name}: std::sync::Mutex<{rust_type}> = std::sync::Mutex::new({value});"
+
+    def _static_field_target(self, path: list[str] | None) -> tuple[StructInstance, StructFieldInfo] | None:
+        """Return the struct and static field that `Type.NAME` names, if it names one."""
+        static_target = self.module_graph.resolve_static_method_target(self._current_module, path) if path else None
+        if static_target is None or static_target[0].kind != "struct":
+            return None
+        owner_symbol, name = static_target
+        struct = self.atlas.structs.get(owner_symbol.qualified_name)
+        field = next((candidate for candidate in struct.static_fields if candidate.name == name), None) if struct else None
+        return (struct, field) if field is not None else None
+
+    def _static_field_expr(self, struct: StructInstance, field: StructFieldInfo) -> str:
+        """Return a Rust expression that reads a static field's current value."""
+        name = self._static_field_rust_name(struct, field)
+        rust_type = field.rust_type()
+        if field.is_const:
+            return f"(*{name}).clone()" if rust_type == "String" else name
+        if rust_type in ATOMIC_STATIC_TYPES:
+            return f"{name}.load(Ordering::SeqCst)"
+        if rust_type == "String":
+            return f"{name}.lock().unwrap().clone()"
+        return f"*{name}.lock().unwrap()"
+
+    def _render_static_field_assignment(
+        self, ctx: ZincParser.VariableAssignmentContext, struct: StructInstance, field: StructFieldInfo, assignment_op: str
+    ) -> str:
+        """Render a write to a static field through its atomic or Mutex."""
+        expr = ctx.expression()
+        name = self._static_field_rust_name(struct, field)
+        value = self._visit_expression_with_expectations(expr, expected_type=field.resolved_type, coerce_scalar=False)
+        if field.resolved_type == BaseType.STRING and assignment_op == "=":
+            value = self._owned_string_value(value)
+        atomic = field.rust_type() in ATOMIC_STATIC_TYPES
+        # The Mutex stays locked while the value is written, so a value that reads the same static is computed first.
+        staged = not atomic and re.search(rf"\b{re.escape(field.name)}\b", expr.getText()) is not None
+        lines = []
+        if staged or (atomic and assignment_op != "=" and not isinstance(expr, ZincParser.PrimaryExprContext)):
+            value_temp = self._staged_temp_name("static_value", expr)
+            lines.append(f"let {value_temp} = {value};")
+            value = value_temp
+        if field.resolved_type == BaseType.STRING:
+            if assignment_op == "+=":
+                lines.append(self._render_push_str(f"{name}.lock().unwrap()", value))
+            else:
+                lines.append(f"*{name}.lock().unwrap() = {value};")
+            return "\n".join(lines)
+        if assignment_op == "=":
+            lines.append(f"{name}.store({value}, Ordering::SeqCst);" if atomic else f"*{name}.lock().unwrap() = {value};")
+            return "\n".join(lines)
+        if assignment_op in BITWISE_VALUE_ASSIGNMENT_OPERATORS:
+            value = self._coerce_bitwise_operand(value, expr, field.exact_type)
+        elif assignment_op not in {"**=", "<<=", ">>="}:
+            value = self._coerce_numeric_rhs_for_target(value, expr, field.resolved_type, field.exact_type)
+        if atomic:
+            new_value = self._render_compound_value("current", assignment_op, field.resolved_type, field.exact_type, value, expr)
+            new_value = new_value or f"current {assignment_op[:-1]} {value}"
+            lines.append(f"{name}.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| Some({new_value})).unwrap();")
+            return "\n".join(lines)
+        guard_name = self._staged_temp_name("static_guard", ctx.assignmentTarget())
+        new_value = self._render_compound_value(f"*{guard_name}", assignment_op, field.resolved_type, field.exact_type, value, expr)
+        assignment = f"*{guard_name} = {new_value};" if new_value is not None else f"*{guard_name} {assignment_op} {value};"
+        lines.extend(["{", f"    let mut {guard_name} = {name}.lock().unwrap();", f"    {assignment}", "}"])
+        return "\n".join(lines)
+
     def _generate_enum(self, enum: EnumInstance) -> str:
         """Generate an enum definition and optional static impl block."""
-        lines = ["#[derive(Clone)]", f"enum {self._enum_rust_name(enum)} {{"]
+        derives = derives_from_ctx(enum.ctx)
+        lines = ["#[derive(Clone, PartialEq)]" if "eq" in derives else "#[derive(Clone)]", f"enum {self._enum_rust_name(enum)} {{"]
         for variant in enum.variants:
             if variant.is_unit:
                 lines.append(f"    {variant.name},")
@@ -2112,21 +2927,130 @@ class CodeGenVisitor(zincVisitor):
                     anonymous_struct_info=variant_field.anonymous_struct_info,
                     as_reference=False,
                 )
-                field_parts.append(f"{variant_field.name}: {rust_type}")
+                field_parts.append(f"{self._rust_ident(variant_field.name)}: {rust_type}")
             lines.append(f"    {variant.name} {{ {', '.join(field_parts)} }},")
         lines.append("}")
+        if self.atlas.uses_debug_print:
+            lines.append("")
+            lines.extend(self._generate_enum_debug_impl(enum))
+        if self.atlas.uses_display:
+            lines.append("")
+            lines.extend(self._generate_display_impl(self._enum_rust_name(enum), enum.methods))
+        if "json" in derives:
+            lines.append("")
+            lines.extend(self._generate_enum_json_impl(enum))
 
-        if enum.methods:
+        methods = self._emitted_methods(enum.methods)
+        if methods:
             lines.append("")
             lines.append(f"impl {self._enum_rust_name(enum)} {{")
-            for method in enum.methods:
-                method_code = self._generate_enum_method(method, enum)
+            for method in methods:
+                declaration = method.body_ctx.parentCtx if method.body_ctx is not None else None
+                method_code = self._with_comments(declaration, self._generate_enum_method(method, enum))
                 for line in method_code.split("\n"):
                     lines.append(f"    {line}")
             lines.append("}")
 
         return "\n".join(lines)
 
+    def _emitted_methods(self, methods: list[StructMethodInfo]) -> list[StructMethodInfo]:
+        """The methods of a type that reachable code can call, or all of them when unused ones are kept."""
+        if self._used_methods is None:
+            return methods
+        return [method for method in methods if method.name in self._used_methods]
+
+    def _generate_struct_debug_impl(self, rust_name: str, display_name: str, field_names: list[str]) -> list[str]:
+        """Generate a Debug impl that prints a struct under its Zinc name."""
+        fields = "".join(f'.field("{name}", &self.{self._rust_ident(name)})' for name in field_names)
+        return [
+            f"impl std::fmt::Debug for {rust_name} {{",
+            "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {",
+            f'        f.debug_struct("{display_name}"){fields}.finish()',
+            "    }",
+            "}",
+        ]
+
+    def _generate_enum_debug_impl(self, enum: EnumInstance) -> list[str]:
+        """Generate a Debug impl that prints enum variants as `Enum.Variant`."""
+        lines = [
+            f"impl std::fmt::Debug for {self._enum_rust_name(enum)} {{",
+            "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {",
+            "        match self {",
+        ]
+        for variant in enum.variants:
+            display_name = f"{enum.name}.{variant.name}"
+            if variant.is_unit:
+                lines.append(f'            Self::{variant.name} => f.write_str("{display_name}"),')
+                continue
+            bindings = ", ".join(self._rust_ident(variant_field.name) for variant_field in variant.fields)
+            fields = "".join(f'.field("{variant_field.name}", {self._rust_ident(variant_field.name)})' for variant_field in variant.fields)
+            lines.append(f'            Self::{variant.name} {{ {bindings} }} => f.debug_struct("{display_name}"){fields}.finish(),')
+        lines.extend(["        }", "    }", "}"])
+        return lines
+
+    def _generate_display_impl(self, rust_name: str, methods: list[StructMethodInfo]) -> list[str]:
+        """Generate a Display impl that shows a value with its to_string() method, or in its Debug form without one."""
+        method = display_method(self._emitted_methods(methods))
+        if method is None:
+            body = "std::fmt::Debug::fmt(self, f)"
+        else:
+            receiver = "Self::" if method.is_static else "self."
+            body = f"f.write_str(&{receiver}{self._rust_ident(method.name)}())"
+        return [
+            f"impl std::fmt::Display for {rust_name} {{",
+            "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {",
+            f"        {body}",
+            "    }",
+            "}",
+        ]
+
+    def _generate_struct_json_impl(self, rust_name: str, field_names: list[str]) -> list[str]:
+        """Generate a ToJson impl that writes a struct as an object of its public fields."""
+        self._require_runtime_symbol("ToJson")
+        self._require_runtime_symbol("write_json_key")
+        lines = [f"impl ToJson for {rust_name} {{", "    fn write_json(&self, out: &mut String) {", "        out.push('{');"]
+        for index, name in enumerate(field_names):
+            if index:
+                lines.append("        out.push(',');")
+            lines.append(f'        write_json_key(out, "{name}");')
+            lines.append(f"        self.{self._rust_ident(name)}.write_json(out);")
+        lines.extend(["        out.push('}');", "    }", "}"])
+        return lines
+
+    def _generate_enum_json_impl(self, enum: EnumInstance) -> list[str]:
+        """Generate a ToJson impl that writes unit variants as strings and others as `{"Variant": {fields}}`."""
+        self._require_runtime_symbol("ToJson")
+        self._require_runtime_symbol("write_json_key")
+        lines = [
+            f"impl ToJson for {self._enum_rust_name(enum)} {{",
+            "    fn write_json(&self, out: &mut String) {",
+            "        match self {",
+        ]
+        for variant in enum.variants:
+            if variant.is_unit:
+                lines.append(f'            Self::{variant.name} => "{variant.name}".write_json(out),')
+                continue
+            bindings = ", ".join(self._rust_ident(variant_field.name) for variant_field in variant.fields)
+            lines.append(f"            Self::{variant.name} {{ {bindings} }} => {{")
+            lines.append(f"                out.push('{{');")
+            lines.append(f'                write_json_key(out, "{variant.name}");')
+            lines.append("                out.push('{');")
+            for index, variant_field in enumerate(variant.fields):
+                if index:
+                    lines.append("                out.push(',');")
+                lines.append(f'                write_json_key(out, "{variant_field.name}");')
+                lines.append(f"                {self._rust_ident(variant_field.name)}.write_json(out);")
+            lines.append('                out.push_str("}}");')
+            lines.append("            }")
+        lines.extend(["        }", "    }", "}"])
+        return lines
+
+    def _rust_attributes(self, ctx: ParserRuleContext | None) -> list[str]:
+        """Rust attributes for the built-in marker decorators of a function or method, such as `@inline` and `@cold`."""
+        if ctx is None:
+            return []
+        return [f"#[{marker}]" for marker in ("inline", "cold") if has_marker_decorator(ctx, marker)]
+
     def _generate_struct_method(self, method: StructMethodInfo, struct: StructInstance) -> str:
         """Generate a single struct method."""
         previous_declared = self._declared_vars.copy()
@@ -2139,34 +3063,25 @@ class CodeGenVisitor(zincVisitor):
         if not method.is_static:
             self._declared_vars.add("self")
 
-        # Build parameter list
-        param_strs = []
-        if not method.is_static:
-            param_strs.append(method.self_mutability or "&self")
-
-        for name, type_ann, resolved in method.parameters:
-            if type_ann:
-                param_strs.append(f"{name}: {self._zinc_type_to_rust(type_ann)}")
-            elif resolved:
-                param_strs.append(f"{name}: {resolved}")
-            else:
-                param_strs.append(f"{name}: i64")  # Default fallback
-
-        params = ", ".join(param_strs)
-        ret_type = f" -> {method.return_type}" if method.return_type else ""
+        signature = self._struct_method_signature(method)
 
         # Generate body
         self._current_struct = struct.qualified_name
         self._current_struct_fields = {f.name: f for f in struct.fields}
         self._current_constructor_owner = method.constructor_owner_qualified_name or method.source_struct_qualified_name
-        body_stmts = self._generate_block(method.body_ctx)
+        self._current_method_return_type = normalize_exact_type(method.return_type)
+        location = f"{self._current_module}.zn:{method.line_num}"
+        guard = self._profile_guard(f"{struct.name}.{method.display_name or method.name}", location)
+        body_stmts = self._with_tail_expression([*guard, *self._generate_block(method.body_ctx)])
         self._current_struct = None
         self._current_struct_fields = None
+        self._current_method_return_type = None
         self._current_constructor_owner = previous_constructor_owner
         self._current_module = previous_module
         self._declared_vars = previous_declared
 
-        lines = [f"fn {method.name}({params}){ret_type} {{"]
+        declaration = method.body_ctx.parentCtx if method.body_ctx is not None else None
+        lines = [*self._rust_attributes(declaration), f"{signature} {{"]
         for stmt in body_stmts:
             for line in stmt.split("\n"):
                 lines.append(f"    {line}")
@@ -2174,6 +3089,23 @@ class CodeGenVisitor(zincVisitor):
 
         return "\n".join(lines)
 
+    def _struct_method_signature(self, method: StructMethodInfo) -> str:
+        """Render `fn name(receiver, params) -> ret` for a struct method, resolving types in the current module."""
+        param_strs = []
+        if not method.is_static:
+            param_strs.append(method.self_mutability or "&self")
+
+        for name, type_ann, resolved in method.parameters:
+            if type_ann:
+                param_strs.append(f"{self._rust_ident(name)}: {self._zinc_type_to_rust(type_ann)}")
+            elif resolved:
+                param_strs.append(f"{self._rust_ident(name)}: {resolved}")
+            else:
+                param_strs.append(f"{self._rust_ident(name)}: {default_exact_type(BaseType.INTEGER)}")  # Default fallback
+
+        ret_type = f" -> {self._zinc_type_to_rust(method.return_type)}" if method.return_type else ""
+        return f"fn {self._rust_ident(method.name)}({', '.join(param_strs)}){ret_type}"
+
     def _generate_enum_method(self, method: StructMethodInfo, enum: EnumInstance) -> str:
         """Generate a single static enum method."""
         previous_declared = self._declared_vars.copy()
@@ -2187,11 +3119,11 @@ class CodeGenVisitor(zincVisitor):
         param_strs = []
         for name, type_ann, resolved in method.parameters:
             if type_ann:
-                param_strs.append(f"{name}: {self._zinc_type_to_rust(type_ann)}")
+                param_strs.append(f"{self._rust_ident(name)}: {self._zinc_type_to_rust(type_ann)}")
             elif resolved:
-                param_strs.append(f"{name}: {resolved}")
+                param_strs.append(f"{self._rust_ident(name)}: {resolved}")
             else:
-                param_strs.append(f"{name}: i64")
+                param_strs.append(f"{self._rust_ident(name)}: {default_exact_type(BaseType.INTEGER)}")
 
         params = ", ".join(param_strs)
         ret_type = f" -> {method.return_type}" if method.return_type else ""
@@ -2199,12 +3131,13 @@ class CodeGenVisitor(zincVisitor):
         self._current_struct = None
         self._current_struct_fields = None
         self._current_constructor_owner = method.constructor_owner_qualified_name or enum.qualified_name
-        body_stmts = self._generate_block(method.body_ctx)
+        body_stmts = self._with_tail_expression(self._generate_block(method.body_ctx))
         self._current_constructor_owner = previous_constructor_owner
         self._current_module = previous_module
         self._declared_vars = previous_declared
 
-        lines = [f"fn {method.name}({params}){ret_type} {{"]
+        declaration = method.body_ctx.parentCtx if method.body_ctx is not None else None
+        lines = [*self._rust_attributes(declaration), f"fn {self._rust_ident(method.name)}({params}){ret_type} {{"]
         for stmt in body_stmts:
             for line in stmt.split("\n"):
                 lines.append(f"    {line}")
@@ -2240,6 +3173,10 @@ class CodeGenVisitor(zincVisitor):
             return mapping[lowered]
         if zinc_type == "Self":
             return "Self"
+        generic = re.fullmatch(r"([\w.]+)<(.+)>", zinc_type)
+        if generic is not None:
+            arguments = ", ".join(self._zinc_type_to_rust(argument.strip()) for argument in split_top_level_commas(generic[2]))
+            return f"{self._zinc_type_to_rust(generic[1])}<{arguments}>"
         if self._current_module is not None:
             parts = zinc_type.split(".")
             extern_type = self.module_graph.resolve_extern_type_path(self._current_module, parts)
@@ -2268,13 +3205,17 @@ class CodeGenVisitor(zincVisitor):
             return func.arg_tuple_infos[index].to_rust_type()
         if index in func.arg_callable_infos:
             return func.arg_callable_infos[index].rust_type_name()
+        borrow = func.arg_borrows.get(index)
+        if func.arg_types[index] == BaseType.STRING and borrow is not None:
+            return "&str"
         if func.arg_types[index] == BaseType.STRUCT:
-            return self._type_with_metadata_to_rust(
+            struct_type = self._type_with_metadata_to_rust(
                 BaseType.STRUCT,
                 struct_qualified_name=func.arg_struct_qualified_names.get(index),
                 anonymous_struct_info=func.arg_anonymous_struct_infos.get(index),
                 as_reference=False,
             )
+            return struct_type if borrow is None else f"{self._borrow_prefix(borrow)}{struct_type}"
         return self._type_with_metadata_to_rust(
             func.arg_types[index],
             exact_type=func.arg_exact_types[index] if index < len(func.arg_exact_types) else None,
@@ -2284,6 +3225,8 @@ class CodeGenVisitor(zincVisitor):
         """Render a Rust function return suffix."""
         if func.return_type == BaseType.VOID:
             return ""
+        if func.return_type == BaseType.ARRAY and func.return_array_info:
+            return " -> " + self._type_with_metadata_to_rust(BaseType.ARRAY, array_info=func.return_array_info, as_reference=False)
         if func.return_type == BaseType.DICT and func.return_dict_info:
             return f" -> {func.return_dict_info.to_rust_type(as_reference=False)}"
         if func.return_type == BaseType.SET and func.return_set_info:
@@ -2314,12 +3257,51 @@ class CodeGenVisitor(zincVisitor):
 
     def _generate_function(self, func: FunctionInstance) -> str:
         """Generate a function definition using mangled name."""
-        if not func.decorator_applications:
-            return self._generate_function_with_name(func, func.mangled_name)
-        return "\n\n".join(
+        rust_name = self._decorated_impl_rust_name(func) if func.decorator_applications else func.mangled_name
+        if has_marker_decorator(func.ctx, "memoize"):
+            parts = [
+                self._generate_function_with_name(func, f"{rust_name}__zinc_uncached"),
+                self._generate_memoized_function(func, rust_name),
+            ]
+        else:
+            parts = [self._generate_function_with_name(func, rust_name)]
+        if func.decorator_applications:
+            parts.append(self._generate_decorated_function_wrapper(func))
+        return "\n\n".join(parts)
+
+    def _generate_memoized_function(self, func: FunctionInstance, rust_name: str) -> str:
+        """Generate the `@memoize` wrapper: a cache keyed by the arguments in front of the `__zinc_uncached` body.
+
+        The lock is released while the body runs, so a recursive call fills the cache without deadlocking.
+        """
+        params: list[str] = []
+        args: list[str] = []
+        key_types: list[str] = []
+        keys: list[str] = []
+        for i, param in enumerate(function_parameters(func.ctx)):
+            name = self._rust_ident(param.name)
+            rust_type = self._function_param_rust_type(func, i)
+            params.append(f"{name}: {rust_type}")
+            args.append(name)
+            is_string = func.arg_types[i] == BaseType.STRING
+            key_types.append("String" if is_string else rust_type)
+            keys.append(f"{name}.to_string()" if is_string else name)
+        return_suffix = self._function_return_type_suffix(func)
+        trailing = "," if len(keys) == 1 else ""
+        cache_type = f"std::collections::HashMap<({', '.join(key_types)}{trailing}), {return_suffix.removeprefix(' -> ')}>"
+        async_kw, await_suffix = ("async ", ".await") if func.is_async else ("", "")
+        return "\n".join(
             [
-                self._generate_function_with_name(func, self._decorated_impl_rust_name(func)),
-                self._generate_decorated_function_wrapper(func),
+                f"{async_kw}fn {rust_name}({', '.join(params)}){return_suffix} {{",
+                f"    static CACHE: std::sync::LazyLock<std::sync::Mutex<{cache_type}>> = std::sync::LazyLock::new(Default::default);",
+                f"    let key = ({', '.join(keys)}{trailing});",
+                "    if let Some(value) = CACHE.lock().unwrap().get(&key) {",
+                "        return value.clone();",
+                "    }",
+                f"    let value = {rust_name}__zinc_uncached({', '.join(args)}){await_suffix};",
+                "    CACHE.lock().unwrap().insert(key, value.clone());",
+                "    value",
+                "}",
             ]
         )
 
@@ -2341,6 +3323,7 @@ class CodeGenVisitor(zincVisitor):
         # Get parameter names and types from func.arg_types
         params = []
         param_prelude: list[str] = []
+        param_bindings: list[str] = []
         if lexical_info is not None:
             params.append(f"__env: {self._closure_env_rust_name(lexical_info)}")
         for i, param in enumerate(function_parameters(ctx)):
@@ -2350,16 +3333,27 @@ class CodeGenVisitor(zincVisitor):
                 if i in func.arg_channel_infos and func.arg_channel_infos[i]:
                     self._current_channel_params.add(param_name)
                 type_str = self._function_param_rust_type(func, i)
-                params.append(f"{param_name}: {type_str}")
+                binding = "mut " if self._is_appended_string(self._parameter_symbol(func, param_name)) else ""
+                params.append(f"{binding}{self._rust_ident(param_name)}: {type_str}")
+                param_bindings.append(f"{binding}{self._rust_ident(param_name)}")
             else:
-                params.append(param_name)
+                params.append(self._rust_ident(param_name))
             self._declared_vars.add(param_name)
             param_symbol = self._lookup_local_symbol(param_name)
-            box_line = self._captured_binding_box_line(param_symbol, param_name)
+            box_line = self._captured_binding_box_line(param_symbol, self._rust_ident(param_name))
             if box_line is not None:
                 param_prelude.append(box_line)
 
-        body_stmts = self._generate_function_body(func)
+        is_async = func.is_async if force_async is None else force_async
+        lowers_tail_calls = rust_name == func.mangled_name and lexical_info is None and not param_prelude and not is_async
+        self._tail_call_sites = self._self_tail_call_sites(func) if lowers_tail_calls else set()
+        try:
+            body_stmts = self._generate_function_body(func)
+        finally:
+            tail_call_sites, self._tail_call_sites = self._tail_call_sites, set()
+        if tail_call_sites:
+            body_stmts = [self._tail_call_loop(func, param_bindings, body_stmts, tail_call_sites)]
+            params = [param.removeprefix("mut ") for param in params]
         if param_prelude:
             body_stmts = [*param_prelude, *body_stmts]
         if lexical_info is not None:
@@ -2373,11 +3367,15 @@ class CodeGenVisitor(zincVisitor):
                 prelude.append(f"let {alias_name} = __env.{self._closure_capture_field_name(capture)}.clone();")
                 self._declared_vars.add(capture.name)
             body_stmts = [*prelude, *body_stmts]
+        if not is_async:
+            # An async call can resume on another thread, so only synchronous calls are timed.
+            body_stmts = [*self._profile_guard(func.name, self._source_location(ctx)), *body_stmts]
+        body_stmts = self._with_tail_expression(body_stmts)
         param_str = ", ".join(params)
         return_type_str = self._function_return_type_suffix(func)
 
-        async_kw = "async " if (func.is_async if force_async is None else force_async) else ""
-        lines = [f"{async_kw}fn {rust_name}({param_str}){return_type_str} {{"]
+        async_kw = "async " if is_async else ""
+        lines = [*self._rust_attributes(ctx), f"{async_kw}fn {rust_name}({param_str}){return_type_str} {{"]
         for stmt in body_stmts:
             # Handle multiline statements (like for loops, if/else) by indenting each line
             for line in stmt.split("\n"):
@@ -2386,6 +3384,95 @@ class CodeGenVisitor(zincVisitor):
 
         return "\n".join(lines)
 
+    def _self_tail_call_sites(self, func: FunctionInstance) -> set[tuple[int, int]]:
+        """Source intervals of the statements that end `func` by calling it again, which run as another pass of a loop.
+
+        Any `return f(...)` counts, and so does a bare `f(...)` that a function
+        returning nothing ends on. Calls inside lambdas, try blocks, and nested
+        functions don't. A parameter taken by reference has to be handed on
+        unchanged, since a loop can't rebind a borrow to a value made inside it.
+        """
+        ctx = func.ctx
+        if arrow_lambda_body_expression(ctx) is not None or self._node_contains_spawn(ctx.block()):
+            return set()
+        sites: set[tuple[int, int]] = set()
+        pending: list = [ctx.block()]
+        while pending:
+            node = pending.pop()
+            if isinstance(node, ZincParser.ReturnStatementContext) and self._is_self_tail_call(func, node.expression()):
+                sites.add(node.getSourceInterval())
+            for i in range(node.getChildCount()):
+                child = node.getChild(i)
+                if isinstance(child, ParserRuleContext) and not isinstance(child, TAIL_CALL_BARRIERS):
+                    pending.append(child)
+        if func.return_type == BaseType.VOID:
+            for stmt in self._tail_statements(ctx.block()):
+                expr_stmt = stmt.expressionStatement()
+                if expr_stmt is not None and self._is_self_tail_call(func, expr_stmt.expression()):
+                    sites.add(expr_stmt.getSourceInterval())
+        return sites
+
+    def _tail_statements(self, block: ZincParser.BlockContext) -> list[ZincParser.StatementContext]:
+        """The statements a block can end on: its last statement, or the last statements of that `if`'s branches."""
+        if not block.statement():
+            return []
+        last = block.statement()[-1]
+        if last.ifStatement() is None:
+            return [last]
+        return [stmt for branch in last.ifStatement().block() for stmt in self._tail_statements(branch)]
+
+    def _is_self_tail_call(self, func: FunctionInstance, expr) -> bool:
+        """Return True for a direct call of `func`'s own specialization, without spreads, that a loop can stand in for."""
+        if not isinstance(expr, ZincParser.FunctionCallExprContext) or self._constant_value_for_expr(expr) is not None:
+            return False
+        if self._specialization_map.get((func.mangled_name, expr.getSourceInterval())) != func.mangled_name:
+            return False
+        call_args = self._call_args_for_ctx(expr)
+        if any(getattr(arg, "spread_source_expr", None) is not None for arg in call_args):
+            return False
+        params = function_parameters(func.ctx)
+        for i in self._borrowed_parameter_indices(func):
+            symbol = self.symbols.lookup_by_interval(self._call_arg_expr(call_args[i]).getSourceInterval(), func.mangled_name)
+            param_symbol = self._parameter_symbol(func, params[i].name)
+            if symbol is None or param_symbol is None or param_symbol.unique_name not in {symbol.unique_name, symbol.binding_unique_name}:
+                return False
+        return True
+
+    def _borrowed_parameter_indices(self, func: FunctionInstance) -> set[int]:
+        """Indexes of the parameters `func` takes as Rust references."""
+        return {i for i in range(len(func.arg_types)) if self._function_param_rust_type(func, i).startswith("&")}
+
+    def _render_self_tail_call(self, call_ctx: ZincParser.FunctionCallExprContext) -> str:
+        """Hand a self tail call's arguments to the next pass of the function's loop instead of calling it."""
+        func = self.atlas.functions[self._current_function]
+        call_args = self._call_args_for_ctx(call_ctx)
+        arg_ctxs = [self._call_arg_expr(arg) for arg in call_args]
+        args = self._process_function_args(func.mangled_name, self._render_function_args_for_instance(func, call_args), arg_ctxs)
+        borrowed = self._borrowed_parameter_indices(func)
+        args = [arg for i, arg in enumerate(args) if i not in borrowed]
+        lines = [f"{TAIL_CALL_ARGS} = ({', '.join(args)}{',' if len(args) == 1 else ''});"] if args else []
+        return "\n".join([*lines, f"continue {TAIL_CALL_LABEL};"])
+
+    def _tail_call_loop(self, func: FunctionInstance, bindings: list[str], body_stmts: list[str], sites: set[tuple[int, int]]) -> str:
+        """Wrap a function body in the loop its self tail calls continue, rebinding the parameters at the top of each pass."""
+        borrowed = self._borrowed_parameter_indices(func)
+        bindings = [binding for i, binding in enumerate(bindings) if i not in borrowed]
+        trailing = "," if len(bindings) == 1 else ""
+        lines: list[str] = []
+        if bindings:
+            names = [binding.removeprefix("mut ") for binding in bindings]
+            lines.append(f"let mut {TAIL_CALL_ARGS} = ({', '.join(names)}{trailing});")
+        lines.append(f"{TAIL_CALL_LABEL}: loop {{")
+        if bindings:
+            lines.append(f"    let ({', '.join(bindings)}{trailing}) = {TAIL_CALL_ARGS};")
+        self._append_block_lines(lines, body_stmts, 1)
+        last = func.ctx.block().statement()[-1] if func.ctx.block().statement() else None
+        ends_on_tail_call = last is not None and last.expressionStatement() is not None and last.expressionStatement().getSourceInterval() in sites
+        if func.return_type == BaseType.VOID and not ends_on_tail_call:
+            lines.append("    return;")
+        lines.append("}")
+        return "\n".join(lines)
+
     def _render_decorator_bound_function_args(
         self,
         mangled_name: str,
@@ -2424,6 +3511,18 @@ class CodeGenVisitor(zincVisitor):
             arg_ctxs.append(arg.expression)
         return self._process_function_args(mangled_name, rendered, arg_ctxs)
 
+    def _parameter_symbol(self, func: FunctionInstance, name: str):
+        """Return the symbol a function specialization binds its parameter `name` to."""
+        prefix = f"{func.mangled_name}."
+        return next(
+            (
+                symbol
+                for symbol in self.symbols.all_symbols()
+                if symbol.kind == SymbolKind.PARAMETER and symbol.id == name and symbol.unique_name.startswith(prefix)
+            ),
+            None,
+        )
+
     def _generate_decorated_function_wrapper(self, func: FunctionInstance) -> str:
         """Generate the public wrapper that applies a function's decorator chain."""
         self._current_function = func.mangled_name
@@ -2435,13 +3534,13 @@ class CodeGenVisitor(zincVisitor):
         param_names: list[str] = []
         for i, param in enumerate(function_parameters(func.ctx)):
             param_name = param.name
-            param_names.append(param_name)
+            param_names.append(self._rust_ident(param_name))
             if i < len(func.arg_types):
                 if i in func.arg_channel_infos and func.arg_channel_infos[i]:
                     self._current_channel_params.add(param_name)
-                params.append(f"{param_name}: {self._function_param_rust_type(func, i)}")
+                params.append(f"{self._rust_ident(param_name)}: {self._function_param_rust_type(func, i)}")
             else:
-                params.append(param_name)
+                params.append(self._rust_ident(param_name))
             self._declared_vars.add(param_name)
 
         target_info = self._decorated_impl_callable_info(func)
@@ -2479,7 +3578,7 @@ class CodeGenVisitor(zincVisitor):
         if func.return_type == BaseType.VOID:
             lines.append(f"    {final_call};")
         else:
-            lines.append(f"    return {final_call};")
+            lines.append(f"    {final_call}")
         lines.append("}")
         return "\n".join(lines)
 
@@ -2489,10 +3588,37 @@ class CodeGenVisitor(zincVisitor):
         return [
             f"if let Err(err) = {call} {{",
             '    eprintln!("{}", err);',
+            *(f"    {hook}" for hook in self._before_exit()),
             "    std::process::exit(1);",
             "}",
         ]
 
+    def _with_tail_expression(self, body_stmts: list[str]) -> list[str]:
+        """Turn a function body's final `return value;` into the tail expression `value`.
+
+        A bare trailing `return;` is dropped. Returns whose value holds a lock or
+        `RefCell` guard stay explicit, since a tail expression's temporaries
+        outlive the locals they borrow.
+        """
+        if not body_stmts:
+            return body_stmts
+        lines = body_stmts[-1].split("\n")
+        start = next((i for i in range(len(lines) - 1, -1, -1) if lines[i].startswith("return ") or lines[i] == "return;"), None)
+        if start is None or not lines[-1].endswith(";"):
+            return body_stmts
+        if any(line and not line[0].isspace() and line[0] not in ")]}" for line in lines[start + 1 :]):
+            return body_stmts
+        if lines[start] == "return;":
+            if start != len(lines) - 1:
+                return body_stmts
+            tail: list[str] = []
+        else:
+            value = "\n".join([lines[start].removeprefix("return "), *lines[start + 1 :]])[:-1]
+            if any(guard in value for guard in TEMPORARY_GUARD_CALLS):
+                return body_stmts
+            tail = value.split("\n")
+        return [*body_stmts[:-1], "\n".join([*lines[:start], *tail])]
+
     def _generate_function_body(self, func: FunctionInstance) -> list[str]:
         """Generate statements for a function body."""
         ctx: ZincParser.FunctionDeclarationContext = func.ctx
@@ -2602,6 +3728,12 @@ class CodeGenVisitor(zincVisitor):
             return False
         return is_string_literal(primary.literal().getText())
 
+    def _owned_string(self, value: str) -> str:
+        """Convert a rendered Zinc string literal to an owned `String`; interpolated ones already are."""
+        if value.startswith("format!(") or value.endswith(".to_string()"):
+            return value
+        return f"String::from({value})"
+
     def _looks_like_rust_string_literal(self, value: str) -> bool:
         """Return True when rendered Rust code is definitely a string literal."""
         return value.startswith('"') or value.startswith('r"') or bool(re.match(r"^r#+\"", value))
@@ -2724,7 +3856,7 @@ class CodeGenVisitor(zincVisitor):
         if self._spread_field_needs_clone(value_info.base_type):
             value = f"{value}.clone()"
         if expected_type == BaseType.FLOAT and value_info.base_type == BaseType.INTEGER:
-            return f"({value} as f64)"
+            return self._numeric_cast(value, None, "f64")
         return value
 
     def _render_bound_struct_field(
@@ -2743,7 +3875,7 @@ class CodeGenVisitor(zincVisitor):
             )
         value = self.visit(bound_field.expression)
         if expected_type == BaseType.FLOAT and bound_field.value_info.base_type == BaseType.INTEGER:
-            return f"({value} as f64)"
+            return self._numeric_cast(value, bound_field.expression, "f64")
         return value
 
     def _with_default_module(self, arg, render):
@@ -2796,6 +3928,8 @@ class CodeGenVisitor(zincVisitor):
             return text
         format_str = re.sub(r"\{[^}]+\}", "{}", inner)
         args = ", ".join(self._rewrite_interpolation_expr(expr) for expr in interpolations)
+        if format_str == "{}" and re.fullmatch(r"[^\W\d][\w.]*", args):
+            return f"{args}.to_string()"
         return f'format!("{format_str}", {args})'
 
     def _rewrite_interpolation_expr(self, expr: str) -> str:
@@ -2812,7 +3946,7 @@ class CodeGenVisitor(zincVisitor):
 
             parts = token.split(".")
             if len(parts) == 1 and token in self._declared_vars:
-                return token
+                return self._rust_ident(token)
 
             const_symbol = self.module_graph.resolve_const_path(self._current_module, parts)
             if const_symbol:
@@ -2828,9 +3962,14 @@ class CodeGenVisitor(zincVisitor):
                     return self._struct_rust_name(struct)
                 return self.module_graph.rust_base_name(struct_symbol.qualified_name)
 
+            static_field = self._static_field_target(parts)
+            if static_field is not None:
+                return self._static_field_expr(*static_field)
+
             static_target = self.module_graph.resolve_static_method_target(self._current_module, parts)
             if static_target:
                 owner_symbol, method_name = static_target
+                method_name = self._rust_ident(method_name)
                 if owner_symbol.kind == "struct":
                     struct = self.atlas.structs.get(owner_symbol.qualified_name)
                     if struct:
@@ -2850,9 +3989,9 @@ class CodeGenVisitor(zincVisitor):
             if enum_symbol:
                 return self._named_enum_rust_name(enum_symbol.qualified_name)
 
-            return token
+            return ".".join(part if part in keyword_like else self._rust_ident(part) for part in parts)
 
-        return re.sub(r"\b[A-Za-z_][A-Za-z0-9_]*(?:\.[A-Za-z_][A-Za-z0-9_]*)*\b", replace, expr)
+        return re.sub(r"\b[^\W\d]\w*(?:\.[^\W\d]\w*)*\b", replace, expr)
 
     def visitPrimaryExpression(self, ctx: ZincParser.PrimaryExpressionContext) -> str:
         """Visit a primary expression."""
@@ -2904,7 +4043,7 @@ class CodeGenVisitor(zincVisitor):
                     if const:
                         return self._const_expr(const)
                     return self.module_graph.rust_base_name(const_symbol.qualified_name).upper()
-            return name
+            return self._rust_ident(name)
         if ctx.arrayLiteral():
             return self.visit(ctx.arrayLiteral())
         if ctx.collectionLiteral():
@@ -2937,7 +4076,7 @@ class CodeGenVisitor(zincVisitor):
                 field_info = field_info_map.get(field_name)
                 if field_info is not None:
                     field_value = self._coerce_owned(field_value, field_info.resolved_type, expr_ctx)
-                fields.append(f"{field_name}: {field_value}")
+                fields.append(self._field_init(field_name, field_value))
             return f"{struct_name} {{ {', '.join(fields)} }}"
         spread_setup, spread_temps = self._prepare_spread_temps(bound_fields, "field_spread")
         self._spread_temp_stack.append(spread_temps)
@@ -2952,7 +4091,7 @@ class CodeGenVisitor(zincVisitor):
                     field_info.resolved_type,
                     None if bound_field.spread_source_expr is not None else bound_field.expression,
                 )
-            fields.append(f"{field_name}: {field_value}")
+            fields.append(self._field_init(field_name, field_value))
         self._spread_temp_stack.pop()
         return self._wrap_spread_temps(f"{struct_name} {{ {', '.join(fields)} }}", spread_setup)
 
@@ -3027,8 +4166,10 @@ class CodeGenVisitor(zincVisitor):
 
     def visitAdditiveExpr(self, ctx: ZincParser.AdditiveExprContext) -> str:
         """Visit addition/subtraction expression."""
-        left = self.visit(ctx.expression(0))
         op = ctx.getChild(1).getText()
+        if op == "+" and self._get_expr_type(ctx) == BaseType.STRING and self._operator_call_for_ctx(ctx) is None:
+            return self._render_string_concat(ctx)
+        left = self.visit(ctx.expression(0))
         right = self.visit(ctx.expression(1))
         call = self._operator_call_for_ctx(ctx)
         if call is not None:
@@ -3040,7 +4181,49 @@ class CodeGenVisitor(zincVisitor):
             ctx.expression(1),
         )
 
-        return f"({left} {op} {right})"
+        return self._render_integer_arithmetic(op, left, right, ctx) or f"({left} {op} {right})"
+
+    def _render_string_concat(self, ctx: ZincParser.AdditiveExprContext) -> str:
+        """Render `a + b + c` on strings as one `format!`, which allocates the result once."""
+        pieces = []
+        while isinstance(ctx, ZincParser.AdditiveExprContext) and ctx.getChild(1).getText() == "+":
+            pieces.append(ctx.expression(1))
+            ctx = ctx.expression(0)
+        pieces.append(ctx)
+        format_str = ""
+        args = []
+        for piece in reversed(pieces):
+            value = self.visit(piece)
+            if value.startswith('"'):
+                format_str += value[1:-1].replace("{", "{{").replace("}", "}}")
+            else:
+                format_str += "{}"
+                args.append(value)
+        return f'format!("{format_str}"{"".join(f", {arg}" for arg in args)})'
+
+    def _render_string_append(self, target: str, name: str, pieces: list) -> str | None:
+        """Render `name = name + a + b` or `name += a` as `push_str` calls, or None when a piece reads `name` itself."""
+        if any(re.search(rf"\b{re.escape(name)}\b", piece.getText()) for piece in pieces):
+            return None
+        return "\n".join(self._render_push_str(target, self.visit(piece)) for piece in pieces)
+
+    def _render_push_str(self, target: str, value: str) -> str:
+        """Render appending one rendered string value to `target`."""
+        return f"{target}.push_str({value if self._looks_like_rust_string_literal(value) else f'&{value}'});"
+
+    def _is_appended_string(self, symbol) -> bool:
+        """Return True when the string binding a variable, reassignment, or closure write resolves to is grown in place."""
+        if symbol is None or symbol.resolved_type != BaseType.STRING:
+            return False
+        while symbol is not None and symbol.binding_unique_name:
+            symbol = self.symbols.lookup_by_unique_name(symbol.binding_unique_name)
+        return symbol is not None and symbol.is_appended
+
+    def _owned_string_value(self, value: str) -> str:
+        """Convert any rendered string value to a `String` of its own, copying one that is already owned elsewhere."""
+        if self._looks_like_rust_string_literal(value) or value.startswith("format!(") or value.endswith(".to_string()"):
+            return self._owned_string(value)
+        return f"{value}.to_string()"
 
     def _render_bitwise_binary_expr(self, ctx) -> str:
         """Render integer bitwise AND, OR, and XOR."""
@@ -3092,7 +4275,7 @@ class CodeGenVisitor(zincVisitor):
             ctx.expression(1),
         )
 
-        return f"({left} {op} {right})"
+        return self._render_integer_arithmetic(op, left, right, ctx) or f"({left} {op} {right})"
 
     def visitPowerExpr(self, ctx: ZincParser.PowerExprContext) -> str:
         """Visit exponentiation expression."""
@@ -3130,17 +4313,105 @@ class CodeGenVisitor(zincVisitor):
             return symbol.exact_type
         return None
 
+    def _numeric_cast(self, value: str, value_ctx, target: str, *, argument: bool = False) -> str:
+        """Convert a rendered number to the Rust type `target`, casting only when its type differs.
+
+        Unsuffixed literals are rewritten in the target's form (`2` becomes `2.0` for floats) and
+        left for Rust to infer, so only values whose resolved type really differs get an `as` cast.
+        Casts rendered as call arguments (`argument=True`) skip the outer parentheses.
+        """
+        literal = self._numeric_literal_as(value, target)
+        if literal is not None:
+            return literal
+        if value_ctx is not None:
+            value_type = self._get_expr_type(value_ctx)
+            value_exact = exact_type_to_rust(self._get_expr_exact_type(value_ctx), value_type)
+            if value_type in (BaseType.INTEGER, BaseType.FLOAT) and value_exact == target:
+                return value
+        cast = f"{self._cast_operand(value)} as {target}"
+        return cast if argument else f"({cast})"
+
+    def _numeric_literal_as(self, value: str, target: str) -> str | None:
+        """Rewrite a rendered numeric literal as a literal of `target`, or return None when it needs a cast."""
+        text = value
+        while text.startswith("(") and self._cast_operand(text) == text and text.endswith(")"):
+            text = text[1:-1]
+        sign = "-" if text.startswith("-") else ""
+        digits = text[len(sign) :]
+        if not is_numeric_literal(digits):
+            return None
+        target_base = exact_type_to_base(target)
+        if not is_unsuffixed_numeric_literal(digits):
+            return value if digits.endswith(target) else None
+        literal_base = parse_numeric_literal(digits).base_type
+        if literal_base == target_base:
+            return value
+        if literal_base == BaseType.INTEGER and target_base == BaseType.FLOAT and digits.replace("_", "").isdigit():
+            return f"({sign}{digits}.0)" if sign else f"{digits}.0"
+        return None
+
+    def _render_integer_arithmetic(self, op: str, left: str, right: str, result_ctx) -> str | None:
+        """Render `+ - * / %` on integers as a wrapping or saturating call, or None when the plain operator applies."""
+        if self._overflow == "checked" or self._get_expr_type(result_ctx) != BaseType.INTEGER:
+            return None
+        exact_type = exact_type_to_rust(self._get_expr_exact_type(result_ctx), BaseType.INTEGER)
+        return f"{self._method_receiver(left, exact_type)}.{self._overflow_method(OVERFLOW_METHODS[op])}({right})"
+
+    def _overflow_method(self, operation: str) -> str:
+        """The integer method for `operation` under the overflow mode, e.g. `wrapping_add` or plain `pow`."""
+        if self._overflow == "checked":
+            return operation
+        if operation == "rem":
+            return "wrapping_rem"
+        return f"{self._overflow}_{operation}"
+
+    def _method_receiver(self, value: str, exact_type: str) -> str:
+        """Render the receiver of a numeric method such as `pow`, suffixing bare literals so Rust knows their type."""
+        text = value[1:-1] if value.startswith("(") and self._cast_operand(value) == value and value.endswith(")") else value
+        sign = "-" if text.startswith("-") else ""
+        digits = text[len(sign) :]
+        if is_numeric_literal(digits) and is_unsuffixed_numeric_literal(digits):
+            return f"({sign}{digits}_{exact_type})" if sign else f"{digits}_{exact_type}"
+        return self._cast_operand(value)
+
+    def _cast_operand(self, value: str) -> str:
+        """Parenthesize a rendered expression unless it can be the left side of `as` on its own."""
+        depth = 0
+        for index, char in enumerate(value):
+            if char in "([{":
+                depth += 1
+            elif char in ")]}":
+                depth -= 1
+                if depth == 0 and index < len(value) - 1 and value[0] == "(" and value[index + 1] not in ".[(":
+                    return f"({value})"
+            elif depth == 0 and (char.isspace() or (char in "+-*/%<>=!&|^?" and index == 0)):
+                return f"({value})"
+        return value
+
     def _promote_numeric_operands(self, left: str, left_ctx, right: str, right_ctx) -> tuple[str, str]:
-        """Cast mixed int/float operands to the float operand's exact type."""
+        """Cast mixed int/float operands to the float operand's exact type, and mixed integer widths to the wider one."""
         left_type = self._get_expr_type(left_ctx)
         right_type = self._get_expr_type(right_ctx)
 
+        if left_type == BaseType.INTEGER and right_type == BaseType.INTEGER:
+            left_exact = normalize_exact_type(self._get_expr_exact_type(left_ctx))
+            right_exact = normalize_exact_type(self._get_expr_exact_type(right_ctx))
+            if left_exact is None or right_exact is None or left_exact == right_exact:
+                return left, right
+            target = promote_exact_numeric(left_exact, right_exact, BaseType.INTEGER)
+            # Bare literals take whatever width Rust infers from the other operand.
+            if left_exact != target and not self._is_unsuffixed_integer_literal(left_ctx):
+                left = self._numeric_cast(left, left_ctx, target)
+            if right_exact != target and not self._is_unsuffixed_integer_literal(right_ctx):
+                right = self._numeric_cast(right, right_ctx, target)
+            return left, right
+
         if left_type == BaseType.INTEGER and right_type == BaseType.FLOAT:
             float_exact = exact_type_to_rust(self._get_expr_exact_type(right_ctx), BaseType.FLOAT)
-            return f"({left} as {float_exact})", right
+            return self._numeric_cast(left, left_ctx, float_exact), right
         if left_type == BaseType.FLOAT and right_type == BaseType.INTEGER:
             float_exact = exact_type_to_rust(self._get_expr_exact_type(left_ctx), BaseType.FLOAT)
-            return left, f"({right} as {float_exact})"
+            return left, self._numeric_cast(right, right_ctx, float_exact)
         return left, right
 
     def _coerce_numeric_rhs_for_target(self, value: str, value_ctx, target_type: BaseType, target_exact_type: str | None) -> str:
@@ -3148,7 +4419,7 @@ class CodeGenVisitor(zincVisitor):
         value_type = self._get_expr_type(value_ctx)
         if target_type == BaseType.FLOAT and value_type == BaseType.INTEGER:
             float_exact = exact_type_to_rust(target_exact_type, BaseType.FLOAT)
-            return f"({value} as {float_exact})"
+            return self._numeric_cast(value, value_ctx, float_exact)
         return value
 
     def _coerce_bitwise_operand(self, value: str, value_ctx, target_exact_type: str | None) -> str:
@@ -3158,7 +4429,7 @@ class CodeGenVisitor(zincVisitor):
         if target_exact is None or value_exact == target_exact:
             return value
         if self._get_expr_type(value_ctx) == BaseType.INTEGER and self._is_compile_time_literal_expr(value_ctx):
-            return f"({value} as {target_exact})"
+            return self._numeric_cast(value, value_ctx, target_exact)
         return value
 
     def _render_power_expr(self, left: str, left_ctx, right: str, right_ctx, result_ctx) -> str:
@@ -3169,12 +4440,13 @@ class CodeGenVisitor(zincVisitor):
             left_type = self._get_expr_type(left_ctx)
             right_type = self._get_expr_type(right_ctx)
             if left_type == BaseType.INTEGER:
-                left = f"({left} as {float_exact})"
+                left = self._numeric_cast(left, left_ctx, float_exact)
             if right_type == BaseType.INTEGER:
-                right = f"({right} as {float_exact})"
-            return f"({left}).powf({right})"
+                right = self._numeric_cast(right, right_ctx, float_exact)
+            return f"{self._method_receiver(left, float_exact)}.powf({right})"
         int_exact = exact_type_to_rust(self._get_expr_exact_type(result_ctx), BaseType.INTEGER)
-        return f"({left} as {int_exact}).pow(({right}) as u32)"
+        receiver = self._method_receiver(self._numeric_cast(left, left_ctx, int_exact), int_exact)
+        return f"{receiver}.{self._overflow_method('pow')}({self._numeric_cast(right, right_ctx, 'u32', argument=True)})"
 
     def _operator_call_for_ctx(self, ctx) -> ResolvedOperatorCall | None:
         """Return a resolved overloaded operator call for a parse context."""
@@ -3279,11 +4551,11 @@ class CodeGenVisitor(zincVisitor):
         """Convert a rendered value into the owned Rust type used inside collections."""
         if target_type == BaseType.STRING:
             if self._expr_is_string_literal(value_ctx) or self._looks_like_rust_string_literal(value):
-                return f"String::from({value})"
+                return self._owned_string(value)
             return value
         if target_type == BaseType.FLOAT and value_ctx is not None:
             if self._get_expr_type(value_ctx) == BaseType.INTEGER:
-                return f"({value} as f64)"
+                return self._numeric_cast(value, value_ctx, "f64")
         return value
 
     def _coerce_to_value_spec(self, value: str, spec: ValueTypeSpec | None, value_ctx=None) -> str:
@@ -3363,11 +4635,11 @@ class CodeGenVisitor(zincVisitor):
         """Render a local binding declaration or reassignment for one identifier."""
         if symbol is None:
             self._declared_vars.add(name)
-            return f"let {name} = {value};"
+            return f"let {self._rust_ident(name)} = {value};"
 
         captured_target = self._symbol_is_captured_cell(symbol)
         storage_name = self._symbol_storage_unique_name(symbol) if captured_target else None
-        rendered_target = self._rust_binding_name(storage_name) if storage_name is not None else name
+        rendered_target = self._rust_binding_name(storage_name) if storage_name is not None else self._rust_ident(name)
         if captured_target and (symbol.is_captured_ref or not (symbol.is_shadow or name not in self._declared_vars)):
             return f"*{rendered_target}.lock().unwrap() = {value};"
 
@@ -3377,12 +4649,22 @@ class CodeGenVisitor(zincVisitor):
             mut_prefix = "mut " if needs_mut else ""
             if storage_name in self._captured_binding_names:
                 value = f"Arc::new(Mutex::new({value}))"
-            if include_type:
+            if include_type or self._needs_integer_annotation(symbol, value):
                 return f"let {mut_prefix}{rendered_target}: {self._symbol_rust_type(symbol)} = {value};"
             return f"let {mut_prefix}{rendered_target} = {value};"
 
         return f"{rendered_target} = {value};"
 
+    def _needs_integer_annotation(self, symbol, value: str) -> bool:
+        """Whether a binding initialized from a bare integer literal needs its type spelled out.
+
+        Rust falls back to `i32` for an unconstrained literal, which would overflow where Zinc's integer doesn't.
+        """
+        if symbol.resolved_type != BaseType.INTEGER or self._symbol_rust_type(symbol) == "i32":
+            return False
+        digits = value.removeprefix("(").removesuffix(")").removeprefix("-")
+        return is_numeric_literal(digits) and is_unsuffixed_numeric_literal(digits)
+
     def _render_broadcast_assignment(self, ctx, target_tuple_ctx, expr_ctx, value: str, *, include_type: bool = False) -> str:
         """Render x, y, z = expr by evaluating expr once and assigning clones in order."""
         tokens = list(target_tuple_ctx.getTokens(ZincParser.IDENTIFIER))
@@ -3406,6 +4688,13 @@ class CodeGenVisitor(zincVisitor):
         call = self._operator_call_for_ctx(ctx)
         if call is not None:
             return self._render_resolved_operator_call(call, [operand])
+        if (
+            op == "-"
+            and self._overflow != "checked"
+            and self._get_expr_type(ctx) == BaseType.INTEGER
+            and not is_numeric_literal(operand.strip("()"))
+        ):
+            return f"{self._cast_operand(operand)}.{self._overflow_method('neg')}()"
         return f"({op}{operand})"
 
     def visitRelationalExpr(self, ctx: ZincParser.RelationalExprContext) -> str:
@@ -3485,8 +4774,17 @@ class CodeGenVisitor(zincVisitor):
 
     def visitArrayLiteral(self, ctx: ZincParser.ArrayLiteralContext) -> str:
         """Visit array literal."""
-        elements = [self.visit(expr) for expr in ctx.expression()]
-        return f"vec![{', '.join(elements)}]"
+        elements = [f"{self.visit(expr)}.clone()" if self._row_needs_copy(expr) else self.visit(expr) for expr in ctx.expression()]
+        symbol = self._get_expr_symbol(ctx)
+        if symbol is None or symbol.array_length is None:
+            return f"vec![{', '.join(elements)}]"
+        expressions = zip(elements, ctx.expression(), strict=True)
+        elements = [self._coerce_owned(element, symbol.element_type, expr) for element, expr in expressions]
+        if array_length(ctx) is None:
+            return f"[{', '.join(elements)}]"
+        if symbol.element_type in {BaseType.INTEGER, BaseType.FLOAT, BaseType.BOOLEAN}:
+            return f"[{elements[0]}; {symbol.array_length}]"
+        return f"{{ let __fill = {elements[0]}; std::array::from_fn::<_, {symbol.array_length}, _>(|_| __fill.clone()) }}"
 
     def visitCollectionLiteral(self, ctx: ZincParser.CollectionLiteralContext) -> str:
         """Visit dict/set literal."""
@@ -3561,20 +4859,34 @@ class CodeGenVisitor(zincVisitor):
                 return value
 
         index_ctx = ctx.expression(1)
+        if self.symbols.is_checked_index(ctx.getSourceInterval(), self._current_function):
+            elements = f"{captured_collection_name}.lock().unwrap()" if captured_collection_name is not None else collection
+            message = f"index out of bounds: the len is {receiver_symbol.array_length} but the index is {{}}"
+            return (
+                f"{{ let __index = {index}; usize::try_from(__index).ok().and_then(|__i| {elements}.get(__i)).cloned()"
+                f'.ok_or_else(|| format!("{message}", __index)) }}'
+            )
         # Cast non-literal integer indices to usize (Rust Vec indexing requires usize)
         index_type = self._get_expr_type(index_ctx)
         if index_type == BaseType.INTEGER and not self._is_integer_literal(index_ctx):
             index = f"({index} as usize)"
         if captured_collection_name is not None:
             result_type = self._get_expr_type(ctx)
-            if result_type == BaseType.ENUM:
+            if result_type == BaseType.ENUM or (result_type == BaseType.ARRAY and not self._reads_row_in_place(ctx)):
                 return f"{captured_collection_name}.lock().unwrap()[{index}].clone()"
             return f"{captured_collection_name}.lock().unwrap()[{index}]"
         result_type = self._get_expr_type(ctx)
-        if result_type == BaseType.ENUM:
+        if result_type == BaseType.ENUM or (result_type == BaseType.ARRAY and not self._reads_row_in_place(ctx)):
             return f"{collection}[{index}].clone()"
         return f"{collection}[{index}]"
 
+    def _reads_row_in_place(self, ctx: ZincParser.IndexAccessExprContext) -> bool:
+        """Return True when a row of a nested list is indexed or called on where it lives, rather than copied out."""
+        parent = ctx.parentCtx
+        if isinstance(parent, (ZincParser.IndexAccessExprContext, ZincParser.IndexAccessContext)):
+            return parent.expression(0) is ctx
+        return isinstance(parent, ZincParser.MemberAccessExprContext)
+
     def _is_integer_literal(self, ctx) -> bool:
         """Return True if expression is a bare integer literal (e.g. 0, 1, 2)."""
         if isinstance(ctx, ZincParser.PrimaryExprContext):
@@ -3584,6 +4896,10 @@ class CodeGenVisitor(zincVisitor):
                 return bool(lit_text) and primary.literal().INTEGER() is not None
         return False
 
+    def _is_unsuffixed_integer_literal(self, ctx) -> bool:
+        """Return True for an integer literal whose width Rust infers from context."""
+        return self._is_integer_literal(ctx) and is_unsuffixed_numeric_literal(ctx.getText())
+
     def visitRangeExpr(self, ctx: ZincParser.RangeExprContext) -> str:
         """Visit range expression."""
         start = self.visit(ctx.expression(0))
@@ -3621,9 +4937,14 @@ class CodeGenVisitor(zincVisitor):
                         return self._const_expr(const)
                     return self.module_graph.rust_base_name(const_symbol.qualified_name).upper()
 
+                static_field = self._static_field_target(path)
+                if static_field is not None:
+                    return self._static_field_expr(*static_field)
+
                 static_target = self.module_graph.resolve_static_method_target(self._current_module, path)
                 if static_target:
                     owner_symbol, method_name = static_target
+                    method_name = self._rust_ident(method_name)
                     if owner_symbol.kind == "struct":
                         struct = self.atlas.structs.get(owner_symbol.qualified_name)
                         if struct:
@@ -3665,7 +4986,7 @@ class CodeGenVisitor(zincVisitor):
                 if receiver_symbol and self._symbol_is_captured_cell(receiver_symbol):
                     storage_name = self._symbol_storage_unique_name(receiver_symbol)
                     if storage_name is not None:
-                        field_expr = f"{self._rust_binding_name(storage_name)}.lock().unwrap().{ctx.IDENTIFIER().getText()}"
+                        field_expr = f"{self._rust_binding_name(storage_name)}.lock().unwrap().{self._rust_ident(ctx.IDENTIFIER().getText())}"
                         expr_type = self._get_expr_type(ctx)
                         if expr_type in {
                             BaseType.STRING,
@@ -3679,7 +5000,7 @@ class CodeGenVisitor(zincVisitor):
                             return f"{field_expr}.clone()"
                         return field_expr
                 if self._boxed_struct_key(receiver_name) in self._boxed_struct_vars:
-                    field_expr = f"{receiver_name}.borrow().{ctx.IDENTIFIER().getText()}"
+                    field_expr = f"{self._rust_ident(receiver_name)}.borrow().{self._rust_ident(ctx.IDENTIFIER().getText())}"
                     expr_type = self._get_expr_type(ctx)
                     if expr_type in {
                         BaseType.STRING,
@@ -3694,7 +5015,28 @@ class CodeGenVisitor(zincVisitor):
                     return field_expr
         # Regular member access (field or instance method)
         obj = self.visit(ctx.expression())
-        return f"{obj}.{ctx.IDENTIFIER().getText()}"
+        return f"{obj}.{self._rust_ident(ctx.IDENTIFIER().getText())}"
+
+    def _constructor_call_target(self, path: list[str]) -> StructInstance | None:
+        """Return the struct built by `Name(args...)` constructor shorthand, if the call is one."""
+        if len(path) == 1 and path[0] in self._declared_vars:
+            return None
+        if self.module_graph.resolve_function_path(self._current_module, path) is not None:
+            return None
+        struct_symbol = self.module_graph.resolve_struct_path(self._current_module, path)
+        return self.atlas.structs.get(struct_symbol.qualified_name) if struct_symbol else None
+
+    def _render_constructor_call(self, ctx, struct: StructInstance, call_args: list) -> str:
+        """Render `Name(args...)` as `Name::new(args...)`, or as a struct literal when there is no static `new`."""
+        constructor = next((method for method in struct.methods if method.name == "new" and method.is_static), None)
+        if constructor is None:
+            expr_symbol = self._get_expr_symbol(ctx)
+            concrete_anonymous_struct = expr_symbol.anonymous_struct_info if expr_symbol else None
+            name = concrete_anonymous_struct.rust_type_name() if concrete_anonymous_struct else self._struct_rust_name(struct)
+            return self._render_bound_struct_literal(name, struct, self._struct_fields_for_ctx(ctx), concrete_anonymous_struct)
+        arg_ctxs = [self._call_arg_expr(arg) for arg in call_args]
+        args = self._process_method_args(struct, "new", [self._visit_call_arg(arg) for arg in call_args], arg_ctxs)
+        return f"{self._struct_rust_name(struct)}::new({', '.join(args)})"
 
     def visitFunctionCallExpr(self, ctx: ZincParser.FunctionCallExprContext) -> str:
         """Visit function call, handling static and instance method calls."""
@@ -3710,6 +5052,11 @@ class CodeGenVisitor(zincVisitor):
             self._spread_temp_stack.pop()
             return self._wrap_spread_temps(value, spread_setup)
 
+        path = extract_identifier_path(callee_ctx) if self._current_module is not None else None
+        constructor_struct = self._constructor_call_target(path) if path else None
+        if constructor_struct is not None:
+            return finish(self._render_constructor_call(ctx, constructor_struct, call_args))
+
         arg_ctxs = [self._call_arg_expr(arg) for arg in call_args]
         args = [self._visit_call_arg(arg) for arg in call_args]
 
@@ -3717,7 +5064,6 @@ class CodeGenVisitor(zincVisitor):
             channel_arg = args[0] if args else "__zinc_missing_close_arg"
             return finish(f"{channel_arg}.close()")
 
-        path = extract_identifier_path(callee_ctx) if self._current_module is not None else None
         if path == ["Context", "background"]:
             self._require_runtime_symbol("Context")
             return finish("Context::background()")
@@ -3825,6 +5171,53 @@ class CodeGenVisitor(zincVisitor):
         if callee == "print":
             return finish(self._render_print_call(args, arg_ctxs))
 
+        if callee == "assert":
+            return finish(self._render_assert_call(ctx, args, arg_ctxs))
+
+        if callee == "assert_eq":
+            return finish(self._render_assert_eq_call(ctx, args, arg_ctxs))
+
+        if callee == "print_debug":
+            return finish(f'println!("{{:#?}}", {args[0]})')
+
+        if callee == "dbg":
+            location = self._format_string_text(f"[{self._source_location(ctx)}] {self._source_text(arg_ctxs[0])}")
+            return finish(f'eprintln!("{location} = {{:#?}}", {args[0]})')
+
+        if callee == "uuid":
+            self._require_runtime_symbol("uuid_v4")
+            return finish("uuid_v4()")
+
+        if callee == "to_json":
+            self._require_runtime_symbol("to_json")
+            return finish(f"to_json(&{args[0]})")
+
+        if callee == "format_number":
+            self._require_runtime_symbol("format_number")
+            spec = number_format_spec(arg_ctxs[1])
+            return finish(f'format_number({self._numeric_cast(args[0], arg_ctxs[0], "f64", argument=True)}, "{spec}")')
+
+        if callee == "matrix":
+            rows, cols = (self._matrix_size(size, size_ctx) for size, size_ctx in zip(args[:2], arg_ctxs[:2], strict=True))
+            return finish(f"vec![vec![{args[2]}; {cols}]; {rows}]")
+
+        if callee == "include_str":
+            # Cargo builds the generated Rust elsewhere, so the path is absolute.
+            path = included_file(self.module_graph.path_for_module(self._current_module), arg_ctxs[0])
+            return finish(f"String::from(include_str!({to_rust_raw_string(str(path))}))")
+
+        if callee in ("temp_file", "temp_dir"):
+            return finish(f"{self._temp_path_guard_name(ctx)}.path()")
+
+        if callee == "on_interrupt":
+            return finish(self._render_on_interrupt_call(args[0]))
+
+        if callee == "exit":
+            code = self._numeric_cast(args[0], arg_ctxs[0], "i32", argument=True) if args else "0"
+            if hooks := self._before_exit():
+                return finish(f"{{ {' '.join(hooks)} std::process::exit({code}) }}")
+            return finish(f"std::process::exit({code})")
+
         if callee in {"dict", "sort_dict"}:
             info = self._expected_dict_info or self._get_dict_info(ctx) or DictTypeInfo(kind=callee)
             collection_type = info.rust_container()
@@ -3937,7 +5330,7 @@ class CodeGenVisitor(zincVisitor):
                 dict_target = f"{captured_receiver_name}.lock().unwrap()" if captured_receiver_name else target
                 info = self._get_dict_info(target_ctx) or DictTypeInfo()
                 if method_name == "len":
-                    return finish(f"({dict_target}.len() as i64)")
+                    return finish(f"({dict_target}.len() as {default_exact_type(BaseType.INTEGER)})")
                 if method_name == "is_empty":
                     return finish(f"{dict_target}.is_empty()")
                 if method_name == "clear":
@@ -3965,7 +5358,7 @@ class CodeGenVisitor(zincVisitor):
                 set_target = f"{captured_receiver_name}.lock().unwrap()" if captured_receiver_name else target
                 info = self._get_set_info(target_ctx) or SetTypeInfo()
                 if method_name == "len":
-                    return finish(f"({set_target}.len() as i64)")
+                    return finish(f"({set_target}.len() as {default_exact_type(BaseType.INTEGER)})")
                 if method_name == "is_empty":
                     return finish(f"{set_target}.is_empty()")
                 if method_name == "clear":
@@ -3979,6 +5372,28 @@ class CodeGenVisitor(zincVisitor):
                         return finish(f"{{ {set_target}.remove({elem}); () }}")
                     return finish(f"{set_target}.{method_name}({elem})")
 
+            if receiver_type == BaseType.ARRAY and method_name in LIST_METHODS:
+                target = self.visit(target_ctx)
+                list_target = f"{captured_receiver_name}.lock().unwrap()" if captured_receiver_name else target
+                list_symbol = self._get_expr_symbol(target_ctx) or self._fallback_symbol_for_ctx(target_ctx)
+                element_type = list_symbol.element_type if list_symbol else None
+                if method_name in {"pop", "reverse"}:
+                    return finish(f"{list_target}.{method_name}()")
+                if method_name == "remove":
+                    return finish(f"{list_target}.remove({self._render_usize_index(arg_ctxs[0], args[0])})")
+                if method_name == "insert":
+                    value = args[1] if element_type == BaseType.STRING else self._coerce_owned(args[1], element_type, arg_ctxs[1])
+                    if self._row_needs_copy(arg_ctxs[1]):
+                        value = f"{value}.clone()"
+                    return finish(f"{list_target}.insert({self._render_usize_index(arg_ctxs[0], args[0])}, {value})")
+                item = args[0] if element_type == BaseType.STRING else self._coerce_owned(args[0], element_type, arg_ctxs[0])
+                if method_name == "index_of":
+                    integer = default_exact_type(BaseType.INTEGER)
+                    return finish(f"{list_target}.iter().position(|__zinc_item| *__zinc_item == {item}).map(|index| index as {integer})")
+                if element_type == BaseType.STRING:
+                    return finish(f"{list_target}.iter().any(|__zinc_item| *__zinc_item == {item})")
+                return finish(f"{list_target}.contains(&{item})")
+
             if receiver_type == BaseType.ARRAY and method_name == "push" and len(args) == 1:
                 receiver_symbol = self._get_expr_symbol(target_ctx)
                 if isinstance(target_ctx, ZincParser.PrimaryExprContext):
@@ -3999,6 +5414,8 @@ class CodeGenVisitor(zincVisitor):
                         arg_symbol.callable_info,
                         receiver_symbol.callable_info,
                     )
+                if arg_ctx is not None and self._row_needs_copy(arg_ctx):
+                    args[0] = f"{args[0]}.clone()"
                 if captured_receiver_name is not None:
                     return finish(f"{captured_receiver_name}.lock().unwrap().push({args[0]})")
 
@@ -4016,18 +5433,18 @@ class CodeGenVisitor(zincVisitor):
                             if captured_receiver_name is not None and method:
                                 result = f"{captured_receiver_name}.lock().unwrap().{method_name}({', '.join(args)})"
                                 if method_name == "len":
-                                    return finish(f"({result} as i64)")
+                                    return finish(f"({result} as {default_exact_type(BaseType.INTEGER)})")
                                 return finish(result)
                             if self._boxed_struct_key(target_var) in self._boxed_struct_vars and method:
                                 borrow = "borrow_mut" if method.self_mutability == "&mut self" else "borrow"
                                 result = f"{target_var}.{borrow}().{method_name}({', '.join(args)})"
                                 if method_name == "len":
-                                    return finish(f"({result} as i64)")
+                                    return finish(f"({result} as {default_exact_type(BaseType.INTEGER)})")
                                 return finish(result)
             result = f"{callee}({', '.join(args)})"
-            # len() returns usize in Rust but Zinc treats all integers as i64
+            # len() returns usize in Rust but Zinc gives it the default integer type
             if method_name == "len":
-                return finish(f"({result} as i64)")
+                return finish(f"({result} as {default_exact_type(BaseType.INTEGER)})")
             return finish(result)
 
         # Look up mangled name from specialization map (scoped by current function)
@@ -4106,7 +5523,10 @@ class CodeGenVisitor(zincVisitor):
             )
             arg_ctx = arg_ctxs[i] if arg_ctxs and i < len(arg_ctxs) else self._call_arg_expr(arg)
             if expected_type == BaseType.STRING and (self._expr_is_string_literal(arg_ctx) or self._looks_like_rust_string_literal(value)):
-                value = f"String::from({value})"
+                value = self._owned_string(value)
+            elif param is not None and param.type_text is not None and param.type_text.startswith("["):
+                # Extern array parameters are borrowed, matching how Zinc functions take arrays.
+                value = f"&{value}"
             rendered.append(value)
         return rendered
 
@@ -4127,9 +5547,18 @@ class CodeGenVisitor(zincVisitor):
                 param_type = func.arg_types[i]
                 arg_ctx = arg_ctxs[i] if arg_ctxs and i < len(arg_ctxs) else None
 
+                # Hand on a parameter this function already holds by reference as is
+                if self._is_reference_parameter(arg_ctx) and self._function_param_rust_type(func, i).startswith("&"):
+                    processed.append(arg)
+                # Borrow strings and structs the callee only reads
+                elif i in func.arg_borrows:
+                    processed.append(self._borrowed_arg(arg, func.arg_borrows[i]))
                 # Convert string literal to String::from() for String parameters
-                if param_type == BaseType.STRING and (self._expr_is_string_literal(arg_ctx) or self._looks_like_rust_string_literal(arg)):
-                    processed.append(f"String::from({arg})")
+                elif param_type == BaseType.STRING and (self._expr_is_string_literal(arg_ctx) or self._looks_like_rust_string_literal(arg)):
+                    processed.append(self._owned_string(arg))
+                # Hand an owning callee its own copy of a string variable the caller still needs
+                elif param_type == BaseType.STRING and self._string_arg_needs_copy(arg_ctx):
+                    processed.append(f"{arg}.to_string()")
                 elif param_type == BaseType.CHANNEL and i in func.arg_channel_infos:
                     processed.append(f"{arg}.clone()")
                 # Pass arrays by reference
@@ -4169,6 +5598,60 @@ class CodeGenVisitor(zincVisitor):
 
         return processed
 
+    def _borrow_prefix(self, borrow: str) -> str:
+        """Return the Rust reference prefix for a borrow mode."""
+        return "&mut " if borrow == "&mut" else "&"
+
+    def _borrowed_arg(self, arg: str, borrow: str) -> str:
+        """Render an argument for a borrowed string or struct parameter."""
+        if borrow == "&" and self._looks_like_rust_string_literal(arg):
+            return arg
+        return f"{self._borrow_prefix(borrow)}{arg}"
+
+    def _is_reference_parameter(self, arg_ctx) -> bool:
+        """Return True when an argument names a parameter the current function receives as a Rust reference."""
+        if not isinstance(arg_ctx, ZincParser.PrimaryExprContext):
+            return False
+        primary = arg_ctx.primaryExpression()
+        if primary is None or primary.IDENTIFIER() is None or primary.getChildCount() != 1:
+            return False
+        name = primary.IDENTIFIER().getText()
+        symbol = self._lookup_local_symbol(name)
+        func = self.atlas.functions.get(self._current_function or "")
+        if symbol is None or symbol.kind != SymbolKind.PARAMETER or func is None:
+            return False
+        for index, param in enumerate(function_parameters(func.ctx)):
+            if param.name == name and index < len(func.arg_types):
+                return self._function_param_rust_type(func, index).startswith("&")
+        return False
+
+    def _string_arg_needs_copy(self, arg_ctx) -> bool:
+        """Return True when a string variable passed by value is a literal slice or is read again later."""
+        if not isinstance(arg_ctx, ZincParser.PrimaryExprContext):
+            return False
+        primary = arg_ctx.primaryExpression()
+        if primary is None or primary.IDENTIFIER() is None or primary.getChildCount() != 1:
+            return False
+        name = primary.IDENTIFIER().getText()
+        if name not in self._declared_vars:
+            return False
+        return is_assigned_string_literal(arg_ctx, name) or is_read_after(arg_ctx, name)
+
+    def _matrix_size(self, size: str, size_ctx) -> str:
+        """Render a matrix() row or column count as a usize, panicking on a negative count."""
+        if self._is_integer_literal(size_ctx):
+            return size
+        return f'usize::try_from({size}).expect("matrix() sizes can\'t be negative")'
+
+    def _row_needs_copy(self, value_ctx) -> bool:
+        """Return True when a list variable stored as a row of another list is read again later."""
+        if not isinstance(value_ctx, ZincParser.PrimaryExprContext) or self._get_expr_type(value_ctx) != BaseType.ARRAY:
+            return False
+        primary = value_ctx.primaryExpression()
+        if primary is None or primary.IDENTIFIER() is None or primary.getChildCount() != 1:
+            return False
+        return is_read_after(value_ctx, primary.IDENTIFIER().getText())
+
     def _process_method_args(
         self,
         struct: StructInstance | EnumInstance,
@@ -4194,7 +5677,7 @@ class CodeGenVisitor(zincVisitor):
                     and param_type.lower() == "string"
                     and (self._expr_is_string_literal(arg_ctx) or self._looks_like_rust_string_literal(arg))
                 ):
-                    processed.append(f"String::from({arg})")
+                    processed.append(self._owned_string(arg))
                 # Apply integer narrowing for literals
                 elif param_type and param_type in ("i32", "i64"):
                     narrowed = self._apply_literal_narrowing(arg, param_type, arg_ctx)
@@ -4218,10 +5701,9 @@ class CodeGenVisitor(zincVisitor):
 
         # Check if the expression contains only compile-time known values
         if self._is_compile_time_literal_expr(arg_ctx):
-            # If target is i32 and we have an i64-inferred expression, cast it
-            if target_type == "i32":
-                # Wrap the expression with an explicit cast
-                return f"({arg}) as i32"
+            # Literal expressions are inferred at the default width; cast them when the parameter is narrower or wider.
+            if target_type != default_exact_type(BaseType.INTEGER):
+                return self._numeric_cast(arg, arg_ctx, target_type, argument=True)
 
         return arg
 
@@ -4315,11 +5797,15 @@ class CodeGenVisitor(zincVisitor):
         """Render a print() call as println!()."""
         if not args:
             return "println!()"
+        if len(args) > 1:
+            return self._render_format_print_call(arg_ctxs[0], args[1:])
         arg = args[0]
         arg_ctx = arg_ctxs[0] if arg_ctxs else None
         arg_symbol = self._get_expr_symbol(arg_ctx) if arg_ctx is not None else None
         if arg_symbol and isinstance(arg_symbol.constant_value, (MetaValue, MetaListValue)):
             return f'println!("{{:?}}", {arg})'
+        if self._expr_is_string_literal(arg_ctx) and arg.endswith(".to_string()"):
+            return f'println!("{{}}", {arg.removesuffix(".to_string()")})'
         if arg.startswith("format!("):
             inner = arg[8:-1]
             return f"println!({inner})"
@@ -4333,6 +5819,74 @@ class CodeGenVisitor(zincVisitor):
             return f'println!("{inner}")'
         return f'println!("{{}}", {arg})'
 
+    def _render_format_print_call(self, format_ctx, values: list[str]) -> str:
+        """Render print("...", values...), filling each `{}` with the next value and interpolating `{expression}` as usual."""
+        inner = format_string_literal(format_ctx).getText()[1:-1]
+        remaining = iter(values)
+        format_args = [self._rewrite_interpolation_expr(expr) if expr else next(remaining) for expr in re.findall(r"\{([^}]*)\}", inner)]
+        format_str = re.sub(r"\{[^}]*\}", "{}", inner)
+        return f'println!("{format_str}", {", ".join(format_args)})'
+
+    def _source_text(self, ctx: ParserRuleContext) -> str:
+        """Return the original Zinc spelling of a parse node."""
+        return ctx.start.getInputStream().getText(ctx.start.start, ctx.stop.stop)
+
+    def _source_location(self, ctx: ParserRuleContext) -> str:
+        """Return a package-relative `module.zn:line` location for a parse node."""
+        line = ctx.start.line if ctx.start is not None else 0
+        return f"{self._current_module}.zn:{line}"
+
+    def _format_string_text(self, text: str) -> str:
+        """Escape plain text for use inside a Rust format string literal."""
+        escaped = text.replace("\\", "\\\\").replace('"', '\\"').replace("\n", "\\n")
+        return escaped.replace("{", "{{").replace("}", "}}")
+
+    def _render_assert_failure(self, headline: str, extra_format: str, extra_args: list[str]) -> list[str]:
+        """Render the stderr report and non-zero exit for a failed assertion."""
+        format_args = "".join(f", {arg}" for arg in extra_args)
+        return [
+            f'    eprintln!("{self._format_string_text(headline)}{extra_format}"{format_args});',
+            *(f"    {hook}" for hook in self._before_exit()),
+            "    std::process::exit(101);",
+        ]
+
+    def _render_assert_call(self, ctx, args: list[str], arg_ctxs: list) -> str:
+        """Render assert(cond, message?) as a checked branch that reports the failing expression."""
+        headline = f"assertion failed at {self._source_location(ctx)}: {self._source_text(arg_ctxs[0])}"
+        extra_format, extra_args = ("", []) if len(args) < 2 else (": {}", [args[1]])
+        return "\n".join(
+            [
+                f"if !({args[0]}) {{",
+                *self._render_assert_failure(headline, extra_format, extra_args),
+                "}",
+            ]
+        )
+
+    def _render_assert_eq_call(self, ctx, args: list[str], arg_ctxs: list) -> str:
+        """Render assert_eq(left, right, message?) reporting both operand values on failure."""
+        left, right = self._promote_numeric_operands(args[0], arg_ctxs[0], args[1], arg_ctxs[1])
+        headline = (
+            f"assertion failed at {self._source_location(ctx)}: "
+            f"{self._source_text(arg_ctxs[0])} == {self._source_text(arg_ctxs[1])}"
+        )
+        extra_format = "\\n  left: {:?}\\n right: {:?}"
+        extra_args = ["__zinc_left", "__zinc_right"]
+        if len(args) == 3:
+            extra_format = ": {}" + extra_format
+            extra_args = [args[2], *extra_args]
+        failure = self._render_assert_failure(headline, extra_format, extra_args)
+        return "\n".join(
+            [
+                f"match (&({left}), &({right})) {{",
+                "    (__zinc_left, __zinc_right) => {",
+                "        if !(*__zinc_left == *__zinc_right) {",
+                *[f"        {line}" for line in failure],
+                "        }",
+                "    }",
+                "}",
+            ]
+        )
+
     def visitChannelReceiveExpr(self, ctx: ZincParser.ChannelReceiveExprContext) -> str:
         """Visit channel receive expression."""
         chan_expr = ctx.expression()
@@ -4379,13 +5933,23 @@ class CodeGenVisitor(zincVisitor):
                         if f.rust_type() == "String" and (
                             self._expr_is_string_literal(expr_ctx) or self._looks_like_rust_string_literal(value)
                         ):
-                            value = f"String::from({value})"
-                        fields.append(f"{f.name}: {value}")
+                            value = self._owned_string(value)
+                        fields.append(self._field_init(f.name, value))
                     else:
-                        fields.append(f"{f.name}: {f.rust_default()}")
+                        fields.append(f"{self._rust_ident(f.name)}: {f.rust_default()}")
                 return f"{name} {{ {', '.join(fields)} }}"
-            return f"{name} {{ {', '.join(f'{field_name}: {value}' for field_name, (value, _expr) in raw_fields.items())} }}"
+            return f"{name} {{ {', '.join(self._field_init(field_name, value) for field_name, (value, _expr) in raw_fields.items())} }}"
+
+        return self._render_bound_struct_literal(name, struct, bound_fields, concrete_anonymous_struct)
 
+    def _render_bound_struct_literal(
+        self,
+        name: str,
+        struct: StructInstance | None,
+        bound_fields: list[BoundStructField],
+        concrete_anonymous_struct: AnonymousStructTypeInfo | None,
+    ) -> str:
+        """Render a struct literal from bound fields, filling the rest with field defaults."""
         spread_setup, spread_temps = self._prepare_spread_temps(bound_fields, "field_spread")
         self._spread_temp_stack.append(spread_temps)
         provided_fields = {field.name: field for field in bound_fields}
@@ -4420,20 +5984,25 @@ class CodeGenVisitor(zincVisitor):
                         bound_field.spread_source_expr is None
                         and (self._expr_is_string_literal(bound_field.expression) or self._looks_like_rust_string_literal(value))
                     ):
-                        value = f"String::from({value})"
-                    fields.append(f"{f.name}: {value}")
+                        value = self._owned_string(value)
+                    fields.append(self._field_init(f.name, value))
                 else:
                     # Use default value
-                    fields.append(f"{f.name}: {f.rust_default()}")
+                    fields.append(f"{self._rust_ident(f.name)}: {f.rust_default()}")
             fields_str = ", ".join(fields)
             self._spread_temp_stack.pop()
             return self._wrap_spread_temps(f"{name} {{ {fields_str} }}", spread_setup)
         # Fallback - just use provided fields
-        fields = [f"{field.name}: {self._render_bound_struct_field(field)}" for field in bound_fields]
+        fields = [self._field_init(field.name, self._render_bound_struct_field(field)) for field in bound_fields]
         fields_str = ", ".join(fields)
         self._spread_temp_stack.pop()
         return self._wrap_spread_temps(f"{name} {{ {fields_str} }}", spread_setup)
 
+    def _field_init(self, name: str, value: str) -> str:
+        """Render one struct literal field, using Rust's shorthand when the value is a same-named binding."""
+        name = self._rust_ident(name)
+        return name if value == name else f"{name}: {value}"
+
     def visitEnumVariantConstruction(self, ctx: ZincParser.EnumVariantConstructionContext) -> str:
         """Visit enum payload construction."""
         variant_name = ctx.enumVariantPath().IDENTIFIER().getText()
@@ -4472,21 +6041,21 @@ class CodeGenVisitor(zincVisitor):
                                     self._expr_is_string_literal(provided_field_exprs.get(info.name))
                                     or self._looks_like_rust_string_literal(value)
                                 ):
-                                    value = f"String::from({value})"
-                                fields.append(f"{info.name}: {value}")
+                                    value = self._owned_string(value)
+                                fields.append(self._field_init(info.name, value))
                             else:
                                 fields.append(f"{info.name}: {info.rust_default()}")
                         return f"{name} {{ {', '.join(fields)} }}"
-                    return f"{name} {{ {', '.join(f'{key}: {value}' for key, value in provided_fields.items())} }}"
+                    return f"{name} {{ {', '.join(self._field_init(key, value) for key, value in provided_fields.items())} }}"
 
         provided = {field.IDENTIFIER().getText(): (self.visit(field.expression()), field.expression()) for field in ctx.fieldInit()}
         if variant is not None:
             field_parts = []
             for field in variant.fields:
                 value, expr_ctx = provided[field.name]
-                field_parts.append(f"{field.name}: {self._coerce_owned(value, field.resolved_type, expr_ctx)}")
+                field_parts.append(self._field_init(field.name, self._coerce_owned(value, field.resolved_type, expr_ctx)))
         else:
-            field_parts = [f"{name}: {value}" for name, (value, _expr) in provided.items()]
+            field_parts = [self._field_init(name, value) for name, (value, _expr) in provided.items()]
         return f"{owner_rust}::{variant_name} {{ {', '.join(field_parts)} }}"
 
     def _match_pattern_local_names(self, pattern_ctx) -> set[str]:
@@ -4521,7 +6090,7 @@ class CodeGenVisitor(zincVisitor):
                 return f"Err({inner})"
             return f"Some({inner})"
         if pattern_ctx.IDENTIFIER():
-            return pattern_ctx.IDENTIFIER().getText()
+            return self._rust_ident(pattern_ctx.IDENTIFIER().getText())
         enum_pattern = pattern_ctx.enumVariantPattern()
         if enum_pattern is None or self._current_module is None:
             return pattern_ctx.getText()
@@ -4540,9 +6109,9 @@ class CodeGenVisitor(zincVisitor):
         for field_pattern in field_patterns:
             identifiers = list(field_pattern.IDENTIFIER())
             if len(identifiers) == 1:
-                fields.append(identifiers[0].getText())
+                fields.append(self._rust_ident(identifiers[0].getText()))
             else:
-                fields.append(f"{identifiers[0].getText()}: {identifiers[1].getText()}")
+                fields.append(f"{self._rust_ident(identifiers[0].getText())}: {self._rust_ident(identifiers[1].getText())}")
         return f"{owner_rust}::{variant_name} {{ {', '.join(fields)} }}"
 
     def _render_match_arm_body(self, arm_ctx, local_names: set[str]) -> list[str]:
@@ -4568,12 +6137,13 @@ class CodeGenVisitor(zincVisitor):
             f"    let {staged_name} = {scrutinee};",
             f"    match {staged_name}.clone() {{",
         ]
-        for arm_ctx in ctx.matchArm():
+        for i, arm_ctx in enumerate(ctx.matchArm()):
             pattern = self._render_match_pattern(arm_ctx.pattern())
             body = self._render_match_arm_body(
                 arm_ctx,
                 self._match_pattern_local_names(arm_ctx.pattern()),
             )
+            body = [*self._coverage_counter(ctx, i), *body]
             lines.append(f"        {pattern} => {{")
             self._append_block_lines(lines, body, 3)
             lines.append("        },")
@@ -4685,24 +6255,28 @@ class CodeGenVisitor(zincVisitor):
         )
         if assignment_op == "=":
             value = self._coerce_numeric_rhs_for_target(value, expr, symbol.resolved_type, symbol.exact_type)
+            if self._is_appended_string(symbol):
+                value = self._owned_string_value(value)
             temp_name = self._staged_temp_name("captured_write", ctx)
             return f"let {temp_name} = {value};\n*{self._rust_binding_name(storage_name)}.lock().unwrap() = {temp_name};"
 
+        rust_target = self._rust_binding_name(storage_name)
+        value_temp = self._staged_temp_name("captured_compound", expr)
+        if symbol.resolved_type == BaseType.STRING and assignment_op == "+=":
+            return f"let {value_temp} = {value};\n{self._render_push_str(f'{rust_target}.lock().unwrap()', value_temp)}"
         if assignment_op in BITWISE_VALUE_ASSIGNMENT_OPERATORS:
             value = self._coerce_bitwise_operand(value, expr, symbol.exact_type)
         elif assignment_op not in {"**=", "<<=", ">>="}:
             value = self._coerce_numeric_rhs_for_target(value, expr, symbol.resolved_type, symbol.exact_type)
-        rust_target = self._rust_binding_name(storage_name)
-        value_temp = self._staged_temp_name("captured_compound", expr)
         lines = [f"let {value_temp} = {value};"]
-        if assignment_op == "**=":
-            guard_name = self._staged_temp_name("captured_guard", ctx)
-            power_value = self._render_power_assignment_expr(f"*{guard_name}", symbol.resolved_type, symbol.exact_type, value_temp, expr)
+        guard_name = self._staged_temp_name("captured_guard", ctx)
+        new_value = self._render_compound_value(f"*{guard_name}", assignment_op, symbol.resolved_type, symbol.exact_type, value_temp, expr)
+        if new_value is not None:
             lines.extend(
                 [
                     "{",
                     f"    let mut {guard_name} = {rust_target}.lock().unwrap();",
-                    f"    *{guard_name} = {power_value};",
+                    f"    *{guard_name} = {new_value};",
                     "}",
                 ]
             )
@@ -4731,7 +6305,7 @@ class CodeGenVisitor(zincVisitor):
                         and symbol.resolved_type == BaseType.FLOAT
                         and tuple_info.element_types[i] == BaseType.INTEGER
                     ):
-                        item_value = f"({item_value} as {exact_type_to_rust(symbol.exact_type, BaseType.FLOAT)})"
+                        item_value = self._numeric_cast(item_value, None, exact_type_to_rust(symbol.exact_type, BaseType.FLOAT))
                     lines.append(self._render_identifier_assignment(name, symbol, item_value, include_type=True))
                 return "\n".join(lines)
             return self._render_broadcast_assignment(ctx, target_ctx.tupleAssignmentTarget(), ctx.expression(), value, include_type=True)
@@ -4750,18 +6324,55 @@ class CodeGenVisitor(zincVisitor):
         )
         if symbol is not None:
             value = self._coerce_numeric_rhs_for_target(value, ctx.expression(), symbol.resolved_type, symbol.exact_type)
+            if self._is_appended_string(symbol):
+                value = self._owned_string_value(value)
         if symbol is None:
-            return f"let {var_name} = {value};"
+            return f"let {self._rust_ident(var_name)} = {value};"
 
         return self._render_identifier_assignment(var_name, symbol, value, include_type=True)
 
     def visitVariableAssignment(self, ctx: ZincParser.VariableAssignmentContext) -> str:
         """Visit variable assignment with shadowing support."""
+        rendered = self._render_variable_assignment(ctx)
+        guard = self._temp_path_guard_declaration(ctx.expression())
+        if guard is not None:
+            return f"{guard}\n{rendered}"
+        return rendered
+
+    def _render_on_interrupt_call(self, handler: str) -> str:
+        """Install a Ctrl-C handler: tokio's signal stream in async programs, a signal thread otherwise."""
+        self._uses_interrupt = True
+        if self._uses_async:
+            return (
+                f"tokio::spawn({{ let __zinc_interrupt_handler = {handler}; async move {{ "
+                "while tokio::signal::ctrl_c().await.is_ok() { __zinc_interrupt_handler.call(); } } })"
+            )
+        self._require_runtime_symbol("on_interrupt")
+        return f"on_interrupt({{ let __zinc_interrupt_handler = {handler}; move || __zinc_interrupt_handler.call() }})"
+
+    def _temp_path_guard_declaration(self, expr: ParserRuleContext) -> str | None:
+        """Declare the scope guard that removes a `temp_file()`/`temp_dir()` path on drop."""
+        kind = {"temp_file": "file", "temp_dir": "dir"}.get(self._function_call_name(expr))
+        if kind is None:
+            return None
+        self._require_runtime_symbol("TempPath")
+        return f"let {self._temp_path_guard_name(expr)} = TempPath::{kind}();"
+
+    def _temp_path_guard_name(self, call_ctx: ParserRuleContext) -> str:
+        """Return the Rust binding that owns a temporary path until scope exit."""
+        return self._staged_temp_name("temp_path", call_ctx)
+
+    def _render_variable_assignment(self, ctx: ZincParser.VariableAssignmentContext) -> str:
+        """Render a variable assignment statement."""
         target = ctx.assignmentTarget().getText()
         target_ctx = ctx.assignmentTarget()
         expr = ctx.expression()
         assignment_op = ctx.assignmentOperator().getText()
 
+        static_field = self._static_field_target(extract_identifier_path(target_ctx.memberAccess())) if target_ctx.memberAccess() else None
+        if static_field is not None:
+            return self._render_static_field_assignment(ctx, *static_field, assignment_op)
+
         if assignment_op != "=":
             return self._render_compound_assignment(ctx, assignment_op)
 
@@ -4775,9 +6386,9 @@ class CodeGenVisitor(zincVisitor):
             pattern_names = []
             for name, symbol in zip(names, target_symbols, strict=False):
                 if needs_declaration and symbol and symbol.is_mutated:
-                    pattern_names.append(f"mut {name}")
+                    pattern_names.append(f"mut {self._rust_ident(name)}")
                 else:
-                    pattern_names.append(name)
+                    pattern_names.append(self._rust_ident(name))
                 if needs_declaration:
                     self._declared_vars.add(name)
             pattern = self._render_tuple_pattern(pattern_names)
@@ -4809,19 +6420,26 @@ class CodeGenVisitor(zincVisitor):
                             constructor = f"Channel::<{chan_info.element_rust_type()}>"
                         self._declared_vars.add(var_name)
                         if chan_info.is_bounded and capacity is not None:
-                            return f"let {var_name} = {constructor}::bounded({capacity});"
-                        return f"let {var_name} = {constructor}::unbounded();"
+                            return f"let {self._rust_ident(var_name)} = {constructor}::bounded({capacity});"
+                        return f"let {self._rust_ident(var_name)} = {constructor}::unbounded();"
                     else:
                         # Fallback - unknown element type
                         self._declared_vars.add(var_name)
                         if capacity is not None:
-                            return f"let {var_name} = Channel::bounded({capacity});"
-                        return f"let {var_name} = Channel::unbounded();"
+                            return f"let {self._rust_ident(var_name)} = Channel::bounded({capacity});"
+                        return f"let {self._rust_ident(var_name)} = Channel::unbounded();"
 
         target_symbol = None
         if target_ctx.IDENTIFIER():
             target_symbol = self.symbols.lookup_by_interval(target_ctx.IDENTIFIER().getSourceInterval(), self._current_function)
 
+        pieces = appended_pieces(target, expr) if target_ctx.IDENTIFIER() else None
+        appends = pieces is not None and target_symbol is not None and target_symbol.kind == SymbolKind.TEMPORARY
+        if appends and self._is_appended_string(target_symbol):
+            appended = self._render_string_append(self._string_append_target(target_ctx, target_symbol), target, pieces)
+            if appended is not None:
+                return appended
+
         previous_dict_info = self._expected_dict_info
         previous_set_info = self._expected_set_info
         previous_tuple_info = self._expected_tuple_info
@@ -4854,9 +6472,12 @@ class CodeGenVisitor(zincVisitor):
                 else:
                     value = f"Rc::new(RefCell::new({value}))"
 
+        if target_ctx.IDENTIFIER() and self._is_appended_string(target_symbol):
+            value = self._owned_string_value(value)
+
         captured_target = target_symbol is not None and self._symbol_is_captured_cell(target_symbol)
         storage_name = self._symbol_storage_unique_name(target_symbol) if captured_target and target_symbol is not None else None
-        rendered_target = self._rust_binding_name(storage_name) if storage_name is not None else target
+        rendered_target = self._rust_binding_name(storage_name) if storage_name is not None else self._rust_text(target_ctx)
         if storage_name in self._captured_binding_names:
             value = f"Arc::new(Mutex::new({value}))" if (target_symbol.is_shadow or target not in self._declared_vars) else value
 
@@ -4873,9 +6494,9 @@ class CodeGenVisitor(zincVisitor):
             pattern_names = []
             for name, symbol in zip(names, target_symbols, strict=False):
                 if needs_declaration and symbol and symbol.is_mutated:
-                    pattern_names.append(f"mut {name}")
+                    pattern_names.append(f"mut {self._rust_ident(name)}")
                 else:
-                    pattern_names.append(name)
+                    pattern_names.append(self._rust_ident(name))
                 if needs_declaration:
                     self._declared_vars.add(name)
             pattern = self._render_tuple_pattern(pattern_names)
@@ -4910,6 +6531,9 @@ class CodeGenVisitor(zincVisitor):
                     value_temp = self._staged_temp_name("captured_value", expr)
                     return f"let {key_temp} = {key};\nlet {value_temp} = {coerced_value};\n{collection}.insert({key_temp}, {value_temp});"
                 return f"{collection}.insert({key}, {coerced_value});"
+            if collection_type == BaseType.ARRAY:
+                value = f"{value}.clone()" if self._row_needs_copy(expr) else value
+                return f"{self._render_list_element_place(index_access)} = {value};"
 
         if target_ctx.IDENTIFIER():
             var_name = target
@@ -4917,7 +6541,7 @@ class CodeGenVisitor(zincVisitor):
 
             if symbol is None:
                 # Fallback - shouldn't happen
-                return f"let {var_name} = {value};"
+                return f"let {rendered_target} = {value};"
 
             if self._symbol_is_captured_cell(symbol) and (
                 symbol.is_captured_ref or not (symbol.is_shadow or var_name not in self._declared_vars)
@@ -4933,9 +6557,10 @@ class CodeGenVisitor(zincVisitor):
                 self._declared_vars.add(var_name)
                 # Check if this is a struct var that needs mut
                 needs_mut = symbol.is_mutated or var_name in self._mut_struct_vars
-                if needs_mut:
-                    return f"let mut {rendered_target} = {value};"
-                return f"let {rendered_target} = {value};"
+                binding = f"mut {rendered_target}" if needs_mut else rendered_target
+                if self._needs_integer_annotation(symbol, value):
+                    return f"let {binding}: {self._symbol_rust_type(symbol)} = {value};"
+                return f"let {binding} = {value};"
             else:
                 # Same-type reassignment -> bare assignment
                 return f"{rendered_target} = {value};"
@@ -4955,27 +6580,39 @@ class CodeGenVisitor(zincVisitor):
                     return (
                         f"let {temp_name} = {value};\n"
                         f"{self._rust_binding_name(storage_name)}.lock().unwrap()."
-                        f"{member_ctx.IDENTIFIER().getText()} = {temp_name};"
+                        f"{self._rust_ident(member_ctx.IDENTIFIER().getText())} = {temp_name};"
                     )
 
-        return f"{target} = {value};"
+        return f"{self._rust_text(target_ctx)} = {value};"
 
     def _render_compound_assignment(self, ctx: ZincParser.VariableAssignmentContext, assignment_op: str) -> str:
         """Render numeric compound assignment operators."""
         target_ctx = ctx.assignmentTarget()
         expr = ctx.expression()
-        target = target_ctx.getText()
+        target = self._rust_text(target_ctx)
         target_symbol = None
         if target_ctx.IDENTIFIER():
             target_symbol = self.symbols.lookup_by_interval(target_ctx.IDENTIFIER().getSourceInterval(), self._current_function)
 
         target_type = target_symbol.resolved_type if target_symbol else self._get_expr_type(target_ctx)
         target_exact_type = target_symbol.exact_type if target_symbol else self._get_expr_exact_type(target_ctx)
+        index_access = target_ctx.indexAccess()
+        if index_access is not None and self._get_expr_type(index_access.expression(0)) == BaseType.ARRAY:
+            target = self._render_list_element_place(index_access)
+            target_type = self._get_expr_type(index_access)
+            target_exact_type = self._get_expr_exact_type(index_access)
         value = self._visit_expression_with_expectations(
             expr,
             expected_type=target_type if target_type != BaseType.UNKNOWN else None,
             coerce_scalar=False,
         )
+        if target_type == BaseType.STRING and assignment_op == "+=" and target_symbol is not None:
+            target_ref = self._string_append_target(target_ctx, target_symbol)
+            if not re.search(rf"\b{re.escape(target_ctx.getText())}\b", expr.getText()):
+                return self._render_push_str(target_ref, value)
+            # The piece reads the string it grows, so finish reading before borrowing it to write
+            value_temp = self._staged_temp_name("appended", expr)
+            return f"let {value_temp} = {self._owned_string_value(value)};\n{target_ref}.push_str(&{value_temp});"
         call = self._operator_call_for_ctx(ctx)
         if call is not None:
             result = self._render_resolved_operator_call(call, [target, value])
@@ -4997,14 +6634,14 @@ class CodeGenVisitor(zincVisitor):
             rust_target = self._rust_binding_name(storage_name)
             value_temp = self._staged_temp_name("captured_compound", expr)
             lines = [f"let {value_temp} = {value};"]
-            if assignment_op == "**=":
-                guard_name = self._staged_temp_name("captured_guard", target_ctx)
-                power_value = self._render_power_assignment_expr(f"*{guard_name}", target_type, target_exact_type, value_temp, expr)
+            guard_name = self._staged_temp_name("captured_guard", target_ctx)
+            new_value = self._render_compound_value(f"*{guard_name}", assignment_op, target_type, target_exact_type, value_temp, expr)
+            if new_value is not None:
                 lines.extend(
                     [
                         "{",
                         f"    let mut {guard_name} = {rust_target}.lock().unwrap();",
-                        f"    *{guard_name} = {power_value};",
+                        f"    *{guard_name} = {new_value};",
                         "}",
                     ]
                 )
@@ -5012,11 +6649,61 @@ class CodeGenVisitor(zincVisitor):
             lines.append(f"*{rust_target}.lock().unwrap() {assignment_op} {value_temp};")
             return "\n".join(lines)
 
-        if assignment_op == "**=":
-            power_value = self._render_power_assignment_expr(target, target_type, target_exact_type, value, expr)
-            return f"{target} = {power_value};"
+        new_value = self._render_compound_value(target, assignment_op, target_type, target_exact_type, value, expr)
+        if new_value is not None:
+            return f"{target} = {new_value};"
         return f"{target} {assignment_op} {value};"
 
+    def _render_list_element_place(self, index_access) -> str:
+        """Render the list element an assignment writes to, as in grid[i][j], with its indexes as usize."""
+        collection = self.visit(index_access.expression(0))
+        return f"{collection}[{self._render_usize_index(index_access.expression(1))}]"
+
+    def _render_usize_index(self, index_ctx, index: str | None = None) -> str:
+        """Render a list position as the usize Rust indexes with, casting anything but an integer literal.
+
+        A collection's `len()` is already a usize in Rust, so its cast to Zinc's integer type is dropped instead.
+        """
+        index = self.visit(index_ctx) if index is None else index
+        len_cast = f" as {default_exact_type(BaseType.INTEGER)})"
+        if self._is_collection_len_call(index_ctx) and index.startswith("(") and index.endswith(len_cast):
+            return index[1 : -len(len_cast)]
+        if self._get_expr_type(index_ctx) == BaseType.INTEGER and not self._is_integer_literal(index_ctx):
+            return f"({index} as usize)"
+        return index
+
+    def _is_collection_len_call(self, ctx) -> bool:
+        """Return True for `len()` called on a list, string, dict, or set, whose Rust result is a usize."""
+        if not isinstance(ctx, ZincParser.FunctionCallExprContext) or not isinstance(ctx.expression(), ZincParser.MemberAccessExprContext):
+            return False
+        callee = ctx.expression()
+        receiver_type = self._get_expr_type(callee.expression())
+        return callee.IDENTIFIER().getText() == "len" and receiver_type in (BaseType.ARRAY, BaseType.STRING, BaseType.DICT, BaseType.SET)
+
+    def _string_append_target(self, target_ctx, symbol) -> str:
+        """Render the string variable an append writes to, locking it first when closures share it."""
+        if self._symbol_is_captured_cell(symbol):
+            storage_name = self._symbol_storage_unique_name(symbol)
+            return f"{self._rust_binding_name(storage_name)}.lock().unwrap()"
+        return self._rust_text(target_ctx)
+
+    def _render_compound_value(
+        self,
+        target: str,
+        assignment_op: str,
+        target_type: BaseType,
+        target_exact_type: str | None,
+        right: str,
+        right_ctx,
+    ) -> str | None:
+        """Render the new value for `target op= right`, or None when Rust's compound operator does the job."""
+        if assignment_op == "**=":
+            return self._render_power_assignment_expr(target, target_type, target_exact_type, right, right_ctx)
+        op = assignment_op[:-1]
+        if self._overflow == "checked" or target_type != BaseType.INTEGER or op not in OVERFLOW_METHODS:
+            return None
+        return f"{self._cast_operand(target)}.{self._overflow_method(OVERFLOW_METHODS[op])}({right})"
+
     def _render_power_assignment_expr(
         self,
         target: str,
@@ -5030,9 +6717,10 @@ class CodeGenVisitor(zincVisitor):
             float_exact = exact_type_to_rust(target_exact_type, BaseType.FLOAT)
             right_type = self._get_expr_type(right_ctx)
             if right_type == BaseType.INTEGER:
-                right = f"({right} as {float_exact})"
-            return f"({target}).powf({right})"
-        return f"({target}).pow(({right}) as u32)"
+                right = self._numeric_cast(right, right_ctx, float_exact)
+            return f"{self._cast_operand(target)}.powf({right})"
+        exponent = self._numeric_cast(right, right_ctx, "u32", argument=True)
+        return f"{self._cast_operand(target)}.{self._overflow_method('pow')}({exponent})"
 
     def visitIfExpr(self, ctx: ZincParser.IfExprContext) -> str:
         """Visit an if-expression wrapper."""
@@ -5101,16 +6789,36 @@ class CodeGenVisitor(zincVisitor):
                         return f"{target}.values().cloned()"
                     return f"{target}.iter().map(|(k, v)| (k.clone(), v.clone()))"
 
+        if (
+            isinstance(expr_ctx, ZincParser.RangeExprContext)
+            and self._overflow != "checked"
+            and self._operator_call_for_ctx(expr_ctx) is None
+            and self._get_expr_type(expr_ctx.expression(0)) == BaseType.INTEGER
+        ):
+            # Wrapping and saturating arithmetic are method calls, which need the loop variable's type settled up front.
+            start_ctx = expr_ctx.expression(0)
+            exact_type = exact_type_to_rust(self._get_expr_exact_type(start_ctx), BaseType.INTEGER)
+            start = self._method_receiver(self.visit(start_ctx), exact_type)
+            return f"{start}{expr_ctx.getChild(1).getText()}{self.visit(expr_ctx.expression(1))}"
+
         rendered = self.visit(expr_ctx)
         if expr_type in {BaseType.ARRAY, BaseType.SET}:
             return f"{rendered}.iter().cloned()"
+        if expr_type == BaseType.STRUCT:
+            struct = self.atlas.structs.get(self._struct_qualified_name_for_expr(expr_ctx) or "")
+            if struct is not None and iterate_method(struct.methods) is not None:
+                return f"{rendered}.{ITERATE_METHOD}()"
+            if isinstance(expr_ctx, ZincParser.PrimaryExprContext) and expr_ctx.primaryExpression().IDENTIFIER() is not None:
+                # Borrowing the iterator leaves it advanced after the loop, as the other backends do.
+                return f"&mut {rendered}"
+            return f"({rendered})"  # Rust doesn't allow a bare struct literal as the loop's iterator
         return rendered
 
     def visitForStatement(self, ctx: ZincParser.ForStatementContext) -> str:
         """Visit for loop."""
         binding = ctx.forBinding()
         binding_ctx = binding.tupleAssignmentTarget() or binding
-        names = self._binding_names(binding_ctx)
+        names = [self._rust_ident(name) for name in self._binding_names(binding_ctx)]
         binding_symbols = [
             self.symbols.lookup_by_interval(token.getSourceInterval(), self._current_function)
             for token in binding_ctx.getTokens(ZincParser.IDENTIFIER)
@@ -5225,10 +6933,11 @@ class CodeGenVisitor(zincVisitor):
             return self._render_select_case_body(case_ctx.block())
         if mode == "single":
             prelude = [
-                f'let {names[0]} = match {option_expr} {{ Some(value) => value, None => panic!("select receive on closed channel") }};'
+                f"let {self._rust_ident(names[0])} = "
+                f'match {option_expr} {{ Some(value) => value, None => panic!("select receive on closed channel") }};'
             ]
             return self._render_select_case_body(case_ctx.block(), prelude=prelude, local_names={names[0]})
-        pattern = self._render_tuple_pattern(names)
+        pattern = self._render_tuple_pattern([self._rust_ident(name) for name in names])
         prelude = [f"let {pattern} = match {option_expr} {{ Some(value) => (value, true), None => (Default::default(), false) }};"]
         return self._render_select_case_body(case_ctx.block(), prelude=prelude, local_names=set(names))
 
@@ -5344,11 +7053,15 @@ class CodeGenVisitor(zincVisitor):
 
     def visitReturnStatement(self, ctx: ZincParser.ReturnStatementContext) -> str:
         """Visit return statement."""
+        if ctx.getSourceInterval() in self._tail_call_sites:
+            return self._render_self_tail_call(ctx.expression())
         if ctx.expression():
             func = self.atlas.functions.get(self._current_function)
+            # Method bodies are generated outside any function, so their declared return type comes from the method.
+            expected_type = exact_type_to_base(self._current_method_return_type) if self._current_method_return_type else None
             value = self._visit_expression_with_expectations(
                 ctx.expression(),
-                expected_type=func.return_type if func else None,
+                expected_type=expected_type or (func.return_type if func else None),
                 dict_info=func.return_dict_info if func else None,
                 set_info=func.return_set_info if func else None,
                 tuple_info=func.return_tuple_info if func else None,
@@ -5455,6 +7168,8 @@ class CodeGenVisitor(zincVisitor):
 
     def visitExpressionStatement(self, ctx: ZincParser.ExpressionStatementContext) -> str:
         """Visit expression statement."""
+        if ctx.getSourceInterval() in self._tail_call_sites:
+            return self._render_self_tail_call(ctx.expression())
         expr = self.visit(ctx.expression())
         if expr.endswith(";"):
             return expr
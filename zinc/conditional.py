@@ -0,0 +1,321 @@
+"""Conditional compilation with `when` blocks.
+
+    when os == "windows" {
+        const SEPARATOR = "\\"
+    } else {
+        const SEPARATOR = "/"
+    }
+
+A `when` block is resolved while its module is parsed, for the target and
+profile being built, like Rust's `#[cfg]`. The body of the first branch whose
+condition holds replaces the whole chain; the other branches are dropped
+without being type-checked, so they may use declarations that only exist on
+other platforms. Because the chosen body is inlined, blocks work at the top
+level, in struct bodies, and among statements alike.
+
+Conditions compare `os`, `arch`, and `family` with string literals, using the
+names of Rust's `target_os`, `target_arch`, and `target_family`, and test the
+booleans `debug` and `release`. They combine with `and`, `or`, `not`, and
+parentheses.
+"""
+
+import os as host_os
+import platform
+from collections.abc import Iterator
+from contextlib import contextmanager
+from contextvars import ContextVar
+from dataclasses import dataclass
+
+from antlr4 import Token
+from zinc.diagnostics import Diagnostic, did_you_mean
+from zinc.fixed_arrays import DEFAULT_BOUNDS_MODE
+from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.shadowing import DEFAULT_SHADOWING_MODE
+from zinc.targets import HOST, Target, host_triple
+
+WHEN_KEYWORD = "when"
+
+_ARCH_ALIASES = {"i386": "x86", "i586": "x86", "i686": "x86", "armv7": "arm", "armv7a": "arm", "thumbv7em": "arm", "riscv64gc": "riscv64"}
+
+
+@dataclass(frozen=True)
+class BuildConfig:
+    """What a build is configured with: the target, the profile, any `--define`s, `--bounds`, and `--shadowing`.
+
+    `when` conditions test everything but the defines, which `zinc.defines`
+    applies to consts, the bounds mode, which decides how fixed-size arrays
+    are indexed (see `zinc.fixed_arrays`), and the shadowing mode, which
+    decides whether `=` may change a variable's type (see `zinc.shadowing`).
+    """
+
+    os: str
+    arch: str
+    family: str
+    debug: bool = True
+    defines: tuple[tuple[str, str], ...] = ()
+    bounds: str = DEFAULT_BOUNDS_MODE
+    shadowing: str = DEFAULT_SHADOWING_MODE
+
+    @classmethod
+    def for_target(
+        cls,
+        target: Target = HOST,
+        *,
+        debug: bool = True,
+        defines: tuple[tuple[str, str], ...] = (),
+        bounds: str = DEFAULT_BOUNDS_MODE,
+        shadowing: str = DEFAULT_SHADOWING_MODE,
+    ) -> "BuildConfig":
+        """The configuration of a build for `target`, in the debug or release profile."""
+        triple = target.triple or host_triple()
+        if triple is None:
+            # Without a toolchain, describe the machine the compiler runs on.
+            system = {"Darwin": "macos"}.get(platform.system(), platform.system().lower())
+            family = "windows" if host_os.name == "nt" else "unix"
+            return cls(system, _arch(platform.machine().lower()), family, debug, defines, bounds, shadowing)
+        return cls(_triple_os(triple), _arch(triple.split("-")[0]), _triple_family(triple), debug, defines, bounds, shadowing)
+
+    def values(self) -> dict[str, str | bool]:
+        """The names a condition can use and their values."""
+        return {"os": self.os, "arch": self.arch, "family": self.family, "debug": self.debug, "release": not self.debug}
+
+
+def _arch(arch: str) -> str:
+    return _ARCH_ALIASES.get(arch, "aarch64" if arch == "arm64" else "x86_64" if arch == "amd64" else arch)
+
+
+def _triple_os(triple: str) -> str:
+    for marker, name in (("windows", "windows"), ("darwin", "macos"), ("ios", "ios"), ("android", "android"), ("linux", "linux")):
+        if marker in triple:
+            return name
+    if "wasi" in triple:
+        return "wasi"
+    parts = triple.split("-")
+    return parts[2] if len(parts) > 2 else "unknown"
+
+
+def _triple_family(triple: str) -> str:
+    if triple.startswith("wasm"):
+        return "wasm"
+    return "windows" if "windows" in triple else "unix"
+
+
+_active_config: ContextVar[BuildConfig | None] = ContextVar("build_config", default=None)
+
+
+@contextmanager
+def build_config(config: BuildConfig) -> Iterator[None]:
+    """Resolve `when` blocks for `config` while a program is loaded and checked."""
+    token = _active_config.set(config)
+    try:
+        yield
+    finally:
+        _active_config.reset(token)
+
+
+def active_build_config() -> BuildConfig:
+    """The configuration set by `build_config`, or a debug build for the host."""
+    config = _active_config.get()
+    if config is None:
+        config = BuildConfig.for_target()
+        _active_config.set(config)
+    return config
+
+
+class _ConditionError(Exception):
+    def __init__(self, message: str, token: Token, suggestions: tuple[str, ...] = ()):
+        super().__init__(message)
+        self.token = token
+        self.suggestions = suggestions
+
+
+class _Condition:
+    """Parses and evaluates one condition, which ends at the `{` of its block."""
+
+    def __init__(self, tokens: list[Token], index: int, values: dict[str, str | bool]):
+        self.tokens = tokens
+        self.index = index
+        self.values = values
+        self.error: _ConditionError | None = None
+
+    def peek(self) -> str | None:
+        return self.tokens[self.index].text if self.index < len(self.tokens) else None
+
+    def take(self) -> Token:
+        if self.index >= len(self.tokens):
+            raise _ConditionError("condition ends unexpectedly", self.tokens[-1])
+        token = self.tokens[self.index]
+        self.index += 1
+        return token
+
+    def parse(self) -> bool:
+        value = self.either()
+        if self.peek() != "{":
+            raise _ConditionError("expected '{' after the condition", self.tokens[min(self.index, len(self.tokens) - 1)])
+        return value
+
+    def either(self) -> bool:
+        value = self.both()
+        while self.peek() in ("or", "||"):
+            self.take()
+            value = self.both() or value
+        return value
+
+    def both(self) -> bool:
+        value = self.negation()
+        while self.peek() in ("and", "&&"):
+            self.take()
+            value = self.negation() and value
+        return value
+
+    def negation(self) -> bool:
+        if self.peek() in ("not", "!"):
+            self.take()
+            return not self.negation()
+        return self.atom()
+
+    def atom(self) -> bool:
+        token = self.take()
+        if token.text == "(":
+            value = self.either()
+            if self.take().text != ")":
+                raise _ConditionError("expected ')' in condition", self.tokens[self.index - 1])
+            return value
+        if token.text in ("true", "false"):
+            return token.text == "true"
+        if token.type != ZincParser.IDENTIFIER:
+            raise _ConditionError(f"unexpected '{token.text}' in condition", token)
+        if self.peek() in ("==", "!="):
+            operator = self.take().text
+            literal = self.take()
+            if literal.type != ZincParser.STRING:
+                raise _ConditionError(f"'{token.text}' can only be compared with a string literal", literal)
+            return (self.lookup(token, str) == literal.text[1:-1]) == (operator == "==")
+        return self.lookup(token, bool)
+
+    def lookup(self, name: Token, kind: type) -> str | bool:
+        if name.text not in self.values:
+            self.fail(f"unknown condition '{name.text}'", name, did_you_mean(name.text, self.values))
+            return "" if kind is str else False
+        value = self.values[name.text]
+        if isinstance(value, str) and kind is bool:
+            self.fail(f"'{name.text}' is a string; compare it with == or !=", name)
+            return False
+        if isinstance(value, bool) and kind is str:
+            self.fail(f"'{name.text}' is true or false and can't be compared with a string", name)
+            return ""
+        return value
+
+    def fail(self, message: str, token: Token, suggestions: tuple[str, ...] = ()) -> None:
+        """Remember the first error in the condition; it is reported only if the whole `when` parses."""
+        if self.error is None:
+            self.error = _ConditionError(message, token, suggestions)
+
+
+def _diagnostic(error: _ConditionError) -> Diagnostic:
+    token = error.token
+    file = getattr(token.getInputStream(), "name", None)
+    return Diagnostic(str(error), file, token.line, token.column, token.column + len(token.text or " "), suggestions=error.suggestions)
+
+
+def _closing_brace(tokens: list[Token], index: int) -> int | None:
+    depth = 0
+    for position in range(index, len(tokens)):
+        if tokens[position].text in ("{", "(", "[", "#["):
+            depth += 1
+        elif tokens[position].text in ("}", ")", "]"):
+            depth -= 1
+            if depth == 0:
+                return position
+    return None
+
+
+@dataclass
+class _Chain:
+    """A parsed `when ... else ...` chain: the chosen body's token range, and the index after the chain."""
+
+    body: tuple[int, int] | None
+    end: int
+    error: _ConditionError | None = None
+
+
+def _chain(tokens: list[Token], index: int, values: dict[str, str | bool]) -> _Chain | None:
+    """Parse the chain starting at a `when` token, or return None when `when` is used as an ordinary name."""
+    if tokens[index].type != ZincParser.IDENTIFIER or tokens[index].text != WHEN_KEYWORD:
+        return None
+    if index > 0 and tokens[index - 1].text == ".":
+        return None
+    chosen: tuple[int, int] | None = None
+    error: _ConditionError | None = None
+    position = index
+    first = True
+    while True:
+        condition = _Condition(tokens, position + 1, values)
+        try:
+            holds = condition.parse()
+        except _ConditionError as exc:
+            if first:
+                return None
+            return _Chain(None, position + 1, exc)
+        first = False
+        error = error or condition.error
+        close = _closing_brace(tokens, condition.index)
+        if close is None:
+            return _Chain(None, len(tokens), _ConditionError(f"'{WHEN_KEYWORD}' block is never closed", tokens[position]))
+        if holds and chosen is None:
+            chosen = (condition.index + 1, close)
+        position = close + 1
+        if position >= len(tokens) or tokens[position].text != "else":
+            return _Chain(chosen, position, error)
+        following = tokens[position + 1] if position + 1 < len(tokens) else None
+        if following is not None and following.text == WHEN_KEYWORD:
+            position += 1
+            continue
+        if following is None or following.text != "{":
+            return _Chain(None, position + 1, _ConditionError(f"expected '{{' or '{WHEN_KEYWORD}' after 'else'", tokens[position]))
+        close = _closing_brace(tokens, position + 1)
+        if close is None:
+            return _Chain(None, len(tokens), _ConditionError("'else' block is never closed", tokens[position]))
+        if chosen is None:
+            chosen = (position + 2, close)
+        return _Chain(chosen, close + 1, error)
+
+
+def resolve_conditionals(tokens: list[Token], config: BuildConfig | None = None) -> tuple[list[Token], list[Diagnostic]]:
+    """Replace every `when` chain with the body of its chosen branch, returning the new tokens and any errors."""
+    if not any(token.text == WHEN_KEYWORD for token in tokens):
+        return tokens, []
+    values = (config or active_build_config()).values()
+    result: list[Token] = []
+    diagnostics: list[Diagnostic] = []
+    index = 0
+    while index < len(tokens):
+        chain = _chain(tokens, index, values)
+        if chain is None:
+            result.append(tokens[index])
+            index += 1
+            continue
+        if chain.error is not None:
+            diagnostics.append(_diagnostic(chain.error))
+        elif chain.body is not None:
+            body, body_errors = resolve_conditionals(tokens[chain.body[0] : chain.body[1]], config)
+            result.extend(body)
+            diagnostics.extend(body_errors)
+        index = chain.end
+    return result, diagnostics
+
+
+def conditional_spans(tokens: list[Token]) -> list[tuple[int, int]]:
+    """(start, stop) character offsets of every outermost `when` chain in a module's tokens."""
+    # Any configuration finds the same chains; only which branch is chosen differs.
+    values = BuildConfig("", "", "").values()
+    spans = []
+    index = 0
+    while index < len(tokens):
+        chain = _chain(tokens, index, values)
+        if chain is None:
+            index += 1
+            continue
+        spans.append((tokens[index].start, tokens[min(chain.end, len(tokens)) - 1].stop))
+        index = chain.end
+    return spans
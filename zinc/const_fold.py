@@ -0,0 +1,312 @@
+"""Constant folding and propagation over the typed AST.
+
+Arithmetic, string concatenation, comparisons, and boolean logic whose
+operands are all known at compile time are evaluated here, so code generation
+can emit the result instead of the computation. Operands may be literals,
+top-level `const`s, or locals that are assigned exactly once from a constant
+expression.
+
+Values follow Rust semantics for the type the checker resolved: integer
+division truncates, `%` takes the sign of the dividend, and a result that
+doesn't fit its integer type is left for the program to compute, so it
+overflows at run time exactly as it would unfolded. Only `f64` arithmetic is
+folded, since Python floats are doubles.
+"""
+
+from __future__ import annotations
+
+import math
+
+from antlr4 import ParserRuleContext
+from antlr4.tree.Tree import TerminalNode
+from zinc.ast.types import BaseType, default_exact_type, normalize_exact_type
+from zinc.atlas import Atlas
+from zinc.numeric_literals import parse_numeric_literal
+from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.string_literals import decode_string_literal, is_interpolated_string_literal, is_string_literal
+from zinc.symbols import SymbolTable
+
+# Folded values are Python ints, floats, strs, and bools; None means "not constant".
+Constant = int | float | str | bool
+
+INTEGER_RANGES = {
+    "i8": (-(2**7), 2**7 - 1),
+    "i16": (-(2**15), 2**15 - 1),
+    "i32": (-(2**31), 2**31 - 1),
+    "i64": (-(2**63), 2**63 - 1),
+    "i128": (-(2**127), 2**127 - 1),
+    "isize": (-(2**63), 2**63 - 1),
+    "u8": (0, 2**8 - 1),
+    "u16": (0, 2**16 - 1),
+    "u32": (0, 2**32 - 1),
+    "u64": (0, 2**64 - 1),
+    "u128": (0, 2**128 - 1),
+    "usize": (0, 2**64 - 1),
+}
+# Larger exponents can't fit any integer type unless the base is -1, 0, or 1.
+MAX_FOLDED_EXPONENT = 128
+_FOLDABLE_EXPRESSIONS = (
+    ZincParser.AdditiveExprContext,
+    ZincParser.MultiplicativeExprContext,
+    ZincParser.PowerExprContext,
+    ZincParser.UnaryExprContext,
+    ZincParser.RelationalExprContext,
+    ZincParser.EqualityExprContext,
+    ZincParser.LogicalAndExprContext,
+    ZincParser.LogicalOrExprContext,
+)
+
+
+class ConstantFolder:
+    """Evaluate constant expressions of one program, remembering each result."""
+
+    def __init__(self, atlas: Atlas, symbols: SymbolTable):
+        self.atlas = atlas
+        self.symbols = symbols
+        self._values: dict[tuple[str | None, tuple[int, int]], Constant | None] = {}
+        self._single_assignments: dict[int, dict[str, ParserRuleContext | None]] = {}
+        self._const_values: dict[str, Constant | None] = {}
+
+    def fold(self, ctx: ParserRuleContext, scope: str | None, module_id: str | None) -> Constant | None:
+        """The compile-time value of an operator expression, or None when it isn't constant.
+
+        Bare literals and names are never folded themselves; they are already as
+        cheap as the value, and replacing a name would hide what it refers to.
+        """
+        if not isinstance(ctx, _FOLDABLE_EXPRESSIONS):
+            return None
+        key = (scope, ctx.getSourceInterval())
+        if key not in self._values:
+            self._values[key] = self._evaluate(ctx, scope, module_id)
+        return self._values[key]
+
+    def _evaluate(self, ctx, scope: str | None, module_id: str | None) -> Constant | None:
+        """Evaluate any expression; `scope` is None inside const initializers, which have no typed nodes."""
+        if isinstance(ctx, ZincParser.ParenExprContext):
+            return self._evaluate(ctx.expression(), scope, module_id)
+        exact_type = self._exact_type(ctx, scope)
+        if isinstance(ctx, ZincParser.PrimaryExprContext):
+            value = self._primary_value(ctx, scope, module_id)
+            # An integer constant read where the checker expects a float, such as a local annotated `f64`.
+            return float(value) if _is_number(value) and _is_float_type(exact_type) else value
+        if not isinstance(ctx, _FOLDABLE_EXPRESSIONS) or self._has_overload(ctx, scope):
+            return None
+        if isinstance(ctx, ZincParser.UnaryExprContext):
+            op, operand_ctxs = ctx.getChild(0).getText(), [ctx.expression()]
+        else:
+            op, operand_ctxs = ctx.getChild(1).getText(), ctx.expression()
+        operands = [self._evaluate(operand, scope, module_id) for operand in operand_ctxs]
+        if any(operand is None for operand in operands):
+            return None
+        try:
+            value = _apply(op, operands, exact_type)
+        except (ArithmeticError, ValueError):
+            return None
+        return value if _fits(value, exact_type) else None
+
+    def _has_overload(self, ctx, scope: str | None) -> bool:
+        """Struct operands use their operator methods, which aren't evaluated here."""
+        symbol = self.symbols.lookup_by_interval(ctx.getSourceInterval(), scope) if scope is not None else None
+        return symbol is not None and symbol.resolved_type not in (BaseType.INTEGER, BaseType.FLOAT, BaseType.STRING, BaseType.BOOLEAN)
+
+    def _exact_type(self, ctx, scope: str | None) -> str | None:
+        """The Rust type the checker resolved for a node, or the default for its value's family."""
+        symbol = self.symbols.lookup_by_interval(ctx.getSourceInterval(), scope) if scope is not None else None
+        if symbol is None:
+            return None
+        return normalize_exact_type(symbol.exact_type) or default_exact_type(symbol.resolved_type)
+
+    def _primary_value(self, ctx: ZincParser.PrimaryExprContext, scope: str | None, module_id: str | None) -> Constant | None:
+        primary = ctx.primaryExpression()
+        if primary.literal() is not None:
+            return _literal_value(primary.literal().getText())
+        if primary.IDENTIFIER() is None:
+            return None
+        name = primary.IDENTIFIER().getText()
+        root = _enclosing_declaration(ctx)
+        assignments = self._assignments_in(root) if root is not None else {}
+        if name in assignments:
+            initializer = assignments[name]
+            if initializer is None:
+                return None
+            value = self._evaluate(initializer, scope, module_id)
+            # Strings can be changed in place, so only numbers and bools travel through locals.
+            return None if isinstance(value, str) else value
+        return self._const_value(name, module_id)
+
+    def _const_value(self, name: str, module_id: str | None) -> Constant | None:
+        """The value of a top-level `const` visible from `module_id`."""
+        if module_id is None:
+            return None
+        const_symbol = self.atlas.module_graph.resolve_const_path(module_id, [name])
+        const = self.atlas.consts.get(const_symbol.qualified_name) if const_symbol is not None else None
+        if const is None:
+            return None
+        if const.qualified_name not in self._const_values:
+            self._const_values[const.qualified_name] = None
+            value = self._evaluate(const.ctx.expression(), None, const.module_id)
+            symbol = self.symbols.lookup_by_id(const.qualified_name)
+            exact_type = normalize_exact_type(symbol.exact_type) if symbol is not None else None
+            self._const_values[const.qualified_name] = value if _fits(value, exact_type) else None
+        return self._const_values[const.qualified_name]
+
+    def _assignments_in(self, root: ParserRuleContext) -> dict[str, ParserRuleContext | None]:
+        """Every name bound in a declaration, mapped to its initializer when it is bound exactly once by plain `=`.
+
+        Any other occurrence of the name outside a read, such as a parameter, a
+        loop variable, a compound assignment, or a second assignment, maps it to None.
+        """
+        key = id(root)
+        if key not in self._single_assignments:
+            bindings: dict[str, list[ParserRuleContext | None]] = {}
+            for token in _identifier_tokens(root):
+                parent = token.parentCtx
+                if isinstance(parent, ZincParser.PrimaryExpressionContext):
+                    continue
+                bindings.setdefault(token.getText(), []).append(_plain_initializer(parent))
+            self._single_assignments[key] = {name: inits[0] if len(inits) == 1 else None for name, inits in bindings.items()}
+        return self._single_assignments[key]
+
+
+def _identifier_tokens(node) -> list[TerminalNode]:
+    tokens = []
+    for child in node.getChildren():
+        if isinstance(child, TerminalNode):
+            if child.getSymbol().type == ZincParser.IDENTIFIER:
+                tokens.append(child)
+        else:
+            tokens.extend(_identifier_tokens(child))
+    return tokens
+
+
+def _plain_initializer(target) -> ParserRuleContext | None:
+    """The value of `name = value` or `name: type = value` when `target` is that assignment's name."""
+    if isinstance(target, ZincParser.AssignmentTargetContext):
+        assignment = target.parentCtx
+        if isinstance(assignment, ZincParser.VariableAssignmentContext) and assignment.assignmentOperator().getText() == "=":
+            return assignment.expression()
+    if isinstance(target, ZincParser.TypedAssignmentTargetContext):
+        return target.parentCtx.expression()
+    return None
+
+
+def _enclosing_declaration(ctx: ParserRuleContext) -> ParserRuleContext | None:
+    """The outermost function or method declaration around `ctx`, so nested lambdas share their parent's bindings."""
+    found = None
+    node = ctx.parentCtx
+    while node is not None:
+        if isinstance(node, ZincParser.FunctionDeclarationContext):
+            found = node
+        node = node.parentCtx
+    return found
+
+
+def _literal_value(text: str) -> Constant | None:
+    if text in ("true", "false"):
+        return text == "true"
+    if is_string_literal(text):
+        return None if is_interpolated_string_literal(text) else decode_string_literal(text)
+    try:
+        parsed = parse_numeric_literal(text)
+    except ValueError:
+        return None
+    return parsed.value if parsed is not None else None
+
+
+def _apply(op: str, operands: list[Constant], exact_type: str | None) -> Constant | None:
+    """Apply one operator with Rust's semantics, or return None when it has no constant result."""
+    if len(operands) == 1:
+        (value,) = operands
+        if op in ("not", "!") and isinstance(value, bool):
+            return not value
+        if op == "-" and _is_number(value):
+            return -value
+        return None
+    left, right = operands
+    if op in ("and", "&&", "or", "||"):
+        if not (isinstance(left, bool) and isinstance(right, bool)):
+            return None
+        return (left and right) if op in ("and", "&&") else (left or right)
+    if op in ("==", "!=", "<", "<=", ">", ">="):
+        return _compare(op, left, right)
+    if isinstance(left, str) and isinstance(right, str):
+        return left + right if op == "+" else None
+    if not (_is_number(left) and _is_number(right)):
+        return None
+    if isinstance(left, float) or isinstance(right, float) or _is_float_type(exact_type):
+        return _float_arithmetic(op, float(left), float(right), exact_type)
+    return _integer_arithmetic(op, left, right)
+
+
+def _is_number(value: Constant | None) -> bool:
+    return isinstance(value, (int, float)) and not isinstance(value, bool)
+
+
+def _is_float_type(exact_type: str | None) -> bool:
+    return exact_type is not None and exact_type.startswith("f")
+
+
+def _compare(op: str, left: Constant, right: Constant) -> bool | None:
+    comparable = (_is_number(left) and _is_number(right)) or (type(left) is type(right) and isinstance(left, str))
+    if not comparable:
+        # Booleans only support equality.
+        if not (isinstance(left, bool) and isinstance(right, bool) and op in ("==", "!=")):
+            return None
+    results = {
+        "==": left == right,
+        "!=": left != right,
+        "<": comparable and left < right,
+        "<=": comparable and left <= right,
+        ">": comparable and left > right,
+        ">=": comparable and left >= right,
+    }
+    return results[op]
+
+
+def _integer_arithmetic(op: str, left: int, right: int) -> int | None:
+    if op == "+":
+        return left + right
+    if op == "-":
+        return left - right
+    if op == "*":
+        return left * right
+    if op in ("/", "%"):
+        if right == 0:
+            return None
+        quotient = abs(left) // abs(right) * (1 if (left < 0) == (right < 0) else -1)
+        return quotient if op == "/" else left - right * quotient
+    if op == "**":
+        if right < 0 or (right > MAX_FOLDED_EXPONENT and abs(left) > 1):
+            return None
+        return left**right
+    return None
+
+
+def _float_arithmetic(op: str, left: float, right: float, exact_type: str | None) -> float | None:
+    if exact_type not in (None, "f64"):
+        # An integer-typed result (or f32, which Python can't round like Rust) is left alone.
+        return None
+    if op == "+":
+        value = left + right
+    elif op == "-":
+        value = left - right
+    elif op == "*":
+        value = left * right
+    elif op == "/" and right != 0:
+        value = left / right
+    elif op == "%" and right != 0:
+        value = math.fmod(left, right)
+    else:
+        # `powf` goes through the platform's libm, whose rounding Python can't promise to match.
+        return None
+    return value if math.isfinite(value) else None
+
+
+def _fits(value: Constant | None, exact_type: str | None) -> bool:
+    """Whether a folded value can stand for the expression the checker typed as `exact_type`."""
+    if value is None:
+        return False
+    if isinstance(value, bool) or not isinstance(value, int):
+        return True
+    bounds = INTEGER_RANGES.get(exact_type or default_exact_type(BaseType.INTEGER))
+    return bounds is not None and bounds[0] <= value <= bounds[1]
@@ -0,0 +1,238 @@
+"""Statement and branch coverage behind `zinc test --coverage`.
+
+A covered build counts how often each Zinc statement runs and which way each
+`if` chain and `match` goes. The counters live in a small Rust module,
+`__zinc_coverage`, added to the generated crate. Every process that runs the
+build appends its counts to a file when it exits, whether that's by returning
+from `main`, calling `exit()`, failing an assertion, or panicking.
+`CoverageReport` adds those counts up across test files and writes them as an
+lcov tracefile and as HTML pages, both keyed to Zinc files and lines.
+
+An `if` without an `else` still counts the times none of its blocks ran, so
+every branch point has a count for each way out of it.
+"""
+
+import html
+import re
+from collections import defaultdict
+from dataclasses import dataclass
+from pathlib import Path
+
+from zinc.diagnostics import display_path
+
+COVERAGE_MODULE = "__zinc_coverage"
+LCOV_FILE_NAME = "lcov.info"
+
+
+@dataclass(frozen=True)
+class CoverageOptions:
+    """A covered build; each process that runs it appends its counts to `counts_path`."""
+
+    counts_path: Path
+
+
+@dataclass(frozen=True)
+class CoveragePoint:
+    """What one counter counts: a statement, or, with `branch` set, one way out of the branch point at `line` and `column`."""
+
+    file: str
+    line: int
+    column: int = 0
+    branch: int | None = None
+
+
+def coverage_hit(counter: int) -> str:
+    """The statement that counts one pass through the point numbered `counter`."""
+    return f"{COVERAGE_MODULE}::hit({counter});"
+
+
+def coverage_session() -> str:
+    """The first statement of `main`, which saves the counts when `main` returns or panics."""
+    return f"let __zinc_coverage_session = {COVERAGE_MODULE}::Session;"
+
+
+def coverage_flush() -> str:
+    """The statement that saves the counts before the program exits without unwinding."""
+    return f"{COVERAGE_MODULE}::flush();"
+
+
+def _rust_str(text: str) -> str:
+    return '"' + text.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def coverage_module(count: int, options: CoverageOptions) -> str:
+    """The Rust module that keeps `count` counters and appends them to the counts file."""
+    return f"""\
+mod {COVERAGE_MODULE} {{
+    use std::io::Write;
+    use std::sync::Once;
+    use std::sync::atomic::{{AtomicU64, Ordering}};
+
+    const COUNTS_PATH: &str = {_rust_str(str(options.counts_path.resolve()))};
+    #[allow(clippy::declare_interior_mutable_const)]
+    const ZERO: AtomicU64 = AtomicU64::new(0);
+    static COUNTS: [AtomicU64; {count}] = [ZERO; {count}];
+    static FLUSHED: Once = Once::new();
+
+    pub fn hit(id: usize) {{
+        COUNTS[id].fetch_add(1, Ordering::Relaxed);
+    }}
+
+    pub struct Session;
+
+    impl Drop for Session {{
+        fn drop(&mut self) {{
+            flush();
+        }}
+    }}
+
+    pub fn flush() {{
+        FLUSHED.call_once(|| {{
+            let counts: Vec<String> = COUNTS.iter().map(|count| count.load(Ordering::Relaxed).to_string()).collect();
+            let written = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(COUNTS_PATH)
+                .and_then(|mut file| writeln!(file, "{{}}", counts.join(" ")));
+            if let Err(error) = written {{
+                eprintln!("zinc coverage: can't write {{COUNTS_PATH}}: {{error}}");
+            }}
+        }});
+    }}
+}}"""
+
+
+def read_counts(path: Path, count: int) -> list[int]:
+    """Add up the counts every run appended to `path`; a missing file means nothing ran."""
+    totals = [0] * count
+    if not path.exists():
+        return totals
+    for line in path.read_text().splitlines():
+        values = line.split()
+        if len(values) == count:
+            totals = [total + int(value) for total, value in zip(totals, values)]
+    return totals
+
+
+def _percent(hit: int, total: int) -> str:
+    return f"{100 * hit / total:.1f}%" if total else "-"
+
+
+class CoverageReport:
+    """Line and branch counts merged across test harnesses, keyed by Zinc file."""
+
+    def __init__(self) -> None:
+        """Start with nothing covered."""
+        self.lines: dict[str, dict[int, int]] = defaultdict(dict)
+        # (line, column, branch) -> count
+        self.branches: dict[str, dict[tuple[int, int, int], int]] = defaultdict(dict)
+
+    def add(self, points: list[CoveragePoint], counts: list[int]) -> None:
+        """Add one build's counts; a line with several statements counts as often as the most-run one.
+
+        Points in files that aren't on disk, like the generated test harness, are left out.
+        """
+        lines: dict[tuple[str, int], int] = {}
+        for point, count in zip(points, counts):
+            if not Path(point.file).is_file():
+                continue
+            if point.branch is None:
+                lines[point.file, point.line] = max(lines.get((point.file, point.line), 0), count)
+                continue
+            key = (point.line, point.column, point.branch)
+            self.branches[point.file][key] = self.branches[point.file].get(key, 0) + count
+        for (file, line), count in lines.items():
+            self.lines[file][line] = self.lines[file].get(line, 0) + count
+
+    def files(self) -> list[str]:
+        """Every file with coverage data, in sorted order."""
+        return sorted(set(self.lines) | set(self.branches))
+
+    def line_totals(self, file: str | None = None) -> tuple[int, int]:
+        """Lines run and lines with statements, for one file or all of them."""
+        counts = [count for name in ([file] if file else self.files()) for count in self.lines.get(name, {}).values()]
+        return sum(1 for count in counts if count), len(counts)
+
+    def branch_totals(self, file: str | None = None) -> tuple[int, int]:
+        """Branches taken and branches, for one file or all of them."""
+        counts = [count for name in ([file] if file else self.files()) for count in self.branches.get(name, {}).values()]
+        return sum(1 for count in counts if count), len(counts)
+
+    def summary(self) -> str:
+        """One line with the line and branch coverage of every file."""
+        lines_hit, lines = self.line_totals()
+        branches_hit, branches = self.branch_totals()
+        return (
+            f"coverage: {_percent(lines_hit, lines)} of lines ({lines_hit}/{lines}), "
+            f"{_percent(branches_hit, branches)} of branches ({branches_hit}/{branches})"
+        )
+
+    def lcov(self) -> str:
+        """The counts as an lcov tracefile."""
+        records = []
+        for file in self.files():
+            record = ["TN:", f"SF:{Path(file).resolve()}"]
+            branches = self.branches.get(file, {})
+            evaluated = defaultdict(int)
+            for (line, column, _), count in branches.items():
+                evaluated[line, column] += count
+            for (line, column, branch), count in sorted(branches.items()):
+                record.append(f"BRDA:{line},{column},{branch},{count if evaluated[line, column] else '-'}")
+            branches_hit, branch_count = self.branch_totals(file)
+            record.extend([f"BRF:{branch_count}", f"BRH:{branches_hit}"])
+            record.extend(f"DA:{line},{count}" for line, count in sorted(self.lines.get(file, {}).items()))
+            lines_hit, line_count = self.line_totals(file)
+            record.extend([f"LF:{line_count}", f"LH:{lines_hit}", "end_of_record"])
+            records.append("\n".join(record))
+        return "\n".join(records) + "\n"
+
+    def write(self, directory: Path) -> Path:
+        """Write `lcov.info`, an `index.html` summary, and one annotated HTML page per file to `directory`; returns the index."""
+        directory.mkdir(parents=True, exist_ok=True)
+        (directory / LCOV_FILE_NAME).write_text(self.lcov())
+        rows = []
+        for file in self.files():
+            page = f"{re.sub(r'[^A-Za-z0-9_.-]', '_', display_path(file))}.html"
+            (directory / page).write_text(self._file_page(file))
+            lines_hit, lines = self.line_totals(file)
+            branches_hit, branches = self.branch_totals(file)
+            rows.append(
+                f'<tr><td><a href="{html.escape(page)}">{html.escape(display_path(file))}</a></td>'
+                f"<td>{_percent(lines_hit, lines)} ({lines_hit}/{lines})</td>"
+                f"<td>{_percent(branches_hit, branches)} ({branches_hit}/{branches})</td></tr>"
+            )
+        table = f"<table>\n<tr><th>File</th><th>Lines</th><th>Branches</th></tr>\n{chr(10).join(rows)}\n</table>"
+        index = directory / "index.html"
+        index.write_text(_html_page("Coverage", ["<h1>Coverage</h1>", f"<p>{html.escape(self.summary())}</p>", table]))
+        return index
+
+    def _file_page(self, file: str) -> str:
+        try:
+            source = Path(file).read_text().splitlines()
+        except OSError:
+            source = []
+        counts = self.lines.get(file, {})
+        missed_branches: dict[int, int] = defaultdict(int)
+        for (line, _, _), count in self.branches.get(file, {}).items():
+            if not count:
+                missed_branches[line] += 1
+        rows = []
+        for number, text in enumerate(source, start=1):
+            count = counts.get(number)
+            status = "" if count is None else "hit" if count else "miss"
+            missed = missed_branches.get(number, 0)
+            note = f" ({missed} branch{'es' if missed != 1 else ''} not taken)" if missed else ""
+            rows.append(
+                f'<tr class="{status}"><td>{number}</td><td>{"" if count is None else count}</td>'
+                f"<td><code>{html.escape(text)}</code>{html.escape(note)}</td></tr>"
+            )
+        table = f"<table>\n{chr(10).join(rows)}\n</table>"
+        return _html_page(display_path(file), [f"<h1>{html.escape(display_path(file))}</h1>", table])
+
+
+_STYLE = "<style>td { padding: 0 .5em; white-space: pre; } .hit { background: #dfd; } .miss { background: #fdd; }</style>\n"
+
+
+def _html_page(title: str, body: list[str]) -> str:
+    head = f'<!DOCTYPE html>\n<html>\n<head>\n<meta charset="utf-8">\n<title>{html.escape(title)}</title>\n{_STYLE}</head>\n<body>\n'
+    return head + "\n".join(body) + "\n</body>\n</html>\n"
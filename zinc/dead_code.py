@@ -0,0 +1,68 @@
+"""Find the struct and enum methods a program can call.
+
+The atlas already limits functions, structs, enums, and consts to what `main`
+reaches, but a reachable type carries every method it declares. This pass walks
+the reachable function bodies for `.name` accesses, then the bodies of the
+methods those names select, until no new names turn up. Matching is by name
+alone, so a method survives whenever any reachable code mentions it on any
+receiver; that errs on the side of emitting too much rather than too little.
+"""
+
+from __future__ import annotations
+
+import re
+
+from antlr4 import ParserRuleContext
+from zinc.atlas import DISPLAY_METHOD, ITERATE_METHOD, NEXT_METHOD, Atlas, StructMethodInfo
+from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.string_literals import is_interpolated_string_literal
+
+_INTERPOLATED_MEMBER = re.compile(r"\.\s*([^\W\d]\w*)")
+
+
+def used_method_names(atlas: Atlas) -> set[str]:
+    """Names of every method reachable code can call, directly or through another live method."""
+    methods = [method for owner in (*atlas.structs.values(), *atlas.enums.values()) for method in owner.methods]
+    bodies: dict[str, list[ParserRuleContext]] = {}
+    for method in methods:
+        # Default arguments are expanded at call sites, so they are live along with the method.
+        nodes = [method.body_ctx, *method.parameter_defaults.values()]
+        bodies.setdefault(method.name, []).extend(node for node in nodes if node is not None)
+
+    used = {name for owner in (*atlas.structs.values(), *atlas.enums.values()) for name in owner.methods_used}
+    # Operators and decorated methods are called from generated code rather than by name.
+    used.update(method.name for method in methods if _is_implicitly_called(method))
+    if atlas.uses_display:
+        # Display impls show values with their to_string() methods.
+        used.add(DISPLAY_METHOD)
+    if atlas.uses_iteration:
+        # for loops over structs step them with iterate() and next().
+        used.update((ITERATE_METHOD, NEXT_METHOD))
+    pending = [func.ctx for func in atlas.functions.values()] + [const.ctx for const in atlas.consts.values()]
+    pending.extend(body for name in used for body in bodies.get(name, []))
+    while pending:
+        for name in _member_names(pending.pop()):
+            if name not in used:
+                used.add(name)
+                pending.extend(bodies.get(name, []))
+    return used
+
+
+def _is_implicitly_called(method: StructMethodInfo) -> bool:
+    return method.operator_symbol is not None or method.has_decorators
+
+
+def _member_names(node: ParserRuleContext) -> set[str]:
+    """Every `.name` under `node`, including those inside string interpolations."""
+    names: set[str] = set()
+    stack = [node]
+    while stack:
+        current = stack.pop()
+        if isinstance(current, ZincParser.MemberAccessExprContext):
+            names.add(current.IDENTIFIER().getText())
+        elif isinstance(current, ZincParser.LiteralContext) and current.STRING() is not None:
+            text = current.STRING().getText()
+            if is_interpolated_string_literal(text):
+                names.update(_INTERPOLATED_MEMBER.findall(text))
+        stack.extend(child for child in current.getChildren() if isinstance(child, ParserRuleContext))
+    return names
@@ -39,8 +39,39 @@ class ResolvedDecoratorApplication:
     factory_callable_info: CallableTypeInfo | None = None
 
 
+# Built-in decorators that tag a declaration instead of wrapping it.
+MARKER_DECORATORS = frozenset({"test", "bench", "derive", "inline", "cold", "interface", "memoize"})
+# Implementations `@derive(...)` can generate for a struct or enum.
+DERIVES = ("eq", "json")
+
+
 def decorators_from_ctx(ctx: Any) -> list[DecoratorInfo]:
-    """Extract decorator metadata from a generated parser context."""
+    """Extract wrapping decorator metadata from a generated parser context."""
+    return [info for info in _all_decorators_from_ctx(ctx) if info.display_name not in MARKER_DECORATORS]
+
+
+def marker_decorators_from_ctx(ctx: Any) -> list[DecoratorInfo]:
+    """Extract built-in marker decorators such as `@test`."""
+    return [info for info in _all_decorators_from_ctx(ctx) if info.display_name in MARKER_DECORATORS]
+
+
+def has_marker_decorator(ctx: Any, marker: str) -> bool:
+    """Return True for a declaration marked with a built-in marker, e.g. `@test`."""
+    return any(info.display_name == marker for info in marker_decorators_from_ctx(ctx))
+
+
+def derives_from_ctx(ctx: Any) -> frozenset[str]:
+    """The implementations a declaration's `@derive(...)` decorators ask for, e.g. `{"eq", "json"}`."""
+    names: set[str] = set()
+    for info in marker_decorators_from_ctx(ctx):
+        if info.display_name != "derive" or info.argument_list_ctx is None:
+            continue
+        for argument in info.argument_list_ctx.argument():
+            names.add(argument.getText())
+    return frozenset(names)
+
+
+def _all_decorators_from_ctx(ctx: Any) -> list[DecoratorInfo]:
     getter = getattr(ctx, "decorator", None)
     if getter is None:
         return []
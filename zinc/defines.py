@@ -0,0 +1,123 @@
+"""Build-time constants set with `--define NAME=VALUE`.
+
+    const VERSION = "dev"
+
+    $ zinc build main.zn --define VERSION=1.2.3
+
+A define replaces the value of a top-level `const` while its module is parsed,
+so a binary can embed version or build metadata without generating code. The
+const's own value is the default, and its literal decides how the define is
+read: a string takes the text as written, a number must be a number of the
+same kind, and a bool must be `true` or `false`.
+
+`NAME` names a const of the entry module; `path/to/module.NAME` names one in
+another module of the package.
+"""
+
+import re
+
+from antlr4 import InputStream, Token
+from zinc.diagnostics import Diagnostic
+from zinc.parser.zincLexer import zincLexer as ZincLexer
+from zinc.parser.zincParser import zincParser as ZincParser
+
+KEY_PATTERN = re.compile(r"(?:(?P<module>[\w/]+)\.)?(?P<name>\w+)")
+
+_NUMBER_KINDS = {ZincParser.INTEGER: "an integer", ZincParser.FLOAT: "a float"}
+
+
+def parse_define(text: str) -> tuple[str, str]:
+    """Split a `NAME=VALUE` define, raising ValueError when it is malformed."""
+    key, equals, value = text.partition("=")
+    if not equals:
+        raise ValueError(f"expected NAME=VALUE, got '{text}'")
+    if not KEY_PATTERN.fullmatch(key):
+        raise ValueError(f"'{key}' is not a const name or module.NAME")
+    return key, value
+
+
+def defines_by_module(defines: tuple[tuple[str, str], ...], entry_module_id: str) -> dict[str, dict[str, str]]:
+    """Group defines by the module whose const they set; a later define of the same const wins."""
+    grouped: dict[str, dict[str, str]] = {}
+    for key, value in defines:
+        match = KEY_PATTERN.fullmatch(key)
+        grouped.setdefault(match["module"] or entry_module_id, {})[match["name"]] = value
+    return grouped
+
+
+def _diagnostic(message: str, token: Token) -> Diagnostic:
+    file = getattr(token.getInputStream(), "name", None)
+    return Diagnostic(message, file, token.line, token.column, token.column + len(token.text or " "))
+
+
+def _default_value(tokens: list[Token], index: int) -> tuple[int, int] | None:
+    """The token range of a const's value when it is one literal, optionally negated, alone on its line."""
+    end = index + 1 if tokens[index].text != "-" else index + 2
+    if end > len(tokens):
+        return None
+    literal = tokens[end - 1]
+    if literal.type not in (ZincParser.STRING, *_NUMBER_KINDS) and literal.text not in ("true", "false"):
+        return None
+    if literal.type == ZincParser.STRING and end - index == 2:
+        return None
+    following = tokens[end] if end < len(tokens) else None
+    if following is not None and following.type != Token.EOF and following.line == literal.line:
+        return None
+    return index, end
+
+
+def _replacement(value: str, default: list[Token]) -> list[tuple[str, int]] | str:
+    """The (text, type) tokens that spell `value` like the default's literal, or why they can't."""
+    literal = default[-1]
+    if literal.type == ZincParser.STRING:
+        return [("`" + value.replace("`", "``") + "`", ZincParser.STRING)]
+    if literal.text in ("true", "false"):
+        return [(value, literal.type)] if value in ("true", "false") else "must be true or false"
+    kind = _NUMBER_KINDS[literal.type]
+    lexer = ZincLexer(InputStream(value))
+    lexer.removeErrorListeners()
+    spelled = [(token.text, token.type) for token in lexer.getAllTokens()]
+    digits = spelled[1:] if spelled[:1] and spelled[0][0] == "-" else spelled
+    if len(digits) != 1 or digits[0][1] != literal.type:
+        return f"must be {kind}"
+    return spelled
+
+
+def apply_defines(tokens: list[Token], defines: dict[str, str]) -> tuple[list[Token], list[Diagnostic]]:
+    """Replace the value of each top-level const named in `defines`, returning the new tokens and any errors."""
+    if not defines:
+        return tokens, []
+    result: list[Token] = []
+    diagnostics: list[Diagnostic] = []
+    depth = 0
+    index = 0
+    while index < len(tokens):
+        token = tokens[index]
+        if token.text == "{":
+            depth += 1
+        elif token.text == "}":
+            depth -= 1
+        name = tokens[index + 1] if index + 1 < len(tokens) else None
+        if depth > 0 or token.text != "const" or name is None or name.text not in defines or index + 3 >= len(tokens):
+            result.append(token)
+            index += 1
+            continue
+        result.extend(tokens[index : index + 3])
+        span = _default_value(tokens, index + 3)
+        if span is None:
+            diagnostics.append(_diagnostic(f"const '{name.text}' is set with --define, so its value must be a single literal", name))
+            index += 3
+            continue
+        default = tokens[span[0] : span[1]]
+        replacement = _replacement(defines[name.text], default)
+        if isinstance(replacement, str):
+            message = f"--define {name.text}={defines[name.text]}: the value {replacement}, like the const's default"
+            diagnostics.append(_diagnostic(message, default[-1]))
+            replacement = [(token.text, token.type) for token in default]
+        for text, token_type in replacement:
+            placed = default[0].clone()
+            placed.text, placed.type = text, token_type
+            placed.stop = default[-1].stop
+            result.append(placed)
+        index = span[1]
+    return result, diagnostics
@@ -0,0 +1,251 @@
+"""Compiler diagnostics and how the CLI prints them.
+
+A rendered diagnostic shows the message, its location, the source line with
+a caret under the offending span, and any notes and suggestions:
+
+    error[Z0003]: variable 'x' expects a compatible 'i64' value
+     --> main.zn:2:14
+      |
+    2 |     x: i64 = "text"
+      |              ^^^^^^ expected i64, found String
+      |
+      = help: ...
+
+Errors with a code end with a pointer to `zinc explain`, which describes the
+code at length. With `--error-format json` each diagnostic is instead printed
+as one JSON object per line; see `Diagnostic.to_dict`.
+"""
+
+import json
+import os
+import re
+import unicodedata
+from dataclasses import dataclass
+from pathlib import Path
+
+from antlr4.error.ErrorListener import ErrorListener
+from zinc.style import paint
+
+ERROR_FORMATS = ("human", "json")
+SEVERITY_COLORS = {"error": "red", "warning": "yellow"}
+GUTTER_COLOR = "blue"
+
+
+@dataclass(frozen=True)
+class Diagnostic:
+    """A compiler message; `line` is 1-based and columns are 0-based code-point offsets.
+
+    `label` is printed under the caret; `notes` and `suggestions` follow the
+    source excerpt as `note:` and `help:` lines. `code` is the stable error
+    code, when the diagnostic has one.
+    """
+
+    message: str
+    file: str | None = None
+    line: int | None = None
+    column: int | None = None
+    end_column: int | None = None
+    severity: str = "error"
+    label: str | None = None
+    notes: tuple[str, ...] = ()
+    suggestions: tuple[str, ...] = ()
+    code: str | None = None
+
+    def __str__(self) -> str:
+        location = _location(self)
+        return f"{location}: {self.severity}: {self.message}" if location else f"{self.severity}: {self.message}"
+
+    def to_dict(self) -> dict:
+        """JSON-ready form; span lines and columns are 1-based and `end_column` is exclusive."""
+        span = None
+        if self.line is not None:
+            span = {
+                "line": self.line,
+                "column": None if self.column is None else self.column + 1,
+                "end_column": None if self.end_column is None else self.end_column + 1,
+            }
+        return {
+            "file": None if self.file is None else display_path(self.file),
+            "span": span,
+            "severity": self.severity,
+            "code": self.code,
+            "message": self.message,
+            "label": self.label,
+            "notes": list(self.notes),
+            "suggestions": list(self.suggestions),
+        }
+
+
+class DiagnosticCollector(ErrorListener):
+    """Collect ANTLR syntax errors as diagnostics instead of printing them."""
+
+    def __init__(self) -> None:
+        self.diagnostics: list[Diagnostic] = []
+
+    def syntaxError(self, recognizer, offendingSymbol, line, column, msg, e):  # noqa: N802, N803 (ANTLR API)
+        width = 1
+        if offendingSymbol is not None and offendingSymbol.type != -1 and offendingSymbol.text:
+            width = len(offendingSymbol.text)
+        stream = offendingSymbol.getInputStream() if offendingSymbol is not None else recognizer.inputStream
+        file = getattr(stream, "name", None)
+        token = offendingSymbol.text if offendingSymbol is not None else None
+        self.diagnostics.append(Diagnostic(msg, file, line, column, column + width, label=syntax_error_label(msg, token)))
+
+
+def syntax_error_label(message: str, token: str | None = None) -> str | None:
+    """A short caret label for ANTLR's common syntax error messages; `token` is the offending token's text."""
+    if match := re.match(r"missing (.+?) at ", message):
+        return f"expected {match.group(1)} here"
+    if match := re.match(r"(?:extraneous|mismatched) input (.+?) expecting", message):
+        return f"unexpected {match.group(1)}"
+    if message.startswith("no viable alternative") and token:
+        return f"unexpected '{token}'"
+    return None
+
+
+def node_span(node) -> tuple[str | None, int, int, int | None]:
+    """File, line, column, and end column of a parse tree node; the end is None when the node spans lines."""
+    start, stop = node.start, node.stop
+    file = getattr(start.getInputStream(), "name", None)
+    end_column = None
+    if stop is not None and stop.line == start.line and stop.text:
+        end_column = stop.column + len(stop.text)
+    return file, start.line, start.column, end_column
+
+
+def mismatch_label(expected: str | None, found: str | None) -> str | None:
+    """The caret label for a type mismatch."""
+    if expected is None or found is None:
+        return None
+    return f"expected {expected}, found {found}"
+
+
+def edit_distance(left: str, right: str) -> int:
+    """Edits between two names: insertions, deletions, substitutions, and swaps of adjacent characters."""
+    rows = [list(range(len(right) + 1))]
+    for row, left_char in enumerate(left, start=1):
+        current = [row]
+        for column, right_char in enumerate(right, start=1):
+            cost = min(rows[-1][column] + 1, current[column - 1] + 1, rows[-1][column - 1] + (left_char != right_char))
+            if row > 1 and column > 1 and left_char == right[column - 2] and left[row - 2] == right_char:
+                cost = min(cost, rows[-2][column - 2] + 1)
+            current.append(cost)
+        rows.append(current)
+    return rows[-1][-1]
+
+
+def closest_name(name: str, candidates) -> str | None:
+    """The candidate most likely meant by a misspelled `name`, if any is close enough.
+
+    A candidate qualifies when it differs only in case, or is within a third of
+    the name's length in edits without rewriting the whole name; ties go to
+    the alphabetically first candidate so suggestions are stable.
+    """
+    best = None
+    best_distance = max(1, len(name) // 3) + 1
+    for candidate in sorted(set(candidates)):
+        if candidate == name:
+            continue
+        if candidate.lower() == name.lower():
+            return candidate
+        distance = edit_distance(name, candidate)
+        if distance < best_distance and distance < len(name):
+            best, best_distance = candidate, distance
+    return best
+
+
+def did_you_mean(name: str, candidates) -> tuple[str, ...]:
+    """A `help:` suggestion naming the closest candidate, or nothing."""
+    candidate = closest_name(name, candidates)
+    return (f"did you mean '{candidate}'?",) if candidate is not None else ()
+
+
+def display_path(file: str) -> str:
+    """Show Zinc files relative to the working directory when they are under it."""
+    try:
+        return os.path.relpath(file) if Path(file).resolve().is_relative_to(Path.cwd()) else file
+    except (OSError, ValueError):
+        return file
+
+
+def _location(diagnostic: Diagnostic, file: str | None = None) -> str:
+    column = None if diagnostic.column is None else diagnostic.column + 1
+    return ":".join(str(part) for part in (file or diagnostic.file, diagnostic.line, column) if part is not None)
+
+
+def _char_width(char: str) -> int:
+    """Terminal columns one character takes: two for wide CJK, none for combining marks."""
+    if unicodedata.combining(char) or unicodedata.category(char) in ("Mn", "Me", "Cf"):
+        return 0
+    return 2 if unicodedata.east_asian_width(char) in ("W", "F") else 1
+
+
+def render_diagnostic(diagnostic: Diagnostic, source: str | None = None, color: bool = False) -> str:
+    """Render one diagnostic; `source` is the text of its file, used for the excerpt."""
+    severity_color = SEVERITY_COLORS.get(diagnostic.severity, "red")
+    severity = f"{diagnostic.severity}[{diagnostic.code}]" if diagnostic.code else diagnostic.severity
+    lines = [paint(severity, color, fg=severity_color, bold=True) + paint(f": {diagnostic.message}", color, bold=True)]
+    source_lines = source.splitlines() if source is not None else []
+    line_number = diagnostic.line
+    width = len(str(line_number)) if line_number is not None else 0
+    pad = " " * width
+    if diagnostic.file is not None:
+        lines.append(f"{pad}{paint('-->', color, fg=GUTTER_COLOR, bold=True)} {_location(diagnostic, display_path(diagnostic.file))}")
+    gutter = paint("|", color, fg=GUTTER_COLOR, bold=True)
+    if line_number is not None and 0 < line_number <= len(source_lines):
+        text = source_lines[line_number - 1]
+        start = diagnostic.column if diagnostic.column is not None else len(text) - len(text.lstrip())
+        end = diagnostic.end_column if diagnostic.end_column is not None else len(text.rstrip())
+        # Columns count characters; pad by display width, keeping tabs, so the caret lines up with the excerpt.
+        indent = "".join(char if char == "\t" else " " * _char_width(char) for char in text[:start])
+        carets = paint("^" * max(1, sum(map(_char_width, text[start:end]))), color, fg=severity_color, bold=True)
+        label = f" {paint(diagnostic.label, color, fg=severity_color, bold=True)}" if diagnostic.label else ""
+        lines.append(f"{pad} {gutter}")
+        lines.append(f"{paint(str(line_number), color, fg=GUTTER_COLOR, bold=True)} {gutter} {text}")
+        lines.append(f"{pad} {gutter} {indent}{carets}{label}")
+    elif diagnostic.label:
+        lines.append(f"{pad} = {diagnostic.label}")
+    if diagnostic.notes or diagnostic.suggestions:
+        if line_number is not None and source_lines:
+            lines.append(f"{pad} {gutter}")
+        lines.extend(f"{pad} = {paint('note', color, bold=True)}: {note}" for note in diagnostic.notes)
+        lines.extend(f"{pad} = {paint('help', color, bold=True)}: {suggestion}" for suggestion in diagnostic.suggestions)
+    return "\n".join(lines)
+
+
+def render_diagnostics(diagnostics: list[Diagnostic], color: bool = False) -> str:
+    """Render diagnostics separated by blank lines, reading each file once, with a count when there are several of them."""
+    sources: dict[str, str | None] = {}
+    for diagnostic in diagnostics:
+        if diagnostic.file is not None and diagnostic.file not in sources:
+            try:
+                sources[diagnostic.file] = Path(diagnostic.file).read_text(encoding="utf-8")
+            except OSError:
+                sources[diagnostic.file] = None
+    blocks = [render_diagnostic(diagnostic, sources.get(diagnostic.file) if diagnostic.file else None, color) for diagnostic in diagnostics]
+    errors = sum(diagnostic.severity == "error" for diagnostic in diagnostics)
+    warnings = sum(diagnostic.severity == "warning" for diagnostic in diagnostics)
+    if errors > 1:
+        blocks.append(render_diagnostic(Diagnostic(f"aborting due to {errors} previous errors"), color=color))
+    elif not errors and warnings > 1:
+        blocks.append(render_diagnostic(Diagnostic(f"{warnings} warnings emitted", severity="warning"), color=color))
+    codes = sorted({diagnostic.code for diagnostic in diagnostics if diagnostic.severity == "error" and diagnostic.code})
+    if codes:
+        blocks.append(explain_hint(codes, color))
+    return "\n\n".join(blocks)
+
+
+def explain_hint(codes: list[str], color: bool = False) -> str:
+    """rustc's closing pointer to the extended explanations of the error codes that were reported."""
+    subject = "this error" if len(codes) == 1 else "an error"
+    hint = f"For more information about {subject}, try `zinc explain {codes[0]}`."
+    if len(codes) > 1:
+        hint = f"Some errors have detailed explanations: {', '.join(codes)}.\n{hint}"
+    return paint(hint, color, bold=True)
+
+
+def format_diagnostics(diagnostics: list[Diagnostic], error_format: str = "human", color: bool = False) -> str:
+    """Render diagnostics for people, or as JSON lines for editors and CI."""
+    if error_format == "json":
+        return "\n".join(json.dumps(diagnostic.to_dict()) for diagnostic in diagnostics)
+    return render_diagnostics(diagnostics, color)
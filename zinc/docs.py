@@ -0,0 +1,194 @@
+"""API documentation for `zinc doc`.
+
+Documentation is read from the parse tree alone, so libraries without a
+`main` are documented as readily as programs. Each module's public
+declarations (names without a leading underscore) are listed with their
+signature, taken from the source text up to the opening brace, and the `///`
+comments above them; `//!` comments at the top of a file describe the module.
+"""
+
+import html
+import re
+from dataclasses import dataclass, field
+
+from antlr4 import ParserRuleContext, Token
+from antlr4.tree.Tree import TerminalNode
+
+from zinc.modules import parse_source
+from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.trivia import MODULE_DOC_MARKER, Trivia
+
+
+@dataclass
+class DocItem:
+    """One documented declaration; `members` holds a struct's fields and methods or an enum's variants and methods."""
+
+    kind: str
+    name: str
+    signature: str
+    doc: str = ""
+    members: list["DocItem"] = field(default_factory=list)
+
+
+@dataclass
+class ModuleDoc:
+    """The documentation of one module."""
+
+    name: str
+    doc: str = ""
+    items: list[DocItem] = field(default_factory=list)
+
+
+def _is_public(name: str) -> bool:
+    return not name.startswith("_")
+
+
+class _DocCollector:
+    """Collects the public declarations of one parsed module."""
+
+    def __init__(self, tree: ZincParser.ProgramContext, source: str):
+        self.source = source
+        self.trivia = Trivia.of(tree)
+
+    def signature(self, start: Token, end: Token) -> str:
+        """The source text from `start` up to but not including `end`, whitespace collapsed."""
+        return " ".join(self.source[start.start : end.start].split())
+
+    def doc(self, ctx: ParserRuleContext) -> str:
+        return self.trivia.doc(ctx.start)
+
+    def function(self, ctx: ZincParser.FunctionDeclarationContext) -> DocItem | None:
+        name = ctx.functionName().getText()
+        if not _is_public(name):
+            return None
+        return DocItem("fn", name, self.signature(_keyword(ctx, "fn"), ctx.block().start), self.doc(ctx))
+
+    def struct(self, ctx: ZincParser.StructDeclarationContext) -> DocItem | None:
+        name = ctx.IDENTIFIER().getText()
+        if not _is_public(name):
+            return None
+        item = DocItem("struct", name, self.signature(_keyword(ctx, "struct"), _keyword(ctx, "{")), self.doc(ctx))
+        for member in ctx.structBody().structMember():
+            if (field_ctx := member.structField()) is not None:
+                field_name = field_ctx.IDENTIFIER().getText()
+                if _is_public(field_name):
+                    item.members.append(DocItem("field", field_name, _text(self.source, field_ctx), self.doc(field_ctx)))
+            elif (method := self.function(member.functionDeclaration())) is not None:
+                item.members.append(method)
+        return item
+
+    def enum(self, ctx: ZincParser.EnumDeclarationContext) -> DocItem | None:
+        name = ctx.IDENTIFIER().getText()
+        if not _is_public(name):
+            return None
+        item = DocItem("enum", name, self.signature(_keyword(ctx, "enum"), _keyword(ctx, "{")), self.doc(ctx))
+        body = ctx.enumBody()
+        for variant in body.enumVariant():
+            item.members.append(DocItem("variant", variant.IDENTIFIER().getText(), _text(self.source, variant), self.doc(variant)))
+        for declaration in body.functionDeclaration():
+            if (method := self.function(declaration)) is not None:
+                item.members.append(method)
+        return item
+
+    def const(self, ctx: ZincParser.ConstDeclarationContext) -> DocItem | None:
+        name = ctx.IDENTIFIER().getText()
+        if not _is_public(name):
+            return None
+        return DocItem("const", name, _text(self.source, ctx), self.doc(ctx))
+
+    def collect(self, tree: ZincParser.ProgramContext) -> list[DocItem]:
+        items = []
+        for statement in tree.statement():
+            for getter, build in (
+                (statement.constDeclaration, self.const),
+                (statement.structDeclaration, self.struct),
+                (statement.enumDeclaration, self.enum),
+                (statement.functionDeclaration, self.function),
+            ):
+                if (declaration := getter()) is not None and (item := build(declaration)) is not None:
+                    items.append(item)
+        return items
+
+
+def _keyword(ctx: ParserRuleContext, text: str) -> Token:
+    """The first token among the children of `ctx` spelled `text`."""
+    return next(child.symbol for child in ctx.getChildren() if isinstance(child, TerminalNode) and child.symbol.text == text)
+
+
+def _text(source: str, ctx: ParserRuleContext) -> str:
+    return " ".join(source[ctx.start.start : ctx.stop.stop + 1].split())
+
+
+def document_module(source: str, name: str, origin: str | None = None) -> ModuleDoc:
+    """Parse one module's source and collect its documentation; syntax errors raise ZincSyntaxError."""
+    tree, _ = parse_source(source, origin or name)
+    collector = _DocCollector(tree, source)
+    return ModuleDoc(name, collector.trivia.doc(tree.start, MODULE_DOC_MARKER), collector.collect(tree))
+
+
+_HEADINGS = {"const": "Constants", "struct": "Structs", "enum": "Enums", "fn": "Functions"}
+
+
+def _grouped(items: list[DocItem]) -> list[tuple[str, list[DocItem]]]:
+    groups = [(heading, [item for item in items if item.kind == kind]) for kind, heading in _HEADINGS.items()]
+    return [(heading, group) for heading, group in groups if group]
+
+
+def render_markdown(module: ModuleDoc) -> str:
+    """A module's documentation as Markdown."""
+    lines = [f"# {module.name}", ""]
+    if module.doc:
+        lines += [module.doc, ""]
+    for heading, items in _grouped(module.items):
+        lines += [f"## {heading}", ""]
+        for item in items:
+            lines += [f"### {item.name}", "", "```zinc", item.signature, "```", ""]
+            if item.doc:
+                lines += [item.doc, ""]
+            for member in item.members:
+                lines += [f"- `{member.signature}`" + (f": {_first_paragraph(member.doc)}" if member.doc else "")]
+            if item.members:
+                lines.append("")
+    return "\n".join(lines).rstrip() + "\n"
+
+
+def _first_paragraph(doc: str) -> str:
+    return " ".join(doc.split("\n\n")[0].split())
+
+
+def _html_paragraphs(doc: str) -> str:
+    return "".join(f"<p>{html.escape(' '.join(block.split()))}</p>" for block in re.split(r"\n\s*\n", doc) if block.strip())
+
+
+def render_html(module: ModuleDoc) -> str:
+    """A module's documentation as a self-contained HTML page."""
+    body = [f"<h1>{html.escape(module.name)}</h1>", _html_paragraphs(module.doc)]
+    for heading, items in _grouped(module.items):
+        body.append(f"<h2>{heading}</h2>")
+        for item in items:
+            body.append(f'<h3 id="{html.escape(item.name)}">{html.escape(item.name)}</h3>')
+            body.append(f"<pre><code>{html.escape(item.signature)}</code></pre>")
+            body.append(_html_paragraphs(item.doc))
+            if item.members:
+                entries = "".join(
+                    f"<li><code>{html.escape(member.signature)}</code>{_html_paragraphs(member.doc)}</li>" for member in item.members
+                )
+                body.append(f"<ul>{entries}</ul>")
+    return _html_page(module.name, body)
+
+
+def _html_page(title: str, body: list[str]) -> str:
+    head = f'<!DOCTYPE html>\n<html>\n<head>\n<meta charset="utf-8">\n<title>{html.escape(title)}</title>\n</head>\n<body>\n'
+    return head + "\n".join(part for part in body if part) + "\n</body>\n</html>\n"
+
+
+def render_index(modules: list[ModuleDoc], doc_format: str) -> str:
+    """A page linking to every module's documentation."""
+    if doc_format == "markdown":
+        lines = ["# Modules", ""]
+        for module in modules:
+            summary = f": {_first_paragraph(module.doc)}" if module.doc else ""
+            lines.append(f"- [{module.name}]({module.name}.md){summary}")
+        return "\n".join(lines) + "\n"
+    entries = "".join(f'<li><a href="{html.escape(module.name)}.html">{html.escape(module.name)}</a></li>' for module in modules)
+    return _html_page("Modules", ["<h1>Modules</h1>", f"<ul>{entries}</ul>"])
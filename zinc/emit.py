@@ -0,0 +1,124 @@
+"""Dump intermediate compiler stages for `zinc compile --emit`.
+
+Each stage has a readable text form and a JSON form:
+
+- `tokens`: the lexer's output for the entry file
+- `ast`: the parse tree of the entry file
+- `typed-ast`: the parse tree of every reachable function instance, with the
+  type the checker resolved for each node. Generic functions appear once per
+  specialization.
+- `rust`: the generated program
+
+`tokens` and `ast` stop after parsing, so they work on programs that do not
+type-check.
+"""
+
+import json
+from pathlib import Path
+
+from antlr4 import ParserRuleContext, Token
+from antlr4.tree.Tree import TerminalNode
+from zinc.api import CheckedProgram, front_end, parse
+from zinc.codegen import RustProgram
+from zinc.modules import lex_source
+from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.symbols import SymbolTable, exact_type_to_rust
+
+EMIT_STAGES = ("tokens", "ast", "typed-ast", "rust")
+# File suffixes for `--out-dir`; JSON output always uses `.json`.
+EMIT_SUFFIXES = {"tokens": ".tokens", "ast": ".ast", "typed-ast": ".typed-ast", "rust": ".rs"}
+
+
+def token_name(token_type: int) -> str:
+    """Symbolic token name, or the quoted literal for keywords and punctuation."""
+    if token_type == Token.EOF:
+        return "EOF"
+    symbolic = ZincParser.symbolicNames[token_type]
+    return symbolic if symbolic != "<INVALID>" else ZincParser.literalNames[token_type]
+
+
+def node_name(ctx: ParserRuleContext) -> str:
+    """Rule name of a node; labeled alternatives such as `additiveExpr` keep their label."""
+    name = type(ctx).__name__.removesuffix("Context")
+    return name[0].lower() + name[1:]
+
+
+def tokens_data(source: str) -> list[dict]:
+    """The entry file's tokens as JSON-ready records."""
+    return [{"type": token_name(token.type), "text": token.text, "line": token.line, "column": token.column} for token in lex_source(source)]
+
+
+def tree_data(node, symbols: SymbolTable | None = None, scope: str | None = None) -> dict:
+    """A parse tree as nested records; with `symbols`, nodes carry their resolved type in `scope`."""
+    if isinstance(node, TerminalNode):
+        token = node.getSymbol()
+        return {"token": token_name(token.type), "text": token.text, "line": token.line, "column": token.column}
+    data = {"node": node_name(node), "line": node.start.line, "column": node.start.column}
+    if symbols is not None:
+        symbol = symbols.lookup_by_interval(node.getSourceInterval(), scope)
+        if symbol is not None:
+            data["type"] = exact_type_to_rust(symbol.exact_type, symbol.resolved_type)
+    data["children"] = [tree_data(child, symbols, scope) for child in node.getChildren()]
+    return data
+
+
+def typed_functions_data(checked: CheckedProgram) -> list[dict]:
+    """Every reachable function instance in code generation order, with a typed tree."""
+    functions = []
+    for mangled_name in checked.atlas.topological_order():
+        func = checked.atlas.functions[mangled_name]
+        functions.append(
+            {
+                "function": func.qualified_name,
+                "rust_name": func.mangled_name,
+                "file": str(checked.graph.path_for_module(func.module_id)),
+                "tree": tree_data(func.ctx, checked.symbols, func.mangled_name),
+            }
+        )
+    return functions
+
+
+def rust_data(program: RustProgram, *, rustfmt: bool = False) -> dict:
+    """The generated program with the dependencies a Cargo project needs for it."""
+    return {
+        "rust": program.render(rustfmt=rustfmt),
+        "runtime_features": sorted(program.runtime_features),
+        "crates": dict(sorted(program.crates.items())),
+    }
+
+
+def format_tokens(tokens: list[dict]) -> str:
+    """One token per line: position, type, and text."""
+    return "\n".join(f"{token['line']}:{token['column']:<4} {token['type']:<16} {json.dumps(token['text'])}" for token in tokens)
+
+
+def format_tree(data: dict, depth: int = 0) -> list[str]:
+    """Indent children under their node; typed nodes end in `: type`."""
+    indent = "  " * depth
+    if "token" in data:
+        label = data["token"] if data["token"] == f"'{data['text']}'" else f"{data['token']} {json.dumps(data['text'])}"
+        return [f"{indent}{label} @{data['line']}:{data['column']}"]
+    typed = f" : {data['type']}" if "type" in data else ""
+    lines = [f"{indent}{data['node']}{typed} @{data['line']}:{data['column']}"]
+    for child in data["children"]:
+        lines.extend(format_tree(child, depth + 1))
+    return lines
+
+
+def emit_stage(stage: str, file: Path, as_json: bool = False, program: RustProgram | None = None, *, rustfmt: bool = False) -> str:
+    """Render one compilation stage of an entry file; `program` is used for `rust` when given."""
+    if stage == "tokens":
+        tokens = tokens_data(file.read_text(encoding="utf-8"))
+        return json.dumps(tokens, indent=2) if as_json else format_tokens(tokens)
+    if stage == "ast":
+        tree = tree_data(parse(file.read_text(encoding="utf-8"), str(file)))
+        return json.dumps(tree, indent=2) if as_json else "\n".join(format_tree(tree))
+    if stage == "typed-ast":
+        functions = typed_functions_data(front_end(file))
+        if as_json:
+            return json.dumps(functions, indent=2)
+        sections = [f"// {func['function']} ({func['rust_name']}) in {func['file']}\n" + "\n".join(format_tree(func["tree"])) for func in functions]
+        return "\n\n".join(sections)
+    if program is None:
+        program = front_end(file).codegen.generate()
+    return json.dumps(rust_data(program, rustfmt=rustfmt), indent=2) if as_json else program.render(rustfmt=rustfmt)
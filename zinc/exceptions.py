@@ -1,3 +1,6 @@
+from zinc.diagnostics import Diagnostic
+
+
 class ZincError(Exception):
     """Base class for all Zinc-related errors."""
 
@@ -11,12 +14,70 @@ class ZincLogLevelError(ZincError):
 
 
 class ZincTypeError(ZincError):
-    """Raised when Zinc type inference or validation fails."""
+    """Raised when Zinc type inference or validation fails.
 
-    pass
+    `ctx` is the parse tree node to point at when it is narrower than the
+    failing statement. `expected` and `found` describe a type mismatch, and
+    `notes`, `suggestions`, and `code` carry over to the diagnostic.
+    """
+
+    def __init__(
+        self,
+        message: str,
+        *,
+        ctx=None,
+        expected: str | None = None,
+        found: str | None = None,
+        notes: tuple[str, ...] = (),
+        suggestions: tuple[str, ...] = (),
+        code: str | None = None,
+    ):
+        super().__init__(message)
+        self.ctx = ctx
+        self.expected = expected
+        self.found = found
+        self.notes = notes
+        self.suggestions = suggestions
+        self.code = code
+
+
+class ZincTypeErrors(ZincTypeError):
+    """Every type error found in one compilation, as diagnostics sorted by location."""
+
+    def __init__(self, diagnostics: list[Diagnostic]):
+        super().__init__("\n".join(str(diagnostic) for diagnostic in diagnostics))
+        self.diagnostics = diagnostics
 
 
 class ZincModuleError(ZincError):
     """Raised when package or module loading fails."""
 
     pass
+
+
+class ZincSyntaxError(ZincModuleError):
+    """Raised when a module fails to parse; `diagnostics` holds every syntax error in it."""
+
+    def __init__(self, message: str, diagnostics: list[Diagnostic]):
+        super().__init__(message)
+        self.diagnostics = diagnostics
+
+
+class ZincBuildError(ZincError):
+    """Raised when turning generated Rust into a native binary fails."""
+
+    pass
+
+
+class ZincRustcErrors(ZincBuildError):
+    """Raised when rustc rejects generated code; `diagnostics` point at the Zinc lines the code came from."""
+
+    def __init__(self, diagnostics: list[Diagnostic]):
+        super().__init__("\n".join(str(diagnostic) for diagnostic in diagnostics))
+        self.diagnostics = diagnostics
+
+
+class ZincFormatError(ZincError):
+    """Raised when source cannot be formatted safely."""
+
+    pass
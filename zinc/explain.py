@@ -0,0 +1,461 @@
+"""Stable diagnostic codes and the extended explanations `zinc explain` prints.
+
+Each code names one kind of mistake, and several checks in the compiler may
+report the same code. A code is never reused for a different mistake once it
+has shipped. Every explanation has an erroneous example, which the test suite
+compiles to make sure it still reports its code, followed by a fix.
+"""
+
+import re
+from dataclasses import dataclass
+
+
+@dataclass(frozen=True)
+class ErrorCode:
+    """A diagnostic code: a one-line title, prose on why it happens, and a broken example with its fix."""
+
+    code: str
+    title: str
+    explanation: str
+    example: str
+    fixed: str
+
+    def render(self) -> str:
+        """The explanation as `zinc explain` prints it."""
+        indent = "    "
+
+        def block(source: str) -> str:
+            return "\n".join(indent + line if line else "" for line in source.strip("\n").splitlines())
+
+        return "\n\n".join(
+            [
+                f"{self.code}: {self.title}",
+                self.explanation.strip(),
+                "Erroneous code example:",
+                block(self.example),
+                "One way to fix it:",
+                block(self.fixed),
+            ]
+        )
+
+
+def _codes(*codes: ErrorCode) -> dict[str, ErrorCode]:
+    return {code.code: code for code in codes}
+
+
+ERROR_CODES = _codes(
+    ErrorCode(
+        "Z0001",
+        "unknown variable",
+        """
+A name was read, but nothing in scope assigns it: no local, parameter,
+constant, or import has that name. This is usually a typo, and the error
+suggests the closest name that is in scope.
+""",
+        """
+fn main() {
+    total = 3
+    print(totl)
+}
+""",
+        """
+fn main() {
+    total = 3
+    print(total)
+}
+""",
+    ),
+    ErrorCode(
+        "Z0002",
+        "variable read before it is assigned",
+        """
+A variable exists only from its first assignment on. Reading it earlier in the
+function, or after the block that assigned it has ended, has no value to read.
+Assign the variable before the read, outside of any block that might not run.
+""",
+        """
+fn main() {
+    if true {
+        label = "yes"
+    }
+    print(label)
+}
+""",
+        """
+fn main() {
+    label = "no"
+    if true {
+        label = "yes"
+    }
+    print(label)
+}
+""",
+    ),
+    ErrorCode(
+        "Z0003",
+        "value doesn't fit the variable's declared type",
+        """
+A variable declared with a type, like `count: i64`, keeps that type for its
+whole life, and every value assigned to it must be compatible. To hold a value
+of another type, convert it or store it under a new name.
+""",
+        """
+fn main() {
+    count: i64 = 1
+    count = "two"
+}
+""",
+        """
+fn main() {
+    count: i64 = 1
+    count = 2
+    print(count)
+}
+""",
+    ),
+    ErrorCode(
+        "Z0004",
+        "argument doesn't fit the parameter's type",
+        """
+A call passed a value whose type isn't compatible with the type the parameter
+is annotated with. Pass a value of that type, or convert the argument first.
+""",
+        """
+fn double(n: i64) -> i64 {
+    return n * 2
+}
+
+fn main() {
+    print(double("4"))
+}
+""",
+        """
+fn double(n: i64) -> i64 {
+    return n * 2
+}
+
+fn main() {
+    print(double(4))
+}
+""",
+    ),
+    ErrorCode(
+        "Z0005",
+        "wrong arguments for a call",
+        """
+A call must give every parameter without a default exactly one value, pass
+positional arguments before named ones, and name only parameters the function
+has. This error reports a call with too many or too few arguments, or with a
+named argument the function doesn't take.
+""",
+        """
+fn greet(name: string, greeting: string = "hello") {
+    print("{greeting}, {name}")
+}
+
+fn main() {
+    greet(greting="hi", name="zinc")
+}
+""",
+        """
+fn greet(name: string, greeting: string = "hello") {
+    print("{greeting}, {name}")
+}
+
+fn main() {
+    greet(greeting="hi", name="zinc")
+}
+""",
+    ),
+    ErrorCode(
+        "Z0006",
+        "operator not defined for these operands",
+        """
+An operator was applied to values it has no meaning for, like adding two
+structs that don't say how. A struct supports an operator once it defines it
+with a method such as `fn operator+(rhs)`, and an enum supports `==` with
+`@derive(eq)`.
+""",
+        """
+struct Meters {
+    value: f64
+}
+
+fn main() {
+    total = Meters { value: 1.5 } + Meters { value: 2.0 }
+}
+""",
+        """
+struct Meters {
+    value: f64
+
+    fn operator+(rhs) -> Self {
+        return Meters { value: self.value + rhs.value }
+    }
+}
+
+fn main() {
+    total = Meters { value: 1.5 } + Meters { value: 2.0 }
+    print(total.value)
+}
+""",
+    ),
+    ErrorCode(
+        "Z0007",
+        "no such field or member",
+        """
+A field or member was accessed that the type doesn't declare. Check the
+spelling against the struct's fields; the error suggests the closest one.
+""",
+        """
+struct Point {
+    x: i64
+    y: i64
+}
+
+fn main() {
+    p = Point { x: 1, y: 2 }
+    print(p.z)
+}
+""",
+        """
+struct Point {
+    x: i64
+    y: i64
+}
+
+fn main() {
+    p = Point { x: 1, y: 2 }
+    print(p.y)
+}
+""",
+    ),
+    ErrorCode(
+        "Z0008",
+        "unknown enum variant",
+        """
+An enum variant was named that the enum doesn't declare.
+""",
+        """
+enum Color {
+    Red
+    Green
+}
+
+fn main() {
+    c = Color.Blue
+}
+""",
+        """
+enum Color {
+    Red
+    Green
+    Blue
+}
+
+fn main() {
+    _c = Color.Blue
+}
+""",
+    ),
+    ErrorCode(
+        "Z0009",
+        "unknown function",
+        """
+A function was called that isn't defined in the module or imported into it.
+Define the function, or import it from the module that does.
+""",
+        """
+fn main() {
+    print(square(3))
+}
+""",
+        """
+fn square(n: i64) -> i64 {
+    return n * n
+}
+
+fn main() {
+    print(square(3))
+}
+""",
+    ),
+    ErrorCode(
+        "Z0010",
+        "branches have incompatible types",
+        """
+When an `if` or `match` is used as a value, every branch must produce a value
+of a compatible type, since the result has one type whichever branch runs.
+""",
+        """
+fn main() {
+    n = 3
+    label = if n > 2 { "big" } else { 0 }
+}
+""",
+        """
+fn main() {
+    n = 3
+    label = if n > 2 { "big" } else { "small" }
+    print(label)
+}
+""",
+    ),
+    ErrorCode(
+        "Z0011",
+        "unused variable",
+        """
+This warning means a variable is assigned but never read, which often points
+to a typo or a leftover. Prefix the name with an underscore when the value is
+deliberately unused.
+""",
+        """
+fn main() {
+    result = 42
+}
+""",
+        """
+fn main() {
+    _result = 42
+}
+""",
+    ),
+    ErrorCode(
+        "Z0012",
+        "unused function",
+        """
+This warning means a private function, one whose name starts with `_`, is
+never called. Nothing outside its module can call it, so remove it or call it.
+""",
+        """
+fn _double(n: i64) -> i64 {
+    return n * 2
+}
+
+fn main() {
+    print(3 * 2)
+}
+""",
+        """
+fn _double(n: i64) -> i64 {
+    return n * 2
+}
+
+fn main() {
+    print(_double(3))
+}
+""",
+    ),
+    ErrorCode(
+        "Z0013",
+        "unreachable code",
+        """
+This warning means a statement, branch, loop body, or match arm can never run:
+it follows a `return`, `break`, or `continue`, its condition is constant, or
+earlier arms already cover every case.
+""",
+        """
+fn main() {
+    for i in 0..3 {
+        break
+        print(i)
+    }
+}
+""",
+        """
+fn main() {
+    for i in 0..3 {
+        print(i)
+        break
+    }
+}
+""",
+    ),
+    ErrorCode(
+        "Z0014",
+        "unknown decorator",
+        """
+A function was decorated with a name that is neither a built-in decorator nor
+a function in scope.
+""",
+        """
+@memoise
+fn fib(n: i64) -> i64 {
+    return n
+}
+
+fn main() {
+    print(fib(3))
+}
+""",
+        """
+fn fib(n: i64) -> i64 {
+    return n
+}
+
+fn main() {
+    print(fib(3))
+}
+""",
+    ),
+    ErrorCode(
+        "Z0015",
+        "return value doesn't fit the declared return type",
+        """
+A function declared with a return type, like `-> string`, must return values
+compatible with it on every path. Convert the value, or change the declared
+type.
+""",
+        """
+fn label(count: i64) -> string {
+    return count
+}
+
+fn main() {
+    print(label(3))
+}
+""",
+        """
+fn label(count: i64) -> string {
+    return "{count}"
+}
+
+fn main() {
+    print(label(3))
+}
+""",
+    ),    ErrorCode(
+        "Z0016",
+        "function named after a builtin",
+        """
+A function was given the name of a builtin such as `print`, `exit`, or `uuid`.
+Calls by that name always run the builtin, so the function could never be
+called. Give it a name of its own.
+""",
+        """
+fn exit(code: i64) {
+    print("stopping with {code}")
+}
+
+fn main() {
+    exit(3)
+}
+""",
+        """
+fn stop(code: i64) {
+    print("stopping with {code}")
+}
+
+fn main() {
+    stop(3)
+}
+""",
+    ),
+)
+
+
+def lookup_code(code: str) -> ErrorCode | None:
+    """The entry for a code like `Z0012`, or `z12`; None when there is no such code."""
+    if match := re.fullmatch(r"[zZ]?0*(\d{1,4})", code.strip()):
+        return ERROR_CODES.get(f"Z{int(match.group(1)):04d}")
+    return None
@@ -0,0 +1,70 @@
+"""Fixed-size arrays: `[T; N]` types and `[value; N]` literals.
+
+    board: [i64; 4] = [0, 0, 0, 0]
+    zeros = [0.0; 8]
+
+A fixed-size array holds exactly `N` elements and compiles to a Rust array
+instead of a `Vec`, so it can't grow or shrink. The grammar spells array types
+and literals without a length, so the length is folded into the closing
+bracket before parsing: `; 8]` becomes one `]` token whose text keeps the
+length. The tree then holds an ordinary array type, or a one-element array
+literal, and `array_length` reads the length back from its closing bracket.
+Lengths are integer literals.
+
+Indexing past the end panics by default. Built with `--bounds error`, reading
+an element through a non-literal index gives a `Result` instead, which a
+`try` block unwraps; a literal index is checked at compile time either way.
+"""
+
+from antlr4 import Token
+from zinc.diagnostics import Diagnostic
+from zinc.numeric_literals import numeric_literal_value
+from zinc.parser.zincParser import zincParser as ZincParser
+
+BOUNDS_MODES = ("panic", "error")
+DEFAULT_BOUNDS_MODE = "panic"
+
+
+def _diagnostic(message: str, token: Token) -> Diagnostic:
+    file = getattr(token.getInputStream(), "name", None)
+    return Diagnostic(message, file, token.line, token.column, token.column + len(token.text or " "))
+
+
+def fold_array_lengths(tokens: list[Token]) -> tuple[list[Token], list[Diagnostic]]:
+    """Fold each `; N]` inside brackets into its closing `]`, returning the new tokens and any errors."""
+    result: list[Token] = []
+    diagnostics: list[Diagnostic] = []
+    brackets: list[str] = []
+    index = 0
+    while index < len(tokens):
+        token = tokens[index]
+        if token.text in ("(", "[", "{", "#["):
+            brackets.append(token.text)
+        elif token.text in (")", "]", "}") and brackets:
+            brackets.pop()
+        if token.type != ZincParser.SEMI or not brackets or brackets[-1] != "[":
+            result.append(token)
+            index += 1
+            continue
+        length = tokens[index + 1] if index + 1 < len(tokens) else None
+        closing = tokens[index + 2] if index + 2 < len(tokens) else None
+        if length is None or length.type != ZincParser.INTEGER or closing is None or closing.type != ZincParser.RBRACK:
+            diagnostics.append(_diagnostic("the length of a fixed-size array must be an integer literal, as in [i64; 4]", length or token))
+            result.append(token)
+            index += 1
+            continue
+        folded = closing.clone()
+        folded.text = f"; {length.text}]"
+        folded.start, folded.line, folded.column = token.start, token.line, token.column
+        brackets.pop()
+        result.append(folded)
+        index += 3
+    return result, diagnostics
+
+
+def array_length(ctx) -> int | None:
+    """The length spelled in an array type or literal's closing bracket, or None for a growable list."""
+    closing = ctx.RBRACK() if hasattr(ctx, "RBRACK") else None
+    if closing is None or not closing.getText().startswith(";"):
+        return None
+    return int(numeric_literal_value(closing.getText()[1:-1].strip()))
@@ -0,0 +1,310 @@
+"""Canonical source formatter for Zinc.
+
+The formatter is lossless: it walks the parser's tokens in order and treats the
+text between two tokens (whitespace, comments, extern rust blocks, macro
+declarations and invocations, `when` blocks) as trivia that is carried over. Line breaks are kept as written (blank-line runs collapse
+to one), indentation is recomputed from bracket nesting, and spacing within a
+line is normalized using the parse tree to tell e.g. unary from binary `-` or
+generic `<` from comparison. A `let` rebinding and a `static` field are
+written back as they were (see zinc.shadowing and zinc.static_fields). The
+result is re-lexed and must produce the same tokens and comments as the input.
+"""
+
+from dataclasses import dataclass
+from pathlib import Path
+
+from antlr4 import ParserRuleContext
+from antlr4.tree.Tree import TerminalNodeImpl
+from zinc.build import BUILD_DIR_NAME
+from zinc.exceptions import ZincFormatError
+from zinc.conditional import conditional_spans
+from zinc.macros import macro_spans
+from zinc.modules import find_rust_extern_spans, lex_source, parse_source
+from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.shadowing import LET_KEYWORD, LetBindingToken
+from zinc.static_fields import STATIC_KEYWORD, StaticFieldToken
+from zinc.trivia import COMMENT_PATTERN
+
+INDENT = "    "
+OPENERS = {"(": ")", "[": "]", "{": "}", "#[": "]"}
+CLOSERS = frozenset({")", "]", "}"})
+CALLABLE_ENDS = frozenset({")", "]", "fn", "Ok", "Err", "Some", "type", "self"})
+NO_SPACE_AFTER = frozenset({"(", "[", "#[", ".", "@"})
+NO_SPACE_BEFORE = frozenset({")", "]", ",", ".", ":", ";"})
+CONTINUATION_ENDINGS = frozenset(
+    {
+        "=", "+=", "-=", "*=", "/=", "%=", "**=", "&=", "|=", "^=", "<<=", ">>=",
+        "+", "-", "*", "/", "%", "**", "<<", ">>", "&", "|", "^", "&&", "||", "and", "or",
+        "==", "!=", "<", "<=", ">", ">=", "->", "<-", "in",
+    }
+)  # fmt: skip
+PREFIX_OPERATORS = frozenset({"-", "!", "~", "<-", ".."})
+PREFIX_OPERATOR_CONTEXTS = (
+    ZincParser.UnaryExprContext,
+    ZincParser.ChannelReceiveExprContext,
+    ZincParser.FieldSpreadContext,
+    ZincParser.ArgumentContext,
+    ZincParser.SelectReceiveCaseContext,
+)
+TIGHT_OPERATOR_CONTEXTS = (ZincParser.RangeExprContext, ZincParser.RangePatternContext, ZincParser.ImportPathContext)
+TIGHT_OPERATORS = frozenset({"..", "..=", "/"})
+# Calls and indexing bind their bracket to whatever comes before it, even a literal like `"ab"[0]`.
+POSTFIX_BRACKET_CONTEXTS = (ZincParser.FunctionCallExprContext, ZincParser.IndexAccessExprContext, ZincParser.IndexAccessContext)
+GENERIC_CONTEXTS = (ZincParser.TypeContext, ZincParser.TypeQueryTypeContext)
+
+
+@dataclass
+class _Token:
+    text: str
+    start: int
+    stop: int
+    type: int
+    parent: ParserRuleContext
+    index_in_parent: int
+
+
+def _collect_tokens(tree: ParserRuleContext) -> list[_Token]:
+    """Return the terminal tokens of a parse tree in source order."""
+    tokens: list[_Token] = []
+    stack: list = [tree]
+    while stack:
+        node = stack.pop()
+        if isinstance(node, TerminalNodeImpl):
+            token = node.symbol
+            if token.type == -1:
+                continue
+            parent = node.parentCtx
+            index = next(i for i in range(parent.getChildCount()) if parent.getChild(i) is node)
+            if isinstance(token, LetBindingToken):
+                # The parser never sees `let` or `static`; they are spelled again in front of the token they mark.
+                tokens.append(_Token(f"{LET_KEYWORD} {token.text}", token.let_start, token.stop, token.type, parent, index))
+                continue
+            if isinstance(token, StaticFieldToken):
+                tokens.append(_Token(f"{STATIC_KEYWORD} {token.text}", token.static_start, token.stop, token.type, parent, index))
+                continue
+            tokens.append(_Token(token.text, token.start, token.stop, token.type, parent, index))
+            continue
+        stack.extend(reversed([node.getChild(i) for i in range(node.getChildCount())]))
+    return tokens
+
+
+def _is_prefix_operator(token: _Token) -> bool:
+    """Return True for operators written directly against their operand, e.g. `-x`, `<-ch`, `..rest`."""
+    if token.text not in PREFIX_OPERATORS:
+        return False
+    if isinstance(token.parent, ZincParser.SelectReceiveCaseContext):
+        return True
+    return isinstance(token.parent, PREFIX_OPERATOR_CONTEXTS) and token.index_in_parent == 0
+
+
+def _is_generic_bracket(token: _Token) -> bool:
+    return token.text in ("<", ">") and isinstance(token.parent, GENERIC_CONTEXTS)
+
+
+def _is_closer(token: _Token) -> bool:
+    """Return True for a closing bracket, including the `; N]` that ends a fixed-size array (see zinc.fixed_arrays)."""
+    return token.text in CLOSERS or token.type == ZincParser.RBRACK
+
+
+def _needs_space(prev: _Token, cur: _Token) -> bool:
+    """Decide whether two adjacent tokens on the same line are separated by a space."""
+    if prev.text in NO_SPACE_AFTER or cur.text in NO_SPACE_BEFORE or cur.type == ZincParser.RBRACK:
+        return False
+    if _is_prefix_operator(prev):
+        return False
+    if isinstance(cur.parent, ZincParser.OperatorSymbolContext) and cur.index_in_parent == 0 and not cur.text.isalpha():
+        return False
+    if prev.text == "{" and cur.text == "}":
+        return False
+    if (prev.text == "{" and isinstance(prev.parent, ZincParser.CollectionLiteralContext)) or (
+        cur.text == "}" and isinstance(cur.parent, ZincParser.CollectionLiteralContext)
+    ):
+        return False
+    if "=" in (prev.text, cur.text) and isinstance(cur.parent if cur.text == "=" else prev.parent, ZincParser.ArgumentContext):
+        return False
+    if _is_generic_bracket(cur) or (_is_generic_bracket(prev) and prev.text == "<"):
+        return False
+    if isinstance(prev.parent, TIGHT_OPERATOR_CONTEXTS) and prev.text in TIGHT_OPERATORS:
+        return False
+    if isinstance(cur.parent, TIGHT_OPERATOR_CONTEXTS) and cur.text in TIGHT_OPERATORS:
+        return False
+    if isinstance(cur.parent, POSTFIX_BRACKET_CONTEXTS) and cur.text in ("(", "["):
+        return False
+    if cur.text == "(":
+        word_before = prev.type == ZincParser.IDENTIFIER or prev.text in CALLABLE_ENDS or _is_generic_bracket(prev)
+        return not (word_before or isinstance(prev.parent, ZincParser.OperatorSymbolContext))
+    if cur.text == "[" and not isinstance(cur.parent, (ZincParser.OperatorSymbolContext, ZincParser.StructCompositionContext, ZincParser.ImportStatementContext)):
+        return not (prev.type in (ZincParser.IDENTIFIER, ZincParser.STRING) or prev.text in (")", "]"))
+    return True
+
+
+class _Writer:
+    """Accumulate formatted lines while tracking bracket-based indentation."""
+
+    def __init__(self) -> None:
+        self.lines: list[str] = []
+        self.current = ""
+        self.line_number = 0
+        # Each open bracket remembers the line it was opened on; several brackets
+        # opened on one line only indent the following lines once.
+        self.open_brackets: list[int] = []
+        self.pending_blank = False
+        self.continuation = False
+
+    def indent_level(self, closing: bool = False) -> int:
+        brackets = self.open_brackets
+        if closing and brackets:
+            # A closer dedents past every bracket opened on its opener's line.
+            brackets = [line for line in brackets if line != brackets[-1]]
+        return len(set(brackets)) + (1 if self.continuation and not closing else 0)
+
+    def at_line_start(self) -> bool:
+        return self.current == ""
+
+    def newline(self, blank_after: bool = False) -> None:
+        if self.current or self.lines:
+            self.lines.append(self.current.rstrip())
+        self.current = ""
+        self.line_number += 1
+        self.pending_blank = self.pending_blank or blank_after
+
+    def begin_line(self, closing: bool = False) -> None:
+        after_opener = bool(self.lines) and self.lines[-1].endswith(tuple(OPENERS))
+        if self.pending_blank and self.lines and self.lines[-1] != "" and not closing and not after_opener:
+            self.lines.append("")
+        self.pending_blank = False
+        self.current = INDENT * self.indent_level(closing)
+
+    def write(self, text: str, space: bool) -> None:
+        if space and self.current.strip():
+            self.current += " "
+        self.current += text
+        self.line_number += text.count("\n")
+
+    def finish(self) -> str:
+        if self.current.strip():
+            self.lines.append(self.current.rstrip())
+        while self.lines and self.lines[0] == "":
+            self.lines.pop(0)
+        while self.lines and self.lines[-1] == "":
+            self.lines.pop()
+        return "\n".join(self.lines) + "\n" if self.lines else ""
+
+
+def _emit_trivia(writer: _Writer, text: str, verbatim_spans: list[tuple[int, int]], offset: int) -> bool:
+    """Carry whitespace, comments, and verbatim regions across; return True if a line break was written."""
+    broke_line = False
+    index = 0
+    while index < len(text):
+        span_end = next((end for start, end in verbatim_spans if start == offset + index), None)
+        if span_end is not None:
+            if writer.at_line_start():
+                writer.begin_line()
+            writer.write(text[index : span_end - offset + 1], space=True)
+            index = span_end - offset + 1
+            continue
+        match = COMMENT_PATTERN.match(text, index)
+        if match is not None:
+            if writer.at_line_start():
+                writer.begin_line()
+                writer.write(match.group(0), space=False)
+            else:
+                # Trailing comments keep their original gap so aligned columns survive.
+                gap = len(text[:index]) - len(text[:index].rstrip(" \t"))
+                writer.write(" " * max(gap, 1) + match.group(0), space=False)
+            index = match.end()
+            continue
+        if text[index] == "\n":
+            newlines = 0
+            while index < len(text) and text[index] in " \t\r\n":
+                newlines += text[index] == "\n"
+                index += 1
+            writer.newline(blank_after=newlines > 1)
+            broke_line = True
+            continue
+        index += 1
+    return broke_line
+
+
+def _preprocessed_spans(source: str) -> list[tuple[int, int]]:
+    """The outermost macro declarations, macro invocations, and `when` chains of a module."""
+    tokens = lex_source(source)
+    spans: list[tuple[int, int]] = []
+    for start, stop in sorted(macro_spans(tokens) + conditional_spans(tokens)):
+        if not spans or start > spans[-1][1]:
+            spans.append((start, stop))
+    return spans
+
+
+def _with_preprocessed(tokens: list[_Token], source: str, tree: ParserRuleContext) -> list[_Token]:
+    """Replace the tokens of each macro expansion or `when` chain with one token spelling it as written.
+
+    The parser only sees what they expand to, and macro declarations are not
+    in the tree at all, so all of them are written back verbatim, spaced like
+    a name.
+    """
+    spans = _preprocessed_spans(source)
+    if not spans:
+        return tokens
+    result = [token for token in tokens if not any(start <= token.start <= stop for start, stop in spans)]
+    result += [_Token(source[start : stop + 1], start, stop, ZincParser.IDENTIFIER, tree, 0) for start, stop in spans]
+    return sorted(result, key=lambda token: token.start)
+
+
+def format_source(source: str, origin: str = "<source>") -> str:
+    """Return the canonical formatting of a Zinc module."""
+    tree, _ = parse_source(source, origin)
+    tokens = _with_preprocessed(_collect_tokens(tree), source, tree)
+    verbatim_spans = find_rust_extern_spans(source)
+    writer = _Writer()
+    cursor = 0
+    prev: _Token | None = None
+    for token in tokens:
+        broke_line = _emit_trivia(writer, source[cursor : token.start], verbatim_spans, cursor)
+        if writer.at_line_start():
+            if broke_line and prev is not None:
+                writer.continuation = (
+                    prev.text in CONTINUATION_ENDINGS and not _is_prefix_operator(prev) and not _is_generic_bracket(prev)
+                ) or token.text == "."
+            writer.begin_line(closing=_is_closer(token))
+            writer.write(token.text, space=False)
+        else:
+            writer.write(token.text, space=prev is None or _needs_space(prev, token))
+        if token.text in OPENERS:
+            writer.open_brackets.append(writer.line_number)
+        elif _is_closer(token) and writer.open_brackets:
+            writer.open_brackets.pop()
+        cursor = token.stop + 1
+        prev = token
+    _emit_trivia(writer, source[cursor:], verbatim_spans, cursor)
+    formatted = writer.finish()
+    _check_equivalent(source, formatted, origin)
+    return formatted
+
+
+def _token_signature(source: str, origin: str) -> tuple[list[str], list[str]]:
+    """Return the token texts and comment texts of a module, ignoring layout."""
+    tree, _ = parse_source(source, origin)
+    stripped = source
+    for start, end in reversed(sorted(find_rust_extern_spans(source) + _preprocessed_spans(source))):
+        stripped = stripped[:start] + " ".join(source[start : end + 1].split()) + stripped[end + 1 :]
+    return [token.text for token in _collect_tokens(tree)], COMMENT_PATTERN.findall(stripped)
+
+
+def _check_equivalent(original: str, formatted: str, origin: str) -> None:
+    """Guard against formatter bugs: formatting must never change tokens or drop comments."""
+    if _token_signature(original, origin) != _token_signature(formatted, origin):
+        raise ZincFormatError(f"internal formatter error: formatting {origin} would change its meaning")
+
+
+def collect_sources(paths: list[Path]) -> list[Path]:
+    """Expand directories to the Zinc sources beneath them, skipping build output."""
+    sources = []
+    for path in paths:
+        if path.is_dir():
+            sources.extend(
+                source for source in sorted(path.rglob("*.zn")) if BUILD_DIR_NAME not in source.relative_to(path).parts
+            )
+        else:
+            sources.append(path)
+    return sources
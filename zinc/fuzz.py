@@ -0,0 +1,154 @@
+"""Fuzzing for the lexer, parser, and type checker behind `zinc fuzz`.
+
+Editors run the front end on every keystroke, so it sees far more broken
+programs than finished ones. Any input may be rejected with a compile error,
+but none should make the compiler itself crash. The fuzzer starts from seed
+programs, mutates them at the character and token level, and runs each
+mutant through the front end. An exception that isn't a `ZincError` is a
+crash; crashing inputs are shrunk to a small reproducer before they are
+reported.
+
+Runs are deterministic for a given seed, so a crash found once can be found
+again with the same `--seed` and `--iterations`.
+"""
+
+import random
+import tempfile
+import traceback
+from collections.abc import Callable, Iterator
+from dataclasses import dataclass
+from pathlib import Path
+
+from zinc.api import front_end
+from zinc.exceptions import ZincError
+from zinc.modules import PKG_FILE_NAME
+from zinc.parser.zincLexer import zincLexer as ZincLexer
+
+# Longer inputs are cut down so a run of duplications can't make one check take seconds.
+MAX_INPUT_LENGTH = 4000
+# Token and character fragments the mutations insert, besides pieces of the seeds.
+FRAGMENTS = (
+    *(name.strip("'") for name in ZincLexer.literalNames if name.startswith("'")),
+    "x",
+    "main",
+    "0",
+    "-1",
+    "9223372036854775808",
+    "1.5e309",
+    '"',
+    '"{x}"',
+    "'",
+    "\\",
+    "\n",
+    "\t",
+    "\x00",
+    "é",
+    "\U0001f600",
+    "\ud800",
+)
+
+
+@dataclass(frozen=True)
+class Crash:
+    """An input that made the front end raise something other than a compile error."""
+
+    text: str
+    error: str
+    traceback: str
+
+    @property
+    def signature(self) -> str:
+        """The exception type and the innermost compiler frame, which tell crashes apart."""
+        frames = [line.strip() for line in self.traceback.splitlines() if line.strip().startswith('File "')]
+        return f"{self.error.split(':', 1)[0]} at {frames[-1] if frames else '?'}"
+
+
+class FrontEndTarget:
+    """Runs inputs through the front end as the entry file of a scratch package."""
+
+    def __init__(self, root: Path) -> None:
+        """Use `root` for the scratch package; it must exist."""
+        (root / PKG_FILE_NAME).write_text('[package]\nname = "fuzz"\nversion = "0.1.0"\n')
+        self.entry = root / "main.zn"
+        self.entry.write_text("")
+
+    def crash(self, text: str) -> Crash | None:
+        """Check `text`, returning the crash it causes, if any; compile errors are expected."""
+        try:
+            front_end(self.entry, {self.entry: text})
+        except ZincError:
+            return None
+        except Exception as exc:  # noqa: BLE001 - anything else is what the fuzzer is looking for
+            return Crash(text, f"{type(exc).__name__}: {exc}", traceback.format_exc())
+        return None
+
+
+def _span(rng: random.Random, text: str) -> tuple[int, int]:
+    start = rng.randrange(len(text) + 1)
+    return start, min(len(text), start + rng.randint(1, 40))
+
+
+def mutate(rng: random.Random, text: str, seeds: list[str]) -> str:
+    """Apply one random edit: delete, duplicate, or replace a span, insert a fragment, or splice in another seed."""
+    choice = rng.randrange(6)
+    start, end = _span(rng, text)
+    if choice == 0:
+        return text[:start] + text[end:]
+    if choice == 1:
+        at = rng.randrange(len(text) + 1)
+        return text[:at] + text[start:end] + text[at:]
+    if choice == 2:
+        return text[:start] + rng.choice(FRAGMENTS) + text[start:]
+    if choice == 3:
+        return text[:start] + rng.choice(FRAGMENTS) + text[end:]
+    if choice == 4:
+        other = rng.choice(seeds)
+        cut = rng.randrange(len(other) + 1)
+        return text[:start] + other[cut:]
+    return text[:start]
+
+
+def mutants(seeds: list[str], iterations: int, seed: int = 0) -> Iterator[str]:
+    """Yield `iterations` mutated inputs, each a seed with one to four edits; the same `seed` gives the same inputs."""
+    rng = random.Random(seed)
+    for _ in range(iterations):
+        text = rng.choice(seeds)
+        for _ in range(rng.randint(1, 4)):
+            text = mutate(rng, text, seeds)
+        yield text[:MAX_INPUT_LENGTH]
+
+
+def shrink(text: str, still_crashes: Callable[[str], bool]) -> str:
+    """Cut lines, then characters, out of a crashing input for as long as it keeps crashing."""
+    for unit in ("lines", "chars"):
+        pieces = text.splitlines(keepends=True) if unit == "lines" else list(text)
+        chunk = max(1, len(pieces) // 2)
+        while chunk >= 1:
+            i = 0
+            while i < len(pieces):
+                candidate = pieces[:i] + pieces[i + chunk :]
+                if candidate and still_crashes("".join(candidate)):
+                    pieces = candidate
+                else:
+                    i += chunk
+            chunk //= 2
+        text = "".join(pieces)
+    return text
+
+
+def fuzz(
+    seeds: list[str], iterations: int, seed: int = 0, *, progress: Callable[[int], None] | None = None
+) -> list[Crash]:
+    """Check `iterations` mutants of `seeds` and return one shrunk crash per distinct signature."""
+    crashes: dict[str, Crash] = {}
+    with tempfile.TemporaryDirectory(prefix="zinc-fuzz-") as workdir:
+        target = FrontEndTarget(Path(workdir))
+        for i, text in enumerate(mutants(seeds, iterations, seed), start=1):
+            crash = target.crash(text)
+            if crash is not None and crash.signature not in crashes:
+                signature = crash.signature
+                small = shrink(text, lambda candidate: (found := target.crash(candidate)) is not None and found.signature == signature)
+                crashes[signature] = target.crash(small) or crash
+            if progress is not None:
+                progress(i)
+    return list(crashes.values())
@@ -0,0 +1,2257 @@
+"""Tree-walking interpreter behind `zinc run --interpret`.
+
+The interpreter runs a checked program by evaluating its parse trees
+directly, so a change can be tried without generating Rust or waiting for
+cargo. It keeps the semantics of compiled programs: assignment copies values,
+functions update the arguments they are given, a nested block that assigns a
+variable a value of another type shadows it, integers are checked for
+overflow at their exact width, and numbers print the way Rust prints them.
+
+Concurrency, Rust interop, and compile-time metadata need the compiled
+backend. A program that uses them is rejected before it starts, so it never
+runs halfway.
+"""
+
+import json
+import math
+import struct
+import sys
+import threading
+import uuid
+from collections.abc import Callable, Iterator
+from dataclasses import dataclass, field, replace
+from decimal import Decimal
+from typing import TextIO
+
+from antlr4 import CommonTokenStream, ParserRuleContext
+from antlr4.tree.Tree import TerminalNode
+from zinc.api import CheckedProgram
+from zinc.ast.types import BaseType
+from zinc.atlas import DISPLAY_METHOD, ITERATE_METHOD, NEXT_METHOD, EnumInstance, StructFieldInfo, StructInstance, StructMethodInfo
+from zinc.codegen import DEFAULT_OVERFLOW_MODE
+from zinc.decorators import decorators_from_ctx, has_marker_decorator
+from zinc.exceptions import ZincError
+from zinc.fixed_arrays import array_length
+from zinc.modules import (
+    enum_variant_path_from_ctx,
+    extract_identifier_path,
+    format_string_literal,
+    qualified_name_path,
+    source_lexer,
+    struct_path_from_ctx,
+)
+from zinc.number_format import group_thousands, parse_number_format
+from zinc.numeric_literals import INTEGER_SUFFIX_SET, parse_numeric_literal
+from zinc.operators import ASSIGNMENT_TO_BINARY_OPERATOR, INDEX_OPERATOR_SYMBOL
+from zinc.string_literals import decode_string_literal, is_interpolated_string_literal
+from zinc.symbols import included_file
+from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.parser.zincVisitor import zincVisitor
+from zinc.shadowing import is_let_binding
+
+# Builtins that only exist in compiled programs.
+UNSUPPORTED_BUILTINS = {
+    "chan": "channels",
+    "close": "channels",
+    "meta": "compile-time metadata",
+    "type": "compile-time metadata",
+    "line": "compile-time metadata",
+    "has_component": "compile-time metadata",
+    "implements": "compile-time metadata",
+    "on_interrupt": "interrupt handlers",
+    "temp_file": "temporary files",
+    "temp_dir": "temporary files",
+}
+
+# Every builtin function a program can call by name.
+BUILTINS = frozenset(
+    {
+        "print",
+        "print_debug",
+        "dbg",
+        "assert",
+        "assert_eq",
+        "exit",
+        "dict",
+        "sort_dict",
+        "set",
+        "sort_set",
+        "uuid",
+        "to_json",
+        "include_str",
+        "matrix",
+        "format_number",
+        *UNSUPPORTED_BUILTINS,
+    }
+)
+
+# Syntax that only compiled programs support, with the name used in errors.
+UNSUPPORTED_SYNTAX = {
+    ZincParser.ExternRustBlockContext: "extern rust blocks",
+    ZincParser.AsyncFunctionDeclarationContext: "async functions",
+    ZincParser.SpawnStatementContext: "spawn",
+    ZincParser.SelectStatementContext: "select",
+    ZincParser.ChannelSendStatementContext: "channels",
+    ZincParser.ChannelReceiveExprContext: "channels",
+    ZincParser.AwaitExprContext: "await",
+    ZincParser.BuiltinTypeQueryContext: "compile-time metadata",
+}
+
+INTEGER_BOUNDS = {
+    "i8": (-(2**7), 2**7 - 1),
+    "i16": (-(2**15), 2**15 - 1),
+    "i32": (-(2**31), 2**31 - 1),
+    "i64": (-(2**63), 2**63 - 1),
+    "i128": (-(2**127), 2**127 - 1),
+    "isize": (-(2**63), 2**63 - 1),
+    "u8": (0, 2**8 - 1),
+    "u16": (0, 2**16 - 1),
+    "u32": (0, 2**32 - 1),
+    "u64": (0, 2**64 - 1),
+    "u128": (0, 2**128 - 1),
+    "usize": (0, 2**64 - 1),
+}
+
+# Rust's overflow panic for each operator; `**` overflows while multiplying.
+OVERFLOW_VERBS = {"+": "add", "-": "subtract", "*": "multiply", "**": "multiply", "neg": "negate"}
+STACK_SIZE = 512 * 1024 * 1024
+RECURSION_LIMIT = 200_000
+
+
+class InterpreterError(ZincError):
+    """Raised when a program uses something only the compiled backend supports."""
+
+    def __init__(self, feature: str):
+        super().__init__(f"the interpreter doesn't support {feature} yet; run the program without --interpret")
+
+
+# --- Values ---
+
+
+class SizedInt(int):
+    """An integer of an exact type other than the default one, like `i32` or `u8`."""
+
+    kind: str
+
+    def __new__(cls, value: int, kind: str):
+        """Wrap `value` as an integer of type `kind`."""
+        number = super().__new__(cls, value)
+        number.kind = kind
+        return number
+
+
+class F32(float):
+    """A 32-bit float; its value is always rounded to f32 precision."""
+
+    def __new__(cls, value: float):
+        """Round `value` to the nearest f32."""
+        return super().__new__(cls, _round_f32(value))
+
+
+@dataclass
+class Ok:
+    """`Ok(value)`."""
+
+    value: object
+
+
+@dataclass
+class Err:
+    """`Err(error)`."""
+
+    value: object
+
+
+@dataclass
+class Some:
+    """`Some(value)`."""
+
+    value: object
+
+
+class _NoneType:
+    def __repr__(self) -> str:
+        return "None"
+
+
+NONE = _NoneType()  # The Option `None`; Python's None is the unit value `()`.
+
+
+@dataclass(eq=False)
+class DictValue:
+    """A `dict` or, with `ordered`, a `sort_dict`."""
+
+    entries: dict = field(default_factory=dict)
+    ordered: bool = False
+
+    def keys(self) -> list:
+        """Keys in iteration order."""
+        return sorted(self.entries) if self.ordered else list(self.entries)
+
+    def __eq__(self, other: object) -> bool:
+        return isinstance(other, DictValue) and self.entries == other.entries
+
+
+@dataclass(eq=False)
+class SetValue:
+    """A `set` or, with `ordered`, a `sort_set`."""
+
+    items: dict = field(default_factory=dict)
+    ordered: bool = False
+
+    def values(self) -> list:
+        """Elements in iteration order."""
+        return sorted(self.items) if self.ordered else list(self.items)
+
+    def __eq__(self, other: object) -> bool:
+        return isinstance(other, SetValue) and self.items.keys() == other.items.keys()
+
+
+@dataclass(eq=False)
+class StructValue:
+    """An instance of a named struct, or of an anonymous one when `qualified_name` is None."""
+
+    qualified_name: str | None
+    name: str
+    fields: dict
+
+    def __eq__(self, other: object) -> bool:
+        return isinstance(other, StructValue) and (self.qualified_name, self.fields) == (other.qualified_name, other.fields)
+
+    def __hash__(self) -> int:
+        return hash((self.qualified_name, tuple(_hashable(value) for value in self.fields.values())))
+
+
+@dataclass(eq=False)
+class EnumValue:
+    """A variant of an enum, with its payload fields."""
+
+    qualified_name: str
+    name: str
+    variant: str
+    fields: dict = field(default_factory=dict)
+
+    def __eq__(self, other: object) -> bool:
+        return isinstance(other, EnumValue) and (self.qualified_name, self.variant, self.fields) == (
+            other.qualified_name,
+            other.variant,
+            other.fields,
+        )
+
+    def __hash__(self) -> int:
+        return hash((self.qualified_name, self.variant, tuple(_hashable(value) for value in self.fields.values())))
+
+
+@dataclass(frozen=True)
+class RangeValue:
+    """`start..stop` or `start..=stop`."""
+
+    start: int
+    stop: int
+    inclusive: bool
+
+    def values(self) -> range:
+        """The integers in the range."""
+        return range(self.start, self.stop + 1 if self.inclusive else self.stop)
+
+
+@dataclass(eq=False)
+class Function:
+    """A function value: a top-level or nested function, a lambda, or a method bound to its receiver.
+
+    `code` is the declaration's parse tree in the interpreter and its compiled
+    `Code` in the bytecode VM. `memo` holds the results of a `@memoize`
+    function, keyed by its arguments.
+    """
+
+    name: str
+    code: object
+    module_id: str
+    closure: "Scope | None" = None
+    owner: StructInstance | EnumInstance | None = None
+    receiver: object = None
+    has_receiver: bool = False
+    constructs: str | None = None
+    memo: dict | None = None
+
+
+@dataclass(eq=False)
+class Builtin:
+    """A builtin function used as a value, such as a collection method bound to its receiver."""
+
+    name: str
+    call: Callable[[list], object]
+
+
+@dataclass(frozen=True)
+class TypeValue:
+    """A struct or enum named in an expression, as in `Counter.new()` or `Shape.Dot`."""
+
+    qualified_name: str
+    kind: str
+
+
+@dataclass(frozen=True)
+class ModuleValue:
+    """A module imported under an alias."""
+
+    module_id: str
+
+
+def _hashable(value: object) -> object:
+    if isinstance(value, list):
+        return tuple(_hashable(item) for item in value)
+    if isinstance(value, DictValue):
+        return tuple((key, _hashable(item)) for key, item in value.entries.items())
+    if isinstance(value, SetValue):
+        return frozenset(value.items)
+    return value
+
+
+def copy_value(value: object) -> object:
+    """A copy of `value` that shares no mutable state with it, as assignment makes."""
+    if isinstance(value, list):
+        return [copy_value(item) for item in value]
+    if isinstance(value, tuple):
+        return tuple(copy_value(item) for item in value)
+    if isinstance(value, StructValue):
+        return StructValue(value.qualified_name, value.name, {name: copy_value(item) for name, item in value.fields.items()})
+    if isinstance(value, EnumValue):
+        return EnumValue(value.qualified_name, value.name, value.variant, {name: copy_value(item) for name, item in value.fields.items()})
+    if isinstance(value, DictValue):
+        return DictValue({key: copy_value(item) for key, item in value.entries.items()}, value.ordered)
+    if isinstance(value, SetValue):
+        return SetValue(dict(value.items), value.ordered)
+    if isinstance(value, (Ok, Err, Some)):
+        return type(value)(copy_value(value.value))
+    return value
+
+
+# --- Numbers ---
+
+
+def _round_f32(value: float) -> float:
+    try:
+        return struct.unpack("f", struct.pack("f", value))[0]
+    except OverflowError:
+        return math.copysign(math.inf, value)
+
+
+def _is_number(value: object) -> bool:
+    return isinstance(value, (int, float)) and not isinstance(value, bool)
+
+
+def _shortest_digits(value: float) -> str:
+    """The shortest decimal spelling that reads back as `value` at its precision."""
+    if isinstance(value, F32):
+        for precision in range(1, 10):
+            text = f"{value:.{precision}g}"
+            if _round_f32(float(text)) == value:
+                return text
+    return repr(float(value))
+
+
+def _positional(digits: str) -> str:
+    text = format(Decimal(digits), "f")
+    if "." in text:
+        text = text.rstrip("0").rstrip(".")
+    return text
+
+
+def display_float(value: float) -> str:
+    """Format a float like Rust's `{}`: the shortest round-trip digits, never in exponent form."""
+    if math.isnan(value):
+        return "NaN"
+    if math.isinf(value):
+        return "inf" if value > 0 else "-inf"
+    return _positional(_shortest_digits(value))
+
+
+def debug_float(value: float) -> str:
+    """Format a float like Rust's `{:?}`, which keeps a `.0` and uses exponents for very large or small values."""
+    if math.isnan(value) or math.isinf(value):
+        return display_float(value)
+    magnitude = abs(value)
+    if magnitude == 0 or 1e-4 <= magnitude < 1e16:
+        text = display_float(value)
+        return text if "." in text else f"{text}.0"
+    return _exponent_form(value)
+
+
+def _exponent_form(value: float) -> str:
+    """Format a finite float like Rust's `{:e}`: the shortest digits with one before the point."""
+    if value == 0:
+        return "-0e0" if math.copysign(1, value) < 0 else "0e0"
+    mantissa, _, exponent = f"{Decimal(_shortest_digits(value)):e}".partition("e")
+    if "." in mantissa:
+        mantissa = mantissa.rstrip("0").rstrip(".")
+    return f"{mantissa}e{int(exponent)}"
+
+
+def format_number(value: float, spec: str) -> str:
+    """Format a number like the runtime's `format_number`, which `format_number()` compiles to."""
+    number_format = parse_number_format(spec)
+    value = float(value)
+    precision = number_format.precision
+    if number_format.style == "%":
+        value *= 100
+    if math.isnan(value) or math.isinf(value):
+        text = display_float(value)
+    elif number_format.style == "e" and precision is not None:
+        mantissa, _, exponent = f"{value:.{precision}e}".partition("e")
+        text = f"{mantissa}e{int(exponent)}"
+    elif number_format.style == "e":
+        text = _exponent_form(value)
+    elif number_format.style in ("f", "%") or precision is not None:
+        text = f"{value:.{6 if precision is None else precision}f}"
+    else:
+        text = display_float(value)
+    if number_format.style == "%":
+        text += "%"
+    return group_thousands(text) if number_format.grouped else text
+
+
+# --- Control flow ---
+
+
+class ZincPanic(Exception):
+    """A runtime failure that ends the program with status 101, like a Rust panic."""
+
+    def __init__(self, message: str, location: str):
+        super().__init__(message)
+        self.location = location
+
+
+class ProgramExit(Exception):
+    """`exit(code)`, or the end of a program with this status."""
+
+    def __init__(self, status: int):
+        super().__init__(status)
+        self.status = status
+
+
+class _Return(Exception):
+    def __init__(self, value: object):
+        self.value = value
+
+
+class _Break(Exception):
+    pass
+
+
+class _Continue(Exception):
+    pass
+
+
+class Failure(Exception):
+    """`fail error`: ends the innermost try block or function with `Err(error)`."""
+
+    def __init__(self, value: object):
+        self.value = value
+
+
+class Propagation(Exception):
+    """An `Err` or `None` unwrapped inside a try block, which becomes the block's value."""
+
+    def __init__(self, value: object):
+        self.value = value
+
+
+# --- Scopes ---
+
+
+@dataclass
+class TryBlock:
+    """A try block being run; `family` is set once something inside it unwraps a Result or Option."""
+
+    family: BaseType | None = None
+
+
+@dataclass(eq=False)
+class Frame:
+    """One function call: its module, `self`, and the try blocks it is inside.
+
+    A method copied into a composed struct builds that struct wherever it
+    instantiates the struct it was declared in, `constructs`.
+    """
+
+    module_id: str
+    receiver: object = None
+    owner: StructInstance | EnumInstance | None = None
+    constructs: str | None = None
+    try_blocks: list[TryBlock] = field(default_factory=list)
+
+
+@dataclass(eq=False)
+class Scope:
+    """The variables of one block; closures keep the scope they were created in.
+
+    A `let` that shadows a variable of the same block opens a nested scope,
+    marked `rebinding`, so closures made before it keep the old variable; the
+    nested scope closes with its block.
+    """
+
+    frame: Frame
+    parent: "Scope | None" = None
+    variables: dict = field(default_factory=dict)
+    rebinding: bool = False
+
+    def find(self, name: str) -> "Scope | None":
+        """The innermost scope, up to the outermost captured one, that defines `name`."""
+        scope = self
+        while scope is not None:
+            if name in scope.variables:
+                return scope
+            scope = scope.parent
+        return None
+
+
+def _same_kind(left: object, right: object) -> bool:
+    """Whether a value may be stored in a variable holding `left` rather than shadowing it."""
+    if isinstance(left, bool) or isinstance(right, bool):
+        return isinstance(left, bool) and isinstance(right, bool)
+    if isinstance(left, int) and isinstance(right, int):
+        return True
+    if isinstance(left, float) and isinstance(right, float):
+        return True
+    if isinstance(left, StructValue) and isinstance(right, StructValue):
+        return left.qualified_name == right.qualified_name
+    if isinstance(left, (Ok, Err)) and isinstance(right, (Ok, Err)):
+        return True
+    if isinstance(left, (Some, _NoneType)) and isinstance(right, (Some, _NoneType)):
+        return True
+    return type(left) is type(right)
+
+
+# --- Shared runtime ---
+
+
+class Runtime:
+    """The semantics of running a program, shared by the interpreter and the bytecode VM.
+
+    Values, operators, builtins, calls, and formatting behave the same in both;
+    a subclass says where code, names, and struct layouts come from. `ctx`
+    arguments say where an operation happens, for panic messages; the VM
+    tracks that itself and passes None.
+    """
+
+    error: type[ZincError] = InterpreterError
+
+    def __init__(self, default_int: str, *, stdout: TextIO | None, stderr: TextIO | None, overflow: str):
+        """Prepare to write the program's output to `stdout` and `stderr`."""
+        self.stdout = stdout or sys.stdout
+        self.stderr = stderr or sys.stderr
+        self.overflow = overflow
+        self.default_int = default_int
+        self.scope: Scope | None = None
+        # The values of structs' static fields, set up the first time each is used.
+        self.statics: dict[tuple[str, str], object] = {}
+
+    # Running
+
+    def run(self) -> int:
+        """Run `main()` and return the program's exit status."""
+        status = 0
+        try:
+            result = self._call(self._main_function(), [], [], None)
+            if isinstance(result, Err):
+                self.stdout.flush()
+                self.stderr.write(f"{self._display(result.value)}\n")
+                status = 1
+        except ProgramExit as exc:
+            status = exc.status
+        except ZincPanic as exc:
+            self.stdout.flush()
+            self.stderr.write(f"thread 'main' panicked at {exc.location}:\n{exc}\n")
+            self.stderr.write("note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n")
+            status = 101
+        except RecursionError:
+            self.stdout.flush()
+            self.stderr.write("error: stack overflow: the program's calls nested too deeply\n")
+            status = 101
+        self.stdout.flush()
+        return status
+
+    @property
+    def frame(self) -> Frame:
+        """The function call being run."""
+        return self.scope.frame
+
+    # What a subclass provides
+
+    def _main_function(self) -> object:
+        raise NotImplementedError
+
+    def _panic(self, message: str, ctx) -> ZincPanic:
+        raise NotImplementedError
+
+    def _parameters_of(self, function: Function) -> list[tuple[str, str | None, object]]:
+        """(name, annotation, default) for each parameter of `function`."""
+        raise NotImplementedError
+
+    def _default_value(self, function: Function, default: object) -> object:
+        raise NotImplementedError
+
+    def _run_body(self, function: Function) -> object:
+        raise NotImplementedError
+
+    def _return_type(self, function: Function) -> str | None:
+        raise NotImplementedError
+
+    def _export(self, module_id: str, name: str) -> object:
+        raise NotImplementedError
+
+    def _type_member(self, owner: TypeValue, name: str, ctx) -> object:
+        raise NotImplementedError
+
+    def _method(self, receiver: StructValue | EnumValue, name: str) -> Function | None:
+        """The method `name` of the receiver's type, bound to it unless the method is static."""
+        raise NotImplementedError
+
+    def _operator_function(self, value: object, symbol: str) -> tuple[Function, bool] | None:
+        """The unbound method a struct declares for the operator `symbol`, and whether it is static."""
+        raise NotImplementedError
+
+    def _field_type(self, qualified_name: str, name: str) -> str | None:
+        raise NotImplementedError
+
+    def _struct_definition(self, qualified_name: str) -> object:
+        """The fields and methods of the struct `qualified_name`, as `_new_struct` takes them."""
+        raise NotImplementedError
+
+    def _new_struct(self, struct: object, given: dict) -> StructValue:
+        """A value of `struct` with the `given` fields, filling the others with their defaults."""
+        raise NotImplementedError
+
+    def _static_field(self, qualified_name: str, name: str) -> tuple[str | None, Callable[[], object]] | None:
+        """The exact type of the struct's static field `name` and a function computing its initial value, if it has one."""
+        raise NotImplementedError
+
+    # Scopes
+
+    def _define(self, name: str, value: object) -> None:
+        self.scope.variables[name] = value
+
+    def _rebind(self, name: str, value: object) -> None:
+        """Declare a new variable the way `let name = value` does, leaving any earlier `name` to closures that captured it."""
+        if name in self.scope.variables:
+            self.scope = Scope(self.frame, self.scope, rebinding=True)
+        self.scope.variables[name] = value
+
+    def _leave_scope(self) -> None:
+        """Close the innermost block, along with the scopes its `let`s opened."""
+        while self.scope.rebinding:
+            self.scope = self.scope.parent
+        self.scope = self.scope.parent
+
+    def _assign(self, name: str, value: object) -> None:
+        """Store a variable the way `name = value` does: nested blocks shadow when the type changes."""
+        holder = self.scope.find(name)
+        if holder is None or holder is self.scope:
+            self.scope.variables[name] = value
+        elif holder.frame is self.frame and _same_kind(holder.variables[name], value):
+            holder.variables[name] = value
+        else:
+            self.scope.variables[name] = value
+
+    def _store(self, name: str, value: object) -> None:
+        """Store into the variable `name` wherever it is visible, as `out name = value` and `name += value` do."""
+        holder = self.scope.find(name) or self.scope
+        holder.variables[name] = value
+
+    def _assign_tuple(self, names: list[str], value: object, bind: Callable[[str, object], None], annotation: str | None = None) -> None:
+        """Destructure a tuple into `names`, or give every name a copy of a single value."""
+        values = list(value) if isinstance(value, tuple) and len(value) == len(names) else [value] * len(names)
+        for name, item in zip(names, values, strict=True):
+            item = copy_value(item)
+            bind(name, self._convert(item, annotation) if annotation is not None else item)
+
+    def _in_module(self, module_id: str, evaluate: Callable[[], object]) -> object:
+        """Evaluate in a fresh frame of `module_id`, as const values and defaults are."""
+        saved = self.scope
+        self.scope = Scope(Frame(module_id))
+        try:
+            return evaluate()
+        finally:
+            self.scope = saved
+
+    # Values
+
+    def _unwrap_try(self, value: object) -> object:
+        if isinstance(value, (Ok, Err)):
+            self.frame.try_blocks[-1].family = BaseType.RESULT
+        elif isinstance(value, (Some, _NoneType)):
+            self.frame.try_blocks[-1].family = BaseType.OPTION
+        else:
+            return value
+        if isinstance(value, (Err, _NoneType)):
+            raise Propagation(value)
+        return value.value
+
+    def _number(self, value: int | float, exact_type: str | None) -> object:
+        if isinstance(value, float) or exact_type in ("f32", "f64"):
+            return F32(value) if exact_type == "f32" else float(value)
+        if exact_type is None or exact_type == self.default_int:
+            return value
+        return SizedInt(value, exact_type)
+
+    def _truth(self, value: object, ctx) -> bool:
+        if not isinstance(value, bool):
+            raise self.error(f"using {self._describe(value)} as a condition")
+        return value
+
+    def _iterate(self, iterable: object, ctx) -> Iterator:
+        if isinstance(iterable, RangeValue):
+            return iter(iterable.values())
+        if isinstance(iterable, (list, tuple)):
+            return iter(list(iterable))
+        if isinstance(iterable, DictValue):
+            return iter([(key, iterable.entries[key]) for key in iterable.keys()])
+        if isinstance(iterable, SetValue):
+            return iter(iterable.values())
+        if isinstance(iterable, str):
+            return iter(list(iterable))
+        if isinstance(iterable, StructValue):
+            return self._iterate_struct(iterable, ctx)
+        raise self.error(f"iterating over {self._describe(iterable)}")
+
+    def _iterate_struct(self, value: StructValue, ctx) -> Iterator:
+        """Step a struct's iterator, from its iterate() method or the struct itself, with next() until it returns None."""
+        iterate = self._method(value, ITERATE_METHOD)
+        iterator = self._call(iterate, [], [], ctx) if iterate is not None else value
+        step = self._method(iterator, NEXT_METHOD)
+        while True:
+            item = self._call(step, [], [], ctx)
+            if not isinstance(item, Some):
+                return
+            yield item.value
+
+    # Operators
+
+    def _unary(self, operator: str, operand: object, ctx) -> object:
+        """Apply a unary operator the way the compiled program does."""
+        if isinstance(operand, StructValue):
+            return self._call_operator(operand, operator, [], ctx)
+        if operator in ("!", "not") and isinstance(operand, bool):
+            return not operand
+        if operator == "-":
+            if isinstance(operand, float):
+                return type(operand)(-operand) if isinstance(operand, F32) else -operand
+            return self._integer(-operand, operand, "neg", ctx)
+        if operator in ("~", "!") and isinstance(operand, int):
+            kind = self._int_kind(operand)
+            low, high = INTEGER_BOUNDS.get(kind, INTEGER_BOUNDS["i64"])
+            return self._wrap_int(high ^ operand if low == 0 else ~operand, operand)
+        raise self.error(f"'{operator}' on {self._describe(operand)}")
+
+    def _binary(self, operator: str, left: object, right: object, ctx) -> object:
+        """Apply a binary operator the way the compiled program does."""
+        if isinstance(left, StructValue) or (isinstance(right, StructValue) and operator not in ("==", "!=")):
+            if operator in ("==", "!=") and self._operator_function(left, operator) is None:
+                return self._equal(left, right) == (operator == "==")
+            return self._call_operator(left if isinstance(left, StructValue) else right, operator, [left, right], ctx, binary=True)
+        if operator == "==":
+            return self._equal(left, right)
+        if operator == "!=":
+            return not self._equal(left, right)
+        if operator in ("<", "<=", ">", ">="):
+            return self._compare(operator, left, right)
+        if isinstance(left, str) and isinstance(right, str) and operator == "+":
+            return left + right
+        if isinstance(left, bool) and isinstance(right, bool) and operator in ("&", "|", "^"):
+            return {"&": left and right, "|": left or right, "^": left != right}[operator]
+        if not (_is_number(left) and _is_number(right)):
+            raise self.error(f"'{operator}' on {self._describe(left)} and {self._describe(right)}")
+        if isinstance(left, float) or isinstance(right, float):
+            return self._float_binary(operator, left, right, ctx)
+        return self._int_binary(operator, left, right, ctx)
+
+    def _float_binary(self, operator: str, left: float, right: float, ctx) -> float:
+        a, b = float(left), float(right)
+        if operator == "+":
+            result = a + b
+        elif operator == "-":
+            result = a - b
+        elif operator == "*":
+            result = a * b
+        elif operator == "/":
+            result = a / b if b != 0 else (math.nan if a == 0 or math.isnan(a) else math.copysign(math.inf, a) * math.copysign(1, b))
+        elif operator == "%":
+            result = math.fmod(a, b) if b != 0 else math.nan
+        elif operator == "**":
+            try:
+                result = a**b
+            except OverflowError:
+                result = math.inf
+            except ZeroDivisionError:
+                result = math.inf
+            if isinstance(result, complex):
+                result = math.nan
+        else:
+            raise self.error(f"'{operator}' on floats")
+        return F32(result) if isinstance(left, F32) or isinstance(right, F32) else result
+
+    def _int_binary(self, operator: str, left: int, right: int, ctx) -> int:
+        sample = left if isinstance(left, SizedInt) else right
+        if operator == "+":
+            return self._integer(left + right, sample, "+", ctx)
+        if operator == "-":
+            return self._integer(left - right, sample, "-", ctx)
+        if operator == "*":
+            return self._integer(left * right, sample, "*", ctx)
+        if operator in ("/", "%"):
+            if right == 0:
+                message = "attempt to divide by zero" if operator == "/" else "attempt to calculate the remainder with a divisor of zero"
+                raise self._panic(message, ctx)
+            quotient = abs(int(left)) // abs(int(right))
+            if (left < 0) != (right < 0):
+                quotient = -quotient
+            if operator == "/":
+                return self._integer(quotient, sample, "/", ctx)
+            return self._integer(left - right * quotient, sample, "%", ctx)
+        if operator == "**":
+            if right < 0:
+                raise self._panic("attempt to multiply with overflow", ctx)
+            low, high = INTEGER_BOUNDS.get(self._int_kind(sample), INTEGER_BOUNDS["i64"])
+            if abs(left) > 1 and right > high.bit_length():
+                return self._integer(high + 1, sample, "**", ctx)
+            return self._integer(left**right, sample, "**", ctx)
+        if operator in ("&", "|", "^"):
+            result = {"&": left & right, "|": left | right, "^": left ^ right}[operator]
+            return self._wrap_int(result, sample)
+        if operator in ("<<", ">>"):
+            kind = self._int_kind(left)
+            bits = INTEGER_BOUNDS.get(kind, INTEGER_BOUNDS["i64"])[1].bit_length() + (kind.startswith("i") or kind == "isize")
+            if not 0 <= right < bits:
+                direction = "left" if operator == "<<" else "right"
+                raise self._panic(f"attempt to shift {direction} with overflow", ctx)
+            result = (int(left) << int(right)) & ((1 << bits) - 1) if operator == "<<" else int(left) >> int(right)
+            low, _ = INTEGER_BOUNDS.get(kind, INTEGER_BOUNDS["i64"])
+            if low < 0 and result >= 1 << (bits - 1):
+                result -= 1 << bits
+            return self._wrap_int(result, left)
+        raise self.error(f"'{operator}' on integers")
+
+    def _int_kind(self, value: int) -> str:
+        return value.kind if isinstance(value, SizedInt) else self.default_int
+
+    def _wrap_int(self, result: int, sample: int) -> int:
+        kind = self._int_kind(sample)
+        return result if kind == self.default_int else SizedInt(result, kind)
+
+    def _integer(self, result: int, sample: int, operator: str, ctx) -> int:
+        """Check `result` against the bounds of its type, panicking, wrapping, or saturating per --overflow."""
+        kind = self._int_kind(sample)
+        low, high = INTEGER_BOUNDS.get(kind, INTEGER_BOUNDS["i64"])
+        if low <= result <= high:
+            return self._wrap_int(result, sample)
+        if self.overflow == "wrapping":
+            span = high - low + 1
+            return self._wrap_int((result - low) % span + low, sample)
+        if self.overflow == "saturating":
+            return self._wrap_int(high if result > high else low, sample)
+        if operator in ("/", "%"):
+            verb = "divide" if operator == "/" else "calculate the remainder"
+            raise self._panic(f"attempt to {verb} with overflow", ctx)
+        raise self._panic(f"attempt to {OVERFLOW_VERBS[operator]} with overflow", ctx)
+
+    def _equal(self, left: object, right: object) -> bool:
+        if _is_number(left) and _is_number(right):
+            return left == right
+        if isinstance(left, bool) or isinstance(right, bool):
+            return left is right
+        return left == right
+
+    def _compare(self, operator: str, left: object, right: object) -> bool:
+        if isinstance(left, bool) != isinstance(right, bool):
+            raise self.error(f"comparing {self._describe(left)} with {self._describe(right)}")
+        try:
+            return {"<": left < right, "<=": left <= right, ">": left > right, ">=": left >= right}[operator]
+        except TypeError as exc:
+            raise self.error(f"comparing {self._describe(left)} with {self._describe(right)}") from exc
+
+    def _call_operator(self, owner: object, symbol: str, operands: list, ctx, *, binary: bool = False) -> object:
+        """Call the `operator` method a struct declares for `symbol`."""
+        found = self._operator_function(owner, symbol)
+        if found is None:
+            raise self.error(f"'{symbol}' on {self._describe(owner)}")
+        function, is_static = found
+        if is_static:
+            return self._call(function, operands if binary else [owner, *operands], [], ctx)
+        receiver = operands[0] if binary else owner
+        arguments = operands[1:] if binary else operands
+        return self._call(replace(function, receiver=receiver, has_receiver=True), arguments, [], ctx)
+
+    def _contains(self, collection: object, item: object) -> bool:
+        if isinstance(collection, (list, tuple)):
+            return any(self._equal(element, item) for element in collection)
+        if isinstance(collection, DictValue):
+            return item in collection.entries
+        if isinstance(collection, SetValue):
+            return item in collection.items
+        if isinstance(collection, str):
+            return item in collection
+        if isinstance(collection, RangeValue):
+            return _is_number(item) and item in collection.values()
+        raise self.error(f"'in' on {self._describe(collection)}")
+
+    # Members and indexes
+
+    def _member(self, receiver: object, name: str, ctx) -> object:
+        if isinstance(receiver, ModuleValue):
+            return self._export(receiver.module_id, name)
+        if isinstance(receiver, TypeValue):
+            key = self._static_key(receiver, name)
+            return self.statics[key] if key is not None else self._type_member(receiver, name, ctx)
+        if isinstance(receiver, (StructValue, EnumValue)):
+            if name in receiver.fields:
+                return receiver.fields[name]
+            method = self._method(receiver, name)
+            if method is not None:
+                return method
+        if isinstance(receiver, tuple) and name.isdigit():
+            return receiver[int(name)]
+        builtin = self._builtin_method(receiver, name, ctx)
+        if builtin is not None:
+            return builtin
+        raise self.error(f"'.{name}' on {self._describe(receiver)}")
+
+    def _callable_member(self, receiver: object, name: str, ctx) -> object | None:
+        if isinstance(receiver, (ModuleValue, TypeValue)):
+            return self._member(receiver, name, ctx)
+        if isinstance(receiver, (StructValue, EnumValue)):
+            if name in receiver.fields:
+                return receiver.fields[name]
+            return self._method(receiver, name)
+        if isinstance(receiver, (Function, Builtin)) and name == "call":
+            return receiver
+        return self._builtin_method(receiver, name, ctx)
+
+    def _index(self, container: object, index: object, ctx) -> object:
+        if isinstance(container, StructValue):
+            return self._call_operator(container, INDEX_OPERATOR_SYMBOL, [index], ctx)
+        if isinstance(container, (list, tuple, str)):
+            if not isinstance(index, int) or isinstance(index, bool):
+                raise self.error(f"indexing with {self._describe(index)}")
+            items = container.encode() if isinstance(container, str) else container
+            if not 0 <= index < len(items):
+                raise self._panic(f"index out of bounds: the len is {len(items)} but the index is {index}", ctx)
+            if isinstance(container, str):
+                raise self.error("indexing strings")
+            return container[index]
+        if isinstance(container, DictValue):
+            if index not in container.entries:
+                raise self._panic("called `Option::unwrap()` on a `None` value", ctx)
+            return container.entries[index]
+        raise self.error(f"indexing {self._describe(container)}")
+
+    def _checked_index(self, container: list, index: int) -> Ok | Err:
+        """Read a fixed-size array element the way `--bounds error` compiles it: Ok, or Err when out of range."""
+        if 0 <= index < len(container):
+            return Ok(container[index])
+        return Err(f"index out of bounds: the len is {len(container)} but the index is {index}")
+
+    def _set_index(self, container: object, index: object, value: object, ctx) -> None:
+        if isinstance(container, list):
+            if not 0 <= index < len(container):
+                raise self._panic(f"index out of bounds: the len is {len(container)} but the index is {index}", ctx)
+            container[index] = value
+        elif isinstance(container, DictValue):
+            container.entries[index] = value
+        else:
+            raise self.error(f"assigning to an index of {self._describe(container)}")
+
+    def _static_key(self, owner: TypeValue, name: str) -> tuple[str, str] | None:
+        """The key of a struct's static field in `statics`, giving it its initial value on first use."""
+        key = (owner.qualified_name, name)
+        if key not in self.statics:
+            static = self._static_field(owner.qualified_name, name) if owner.kind == "struct" else None
+            if static is None:
+                return None
+            self.statics[key] = static[1]()
+        return key
+
+    def _set_field(self, receiver: object, name: str, value: object, ctx) -> None:
+        if isinstance(receiver, TypeValue) and self._static_key(receiver, name) is not None:
+            exact_type, _initial = self._static_field(receiver.qualified_name, name)
+            self.statics[(receiver.qualified_name, name)] = self._convert(value, exact_type)
+            return
+        if not isinstance(receiver, StructValue) or name not in receiver.fields:
+            raise self.error(f"assigning '.{name}' on {self._describe(receiver)}")
+        if receiver.qualified_name is not None:
+            value = self._convert(value, self._field_type(receiver.qualified_name, name))
+        receiver.fields[name] = value
+
+    # Calls
+
+    def _call(self, callee: object, positional: list, keywords: list[tuple[str | None, object]], ctx) -> object:
+        """Call a function value; arguments are passed by reference, so the callee can update them.
+
+        Keyword arguments and spreads apply in order, so a later one replaces
+        an earlier value for the same parameter.
+        """
+        if isinstance(callee, Builtin):
+            return callee.call(positional)
+        if isinstance(callee, TypeValue) and callee.kind == "struct":
+            return self._construct(callee, positional, keywords, ctx)
+        if not isinstance(callee, Function):
+            raise self.error(f"calling {self._describe(callee)}")
+        parameters = self._parameters_of(callee)
+        given = dict(zip((name for name, _, _ in parameters), positional, strict=False))
+        for name, value in keywords:
+            if name is not None:
+                given[name] = value
+                continue
+            for parameter, _, _ in parameters[len(positional) :]:
+                if parameter in value.fields:
+                    given[parameter] = copy_value(value.fields[parameter])
+        values: dict[str, object] = {}
+        for name, annotation, default in parameters:
+            if name in given:
+                value = given[name]
+            elif default is not None:
+                value = self._in_module(callee.module_id, lambda default=default: self._default_value(callee, default))
+            else:
+                raise self.error(f"calling '{callee.name}' without its argument '{name}'")
+            values[name] = self._convert(value, annotation)
+        key = tuple(values.values()) if callee.memo is not None else None
+        if key is not None and key in callee.memo:
+            return copy_value(callee.memo[key])
+        frame = Frame(callee.module_id, callee.receiver, callee.owner, callee.constructs)
+        saved = self.scope
+        self.scope = Scope(frame, callee.closure, values)
+        try:
+            result = self._run_body(callee)
+        except _Return as exc:
+            result = exc.value
+        except Failure as exc:
+            result = Err(exc.value)
+        finally:
+            self.scope = saved
+        return_type = self._return_type(callee)
+        result = self._convert(result, return_type) if return_type is not None else result
+        if key is not None:
+            callee.memo[key] = copy_value(result)
+        return result
+
+    def _construct(self, owner: TypeValue, positional: list, keywords: list[tuple[str | None, object]], ctx) -> object:
+        """Evaluate `Name(args...)`: a call to the struct's static `new` when it has one, else its fields in declaration order."""
+        struct = self._struct_definition(owner.qualified_name)
+        if any(method.name == "new" and method.is_static for method in struct.methods):
+            return self._call(self._type_member(owner, "new", ctx), positional, keywords, ctx)
+        names = [info.name for info in struct.fields]
+        given = {name: copy_value(value) for name, value in zip(names, positional, strict=False)}
+        for name, value in keywords:
+            if name is not None:
+                given[name] = copy_value(value)
+                continue
+            for field in names[len(positional) :]:
+                if field in value.fields:
+                    given[field] = copy_value(value.fields[field])
+        return self._new_struct(struct, given)
+
+    # Builtins
+
+    def _builtin(self, name: str, positional: list, location: str, sources: list[str]) -> object:
+        """Call the builtin `name`; `location` and the arguments' `sources` are what dbg() and assertions report."""
+        if name in UNSUPPORTED_BUILTINS:
+            raise self.error(UNSUPPORTED_BUILTINS[name])
+        if name == "print":
+            self.stdout.write((self._display(positional[0]) if positional else "") + "\n")
+            return None
+        if name == "print_debug":
+            self.stdout.write(self._debug(positional[0], pretty=True) + "\n")
+            return None
+        if name == "dbg":
+            self.stdout.flush()
+            self.stderr.write(f"[{location}] {sources[0]} = {self._debug(positional[0], pretty=True)}\n")
+            return None
+        if name == "assert":
+            if not positional[0]:
+                message = f": {self._display(positional[1])}" if len(positional) > 1 else ""
+                self._fail_assertion(f"assertion failed at {location}: {sources[0]}{message}")
+            return None
+        if name == "assert_eq":
+            left, right = positional[0], positional[1]
+            if not self._equal(left, right):
+                message = f": {self._display(positional[2])}" if len(positional) > 2 else ""
+                headline = f"assertion failed at {location}: {sources[0]} == {sources[1]}"
+                self._fail_assertion(f"{headline}{message}\n  left: {self._debug(left)}\n right: {self._debug(right)}")
+            return None
+        if name == "exit":
+            self.stdout.flush()
+            raise ProgramExit(int(positional[0]) if positional else 0)
+        if name in ("dict", "sort_dict"):
+            return DictValue(ordered=name == "sort_dict")
+        if name in ("set", "sort_set"):
+            return SetValue(ordered=name == "sort_set")
+        if name == "uuid":
+            return str(uuid.uuid4())
+        if name == "matrix":
+            rows, cols, fill = positional
+            if rows < 0 or cols < 0:
+                raise ZincPanic("matrix() sizes can't be negative", location)
+            return [[copy_value(fill) for _ in range(cols)] for _ in range(rows)]
+        if name == "format_number":
+            return format_number(*positional)
+        if name == "to_json":
+            return json.dumps(self._json(positional[0]), separators=(",", ":"), ensure_ascii=False)
+        raise self.error(f"the builtin '{name}'")
+
+    def _fail_assertion(self, report: str) -> None:
+        self.stdout.flush()
+        self.stderr.write(report + "\n")
+        raise ProgramExit(101)
+
+    def _builtin_method(self, receiver: object, name: str, ctx) -> Builtin | None:
+        """A method of a list, dict, set, or string, bound to `receiver`."""
+
+        def method(call: Callable[..., object]) -> Builtin:
+            return Builtin(name, lambda arguments: call(*arguments))
+
+        if isinstance(receiver, str):
+            methods = {
+                "len": lambda: len(receiver.encode()),
+                "is_empty": lambda: not receiver,
+                "contains": lambda part: part in receiver,
+            }
+        elif isinstance(receiver, list):
+            methods = {
+                "len": lambda: len(receiver),
+                "is_empty": lambda: not receiver,
+                "push": lambda item: receiver.append(copy_value(item)),
+                "insert": lambda index, item: self._list_insert(receiver, index, item, ctx),
+                "remove": lambda index: self._list_remove(receiver, index, ctx),
+                "pop": lambda: Some(receiver.pop()) if receiver else NONE,
+                "index_of": lambda item: next((Some(i) for i, element in enumerate(receiver) if self._equal(element, item)), NONE),
+                "reverse": receiver.reverse,
+                "contains": lambda item: self._contains(receiver, item),
+                "clear": receiver.clear,
+            }
+        elif isinstance(receiver, DictValue):
+            entries = receiver.entries
+            methods = {
+                "len": lambda: len(entries),
+                "is_empty": lambda: not entries,
+                "clear": entries.clear,
+                "keys": lambda: [copy_value(key) for key in receiver.keys()],
+                "values": lambda: [copy_value(entries[key]) for key in receiver.keys()],
+                "items": lambda: [(copy_value(key), copy_value(entries[key])) for key in receiver.keys()],
+                "insert": lambda key, value: entries.__setitem__(copy_value(key), copy_value(value)),
+                "get": lambda key: copy_value(self._index(receiver, key, ctx)),
+                "contains_key": lambda key: key in entries,
+                "remove": lambda key: entries.pop(key, None) and None,
+            }
+        elif isinstance(receiver, SetValue):
+            items = receiver.items
+            methods = {
+                "len": lambda: len(items),
+                "is_empty": lambda: not items,
+                "clear": items.clear,
+                "push": lambda item: items.setdefault(copy_value(item)) and None,
+                "insert": lambda item: items.setdefault(copy_value(item)) and None,
+                "contains": lambda item: item in items,
+                "remove": lambda item: items.pop(item, None) and None,
+            }
+        else:
+            return None
+        call = methods.get(name)
+        return method(call) if call is not None else None
+
+    def _list_insert(self, items: list, index: int, item: object, ctx) -> None:
+        """Insert into a list like `Vec::insert`, which panics past the end."""
+        if not 0 <= index <= len(items):
+            raise self._panic(f"insertion index (is {index}) should be <= len (is {len(items)})", ctx)
+        items.insert(index, copy_value(item))
+
+    def _list_remove(self, items: list, index: int, ctx) -> object:
+        """Remove from a list like `Vec::remove`, which panics past the end."""
+        if not 0 <= index < len(items):
+            raise self._panic(f"removal index (is {index}) should be < len (is {len(items)})", ctx)
+        return items.pop(index)
+
+    # Conversions and formatting
+
+    def _convert(self, value: object, annotation: str | None) -> object:
+        """Give a number the exact type named by an annotation; other values keep their own type."""
+        if annotation is None or not _is_number(value):
+            return value
+        if annotation in INTEGER_SUFFIX_SET and isinstance(value, int):
+            return self._number(int(value), annotation)
+        if annotation == "f32":
+            return F32(value)
+        if annotation == "f64":
+            return float(value)
+        return value
+
+    def _display(self, value: object) -> str:
+        """Format a value like Rust's `{}`."""
+        if isinstance(value, bool):
+            return "true" if value else "false"
+        if isinstance(value, int):
+            return str(int(value))
+        if isinstance(value, float):
+            return display_float(value)
+        if isinstance(value, str):
+            return value
+        if isinstance(value, (StructValue, EnumValue)):
+            method = self._method(value, DISPLAY_METHOD)
+            return self._display(self._call(method, [], [], None)) if method is not None else self._debug(value)
+        raise self.error(f"printing {self._describe(value)}")
+
+    def _debug(self, value: object, *, pretty: bool = False, indent: str = "") -> str:
+        """Format a value like Rust's `{:?}`, or `{:#?}` when `pretty`."""
+        if isinstance(value, bool):
+            return "true" if value else "false"
+        if isinstance(value, int):
+            return str(int(value))
+        if isinstance(value, float):
+            return debug_float(value)
+        if isinstance(value, str):
+            return json.dumps(value, ensure_ascii=False)
+        if value is None:
+            return "()"
+        if value is NONE:
+            return "None"
+        if isinstance(value, (Ok, Err, Some)):
+            return self._debug_group(f"{type(value).__name__}(", ")", [value.value], pretty, indent)
+        if isinstance(value, list):
+            return self._debug_group("[", "]", value, pretty, indent)
+        if isinstance(value, tuple):
+            return self._debug_group("(", ")", list(value), pretty, indent, trailing_comma=len(value) == 1)
+        if isinstance(value, SetValue):
+            return self._debug_group("{", "}", value.values(), pretty, indent)
+        if isinstance(value, DictValue):
+            entries = [(key, value.entries[key]) for key in value.keys()]
+            return self._debug_group("{", "}", entries, pretty, indent, entries=True)
+        if isinstance(value, StructValue):
+            names = sorted(value.fields) if value.qualified_name is None else list(value.fields)
+            return self._debug_fields(value.name, [(name, value.fields[name]) for name in names], pretty, indent)
+        if isinstance(value, EnumValue):
+            label = f"{value.name}.{value.variant}"
+            return self._debug_fields(label, list(value.fields.items()), pretty, indent) if value.fields else label
+        raise self.error(f"debug-printing {self._describe(value)}")
+
+    def _debug_group(
+        self, open_: str, close: str, items: list, pretty: bool, indent: str, *, entries: bool = False, trailing_comma: bool = False
+    ) -> str:
+        def item(element: object, inner: str) -> str:
+            if entries:
+                key, value = element
+                return f"{self._debug(key, pretty=pretty, indent=inner)}: {self._debug(value, pretty=pretty, indent=inner)}"
+            return self._debug(element, pretty=pretty, indent=inner)
+
+        if not items:
+            return open_ + close
+        if not pretty:
+            body = ", ".join(item(element, indent) for element in items)
+            return f"{open_}{body}{',' if trailing_comma else ''}{close}"
+        inner = indent + "    "
+        lines = "".join(f"{inner}{item(element, inner)},\n" for element in items)
+        return f"{open_}\n{lines}{indent}{close}"
+
+    def _debug_fields(self, label: str, fields: list[tuple[str, object]], pretty: bool, indent: str) -> str:
+        if not fields:
+            return label
+        if not pretty:
+            body = ", ".join(f"{name}: {self._debug(value)}" for name, value in fields)
+            return f"{label} {{ {body} }}"
+        inner = indent + "    "
+        lines = "".join(f"{inner}{name}: {self._debug(value, pretty=True, indent=inner)},\n" for name, value in fields)
+        return f"{label} {{\n{lines}{indent}}}"
+
+    def _json(self, value: object) -> object:
+        """The JSON structure `to_json` serializes, matching the compiled program's serde output."""
+        if isinstance(value, float):
+            return _JsonFloat(value)
+        if isinstance(value, (bool, int, str)) or value is None:
+            return value if not isinstance(value, int) or isinstance(value, bool) else int(value)
+        if isinstance(value, (list, tuple)):
+            return [self._json(item) for item in value]
+        if isinstance(value, SetValue):
+            return [self._json(item) for item in value.values()]
+        if isinstance(value, DictValue):
+            return {str(key): self._json(value.entries[key]) for key in sorted(value.entries)}
+        if value is NONE:
+            return None
+        if isinstance(value, Some):
+            return self._json(value.value)
+        if isinstance(value, StructValue):
+            return {name: self._json(item) for name, item in value.fields.items() if not name.startswith("_")}
+        if isinstance(value, EnumValue):
+            if not value.fields:
+                return value.variant
+            return {value.variant: {name: self._json(item) for name, item in value.fields.items()}}
+        raise self.error(f"to_json() of {self._describe(value)}")
+
+    def _describe(self, value: object) -> str:
+        if isinstance(value, bool):
+            return "a bool"
+        if isinstance(value, int):
+            return "an integer"
+        if isinstance(value, float):
+            return "a float"
+        if isinstance(value, str):
+            return "a string"
+        if isinstance(value, StructValue):
+            return f"a '{value.name}' struct"
+        if isinstance(value, EnumValue):
+            return f"a '{value.name}' enum"
+        if isinstance(value, (Function, Builtin)):
+            return "a function"
+        names = {list: "an array", tuple: "a tuple", DictValue: "a dict", SetValue: "a set", RangeValue: "a range"}
+        return names.get(type(value), "the unit value" if value is None else f"a {type(value).__name__} value")
+
+
+# --- The interpreter ---
+
+
+class Interpreter(Runtime, zincVisitor):
+    """Runs a checked program's `main()`; statements are visited for effect and expressions return values."""
+
+    def __init__(
+        self,
+        checked: CheckedProgram,
+        *,
+        stdout: TextIO | None = None,
+        stderr: TextIO | None = None,
+        overflow: str = DEFAULT_OVERFLOW_MODE,
+    ):
+        """Prepare to run `checked`, writing the program's output to `stdout` and `stderr`."""
+        feature = unsupported_feature(checked)
+        if feature is not None:
+            raise InterpreterError(feature)
+        super().__init__(checked.graph.default_int, stdout=stdout, stderr=stderr, overflow=overflow)
+        self.graph = checked.graph
+        self.atlas = checked.atlas
+        self.symbols = checked.symbols
+        self._consts: dict[str, object] = {}
+        self._decorated: dict[str, object] = {}
+        self._interpolations: dict[str, ParserRuleContext] = {}
+
+    # Running
+
+    def _main_function(self) -> object:
+        return self._top_level_function(self.atlas.main.qualified_name)
+
+    def _panic(self, message: str, ctx: ParserRuleContext) -> ZincPanic:
+        return ZincPanic(message, self._location(ctx, column=True))
+
+    def _location(self, ctx: ParserRuleContext, *, column: bool = False) -> str:
+        module_id = self._module_of(ctx)
+        location = f"{module_id}.zn:{ctx.start.line}"
+        return f"{location}:{ctx.start.column + 1}" if column else location
+
+    def _module_of(self, ctx: ParserRuleContext) -> str:
+        name = ctx.start.getInputStream().name
+        for module in self.graph.modules.values():
+            if str(module.path) == name:
+                return module.module_id
+        return self.frame.module_id
+
+    # Blocks and scopes
+
+    def _run_block(self, ctx: ZincParser.BlockContext, *, as_value: bool = False) -> object:
+        """Run a block in a new scope, returning the value of its last statement when it is used as a value."""
+        self.scope = Scope(self.frame, self.scope)
+        try:
+            return self._run_statements(ctx.statement(), as_value=as_value)
+        finally:
+            self._leave_scope()
+
+    def _run_statements(self, statements: list, *, as_value: bool = False) -> object:
+        for statement in statements[:-1]:
+            self.visit(statement)
+        if not statements:
+            return None
+        last = statements[-1]
+        if not as_value:
+            self.visit(last)
+            return None
+        value = self._tail_value(last)
+        return self._unwrap_try(value) if self.frame.try_blocks else value
+
+    def _tail_value(self, ctx: ZincParser.StatementContext) -> object:
+        if ctx.expressionStatement() is not None:
+            return self.visit(ctx.expressionStatement().expression())
+        if ctx.block() is not None:
+            return self._run_block(ctx.block(), as_value=True)
+        if ctx.ifStatement() is not None:
+            return self._if(ctx.ifStatement(), as_value=True)
+        if ctx.matchStatement() is not None:
+            return self._match(ctx.matchStatement(), as_value=True)
+        self.visit(ctx)
+        return None
+
+    # Statements
+
+    def visitStatement(self, ctx: ZincParser.StatementContext):
+        """Run one statement; declarations were handled before the program started."""
+        child = ctx.getChild(0)
+        if isinstance(child, (ZincParser.ImportStatementContext, ZincParser.ConstDeclarationContext, ZincParser.StructDeclarationContext)):
+            return None
+        if isinstance(child, ZincParser.EnumDeclarationContext):
+            return None
+        if isinstance(child, ZincParser.FunctionDeclarationContext):
+            name = child.functionName().getText()
+            self._define(name, self._decorate(Function(name, child, self.frame.module_id, closure=self.scope), child))
+            return None
+        if isinstance(child, ZincParser.BlockContext):
+            return self._run_block(child)
+        return self.visit(child)
+
+    def visitExpressionStatement(self, ctx: ZincParser.ExpressionStatementContext):
+        """Evaluate an expression for its effects."""
+        return self.visit(ctx.expression())
+
+    def visitBlock(self, ctx: ZincParser.BlockContext):
+        """Run a nested block."""
+        return self._run_block(ctx)
+
+    def visitVariableAssignment(self, ctx: ZincParser.VariableAssignmentContext):
+        """Run `target = value` or a compound assignment like `target += value`."""
+        operator = ctx.assignmentOperator().getText()
+        target = ctx.assignmentTarget()
+        if operator != "=":
+            self._compound_assign(target, ASSIGNMENT_TO_BINARY_OPERATOR[operator], ctx.expression(), ctx)
+            return None
+        if target.IDENTIFIER() is not None:
+            name = target.IDENTIFIER().getText()
+            rebinds = is_let_binding(target)
+            holder = None if rebinds else self.scope.find(name)
+            existing = holder.variables[name] if holder is not None else None
+            keeps_wrapper = isinstance(existing, (Ok, Err, Some, _NoneType))
+            value = self.visit(ctx.expression()) if keeps_wrapper else self._value(ctx.expression())
+            (self._rebind if rebinds else self._assign)(name, copy_value(value))
+        elif target.tupleAssignmentTarget() is not None:
+            self._assign_tuple(self._tuple_names(target.tupleAssignmentTarget()), self._value(ctx.expression()), self._assign)
+        elif target.memberAccess() is not None:
+            access = target.memberAccess()
+            receiver = self.visit(access.expression())
+            self._set_field(receiver, access.IDENTIFIER().getText(), copy_value(self._value(ctx.expression())), access)
+        else:
+            access = target.indexAccess()
+            container = self.visit(access.expression(0))
+            index = self._value(access.expression(1))
+            self._set_index(container, index, copy_value(self._value(ctx.expression())), access)
+        return None
+
+    def _compound_assign(self, target: ZincParser.AssignmentTargetContext, operator: str, expr_ctx, ctx) -> None:
+        if target.IDENTIFIER() is not None:
+            name = target.IDENTIFIER().getText()
+            current = self._read_variable(name, target)
+            self._store(name, self._binary(operator, current, self._value(expr_ctx), ctx))
+        elif target.memberAccess() is not None:
+            access = target.memberAccess()
+            receiver = self.visit(access.expression())
+            member = access.IDENTIFIER().getText()
+            current = self._member(receiver, member, access)
+            self._set_field(receiver, member, self._binary(operator, current, self._value(expr_ctx), ctx), access)
+        elif target.indexAccess() is not None:
+            access = target.indexAccess()
+            container = self.visit(access.expression(0))
+            index = self._value(access.expression(1))
+            current = self._index(container, index, access)
+            self._set_index(container, index, self._binary(operator, current, self._value(expr_ctx), ctx), access)
+        else:
+            raise InterpreterError("compound assignment to several variables")
+
+    def visitOutAssignment(self, ctx: ZincParser.OutAssignmentContext):
+        """Run `out name = value`, which assigns a variable captured from the enclosing function."""
+        name = ctx.IDENTIFIER(1).getText()
+        operator = ctx.assignmentOperator().getText()
+        if operator == "=":
+            self._store(name, copy_value(self._value(ctx.expression())))
+        else:
+            current = self._read_variable(name, ctx)
+            self._store(name, self._binary(ASSIGNMENT_TO_BINARY_OPERATOR[operator], current, self._value(ctx.expression()), ctx))
+        return None
+
+    def visitTypedVariableAssignment(self, ctx: ZincParser.TypedVariableAssignmentContext):
+        """Run `name: Type = value`, converting the value to the annotated type."""
+        annotation = ctx.type_().getText()
+        keeps_wrapper = annotation.startswith(("Result<", "Option<"))
+        value = self.visit(ctx.expression()) if keeps_wrapper else self._value(ctx.expression())
+        value = self._convert(copy_value(value), annotation)
+        target = ctx.typedAssignmentTarget()
+        if target.IDENTIFIER() is not None:
+            self._define(target.IDENTIFIER().getText(), value)
+        else:
+            self._assign_tuple(self._tuple_names(target.tupleAssignmentTarget()), value, self._define, annotation)
+        return None
+
+    def _tuple_names(self, ctx: ZincParser.TupleAssignmentTargetContext) -> list[str]:
+        return [token.getText() for token in ctx.IDENTIFIER()]
+
+    def visitIfStatement(self, ctx: ZincParser.IfStatementContext):
+        """Run the first branch whose condition holds."""
+        self._if(ctx)
+        return None
+
+    def _if(self, ctx: ZincParser.IfStatementContext, *, as_value: bool = False) -> object:
+        conditions = ctx.expression()
+        blocks = ctx.block()
+        for index, condition in enumerate(conditions):
+            if self._truth(self._value(condition), condition):
+                return self._run_block(blocks[index], as_value=as_value)
+        if len(blocks) > len(conditions):
+            return self._run_block(blocks[-1], as_value=as_value)
+        return None
+
+    def visitWhileStatement(self, ctx: ZincParser.WhileStatementContext):
+        """Run a while loop."""
+        while self._truth(self._value(ctx.expression()), ctx.expression()):
+            try:
+                self._run_block(ctx.block())
+            except _Break:
+                break
+            except _Continue:
+                continue
+        return None
+
+    def visitLoopStatement(self, ctx: ZincParser.LoopStatementContext):
+        """Run a loop until it breaks."""
+        while True:
+            try:
+                self._run_block(ctx.block())
+            except _Break:
+                break
+            except _Continue:
+                continue
+        return None
+
+    def visitForStatement(self, ctx: ZincParser.ForStatementContext):
+        """Run a for loop; every iteration binds copies of the elements in a fresh scope."""
+        binding = ctx.forBinding()
+        iterable = self._value(ctx.expression())
+        names = [binding.IDENTIFIER().getText()] if binding.IDENTIFIER() is not None else self._tuple_names(binding.tupleAssignmentTarget())
+        for item in self._iterate(iterable, ctx.expression()):
+            self.scope = Scope(self.frame, self.scope)
+            try:
+                if len(names) == 1:
+                    self._define(names[0], copy_value(item))
+                else:
+                    self._assign_tuple(names, item, self._define)
+                self._run_statements(ctx.block().statement())
+            except _Break:
+                break
+            except _Continue:
+                continue
+            finally:
+                self._leave_scope()
+        return None
+
+    def visitBreakStatement(self, ctx):
+        """Leave the innermost loop."""
+        raise _Break()
+
+    def visitContinueStatement(self, ctx):
+        """Start the next iteration of the innermost loop."""
+        raise _Continue()
+
+    def visitReturnStatement(self, ctx: ZincParser.ReturnStatementContext):
+        """Return from the current function."""
+        raise _Return(self.visit(ctx.expression()) if ctx.expression() is not None else None)
+
+    def visitFailStatement(self, ctx: ZincParser.FailStatementContext):
+        """End the innermost try block, or the function, with `Err(error)`."""
+        raise Failure(self.visit(ctx.expression()))
+
+    def visitMatchStatement(self, ctx: ZincParser.MatchStatementContext):
+        """Run the first arm whose pattern matches."""
+        self._match(ctx)
+        return None
+
+    def _match(self, ctx: ZincParser.MatchStatementContext, *, as_value: bool = False) -> object:
+        subject = self.visit(ctx.expression())
+        for arm in ctx.matchArm():
+            bindings: dict[str, object] = {}
+            if not self._pattern_matches(arm.pattern(), subject, bindings):
+                continue
+            self.scope = Scope(self.frame, self.scope)
+            try:
+                for name, value in bindings.items():
+                    self._define(name, copy_value(value))
+                if arm.block() is not None:
+                    return self._run_statements(arm.block().statement(), as_value=as_value)
+                value = self.visit(arm.expression())
+                return value if as_value else None
+            finally:
+                self._leave_scope()
+        raise self._panic("no match arm matched the value", ctx)
+
+    def _pattern_matches(self, ctx: ZincParser.PatternContext, value: object, bindings: dict) -> bool:
+        text = ctx.getText()
+        if text == "_":
+            return True
+        if ctx.literal() is not None:
+            return self._equal(self.visit(ctx.literal()), value)
+        if ctx.resultOptionPattern() is not None:
+            pattern = ctx.resultOptionPattern()
+            head = pattern.getChild(0).getText()
+            if head == "None":
+                return value is NONE
+            wrapper = {"Ok": Ok, "Err": Err, "Some": Some}[head]
+            return isinstance(value, wrapper) and self._pattern_matches(pattern.pattern(), value.value, bindings)
+        if ctx.enumVariantPattern() is not None:
+            return self._variant_pattern_matches(ctx.enumVariantPattern(), value, bindings)
+        if ctx.rangePattern() is not None:
+            low, high = (parse_numeric_literal(token.getText()).value for token in ctx.rangePattern().INTEGER())
+            inclusive = ctx.rangePattern().getChild(1).getText() == "..="
+            return _is_number(value) and low <= value and (value <= high if inclusive else value < high)
+        if ctx.fieldPattern():
+            if not isinstance(value, StructValue) or value.name != ctx.IDENTIFIER().getText():
+                return False
+            for field_pattern in ctx.fieldPattern():
+                name = field_pattern.IDENTIFIER().getText()
+                if field_pattern.pattern() is None:
+                    bindings[name] = value.fields[name]
+                elif not self._pattern_matches(field_pattern.pattern(), value.fields[name], bindings):
+                    return False
+            return True
+        if ctx.pattern():
+            patterns = ctx.pattern()
+            if not isinstance(value, tuple) or len(value) != len(patterns):
+                return False
+            return all(self._pattern_matches(pattern, item, bindings) for pattern, item in zip(patterns, value, strict=True))
+        bindings[ctx.IDENTIFIER().getText()] = value
+        return True
+
+    def _variant_pattern_matches(self, ctx: ZincParser.EnumVariantPatternContext, value: object, bindings: dict) -> bool:
+        path = ctx.enumVariantPath()
+        resolved = self.graph.resolve_enum_variant_path(
+            self.frame.module_id, [*qualified_name_path(path.qualifiedName()), path.IDENTIFIER().getText()]
+        )
+        if resolved is None:
+            raise InterpreterError(f"the pattern '{ctx.getText()}'")
+        enum_symbol, variant = resolved
+        if not isinstance(value, EnumValue) or value.qualified_name != enum_symbol.qualified_name or value.variant != variant:
+            return False
+        for field_pattern in ctx.enumVariantFieldPattern():
+            names = [token.getText() for token in field_pattern.IDENTIFIER()]
+            bindings[names[-1]] = value.fields[names[0]]
+        return True
+
+    # Expressions
+
+    def _value(self, ctx: ParserRuleContext) -> object:
+        """Evaluate an expression whose Result or Option is unwrapped inside a try block."""
+        value = self.visit(ctx)
+        return self._unwrap_try(value) if self.frame.try_blocks else value
+
+    def visitParenExpr(self, ctx: ZincParser.ParenExprContext):
+        """Evaluate a parenthesized expression."""
+        return self.visit(ctx.expression())
+
+    def visitPrimaryExpr(self, ctx: ZincParser.PrimaryExprContext):
+        """Evaluate a literal, name, or constructor."""
+        return self.visit(ctx.primaryExpression())
+
+    def visitPrimaryExpression(self, ctx: ZincParser.PrimaryExpressionContext):
+        """Evaluate the one alternative of a primary expression."""
+        if ctx.IDENTIFIER() is not None:
+            return self._read_variable(ctx.IDENTIFIER().getText(), ctx)
+        if ctx.SELF() is not None:
+            return self.frame.receiver
+        if ctx.TYPE_KW() is not None:
+            raise InterpreterError("compile-time metadata")
+        return self.visit(ctx.getChild(0))
+
+    def visitLiteral(self, ctx: ZincParser.LiteralContext):
+        """Evaluate a number, string, or bool literal."""
+        text = ctx.getText()
+        if ctx.STRING() is not None:
+            return self._interpolate(text, ctx) if is_interpolated_string_literal(text) else decode_string_literal(text)
+        if ctx.booleanLiteral() is not None:
+            return text == "true"
+        if text == "nil":
+            return None
+        literal = parse_numeric_literal(text)
+        return self._number(literal.value, literal.exact_type)
+
+    def visitUnitLiteral(self, ctx):
+        """Evaluate `()`."""
+        return None
+
+    def _interpolate(self, text: str, ctx: ParserRuleContext, placeholders: Iterator[ParserRuleContext] | None = None) -> str:
+        """Build an interpolated string, evaluating each `{expression}` in the current scope.
+
+        With `placeholders`, as for print("...", values...), each empty `{}` shows the next of those expressions.
+        """
+        quote, inner = text[0], text[1:-1]
+        pieces = []
+        position = 0
+        while (start := inner.find("{", position)) != -1:
+            stop = inner.find("}", start)
+            if stop == -1:
+                break
+            pieces.append(decode_string_literal(quote + inner[position:start] + quote))
+            expression = inner[start + 1 : stop]
+            value_ctx = next(placeholders) if placeholders is not None and not expression else self._interpolation(expression)
+            pieces.append(self._display(self.visit(value_ctx)))
+            position = stop + 1
+        pieces.append(decode_string_literal(quote + inner[position:] + quote))
+        return "".join(pieces)
+
+    def _interpolation(self, text: str) -> ParserRuleContext:
+        if text not in self._interpolations:
+            parser = ZincParser(CommonTokenStream(source_lexer(text)))
+            parser.removeErrorListeners()
+            self._interpolations[text] = parser.expression()
+        return self._interpolations[text]
+
+    def visitArrayLiteral(self, ctx: ZincParser.ArrayLiteralContext):
+        """Evaluate `[a, b, c]`, or `[value; n]`."""
+        length = array_length(ctx)
+        if length is not None:
+            value = self._value(ctx.expression(0))
+            return [copy_value(value) for _ in range(length)]
+        return [copy_value(self._value(expr)) for expr in ctx.expression()]
+
+    def visitTupleLiteral(self, ctx: ZincParser.TupleLiteralContext):
+        """Evaluate `(a, b)`."""
+        return tuple(copy_value(self._value(expr)) for expr in ctx.expression())
+
+    def visitCollectionLiteral(self, ctx: ZincParser.CollectionLiteralContext):
+        """Evaluate a dict literal `{k: v}` or a set literal `{a, b}`."""
+        if ctx.dictEntry():
+            return DictValue({self._value(entry.expression(0)): copy_value(self._value(entry.expression(1))) for entry in ctx.dictEntry()})
+        if ctx.expression():
+            return SetValue(dict.fromkeys(self._value(expr) for expr in ctx.expression()))
+        return DictValue()
+
+    def visitBuiltinResultOptionConstructor(self, ctx: ZincParser.BuiltinResultOptionConstructorContext):
+        """Evaluate `Ok(x)`, `Err(e)`, `Some(x)`, or `None`."""
+        head = ctx.getChild(0).getText()
+        if head == "None":
+            return NONE
+        return {"Ok": Ok, "Err": Err, "Some": Some}[head](copy_value(self._value(ctx.expression())))
+
+    def visitAnonymousStructLiteral(self, ctx: ZincParser.AnonymousStructLiteralContext):
+        """Evaluate `struct { name: value }`."""
+        fields: dict[str, object] = {}
+        for entry in ctx.structFieldEntry():
+            self._field_entry(entry, fields)
+        return StructValue(None, "struct", fields)
+
+    def _field_entry(self, entry: ZincParser.StructFieldEntryContext, fields: dict, struct: StructInstance | None = None) -> None:
+        if entry.fieldSpread() is not None:
+            source = self._value(entry.fieldSpread().expression())
+            for name, value in source.fields.items():
+                if struct is None or any(candidate.name == name for candidate in struct.fields):
+                    fields[name] = copy_value(value)
+            return
+        init = entry.fieldInit()
+        fields[init.IDENTIFIER().getText()] = copy_value(self._value(init.expression()))
+
+    def visitStructInstantiation(self, ctx: ZincParser.StructInstantiationContext):
+        """Evaluate `Name { field: value, ..spread }`, filling the other fields with their defaults."""
+        symbol = self.graph.resolve_struct_path(self.frame.module_id, struct_path_from_ctx(ctx))
+        if symbol is None:
+            raise InterpreterError(f"the struct '{ctx.qualifiedName().getText()}'")
+        struct = self.atlas.structs[symbol.qualified_name]
+        if symbol.qualified_name == self.frame.constructs and isinstance(self.frame.owner, StructInstance):
+            struct = self.frame.owner
+        given: dict[str, object] = {}
+        entries = ctx.structFieldEntry()
+        for index, entry in enumerate(entries):
+            if entry.fieldSpread() is not None:
+                later = {following.fieldInit().IDENTIFIER().getText() for following in entries[index + 1 :] if following.fieldInit()}
+                if {info.name for info in struct.fields} <= later:
+                    # Every field the spread could supply is given again, so it is never evaluated.
+                    continue
+            self._field_entry(entry, given, struct)
+        return self._new_struct(struct, given)
+
+    def _struct_definition(self, qualified_name: str) -> StructInstance:
+        return self.atlas.structs[qualified_name]
+
+    def _new_struct(self, struct: StructInstance, given: dict) -> StructValue:
+        fields = {}
+        for info in struct.fields:
+            if info.name in given:
+                value = given[info.name]
+            elif info.default_value is not None:
+                value = self._field_default(struct, info.name)
+            else:
+                value = self._zero(info)
+            fields[info.name] = self._convert(value, info.exact_type)
+        return StructValue(struct.qualified_name, struct.name, fields)
+
+    def _static_field(self, qualified_name: str, name: str) -> tuple[str | None, Callable[[], object]] | None:
+        struct = self.atlas.structs.get(qualified_name)
+        info = next((candidate for candidate in struct.static_fields if candidate.name == name), None) if struct else None
+        return (info.exact_type, lambda: self._field_default(struct, name)) if info is not None else None
+
+    def _field_default(self, struct: StructInstance, name: str) -> object:
+        info = next(candidate for candidate in (*struct.fields, *struct.static_fields) if candidate.name == name)
+        owner = info.source_struct_qualified_name or struct.qualified_name
+        symbol = self.graph.top_level_symbols[owner]
+        for member in symbol.ctx.structBody().structMember():
+            field_ctx = member.structField()
+            if field_ctx is not None and field_ctx.IDENTIFIER().getText() == name and field_ctx.expression() is not None:
+                return self._in_module(symbol.module_id, lambda: self.visit(field_ctx.expression()))
+        raise InterpreterError(f"the default of field '{name}'")
+
+    def _zero(self, info: StructFieldInfo) -> object:
+        """The value of a field left out of an instantiation: Rust's `Default::default()` for its type."""
+        if info.array_info is not None:
+            return []
+        if info.dict_info is not None:
+            return DictValue()
+        if info.set_info is not None:
+            return SetValue()
+        if info.option_info is not None:
+            return NONE
+        rust_type = info.rust_type()
+        zeros = {"String": "", "bool": False, "f64": 0.0, "f32": F32(0.0)}
+        if rust_type in zeros:
+            return zeros[rust_type]
+        if rust_type in INTEGER_BOUNDS:
+            return self._number(0, rust_type)
+        raise InterpreterError(f"a default value for the field '{info.name}'")
+
+    def visitEnumVariantConstruction(self, ctx: ZincParser.EnumVariantConstructionContext):
+        """Evaluate `Enum.Variant { field: value }`."""
+        path = enum_variant_path_from_ctx(ctx)
+        resolved = self.graph.resolve_enum_variant_path(self.frame.module_id, path)
+        if resolved is None:
+            # `alias.Name { ... }` parses like a variant but names a struct of an imported module.
+            symbol = self.graph.resolve_struct_path(self.frame.module_id, path)
+            if symbol is None:
+                raise InterpreterError(f"the enum variant '{ctx.enumVariantPath().getText()}'")
+            given = {init.IDENTIFIER().getText(): copy_value(self._value(init.expression())) for init in ctx.fieldInit()}
+            return self._new_struct(self.atlas.structs[symbol.qualified_name], given)
+        enum_symbol, variant = resolved
+        enum = self.atlas.enums[enum_symbol.qualified_name]
+        info = next(candidate for candidate in enum.variants if candidate.name == variant)
+        given = {init.IDENTIFIER().getText(): copy_value(self._value(init.expression())) for init in ctx.fieldInit()}
+        fields = {field_info.name: self._convert(given[field_info.name], field_info.exact_type) for field_info in info.fields}
+        return EnumValue(enum.qualified_name, enum.name, variant, fields)
+
+    def visitIfExpr(self, ctx: ZincParser.IfExprContext):
+        """Evaluate an if expression."""
+        return self._if_expression(ctx.ifExpression())
+
+    def _if_expression(self, ctx: ZincParser.IfExpressionContext) -> object:
+        if self._truth(self._value(ctx.expression()), ctx.expression()):
+            return self._run_block(ctx.block(0), as_value=True)
+        if ctx.ifExpression() is not None:
+            return self._if_expression(ctx.ifExpression())
+        if len(ctx.block()) > 1:
+            return self._run_block(ctx.block(1), as_value=True)
+        return None
+
+    def visitBlockExpr(self, ctx: ZincParser.BlockExprContext):
+        """Evaluate a block expression to the value of its last statement."""
+        block = ctx.blockExpression()
+        self.scope = Scope(self.frame, self.scope)
+        try:
+            return self._run_statements(block.statement(), as_value=True)
+        finally:
+            self._leave_scope()
+
+    def visitTryExpr(self, ctx: ZincParser.TryExprContext):
+        """Evaluate `try { ... }`: an `Err` or `None` unwrapped inside ends the block as its value."""
+        block = ctx.tryExpression().block()
+        try_block = TryBlock()
+        self.frame.try_blocks.append(try_block)
+        try:
+            value = self._run_block(block, as_value=True)
+        except Propagation as exc:
+            return exc.value
+        except Failure as exc:
+            return Err(exc.value)
+        finally:
+            self.frame.try_blocks.pop()
+        family = try_block.family or self._try_family(block)
+        return Ok(value) if family == BaseType.RESULT else Some(value)
+
+    def _try_family(self, block: ZincParser.BlockContext) -> BaseType:
+        """Whether a try block that unwrapped nothing makes a Result or an Option, as the checker decided."""
+        families = self.symbols.auto_unwrap_families_within(block.getSourceInterval())
+        if contains_node(block, ZincParser.FailStatementContext):
+            families.add(BaseType.RESULT)
+        if len(families) != 1:
+            raise InterpreterError("try blocks whose Result or Option type is only known from annotations")
+        return families.pop()
+
+    def visitLambdaExpr(self, ctx: ZincParser.LambdaExprContext):
+        """Create a closure over the current scope."""
+        return Function("<lambda>", ctx.lambdaExpression(), self.frame.module_id, closure=self.scope)
+
+    def visitMemberAccessExpr(self, ctx: ZincParser.MemberAccessExprContext):
+        """Evaluate `value.name`: a field, a bound method, an enum variant, or a module's export."""
+        return self._member(self.visit(ctx.expression()), ctx.IDENTIFIER().getText(), ctx)
+
+    def visitIndexAccessExpr(self, ctx: ZincParser.IndexAccessExprContext):
+        """Evaluate `value[index]`."""
+        container, index = self._value(ctx.expression(0)), self._value(ctx.expression(1))
+        if self.symbols.is_checked_index(ctx.getSourceInterval()):
+            return self._checked_index(container, index)
+        return self._index(container, index, ctx)
+
+    def visitUnaryExpr(self, ctx: ZincParser.UnaryExprContext):
+        """Evaluate `-x`, `!x`, `not x`, or `~x`."""
+        return self._unary(ctx.getChild(0).getText(), self._value(ctx.expression()), ctx)
+
+    def _binary_expr(self, ctx) -> object:
+        operator = ctx.getChild(1).getText()
+        return self._binary(operator, self._value(ctx.expression(0)), self._value(ctx.expression(1)), ctx)
+
+    visitPowerExpr = _binary_expr
+
+    visitMultiplicativeExpr = _binary_expr
+
+    visitAdditiveExpr = _binary_expr
+
+    visitShiftExpr = _binary_expr
+
+    visitBitwiseAndExpr = _binary_expr
+
+    visitBitwiseXorExpr = _binary_expr
+
+    visitBitwiseOrExpr = _binary_expr
+
+    visitRelationalExpr = _binary_expr
+
+    visitEqualityExpr = _binary_expr
+
+    def visitCustomOperatorExpr(self, ctx: ZincParser.CustomOperatorExprContext):
+        """Evaluate a user-defined operator such as `a <+> b`."""
+        left = self._value(ctx.expression(0))
+        right = self._value(ctx.expression(1))
+        return self._call_operator(left, ctx.getChild(1).getText(), [right], ctx)
+
+    def visitRangeExpr(self, ctx: ZincParser.RangeExprContext):
+        """Evaluate `a..b` or `a..=b`."""
+        return RangeValue(self._value(ctx.expression(0)), self._value(ctx.expression(1)), ctx.getChild(1).getText() == "..=")
+
+    def visitMembershipExpr(self, ctx: ZincParser.MembershipExprContext):
+        """Evaluate `item in collection`."""
+        item = self._value(ctx.expression(0))
+        collection = self._value(ctx.expression(1))
+        if isinstance(collection, StructValue):
+            return self._call_operator(collection, "in", [item], ctx)
+        return self._contains(collection, item)
+
+    def visitLogicalAndExpr(self, ctx: ZincParser.LogicalAndExprContext):
+        """Evaluate `a and b`, skipping `b` when `a` is false."""
+        left = self._value(ctx.expression(0))
+        if isinstance(left, StructValue):
+            return self._call_operator(left, "&&", [self._value(ctx.expression(1))], ctx)
+        return self._truth(left, ctx) and self._truth(self._value(ctx.expression(1)), ctx)
+
+    def visitLogicalOrExpr(self, ctx: ZincParser.LogicalOrExprContext):
+        """Evaluate `a or b`, skipping `b` when `a` is true."""
+        left = self._value(ctx.expression(0))
+        if isinstance(left, StructValue):
+            return self._call_operator(left, "||", [self._value(ctx.expression(1))], ctx)
+        return self._truth(left, ctx) or self._truth(self._value(ctx.expression(1)), ctx)
+
+    # Operators
+
+    def _operator_function(self, value: object, symbol: str) -> tuple[Function, bool] | None:
+        if not isinstance(value, StructValue) or value.qualified_name is None:
+            return None
+        struct = self.atlas.structs[value.qualified_name]
+        method = next((method for method in struct.methods if method.operator_symbol == symbol), None)
+        return (self._method_function(struct, method), method.is_static) if method is not None else None
+
+    # Names, members, and indexes
+
+    def _read_variable(self, name: str, ctx) -> object:
+        holder = self.scope.find(name)
+        if holder is not None:
+            return holder.variables[name]
+        return self._top_level_value([name], ctx)
+
+    def _top_level_value(self, path: list[str], ctx) -> object:
+        module_id = self.frame.module_id
+        if len(path) == 1 and self.graph.resolve_alias(module_id, path[0]) is not None:
+            return ModuleValue(self.graph.resolve_alias(module_id, path[0]))
+        symbol = self.graph.resolve_top_level_path(module_id, path)
+        if symbol is None:
+            raise InterpreterError(f"the name '{'.'.join(path)}'")
+        return self._symbol_value(symbol)
+
+    def _symbol_value(self, symbol) -> object:
+        if symbol.kind == "const":
+            return copy_value(self._const(symbol.qualified_name))
+        if symbol.kind == "function":
+            return self._top_level_function(symbol.qualified_name)
+        if symbol.kind in ("struct", "enum"):
+            return TypeValue(symbol.qualified_name, symbol.kind)
+        raise InterpreterError(f"the {symbol.kind} '{symbol.name}'")
+
+    def _const(self, qualified_name: str) -> object:
+        if qualified_name not in self._consts:
+            symbol = self.graph.top_level_symbols[qualified_name]
+            self._consts[qualified_name] = self._in_module(symbol.module_id, lambda: self.visit(symbol.ctx.expression()))
+        return self._consts[qualified_name]
+
+    def _top_level_function(self, qualified_name: str) -> object:
+        if qualified_name not in self._decorated:
+            symbol = self.graph.top_level_symbols[qualified_name]
+            function = Function(symbol.name, symbol.ctx, symbol.module_id, memo={} if has_marker_decorator(symbol.ctx, "memoize") else None)
+            self._decorated[qualified_name] = self._in_module(symbol.module_id, lambda: self._decorate(function, symbol.ctx))
+        return self._decorated[qualified_name]
+
+    def _decorate(self, function: Function, ctx) -> object:
+        """Apply a function's decorators, innermost first, as the compiled program does when it starts."""
+        value: object = function
+        for decorator in reversed(decorators_from_ctx(ctx)):
+            wrapper = self._top_level_value(list(decorator.path), decorator.ctx)
+            if decorator.has_call:
+                positional, keywords = self._arguments(decorator.argument_list_ctx)
+                wrapper = self._call(wrapper, positional, keywords, decorator.ctx)
+            value = self._call(wrapper, [value], [], decorator.ctx)
+        return value
+
+    def _export(self, module_id: str, name: str) -> object:
+        symbol = self.graph.resolve_export(module_id, name)
+        if symbol is None:
+            raise InterpreterError(f"the name '{name}'")
+        return self._symbol_value(symbol)
+
+    def _type_member(self, owner: TypeValue, name: str, ctx) -> object:
+        if owner.kind == "enum":
+            enum = self.atlas.enums[owner.qualified_name]
+            variant = next((candidate for candidate in enum.variants if candidate.name == name), None)
+            if variant is not None:
+                return EnumValue(enum.qualified_name, enum.name, name)
+            method = next((candidate for candidate in enum.methods if candidate.name == name), None)
+            if method is not None:
+                return self._method_function(enum, method)
+        else:
+            struct = self.atlas.structs[owner.qualified_name]
+            method = next((candidate for candidate in struct.methods if candidate.name == name), None)
+            if method is not None:
+                return self._method_function(struct, method)
+        raise InterpreterError(f"'{owner.qualified_name.partition('::')[2]}.{name}'")
+
+    def _method(self, receiver: StructValue | EnumValue, name: str) -> Function | None:
+        if receiver.qualified_name is None:
+            return None
+        owner = self.atlas.structs.get(receiver.qualified_name) or self.atlas.enums.get(receiver.qualified_name)
+        method = next((candidate for candidate in owner.methods if candidate.name == name), None) if owner is not None else None
+        if method is None:
+            return None
+        return self._method_function(owner, method, None if method.is_static else receiver)
+
+    def _method_function(self, owner, method: StructMethodInfo, receiver: object = None) -> Function:
+        declaration = method.body_ctx.parentCtx
+        module_id = method.source_module_id or owner.module_id
+        return Function(
+            method.name,
+            declaration,
+            module_id,
+            owner=owner,
+            receiver=receiver,
+            has_receiver=receiver is not None,
+            constructs=method.constructor_owner_qualified_name,
+        )
+
+    def _field_type(self, qualified_name: str, name: str) -> str | None:
+        return next(candidate for candidate in self.atlas.structs[qualified_name].fields if candidate.name == name).exact_type
+
+    # Calls
+
+    def visitFunctionCallExpr(self, ctx: ZincParser.FunctionCallExprContext):
+        """Call a function, method, builtin, or callable value."""
+        callee_ctx = ctx.expression()
+        if isinstance(callee_ctx, ZincParser.MemberAccessExprContext):
+            receiver = self.visit(callee_ctx.expression())
+            name = callee_ctx.IDENTIFIER().getText()
+            callee = self._callable_member(receiver, name, callee_ctx)
+            if callee is None:
+                # Uniform call syntax: `x.f(y)` calls the function `f(x, y)`.
+                callee = self._ufcs_function(name)
+                if callee is None:
+                    raise InterpreterError(f"'.{name}()' on {self._describe(receiver)}")
+                positional, keywords = self._arguments(ctx.argumentList(), callee, skip=1)
+                return self._call(callee, [receiver, *positional], keywords, ctx)
+            return self._call(callee, *self._arguments(ctx.argumentList(), callee), ctx)
+        path = extract_identifier_path(callee_ctx)
+        if path is not None and len(path) == 1 and self.scope.find(path[0]) is None:
+            if self.graph.resolve_top_level_path(self.frame.module_id, path) is None:
+                if path[0] == "print" and ctx.argumentList() is not None and len(arguments := ctx.argumentList().argument()) > 1:
+                    literal = format_string_literal(arguments[0].expression())
+                    values = (argument.expression() for argument in arguments[1:])
+                    return self._call_builtin(path[0], ctx, [self._interpolate(literal.getText(), literal, values)])
+                return self._call_builtin(path[0], ctx, self._arguments(ctx.argumentList())[0])
+        callee = self.visit(callee_ctx)
+        return self._call(callee, *self._arguments(ctx.argumentList(), callee), ctx)
+
+    def _ufcs_function(self, name: str) -> object | None:
+        holder = self.scope.find(name)
+        if holder is not None:
+            return holder.variables[name]
+        function = self.graph.resolve_function_path(self.frame.module_id, [name])
+        return self._top_level_function(function.qualified_name) if function is not None else None
+
+    def _arguments(
+        self, ctx: ZincParser.ArgumentListContext | None, callee: object = None, skip: int = 0
+    ) -> tuple[list, list[tuple[str | None, object]]]:
+        """Positional arguments, then `name=value` and `..spread` arguments as (name, value) in source order; spreads have no name.
+
+        A spread whose parameters are all named again after it is never evaluated.
+        """
+        arguments = ctx.argument() if ctx is not None else []
+        parameters = [name for name, _, _ in self._parameters_of(callee)] if isinstance(callee, Function) else None
+        positional: list = []
+        keywords: list[tuple[str | None, object]] = []
+        for index, argument in enumerate(arguments):
+            if argument.IDENTIFIER() is not None:
+                keywords.append((argument.IDENTIFIER().getText(), self.visit(argument.expression())))
+            elif argument.getChild(0).getText() == "..":
+                if parameters is not None:
+                    later = {following.IDENTIFIER().getText() for following in arguments[index + 1 :] if following.IDENTIFIER() is not None}
+                    if set(parameters[skip + len(positional) :]) <= later:
+                        continue
+                keywords.append((None, self._value(argument.expression())))
+            else:
+                positional.append(self.visit(argument.expression()))
+        return positional, keywords
+
+    def _parameters_of(self, function: Function) -> list[tuple[str, str | None, object]]:
+        return function_parameters(function.code)
+
+    def _default_value(self, function: Function, default: object) -> object:
+        return self.visit(default)
+
+    def _run_body(self, function: Function) -> object:
+        ctx = function.code
+        if isinstance(ctx, ZincParser.LambdaExpressionContext) and ctx.expression() is not None:
+            return self.visit(ctx.expression())
+        self._run_statements(ctx.block().statement())
+        return None
+
+    def _return_type(self, function: Function) -> str | None:
+        ctx = function.code
+        return_type = ctx.type_() if isinstance(ctx, ZincParser.FunctionDeclarationContext) else None
+        return return_type.getText() if return_type is not None else None
+
+    # Builtins
+
+    def _call_builtin(self, name: str, ctx: ZincParser.FunctionCallExprContext, positional: list) -> object:
+        arguments = ctx.argumentList().argument() if ctx.argumentList() is not None else []
+        if name == "include_str":
+            return included_file(self.graph.path_for_module(self.frame.module_id), arguments[0].expression()).read_text()
+        return self._builtin(name, positional, self._location(ctx), [source_text(argument) for argument in arguments])
+
+
+class _JsonFloat(float):
+    """A float that `json.dumps` writes the way serde does."""
+
+    def __repr__(self) -> str:
+        return debug_float(self)
+
+
+def function_parameters(ctx) -> list[tuple[str, str | None, ParserRuleContext | None]]:
+    """(name, annotation, default) for each parameter of a function, method, or lambda."""
+    parameter_list = ctx.parameterList() if hasattr(ctx, "parameterList") else None
+    if parameter_list is not None:
+        return [
+            (
+                parameter.IDENTIFIER().getText(),
+                parameter.typeAlternative().getText() if parameter.typeAlternative() is not None else None,
+                parameter.expression(),
+            )
+            for parameter in parameter_list.parameter()
+        ]
+    if isinstance(ctx, ZincParser.LambdaExpressionContext) and ctx.IDENTIFIER() is not None:
+        return [(ctx.IDENTIFIER().getText(), None, None)]
+    return []
+
+
+def source_text(ctx: ParserRuleContext) -> str:
+    """The source code of a parse tree, as written."""
+    return ctx.start.getInputStream().getText(ctx.start.start, ctx.stop.stop)
+
+
+def contains_node(ctx, kind: type) -> bool:
+    """Whether a parse tree has a node of type `kind`."""
+    stack = [ctx]
+    while stack:
+        node = stack.pop()
+        if isinstance(node, kind):
+            return True
+        if not isinstance(node, TerminalNode) and node.children:
+            stack.extend(node.children)
+    return False
+
+
+def _reachable_code(checked: CheckedProgram) -> Iterator[tuple[ParserRuleContext, str]]:
+    """Every reachable declaration and the module it is in."""
+    atlas = checked.atlas
+    yield atlas.main.ctx, atlas.main.module_id
+    for function in atlas.functions.values():
+        yield function.ctx, function.module_id
+    for owner in [*atlas.structs.values(), *atlas.enums.values()]:
+        yield owner.ctx, owner.module_id
+        for method in owner.methods:
+            yield method.body_ctx.parentCtx, method.source_module_id or owner.module_id
+    for const in atlas.consts.values():
+        yield const.ctx, const.module_id
+
+
+def unsupported_feature(checked: CheckedProgram) -> str | None:
+    """What a program uses that only compiled programs support, if anything, found before any of it runs."""
+    graph = checked.graph
+    for root, module_id in _reachable_code(checked):
+        stack = [root]
+        while stack:
+            node = stack.pop()
+            if isinstance(node, TerminalNode):
+                continue
+            feature = UNSUPPORTED_SYNTAX.get(type(node))
+            if feature is not None:
+                return feature
+            if isinstance(node, ZincParser.FunctionCallExprContext):
+                path = extract_identifier_path(node.expression())
+                if path is not None and len(path) == 1 and path[0] in UNSUPPORTED_BUILTINS:
+                    return UNSUPPORTED_BUILTINS[path[0]]
+            if isinstance(node, ZincParser.PrimaryExpressionContext) and node.IDENTIFIER() is not None:
+                name = node.IDENTIFIER().getText()
+                if graph.resolve_local_or_imported(module_id, name) is None and (
+                    graph.resolve_extern_function_path(module_id, [name]) or graph.resolve_extern_type_path(module_id, [name])
+                ):
+                    return "Rust functions and types"
+            stack.extend(node.children or [])
+    return None
+
+
+def run_on_large_stack(runtime: Runtime) -> int:
+    """Run a program and return its exit status.
+
+    Deep recursion in the program is deep recursion here, so it runs on a
+    thread with a large stack.
+    """
+    status: list[int] = []
+    failure: list[BaseException] = []
+
+    def run() -> None:
+        try:
+            status.append(runtime.run())
+        except BaseException as exc:  # noqa: BLE001 - re-raised on the calling thread
+            failure.append(exc)
+
+    previous_stack = threading.stack_size(STACK_SIZE)
+    previous_limit = sys.getrecursionlimit()
+    sys.setrecursionlimit(RECURSION_LIMIT)
+    try:
+        thread = threading.Thread(target=run, name="zinc-runtime")
+        thread.start()
+        thread.join()
+    finally:
+        threading.stack_size(previous_stack)
+        sys.setrecursionlimit(previous_limit)
+    if failure:
+        raise failure[0]
+    return status[0]
+
+
+def run_interpreted(checked: CheckedProgram, *, overflow: str = DEFAULT_OVERFLOW_MODE) -> int:
+    """Interpret a checked program and return its exit status."""
+    return run_on_large_stack(Interpreter(checked, overflow=overflow))
@@ -0,0 +1,123 @@
+"""Lint levels: whether each kind of warning is allowed, printed, or fails the compile.
+
+Every warning belongs to a lint, named after its error code's kind, and the
+`warnings` group names them all. The command line sets levels with
+`-A`/`--allow`, `-W`/`--warn`, and `-D`/`--deny`. A source file sets them with
+pragma comments like `// lint: allow(unused-variable), deny(unreachable-code)`.
+A pragma on its own line covers the whole file, and one after code covers only
+its line.
+
+For each warning, a line pragma beats a file pragma, and a file pragma beats
+the command line. Within one of those, a lint named directly beats the
+`warnings` group, and the strictest level wins. A warning whose lint is denied
+is reported as an error.
+"""
+
+import re
+from dataclasses import dataclass, field, replace
+from pathlib import Path
+
+from zinc.diagnostics import Diagnostic
+
+LEVELS = ("allow", "warn", "deny")
+# The lint each warning code belongs to.
+LINTS = {"unused-variable": "Z0011", "unused-function": "Z0012", "unreachable-code": "Z0013"}
+# Names every warning, including ones from compiler passes that have no code.
+WARNINGS_GROUP = "warnings"
+PRAGMA_PATTERN = re.compile(r"//\s*lint:\s*(.*)$")
+PRAGMA_ITEM_PATTERN = re.compile(r"(allow|warn|deny)\(([^)]*)\)")
+
+
+def check_lint_name(name: str) -> str:
+    """Return a lint or group name as given, or raise ValueError naming the valid ones."""
+    if name != WARNINGS_GROUP and name not in LINTS:
+        raise ValueError(f"unknown lint '{name}'; expected one of: {', '.join([WARNINGS_GROUP, *LINTS])}")
+    return name
+
+
+@dataclass
+class LintLevels:
+    """Levels set for lints and the `warnings` group in one place: the command line, a file, or a line."""
+
+    levels: dict[str, str] = field(default_factory=dict)
+
+    def set(self, name: str, level: str) -> None:
+        """Set a lint's level, keeping the stricter one if it was already set here."""
+        current = self.levels.get(name)
+        if current is None or LEVELS.index(level) > LEVELS.index(current):
+            self.levels[name] = level
+
+    def level_for(self, lint: str | None) -> tuple[str, str] | None:
+        """The level set here for a warning of `lint` and the name it was set under, or None when nothing here covers it."""
+        for name in (lint, WARNINGS_GROUP):
+            if name is not None and name in self.levels:
+                return self.levels[name], name
+        return None
+
+
+@dataclass
+class SourcePragmas:
+    """The lint pragmas of one file: file-wide levels and levels for single lines, by 1-based line number."""
+
+    file_levels: LintLevels = field(default_factory=LintLevels)
+    line_levels: dict[int, LintLevels] = field(default_factory=dict)
+    # Pragmas naming lints that don't exist, as (line, name).
+    unknown: list[tuple[int, str]] = field(default_factory=list)
+
+
+def parse_pragmas(source: str) -> SourcePragmas:
+    """Read the `// lint:` pragmas of a source file."""
+    pragmas = SourcePragmas()
+    for number, line in enumerate(source.splitlines(), start=1):
+        match = PRAGMA_PATTERN.search(line)
+        if match is None:
+            continue
+        own_line = not line[: match.start()].strip()
+        levels = pragmas.file_levels if own_line else pragmas.line_levels.setdefault(number, LintLevels())
+        for level, names in PRAGMA_ITEM_PATTERN.findall(match.group(1)):
+            for name in filter(None, (name.strip() for name in names.split(","))):
+                if name == WARNINGS_GROUP or name in LINTS:
+                    levels.set(name, level)
+                else:
+                    pragmas.unknown.append((number, name))
+    return pragmas
+
+
+def lint_of(diagnostic: Diagnostic) -> str | None:
+    """The lint a warning belongs to, or None for warnings that are only in the `warnings` group."""
+    return next((name for name, code in LINTS.items() if code == diagnostic.code), None)
+
+
+def apply_lint_levels(warnings: list[Diagnostic], command_line: LintLevels | None = None) -> tuple[list[Diagnostic], list[Diagnostic]]:
+    """Split warnings into the ones to print and the denied ones, which come back as errors.
+
+    Pragmas are read from each warning's file. A pragma that names an unknown
+    lint is itself reported as a warning.
+    """
+    command_line = command_line or LintLevels()
+    pragmas: dict[str, SourcePragmas] = {}
+    shown, denied = [], []
+    for file in dict.fromkeys(warning.file for warning in warnings if warning.file is not None):
+        try:
+            pragmas[file] = parse_pragmas(Path(file).read_text(encoding="utf-8"))
+        except OSError:
+            continue
+        for line, name in pragmas[file].unknown:
+            shown.append(Diagnostic(f"unknown lint '{name}'", file, line, severity="warning", notes=(f"known lints: {', '.join(LINTS)}",)))
+    for warning in warnings:
+        lint = lint_of(warning)
+        file_pragmas = pragmas.get(warning.file) if warning.file is not None else None
+        scopes = [command_line]
+        if file_pragmas is not None:
+            scopes.append(file_pragmas.file_levels)
+            if warning.line in file_pragmas.line_levels:
+                scopes.append(file_pragmas.line_levels[warning.line])
+        level, name = "warn", None
+        for scope in scopes:
+            level, name = scope.level_for(lint) or (level, name)
+        if level == "warn":
+            shown.append(warning)
+        elif level == "deny":
+            note = f"'{name}' is denied, which turns this warning into an error"
+            denied.append(replace(warning, severity="error", notes=(*warning.notes, note)))
+    return shown, denied
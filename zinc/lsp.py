@@ -0,0 +1,436 @@
+"""Language server for Zinc editors, started with `zinc lsp`.
+
+Speaks the Language Server Protocol over stdin/stdout with full-document sync.
+Every change to an open document re-runs the compiler front end on it (parse,
+module graph, type resolution) with unsaved buffers taking precedence over the
+files on disk, and publishes the resulting diagnostics. The last analysis that
+parsed cleanly is kept per document, so hover, go-to-definition, and completion
+keep working while the buffer is temporarily broken.
+
+Positions are treated as code-point offsets, which matches the protocol's
+UTF-16 offsets for source outside the astral planes.
+"""
+
+import json
+import re
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import BinaryIO
+from urllib.parse import unquote, urlparse
+
+from antlr4 import ParserRuleContext
+from antlr4.tree.Tree import TerminalNodeImpl
+from zinc.atlas import BUILTIN_FUNCTIONS, AtlasBuilder
+from zinc.diagnostics import Diagnostic
+from zinc.ast.types import exact_type_to_rust
+from zinc.exceptions import ZincError, ZincModuleError, ZincSyntaxError, ZincTypeErrors
+from zinc.modules import LoadedModule, ModuleGraph, TopLevelSymbol, build_module_graph, parse_source
+from zinc.operators import function_name_from_ctx
+from zinc.parser.zincLexer import zincLexer as ZincLexer
+from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.symbols import SymbolKind, SymbolTable, SymbolTableVisitor
+
+SERVER_NAME = "zinc-lsp"
+IDENTIFIER_PATTERN = re.compile(r"[^\W\d]\w*")
+KEYWORDS = sorted(name.strip("'") for name in ZincLexer.literalNames if re.fullmatch(r"'[a-z]+'", name))
+
+# Protocol constants.
+TEXT_DOCUMENT_SYNC_FULL = 1
+SEVERITY_ERROR = 1
+SEVERITY_WARNING = 2
+SEVERITIES = {"error": SEVERITY_ERROR, "warning": SEVERITY_WARNING}
+METHOD_NOT_FOUND = -32601
+INTERNAL_ERROR = -32603
+COMPLETION_KIND = {"function": 3, "variable": 6, "module": 9, "enum": 13, "keyword": 14, "const": 21, "struct": 22}
+
+# Parse-tree parents of an IDENTIFIER that introduce a local binding.
+BINDING_CONTEXTS = (
+    ZincParser.ParameterContext,
+    ZincParser.AssignmentTargetContext,
+    ZincParser.TupleAssignmentTargetContext,
+    ZincParser.ForBindingContext,
+)
+SCOPE_CONTEXTS = (
+    ZincParser.FunctionDeclarationContext,
+    ZincParser.AsyncFunctionDeclarationContext,
+    ZincParser.LambdaExpressionContext,
+)
+
+
+def read_message(stream: BinaryIO) -> dict | None:
+    """Read one framed JSON-RPC message, or None at end of input."""
+    content_length = None
+    while True:
+        line = stream.readline()
+        if not line:
+            return None
+        line = line.strip()
+        if not line:
+            break
+        name, _, value = line.decode("ascii").partition(":")
+        if name.strip().lower() == "content-length":
+            content_length = int(value.strip())
+    if content_length is None:
+        return None
+    return json.loads(stream.read(content_length).decode("utf-8"))
+
+
+def write_message(stream: BinaryIO, payload: dict) -> None:
+    """Write one framed JSON-RPC message."""
+    body = json.dumps(payload).encode("utf-8")
+    stream.write(f"Content-Length: {len(body)}\r\n\r\n".encode("ascii") + body)
+    stream.flush()
+
+
+def uri_to_path(uri: str) -> Path:
+    return Path(unquote(urlparse(uri).path)).resolve()
+
+
+def _range(line: int, start: int, end: int) -> dict:
+    return {"start": {"line": line, "character": start}, "end": {"line": line, "character": end}}
+
+
+def _diagnostic(line: int, start: int, end: int, message: str, severity: int = SEVERITY_ERROR) -> dict:
+    return {"range": _range(line, start, end), "severity": severity, "source": "zinc", "message": message}
+
+
+@dataclass
+class Analysis:
+    """Front-end results for one version of a document."""
+
+    path: Path
+    text: str
+    tree: ZincParser.ProgramContext | None = None
+    graph: ModuleGraph | None = None
+    symbols: SymbolTable | None = None
+    diagnostics: list[dict] = field(default_factory=list)
+
+    @property
+    def module(self) -> LoadedModule | None:
+        if self.graph is None:
+            return None
+        return next((module for module in self.graph.modules.values() if module.path == self.path), None)
+
+
+def _defines_main(tree: ZincParser.ProgramContext) -> bool:
+    return any(stmt.functionDeclaration() and function_name_from_ctx(stmt.functionDeclaration()) == "main" for stmt in tree.statement())
+
+
+def analyze(path: Path, text: str, sources: dict[Path, str]) -> Analysis:
+    """Parse and type-check a document, collecting diagnostics instead of raising.
+
+    Modules without `fn main()` are loaded as libraries: their imports and
+    declarations are checked, but type resolution needs an entry point.
+    """
+    analysis = Analysis(path, text)
+    try:
+        analysis.tree, _ = parse_source(text, str(path))
+    except ZincModuleError as exc:
+        syntax_errors = exc.diagnostics if isinstance(exc, ZincSyntaxError) else []
+        analysis.diagnostics = [_diagnostic(error.line - 1, error.column, error.end_column, error.message) for error in syntax_errors]
+        analysis.diagnostics = analysis.diagnostics or [_diagnostic(0, 0, 0, str(exc))]
+        return analysis
+    has_main = _defines_main(analysis.tree)
+    try:
+        analysis.graph = build_module_graph(path, {**sources, path: text}, require_main=has_main)
+        if has_main:
+            visitor = SymbolTableVisitor(AtlasBuilder(analysis.graph).build())
+            analysis.symbols = visitor.resolve()
+            analysis.diagnostics = [_compiler_diagnostic(path, warning) for warning in visitor.warnings if warning.file == str(path)]
+    except ZincTypeErrors as exc:
+        analysis.diagnostics = [_compiler_diagnostic(path, error) for error in exc.diagnostics]
+    except ZincError as exc:
+        analysis.diagnostics = [_diagnostic(0, 0, 0, str(exc))]
+    return analysis
+
+
+def _compiler_diagnostic(path: Path, error: Diagnostic) -> dict:
+    """Diagnostics in this document's statements go on their span; the rest go on the first line. Error codes carry over."""
+    severity = SEVERITIES.get(error.severity, SEVERITY_ERROR)
+    if error.file == str(path) and error.line is not None:
+        start = error.column or 0
+        end = error.end_column if error.end_column is not None else start
+        diagnostic = _diagnostic(error.line - 1, start, end, error.message, severity)
+    else:
+        diagnostic = _diagnostic(0, 0, 0, str(error) if error.file is not None else error.message, severity)
+    return {**diagnostic, "code": error.code} if error.code else diagnostic
+
+
+def _terminals(ctx: ParserRuleContext) -> list[TerminalNodeImpl]:
+    terminals = []
+    stack = [ctx]
+    while stack:
+        node = stack.pop()
+        if isinstance(node, TerminalNodeImpl):
+            if node.symbol.type != -1:
+                terminals.append(node)
+            continue
+        stack.extend(reversed([node.getChild(i) for i in range(node.getChildCount())]))
+    return terminals
+
+
+def _position(terminal: TerminalNodeImpl) -> tuple[int, int]:
+    return terminal.symbol.line - 1, terminal.symbol.column
+
+
+def _terminal_range(terminal: TerminalNodeImpl) -> dict:
+    line, column = _position(terminal)
+    return _range(line, column, column + len(terminal.getText()))
+
+
+def _word_at(text: str, line: int, character: int) -> tuple[str | None, str | None]:
+    """Return (qualifier, identifier) under the cursor, e.g. ("hash", "sha256") for `hash.sha256`."""
+    lines = text.splitlines()
+    if line >= len(lines):
+        return None, None
+    line_text = lines[line]
+    for match in IDENTIFIER_PATTERN.finditer(line_text):
+        if match.start() <= character <= match.end():
+            prefix = line_text[: match.start()]
+            qualifier = re.search(r"([^\W\d]\w*)\.$", prefix)
+            return (qualifier.group(1) if qualifier else None), match.group(0)
+    return None, None
+
+
+def _completion_prefix(text: str, line: int, character: int) -> tuple[str | None, str]:
+    """Return (qualifier, partial identifier) immediately before the cursor."""
+    lines = text.splitlines()
+    line_text = lines[line][:character] if line < len(lines) else ""
+    match = re.search(r"(?:([^\W\d]\w*)\.)?([^\W\d]\w*)?$", line_text)
+    return match.group(1), match.group(2) or ""
+
+
+def _enclosing_scope(tree: ParserRuleContext, line: int, character: int) -> ParserRuleContext | None:
+    """Return the innermost function or lambda containing a position."""
+    best = None
+    stack = [tree]
+    while stack:
+        node = stack.pop()
+        if isinstance(node, TerminalNodeImpl) or node.start is None or node.stop is None:
+            continue
+        start = (node.start.line - 1, node.start.column)
+        stop = (node.stop.line - 1, node.stop.column + len(node.stop.text or ""))
+        if not start <= (line, character) <= stop:
+            continue
+        if isinstance(node, SCOPE_CONTEXTS):
+            best = node
+        stack.extend(node.getChild(i) for i in range(node.getChildCount()))
+    return best
+
+
+def _local_bindings(scope: ParserRuleContext, line: int, character: int) -> dict[str, TerminalNodeImpl]:
+    """Map each name bound in `scope` before a position to its first binding."""
+    bindings: dict[str, TerminalNodeImpl] = {}
+    for terminal in _terminals(scope):
+        if terminal.symbol.type != ZincParser.IDENTIFIER or _position(terminal) > (line, character):
+            continue
+        if isinstance(terminal.parentCtx, BINDING_CONTEXTS):
+            bindings.setdefault(terminal.getText(), terminal)
+    return bindings
+
+
+def _name_terminal(symbol: TopLevelSymbol) -> TerminalNodeImpl | None:
+    return next((terminal for terminal in _terminals(symbol.ctx) if terminal.getText() == symbol.name), None)
+
+
+def _declaration_header(symbol: TopLevelSymbol, text: str) -> str:
+    """Return a declaration's source up to its body, e.g. `fn add(x: i64, y: i64) -> i64`."""
+    ctx = symbol.ctx
+    end = ctx.stop.stop + 1
+    if symbol.kind == "function" and ctx.block() is not None:
+        end = ctx.block().start.start
+    elif symbol.kind in ("struct", "enum"):
+        brace = next((child for child in ctx.getChildren() if isinstance(child, TerminalNodeImpl) and child.getText() == "{"), None)
+        end = brace.symbol.start if brace is not None else end
+    return " ".join(text[ctx.start.start : end].split())
+
+
+class LanguageServer:
+    """Stateful request handler; `handle` maps one incoming message to outgoing ones."""
+
+    def __init__(self) -> None:
+        self.texts: dict[Path, str] = {}
+        self.analyses: dict[Path, Analysis] = {}
+        self.shutdown_requested = False
+        self.exited = False
+
+    def handle(self, message: dict) -> list[dict]:
+        method = message.get("method")
+        params = message.get("params") or {}
+        handler = getattr(self, "_" + (method or "").replace("/", "_").replace("$", "dollar"), None)
+        is_request = "id" in message
+        if handler is None:
+            if is_request:
+                return [{"jsonrpc": "2.0", "id": message["id"], "error": {"code": METHOD_NOT_FOUND, "message": f"unsupported method {method}"}}]
+            return []
+        try:
+            result, notifications = handler(params)
+        except Exception as exc:  # A broken request must not take the server down.
+            if is_request:
+                return [{"jsonrpc": "2.0", "id": message["id"], "error": {"code": INTERNAL_ERROR, "message": str(exc)}}]
+            return []
+        if is_request:
+            return [{"jsonrpc": "2.0", "id": message["id"], "result": result}, *notifications]
+        return notifications
+
+    # Lifecycle.
+
+    def _initialize(self, params: dict):
+        capabilities = {
+            "textDocumentSync": TEXT_DOCUMENT_SYNC_FULL,
+            "hoverProvider": True,
+            "definitionProvider": True,
+            "completionProvider": {"triggerCharacters": ["."]},
+        }
+        return {"capabilities": capabilities, "serverInfo": {"name": SERVER_NAME}}, []
+
+    def _initialized(self, params: dict):
+        return None, []
+
+    def _shutdown(self, params: dict):
+        self.shutdown_requested = True
+        return None, []
+
+    def _exit(self, params: dict):
+        self.exited = True
+        return None, []
+
+    # Document sync.
+
+    def _textDocument_didOpen(self, params: dict):  # noqa: N802
+        document = params["textDocument"]
+        return None, self._update(document["uri"], document["text"])
+
+    def _textDocument_didChange(self, params: dict):  # noqa: N802
+        return None, self._update(params["textDocument"]["uri"], params["contentChanges"][-1]["text"])
+
+    def _textDocument_didClose(self, params: dict):  # noqa: N802
+        uri = params["textDocument"]["uri"]
+        path = uri_to_path(uri)
+        self.texts.pop(path, None)
+        self.analyses.pop(path, None)
+        return None, [self._publish(uri, [])]
+
+    def _update(self, uri: str, text: str) -> list[dict]:
+        path = uri_to_path(uri)
+        self.texts[path] = text
+        analysis = analyze(path, text, self.texts)
+        previous = self.analyses.get(path)
+        if analysis.tree is not None:
+            if analysis.symbols is None and previous is not None:
+                # Keep hover types from the last version that type-checked.
+                analysis.symbols = previous.symbols
+            self.analyses[path] = analysis
+        return [self._publish(uri, analysis.diagnostics)]
+
+    def _publish(self, uri: str, diagnostics: list[dict]) -> dict:
+        return {"jsonrpc": "2.0", "method": "textDocument/publishDiagnostics", "params": {"uri": uri, "diagnostics": diagnostics}}
+
+    # Language features.
+
+    def _resolve(self, params: dict) -> tuple[Analysis, str, TerminalNodeImpl | TopLevelSymbol | str] | None:
+        """Resolve the identifier under the cursor to a local binding, declaration, or module id."""
+        analysis = self.analyses.get(uri_to_path(params["textDocument"]["uri"]))
+        if analysis is None:
+            return None
+        line, character = params["position"]["line"], params["position"]["character"]
+        qualifier, word = _word_at(analysis.text, line, character)
+        if word is None:
+            return None
+        if qualifier is None:
+            scope = _enclosing_scope(analysis.tree, line, character)
+            binding = _local_bindings(scope, line, character).get(word) if scope is not None else None
+            if binding is not None:
+                return analysis, word, binding
+        module = analysis.module
+        if module is None:
+            return None
+        path = [qualifier, word] if qualifier else [word]
+        symbol = analysis.graph.resolve_top_level_path(module.module_id, path)
+        if symbol is not None:
+            return analysis, word, symbol
+        alias_target = analysis.graph.resolve_alias(module.module_id, word) if qualifier is None else None
+        return (analysis, word, alias_target) if alias_target is not None else None
+
+    def _module_text(self, analysis: Analysis, module_id: str) -> tuple[Path, str]:
+        path = analysis.graph.path_for_module(module_id).resolve()
+        return path, self.texts.get(path) or path.read_text(encoding="utf-8")
+
+    def _textDocument_definition(self, params: dict):  # noqa: N802
+        resolved = self._resolve(params)
+        if resolved is None:
+            return None, []
+        analysis, _, target = resolved
+        if isinstance(target, TerminalNodeImpl):
+            return {"uri": params["textDocument"]["uri"], "range": _terminal_range(target)}, []
+        if isinstance(target, str):
+            return {"uri": analysis.graph.path_for_module(target).resolve().as_uri(), "range": _range(0, 0, 0)}, []
+        name = _name_terminal(target)
+        location_range = _terminal_range(name) if name is not None else _range(target.ctx.start.line - 1, target.ctx.start.column, target.ctx.start.column)
+        return {"uri": analysis.graph.path_for_module(target.module_id).resolve().as_uri(), "range": location_range}, []
+
+    def _textDocument_hover(self, params: dict):  # noqa: N802
+        resolved = self._resolve(params)
+        if resolved is None:
+            return None, []
+        analysis, word, target = resolved
+        if isinstance(target, TerminalNodeImpl):
+            if analysis.symbols is None:
+                return None, []
+            line = target.symbol.line
+            types = sorted(
+                {
+                    exact_type_to_rust(symbol.exact_type, symbol.resolved_type)
+                    for symbol in analysis.symbols.all_symbols()
+                    if symbol.id == word and symbol.line_num == line and symbol.kind in (SymbolKind.VARIABLE, SymbolKind.PARAMETER)
+                }
+            )
+            if not types:
+                return None, []
+            # Generic functions are specialized per call site; list every instantiation.
+            contents = f"{word}: {' | '.join(types)}"
+        elif isinstance(target, str):
+            contents = f"module {target}"
+        else:
+            _, text = self._module_text(analysis, target.module_id)
+            contents = _declaration_header(target, text)
+        return {"contents": {"kind": "markdown", "value": f"```zinc\n{contents}\n```"}}, []
+
+    def _textDocument_completion(self, params: dict):  # noqa: N802
+        analysis = self.analyses.get(uri_to_path(params["textDocument"]["uri"]))
+        if analysis is None:
+            return [], []
+        line, character = params["position"]["line"], params["position"]["character"]
+        qualifier, partial = _completion_prefix(analysis.text, line, character)
+        module = analysis.module
+        items: dict[str, str] = {}
+        if qualifier is not None:
+            target = analysis.graph.resolve_alias(module.module_id, qualifier) if module is not None else None
+            if target is not None:
+                items.update({name: symbol.kind for name, symbol in analysis.graph.get_module(target).exports.items()})
+        else:
+            items.update({keyword: "keyword" for keyword in KEYWORDS})
+            items.update({name: "function" for name in BUILTIN_FUNCTIONS})
+            if module is not None:
+                items.update({name: symbol.kind for name, symbol in module.symbols.items()})
+                items.update({name: analysis.graph.top_level_symbols[qualified].kind for name, qualified in module.injected_symbols.items()})
+                items.update({alias: "module" for alias in module.alias_imports})
+            scope = _enclosing_scope(analysis.tree, line, character)
+            if scope is not None:
+                items.update({name: "variable" for name in _local_bindings(scope, line, character)})
+        completions = [
+            {"label": name, "kind": COMPLETION_KIND[kind]} for name, kind in sorted(items.items()) if name.startswith(partial)
+        ]
+        return completions, []
+
+
+def serve(reader: BinaryIO, writer: BinaryIO) -> int:
+    """Run the server until `exit`; return the process exit code the protocol expects."""
+    server = LanguageServer()
+    while not server.exited:
+        message = read_message(reader)
+        if message is None:
+            break
+        for outgoing in server.handle(message):
+            write_message(writer, outgoing)
+    return 0 if server.shutdown_requested else 1
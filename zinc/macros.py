@@ -0,0 +1,459 @@
+"""Macros: compile-time templates written in Zinc.
+
+A macro is declared at the top level of a module and used anywhere in that
+module, including before its declaration:
+
+    macro getter(field: name, kind: type) {
+        fn get_$field() -> $kind {
+            return self.$field
+        }
+    }
+
+    struct Point {
+        x: i64
+        getter!(x, i64)
+    }
+
+Expansion works on tokens, before parsing. Each `name!(...)` invocation is
+replaced by the macro's body with every `$param` (or `${param}`) substituted
+by the tokens of its argument, so a macro can produce declarations, members,
+statements, or an expression. A parameter written against identifier text is
+pasted into it, as in `get_$field`, and inside string literals `$param` is
+replaced by the argument's source text.
+
+Parameters may name a kind. A `name` argument must be a single identifier, an
+`expr` argument is substituted in parentheses so operator precedence is kept,
+and commas inside the angle brackets of a `type` argument don't separate
+arguments. Without a kind, the argument's tokens are substituted as written.
+
+Expansion is hygienic for variables: names the body assigns or loops over are
+renamed on every expansion, so they neither clash with nor capture the
+caller's variables. A macro that should assign a caller's variable takes it as
+a parameter. Expanded tokens report their position as the invocation's, and
+tokens that came from an argument keep their own.
+"""
+
+import re
+from collections.abc import Iterator
+from dataclasses import dataclass
+
+from antlr4 import Token
+from zinc.diagnostics import Diagnostic, did_you_mean
+from zinc.exceptions import ZincSyntaxError
+from zinc.parser.zincParser import zincParser as ZincParser
+
+MACRO_KEYWORD = "macro"
+PARAMETER_KINDS = ("name", "expr", "type")
+# Guards against macros that expand to themselves.
+MAX_EXPANSION_DEPTH = 64
+# A cheap test for whether a module may declare macros at all.
+DEFINITION_PATTERN = re.compile(rf"^[ \t]*{MACRO_KEYWORD}\s+\w+\s*\(", re.MULTILINE)
+INTERPOLATION_PATTERN = re.compile(r"\{([^}]+)\}")
+STRING_PARAMETER_PATTERN = re.compile(r"\$\{([A-Za-z_]\w*)\}|\$([A-Za-z_]\w*)")
+
+_OPENERS = {"(": ")", "[": "]", "{": "}", "#[": "]"}
+_ASSIGNMENTS = frozenset({"=", "+=", "-=", "*=", "/=", "%=", "**=", "&=", "|=", "^=", "<<=", ">>="})
+
+
+@dataclass(frozen=True)
+class MacroParameter:
+    """A macro parameter; `kind` is one of PARAMETER_KINDS, or None to substitute tokens as written."""
+
+    name: str
+    kind: str | None = None
+
+
+@dataclass
+class Macro:
+    """One macro declaration; `start` and `stop` are the character offsets of the whole declaration."""
+
+    name: str
+    parameters: list[MacroParameter]
+    body: list[Token]
+    start: int
+    stop: int
+    # Variables the body binds, renamed on every expansion.
+    locals: frozenset[str] = frozenset()
+
+
+def _adjacent(left: Token, right: Token) -> bool:
+    """True when `right` follows `left` in the source with nothing in between."""
+    return right.start == left.stop + 1
+
+
+def _diagnostic(message: str, token: Token, suggestions: tuple[str, ...] = ()) -> Diagnostic:
+    file = getattr(token.getInputStream(), "name", None)
+    return Diagnostic(message, file, token.line, token.column, token.column + len(token.text or " "), suggestions=suggestions)
+
+
+def _closing(tokens: list[Token], index: int) -> int | None:
+    """The index of the bracket closing the one at `index`, or None when it is never closed."""
+    depth = 0
+    for position in range(index, len(tokens)):
+        text = tokens[position].text
+        if text in _OPENERS:
+            depth += 1
+        elif text in (")", "]", "}"):
+            depth -= 1
+            if depth == 0:
+                return position
+    return None
+
+
+def _is_definition(tokens: list[Token], index: int) -> bool:
+    token = tokens[index]
+    return (
+        token.type == ZincParser.IDENTIFIER
+        and token.text == MACRO_KEYWORD
+        and index + 2 < len(tokens)
+        and tokens[index + 1].type == ZincParser.IDENTIFIER
+        and tokens[index + 2].text == "("
+    )
+
+
+def _is_invocation(tokens: list[Token], index: int) -> bool:
+    """`name!(`, with the `!` written directly after the name."""
+    token = tokens[index]
+    return (
+        token.type == ZincParser.IDENTIFIER
+        and index + 2 < len(tokens)
+        and tokens[index + 1].text == "!"
+        # Tokens of an expansion all sit at their invocation, so an invocation inside one counts as adjacent.
+        and (_adjacent(token, tokens[index + 1]) or token.start == tokens[index + 1].start)
+        and tokens[index + 2].text == "("
+        and (index == 0 or tokens[index - 1].text != ".")
+    )
+
+
+def _definitions(tokens: list[Token]) -> Iterator[tuple[int, int | None]]:
+    """(first, last) token indices of each top-level macro declaration; last is None when its body never closes."""
+    depth = 0
+    index = 0
+    while index < len(tokens):
+        text = tokens[index].text
+        if depth == 0 and _is_definition(tokens, index):
+            close_params = _closing(tokens, index + 2)
+            body_open = close_params + 1 if close_params is not None else None
+            if body_open is None or body_open >= len(tokens) or tokens[body_open].text != "{":
+                yield index, None
+                return
+            last = _closing(tokens, body_open)
+            yield index, last
+            if last is None:
+                return
+            index = last + 1
+            continue
+        if text in _OPENERS:
+            depth += 1
+        elif text in (")", "]", "}"):
+            depth -= 1
+        index += 1
+
+
+def find_macros(tokens: list[Token]) -> dict[str, Macro]:
+    """Read the top-level macro declarations from a module's tokens; malformed ones raise ZincSyntaxError."""
+    macros: dict[str, Macro] = {}
+    diagnostics: list[Diagnostic] = []
+    for first, last in _definitions(tokens):
+        keyword, name = tokens[first], tokens[first + 1]
+        if last is None:
+            diagnostics.append(_diagnostic(f"macro '{name.text}' needs a parameter list and a body in braces", name))
+            break
+        close_params = _closing(tokens, first + 2)
+        try:
+            parameters = _parameters(tokens[first + 3 : close_params])
+        except ValueError as exc:
+            diagnostics.append(_diagnostic(f"invalid parameters for macro '{name.text}': {exc}", name))
+            continue
+        if name.text in macros:
+            diagnostics.append(_diagnostic(f"macro '{name.text}' is declared more than once", name))
+            continue
+        macro = Macro(name.text, parameters, tokens[close_params + 2 : last], keyword.start, tokens[last].stop)
+        diagnostics.extend(_check_references(macro))
+        macro.locals = _bound_names(macro.body)
+        macros[macro.name] = macro
+    if diagnostics:
+        raise ZincSyntaxError(f"found {len(diagnostics)} error(s) in macro declarations", diagnostics)
+    return macros
+
+
+def _parameters(tokens: list[Token]) -> list[MacroParameter]:
+    parameters: list[MacroParameter] = []
+    for group in _split(tokens):
+        texts = [token.text for token in group]
+        if len(texts) == 1 and group[0].type == ZincParser.IDENTIFIER:
+            parameter = MacroParameter(texts[0])
+        elif len(texts) == 3 and group[0].type == ZincParser.IDENTIFIER and texts[1] == ":" and texts[2] in PARAMETER_KINDS:
+            parameter = MacroParameter(texts[0], texts[2])
+        else:
+            kinds = ", ".join(PARAMETER_KINDS)
+            raise ValueError(f"expected 'name' or 'name: kind' with a kind of {kinds}, found '{' '.join(texts)}'")
+        if any(existing.name == parameter.name for existing in parameters):
+            raise ValueError(f"'{parameter.name}' is declared twice")
+        parameters.append(parameter)
+    return parameters
+
+
+def _split(tokens: list[Token], types: list[bool] | None = None) -> list[list[Token]]:
+    """Split tokens at top-level commas; a trailing comma is allowed.
+
+    `types` says, for each group in turn, whether it is a type, in which `<`
+    and `>` nest like brackets.
+    """
+    groups: list[list[Token]] = [[]]
+    depth = 0
+    for token in tokens:
+        angles = types is not None and len(groups) <= len(types) and types[len(groups) - 1]
+        text = token.text
+        if text == "," and depth == 0:
+            groups.append([])
+            continue
+        if text in _OPENERS or (angles and text == "<"):
+            depth += 1
+        elif text in (")", "]", "}") or (angles and text == ">"):
+            depth -= 1
+        elif angles and text == ">>":
+            depth -= 2
+        groups[-1].append(token)
+    if not groups[-1]:
+        groups.pop()
+    return groups
+
+
+def _reference(body: list[Token], index: int) -> tuple[str, int] | None:
+    """The parameter named by `$name` or `${name}` at `index`, and the index after it."""
+    if body[index].text != "$" or index + 1 >= len(body) or not _adjacent(body[index], body[index + 1]):
+        return None
+    following = body[index + 1]
+    if following.type == ZincParser.IDENTIFIER:
+        return following.text, index + 2
+    if following.text == "{" and index + 3 < len(body) and body[index + 2].type == ZincParser.IDENTIFIER and body[index + 3].text == "}":
+        return body[index + 2].text, index + 4
+    return None
+
+
+def _check_references(macro: Macro) -> list[Diagnostic]:
+    names = [parameter.name for parameter in macro.parameters]
+    diagnostics = []
+    for index, token in enumerate(macro.body):
+        if (reference := _reference(macro.body, index)) is not None and reference[0] not in names:
+            message = f"macro '{macro.name}' has no parameter '{reference[0]}'"
+            diagnostics.append(_diagnostic(message, macro.body[index + 1], did_you_mean(reference[0], names)))
+        elif token.type == ZincParser.STRING:
+            for match in STRING_PARAMETER_PATTERN.finditer(token.text):
+                if (name := match.group(1) or match.group(2)) not in names:
+                    diagnostics.append(_diagnostic(f"macro '{macro.name}' has no parameter '{name}'", token, did_you_mean(name, names)))
+    return diagnostics
+
+
+def _starts_statement(body: list[Token], index: int) -> bool:
+    if index == 0:
+        return True
+    previous = body[index - 1]
+    return previous.text in ("{", "}") or previous.line < body[index].line
+
+
+def _binding_sites(body: list[Token]) -> set[int]:
+    """Indices of the body tokens that bind a variable: assignment targets and loop variables."""
+    sites: set[int] = set()
+    for index, token in enumerate(body):
+        if token.text == "for":
+            position = index + 1
+            while position < len(body) and body[position].text != "in":
+                if body[position].type == ZincParser.IDENTIFIER and body[position - 1].text != "$":
+                    sites.add(position)
+                position += 1
+        if token.type != ZincParser.IDENTIFIER or not _starts_statement(body, index):
+            continue
+        targets, position = [index], index + 1
+        while position + 1 < len(body) and body[position].text == "," and body[position + 1].type == ZincParser.IDENTIFIER:
+            targets.append(position + 1)
+            position += 2
+        if position >= len(body):
+            continue
+        if body[position].text in _ASSIGNMENTS:
+            sites.update(targets)
+        elif body[position].text == ":" and len(targets) == 1:
+            # `x: T = value`, as opposed to a struct field `x: T`.
+            line = body[position].line
+            rest = (token for token in body[position + 1 :] if token.line == line)
+            if any(token.text == "=" for token in rest):
+                sites.add(index)
+    return sites
+
+
+def _bound_names(body: list[Token]) -> frozenset[str]:
+    return frozenset(body[index].text for index in _binding_sites(body))
+
+
+def _spell(tokens: list[Token]) -> str:
+    """Tokens as source text, with a space wherever the source had one."""
+    pieces = []
+    for index, token in enumerate(tokens):
+        if index and not _adjacent(tokens[index - 1], token):
+            pieces.append(" ")
+        pieces.append(token.text)
+    return "".join(pieces)
+
+
+class MacroExpander:
+    """Expands the macro invocations in one module's tokens."""
+
+    def __init__(self, macros: dict[str, Macro]):
+        self.macros = macros
+        self.diagnostics: list[Diagnostic] = []
+        self._expansions = 0
+
+    def expand(self, tokens: list[Token], depth: int = 0) -> list[Token]:
+        """Return `tokens` with every invocation replaced by its expansion; errors are collected in `diagnostics`."""
+        result: list[Token] = []
+        index = 0
+        while index < len(tokens):
+            if not _is_invocation(tokens, index):
+                result.append(tokens[index])
+                index += 1
+                continue
+            close = _closing(tokens, index + 2)
+            if close is None:
+                self.diagnostics.append(_diagnostic(f"macro invocation '{tokens[index].text}!' is never closed", tokens[index]))
+                return result
+            expansion = self._expand_invocation(tokens[index], tokens[index + 3 : close], tokens[close], depth)
+            result.extend(expansion)
+            index = close + 1
+        return result
+
+    def _expand_invocation(self, name: Token, arguments: list[Token], close: Token, depth: int) -> list[Token]:
+        macro = self.macros.get(name.text)
+        if macro is None:
+            self.diagnostics.append(_diagnostic(f"unknown macro '{name.text}'", name, did_you_mean(name.text, self.macros)))
+            return []
+        if depth >= MAX_EXPANSION_DEPTH:
+            message = f"macro '{macro.name}' expands recursively more than {MAX_EXPANSION_DEPTH} levels deep"
+            self.diagnostics.append(_diagnostic(message, name))
+            return []
+        groups = _split(arguments, [parameter.kind == "type" for parameter in macro.parameters])
+        if len(groups) != len(macro.parameters) or any(not group for group in groups):
+            expected, given = len(macro.parameters), len(groups)
+            message = f"macro '{macro.name}' takes {expected} argument(s), but {given} {'was' if given == 1 else 'were'} given"
+            self.diagnostics.append(_diagnostic(message, name))
+            return []
+        bindings: dict[str, list[Token]] = {}
+        for parameter, group in zip(macro.parameters, groups):
+            if parameter.kind == "name" and (len(group) != 1 or group[0].type != ZincParser.IDENTIFIER):
+                message = f"argument for '{parameter.name}' of macro '{macro.name}' must be a single name, found '{_spell(group)}'"
+                self.diagnostics.append(_diagnostic(message, group[0]))
+                return []
+            if parameter.kind == "expr" and len(group) > 1:
+                group = [_token(group[0], "(", ZincParser.LPAREN), *group, _token(group[-1], ")", ZincParser.RPAREN)]
+            bindings[parameter.name] = group
+        self._expansions += 1
+        renames = {local: f"_{local}_{self._expansions}" for local in macro.locals}
+        body = self._substitute(macro, bindings, renames, name, close)
+        return self.expand(body, depth + 1)
+
+    def _substitute(
+        self, macro: Macro, bindings: dict[str, list[Token]], renames: dict[str, str], name: Token, close: Token
+    ) -> list[Token]:
+        """The body of `macro` with parameters substituted and locals renamed, placed at the invocation."""
+        body = macro.body
+        sites = _binding_sites(body)
+        output: list[Token] = []
+        index = 0
+        while index < len(body):
+            # A run of identifier text and parameters written against each other is pasted into one name.
+            pieces: list[str | list[Token]] = []
+            position = index
+            while position < len(body):
+                if pieces and not _adjacent(body[position - 1], body[position]):
+                    break
+                if (reference := _reference(body, position)) is not None:
+                    pieces.append(bindings[reference[0]])
+                    position = reference[1]
+                elif body[position].type == ZincParser.IDENTIFIER:
+                    pieces.append(body[position].text)
+                    position += 1
+                else:
+                    break
+            if len(pieces) > 1:
+                pasted = ""
+                for piece in pieces:
+                    if isinstance(piece, list) and (len(piece) != 1 or piece[0].type not in (ZincParser.IDENTIFIER, ZincParser.INTEGER)):
+                        message = f"macro '{macro.name}' pastes '{_spell(piece)}' into a name, but only a single name can be pasted"
+                        self.diagnostics.append(_diagnostic(message, name))
+                        return []
+                    pasted += piece if isinstance(piece, str) else piece[0].text
+                output.append(_placed(body[index], name, close, pasted, ZincParser.IDENTIFIER))
+                index = position
+                continue
+            if pieces and isinstance(pieces[0], list):
+                output.extend(token.clone() for token in pieces[0])
+                index = position
+                continue
+            token = body[index]
+            text = token.text
+            if token.type == ZincParser.IDENTIFIER and text in renames and self._is_local_use(body, index, sites):
+                text = renames[text]
+            elif token.type == ZincParser.STRING:
+                text = _substitute_string(text, bindings, renames)
+            output.append(_placed(token, name, close, text, token.type))
+            index += 1
+        return output
+
+    @staticmethod
+    def _is_local_use(body: list[Token], index: int, sites: set[int]) -> bool:
+        """False for field names: after `.`, or before `:` in a struct literal."""
+        if index > 0 and body[index - 1].text == ".":
+            return False
+        return index in sites or index + 1 >= len(body) or body[index + 1].text != ":"
+
+
+def _substitute_string(text: str, bindings: dict[str, list[Token]], renames: dict[str, str]) -> str:
+    """Substitute parameters into a string literal, and renamed locals into its interpolations."""
+
+    def rename(match: re.Match) -> str:
+        expression = re.sub(r"(?<![.\w])(\w+)", lambda name: renames.get(name.group(1), name.group(1)), match.group(1))
+        return "{" + expression + "}"
+
+    if renames:
+        text = INTERPOLATION_PATTERN.sub(rename, text)
+    return STRING_PARAMETER_PATTERN.sub(lambda match: _spell(bindings[match.group(1) or match.group(2)]), text)
+
+
+def _token(like: Token, text: str, token_type: int) -> Token:
+    token = like.clone()
+    token.text = text
+    token.type = token_type
+    return token
+
+
+def _placed(token: Token, name: Token, close: Token, text: str, token_type: int) -> Token:
+    """A copy of a body token that reports the invocation as its position."""
+    placed = _token(token, text, token_type)
+    placed.source = name.source
+    placed.start, placed.stop = name.start, close.stop
+    placed.line, placed.column = name.line, name.column
+    return placed
+
+
+def expand_macros(tokens: list[Token], macros: dict[str, Macro]) -> tuple[list[Token], list[Diagnostic]]:
+    """Expand every macro invocation in a module's tokens, returning the new tokens and any errors."""
+    expander = MacroExpander(macros)
+    return expander.expand(tokens), expander.diagnostics
+
+
+def macro_spans(tokens: list[Token]) -> list[tuple[int, int]]:
+    """(start, stop) character offsets of the macro declarations and outermost invocations in unexpanded tokens."""
+    spans = []
+    declarations = {first: last for first, last in _definitions(tokens) if last is not None}
+    index = 0
+    while index < len(tokens):
+        if index in declarations:
+            spans.append((tokens[index].start, tokens[declarations[index]].stop))
+            index = declarations[index] + 1
+            continue
+        if _is_invocation(tokens, index) and (close := _closing(tokens, index + 2)) is not None:
+            spans.append((tokens[index].start, tokens[close].stop))
+            index = close + 1
+            continue
+        index += 1
+    return spans
@@ -1,80 +1,1028 @@
 """Command-line interface for the Zinc compiler."""
 
+import dataclasses
+import functools
 import json
+import sys
+import tempfile
+from concurrent.futures import ProcessPoolExecutor
+from contextlib import nullcontext
+from itertools import repeat
 from pathlib import Path
 
 import click
-from zinc.atlas import AtlasBuilder
-from zinc.codegen import CodeGenVisitor
-from zinc.modules import build_module_graph
+from zinc.api import CompileError, front_end
+from zinc.bench import build_and_run_benches, run_criterion, write_criterion_project
+from zinc.bench import report as report_benches
+from zinc.build import (
+    DEBUG,
+    RELEASE,
+    BuildProfile,
+    build_binary,
+    copy_binary,
+    crate_name_for,
+    default_build_dir,
+    profile_setting,
+    run_binary,
+    write_cargo_project,
+)
+from zinc.bytecode import BYTECODE_SUFFIX, compile_bytecode, read_bytecode, write_bytecode
+from zinc.cache import CachedCompile, CompileCache, compile_inputs, default_cache_dir
+from zinc.codegen import DEFAULT_OVERFLOW_MODE, OVERFLOW_MODES, CodeGenVisitor, RustProgram
+from zinc.conditional import BuildConfig, build_config
+from zinc.coverage import LCOV_FILE_NAME, CoverageReport
+from zinc.defines import parse_define
+from zinc.fixed_arrays import BOUNDS_MODES, DEFAULT_BOUNDS_MODE
+from zinc.diagnostics import ERROR_FORMATS, Diagnostic, format_diagnostics
+from zinc.docs import document_module, render_html, render_index, render_markdown
+from zinc.emit import EMIT_STAGES, EMIT_SUFFIXES, emit_stage
+from zinc.explain import ERROR_CODES, lookup_code
+from zinc.exceptions import ZincBuildError, ZincError, ZincRustcErrors, ZincSyntaxError, ZincTypeErrors
+from zinc.formatter import collect_sources, format_source
+from zinc.fuzz import fuzz as fuzz_front_end
+from zinc.interpreter import run_interpreted
+from zinc.lints import LintLevels, apply_lint_levels, check_lint_name
+from zinc.lsp import serve
+from zinc.modules import build_module_graph, find_package_root
+from zinc.profiling import ProfilingOptions
+from zinc.project import Project, is_project_path, load_project
+from zinc.repl import ReplSession, run_repl
+from zinc.shadowing import DEFAULT_SHADOWING_MODE, SHADOWING_MODES
+from zinc.sourcemap import SourceMap
 from zinc.struct_logging import configure_logging, get_logger
-from zinc.symbols import SymbolTableVisitor
+from zinc.style import color_option, should_color
+from zinc.targets import HOST, Target, resolve_target
+from zinc.testing import build_and_run_tests, report
+from zinc.timings import Span, Timings, active_timings, record_timings
+from zinc.vm import run_bytecode
+from zinc.watch import watch_and_run
 
 configure_logging()
 logger = get_logger()
 
 
-@click.group()
+# Compiler errors that carry positioned diagnostics.
+DIAGNOSTIC_ERRORS = (ZincSyntaxError, ZincTypeErrors, CompileError, ZincRustcErrors)
+# Context.meta key for the --error-format chosen on the command line.
+ERROR_FORMAT_META = "zinc.error_format"
+# Context.meta key for the lint levels set with -A, -W, and -D.
+LINT_LEVELS_META = "zinc.lint_levels"
+# Context.meta key for the reports asked for with --timings, --timings-json, and --timings-trace.
+TIMINGS_META = "zinc.timings"
+
+
+def _error_format() -> str:
+    """The --error-format of the running command."""
+    ctx = click.get_current_context(silent=True)
+    return ctx.meta.get(ERROR_FORMAT_META, "human") if ctx is not None else "human"
+
+
+def _show_warnings(warnings: list[Diagnostic]) -> None:
+    """Print compiler warnings to stderr in the chosen error format, failing the command if a lint level denies any."""
+    ctx = click.get_current_context(silent=True)
+    shown, denied = apply_lint_levels(warnings, ctx.meta.get(LINT_LEVELS_META) if ctx is not None else None)
+    if shown:
+        click.echo(format_diagnostics(shown, _error_format(), color=should_color(sys.stderr)), err=True)
+    if denied:
+        raise DiagnosticsError(ZincTypeErrors(denied))
+
+
+def _error_diagnostics(error: ZincError, file: Path | None = None) -> list[Diagnostic]:
+    """The diagnostics a compiler error carries, or one naming `file` for errors without a position."""
+    if isinstance(error, DIAGNOSTIC_ERRORS):
+        return error.diagnostics
+    return [Diagnostic(str(error), None if file is None else str(file), code=getattr(error, "code", None))]
+
+
+class DiagnosticsError(click.ClickException):
+    """Compiler errors, printed rustc-style with source excerpts or as JSON lines."""
+
+    def __init__(self, error: ZincError, file: Path | None = None):
+        super().__init__(str(error))
+        self.diagnostics = _error_diagnostics(error, file)
+        self.error_format = _error_format()
+        # Decided now, since click prints the exception after the command's context is gone.
+        self.color = should_color(sys.stderr)
+
+    def show(self, file=None) -> None:
+        """Print the diagnostics, colored as `--color` and `NO_COLOR` say."""
+        click.echo(format_diagnostics(self.diagnostics, self.error_format, color=self.color), file=file, err=file is None, color=self.color)
+
+
+class ZincCommandGroup(click.Group):
+    """Commands that fail with compiler errors print them as diagnostics."""
+
+    def invoke(self, ctx: click.Context):
+        """Run the subcommand, rendering compiler errors it lets escape."""
+        try:
+            return super().invoke(ctx)
+        except ZincError as exc:
+            raise DiagnosticsError(exc) from exc
+
+
+@click.group(cls=ZincCommandGroup)
+@color_option
 @click.version_option(version="0.1.0", prog_name="zinc")
 def main():
     """Zinc - A language that compiles to Rust."""
     pass
 
 
-def _compile_pipeline(file: Path):
-    """Build the module graph, atlas, symbols, and codegen for a file."""
-    module_graph = build_module_graph(file)
-    atlas = AtlasBuilder(module_graph).build()
-    symbol_visitor = SymbolTableVisitor(atlas)
-    symbols = symbol_visitor.resolve()
-    codegen = CodeGenVisitor(
-        atlas,
-        symbols,
-        symbol_visitor.specialization_map,
-        symbol_visitor._channel_infos,
-        symbol_visitor.lexical_functions,
-        symbol_visitor.bound_call_args,
-        symbol_visitor.bound_struct_fields,
-        symbol_visitor.callable_call_specialization_map,
-        symbol_visitor.ufcs_extern_call_map,
-        symbol_visitor.operator_calls,
-    )
-    return module_graph, atlas, symbols, codegen
+def _compile_pipeline(file: Path, sources: dict[Path, str] | None = None, *, warn: bool = True):
+    """Build the module graph, atlas, symbols, and codegen for a file, printing warnings unless `warn` is False."""
+    checked = front_end(file, sources)
+    if warn:
+        _show_warnings(checked.warnings)
+    return checked.graph, checked.atlas, checked.symbols, checked.codegen
+
+
+def _harness_codegen(file: Path, sources: dict[Path, str]) -> CodeGenVisitor:
+    """Compile a generated test or bench harness up to code generation."""
+    return _compile_pipeline(file, sources, warn=False)[3]
+
+
+def compile_program(
+    file: Path,
+    target: Target = HOST,
+    *,
+    warn: bool = True,
+    overflow: str = DEFAULT_OVERFLOW_MODE,
+    keep_unused: bool = False,
+    cache_dir: Path | None = None,
+    debug: bool = True,
+    defines: tuple[tuple[str, str], ...] = (),
+    bounds: str = DEFAULT_BOUNDS_MODE,
+    shadowing: str = DEFAULT_SHADOWING_MODE,
+    profiling: ProfilingOptions | None = None,
+) -> RustProgram:
+    """Compile a Zinc entry file to a structured Rust program.
+
+    `when` blocks are resolved for `target` and the debug or release profile,
+    `defines` set the values of consts, `bounds` chooses how fixed-size
+    arrays are indexed, and `shadowing` whether `=` may change a variable's
+    type. With `profiling`, the program times its functions and reports them
+    on exit. With a `cache_dir`, a program whose sources are unchanged since
+    the last compile there is reused instead of being checked and generated
+    again.
+    """
+    config = BuildConfig.for_target(target, debug=debug, defines=defines, bounds=bounds, shadowing=shadowing)
+    program, warnings = _compile_with_warnings(file, overflow, keep_unused, cache_dir, config, profiling)
+    if warn:
+        _show_warnings(warnings)
+    return dataclasses.replace(program, target=target)
+
+
+def _compile_with_warnings(
+    file: Path, overflow: str, keep_unused: bool, cache_dir: Path | None, config: BuildConfig, profiling: ProfilingOptions | None = None
+) -> tuple[RustProgram, list[Diagnostic]]:
+    """Compile an entry file, returning its warnings instead of printing them."""
+    cache = CompileCache(cache_dir) if cache_dir is not None else None
+    options = (overflow, keep_unused, config, profiling)
+    cached = cache.load(file, options) if cache is not None else None
+    if cached is not None:
+        return cached.program, cached.warnings
+
+    with build_config(config):
+        checked = front_end(file)
+    program = checked.codegen.generate(overflow, keep_unused=keep_unused, profiling=profiling)
+    if cache is not None:
+        cache.store(file, options, CachedCompile(compile_inputs(checked.graph, checked.passes.plugin_files), program, checked.warnings))
+    return program, checked.warnings
+
+
+def _compile_in_worker(
+    file: Path, overflow: str, keep_unused: bool, cached: bool, config: BuildConfig, profiling: ProfilingOptions | None, timed: bool
+) -> tuple[RustProgram | None, list[Diagnostic], list[Diagnostic], list[Span]]:
+    """Compile one entry file in a worker process; errors come back as diagnostics, since they can't all be pickled.
+
+    With `timed`, the spans of the compiler phases come back too.
+    """
+    timings = Timings()
+    with record_timings(timings) if timed else nullcontext():
+        try:
+            cache_dir = default_cache_dir(file) if cached else None
+            program, warnings = _compile_with_warnings(file, overflow, keep_unused, cache_dir, config, profiling)
+        except ZincError as exc:
+            return None, [], _error_diagnostics(exc, file), timings.spans
+    return program, warnings, [], timings.spans
+
+
+def compile_programs(
+    files: list[Path],
+    target: Target = HOST,
+    *,
+    jobs: int | None = None,
+    overflow: str = DEFAULT_OVERFLOW_MODE,
+    keep_unused: bool = False,
+    cached: bool = False,
+    debug: bool = True,
+    defines: tuple[tuple[str, str], ...] = (),
+    bounds: str = DEFAULT_BOUNDS_MODE,
+    shadowing: str = DEFAULT_SHADOWING_MODE,
+    profiling: ProfilingOptions | None = None,
+) -> list[RustProgram]:
+    """Compile several entry files at once, one process each, up to `jobs` at a time (default: one per CPU).
+
+    Type checking covers a whole program, so separate entry files are the unit
+    that can be compiled independently. Warnings are printed in the order of
+    `files`, and the first file with errors raises them.
+    """
+    if len(files) == 1 or jobs == 1:
+        return [
+            compile_program(
+                file,
+                target,
+                overflow=overflow,
+                keep_unused=keep_unused,
+                cache_dir=default_cache_dir(file) if cached else None,
+                debug=debug,
+                defines=defines,
+                bounds=bounds,
+                shadowing=shadowing,
+                profiling=profiling,
+            )
+            for file in files
+        ]
+    config = BuildConfig.for_target(target, debug=debug, defines=defines, bounds=bounds, shadowing=shadowing)
+    timings = active_timings()
+    with ProcessPoolExecutor(max_workers=jobs) as pool:
+        results = list(
+            pool.map(
+                _compile_in_worker,
+                files,
+                repeat(overflow),
+                repeat(keep_unused),
+                repeat(cached),
+                repeat(config),
+                repeat(profiling),
+                repeat(timings is not None),
+            )
+        )
+    programs = []
+    for program, warnings, errors, spans in results:
+        if timings is not None:
+            timings.spans.extend(spans)
+        _show_warnings(warnings)
+        if errors:
+            raise DiagnosticsError(CompileError(errors))
+        programs.append(dataclasses.replace(program, target=target))
+    return programs
+
+
+def _set_error_format(ctx: click.Context, param: click.Parameter, value: str | None) -> None:
+    if value is not None:
+        ctx.meta[ERROR_FORMAT_META] = value
+
+
+error_format_option = click.option(
+    "--error-format",
+    type=click.Choice(ERROR_FORMATS),
+    callback=_set_error_format,
+    expose_value=False,
+    is_eager=True,
+    help="How to print compile errors: human (default) or json (one object per line on stderr)",
+)
+
+
+def _set_lint_level(ctx: click.Context, param: click.Parameter, value: tuple[str, ...]) -> None:
+    levels = ctx.meta.setdefault(LINT_LEVELS_META, LintLevels())
+    for name in value:
+        try:
+            levels.set(check_lint_name(name), param.name)
+        except ValueError as exc:
+            raise click.BadParameter(str(exc), ctx, param) from exc
+
+
+def diagnostic_options(command):
+    """Add --error-format, --color, and the -A/-W/-D lint level flags to a command that compiles Zinc."""
+    for flag, level, help_text in (
+        ("-D", "deny", "Turn a lint like unused-variable, or `warnings` for all of them, into errors"),
+        ("-W", "warn", "Report a lint like unused-variable, or `warnings` for all of them, as warnings"),
+        ("-A", "allow", "Silence a lint like unused-variable, or `warnings` for all of them"),
+    ):
+        command = click.option(
+            flag,
+            f"--{level}",
+            level,
+            multiple=True,
+            metavar="LINT",
+            callback=_set_lint_level,
+            expose_value=False,
+            is_eager=True,
+            help=help_text,
+        )(command)
+    return color_option(error_format_option(command))
+
+
+def _resolve_target_option(ctx: click.Context, param: click.Parameter, value: str | None) -> Target:
+    try:
+        return resolve_target(value)
+    except ZincBuildError as exc:
+        raise click.BadParameter(str(exc)) from exc
+
+
+fmt_option = click.option("--no-fmt", "no_fmt", is_flag=True, help="Write the generated Rust without running rustfmt on it")
+
+overflow_option = click.option(
+    "--overflow",
+    type=click.Choice(OVERFLOW_MODES),
+    default=DEFAULT_OVERFLOW_MODE,
+    show_default=True,
+    help="Integer overflow: panic (checked), wrap around (wrapping), or clamp to the type's bounds (saturating)",
+)
+
+jobs_option = click.option(
+    "-j", "--jobs", type=click.IntRange(min=1), help="Entry files or binaries to compile at once (default: one per CPU)"
+)
+
+target_option = click.option(
+    "--target",
+    callback=_resolve_target_option,
+    help="Target to compile for: native (default), wasm (WASI), wasm-browser, or a Rust target triple",
+)
+
+
+def _resolve_define_option(ctx: click.Context, param: click.Parameter, value: tuple[str, ...]) -> tuple[tuple[str, str], ...]:
+    try:
+        return tuple(parse_define(define) for define in value)
+    except ValueError as exc:
+        raise click.BadParameter(str(exc)) from exc
+
+
+define_option = click.option(
+    "--define",
+    "defines",
+    multiple=True,
+    metavar="NAME=VALUE",
+    callback=_resolve_define_option,
+    help="Set a top-level const to VALUE; NAME is a const of the entry file, or module.NAME (repeatable)",
+)
+
+bounds_option = click.option(
+    "--bounds",
+    type=click.Choice(BOUNDS_MODES),
+    default=DEFAULT_BOUNDS_MODE,
+    show_default=True,
+    help="Out-of-range fixed-size array reads: panic, or give a Result (error)",
+)
+
+shadowing_option = click.option(
+    "--shadowing",
+    type=click.Choice(SHADOWING_MODES),
+    default=DEFAULT_SHADOWING_MODE,
+    show_default=True,
+    help="Whether `=` may give a variable a new type (allow), or only `let` may (strict)",
+)
+
+
+def _resolve_profile_option(ctx: click.Context, param: click.Parameter, value: str | None) -> bool | int | str | None:
+    if value is None:
+        return None
+    try:
+        return profile_setting(param.name.replace("_", "-"), value)
+    except ZincBuildError as exc:
+        raise click.BadParameter(str(exc)) from exc
+
+
+def profile_options(command):
+    """Add the Cargo profile overrides shared by build and run."""
+    command = click.option("--panic", callback=_resolve_profile_option, help="Panic strategy: unwind or abort")(command)
+    command = click.option("--lto", callback=_resolve_profile_option, help="Link-time optimization: true, false, thin, fat, or off")(command)
+    return click.option("--opt-level", callback=_resolve_profile_option, help="Optimization level: 0-3, s, or z")(command)
+
+
+def profiling_options(command):
+    """Add the --profile instrumentation options shared by build and run."""
+    command = click.option(
+        "--profile-json",
+        type=click.Path(dir_okay=False, path_type=Path),
+        help="Write the --profile report to this JSON file instead of stderr (implies --profile)",
+    )(command)
+    return click.option(
+        "--profile",
+        "profile_calls",
+        is_flag=True,
+        help="Time every function call and print the hottest functions when the program exits",
+    )(command)
+
+
+def _record_timings(ctx: click.Context, param: click.Parameter, value: bool | Path | None) -> None:
+    if not value:
+        return
+    reports = ctx.meta.get(TIMINGS_META)
+    if reports is None:
+        timings = ctx.with_resource(record_timings())
+        reports = ctx.meta[TIMINGS_META] = {}
+        ctx.call_on_close(functools.partial(_report_timings, timings, reports))
+    reports[param.name] = value
+
+
+def _report_timings(timings: Timings, reports: dict[str, bool | Path]) -> None:
+    """Print or write the timings of the compiler phases once the command is done, even if it failed."""
+    if reports.get("timings"):
+        click.echo(timings.render(), err=True)
+    for name, render in (("timings_json", timings.to_json), ("timings_trace", timings.to_chrome_trace)):
+        if name in reports:
+            reports[name].write_text(render())
+
+
+def timings_options(command):
+    """Add --timings and its JSON and trace outputs to a command that compiles Zinc."""
+    for name, help_text in (
+        ("--timings-trace", "Write the --timings spans to this file as a Chrome trace, for chrome://tracing or Perfetto"),
+        ("--timings-json", "Write the --timings report to this JSON file instead of stderr"),
+    ):
+        command = click.option(
+            name, type=click.Path(dir_okay=False, path_type=Path), callback=_record_timings, expose_value=False, help=help_text
+        )(command)
+    return click.option(
+        "--timings",
+        is_flag=True,
+        callback=_record_timings,
+        expose_value=False,
+        help="Print how long lexing, parsing, type checking, code generation, rustfmt, and cargo took",
+    )(command)
+
+
+def _profiling(profile_calls: bool, profile_json: Path | None) -> ProfilingOptions | None:
+    if profile_json is not None:
+        return ProfilingOptions(json_path=profile_json.absolute())
+    return ProfilingOptions() if profile_calls else None
+
+
+def _override_profile(profile: BuildProfile, opt_level, lto, panic) -> BuildProfile:
+    overrides = {"opt_level": opt_level, "lto": lto, "panic": panic}
+    return dataclasses.replace(profile, **{key: value for key, value in overrides.items() if value is not None})
+
+
+def _write_output(text: str, output: Path | None) -> None:
+    """Write generated text to a file, creating its directory, or to stdout when there is no path."""
+    if output is None:
+        click.echo(text)
+        return
+    output.parent.mkdir(parents=True, exist_ok=True)
+    output.write_text(text + "\n")
 
 
 @main.command()
-@click.argument("file", type=click.Path(exists=True, path_type=Path))
-@click.option("-o", "--output", type=click.Path(path_type=Path), help="Output file path")
-def compile(file: Path, output: Path | None):
-    """Compile a Zinc source file to Rust."""
-    _, _, _, codegen = _compile_pipeline(file)
-    program = codegen.generate()
-    rust_code = program.render()
+@click.argument("files", nargs=-1, required=True, type=click.Path(exists=True, path_type=Path))
+@click.option("-o", "--output", type=click.Path(path_type=Path), help="Output file path for a single FILE")
+@click.option("--out-dir", type=click.Path(file_okay=False, path_type=Path), help="Write each FILE's output to DIR/<stem>.rs")
+@click.option("--stdout", "to_stdout", is_flag=True, help="Write to stdout (the default without -o or --out-dir)")
+@click.option(
+    "--cargo",
+    "cargo_dir",
+    type=click.Path(file_okay=False, path_type=Path),
+    help="Write a complete Cargo project (Cargo.toml and src/main.rs) for a single FILE to DIR",
+)
+@click.option("--source-map", type=click.Path(path_type=Path), help="Also write a JSON map from Rust lines to Zinc source lines")
+@click.option("--emit", type=click.Choice(EMIT_STAGES), default="rust", show_default=True, help="Compilation stage to output")
+@click.option("--json", "as_json", is_flag=True, help="Output the stage as JSON")
+@click.option("--keep-unused", is_flag=True, help="Emit every method of the emitted types, even ones the program never calls")
+@jobs_option
+@fmt_option
+@overflow_option
+@target_option
+@define_option
+@bounds_option
+@shadowing_option
+@timings_options
+@diagnostic_options
+def compile(
+    files: tuple[Path, ...],
+    output: Path | None,
+    out_dir: Path | None,
+    to_stdout: bool,
+    cargo_dir: Path | None,
+    source_map: Path | None,
+    emit: str,
+    as_json: bool,
+    keep_unused: bool,
+    jobs: int | None,
+    no_fmt: bool,
+    overflow: str,
+    target: Target,
+    defines: tuple[tuple[str, str], ...],
+    bounds: str,
+    shadowing: str,
+):
+    """Compile Zinc source files to Rust, or dump an intermediate stage with --emit."""
+    if sum((output is not None, out_dir is not None, to_stdout, cargo_dir is not None)) > 1:
+        raise click.UsageError("choose one of -o, --out-dir, --stdout, and --cargo")
+    if cargo_dir is not None:
+        if len(files) > 1:
+            raise click.UsageError("--cargo applies to a single FILE")
+        if emit != "rust" or as_json or source_map:
+            raise click.UsageError("--cargo writes plain Rust and its own source map")
+        program = compile_program(
+            files[0], target, overflow=overflow, keep_unused=keep_unused, defines=defines, bounds=bounds, shadowing=shadowing
+        )
+        write_cargo_project(program, crate_name_for(files[0]), cargo_dir, rustfmt=not no_fmt)
+        logger.info(f"Wrote Cargo project for {files[0]} to {cargo_dir}")
+        return
+    if len(files) > 1 and out_dir is None:
+        raise click.UsageError("compiling several files needs --out-dir")
+    if source_map and len(files) > 1:
+        raise click.UsageError("--source-map applies to a single FILE")
+    if source_map and (emit != "rust" or as_json):
+        raise click.UsageError("--source-map applies to plain Rust output")
+
+    suffix = ".json" if as_json else EMIT_SUFFIXES[emit]
+    if emit == "rust":
+        programs = compile_programs(
+            list(files),
+            target,
+            jobs=jobs,
+            overflow=overflow,
+            keep_unused=keep_unused,
+            defines=defines,
+            bounds=bounds,
+            shadowing=shadowing,
+        )
+    else:
+        programs = [None] * len(files)
+    with build_config(BuildConfig.for_target(target, defines=defines, bounds=bounds, shadowing=shadowing)):
+        for file, program in zip(files, programs):
+            destination = out_dir / f"{file.stem}{suffix}" if out_dir is not None else output
+            _compile_file(file, program, destination, source_map, emit, as_json, rustfmt=not no_fmt)
+
+
+def _compile_file(
+    file: Path,
+    program: RustProgram | None,
+    output: Path | None,
+    source_map: Path | None,
+    emit: str,
+    as_json: bool,
+    *,
+    rustfmt: bool = True,
+) -> None:
+    """Write one entry file's output for the `compile` command; `program` is its compiled Rust when emitting Rust."""
+    if emit != "rust" or as_json:
+        _write_output(emit_stage(emit, file, as_json, program=program, rustfmt=rustfmt), output)
+        return
+
+    rust_code, line_map = program.render_with_source_map(rustfmt=rustfmt)
+    if source_map:
+        source_map.write_text(line_map.to_json())
     if program.runtime_features:
         features = ", ".join(f'"{feature}"' for feature in sorted(program.runtime_features))
         click.echo(
             f"zinc-internal runtime required: default-features = false, features = [{features}]",
             err=True,
         )
+    for name, requirement in sorted(program.crates.items()):
+        click.echo(f"crate dependency required: {name} = {requirement}", err=True)
 
+    _write_output(rust_code, output)
     if output:
-        output.write_text(rust_code)
         logger.info(f"Compiled {file} to {output}")
-    else:
-        click.echo(rust_code)
+
+
+def build_project(
+    project: Project,
+    bin_name: str | None,
+    profile: BuildProfile,
+    target: Target = HOST,
+    *,
+    rustfmt: bool = True,
+    overflow: str = DEFAULT_OVERFLOW_MODE,
+    jobs: int | None = None,
+    defines: tuple[tuple[str, str], ...] = (),
+    bounds: str = DEFAULT_BOUNDS_MODE,
+    shadowing: str = DEFAULT_SHADOWING_MODE,
+    profiling: ProfilingOptions | None = None,
+) -> None:
+    """Build a project's binaries into its output directory and check its library."""
+    if project.lib is not None and bin_name is None:
+        build_module_graph(project.lib, require_main=False)
+        logger.info(f"Checked library {project.lib}")
+    bins = [project.bin(bin_name)] if bin_name is not None else project.bins
+    paths = [bin_target.path for bin_target in bins]
+    programs = compile_programs(
+        paths,
+        target,
+        jobs=jobs,
+        overflow=overflow,
+        cached=True,
+        debug=not profile.release,
+        defines=defines,
+        bounds=bounds,
+        shadowing=shadowing,
+        profiling=profiling,
+    )
+    for bin_target, program in zip(bins, programs):
+        binary = build_binary(program, bin_target.path, build_dir=project.build_dir(bin_target), profile=profile, rustfmt=rustfmt)
+        output = copy_binary(binary, project.out_dir / f"{bin_target.name}{target.artifact_suffix}")
+        logger.info(f"Built {bin_target.name} to {output}")
+
+
+@main.command()
+@click.argument("file", type=click.Path(exists=True, path_type=Path))
+@click.option("-o", "--output", type=click.Path(path_type=Path), help="Binary path (default: ./<file stem>, or ./<file stem>.znb)")
+@click.option("--build-dir", type=click.Path(path_type=Path), help="Cargo project directory (default: .zinc-build/<name> next to FILE)")
+@click.option("--release/--debug", default=None, help="Build with or without optimizations (default: release, or [build] release for projects)")
+@profile_options
+@profiling_options
+@click.option("--bin", "bin_name", help="Build only this binary of a project")
+@click.option(
+    "--emit",
+    type=click.Choice(["binary", "bytecode"]),
+    default="binary",
+    show_default=True,
+    help="Build a native binary with Cargo, or bytecode for `zinc exec`",
+)
+@jobs_option
+@fmt_option
+@overflow_option
+@target_option
+@define_option
+@bounds_option
+@shadowing_option
+@timings_options
+@diagnostic_options
+def build(
+    file: Path,
+    output: Path | None,
+    build_dir: Path | None,
+    release: bool | None,
+    opt_level: int | str | None,
+    lto: bool | str | None,
+    panic: str | None,
+    profile_calls: bool,
+    profile_json: Path | None,
+    bin_name: str | None,
+    emit: str,
+    jobs: int | None,
+    no_fmt: bool,
+    overflow: str,
+    target: Target,
+    defines: tuple[tuple[str, str], ...],
+    bounds: str,
+    shadowing: str,
+):
+    """Compile a Zinc source file, or every target of a project directory, to native binaries using Cargo."""
+    profiling = _profiling(profile_calls, profile_json)
+    if emit == "bytecode" and profiling is not None:
+        raise click.UsageError("--profile instruments native builds; build without --emit bytecode")
+    if is_project_path(file):
+        if emit == "bytecode":
+            raise click.UsageError("--emit bytecode builds single files")
+        if output or build_dir:
+            raise click.UsageError("-o and --build-dir apply to single files; set [build] out-dir in zinc.toml for projects")
+        try:
+            project = load_project(file)
+            profile = _override_profile(project.profile(release), opt_level, lto, panic)
+            build_project(
+                project,
+                bin_name,
+                profile,
+                target,
+                rustfmt=not no_fmt,
+                overflow=overflow,
+                jobs=jobs,
+                defines=defines,
+                bounds=bounds,
+                shadowing=shadowing,
+                profiling=profiling,
+            )
+        except ZincError as exc:
+            raise DiagnosticsError(exc) from exc
+        return
+
+    profile = _override_profile(DEBUG if release is False else RELEASE, opt_level, lto, panic)
+    if emit == "bytecode":
+        with build_config(BuildConfig.for_target(target, debug=not profile.release, defines=defines, bounds=bounds, shadowing=shadowing)):
+            checked = front_end(file)
+        _show_warnings(checked.warnings)
+        output = write_bytecode(compile_bytecode(checked, overflow=overflow), output or Path(f"{crate_name_for(file)}{BYTECODE_SUFFIX}"))
+        logger.info(f"Built {file} to {output}")
+        return
+    program = compile_program(
+        file,
+        target,
+        overflow=overflow,
+        cache_dir=default_cache_dir(file),
+        debug=not profile.release,
+        defines=defines,
+        bounds=bounds,
+        shadowing=shadowing,
+        profiling=profiling,
+    )
+    try:
+        binary = build_binary(program, file, build_dir=build_dir, profile=profile, rustfmt=not no_fmt)
+    except ZincRustcErrors as exc:
+        raise DiagnosticsError(exc) from exc
+    except ZincBuildError as exc:
+        raise click.ClickException(str(exc)) from exc
+    output = copy_binary(binary, output or Path(f"{crate_name_for(file)}{target.artifact_suffix}"))
+    logger.info(f"Built {file} to {output}")
+
+
+@main.command(context_settings={"ignore_unknown_options": True})
+@click.argument("file", type=click.Path(exists=True, path_type=Path))
+@click.argument("args", nargs=-1, type=click.UNPROCESSED)
+@click.option("--build-dir", type=click.Path(path_type=Path), help="Cargo project directory (default: .zinc-build/<name> next to FILE)")
+@click.option("--release/--debug", default=False, help="Build with or without optimizations before running (default: debug)")
+@profile_options
+@profiling_options
+@click.option("--watch", is_flag=True, help="Rebuild and restart the program whenever a source file changes")
+@click.option("--interpret", is_flag=True, help="Run the program in the interpreter instead of building it with Cargo")
+@click.option("--bin", "bin_name", help="Binary to run when FILE is a project with several")
+@fmt_option
+@overflow_option
+@target_option
+@define_option
+@bounds_option
+@shadowing_option
+@timings_options
+@diagnostic_options
+def run(
+    file: Path,
+    args: tuple[str, ...],
+    build_dir: Path | None,
+    release: bool,
+    opt_level: int | str | None,
+    lto: bool | str | None,
+    panic: str | None,
+    profile_calls: bool,
+    profile_json: Path | None,
+    watch: bool,
+    interpret: bool,
+    bin_name: str | None,
+    no_fmt: bool,
+    overflow: str,
+    target: Target,
+    defines: tuple[tuple[str, str], ...],
+    bounds: str,
+    shadowing: str,
+):
+    """Compile and run a Zinc source file or project binary, passing ARGS (after --) to the program."""
+    profile = RELEASE if release else DEBUG
+    if is_project_path(file):
+        try:
+            project = load_project(file)
+            bin_target = project.bin(bin_name)
+        except ZincError as exc:
+            raise DiagnosticsError(exc) from exc
+        file, build_dir, profile = bin_target.path, build_dir or project.build_dir(bin_target), project.profile(release)
+    profile = _override_profile(profile, opt_level, lto, panic)
+    profiling = _profiling(profile_calls, profile_json)
+
+    if target.is_cross:
+        raise click.UsageError(f"can't run {target.triple} binaries on this machine; use `zinc build --target {target.triple}` instead")
+    if interpret:
+        if watch:
+            raise click.UsageError("--interpret and --watch can't be combined")
+        if profiling is not None:
+            raise click.UsageError("--profile instruments native builds; run without --interpret")
+        with build_config(BuildConfig.for_target(target, debug=not profile.release, defines=defines, bounds=bounds, shadowing=shadowing)):
+            checked = front_end(file)
+        _show_warnings(checked.warnings)
+        raise SystemExit(run_interpreted(checked, overflow=overflow))
+    if watch:
+        if target != HOST:
+            raise click.UsageError("--watch runs native builds only")
+
+        def rebuild() -> Path | None:
+            try:
+                program = compile_program(
+                    file,
+                    overflow=overflow,
+                    cache_dir=default_cache_dir(file),
+                    debug=not profile.release,
+                    defines=defines,
+                    bounds=bounds,
+                    shadowing=shadowing,
+                    profiling=profiling,
+                )
+                return build_binary(program, file, build_dir=build_dir, profile=profile, rustfmt=not no_fmt)
+            except DIAGNOSTIC_ERRORS as exc:
+                DiagnosticsError(exc).show()
+                return None
+            except ZincError as exc:
+                click.echo(f"error: {exc}", err=True)
+                return None
+
+        watch_and_run(find_package_root(file), rebuild, args, notify=lambda message: click.echo(message, err=True))
+        return
+
+    program = compile_program(
+        file,
+        target,
+        overflow=overflow,
+        cache_dir=default_cache_dir(file),
+        debug=not profile.release,
+        defines=defines,
+        bounds=bounds,
+        shadowing=shadowing,
+        profiling=profiling,
+    )
+    try:
+        binary = build_binary(program, file, build_dir=build_dir, profile=profile, rustfmt=not no_fmt)
+        status = run_binary(binary, args, target, SourceMap.load(build_dir or default_build_dir(file)))
+    except ZincRustcErrors as exc:
+        raise DiagnosticsError(exc) from exc
+    except ZincBuildError as exc:
+        raise click.ClickException(str(exc)) from exc
+    raise SystemExit(status)
+
+
+@main.command()
+@click.argument("file", type=click.Path(exists=True, dir_okay=False, path_type=Path))
+def exec(file: Path):
+    """Run a program built with `zinc build --emit bytecode`."""
+    raise SystemExit(run_bytecode(read_bytecode(file)))
+
+
+@main.command()
+def repl():
+    """Start an interactive Zinc session."""
+    with tempfile.TemporaryDirectory(prefix="zinc-repl-") as workdir:
+        # Earlier inputs stay in the program, so its unused bindings are expected.
+        session = ReplSession(Path(workdir), compile_program=functools.partial(compile_program, warn=False))
+        run_repl(session)
+
+
+@main.command()
+@click.argument("paths", nargs=-1, required=True, type=click.Path(exists=True, path_type=Path))
+@click.option("--coverage", is_flag=True, help="Count the statements and branches the tests run and write lcov and HTML reports")
+@click.option(
+    "--coverage-dir",
+    type=click.Path(file_okay=False, path_type=Path),
+    default=Path("coverage"),
+    show_default=True,
+    help="Where --coverage writes lcov.info and index.html",
+)
+@diagnostic_options
+def test(paths: tuple[Path, ...], coverage: bool, coverage_dir: Path):
+    """Build and run the @test functions in Zinc source files; directories are searched for .zn files."""
+    results = {}
+    coverage_report = CoverageReport() if coverage else None
+    for source in collect_sources(list(paths)):
+        try:
+            file_results = build_and_run_tests(source, _harness_codegen, coverage_report)
+        except ZincError as exc:
+            raise DiagnosticsError(exc, source) from exc
+        if file_results:
+            results[source] = file_results
+    passed = report(results, write=click.echo)
+    if coverage_report is not None:
+        index = coverage_report.write(coverage_dir)
+        click.echo(f"\n{coverage_report.summary()}")
+        click.echo(f"wrote {coverage_dir / LCOV_FILE_NAME} and {index}")
+    if not passed:
+        raise SystemExit(1)
+
+
+@main.command()
+@click.argument("paths", nargs=-1, required=True, type=click.Path(exists=True, path_type=Path))
+@click.option("--warmup", type=click.IntRange(min=0), default=10, show_default=True, help="Untimed calls before measuring")
+@click.option("--iterations", type=click.IntRange(min=1), default=100, show_default=True, help="Timed calls per benchmark")
+@click.option("--criterion", is_flag=True, help="Write a criterion bench target and run it with cargo bench instead")
+@diagnostic_options
+def bench(paths: tuple[Path, ...], warmup: int, iterations: int, criterion: bool):
+    """Build and run the @bench functions in Zinc source files; directories are searched for .zn files."""
+    results = {}
+    for source in collect_sources(list(paths)):
+        try:
+            if criterion:
+                project_dir = write_criterion_project(source, _harness_codegen)
+                if project_dir is not None and (code := run_criterion(project_dir)) != 0:
+                    raise SystemExit(code)
+                continue
+            file_results = build_and_run_benches(source, _harness_codegen, warmup, iterations)
+        except ZincError as exc:
+            raise DiagnosticsError(exc, source) from exc
+        if file_results:
+            results[source] = file_results
+    if not criterion and not report_benches(results, write=click.echo):
+        raise SystemExit(1)
+
+
+def _module_name(source: Path, paths: tuple[Path, ...]) -> str:
+    """A source's module name: its path below the directory argument that found it, or its stem for a file argument."""
+    for path in paths:
+        if path.is_dir() and source.is_relative_to(path):
+            return source.relative_to(path).with_suffix("").as_posix()
+    return source.stem
+
+
+@main.command()
+@click.argument("paths", nargs=-1, required=True, type=click.Path(exists=True, path_type=Path))
+@click.option(
+    "--format", "doc_format", type=click.Choice(["markdown", "html"]), default="markdown", show_default=True, help="Page format"
+)
+@click.option("--out-dir", type=click.Path(file_okay=False, path_type=Path), help="Write one page per module and an index to DIR")
+@diagnostic_options
+def doc(paths: tuple[Path, ...], doc_format: str, out_dir: Path | None):
+    """Render documentation for the public declarations in Zinc source files; directories are searched for .zn files."""
+    render = render_markdown if doc_format == "markdown" else render_html
+    suffix = ".md" if doc_format == "markdown" else ".html"
+    modules = []
+    for source in collect_sources(list(paths)):
+        try:
+            module = document_module(source.read_text(encoding="utf-8"), _module_name(source, paths), str(source))
+        except ZincError as exc:
+            raise DiagnosticsError(exc, source) from exc
+        modules.append(module)
+        if out_dir is None:
+            click.echo(render(module))
+        else:
+            page = out_dir / f"{module.name}{suffix}"
+            page.parent.mkdir(parents=True, exist_ok=True)
+            page.write_text(render(module), encoding="utf-8")
+    if out_dir is not None:
+        out_dir.mkdir(parents=True, exist_ok=True)
+        (out_dir / f"index{suffix}").write_text(render_index(modules, doc_format), encoding="utf-8")
+        click.echo(f"documented {len(modules)} module(s) in {out_dir}")
+
+
+@main.command()
+def lsp():
+    """Run the Zinc language server over stdin/stdout."""
+    protocol_out = sys.stdout.buffer
+    # Anything printed by the compiler must not corrupt the protocol stream.
+    sys.stdout = sys.stderr
+    raise SystemExit(serve(sys.stdin.buffer, protocol_out))
+
+
+@main.command()
+@click.argument("paths", nargs=-1, required=True, type=click.Path(exists=True, path_type=Path))
+@click.option("--check", is_flag=True, help="Report unformatted files and exit with status 1 instead of rewriting them")
+def fmt(paths: tuple[Path, ...], check: bool):
+    """Format Zinc source files in place; directories are searched for .zn files."""
+    unformatted = []
+    for source in collect_sources(list(paths)):
+        original = source.read_text(encoding="utf-8")
+        try:
+            formatted = format_source(original, str(source))
+        except ZincError as exc:
+            raise click.ClickException(str(exc)) from exc
+        if formatted == original:
+            continue
+        unformatted.append(source)
+        if check:
+            click.echo(f"would reformat {source}")
+        else:
+            source.write_text(formatted, encoding="utf-8")
+            click.echo(f"reformatted {source}")
+    if check and unformatted:
+        raise SystemExit(1)
+
+
+@main.command()
+@click.argument("code", required=False)
+def explain(code: str | None):
+    """Explain an error code like Z0012 at length, with an example and a fix; without CODE, list every code."""
+    if code is None:
+        for entry in ERROR_CODES.values():
+            click.echo(f"{entry.code}  {entry.title}")
+        return
+    entry = lookup_code(code)
+    if entry is None:
+        raise click.UsageError(f"no error code {code!r}; run `zinc explain` to list them")
+    click.echo(entry.render())
+
+
+@main.command()
+@click.argument("paths", nargs=-1, required=True, type=click.Path(exists=True, path_type=Path))
+@click.option("--iterations", type=click.IntRange(min=1), default=1000, show_default=True, help="Mutated inputs to check")
+@click.option("--seed", type=int, default=0, show_default=True, help="Random seed; the same seed checks the same inputs")
+@click.option(
+    "--out-dir",
+    type=click.Path(file_okay=False, path_type=Path),
+    default=Path("fuzz-crashes"),
+    show_default=True,
+    help="Where to write the inputs that crash the compiler",
+)
+def fuzz(paths: tuple[Path, ...], iterations: int, seed: int, out_dir: Path):
+    """Check mutations of Zinc source files for compiler crashes; directories are searched for .zn files."""
+    seeds = [source.read_text(encoding="utf-8") for source in collect_sources(list(paths))]
+    if not seeds:
+        raise click.UsageError("no .zn files to start from")
+    crashes = fuzz_front_end(seeds, iterations, seed)
+    for number, crash in enumerate(crashes, start=1):
+        out_dir.mkdir(parents=True, exist_ok=True)
+        path = out_dir / f"crash-{number}.zn"
+        path.write_text(crash.text, encoding="utf-8")
+        click.echo(f"crash: {crash.signature}\n  {crash.error}\n  input: {path}")
+    click.echo(f"checked {iterations} inputs from {len(seeds)} seed(s): {len(crashes)} crash(es)")
+    if crashes:
+        raise SystemExit(1)
 
 
 @main.command()
 @click.argument("file", type=click.Path(exists=True, path_type=Path))
 def tree(file: Path):
-    """Print the AST of a Zinc source file."""
-    _, _, _, codegen = _compile_pipeline(file)
-    program = codegen.generate()
-    click.echo(program)
+    """Print the parse tree of a Zinc source file; the same as `compile --emit ast`."""
+    click.echo(emit_stage("ast", file))
 
 
 @main.command()
 @click.argument("file", type=click.Path(exists=True, path_type=Path))
+@timings_options
+@diagnostic_options
 def check(file: Path):
     """Check a Zinc source file for syntax errors."""
     _compile_pipeline(file)
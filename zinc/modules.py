@@ -2,24 +2,44 @@
 
 from __future__ import annotations
 
+import dataclasses
 import re
+import sys
 import tomllib
 from dataclasses import dataclass, field
 from pathlib import Path
 from typing import Literal
 
-from antlr4 import CommonTokenStream, InputStream, ParserRuleContext
-from zinc.exceptions import ZincModuleError
+from antlr4 import CommonTokenStream, InputStream, ParserRuleContext, Token
+from antlr4.atn.PredictionMode import PredictionMode
+from antlr4.CommonTokenFactory import CommonTokenFactory
+from antlr4.error.ErrorListener import ErrorListener
+from antlr4.error.Errors import ParseCancellationException
+from antlr4.error.ErrorStrategy import BailErrorStrategy, DefaultErrorStrategy
+from zinc.ast.types import DEFAULT_INTEGER_CHOICES, DEFAULT_INTEGER_TYPE
+from zinc.conditional import active_build_config, resolve_conditionals
+from zinc.defines import apply_defines, defines_by_module
+from zinc.fixed_arrays import fold_array_lengths
+from zinc.diagnostics import DiagnosticCollector, closest_name
+from zinc.exceptions import ZincModuleError, ZincSyntaxError
+from zinc.macros import DEFINITION_PATTERN, Macro, expand_macros, find_macros
 from zinc.operators import function_is_operator, function_name_from_ctx
 from zinc.parser.zincLexer import zincLexer as ZincLexer
 from zinc.parser.zincParser import zincParser as ZincParser
+from zinc.shadowing import mark_let_bindings
+from zinc.static_fields import mark_static_fields
+from zinc.timings import phase
 
 RESERVED_ERROR_NAMES = frozenset({"Ok", "Err", "Some", "None"})
+RESERVED_CRATE_NAMES = frozenset({"tokio", "zinc-internal", "zinc_internal"})
 
 TopLevelKind = Literal["function", "struct", "enum", "const"]
 CompositionMode = Literal["orthogonal", "merge"]
 
 PKG_FILE_NAME = "pkg.toml"
+PROJECT_FILE_NAME = "zinc.toml"
+# A package root holds either manifest; zinc.toml wins when both exist.
+MANIFEST_FILE_NAMES = (PROJECT_FILE_NAME, PKG_FILE_NAME)
 
 
 @dataclass(frozen=True)
@@ -63,10 +83,24 @@ class RustExternType:
     rust_name: str
 
 
+@dataclass(frozen=True)
+class RustCrateDependency:
+    """A crates.io dependency declared with `crate name = "<requirement>";`."""
+
+    name: str
+    requirement: str
+
+    def normalized(self) -> dict:
+        """Return the requirement as a Cargo dependency table."""
+        value = tomllib.loads(f"value = {self.requirement}")["value"]
+        return {"version": value} if isinstance(value, str) else value
+
+
 @dataclass(frozen=True)
 class RustExternBlock:
     """Rust interop declarations extracted from one extern rust block."""
 
+    crates: tuple[RustCrateDependency, ...] = ()
     uses: tuple[str, ...] = ()
     types: tuple[str, ...] = ()
     functions: tuple[RustExternFunction, ...] = ()
@@ -98,6 +132,7 @@ class LoadedModule:
     rust_extern_methods: dict[tuple[str, str], RustExternFunction]
     symbols: dict[str, TopLevelSymbol]
     exports: dict[str, TopLevelSymbol]
+    rust_crates: dict[str, RustCrateDependency] = field(default_factory=dict)
     injected_symbols: dict[str, str] = field(default_factory=dict)
     alias_imports: dict[str, str] = field(default_factory=dict)
 
@@ -121,6 +156,7 @@ class ModuleGraph:
     top_level_symbols: dict[str, TopLevelSymbol]
     package_name: str
     package_version: str
+    default_int: str = DEFAULT_INTEGER_TYPE
 
     @staticmethod
     def extern_type_qualified_name(module_id: str, name: str) -> str:
@@ -192,6 +228,17 @@ class ModuleGraph:
             return symbol
         return None
 
+    def visible_names(self, module_id: str, kinds: set[TopLevelKind] | None = None) -> list[str]:
+        """Bare top-level names a module can use, declared or imported."""
+        module = self.modules[module_id]
+        names = [name for name, symbol in module.symbols.items() if kinds is None or symbol.kind in kinds]
+        names.extend(
+            name
+            for name, qualified_name in module.injected_symbols.items()
+            if kinds is None or self.top_level_symbols[qualified_name].kind in kinds
+        )
+        return names
+
     def resolve_export(self, module_id: str, name: str, kinds: set[TopLevelKind] | None = None) -> TopLevelSymbol | None:
         """Resolve a public export from a specific module."""
         symbol = self.modules[module_id].exports.get(name)
@@ -294,8 +341,25 @@ class ModuleGraph:
             return None
         return enum_symbol, path[-1]
 
+    def rust_crates(self) -> dict[str, RustCrateDependency]:
+        """Collect the crates declared by all modules, rejecting conflicting requirements."""
+        crates: dict[str, tuple[str, RustCrateDependency]] = {}
+        for module_id in sorted(self.modules):
+            for crate in self.modules[module_id].rust_crates.values():
+                existing = crates.get(crate.name)
+                if existing is not None and existing[1].normalized() != crate.normalized():
+                    raise ZincModuleError(
+                        f"crate '{crate.name}' is declared as {existing[1].requirement} in module '{existing[0]}' "
+                        f"and as {crate.requirement} in module '{module_id}'"
+                    )
+                crates.setdefault(crate.name, (module_id, crate))
+        return {name: crate for name, (_, crate) in sorted(crates.items())}
+
     def path_for_module(self, module_id: str) -> Path:
         """Return the source file path for a module id."""
+        module = self.modules.get(module_id)
+        if module is not None:
+            return module.path
         return self.package_root / f"{module_id}.zn"
 
     def top_level_functions(self) -> dict[str, ParserRuleContext]:
@@ -304,29 +368,56 @@ class ModuleGraph:
 
 
 def find_package_root(entry_file: Path) -> Path:
-    """Find the nearest package root containing pkg.toml."""
+    """Find the nearest package root containing zinc.toml or pkg.toml."""
     resolved_file = entry_file.resolve()
     for candidate in (resolved_file.parent, *resolved_file.parents):
-        pkg_file = candidate / PKG_FILE_NAME
-        if pkg_file.exists():
+        pkg_file = package_manifest(candidate)
+        if pkg_file is not None:
             _read_pkg_metadata(pkg_file)
             return candidate
-    raise ZincModuleError(f"missing {PKG_FILE_NAME} for {entry_file}")
+    raise ZincModuleError(f"missing {PKG_FILE_NAME} or {PROJECT_FILE_NAME} for {entry_file}")
+
+
+def package_manifest(package_root: Path) -> Path | None:
+    """Return the manifest file of a package root, if it has one."""
+    for file_name in MANIFEST_FILE_NAMES:
+        pkg_file = package_root / file_name
+        if pkg_file.exists():
+            return pkg_file
+    return None
 
 
-def build_module_graph(entry_file: Path) -> ModuleGraph:
-    """Load the entry module and all transitive imports."""
+def build_module_graph(
+    entry_file: Path,
+    sources: dict[Path, str] | None = None,
+    require_main: bool = True,
+    error_listener: ErrorListener | None = None,
+) -> ModuleGraph:
+    """Load the entry module and all transitive imports.
+
+    `sources` maps resolved file paths to in-memory text that takes precedence
+    over the file on disk (unsaved editor buffers). An `error_listener`
+    receives the syntax errors of every module.
+    """
     resolved_entry = entry_file.resolve()
     package_root = find_package_root(resolved_entry)
-    pkg_name, pkg_version = _read_pkg_metadata(package_root / PKG_FILE_NAME)
+    pkg_name, pkg_version, default_int = _read_pkg_metadata(package_manifest(package_root))
     entry_module_id = _module_id_from_path(package_root, resolved_entry)
+    defines = defines_by_module(active_build_config().defines, entry_module_id)
 
     modules: dict[str, LoadedModule] = {}
     top_level_symbols: dict[str, TopLevelSymbol] = {}
     loading_stack: list[str] = []
+    dependencies_by_root: dict[Path, dict[str, Path]] = {}
+
+    def dependencies_of(root: Path) -> dict[str, Path]:
+        if root not in dependencies_by_root:
+            dependencies_by_root[root] = read_path_dependencies(package_manifest(root))
+        return dependencies_by_root[root]
 
-    def load_module_file(module_file: Path) -> LoadedModule:
-        module_id = _module_id_from_path(package_root, module_file)
+    def load_module_file(module_file: Path, root: Path = package_root, id_prefix: str = "") -> LoadedModule:
+        # Modules of path dependencies are namespaced by the dependency name.
+        module_id = id_prefix + _module_id_from_path(root, module_file)
 
         if module_id in loading_stack:
             cycle = " -> ".join([*loading_stack, module_id])
@@ -340,8 +431,13 @@ def build_module_graph(entry_file: Path) -> ModuleGraph:
 
         loading_stack.append(module_id)
         try:
-            tree, extern_block = _parse_program(module_file)
-            imports = _collect_imports(tree)
+            source_text = sources.get(module_file.resolve()) if sources else None
+            tree, extern_block = _parse_program(module_file, source_text, error_listener, defines.get(module_id))
+            import_specs = _collect_imports(tree)
+            resolved_imports = [_resolve_import(root, id_prefix, dependencies_of(root), spec.module_path) for spec in import_specs]
+            imports = [
+                dataclasses.replace(spec, module_path=target_id) for spec, (_, _, _, target_id) in zip(import_specs, resolved_imports)
+            ]
             symbols = _collect_top_level_symbols(tree, module_id)
             exports = {name: symbol for name, symbol in symbols.items() if symbol.is_public}
             duplicate_extern_types = {name for name in extern_block.types if extern_block.types.count(name) > 1}
@@ -357,6 +453,12 @@ def build_module_graph(entry_file: Path) -> ModuleGraph:
                 )
                 for name in extern_block.types
             }
+            rust_crates: dict[str, RustCrateDependency] = {}
+            for crate in extern_block.crates:
+                crate = _resolve_crate_path(crate, module_file.resolve().parent)
+                if crate.name in rust_crates:
+                    raise ZincModuleError(f"duplicate extern rust crate '{crate.name}' in module '{module_id}'")
+                rust_crates[crate.name] = crate
             rust_extern_functions: dict[str, RustExternFunction] = {}
             rust_extern_methods: dict[tuple[str, str], RustExternFunction] = {}
             for function in extern_block.functions:
@@ -383,6 +485,7 @@ def build_module_graph(entry_file: Path) -> ModuleGraph:
                 rust_extern_methods=rust_extern_methods,
                 symbols=symbols,
                 exports=exports,
+                rust_crates=rust_crates,
             )
             modules[module_id] = module
 
@@ -391,15 +494,15 @@ def build_module_graph(entry_file: Path) -> ModuleGraph:
                     raise ZincModuleError(f"duplicate symbol id '{symbol.qualified_name}'")
                 top_level_symbols[symbol.qualified_name] = symbol
 
-            for import_spec in imports:
-                target_path = _module_file_from_import(package_root, import_spec.module_path)
-                load_module_file(target_path)
+            for target_path, target_root, target_prefix, _ in resolved_imports:
+                load_module_file(target_path, target_root, target_prefix)
 
             return module
         finally:
             loading_stack.pop()
 
     load_module_file(resolved_entry)
+    _check_defines(defines, modules)
 
     graph = ModuleGraph(
         package_root=package_root,
@@ -409,13 +512,15 @@ def build_module_graph(entry_file: Path) -> ModuleGraph:
         top_level_symbols=top_level_symbols,
         package_name=pkg_name,
         package_version=pkg_version,
+        default_int=default_int,
     )
 
     for module in graph.modules.values():
         _resolve_module_import_scope(graph, module)
+    graph.rust_crates()
 
     entry_module = graph.get_module(entry_module_id)
-    if "main" not in entry_module.symbols or entry_module.symbols["main"].kind != "function":
+    if require_main and ("main" not in entry_module.symbols or entry_module.symbols["main"].kind != "function"):
         raise ZincModuleError(f"entry module '{entry_module_id}' must define fn main()")
 
     return graph
@@ -433,7 +538,7 @@ def extract_identifier_path(expr_ctx) -> list[str] | None:
             return [expr_ctx.TYPE_KW().getText()]
         return None
 
-    if isinstance(expr_ctx, ZincParser.MemberAccessExprContext):
+    if isinstance(expr_ctx, (ZincParser.MemberAccessExprContext, ZincParser.MemberAccessContext)):
         prefix = extract_identifier_path(expr_ctx.expression())
         if prefix is None:
             return None
@@ -442,6 +547,25 @@ def extract_identifier_path(expr_ctx) -> list[str] | None:
     return None
 
 
+def format_string_literal(expr_ctx) -> ZincParser.LiteralContext | None:
+    """Return the literal when an expression is a double-quoted string, the only kind print() fills `{}` placeholders in."""
+    if not isinstance(expr_ctx, ZincParser.PrimaryExprContext) or expr_ctx.primaryExpression().literal() is None:
+        return None
+    literal = expr_ctx.primaryExpression().literal()
+    return literal if literal.STRING() is not None and literal.getText().startswith('"') else None
+
+
+def appended_pieces(name: str, expr_ctx) -> list | None:
+    """Return the pieces of `name + a + b`, in order, when an expression only adds onto the variable `name`."""
+    pieces = []
+    while isinstance(expr_ctx, ZincParser.AdditiveExprContext) and expr_ctx.getChild(1).getText() == "+":
+        pieces.append(expr_ctx.expression(1))
+        expr_ctx = expr_ctx.expression(0)
+    if not pieces or extract_identifier_path(expr_ctx) != [name]:
+        return None
+    return pieces[::-1]
+
+
 def struct_path_from_ctx(ctx: ZincParser.StructInstantiationContext) -> list[str]:
     """Extract the declared struct path from a struct instantiation node."""
     return qualified_name_path(ctx.qualifiedName())
@@ -483,8 +607,8 @@ def struct_composition_from_ctx(
     return None
 
 
-def _read_pkg_metadata(pkg_file: Path) -> tuple[str, str]:
-    """Read and validate package metadata."""
+def _read_pkg_metadata(pkg_file: Path) -> tuple[str, str, str]:
+    """Read and validate package metadata: name, version, and the width of unannotated integers."""
     try:
         with pkg_file.open("rb") as handle:
             data = tomllib.load(handle)
@@ -501,7 +625,10 @@ def _read_pkg_metadata(pkg_file: Path) -> tuple[str, str]:
         raise ZincModuleError(f"{pkg_file} must define package.name")
     if not isinstance(version, str) or not version:
         raise ZincModuleError(f"{pkg_file} must define package.version")
-    return name, version
+    default_int = package.get("default-int", DEFAULT_INTEGER_TYPE)
+    if default_int not in DEFAULT_INTEGER_CHOICES:
+        raise ZincModuleError(f"{pkg_file}: package.default-int must be one of {', '.join(DEFAULT_INTEGER_CHOICES)}, not {default_int!r}")
+    return name, version, default_int
 
 
 def _module_id_from_path(package_root: Path, module_file: Path) -> str:
@@ -518,6 +645,40 @@ def _module_id_from_path(package_root: Path, module_file: Path) -> str:
     return relative.with_suffix("").as_posix()
 
 
+def read_path_dependencies(pkg_file: Path) -> dict[str, Path]:
+    """Read `[dependencies]` entries of the form `name = { path = "../dir" }`."""
+    try:
+        with pkg_file.open("rb") as handle:
+            dependencies = tomllib.load(handle).get("dependencies", {})
+    except (OSError, tomllib.TOMLDecodeError) as exc:
+        raise ZincModuleError(f"failed to read {pkg_file}: {exc}") from exc
+    if not isinstance(dependencies, dict):
+        raise ZincModuleError(f"{pkg_file}: [dependencies] must be a table")
+
+    resolved: dict[str, Path] = {}
+    for name, spec in dependencies.items():
+        if not isinstance(spec, dict) or not isinstance(spec.get("path"), str):
+            raise ZincModuleError(f"{pkg_file}: dependency '{name}' must be a table with a path, e.g. {name} = {{ path = \"../{name}\" }}")
+        root = (pkg_file.parent / spec["path"]).resolve()
+        if package_manifest(root) is None:
+            raise ZincModuleError(f"{pkg_file}: dependency '{name}' has no {PROJECT_FILE_NAME} or {PKG_FILE_NAME} in {root}")
+        resolved[name] = root
+    return resolved
+
+
+def _resolve_import(root: Path, id_prefix: str, dependencies: dict[str, Path], module_path: str) -> tuple[Path, Path, str, str]:
+    """Resolve an import to (file, package root, module id prefix, module id).
+
+    An import whose first segment names a path dependency loads from that
+    package; anything else is relative to the importing module's package.
+    """
+    head, _, rest = module_path.partition("/")
+    if head in dependencies and rest:
+        root, id_prefix, module_path = dependencies[head], f"{head}/", rest
+    target = _module_file_from_import(root, module_path)
+    return target, root, id_prefix, id_prefix + _module_id_from_path(root, target)
+
+
 def _module_file_from_import(package_root: Path, module_path: str) -> Path:
     """Resolve an import path to a source file."""
     target = (package_root / f"{module_path}.zn").resolve()
@@ -531,28 +692,212 @@ def _module_file_from_import(package_root: Path, module_path: str) -> Path:
     return target
 
 
-def _parse_program(module_file: Path) -> tuple[ZincParser.ProgramContext, RustExternBlock]:
+def _check_defines(defines: dict[str, dict[str, str]], modules: dict[str, LoadedModule]) -> None:
+    """Every `--define` must name a const of a module the program loads."""
+    for module_id, names in defines.items():
+        module = modules.get(module_id)
+        if module is None:
+            raise ZincModuleError(f"--define names module '{module_id}', which the program doesn't import")
+        consts = [name for name, symbol in module.symbols.items() if symbol.kind == "const"]
+        for name in names:
+            if name not in consts:
+                suggestion = closest_name(name, consts)
+                hint = f"; did you mean '{suggestion}'?" if suggestion is not None else ""
+                raise ZincModuleError(f"--define {name}: module '{module_id}' has no const '{name}'{hint}")
+
+
+def _parse_program(
+    module_file: Path,
+    source_text: str | None = None,
+    error_listener: ErrorListener | None = None,
+    defines: dict[str, str] | None = None,
+) -> tuple[ZincParser.ProgramContext, RustExternBlock]:
     """Parse a Zinc source file into a program tree and extracted Rust extern metadata."""
-    source_text = module_file.read_text()
-    stripped_text, extern_block = _extract_rust_extern_blocks(source_text)
-    input_stream = InputStream(stripped_text)
+    if source_text is None:
+        source_text = module_file.read_text(encoding="utf-8")
+    return parse_source(source_text, str(module_file), error_listener, defines)
+
+
+class SourceStream(InputStream):
+    """Zinc source as the lexer reads it, with Unicode identifier characters folded to ASCII.
+
+    The generated lexer only knows ASCII identifiers. Lookahead sees each
+    non-ASCII letter (anything that can start a Python identifier) as `a`, and
+    each other identifier character, like a combining mark, as `0`, so
+    `café` and `名前` lex as IDENTIFIER. Token text still comes from the
+    original string, and because one character stays one index, token columns
+    count characters rather than bytes.
+    """
+
+    def _loadString(self) -> None:  # noqa: N802 (ANTLR API)
+        super()._loadString()
+        self.data = [_lexer_code_point(char) for char in self.strdata]
+
+
+def _lexer_code_point(char: str) -> int:
+    """The code point the lexer sees for one source character."""
+    if char.isascii():
+        return ord(char)
+    if char.isidentifier():
+        return ord("a")
+    if f"a{char}".isidentifier():
+        return ord("0")
+    return ord(char)
+
+
+class InterningTokenFactory(CommonTokenFactory):
+    """Create tokens whose identifier and string-literal text is interned.
+
+    A name is spelled the same at every use, so interning stores it once per
+    distinct spelling instead of once per token, and the dict and set lookups
+    the type checker does on names compare by identity before comparing
+    characters. Other tokens keep reading their text lazily from the stream.
+    """
+
+    INTERNED_TYPES = frozenset({ZincLexer.IDENTIFIER, ZincLexer.STRING})
+
+    def create(self, source, type: int, text: str, channel: int, start: int, stop: int, line: int, column: int):  # noqa: A002 (ANTLR API)
+        if text is None and type in self.INTERNED_TYPES:
+            text = sys.intern(source[1].getText(start, stop))
+        return super().create(source, type, text, channel, start, stop, line, column)
+
+
+INTERNING_TOKEN_FACTORY = InterningTokenFactory()
+
+
+def source_lexer(source_text: str, origin: str | None = None) -> ZincLexer:
+    """A lexer over Zinc source text that accepts Unicode identifiers and interns names."""
+    input_stream = SourceStream(source_text)
+    if origin is not None:
+        # Lets error listeners tell which module a syntax error belongs to.
+        input_stream.name = origin
     lexer = ZincLexer(input_stream)
-    stream = CommonTokenStream(lexer)
-    parser = ZincParser(stream)
-    tree = parser.program()
-    if parser.getNumberOfSyntaxErrors() > 0:
-        raise ZincModuleError(f"found {parser.getNumberOfSyntaxErrors()} syntax error(s) while parsing {module_file}")
+    lexer._factory = INTERNING_TOKEN_FACTORY
+    return lexer
+
+
+class StatementRecoveryStrategy(DefaultErrorStrategy):
+    """Resynchronize after a syntax error at the start of the next statement.
+
+    ANTLR's default recovery stops at the first token that could follow the
+    failed rule, which is often still on the broken line and produces a cascade
+    of follow-on errors. Skipping to a token on a later line, or to a closing
+    brace, lets the parser report each real mistake once and keep going.
+    """
+
+    def consumeUntil(self, recognizer, set_) -> None:  # noqa: N802, D102 (ANTLR API)
+        error_line = recognizer.getCurrentToken().line
+        stream = recognizer.getTokenStream()
+        while True:
+            token = stream.LT(1)
+            if token.type == Token.EOF:
+                return
+            if token.type in set_ and (token.line > error_line or token.type == ZincParser.RBRACE):
+                return
+            recognizer.consume()
+
+
+def parse_source(
+    source_text: str, origin: str, error_listener: ErrorListener | None = None, defines: dict[str, str] | None = None
+) -> tuple[ZincParser.ProgramContext, RustExternBlock]:
+    """Parse Zinc source text; token positions match the original text.
+
+    Macro invocations are expanded, `when` blocks resolved, and the consts
+    named in `defines` given their values before parsing, so the tree holds
+    only the code being compiled; see `zinc.macros`, `zinc.conditional`, and
+    `zinc.defines`. Fixed-size array lengths are folded into their closing
+    brackets; see `zinc.fixed_arrays`, a statement-leading `let` is folded
+    into the name it binds; see `zinc.shadowing`, and a field's `static` into
+    the field; see `zinc.static_fields`. Syntax errors are raised together as
+    a `ZincSyntaxError`; an `error_listener` also sees each one as it is
+    found.
+    """
+    with phase("lex", origin):
+        stripped_text, extern_block = _extract_rust_extern_blocks(source_text)
+        stripped_text, macros = _extract_macros(stripped_text, origin)
+        lexer = source_lexer(stripped_text, origin)
+        stream = CommonTokenStream(lexer)
+        parser = ZincParser(stream)
+        collector = DiagnosticCollector()
+        listeners = [collector] if error_listener is None else [collector, error_listener]
+        lexer.removeErrorListeners()
+        for listener in listeners:
+            lexer.addErrorListener(listener)
+        stream.fill()
+        tokens, macro_errors = expand_macros(stream.tokens, macros)
+        tokens, when_errors = resolve_conditionals(tokens)
+        tokens, define_errors = apply_defines(tokens, defines or {})
+        tokens, length_errors = fold_array_lengths(tokens)
+        tokens = mark_let_bindings(tokens)
+        tokens = mark_static_fields(tokens)
+        for index, token in enumerate(tokens):
+            token.tokenIndex = index
+        stream.tokens = tokens
+    preprocess_errors = macro_errors + when_errors + define_errors + length_errors
+    collector.diagnostics.extend(preprocess_errors)
+    with phase("parse", origin):
+        tree = _parse_program_tree(parser, listeners)
+    if (error_count := parser.getNumberOfSyntaxErrors() + len(preprocess_errors)) > 0:
+        raise ZincSyntaxError(f"found {error_count} syntax error(s) while parsing {origin}", collector.diagnostics)
     return tree, extern_block
 
 
-def _extract_rust_extern_blocks(source_text: str) -> tuple[str, RustExternBlock]:
-    """Extract extern rust blocks and replace them with whitespace for normal Zinc parsing."""
-    uses: list[str] = []
-    types: list[str] = []
-    functions: list[RustExternFunction] = []
+def _extract_macros(source_text: str, origin: str) -> tuple[str, dict[str, Macro]]:
+    """Read the module's macro declarations and replace them, and their doc comments, with whitespace."""
+    if not DEFINITION_PATTERN.search(source_text):
+        return source_text, {}
+    lexer = source_lexer(source_text, origin)
+    lexer.removeErrorListeners()
+    macros = find_macros(lexer.getAllTokens())
     replacements = list(source_text)
+    for macro in macros.values():
+        start = macro.start
+        # `///` lines above the declaration document the macro, not whatever follows it.
+        while (line_start := source_text.rfind("\n", 0, start) + 1) > 0:
+            previous_start = source_text.rfind("\n", 0, line_start - 1) + 1
+            if not source_text[previous_start : line_start - 1].strip().startswith("///"):
+                break
+            start = previous_start
+        for index in range(start, macro.stop + 1):
+            replacements[index] = "\n" if source_text[index] == "\n" else " "
+    return "".join(replacements), macros
+
+
+def _parse_program_tree(parser: ZincParser, listeners: list[ErrorListener]) -> ZincParser.ProgramContext:
+    """Parse a whole program with SLL prediction, falling back to full LL when SLL gives up.
+
+    SLL never looks at the surrounding rule context, so it skips the expensive
+    full-context predictions that dominate parse time, and on inputs it accepts
+    it builds the tree LL would. A few constructs, like a statement after
+    `spawn f(x)`, need full context; those and real syntax errors make SLL bail,
+    and the program is parsed again with LL, statement recovery, and the error
+    listeners, so diagnostics come out as before.
+    """
+    parser.removeErrorListeners()
+    parser._interp.predictionMode = PredictionMode.SLL
+    parser._errHandler = BailErrorStrategy()
+    try:
+        return parser.program()
+    except ParseCancellationException:
+        pass
+    parser.reset()
+    parser._interp.predictionMode = PredictionMode.LL
+    parser._errHandler = StatementRecoveryStrategy()
+    for listener in listeners:
+        parser.addErrorListener(listener)
+    return parser.program()
+
+
+def lex_source(source_text: str) -> list[Token]:
+    """Tokenize Zinc source text as the parser sees it, with extern rust blocks blanked out."""
+    stripped_text, _ = _extract_rust_extern_blocks(source_text)
+    return source_lexer(stripped_text).getAllTokens()
+
+
+def find_rust_extern_spans(source_text: str) -> list[tuple[int, int]]:
+    """Return (start, closing brace) offsets of every extern rust block."""
+    spans: list[tuple[int, int]] = []
     cursor = 0
-
     while True:
         match = re.search(r"\bextern\s+rust\s*\{", source_text[cursor:])
         if match is None:
@@ -562,16 +907,31 @@ def _extract_rust_extern_blocks(source_text: str) -> tuple[str, RustExternBlock]
         close_brace = _find_matching_brace(source_text, open_brace)
         if close_brace is None:
             raise ZincModuleError("unterminated extern rust block")
+        spans.append((start, close_brace))
+        cursor = close_brace + 1
+    return spans
+
+
+def _extract_rust_extern_blocks(source_text: str) -> tuple[str, RustExternBlock]:
+    """Extract extern rust blocks and replace them with whitespace for normal Zinc parsing."""
+    crates: list[RustCrateDependency] = []
+    uses: list[str] = []
+    types: list[str] = []
+    functions: list[RustExternFunction] = []
+    replacements = list(source_text)
+
+    for start, close_brace in find_rust_extern_spans(source_text):
+        open_brace = source_text.index("{", start)
         body = source_text[open_brace + 1 : close_brace]
         block = _parse_rust_extern_body(body)
+        crates.extend(block.crates)
         uses.extend(block.uses)
         types.extend(block.types)
         functions.extend(block.functions)
         for index in range(start, close_brace + 1):
             replacements[index] = "\n" if source_text[index] == "\n" else " "
-        cursor = close_brace + 1
 
-    return "".join(replacements), RustExternBlock(uses=tuple(uses), types=tuple(types), functions=tuple(functions))
+    return "".join(replacements), RustExternBlock(crates=tuple(crates), uses=tuple(uses), types=tuple(types), functions=tuple(functions))
 
 
 def _find_matching_brace(text: str, open_index: int) -> int | None:
@@ -621,6 +981,7 @@ def _find_matching_brace(text: str, open_index: int) -> int | None:
 
 def _parse_rust_extern_body(body: str) -> RustExternBlock:
     """Parse the supported declarations inside an extern rust block."""
+    crates: list[RustCrateDependency] = []
     uses: list[str] = []
     types: list[str] = []
     functions: list[RustExternFunction] = []
@@ -630,13 +991,17 @@ def _parse_rust_extern_body(body: str) -> RustExternBlock:
         cursor = _skip_extern_ws(body, cursor)
         if cursor >= len(body):
             break
+        if _body_startswith_kw(body, cursor, "crate"):
+            statement, cursor = _read_semicolon_item(body, cursor)
+            crates.append(_parse_rust_crate_dependency(statement))
+            continue
         if _body_startswith_kw(body, cursor, "use"):
             statement, cursor = _read_semicolon_item(body, cursor)
             uses.append(statement.strip())
             continue
         if _body_startswith_kw(body, cursor, "type"):
             statement, cursor = _read_semicolon_item(body, cursor)
-            match = re.fullmatch(r"type\s+([A-Za-z_][A-Za-z0-9_]*)\s*;", statement.strip())
+            match = re.fullmatch(r"type\s+([^\W\d]\w*)\s*;", statement.strip())
             if match is None:
                 raise ZincModuleError(f"invalid extern rust type declaration: {statement.strip()}")
             types.append(match.group(1))
@@ -651,7 +1016,47 @@ def _parse_rust_extern_body(body: str) -> RustExternBlock:
             continue
         raise ZincModuleError(f"unsupported extern rust item near: {body[cursor : cursor + 40].strip()}")
 
-    return RustExternBlock(uses=tuple(uses), types=tuple(types), functions=tuple(functions))
+    return RustExternBlock(crates=tuple(crates), uses=tuple(uses), types=tuple(types), functions=tuple(functions))
+
+
+def _parse_rust_crate_dependency(statement: str) -> RustCrateDependency:
+    """Parse `crate name = "1.0";` or `crate name = { version = "1.0", features = [...] };`."""
+    match = re.fullmatch(r"crate\s+([A-Za-z][A-Za-z0-9_-]*)\s*=\s*(.+?)\s*;", statement.strip(), re.DOTALL)
+    if match is None:
+        raise ZincModuleError(f"invalid extern rust crate declaration: {statement.strip()}")
+    name = match.group(1)
+    if name in RESERVED_CRATE_NAMES:
+        raise ZincModuleError(f"crate '{name}' is added by the compiler and cannot be declared")
+    crate = RustCrateDependency(name=name, requirement=" ".join(match.group(2).split()))
+    try:
+        requirement = crate.normalized()
+    except tomllib.TOMLDecodeError as exc:
+        raise ZincModuleError(f"invalid requirement for crate '{name}': {crate.requirement}") from exc
+    if not isinstance(requirement, dict) or not any(key in requirement for key in ("version", "git", "path")):
+        raise ZincModuleError(f"crate '{name}' needs a version string or a table with version, git, or path")
+    return crate
+
+
+def _resolve_crate_path(crate: RustCrateDependency, base_dir: Path) -> RustCrateDependency:
+    """Make a `path` requirement absolute, relative to the declaring source file."""
+    requirement = crate.normalized()
+    if "path" not in requirement:
+        return crate
+    requirement["path"] = (base_dir / requirement["path"]).resolve().as_posix()
+    return RustCrateDependency(crate.name, _toml_inline(requirement))
+
+
+def _toml_inline(value) -> str:
+    """Render a TOML value on one line."""
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    if isinstance(value, str):
+        return '"' + value.replace("\\", "\\\\").replace('"', '\\"') + '"'
+    if isinstance(value, list):
+        return "[" + ", ".join(_toml_inline(item) for item in value) + "]"
+    if isinstance(value, dict):
+        return "{ " + ", ".join(f"{key} = {_toml_inline(item)}" for key, item in value.items()) + " }"
+    return str(value)
 
 
 def _skip_extern_ws(text: str, cursor: int) -> int:
@@ -699,7 +1104,7 @@ def _read_semicolon_item(text: str, cursor: int) -> tuple[str, int]:
 
 def _parse_rust_extern_impl(text: str, cursor: int) -> tuple[list[RustExternFunction], int]:
     """Parse an extern impl block."""
-    match = re.match(r"impl\s+([A-Za-z_][A-Za-z0-9_]*)\s*\{", text[cursor:])
+    match = re.match(r"impl\s+([^\W\d]\w*)\s*\{", text[cursor:])
     if match is None:
         raise ZincModuleError(f"invalid extern rust impl near: {text[cursor : cursor + 40].strip()}")
     owner_type = match.group(1)
@@ -732,7 +1137,7 @@ def _parse_rust_extern_function(statement: str, owner_type: str | None = None) -
     if text.startswith("async "):
         is_async = True
         text = text[len("async ") :].lstrip()
-    match = re.match(r"fn\s+([A-Za-z_][A-Za-z0-9_]*)\s*\(", text)
+    match = re.match(r"fn\s+([^\W\d]\w*)\s*\(", text)
     if match is None:
         raise ZincModuleError(f"invalid extern rust function declaration: {statement.strip()}")
     name = match.group(1)
@@ -781,7 +1186,7 @@ def _parse_rust_extern_params(params_text: str) -> list[RustExternParameter]:
     if not params_text:
         return []
     params: list[RustExternParameter] = []
-    for raw_param in _split_top_level_commas(params_text):
+    for raw_param in split_top_level_commas(params_text):
         param = raw_param.strip()
         if not param:
             continue
@@ -795,7 +1200,7 @@ def _parse_rust_extern_params(params_text: str) -> list[RustExternParameter]:
     return params
 
 
-def _split_top_level_commas(text: str) -> list[str]:
+def split_top_level_commas(text: str) -> list[str]:
     """Split a comma-delimited string while respecting nested type syntax."""
     parts: list[str] = []
     depth = 0
@@ -821,9 +1226,9 @@ def _rust_use_imports_name(statement: str, name: str) -> bool:
         text = text[:-1].strip()
     if "::{" in text and text.endswith("}"):
         inner = text[text.rfind("{") + 1 : -1]
-        for item in _split_top_level_commas(inner):
+        for item in split_top_level_commas(inner):
             imported = item.strip()
-            alias_match = re.search(r"\bas\s+([A-Za-z_][A-Za-z0-9_]*)$", imported)
+            alias_match = re.search(r"\bas\s+([^\W\d]\w*)$", imported)
             if alias_match:
                 if alias_match.group(1) == name:
                     return True
@@ -831,7 +1236,7 @@ def _rust_use_imports_name(statement: str, name: str) -> bool:
             if imported.rsplit("::", 1)[-1].strip() == name:
                 return True
         return False
-    alias_match = re.search(r"\bas\s+([A-Za-z_][A-Za-z0-9_]*)$", text)
+    alias_match = re.search(r"\bas\s+([^\W\d]\w*)$", text)
     if alias_match:
         return alias_match.group(1) == name
     return text.rsplit("::", 1)[-1].strip() == name
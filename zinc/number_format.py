@@ -0,0 +1,54 @@
+"""`format_number(value, spec)`: report-style numbers from a short format spec.
+
+    format_number(1234567.891, ",.2f")  // 1,234,567.89
+    format_number(0.125, ".1%")         // 12.5%
+    format_number(1234567.891, ".3e")   // 1.235e6
+
+A spec is an optional `,` that groups thousands, an optional `.N` precision,
+and a style: `f` for fixed decimals, `%` for a percentage, or `e` for
+scientific notation. Without a style, `.N` means fixed decimals and an empty
+spec spells the number the way `print` does. `f` and `%` default to six
+decimals; `e` without a precision gives the shortest digits. Exponents are
+spelled the Rust way, as in `1.5e6`.
+
+The value is formatted as an `f64`, and the spec is a string literal, checked
+when the program compiles. Compiled programs call the runtime's
+`format_number`, which reads the same spec.
+"""
+
+import re
+from dataclasses import dataclass
+
+_SPEC = re.compile(r"(?P<grouped>,?)(?:\.(?P<precision>\d+))?(?P<style>[fe%]?)")
+
+
+@dataclass(frozen=True)
+class NumberFormat:
+    """A parsed `format_number` spec."""
+
+    grouped: bool
+    precision: int | None
+    style: str  # "f", "%", "e", or "" when the spec names none
+
+
+def parse_number_format(spec: str) -> NumberFormat:
+    """Parse a `format_number` spec, raising ValueError with the reason when it isn't one."""
+    match = _SPEC.fullmatch(spec)
+    if match is None:
+        raise ValueError(f"'{spec}' isn't a number format; use something like ',.2f', '.1%', or '.3e'")
+    if match["grouped"] and match["style"] == "e":
+        raise ValueError("thousands separators don't apply to scientific notation")
+    precision = int(match["precision"]) if match["precision"] is not None else None
+    return NumberFormat(grouped=bool(match["grouped"]), precision=precision, style=match["style"])
+
+
+def group_thousands(text: str) -> str:
+    """Put a `,` between each group of three digits in the whole part of a formatted number."""
+    sign, unsigned = ("-", text[1:]) if text.startswith("-") else ("", text)
+    end = next((index for index, char in enumerate(unsigned) if not char.isdigit()), len(unsigned))
+    whole, tail = unsigned[:end], unsigned[end:]
+    groups: list[str] = []
+    while len(whole) > 3:
+        whole, group = whole[:-3], whole[-3:]
+        groups.insert(0, group)
+    return sign + ",".join([whole, *groups]) + tail
@@ -83,6 +83,11 @@ def is_numeric_literal(text: str) -> bool:
         return False
 
 
+def is_unsuffixed_numeric_literal(text: str) -> bool:
+    """Return True when text is a numeric literal without a type suffix."""
+    return is_numeric_literal(text) and _strip_suffix(text)[1] is None
+
+
 def numeric_literal_value(text: str) -> int | float:
     """Return the computed value for a numeric literal."""
     parsed = parse_numeric_literal(text)
@@ -0,0 +1,194 @@
+"""Compiler passes registered by plugins.
+
+A plugin is a Python module with a `register(passes)` function that adds
+passes to a `PassManager`. Packages list their plugins under `[build]` in the
+manifest, as module names or as `.py` paths relative to the package root:
+
+    [build]
+    plugins = ["tools/trace.py", "house_rules"]
+
+Syntax passes run on every module's parse tree before type checking, and
+typed passes run once the program has type-checked. A pass is called with a
+`PassContext` for one module, through which it can report diagnostics and
+edit the module's source. Edits are applied once every pass of a stage has
+run, and the edited modules are parsed and checked again, so the rest of the
+compiler never sees a tree that differs from its text. Diagnostics from a later
+stage, including type errors, point into the edited text.
+"""
+
+import importlib
+import importlib.util
+import tomllib
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from antlr4 import ParserRuleContext
+from antlr4.tree.Tree import TerminalNode
+from zinc.diagnostics import Diagnostic, node_span
+from zinc.exceptions import ZincModuleError
+from zinc.modules import LoadedModule, ModuleGraph, find_package_root, package_manifest
+
+if TYPE_CHECKING:
+    from zinc.api import CheckedProgram
+
+PLUGIN_REGISTER = "register"
+
+# Passes may point at rules or at single tokens of the parse tree.
+Node = ParserRuleContext | TerminalNode
+
+
+def _offsets(node: Node) -> tuple[int, int]:
+    """Start and end character offsets of a parse tree node in its module's source."""
+    if isinstance(node, TerminalNode):
+        return node.getSymbol().start, node.getSymbol().stop + 1
+    return node.start.start, node.stop.stop + 1
+
+
+@dataclass
+class PassContext:
+    """One module as a pass sees it; `checked` is the type-checked program, or None in a syntax pass."""
+
+    module: LoadedModule
+    source: str
+    checked: "CheckedProgram | None" = None
+    diagnostics: list[Diagnostic] = field(default_factory=list)
+    edits: list[tuple[int, int, str]] = field(default_factory=list)
+
+    def insert_before(self, node: Node, text: str) -> None:
+        """Insert `text` in front of a parse tree node."""
+        start, _ = _offsets(node)
+        self.edits.append((start, start, text))
+
+    def insert_after(self, node: Node, text: str) -> None:
+        """Insert `text` after a parse tree node."""
+        _, stop = _offsets(node)
+        self.edits.append((stop, stop, text))
+
+    def replace(self, node: Node, text: str) -> None:
+        """Replace the source of a parse tree node with `text`."""
+        self.edits.append((*_offsets(node), text))
+
+    def error(self, message: str, node: Node | None = None) -> None:
+        """Report an error, failing the compile once the stage has finished."""
+        self._report(message, node, "error")
+
+    def warning(self, message: str, node: Node | None = None) -> None:
+        """Report a warning."""
+        self._report(message, node, "warning")
+
+    def _report(self, message: str, node: Node | None, severity: str) -> None:
+        if node is None:
+            self.diagnostics.append(Diagnostic(message, str(self.module.path), severity=severity))
+            return
+        if isinstance(node, TerminalNode):
+            token = node.getSymbol()
+            span = (token.getInputStream().name, token.line, token.column, token.column + len(token.text))
+        else:
+            span = node_span(node)
+        self.diagnostics.append(Diagnostic(message, *span, severity=severity))
+
+    def edited_source(self) -> str | None:
+        """The source with every edit applied, or None when there were none; overlapping edits are an error."""
+        if not self.edits:
+            return None
+        pieces, position = [], 0
+        for start, stop, text in sorted(self.edits, key=lambda edit: (edit[0], edit[1])):
+            if start < position:
+                raise ZincModuleError(f"compiler passes made overlapping edits to {self.module.path}")
+            pieces += [self.source[position:start], text]
+            position = stop
+        return "".join([*pieces, self.source[position:]])
+
+
+Pass = Callable[[PassContext], None]
+
+
+@dataclass
+class StageResult:
+    """What one stage of passes produced: rewritten module sources by path, and diagnostics."""
+
+    rewritten: dict[Path, str] = field(default_factory=dict)
+    diagnostics: list[Diagnostic] = field(default_factory=list)
+
+
+class PassManager:
+    """The syntax and typed passes of one compile, run in registration order."""
+
+    def __init__(self) -> None:
+        self.syntax_passes: list[Pass] = []
+        self.typed_passes: list[Pass] = []
+        # Plugin files, so that cached compiles are invalidated when they change.
+        self.plugin_files: list[Path] = []
+
+    def syntax_pass(self, function: Pass) -> Pass:
+        """Register a pass over parse trees; usable as a decorator."""
+        self.syntax_passes.append(function)
+        return function
+
+    def typed_pass(self, function: Pass) -> Pass:
+        """Register a pass over the type-checked program; usable as a decorator."""
+        self.typed_passes.append(function)
+        return function
+
+    def run_syntax(self, graph: ModuleGraph, sources: dict[Path, str] | None = None) -> StageResult:
+        """Run the syntax passes over every module of `graph`."""
+        return self._run(self.syntax_passes, graph, sources, None)
+
+    def run_typed(self, checked: "CheckedProgram", sources: dict[Path, str] | None = None) -> StageResult:
+        """Run the typed passes over every module of a checked program."""
+        return self._run(self.typed_passes, checked.graph, sources, checked)
+
+    @staticmethod
+    def _run(passes: list[Pass], graph: ModuleGraph, sources: dict[Path, str] | None, checked) -> StageResult:
+        result = StageResult()
+        if not passes:
+            return result
+        for module in graph.modules.values():
+            # Parse trees have `extern rust` blocks blanked out, so edits go to the text as written.
+            source = sources[module.path] if sources and module.path in sources else module.path.read_text(encoding="utf-8")
+            context = PassContext(module, source, checked)
+            for compiler_pass in passes:
+                compiler_pass(context)
+            result.diagnostics.extend(context.diagnostics)
+            if (edited := context.edited_source()) is not None:
+                result.rewritten[module.path] = edited
+        return result
+
+
+def load_plugins(plugins: list[str], root: Path, passes: PassManager | None = None) -> PassManager:
+    """Import plugins by module name or by `.py` path relative to `root` and let each register its passes."""
+    passes = passes if passes is not None else PassManager()
+    for plugin in plugins:
+        try:
+            if plugin.endswith(".py"):
+                path = (root / plugin).resolve()
+                spec = importlib.util.spec_from_file_location(f"zinc_plugin_{path.stem}", path)
+                module = importlib.util.module_from_spec(spec)
+                spec.loader.exec_module(module)
+                passes.plugin_files.append(path)
+            else:
+                module = importlib.import_module(plugin)
+        except (ImportError, OSError, SyntaxError) as exc:
+            raise ZincModuleError(f"cannot load compiler plugin '{plugin}': {exc}") from exc
+        register = getattr(module, PLUGIN_REGISTER, None)
+        if not callable(register):
+            raise ZincModuleError(f"compiler plugin '{plugin}' has no {PLUGIN_REGISTER}(passes) function")
+        register(passes)
+    return passes
+
+
+def package_passes(entry_file: Path) -> PassManager:
+    """The passes registered by the plugins in the manifest of the package containing `entry_file`."""
+    root = find_package_root(entry_file.resolve())
+    manifest = package_manifest(root)
+    try:
+        with manifest.open("rb") as handle:
+            build = tomllib.load(handle).get("build", {})
+    except (OSError, tomllib.TOMLDecodeError) as exc:
+        raise ZincModuleError(f"failed to read {manifest}: {exc}") from exc
+    plugins = build.get("plugins", []) if isinstance(build, dict) else []
+    if not isinstance(plugins, list) or not all(isinstance(plugin, str) for plugin in plugins):
+        raise ZincModuleError(f"{manifest}: build.plugins must be a list of module names or .py paths")
+    return load_plugins(plugins, root)
@@ -0,0 +1,495 @@
+"""A syntax tree that round-trips through Zinc source, for property tests.
+
+The compiler works on the parser's concrete trees, which keep every token
+and so can't be built by hand. The nodes here hold only what a program
+means: parentheses, spacing, and line breaks are gone. `parse_program`
+reads source into them, `pretty` prints them back with only the
+parentheses precedence needs, and `random_program` builds random ones. For
+any tree, parsing its printed form must give the same tree back, and so must
+parsing the printed form after `zinc fmt`; a precedence table that disagrees
+with the grammar, or a formatter that changes what code means, breaks that.
+
+The nodes cover functions, the common statements, and the operator,
+postfix, and literal expressions; other syntax is rejected by
+`parse_program`.
+"""
+
+import random
+from dataclasses import dataclass
+
+from antlr4 import ParserRuleContext
+from zinc.modules import parse_source
+from zinc.parser.zincParser import zincParser as ZincParser
+
+INDENT = "    "
+
+# Binary operators by how tightly they bind, from the order of the grammar's expression alternatives.
+BINARY_PRECEDENCE = {
+    **dict.fromkeys(("or", "||"), 2),
+    **dict.fromkeys(("and", "&&"), 3),
+    **dict.fromkeys(("==", "!="), 4),
+    "in": 5,
+    **dict.fromkeys(("<", "<=", ">", ">="), 6),
+    **dict.fromkeys(("..", "..="), 7),
+    "|": 8,
+    "^": 9,
+    "&": 10,
+    **dict.fromkeys(("<<", ">>"), 11),
+    **dict.fromkeys(("+", "-"), 12),
+    **dict.fromkeys(("*", "/", "%"), 13),
+    "**": 14,
+}
+UNARY_PRECEDENCE = 15
+POSTFIX_PRECEDENCE = 16
+ATOM_PRECEDENCE = 17
+RIGHT_ASSOCIATIVE = frozenset({"**"})
+# Ranges print without spaces, the way `zinc fmt` writes them.
+TIGHT_OPERATORS = frozenset({"..", "..="})
+UNARY_OPERATORS = ("-", "!", "~", "not")
+ASSIGNMENT_OPERATORS = ("=", "+=", "-=", "*=", "/=", "%=", "**=", "&=", "|=", "^=", "<<=", ">>=")
+
+BINARY_CONTEXTS = (
+    ZincParser.PowerExprContext,
+    ZincParser.MultiplicativeExprContext,
+    ZincParser.AdditiveExprContext,
+    ZincParser.ShiftExprContext,
+    ZincParser.BitwiseAndExprContext,
+    ZincParser.BitwiseXorExprContext,
+    ZincParser.BitwiseOrExprContext,
+    ZincParser.RangeExprContext,
+    ZincParser.RelationalExprContext,
+    ZincParser.MembershipExprContext,
+    ZincParser.EqualityExprContext,
+    ZincParser.LogicalAndExprContext,
+    ZincParser.LogicalOrExprContext,
+)
+
+
+# --- Expressions ---
+
+
+@dataclass(frozen=True)
+class Literal:
+    """A number, string, boolean, or `nil`, as written."""
+
+    text: str
+
+
+@dataclass(frozen=True)
+class Name:
+    """A variable or function name."""
+
+    name: str
+
+
+@dataclass(frozen=True)
+class Unary:
+    """A prefix operator applied to an operand."""
+
+    op: str
+    operand: "Expr"
+
+
+@dataclass(frozen=True)
+class Binary:
+    """An infix operator applied to two operands."""
+
+    op: str
+    left: "Expr"
+    right: "Expr"
+
+
+@dataclass(frozen=True)
+class Member:
+    """`target.name`."""
+
+    target: "Expr"
+    name: str
+
+
+@dataclass(frozen=True)
+class Index:
+    """`target[index]`."""
+
+    target: "Expr"
+    index: "Expr"
+
+
+@dataclass(frozen=True)
+class Call:
+    """A call with positional arguments."""
+
+    callee: "Expr"
+    args: tuple["Expr", ...]
+
+
+@dataclass(frozen=True)
+class ListLiteral:
+    """`[a, b, c]`."""
+
+    items: tuple["Expr", ...]
+
+
+@dataclass(frozen=True)
+class TupleLiteral:
+    """`(a, b)`, or `(a,)` with one item."""
+
+    items: tuple["Expr", ...]
+
+
+Expr = Literal | Name | Unary | Binary | Member | Index | Call | ListLiteral | TupleLiteral
+
+
+# --- Statements ---
+
+
+@dataclass(frozen=True)
+class Assign:
+    """`target = value`, or a compound assignment like `target += value`."""
+
+    target: Expr
+    op: str
+    value: Expr
+
+
+@dataclass(frozen=True)
+class TypedAssign:
+    """`name: type = value`; the type is kept as its tokens without spaces."""
+
+    name: str
+    type: str
+    value: Expr
+
+
+@dataclass(frozen=True)
+class ExprStatement:
+    """An expression evaluated for its effect, like a call."""
+
+    expr: Expr
+
+
+@dataclass(frozen=True)
+class If:
+    """An `if` with its `else if` branches, as (condition, body) pairs, and an optional `else` body."""
+
+    branches: tuple[tuple[Expr, tuple["Statement", ...]], ...]
+    orelse: tuple["Statement", ...] | None = None
+
+
+@dataclass(frozen=True)
+class While:
+    """`while condition { body }`."""
+
+    condition: Expr
+    body: tuple["Statement", ...]
+
+
+@dataclass(frozen=True)
+class For:
+    """`for name in iterable { body }`."""
+
+    name: str
+    iterable: Expr
+    body: tuple["Statement", ...]
+
+
+@dataclass(frozen=True)
+class Return:
+    """`return`, with or without a value."""
+
+    value: Expr | None = None
+
+
+@dataclass(frozen=True)
+class Break:
+    """`break`."""
+
+
+@dataclass(frozen=True)
+class Continue:
+    """`continue`."""
+
+
+@dataclass(frozen=True)
+class Function:
+    """A function declaration; parameter and return types are kept as their tokens without spaces."""
+
+    name: str
+    params: tuple[tuple[str, str | None], ...]
+    returns: str | None
+    body: tuple["Statement", ...]
+
+
+@dataclass(frozen=True)
+class Program:
+    """The functions of one file, in order."""
+
+    functions: tuple[Function, ...]
+
+
+Statement = Assign | TypedAssign | ExprStatement | If | While | For | Return | Break | Continue
+
+
+# --- Printing ---
+
+
+def precedence(expr: Expr) -> int:
+    """How tightly `expr` binds; an operand that binds looser than its place needs parentheses."""
+    if isinstance(expr, Binary):
+        return BINARY_PRECEDENCE[expr.op]
+    if isinstance(expr, Unary):
+        return UNARY_PRECEDENCE
+    if isinstance(expr, (Member, Index, Call)):
+        return POSTFIX_PRECEDENCE
+    return ATOM_PRECEDENCE
+
+
+def pretty_expr(expr: Expr, min_precedence: int = 0) -> str:
+    """Print `expr`, in parentheses if it binds looser than `min_precedence`."""
+    text = _expr_text(expr)
+    return f"({text})" if precedence(expr) < min_precedence else text
+
+
+def _expr_text(expr: Expr) -> str:
+    if isinstance(expr, Literal):
+        return expr.text
+    if isinstance(expr, Name):
+        return expr.name
+    if isinstance(expr, Unary):
+        separator = " " if expr.op == "not" else ""
+        return f"{expr.op}{separator}{pretty_expr(expr.operand, UNARY_PRECEDENCE)}"
+    if isinstance(expr, Binary):
+        level = BINARY_PRECEDENCE[expr.op]
+        right_associative = expr.op in RIGHT_ASSOCIATIVE
+        left = pretty_expr(expr.left, level + 1 if right_associative else level)
+        right = pretty_expr(expr.right, level if right_associative else level + 1)
+        return f"{left}{expr.op}{right}" if expr.op in TIGHT_OPERATORS else f"{left} {expr.op} {right}"
+    if isinstance(expr, Member):
+        return f"{pretty_expr(expr.target, POSTFIX_PRECEDENCE)}.{expr.name}"
+    if isinstance(expr, Index):
+        return f"{pretty_expr(expr.target, POSTFIX_PRECEDENCE)}[{pretty_expr(expr.index)}]"
+    if isinstance(expr, Call):
+        return f"{pretty_expr(expr.callee, POSTFIX_PRECEDENCE)}({', '.join(pretty_expr(arg) for arg in expr.args)})"
+    if isinstance(expr, ListLiteral):
+        return f"[{', '.join(pretty_expr(item) for item in expr.items)}]"
+    items = ", ".join(pretty_expr(item) for item in expr.items)
+    return f"({items},)" if len(expr.items) == 1 else f"({items})"
+
+
+def _block(body: tuple[Statement, ...], depth: int) -> list[str]:
+    lines = ["{"]
+    for statement in body:
+        lines.extend(_statement_lines(statement, depth + 1))
+    lines.append(f"{INDENT * depth}}}")
+    return lines
+
+
+def _with_block(header: str, body: tuple[Statement, ...], depth: int) -> list[str]:
+    lines = _block(body, depth)
+    lines[0] = f"{INDENT * depth}{header} {lines[0]}"
+    return lines
+
+
+def _statement_lines(statement: Statement, depth: int) -> list[str]:
+    indent = INDENT * depth
+    if isinstance(statement, Assign):
+        return [f"{indent}{pretty_expr(statement.target)} {statement.op} {pretty_expr(statement.value)}"]
+    if isinstance(statement, TypedAssign):
+        return [f"{indent}{statement.name}: {statement.type} = {pretty_expr(statement.value)}"]
+    if isinstance(statement, ExprStatement):
+        return [f"{indent}{pretty_expr(statement.expr)}"]
+    if isinstance(statement, Return):
+        return [f"{indent}return" if statement.value is None else f"{indent}return {pretty_expr(statement.value)}"]
+    if isinstance(statement, Break):
+        return [f"{indent}break"]
+    if isinstance(statement, Continue):
+        return [f"{indent}continue"]
+    if isinstance(statement, While):
+        return _with_block(f"while {pretty_expr(statement.condition)}", statement.body, depth)
+    if isinstance(statement, For):
+        return _with_block(f"for {statement.name} in {pretty_expr(statement.iterable)}", statement.body, depth)
+    lines: list[str] = []
+    for i, (condition, body) in enumerate(statement.branches):
+        branch = _with_block(f"if {pretty_expr(condition)}", body, depth)
+        if i:
+            lines[-1] += f" else {branch[0].lstrip()}"
+            branch = branch[1:]
+        lines.extend(branch)
+    if statement.orelse is not None:
+        orelse = _block(statement.orelse, depth)
+        lines[-1] += f" else {orelse[0]}"
+        lines.extend(orelse[1:])
+    return lines
+
+
+def pretty(program: Program) -> str:
+    """Print `program` as Zinc source, one statement per line, with only the parentheses it needs."""
+    functions = []
+    for function in program.functions:
+        params = ", ".join(name if type_ is None else f"{name}: {type_}" for name, type_ in function.params)
+        returns = "" if function.returns is None else f" -> {function.returns}"
+        functions.append("\n".join(_with_block(f"fn {function.name}({params}){returns}", function.body, 0)))
+    return "\n\n".join(functions) + "\n"
+
+
+# --- Parsing ---
+
+
+def _expr_from_ctx(ctx: ParserRuleContext) -> Expr:
+    if isinstance(ctx, ZincParser.ParenExprContext):
+        return _expr_from_ctx(ctx.expression())
+    if isinstance(ctx, BINARY_CONTEXTS):
+        return Binary(ctx.getChild(1).getText(), _expr_from_ctx(ctx.expression(0)), _expr_from_ctx(ctx.expression(1)))
+    if isinstance(ctx, ZincParser.UnaryExprContext):
+        return Unary(ctx.getChild(0).getText(), _expr_from_ctx(ctx.expression()))
+    if isinstance(ctx, (ZincParser.MemberAccessExprContext, ZincParser.MemberAccessContext)):
+        return Member(_expr_from_ctx(ctx.expression()), ctx.IDENTIFIER().getText())
+    if isinstance(ctx, (ZincParser.IndexAccessExprContext, ZincParser.IndexAccessContext)):
+        return Index(_expr_from_ctx(ctx.expression(0)), _expr_from_ctx(ctx.expression(1)))
+    if isinstance(ctx, ZincParser.FunctionCallExprContext):
+        arguments = ctx.argumentList().argument() if ctx.argumentList() else []
+        if any(argument.getChildCount() != 1 for argument in arguments):
+            raise ValueError(f"unsupported argument in {ctx.getText()!r}")
+        return Call(_expr_from_ctx(ctx.expression()), tuple(_expr_from_ctx(argument.expression()) for argument in arguments))
+    if isinstance(ctx, ZincParser.PrimaryExprContext):
+        primary = ctx.primaryExpression()
+        if primary.literal():
+            return Literal(primary.getText())
+        if primary.IDENTIFIER():
+            return Name(primary.getText())
+        if primary.arrayLiteral():
+            return ListLiteral(tuple(_expr_from_ctx(item) for item in primary.arrayLiteral().expression()))
+        if primary.tupleLiteral():
+            return TupleLiteral(tuple(_expr_from_ctx(item) for item in primary.tupleLiteral().expression()))
+    raise ValueError(f"unsupported expression {ctx.getText()!r}")
+
+
+def _body_from_ctx(ctx: ZincParser.BlockContext) -> tuple[Statement, ...]:
+    return tuple(_statement_from_ctx(statement.getChild(0)) for statement in ctx.statement())
+
+
+def _statement_from_ctx(ctx: ParserRuleContext) -> Statement:
+    if isinstance(ctx, ZincParser.VariableAssignmentContext):
+        target = ctx.assignmentTarget()
+        if target.tupleAssignmentTarget():
+            raise ValueError(f"unsupported assignment target {target.getText()!r}")
+        target_expr = Name(target.getText()) if target.IDENTIFIER() else _expr_from_ctx(target.getChild(0))
+        return Assign(target_expr, ctx.assignmentOperator().getText(), _expr_from_ctx(ctx.expression()))
+    if isinstance(ctx, ZincParser.TypedVariableAssignmentContext) and ctx.typedAssignmentTarget().IDENTIFIER():
+        return TypedAssign(ctx.typedAssignmentTarget().getText(), ctx.type_().getText(), _expr_from_ctx(ctx.expression()))
+    if isinstance(ctx, ZincParser.ExpressionStatementContext):
+        return ExprStatement(_expr_from_ctx(ctx.expression()))
+    if isinstance(ctx, ZincParser.IfStatementContext):
+        conditions, blocks = ctx.expression(), ctx.block()
+        branches = tuple((_expr_from_ctx(condition), _body_from_ctx(block)) for condition, block in zip(conditions, blocks))
+        return If(branches, _body_from_ctx(blocks[-1]) if len(blocks) > len(conditions) else None)
+    if isinstance(ctx, ZincParser.WhileStatementContext):
+        return While(_expr_from_ctx(ctx.expression()), _body_from_ctx(ctx.block()))
+    if isinstance(ctx, ZincParser.ForStatementContext) and ctx.forBinding().IDENTIFIER():
+        return For(ctx.forBinding().getText(), _expr_from_ctx(ctx.expression()), _body_from_ctx(ctx.block()))
+    if isinstance(ctx, ZincParser.ReturnStatementContext):
+        return Return(_expr_from_ctx(ctx.expression()) if ctx.expression() else None)
+    if isinstance(ctx, ZincParser.BreakStatementContext):
+        return Break()
+    if isinstance(ctx, ZincParser.ContinueStatementContext):
+        return Continue()
+    raise ValueError(f"unsupported statement {ctx.getText()!r}")
+
+
+def _function_from_ctx(ctx: ZincParser.FunctionDeclarationContext) -> Function:
+    if ctx.attributeBlock() or ctx.decorator():
+        raise ValueError(f"unsupported attributes on {ctx.functionName().getText()}")
+    params = []
+    for param in ctx.parameterList().parameter() if ctx.parameterList() else []:
+        if param.expression():
+            raise ValueError(f"unsupported default value for {param.IDENTIFIER().getText()}")
+        params.append((param.IDENTIFIER().getText(), param.typeAlternative().getText() if param.typeAlternative() else None))
+    returns = ctx.type_().getText() if ctx.type_() else None
+    return Function(ctx.functionName().getText(), tuple(params), returns, _body_from_ctx(ctx.block()))
+
+
+def parse_program(source: str, origin: str = "<source>") -> Program:
+    """Parse `source` into a tree; raises `ValueError` for syntax the tree can't hold."""
+    tree, _ = parse_source(source, origin)
+    functions = []
+    for statement in tree.statement():
+        if not statement.functionDeclaration():
+            raise ValueError(f"unsupported top-level statement {statement.getText()!r}")
+        functions.append(_function_from_ctx(statement.functionDeclaration()))
+    return Program(tuple(functions))
+
+
+# --- Generation ---
+
+NAMES = ("a", "b", "count", "items", "total", "x", "y")
+LITERALS = ("0", "1", "42", "1_000", "0xff", "7i32", "2.5", "1e3", '"text"', '"hi {x}"', "true", "false", "nil")
+TYPES = ("i64", "f64", "bool", "string", "[i64]", "list<i64>")
+
+
+class _Generator:
+    def __init__(self, rng: random.Random) -> None:
+        self.rng = rng
+
+    def expr(self, depth: int) -> Expr:
+        rng = self.rng
+        if depth <= 0 or rng.random() < 0.25:
+            return Literal(rng.choice(LITERALS)) if rng.random() < 0.5 else Name(rng.choice(NAMES))
+        kind = rng.randrange(8)
+        if kind <= 2:
+            return Binary(rng.choice(tuple(BINARY_PRECEDENCE)), self.expr(depth - 1), self.expr(depth - 1))
+        if kind == 3:
+            return Unary(rng.choice(UNARY_OPERATORS), self.expr(depth - 1))
+        if kind == 4:
+            return Member(self.expr(depth - 1), rng.choice(NAMES))
+        if kind == 5:
+            return Index(self.expr(depth - 1), self.expr(depth - 1))
+        if kind == 6:
+            return Call(self.expr(depth - 1), self.exprs(depth - 1))
+        items = self.exprs(depth - 1)
+        return ListLiteral(items) if rng.random() < 0.5 or not items else TupleLiteral(items)
+
+    def exprs(self, depth: int) -> tuple[Expr, ...]:
+        return tuple(self.expr(depth) for _ in range(self.rng.randrange(4)))
+
+    def target(self) -> Expr:
+        target: Expr = Name(self.rng.choice(NAMES))
+        for _ in range(self.rng.randrange(3)):
+            target = Member(target, self.rng.choice(NAMES)) if self.rng.random() < 0.5 else Index(target, self.expr(1))
+        return target
+
+    def body(self, depth: int) -> tuple[Statement, ...]:
+        # Statements that can't be followed on the next line, because the parser would read on into it, come last.
+        statements = [self.statement(depth) for _ in range(self.rng.randint(1, 3))]
+        if self.rng.random() < 0.3:
+            statements.append(self.rng.choice((Return(), Return(self.expr(2)), Break(), Continue())))
+        return tuple(statements)
+
+    def statement(self, depth: int) -> Statement:
+        rng = self.rng
+        kind = rng.randrange(7) if depth > 0 else rng.randrange(3)
+        if kind == 0:
+            return Assign(self.target(), rng.choice(ASSIGNMENT_OPERATORS), self.expr(3))
+        if kind == 1:
+            return TypedAssign(rng.choice(NAMES), rng.choice(TYPES), self.expr(3))
+        if kind == 2:
+            # An expression statement starts with a name, or it would continue the statement before it.
+            return ExprStatement(Call(Name(rng.choice(NAMES)), self.exprs(2)))
+        if kind == 3:
+            branches = tuple((self.expr(3), self.body(depth - 1)) for _ in range(rng.randint(1, 3)))
+            return If(branches, self.body(depth - 1) if rng.random() < 0.5 else None)
+        if kind == 4:
+            return While(self.expr(3), self.body(depth - 1))
+        if kind == 5:
+            return For(rng.choice(NAMES), self.expr(3), self.body(depth - 1))
+        return ExprStatement(Call(Member(Name(rng.choice(NAMES)), rng.choice(NAMES)), self.exprs(2)))
+
+    def function(self, name: str) -> Function:
+        params = tuple((param, self.rng.choice((None, *TYPES))) for param in self.rng.sample(NAMES, self.rng.randrange(3)))
+        returns = self.rng.choice((None, *TYPES))
+        return Function(name, params, returns, self.body(2))
+
+
+def random_program(rng: random.Random, functions: int = 2) -> Program:
+    """Build a random program of `functions` functions; it parses, but needn't type check."""
+    generator = _Generator(rng)
+    return Program(tuple(generator.function(f"f{i}") for i in range(functions)))
@@ -0,0 +1,163 @@
+"""Per-function timing behind `zinc build --profile` and `zinc run --profile`.
+
+A profiled build times every call of every function and method. The counters
+live in a small Rust module, `__zinc_profile`, added to the generated crate;
+each instrumented function starts by creating a guard that records its time
+when it is dropped. When the program ends, by returning from `main`, calling
+`exit()`, failing an assertion, or panicking, it prints a report of its hot
+functions to stderr, or writes it as JSON with `--profile-json`.
+
+A function's total time includes the functions it calls and its self time
+doesn't, so self time is what points at slow code. Recursive calls count
+toward the outermost call's total once, and calls still running when the
+program exits count as finished. Async functions and lambdas aren't timed;
+their time shows up in their caller's self time.
+"""
+
+from dataclasses import dataclass
+from pathlib import Path
+
+PROFILE_MODULE = "__zinc_profile"
+
+
+@dataclass(frozen=True)
+class ProfilingOptions:
+    """A profiled build; the report is printed to stderr unless `json_path` names a file to write it to."""
+
+    json_path: Path | None = None
+
+
+def profile_call(function_id: int) -> str:
+    """The statement that starts timing a call of the function numbered `function_id`."""
+    return f"let __zinc_profile_call = {PROFILE_MODULE}::enter({function_id});"
+
+
+def profile_session() -> str:
+    """The first statement of `main`, which reports the timings when `main` returns or panics."""
+    return f"let __zinc_profile_session = {PROFILE_MODULE}::Session;"
+
+
+def profile_report() -> str:
+    """The statement that reports the timings before the program exits without unwinding."""
+    return f"{PROFILE_MODULE}::report();"
+
+
+def _rust_str(text: str) -> str:
+    return '"' + text.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def profile_module(functions: list[tuple[str, str]], options: ProfilingOptions) -> str:
+    """The Rust module that keeps the timings of `functions`, each a (name, location) pair, and reports them."""
+    entries = "".join(f"\n        ({_rust_str(name)}, {_rust_str(location)})," for name, location in functions)
+    json_path = f"Some({_rust_str(str(options.json_path.resolve()))})" if options.json_path is not None else "None"
+    count = len(functions)
+    return f"""\
+mod {PROFILE_MODULE} {{
+    use std::cell::RefCell;
+    use std::sync::{{Mutex, Once}};
+    use std::time::{{Duration, Instant}};
+
+    const FUNCTIONS: [(&str, &str); {count}] = [{entries}
+    ];
+    const JSON_PATH: Option<&str> = {json_path};
+
+    #[derive(Clone, Copy)]
+    struct Timing {{
+        calls: u64,
+        total: Duration,
+        own: Duration,
+    }}
+
+    static TIMINGS: Mutex<[Timing; {count}]> = Mutex::new([Timing {{ calls: 0, total: Duration::ZERO, own: Duration::ZERO }}; {count}]);
+    static REPORTED: Once = Once::new();
+
+    struct Active {{
+        id: usize,
+        start: Instant,
+        callees: Duration,
+    }}
+
+    thread_local! {{
+        static ACTIVE: RefCell<Vec<Active>> = const {{ RefCell::new(Vec::new()) }};
+    }}
+
+    pub struct Call;
+
+    pub fn enter(id: usize) -> Call {{
+        ACTIVE.with(|active| active.borrow_mut().push(Active {{ id, start: Instant::now(), callees: Duration::ZERO }}));
+        Call
+    }}
+
+    impl Drop for Call {{
+        fn drop(&mut self) {{
+            leave();
+        }}
+    }}
+
+    /// Record the innermost running call as finished, returning false if there is none.
+    fn leave() -> bool {{
+        ACTIVE.with(|active| {{
+            let mut active = active.borrow_mut();
+            let Some(call) = active.pop() else {{ return false }};
+            let elapsed = call.start.elapsed();
+            let recursive = active.iter().any(|outer| outer.id == call.id);
+            if let Some(caller) = active.last_mut() {{
+                caller.callees += elapsed;
+            }}
+            let mut timings = TIMINGS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let timing = &mut timings[call.id];
+            timing.calls += 1;
+            timing.own += elapsed.saturating_sub(call.callees);
+            if !recursive {{
+                timing.total += elapsed;
+            }}
+            true
+        }})
+    }}
+
+    pub struct Session;
+
+    impl Drop for Session {{
+        fn drop(&mut self) {{
+            report();
+        }}
+    }}
+
+    pub fn report() {{
+        REPORTED.call_once(|| {{
+            // `exit()` ends the program inside the calls that led to it; count them as finished.
+            while leave() {{}}
+            let timings = *TIMINGS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let mut hot: Vec<usize> = (0..FUNCTIONS.len()).filter(|&id| timings[id].calls > 0).collect();
+            hot.sort_by(|&a, &b| timings[b].own.cmp(&timings[a].own));
+            let Some(path) = JSON_PATH else {{
+                eprintln!("{{:>12}} {{:>12}} {{:>10}}  function", "self ms", "total ms", "calls");
+                for id in hot {{
+                    let (name, location) = FUNCTIONS[id];
+                    let timing = timings[id];
+                    let (own, total) = (timing.own.as_secs_f64() * 1e3, timing.total.as_secs_f64() * 1e3);
+                    eprintln!("{{own:>12.3}} {{total:>12.3}} {{:>10}}  {{name}} ({{location}})", timing.calls);
+                }}
+                return;
+            }};
+            let rows: Vec<String> = hot
+                .iter()
+                .map(|&id| {{
+                    let (name, location) = FUNCTIONS[id];
+                    let timing = timings[id];
+                    format!(
+                        "{{{{\\"name\\":{{:?}},\\"location\\":{{:?}},\\"calls\\":{{}},\\"total_ns\\":{{}},\\"self_ns\\":{{}}}}}}",
+                        name,
+                        location,
+                        timing.calls,
+                        timing.total.as_nanos(),
+                        timing.own.as_nanos()
+                    )
+                }})
+                .collect();
+            if let Err(error) = std::fs::write(path, format!("{{{{\\"functions\\":[{{}}]}}}}\\n", rows.join(","))) {{
+                eprintln!("zinc profile: can't write {{path}}: {{error}}");
+            }}
+        }});
+    }}
+}}"""
@@ -0,0 +1,188 @@
+"""Project manifests for building whole packages.
+
+A `zinc.toml` is a superset of `pkg.toml`: besides `[package]` it can declare
+binary targets, a library entry module, path dependencies on other Zinc
+packages, and output settings:
+
+    [package]
+    name = "app"
+    version = "0.1.0"
+
+    [[bin]]
+    name = "server"
+    path = "server.zn"
+
+    [lib]
+    path = "lib.zn"
+
+    [dependencies]
+    util = { path = "../util" }
+
+    [build]
+    out-dir = "bin"
+    release = true
+
+    [profile.release]
+    opt-level = 3
+    lto = "thin"
+    panic = "abort"
+
+Without `[[bin]]` or `[lib]` tables, `main.zn` is a binary named after the
+package and `lib.zn` is the library.
+"""
+
+import tomllib
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from zinc.build import BUILD_DIR_NAME, DEBUG, RELEASE, BuildProfile, profile_setting
+from zinc.exceptions import ZincBuildError, ZincModuleError
+from zinc.modules import PROJECT_FILE_NAME, package_manifest, read_path_dependencies
+
+DEFAULT_BIN_FILE = "main.zn"
+DEFAULT_LIB_FILE = "lib.zn"
+DEFAULT_OUT_DIR = "bin"
+
+
+@dataclass(frozen=True)
+class BinTarget:
+    """A binary built from an entry file with `fn main()`."""
+
+    name: str
+    path: Path
+
+
+@dataclass
+class Project:
+    """A package and the targets it builds."""
+
+    root: Path
+    name: str
+    version: str
+    bins: list[BinTarget]
+    lib: Path | None
+    dependencies: dict[str, Path]
+    out_dir: Path
+    release: bool
+    profiles: dict[str, BuildProfile] = field(default_factory=lambda: {"release": RELEASE, "dev": DEBUG})
+
+    def bin(self, name: str | None = None) -> BinTarget:
+        """Select a binary target by name; the name may be omitted when there is exactly one."""
+        if name is None:
+            if len(self.bins) != 1:
+                choices = ", ".join(target.name for target in self.bins) or "none"
+                raise ZincModuleError(f"project '{self.name}' has {len(self.bins)} binaries ({choices}); choose one with --bin")
+            return self.bins[0]
+        for target in self.bins:
+            if target.name == name:
+                return target
+        raise ZincModuleError(f"project '{self.name}' has no binary named '{name}'")
+
+    def profile(self, release: bool | None = None) -> BuildProfile:
+        """The configured Cargo profile; `None` picks the one named by [build] release."""
+        return self.profiles["release" if (self.release if release is None else release) else "dev"]
+
+    def build_dir(self, target: BinTarget) -> Path:
+        """Cargo project directory for a binary target."""
+        return self.root / BUILD_DIR_NAME / target.name
+
+
+def is_project_path(path: Path) -> bool:
+    """Return True for a package directory or a manifest file, as opposed to a source file."""
+    return path.is_dir() or path.suffix == ".toml"
+
+
+def load_project(path: Path) -> Project:
+    """Load the project rooted at a directory or described by a manifest file."""
+    root = (path.parent if path.is_file() else path).resolve()
+    manifest = path.resolve() if path.is_file() else package_manifest(root)
+    if manifest is None:
+        raise ZincModuleError(f"missing {PROJECT_FILE_NAME} in {root}")
+    try:
+        with manifest.open("rb") as handle:
+            data = tomllib.load(handle)
+    except (OSError, tomllib.TOMLDecodeError) as exc:
+        raise ZincModuleError(f"failed to read {manifest}: {exc}") from exc
+
+    package = data.get("package")
+    if not isinstance(package, dict) or not isinstance(package.get("name"), str) or not isinstance(package.get("version"), str):
+        raise ZincModuleError(f"{manifest} must define package.name and package.version")
+
+    bins = _read_bins(manifest, root, data.get("bin"), package["name"])
+    lib = _read_lib(manifest, root, data.get("lib"))
+    if not bins and lib is None:
+        raise ZincModuleError(f"{manifest} declares no [[bin]] or [lib] targets and {root} has no {DEFAULT_BIN_FILE} or {DEFAULT_LIB_FILE}")
+
+    build = data.get("build", {})
+    if not isinstance(build, dict):
+        raise ZincModuleError(f"{manifest}: [build] must be a table")
+    out_dir = build.get("out-dir", DEFAULT_OUT_DIR)
+    release = build.get("release", True)
+    if not isinstance(out_dir, str) or not isinstance(release, bool):
+        raise ZincModuleError(f"{manifest}: build.out-dir must be a string and build.release a boolean")
+
+    return Project(
+        root=root,
+        name=package["name"],
+        version=package["version"],
+        bins=bins,
+        lib=lib,
+        dependencies=read_path_dependencies(manifest),
+        out_dir=root / out_dir,
+        release=release,
+        profiles=_read_profiles(manifest, data.get("profile", {})),
+    )
+
+
+def _read_profiles(manifest: Path, tables) -> dict[str, BuildProfile]:
+    if not isinstance(tables, dict) or not all(isinstance(table, dict) for table in tables.values()):
+        raise ZincModuleError(f"{manifest}: profiles are declared as [profile.release] and [profile.dev] tables")
+    unknown = sorted(set(tables) - {"release", "dev"})
+    if unknown:
+        raise ZincModuleError(f"{manifest}: unknown profile '{unknown[0]}'; expected release or dev")
+    profiles = {}
+    for name, base in (("release", RELEASE), ("dev", DEBUG)):
+        try:
+            settings = {key.replace("-", "_"): profile_setting(key, value) for key, value in tables.get(name, {}).items()}
+        except ZincBuildError as exc:
+            raise ZincModuleError(f"{manifest}: [profile.{name}] {exc}") from exc
+        profiles[name] = BuildProfile(release=base.release, **settings)
+    return profiles
+
+
+def _read_bins(manifest: Path, root: Path, tables, package_name: str) -> list[BinTarget]:
+    if tables is None:
+        default = root / DEFAULT_BIN_FILE
+        return [BinTarget(package_name, default)] if default.exists() else []
+    if not isinstance(tables, list):
+        raise ZincModuleError(f"{manifest}: binaries are declared as [[bin]] tables")
+
+    bins: list[BinTarget] = []
+    for table in tables:
+        name = table.get("name")
+        if not isinstance(name, str) or not name:
+            raise ZincModuleError(f"{manifest}: every [[bin]] needs a name")
+        if any(target.name == name for target in bins):
+            raise ZincModuleError(f"{manifest}: duplicate binary name '{name}'")
+        bins.append(BinTarget(name, _source_path(manifest, root, table.get("path"), f"bin '{name}'")))
+    return bins
+
+
+def _read_lib(manifest: Path, root: Path, table) -> Path | None:
+    if table is None:
+        default = root / DEFAULT_LIB_FILE
+        return default if default.exists() else None
+    if not isinstance(table, dict):
+        raise ZincModuleError(f"{manifest}: [lib] must be a table")
+    return _source_path(manifest, root, table.get("path", DEFAULT_LIB_FILE), "lib")
+
+
+def _source_path(manifest: Path, root: Path, relative, label: str) -> Path:
+    if not isinstance(relative, str):
+        raise ZincModuleError(f"{manifest}: {label} needs a path")
+    path = (root / relative).resolve()
+    if path.suffix != ".zn" or not path.is_relative_to(root):
+        raise ZincModuleError(f"{manifest}: {label} path must be a .zn file inside the package: {relative}")
+    if not path.exists():
+        raise ZincModuleError(f"{manifest}: {label} path does not exist: {relative}")
+    return path
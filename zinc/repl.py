@@ -0,0 +1,161 @@
+"""Interactive REPL built on incremental whole-program recompilation.
+
+Each accepted input is appended to the session: declarations (functions,
+structs, imports, ...) go to the top level and everything else becomes a
+statement in `main()`. The session program is rebuilt and rerun after every
+input and only the output produced by the newest input is shown. Inputs that
+fail to compile, build, or run are reported and dropped from the session.
+"""
+
+import contextlib
+import io
+import subprocess
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from zinc.build import BUILD_DIR_NAME, DEBUG, build_binary
+from zinc.codegen import RustProgram
+from zinc.exceptions import ZincError
+
+ENTRY_FILE_NAME = "repl.zn"
+DECLARATION_PREFIXES = ("fn ", "async fn ", "struct ", "enum ", "const ", "import ", "extern ", "type ", "@", "#[")
+BRACKET_PAIRS = {"(": ")", "[": "]", "{": "}"}
+
+
+def is_complete(text: str) -> bool:
+    """Return True when every bracket opened in `text` has been closed."""
+    depth = 0
+    quote = None
+    escaped = False
+    for char in text:
+        if quote is not None:
+            if escaped:
+                escaped = False
+            elif char == "\\" and quote == '"':
+                escaped = True
+            elif char == quote:
+                quote = None
+            continue
+        if char in ('"', "`"):
+            quote = char
+        elif char in BRACKET_PAIRS:
+            depth += 1
+        elif char in BRACKET_PAIRS.values():
+            depth -= 1
+    return depth <= 0 and quote is None
+
+
+def is_declaration(text: str) -> bool:
+    """Return True for inputs that belong at module level rather than in main()."""
+    return text.lstrip().startswith(DECLARATION_PREFIXES)
+
+
+def _indent(text: str) -> str:
+    return "\n".join(f"    {line}" if line else line for line in text.splitlines())
+
+
+@dataclass
+class ReplResult:
+    """Outcome of one REPL input."""
+
+    output: str = ""
+    error: str | None = None
+
+
+@dataclass
+class ReplSession:
+    """Accumulated REPL state plus the scratch package it is compiled in."""
+
+    workdir: Path
+    compile_program: Callable[[Path], RustProgram]
+    declarations: list[str] = field(default_factory=list)
+    statements: list[str] = field(default_factory=list)
+    _seen_output: str = ""
+
+    def __post_init__(self) -> None:
+        self.workdir.mkdir(parents=True, exist_ok=True)
+        (self.workdir / "pkg.toml").write_text('[package]\nname = "repl"\nversion = "0.1.0"\n')
+
+    @property
+    def entry(self) -> Path:
+        return self.workdir / ENTRY_FILE_NAME
+
+    def render_source(self, declarations: list[str], statements: list[str]) -> str:
+        """Render session state as a Zinc program."""
+        main = "\n".join(["fn main() {", *(_indent(statement) for statement in statements), "}"])
+        return "\n\n".join([*declarations, main]) + "\n"
+
+    def submit(self, text: str) -> ReplResult:
+        """Evaluate one complete input, keeping it in the session when it succeeds."""
+        text = text.strip()
+        if not text:
+            return ReplResult()
+        if is_declaration(text):
+            candidates = [(self.declarations + [text], self.statements)]
+        else:
+            # Bare expressions echo their value; anything that is not a value
+            # (assignments, loops, void calls) is retried as a plain statement.
+            candidates = [
+                (self.declarations, self.statements + [f"print_debug({text})"]),
+                (self.declarations, self.statements + [text]),
+            ]
+        error = None
+        for index, (declarations, statements) in enumerate(candidates):
+            quiet = index + 1 < len(candidates)
+            program, error = self._compile(declarations, statements, quiet=quiet)
+            if program is None:
+                continue
+            return self._build_and_run(program, declarations, statements)
+        return ReplResult(error=error)
+
+    def _compile(self, declarations: list[str], statements: list[str], quiet: bool) -> tuple[RustProgram | None, str | None]:
+        self.entry.write_text(self.render_source(declarations, statements))
+        stderr = io.StringIO() if quiet else None
+        try:
+            with contextlib.redirect_stderr(stderr) if stderr is not None else contextlib.nullcontext():
+                return self.compile_program(self.entry), None
+        except ZincError as exc:
+            return None, str(exc)
+
+    def _build_and_run(self, program: RustProgram, declarations: list[str], statements: list[str]) -> ReplResult:
+        try:
+            binary = build_binary(program, self.entry, build_dir=self.workdir / BUILD_DIR_NAME, profile=DEBUG)
+        except ZincError as exc:
+            return ReplResult(error=str(exc))
+        result = subprocess.run([str(binary)], capture_output=True, text=True)
+        if result.returncode != 0:
+            return ReplResult(output=self._new_output(result.stdout), error=result.stderr.strip() or f"exited with status {result.returncode}")
+        output = self._new_output(result.stdout)
+        self._seen_output = result.stdout
+        self.declarations = declarations
+        self.statements = statements
+        return ReplResult(output=output)
+
+    def _new_output(self, stdout: str) -> str:
+        if stdout.startswith(self._seen_output):
+            return stdout[len(self._seen_output) :]
+        # Earlier inputs printed something different this time (e.g. random values).
+        seen_lines = self._seen_output.count("\n")
+        return "".join(stdout.splitlines(keepends=True)[seen_lines:])
+
+
+def run_repl(session: ReplSession, read: Callable[[str], str] = input, write: Callable[[str], None] = print) -> None:
+    """Read inputs until EOF, printing each one's output or error."""
+    write("Zinc REPL. Enter statements, expressions, or declarations; Ctrl-D to exit.")
+    while True:
+        try:
+            text = read(">>> ")
+            while not is_complete(text):
+                text += "\n" + read("... ")
+        except EOFError:
+            write("")
+            return
+        except KeyboardInterrupt:
+            write("")
+            continue
+        result = session.submit(text)
+        if result.output:
+            write(result.output.rstrip("\n"))
+        if result.error:
+            write(f"error: {result.error}")
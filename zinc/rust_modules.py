@@ -0,0 +1,77 @@
+"""Lay out generated Rust as one module per imported Zinc file.
+
+Top-level names are already unique across the program (each carries its
+module's prefix), so the split only has to keep everything reachable: the
+crate root declares the module tree and glob-imports every module's items,
+and each module glob-imports the crate root. Items in module files are made
+`pub(crate)` so their siblings and the root can see them.
+
+A module id such as `net/http/client` becomes `src/net/http/client.rs`, with
+`src/net.rs` and `src/net/http.rs` declaring the submodules below them.
+"""
+
+import re
+
+from zinc.rust_names import RUST_KEYWORDS
+
+# Names a root module can't take without shadowing a crate or breaking the path syntax.
+RESERVED_MODULE_NAMES = RUST_KEYWORDS | {"std", "core", "alloc", "proc_macro", "test", "tokio", "zinc_internal"}
+
+_ITEM_START_RE = re.compile(r"(?:async fn|fn|struct|enum|const|static|type|trait) ")
+_INHERENT_MEMBER_RE = re.compile(r"    (?:async fn|fn|const) ")
+
+
+def module_name(part: str, reserved: frozenset[str] | set[str] = RESERVED_MODULE_NAMES) -> str:
+    """A Rust module name for one segment of a Zinc module id."""
+    name = re.sub(r"\W", "_", part, flags=re.ASCII)
+    if not name or name[0].isdigit() or name in reserved:
+        name = f"zn_{name}"
+    return name
+
+
+def module_path(module_id: str, reserved: frozenset[str] | set[str] = RESERVED_MODULE_NAMES) -> tuple[str, ...]:
+    """The Rust module path a Zinc module id is written to."""
+    return tuple(module_name(part, reserved) for part in module_id.split("/"))
+
+
+def crate_visible(item: str) -> str:
+    """Make a top-level item, its fields, and its inherent methods visible to the rest of the crate."""
+    lines = []
+    block = None
+    for line in item.split("\n"):
+        if line and not line[0].isspace():
+            if _ITEM_START_RE.match(line):
+                line = f"pub(crate) {line}"
+            if line.endswith("{"):
+                block = "struct" if line.startswith("pub(crate) struct ") else "impl" if _is_inherent_impl(line) else None
+            elif line == "}":
+                block = None
+        elif block == "struct" and re.match(r"    \w", line) and not line.startswith("    pub"):
+            line = f"    pub(crate) {line[4:]}"
+        elif block == "impl" and _INHERENT_MEMBER_RE.match(line):
+            line = f"    pub(crate) {line[4:]}"
+        lines.append(line)
+    return "\n".join(lines)
+
+
+def _is_inherent_impl(line: str) -> bool:
+    """Trait impls inherit the trait's visibility and reject their own."""
+    return (line.startswith("impl ") or line.startswith("impl<")) and " for " not in line
+
+
+def module_declarations(paths: list[tuple[str, ...]]) -> dict[tuple[str, ...], list[str]]:
+    """`mod` lines for each file in the module tree, keyed by the declaring module's path; `()` is the root."""
+    children: dict[tuple[str, ...], set[str]] = {}
+    for path in paths:
+        for depth in range(len(path)):
+            children.setdefault(path[:depth], set()).add(path[depth])
+            children.setdefault(path[: depth + 1], set())
+    return {
+        parent: [f"{'mod' if not parent else 'pub(crate) mod'} {name};" for name in sorted(names)]
+        for parent, names in children.items()
+    }
+
+
+def module_file_name(path: tuple[str, ...]) -> str:
+    """The file under `src/` that holds a module."""
+    return "/".join(path) + ".rs"
@@ -0,0 +1,35 @@
+"""Spell Zinc identifiers as Rust identifiers.
+
+Zinc reserves fewer words than Rust, so a program may call a variable `let`, a
+field `mut`, or a method `move`. Generated code writes those as raw identifiers
+(`r#let`), which Rust treats as ordinary names. `crate`, `super`, and `Self`
+can't be raw identifiers, so they take a trailing underscore instead, with one
+more for as long as the program already uses the result.
+"""
+
+from collections.abc import Set
+
+# Strict and reserved keywords of Rust 2021, plus `gen` from 2024.
+RUST_KEYWORDS = frozenset(
+    {
+        *("as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn", "for"),
+        *("if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static"),
+        *("struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do"),
+        *("final", "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield", "gen"),
+    }
+)
+
+# Keywords that stay keywords even as `r#name`.
+_NON_RAW_KEYWORDS = frozenset({"crate", "self", "Self", "super"})
+
+
+def rust_identifier(name: str, taken: Set[str] = frozenset()) -> str:
+    """The Rust spelling of a Zinc identifier; renamed keywords avoid every name in `taken`."""
+    if name not in RUST_KEYWORDS:
+        return name
+    if name not in _NON_RAW_KEYWORDS:
+        return f"r#{name}"
+    renamed = f"{name}_"
+    while renamed in taken:
+        renamed += "_"
+    return renamed
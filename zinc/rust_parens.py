@@ -0,0 +1,341 @@
+"""Drop parentheses from generated Rust that its operator precedence makes redundant.
+
+Expression lowering wraps every binary and unary expression in parentheses so
+rendered fragments can be spliced anywhere (`&{value}`, `{value}.clone()`,
+`{value} as f64`). Once a function is fully rendered this pass lexes it and
+removes each group whose contents would parse the same without it:
+
+    self._balance = (self._balance + amount);   ->  self._balance = self._balance + amount;
+    let d = (((1.0 * 2.0)) + ((3 * 4) as f64)); ->  let d = 1.0 * 2.0 + (3 * 4) as f64;
+
+Groups are decided outermost first, so an inner group is judged against the
+neighbours it ends up with. Tuples, call and macro argument lists, and any
+group the pass cannot classify are kept.
+"""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+
+# Binding power of Rust's binary operators; higher binds tighter.
+BINARY_PRECEDENCE = {
+    "*": 12,
+    "/": 12,
+    "%": 12,
+    "+": 11,
+    "-": 11,
+    "<<": 10,
+    ">>": 10,
+    "&": 9,
+    "^": 8,
+    "|": 7,
+    "==": 6,
+    "!=": 6,
+    "<": 6,
+    ">": 6,
+    "<=": 6,
+    ">=": 6,
+    "&&": 5,
+    "||": 4,
+    "..": 3,
+    "..=": 3,
+}
+ARITHMETIC_LEVELS = frozenset({11, 12})
+BITWISE_LEVELS = frozenset({7, 8, 9, 10})
+CAST_PRECEDENCE = 13
+UNARY_PRECEDENCE = 14
+ATOM_PRECEDENCE = 15
+# Comparisons and ranges do not chain, so equal precedence on either side still needs parentheses.
+NON_ASSOCIATIVE = frozenset({6, 3})
+ASSIGNMENT_OPERATORS = frozenset({"=", "+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "<<=", ">>="})
+UNARY_OPERATORS = frozenset({"-", "!", "*", "&", "&&"})
+# Expressions that start with these keywords parse differently in statement and condition positions.
+EXPRESSION_KEYWORDS = frozenset({"if", "match", "loop", "while", "for", "unsafe", "async", "move", "return", "break", "continue", "let"})
+NON_OPERAND_KEYWORDS = EXPRESSION_KEYWORDS | {"as", "in", "else", "mut", "ref", "dyn", "impl", "where", "fn", "pub", "use"}
+# Positions where a group is a whole expression, bounded on the left.
+OPEN_LEFT = frozenset({"(", "[", ",", "=>", ":", "return", "break"}) | ASSIGNMENT_OPERATORS
+CONDITION_KEYWORDS = frozenset({"if", "while", "match", "in"})
+STATEMENT_STARTS = frozenset({"{", ";", "}"})
+OPEN_RIGHT = frozenset({")", "]", ",", ";", "}"})
+POSTFIX_STARTS = frozenset({".", "[", "?"})
+
+# Multi-character punctuation, longest first so `..=` wins over `..`.
+_PUNCTUATION = sorted("..= ... <<= >>= :: -> => == != <= >= && || += -= *= /= %= ^= &= |= << >> ..".split(), key=len, reverse=True)
+_IDENTIFIER_RE = re.compile(r"(?:r#)?(?:[^\W\d]|\$)\w*")
+_NUMBER_RE = re.compile(
+    r"0[xob][0-9A-Fa-f_]+(?:[iu](?:8|16|32|64|128|size))?"
+    r"|[0-9][0-9_]*(?:\.[0-9][0-9_]*)?(?:[eE][+-]?[0-9_]+)?(?:_?[A-Za-z][A-Za-z0-9]*)?"
+)
+_TUPLE_INDEX_RE = re.compile(r"[0-9]+")
+_RAW_STRING_RE = re.compile(r'b?r(#*)"')
+
+
+@dataclass(frozen=True)
+class _Token:
+    kind: str  # "ident", "number", "string", "char", "punct"
+    text: str
+    start: int
+
+
+def remove_redundant_parens(source: str) -> str:
+    """Return `source` with every parenthesis group that does not affect parsing removed."""
+    tokens = _tokenize(source)
+    if tokens is None:
+        return source
+    pairs = _paren_pairs(tokens)
+    if pairs is None:
+        return source
+    removed: set[int] = set()
+    for open_index in sorted(pairs):
+        close_index = pairs[open_index]
+        if _is_redundant(tokens, open_index, close_index, removed):
+            removed.update((open_index, close_index))
+    if not removed:
+        return source
+    drop = {tokens[index].start for index in removed}
+    return "".join(char for offset, char in enumerate(source) if offset not in drop)
+
+
+def _tokenize(source: str) -> list[_Token] | None:
+    """Lex Rust source into the tokens this pass cares about, skipping whitespace and comments."""
+    tokens: list[_Token] = []
+    index = 0
+    length = len(source)
+    while index < length:
+        char = source[index]
+        if char.isspace():
+            index += 1
+        elif source.startswith("//", index):
+            end = source.find("\n", index)
+            index = length if end == -1 else end
+        elif source.startswith("/*", index):
+            end = source.find("*/", index + 2)
+            if end == -1:
+                return None
+            index = end + 2
+        elif (raw := _RAW_STRING_RE.match(source, index)) is not None:
+            closing = '"' + raw.group(1)
+            end = source.find(closing, raw.end())
+            if end == -1:
+                return None
+            tokens.append(_Token("string", source[index : end + len(closing)], index))
+            index = end + len(closing)
+        elif char == '"' or source.startswith('b"', index):
+            end = _string_end(source, source.index('"', index) + 1)
+            if end is None:
+                return None
+            tokens.append(_Token("string", source[index:end], index))
+            index = end
+        elif char == "'" or source.startswith("b'", index):
+            quote = source.index("'", index)
+            if source.startswith("\\", quote + 1) or source.startswith("'", quote + 2):
+                end = _char_end(source, quote + 1)
+                if end is None:
+                    return None
+                tokens.append(_Token("char", source[index:end], index))
+                index = end
+            else:
+                # A lifetime or loop label.
+                match = _IDENTIFIER_RE.match(source, quote + 1)
+                end = match.end() if match else quote + 1
+                tokens.append(_Token("ident", source[index:end], index))
+                index = end
+        elif char.isdigit():
+            after_dot = bool(tokens) and tokens[-1].text == "."
+            match = (_TUPLE_INDEX_RE if after_dot else _NUMBER_RE).match(source, index)
+            end = match.end()
+            tokens.append(_Token("number", source[index:end], index))
+            index = end
+        elif (match := _IDENTIFIER_RE.match(source, index)) is not None:
+            tokens.append(_Token("ident", match.group(), index))
+            index = match.end()
+        else:
+            text = next((punct for punct in _PUNCTUATION if source.startswith(punct, index)), char)
+            tokens.append(_Token("punct", text, index))
+            index += len(text)
+    return tokens
+
+
+def _string_end(source: str, index: int) -> int | None:
+    """Return the offset just past the closing quote of a string whose body starts at `index`."""
+    while index < len(source):
+        if source[index] == "\\":
+            index += 2
+        elif source[index] == '"':
+            return index + 1
+        else:
+            index += 1
+    return None
+
+
+def _char_end(source: str, index: int) -> int | None:
+    """Return the offset just past the closing quote of a char literal whose body starts at `index`."""
+    end = source.find("'", index + 2 if source.startswith("\\", index) else index + 1)
+    return None if end == -1 else end + 1
+
+
+def _paren_pairs(tokens: list[_Token]) -> dict[int, int] | None:
+    """Map the index of every `(` token to its matching `)`, or None when brackets do not balance."""
+    pairs: dict[int, int] = {}
+    stack: list[int] = []
+    closers = {")": "(", "]": "[", "}": "{"}
+    for index, token in enumerate(tokens):
+        if token.kind != "punct":
+            continue
+        if token.text in ("(", "[", "{"):
+            stack.append(index)
+        elif token.text in closers:
+            if not stack or tokens[stack[-1]].text != closers[token.text]:
+                return None
+            opener = stack.pop()
+            if token.text == ")":
+                pairs[opener] = index
+    return pairs if not stack else None
+
+
+def _is_operand_end(token: _Token | None) -> bool:
+    """Return True when an operator after `token` is binary rather than unary."""
+    if token is None:
+        return False
+    if token.kind in ("number", "string", "char"):
+        return True
+    if token.kind == "ident":
+        return token.text not in NON_OPERAND_KEYWORDS
+    return token.text in (")", "]", "}", "?")
+
+
+@dataclass(frozen=True)
+class _Contents:
+    precedence: int
+    has_cast: bool
+    has_brace: bool
+    leading_keyword: bool
+    is_number: bool
+
+
+def _analyze(tokens: list[_Token]) -> _Contents | None:
+    """Summarize a group's contents, or return None for tuples and empty groups."""
+    if not tokens:
+        return None
+    precedence = ATOM_PRECEDENCE
+    depth = 0
+    has_brace = False
+    previous: _Token | None = None
+    for token in tokens:
+        text = token.text if token.kind == "punct" else None
+        if text in ("(", "[", "{"):
+            has_brace = has_brace or (text == "{" and depth == 0)
+            depth += 1
+        elif text in (")", "]", "}"):
+            depth -= 1
+        elif depth == 0:
+            if text in (",", ";"):
+                return None
+            if token.kind == "ident" and token.text in EXPRESSION_KEYWORDS:
+                precedence = 0
+            elif token.kind == "ident" and token.text == "as":
+                precedence = min(precedence, CAST_PRECEDENCE)
+            elif text in ASSIGNMENT_OPERATORS or text in ("=>", "->"):
+                precedence = 0
+            elif text in BINARY_PRECEDENCE and _is_operand_end(previous):
+                precedence = min(precedence, BINARY_PRECEDENCE[text])
+            elif text in ("|", "||"):
+                # A closure.
+                precedence = 0
+        previous = token
+    first = tokens[0]
+    if precedence == ATOM_PRECEDENCE and first.kind == "punct" and first.text in UNARY_OPERATORS:
+        precedence = UNARY_PRECEDENCE
+    return _Contents(
+        precedence=precedence,
+        has_cast=any(token.kind == "ident" and token.text == "as" for token in tokens),
+        has_brace=has_brace or first.text == "{",
+        leading_keyword=first.kind == "ident" and first.text in EXPRESSION_KEYWORDS,
+        is_number=len(tokens) == 1 and first.kind == "number",
+    )
+
+
+def _neighbor(tokens: list[_Token], index: int, step: int, removed: set[int]) -> int | None:
+    """Return the index of the nearest token from `index` in direction `step` that is still present."""
+    index += step
+    while 0 <= index < len(tokens) and index in removed:
+        index += step
+    return index if 0 <= index < len(tokens) else None
+
+
+def _is_redundant(tokens: list[_Token], open_index: int, close_index: int, removed: set[int]) -> bool:
+    """Return True when the group spanning `open_index..close_index` can be dropped without changing the parse."""
+    contents = _analyze(tokens[open_index + 1 : close_index])
+    if contents is None:
+        return False
+    before_index = _neighbor(tokens, open_index, -1, removed)
+    after_index = _neighbor(tokens, close_index, 1, removed)
+    if before_index is None or after_index is None:
+        return False
+    before = tokens[before_index]
+    after = tokens[after_index]
+    before_before_index = _neighbor(tokens, before_index, -1, removed)
+    before_before = tokens[before_before_index] if before_before_index is not None else None
+    return _fits_left(before, before_before, contents, after) and _fits_right(after, contents)
+
+
+def _fits_left(before: _Token, before_before: _Token | None, contents: _Contents, after: _Token) -> bool:
+    """Check the group's contents against the token that precedes it."""
+    text = before.text
+    if before.kind == "ident":
+        if text in ("return", "break"):
+            return True
+        if text in CONDITION_KEYWORDS:
+            return after.text == "{" and not contents.has_brace and contents.precedence > 0
+        if text == "mut" and before_before is not None and before_before.text == "&":
+            return contents.precedence >= UNARY_PRECEDENCE
+        return False
+    if before.kind != "punct":
+        return False
+    if text in OPEN_LEFT:
+        return True
+    if text in STATEMENT_STARTS:
+        return contents.precedence > 0 and not contents.has_brace and not contents.leading_keyword
+    if text in UNARY_OPERATORS and not _is_operand_end(before_before):
+        return contents.precedence >= UNARY_PRECEDENCE
+    if text in BINARY_PRECEDENCE:
+        return _binds_tighter(contents.precedence, BINARY_PRECEDENCE[text])
+    return False
+
+
+def _fits_right(after: _Token, contents: _Contents) -> bool:
+    """Check the group's contents against the token that follows it."""
+    text = after.text
+    if after.kind == "ident":
+        return text == "as" and contents.precedence >= UNARY_PRECEDENCE
+    if after.kind != "punct":
+        return False
+    if text in OPEN_RIGHT:
+        return True
+    if text == "{":
+        # Only a condition or scrutinee can precede a block; struct literals would end it early.
+        return contents.precedence > 0 and not contents.has_brace
+    if text in ASSIGNMENT_OPERATORS:
+        return contents.precedence >= UNARY_PRECEDENCE
+    if text in POSTFIX_STARTS:
+        return contents.precedence == ATOM_PRECEDENCE and not contents.is_number and not contents.has_brace and not contents.leading_keyword
+    if text in BINARY_PRECEDENCE:
+        if text in ("<", "<<") and contents.has_cast:
+            # `x as i64 < y` reads `<` as the start of generic arguments.
+            return False
+        level = BINARY_PRECEDENCE[text]
+        if _binds_tighter(contents.precedence, level):
+            return True
+        return contents.precedence == level and level not in NON_ASSOCIATIVE
+    return False
+
+
+def _binds_tighter(precedence: int, level: int) -> bool:
+    """Return True when contents at `precedence` need no parentheses as an operand of a `level` operator.
+
+    Arithmetic inside a shift or bitwise operand keeps its parentheses, as clippy's `precedence` lint asks.
+    """
+    if level in BITWISE_LEVELS and precedence in ARITHMETIC_LEVELS:
+        return False
+    return precedence > level
@@ -0,0 +1,94 @@
+"""Rustc errors in generated code, reported against the Zinc source.
+
+Generated Rust is supposed to compile, so when rustc rejects it the compiler
+is at fault: a code generation bug, or a construct it doesn't handle yet.
+Builds ask cargo for JSON diagnostics, and each rustc error whose primary
+span is in the generated crate's `src/` is turned into a Zinc diagnostic. The
+build's source map gives the Zinc line the code came from, and the notes keep
+rustc's message and the generated location while asking for a bug report,
+instead of showing rustc output about code the user never wrote.
+
+Errors outside the generated source, like a missing target or a failed link,
+aren't compiler bugs and stay in cargo's own words.
+"""
+
+import json
+from dataclasses import dataclass
+from pathlib import Path
+
+from zinc.diagnostics import Diagnostic, display_path
+from zinc.sourcemap import SourceMap
+
+GENERATED_SOURCE_DIR = "src/"
+BUG_REPORT_NOTE = "this is a bug in the Zinc compiler, not in your program; please report it along with the code that triggered it"
+
+
+@dataclass(frozen=True)
+class RustcError:
+    """One error from cargo's JSON output; `file` is relative to the crate, like `src/main.rs`, and `line` and `column` are 1-based.
+
+    `manifest_path` is the `Cargo.toml` of the package rustc was compiling.
+    """
+
+    message: str
+    rendered: str
+    code: str | None = None
+    file: str | None = None
+    line: int | None = None
+    column: int | None = None
+    label: str | None = None
+    notes: tuple[str, ...] = ()
+    manifest_path: str | None = None
+
+    def in_generated_source(self, project_dir: Path) -> bool:
+        """Whether rustc pointed at code the Zinc compiler generated into the crate at `project_dir`."""
+        if self.file is None or not self.file.startswith(GENERATED_SOURCE_DIR) or self.manifest_path is None:
+            return False
+        return Path(self.manifest_path).resolve() == (project_dir / "Cargo.toml").resolve()
+
+
+def parse_cargo_messages(output: str) -> list[RustcError]:
+    """The rustc errors in the output of `cargo build --message-format json`, skipping rustc's closing summary."""
+    errors = []
+    for line in output.splitlines():
+        try:
+            record = json.loads(line)
+        except ValueError:
+            continue
+        message = record.get("message") if isinstance(record, dict) and record.get("reason") == "compiler-message" else None
+        if not message or message.get("level") != "error" or message["message"].startswith("aborting due to"):
+            continue
+        primary = next((span for span in message.get("spans", []) if span.get("is_primary")), None)
+        notes = tuple(f"rustc {child['level']}: {child['message']}" for child in message.get("children", []) if child.get("message"))
+        errors.append(
+            RustcError(
+                message["message"],
+                message.get("rendered") or message["message"],
+                (message.get("code") or {}).get("code"),
+                primary["file_name"] if primary else None,
+                primary["line_start"] if primary else None,
+                primary["column_start"] if primary else None,
+                primary.get("label") if primary else None,
+                notes,
+                record.get("manifest_path"),
+            )
+        )
+    return errors
+
+
+def zinc_diagnostic(error: RustcError, source_map: SourceMap | None, project_dir: Path) -> Diagnostic:
+    """Present a rustc error in generated code as a compiler bug at the Zinc line the code came from."""
+    location = None
+    if source_map is not None and error.in_generated_source(project_dir) and error.line is not None:
+        location = source_map.lookup(error.line, error.file.removeprefix(GENERATED_SOURCE_DIR))
+    crate_file = display_path(str(project_dir / error.file)) if error.file is not None else display_path(str(project_dir))
+    generated = ":".join(str(part) for part in (crate_file, error.line, error.column) if part is not None)
+    rustc = f"error[{error.code}]" if error.code else "error"
+    detail = f": {error.label}" if error.label else ""
+    return Diagnostic(
+        f"the Rust generated for this program doesn't compile: {error.message}",
+        location.file if location is not None else None,
+        location.line if location is not None else None,
+        label="rustc rejected the code generated for this line" if location is not None else None,
+        notes=(f"rustc reported {rustc} at {generated}{detail}", *error.notes, BUG_REPORT_NOTE),
+    )
@@ -0,0 +1,73 @@
+"""`let` rebinding versus `=` reassignment.
+
+    count = 3
+    count = count + 1        // reassigns count
+    let count = "three"      // a new count, which may have a new type
+
+`=` stores into the variable a name already refers to. `let` at the start of
+a statement declares a new variable that shadows any earlier one, like Rust's
+`let`, so the old value and its type are left behind. `let` is not a keyword,
+so the `let` is dropped before parsing and its name token is replaced by a
+`LetBindingToken`; `is_let_binding` reads it back from the assignment target.
+
+By default, `=` that gives a variable a value of a different type shadows it
+implicitly, as if written with `let`. Built with `--shadowing strict`, that
+is an error, so every change of type has to be spelled `let`.
+"""
+
+from antlr4 import Token
+from antlr4.Token import CommonToken
+from zinc.parser.zincParser import zincParser as ZincParser
+
+SHADOWING_MODES = ("allow", "strict")
+DEFAULT_SHADOWING_MODE = "allow"
+LET_KEYWORD = "let"
+
+
+class LetBindingToken(CommonToken):
+    """The name bound by a `let`, remembering where its `let` started for the formatter."""
+
+    def __init__(self, name: Token, let_start: int):
+        super().__init__(name.source, name.type, name.channel, name.start, name.stop)
+        self.tokenIndex, self.line, self.column, self.text = name.tokenIndex, name.line, name.column, name.text
+        self.let_start = let_start
+
+
+def _starts_statement(tokens: list[Token], index: int) -> bool:
+    """Whether the token at `index` opens a line, a block, or follows a `;` or a closing brace."""
+    if index == 0:
+        return True
+    previous = tokens[index - 1]
+    return previous.line < tokens[index].line or previous.text in ("{", "}", ";")
+
+
+def mark_let_bindings(tokens: list[Token]) -> list[Token]:
+    """Drop each statement-leading `let` and mark the name after it, returning the new tokens."""
+    result: list[Token] = []
+    index = 0
+    while index < len(tokens):
+        token = tokens[index]
+        name = tokens[index + 1] if index + 1 < len(tokens) else None
+        after = tokens[index + 2] if index + 2 < len(tokens) else None
+        if (
+            token.text == LET_KEYWORD
+            and token.type == ZincParser.IDENTIFIER
+            and name is not None
+            and name.type == ZincParser.IDENTIFIER
+            and name.line == token.line
+            and after is not None
+            and after.text in ("=", ":")
+            and _starts_statement(tokens, index)
+        ):
+            result.append(LetBindingToken(name, token.start))
+            index += 2
+            continue
+        result.append(token)
+        index += 1
+    return result
+
+
+def is_let_binding(target) -> bool:
+    """Whether an assignment target's name was written after `let`."""
+    name = target.IDENTIFIER() if target is not None else None
+    return name is not None and isinstance(name.symbol, LetBindingToken)
@@ -0,0 +1,124 @@
+"""Map generated Rust lines back to the Zinc statements they came from.
+
+Code generation puts a marker comment in front of every statement it renders.
+Rendering strips the markers and records, for each Rust line, the Zinc file
+and line of the statement it belongs to. Builds save that map next to the
+generated source so rustc errors and panic messages can point at Zinc code:
+
+    --> hello.zn:3 (src/main.rs:12:5)
+"""
+
+import json
+import re
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from zinc.diagnostics import display_path
+
+SOURCE_MARKER = "// __zinc_src:"
+SOURCE_MAP_FILE_NAME = "zinc-source-map.json"
+ROOT_FILE_NAMES = ("main.rs", "lib.rs")
+
+_MARKER_RE = re.compile(r"\s*// __zinc_src:(\d+)$")
+# Covers `--> src/main.rs:12:5` and `src/util/strings.rs:3` from rustc and absolute paths in panic messages and backtraces.
+_RUST_LOCATION_RE = re.compile(r"(?:[^\s:()]*[/\\])?src[/\\]((?:\w+[/\\])*\w+\.rs):(\d+)(?::(\d+))?")
+
+
+@dataclass(frozen=True)
+class SourceLocation:
+    """A line in a Zinc source file; `line` is 1-based."""
+
+    file: str
+    line: int
+
+
+def source_marker(index: int) -> str:
+    """The comment code generation emits before the statement at `index` in its location table."""
+    return f"{SOURCE_MARKER}{index}"
+
+
+@dataclass
+class SourceMap:
+    """Zinc locations keyed by 1-based line in the generated crate root, plus one table per module file."""
+
+    lines: dict[int, SourceLocation] = field(default_factory=dict)
+    # Module files are keyed by their path under `src/`, e.g. `util/strings.rs`.
+    modules: dict[str, dict[int, SourceLocation]] = field(default_factory=dict)
+
+    def lookup(self, rust_line: int, rust_file: str = "main.rs") -> SourceLocation | None:
+        """The Zinc statement a generated line belongs to, if any."""
+        lines = self.lines if rust_file in ROOT_FILE_NAMES else self.modules.get(rust_file, {})
+        return lines.get(rust_line)
+
+    def rewrite(self, text: str) -> str:
+        """Replace generated-file locations in compiler or runtime output with Zinc ones."""
+
+        def replace(match: re.Match) -> str:
+            rust_file, rust_line, column = match.groups()
+            rust_file = rust_file.replace("\\", "/")
+            location = self.lookup(int(rust_line), rust_file)
+            if location is None:
+                return match.group(0)
+            generated = ":".join(part for part in (f"src/{rust_file}", rust_line, column) if part)
+            return f"{display_path(location.file)}:{location.line} ({generated})"
+
+        return _RUST_LOCATION_RE.sub(replace, text)
+
+    def to_json(self) -> str:
+        """Serialize with each Zinc file listed once; `modules` appears only when there are module files."""
+        tables = [self.lines, *self.modules.values()]
+        files = sorted({location.file for table in tables for location in table.values()})
+        index = {file: position for position, file in enumerate(files)}
+
+        def encode(table: dict[int, SourceLocation]) -> dict[str, list[int]]:
+            return {str(line): [index[location.file], location.line] for line, location in sorted(table.items())}
+
+        data = {"version": 1, "files": files, "lines": encode(self.lines)}
+        if self.modules:
+            data["modules"] = {name: encode(table) for name, table in sorted(self.modules.items())}
+        return json.dumps(data) + "\n"
+
+    @classmethod
+    def from_json(cls, text: str) -> "SourceMap":
+        """Load a map written by `to_json`."""
+        data = json.loads(text)
+        files = data["files"]
+
+        def decode(table: dict[str, list[int]]) -> dict[int, SourceLocation]:
+            return {int(line): SourceLocation(files[file], zinc_line) for line, (file, zinc_line) in table.items()}
+
+        return cls(decode(data["lines"]), {name: decode(table) for name, table in data.get("modules", {}).items()})
+
+    @classmethod
+    def load(cls, project_dir: Path) -> "SourceMap | None":
+        """Load the map saved in a generated Cargo project, if there is one."""
+        path = project_dir / SOURCE_MAP_FILE_NAME
+        try:
+            return cls.from_json(path.read_text())
+        except (OSError, ValueError, KeyError, TypeError):
+            return None
+
+
+def strip_source_markers(rendered: str, locations: list[SourceLocation]) -> tuple[str, SourceMap]:
+    """Remove statement markers from rendered Rust and build the line map they describe.
+
+    A statement owns the lines after its marker until the next marker or the
+    end of the enclosing top-level item.
+    """
+    lines: list[str] = []
+    source_map = SourceMap()
+    current: SourceLocation | None = None
+    for line in rendered.split("\n"):
+        match = _MARKER_RE.search(line)
+        if match is not None:
+            current = locations[int(match.group(1))]
+            line = line[: match.start()]
+            if not line.strip():
+                continue
+        elif line and not line[0].isspace():
+            # Top-level items start at column 0; `main` and other functions never inherit a location.
+            current = None
+        lines.append(line)
+        if current is not None:
+            source_map.lines[len(lines)] = current
+    return "\n".join(lines), source_map
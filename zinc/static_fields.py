@@ -0,0 +1,67 @@
+"""Static struct fields, marked with `static`.
+
+    struct Ticket {
+        id: i64
+        static ISSUED: 0
+        static const LIMIT: 3
+    }
+
+A static field is one value owned by the struct rather than a copy in each
+instance, read and written as `Ticket.ISSUED`. The grammar has no `static`,
+so inside a struct body a `static` in front of a field is dropped before
+parsing and the field's first token is replaced by a `StaticFieldToken`;
+`is_static_field` reads it back from the field. Elsewhere `static` is an
+ordinary name.
+"""
+
+from antlr4 import Token
+from antlr4.Token import CommonToken
+from zinc.parser.zincParser import zincParser as ZincParser
+
+STATIC_KEYWORD = "static"
+
+
+class StaticFieldToken(CommonToken):
+    """The first token of a static field, remembering where its `static` started for the formatter."""
+
+    def __init__(self, first: Token, static_start: int):
+        super().__init__(first.source, first.type, first.channel, first.start, first.stop)
+        self.tokenIndex, self.line, self.column, self.text = first.tokenIndex, first.line, first.column, first.text
+        self.static_start = static_start
+
+
+def _starts_field(tokens: list[Token], index: int) -> bool:
+    """Whether the tokens from `index` spell a field declaration: `NAME :` or `const NAME :`."""
+    texts = [token.text for token in tokens[index : index + 3]]
+    if texts[:1] == ["const"]:
+        return len(texts) == 3 and tokens[index + 1].type == ZincParser.IDENTIFIER and texts[2] == ":"
+    return len(texts) >= 2 and tokens[index].type == ZincParser.IDENTIFIER and texts[1] == ":"
+
+
+def mark_static_fields(tokens: list[Token]) -> list[Token]:
+    """Drop each `static` in front of a struct field and mark the field's first token, returning the new tokens."""
+    result: list[Token] = []
+    braces: list[bool] = []  # for each open `{`, whether it is a struct body
+    struct_pending = False
+    index = 0
+    while index < len(tokens):
+        token = tokens[index]
+        if token.text == "struct":
+            struct_pending = True
+        elif token.text == "{":
+            braces.append(struct_pending)
+            struct_pending = False
+        elif token.text == "}" and braces:
+            braces.pop()
+        elif token.text == STATIC_KEYWORD and token.type == ZincParser.IDENTIFIER and braces and braces[-1] and _starts_field(tokens, index + 1):
+            result.append(StaticFieldToken(tokens[index + 1], token.start))
+            index += 2
+            continue
+        result.append(token)
+        index += 1
+    return result
+
+
+def is_static_field(field: ZincParser.StructFieldContext) -> bool:
+    """Whether a struct field was declared with `static`."""
+    return isinstance(field.start, StaticFieldToken)
@@ -4,6 +4,8 @@ from __future__ import annotations
 
 import ast
 
+FORMAT_PLACEHOLDER = "{}"
+
 
 def is_raw_string_literal(text: str) -> bool:
     """Return True when the Zinc literal uses C3-style backticks."""
@@ -25,6 +27,11 @@ def is_interpolated_string_literal(text: str) -> bool:
     return len(text) >= 2 and text[0] == text[-1] == '"' and "{" in text
 
 
+def count_format_placeholders(text: str) -> int:
+    """Return how many empty `{}` placeholders a double-quoted Zinc string has for print() arguments to fill."""
+    return text.count(FORMAT_PLACEHOLDER) if is_interpolated_string_literal(text) else 0
+
+
 def decode_string_literal(text: str) -> str:
     """Decode a Zinc string literal into its runtime contents."""
     if is_raw_string_literal(text):
@@ -38,7 +45,14 @@ def decode_string_literal(text: str) -> str:
 
 
 def to_rust_raw_string(value: str) -> str:
-    """Render a Python string as a Rust raw string literal with safe delimiters."""
+    """Render a Python string as a Rust raw string literal with safe delimiters.
+
+    Text spanning several lines is escaped onto one line instead, since generated
+    statements are re-indented line by line and would indent the string's contents.
+    """
+    if "\n" in value or "\r" in value:
+        escaped = value.replace("\\", "\\\\").replace('"', '\\"').replace("\n", "\\n").replace("\r", "\\r")
+        return f'"{escaped}"'
     hashes = ""
     while f'"{hashes}' in value:
         hashes += "#"
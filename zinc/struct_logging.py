@@ -4,8 +4,8 @@ import sys
 from pathlib import Path
 
 import structlog
-from colorama import Back, Fore, Style
 from zinc.exceptions import ZincLogLevelError
+from zinc.style import paint, should_color
 
 
 def get_logger(*args, **initial_values):
@@ -13,21 +13,22 @@ def get_logger(*args, **initial_values):
 
 
 def _colorize(level: str, msg: str) -> str:
+    color = should_color(sys.stderr)
     # if a critical error, color the background red
-    background = Back.LIGHTRED_EX if level == "critical" else Back.RESET
+    background = "bright_red" if level == "critical" else None
 
     # always make errors bold
-    style = Style.BRIGHT if level in {"critical", "error"} else Style.NORMAL
+    bold = level in {"critical", "error"}
 
-    color = {
-        "critical": Fore.LIGHTWHITE_EX,
-        "error": Fore.LIGHTRED_EX,
-        "warning": Fore.LIGHTYELLOW_EX,
-        "info": Fore.LIGHTGREEN_EX,
-        "debug": Fore.LIGHTMAGENTA_EX,
-    }.get(level, Fore.RESET)
+    foreground = {
+        "critical": "bright_white",
+        "error": "bright_red",
+        "warning": "bright_yellow",
+        "info": "bright_green",
+        "debug": "bright_magenta",
+    }.get(level)
 
-    return f"{background}{style}{color}{msg}{Style.RESET_ALL}"
+    return paint(msg, color, fg=foreground, bg=background, bold=bold)
 
 
 def _source_location(_, level, event_dict):
@@ -0,0 +1,68 @@
+"""Terminal colors for everything zinc prints.
+
+Output is styled only through `paint`, and whether to style it at all is
+decided in one place, `should_color`. `--color=always` and `--color=never`
+decide outright. Otherwise, with `--color=auto` or no flag, a non-empty
+`NO_COLOR` environment variable turns color off (see https://no-color.org),
+and without it output is colored only when it goes to a terminal.
+
+The CLI records its choice on the click context, where `click.echo` also looks
+for it, so styled text is never stripped from output that asked for color.
+"""
+
+import os
+import sys
+from typing import TextIO
+
+import click
+
+COLOR_CHOICES = ("auto", "always", "never")
+
+
+def color_setting(choice: str = "auto") -> bool | None:
+    """Whether `--color=CHOICE` forces color on or off, or None when it depends on the stream."""
+    if choice == "always":
+        return True
+    if choice == "never" or os.environ.get("NO_COLOR"):
+        return False
+    return None
+
+
+def set_color(ctx: click.Context, param: click.Parameter, value: str | None) -> None:
+    """Click callback for a `--color` option: record the choice for the whole command line.
+
+    The choice also goes on the parent commands, since a group may print the
+    errors a subcommand lets escape.
+    """
+    if value is None:
+        return
+    setting = color_setting(value)
+    while ctx is not None:
+        ctx.color = setting
+        ctx = ctx.parent
+
+
+def should_color(stream: TextIO | None = None) -> bool:
+    """Whether text written to `stream`, stdout by default, should be colored."""
+    ctx = click.get_current_context(silent=True)
+    setting = ctx.color if ctx is not None and ctx.color is not None else color_setting()
+    if setting is not None:
+        return setting
+    stream = stream if stream is not None else sys.stdout
+    isatty = getattr(stream, "isatty", None)
+    return bool(isatty is not None and isatty())
+
+
+def paint(text: str, color: bool, **styles) -> str:
+    """`text` with click styles like `fg="red"` or `bold=True` when `color` is set, and plain otherwise."""
+    return click.style(text, **styles) if color else text
+
+
+color_option = click.option(
+    "--color",
+    type=click.Choice(COLOR_CHOICES),
+    callback=set_color,
+    expose_value=False,
+    is_eager=True,
+    help="When to color output: auto (default; only on a terminal, and never with NO_COLOR set), always, or never",
+)
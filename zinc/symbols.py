@@ -1,10 +1,13 @@
 """Symbol Table for the Zinc compiler."""
 
+import os
 import re
 from dataclasses import dataclass, replace
 from enum import Enum, auto
+from pathlib import Path
 
-from antlr4 import CommonTokenStream, InputStream, ParserRuleContext
+from antlr4 import CommonTokenStream, ParserRuleContext
+from antlr4.tree.Tree import TerminalNodeImpl
 from zinc.ast.types import (
     AnonymousStructFieldInfo,
     AnonymousStructTypeInfo,
@@ -21,6 +24,7 @@ from zinc.ast.types import (
     TypeInfo,
     ValueTypeSpec,
     default_exact_type,
+    default_integer_type,
     exact_type_to_base,
     exact_type_to_rust,
     is_mutating_method,
@@ -31,6 +35,10 @@ from zinc.ast.types import (
     type_to_rust,
 )
 from zinc.atlas import (
+    BUILTIN_FUNCTIONS,
+    DISPLAY_METHOD,
+    ITERATE_METHOD,
+    NEXT_METHOD,
     NUMERIC_TYPE_ALTERNATIVES,
     Atlas,
     ConstInstance,
@@ -40,9 +48,24 @@ from zinc.atlas import (
     StructFieldInfo,
     StructInstance,
     StructMethodInfo,
+    display_method,
+    is_required_method,
+    iterate_method,
+    iterator_next_method,
 )
-from zinc.decorators import DecoratorInfo, ResolvedDecoratorApplication, decorators_from_ctx
-from zinc.exceptions import ZincTypeError
+from zinc.conditional import active_build_config
+from zinc.decorators import (
+    DERIVES,
+    DecoratorInfo,
+    ResolvedDecoratorApplication,
+    decorators_from_ctx,
+    derives_from_ctx,
+    has_marker_decorator,
+    marker_decorators_from_ctx,
+)
+from zinc.diagnostics import Diagnostic, did_you_mean, mismatch_label, node_span
+from zinc.exceptions import ZincTypeError, ZincTypeErrors
+from zinc.fixed_arrays import array_length
 from zinc.meta_runtime import (
     BUILTIN_META_QNAME,
     CHANNEL_META_QNAME,
@@ -62,17 +85,22 @@ from zinc.meta_runtime import (
     MetaListValue,
     MetaValue,
     component_order_value,
+    is_meta_struct_qname,
     is_type_meta_qname,
     unknown_type_meta,
 )
 from zinc.modules import (
     RustExternFunction,
+    appended_pieces,
     enum_variant_path_from_ctx,
     extract_identifier_path,
+    format_string_literal,
+    source_lexer,
     struct_composition_from_ctx,
     struct_path_from_ctx,
 )
-from zinc.numeric_literals import numeric_literal_value, parse_numeric_literal
+from zinc.number_format import parse_number_format
+from zinc.numeric_literals import is_unsuffixed_numeric_literal, numeric_literal_value, parse_numeric_literal
 from zinc.operators import (
     ASSIGNMENT_TO_BINARY_OPERATOR,
     BOOL_RESULT_OPERATOR_SYMBOLS,
@@ -86,13 +114,68 @@ from zinc.operators import (
     operator_kind_for_declaration,
     operator_symbol_from_function_ctx,
 )
-from zinc.parser.zincLexer import zincLexer as ZincLexer
 from zinc.parser.zincParser import zincParser as ZincParser
 from zinc.parser.zincVisitor import zincVisitor
+from zinc.shadowing import is_let_binding
+from zinc.static_fields import is_static_field
+from zinc.string_literals import count_format_placeholders, decode_string_literal, is_interpolated_string_literal
 
 RESERVED_ERROR_NAMES = frozenset({"Ok", "Err", "Some", "None"})
+# Built-in functions and what each returns; a user function can't take one of these names
+BUILTIN_RETURN_TYPES = {
+    "print": BaseType.VOID,
+    "assert": BaseType.VOID,
+    "assert_eq": BaseType.VOID,
+    "exit": BaseType.VOID,
+    "on_interrupt": BaseType.VOID,
+    "dbg": BaseType.VOID,
+    "print_debug": BaseType.VOID,
+    "uuid": BaseType.STRING,
+    "to_json": BaseType.STRING,
+    "temp_file": BaseType.STRING,
+    "temp_dir": BaseType.STRING,
+    "include_str": BaseType.STRING,
+    "matrix": BaseType.ARRAY,
+    "format_number": BaseType.STRING,
+    "chan": BaseType.CHANNEL,
+    "close": BaseType.VOID,
+    "dict": BaseType.DICT,
+    "sort_dict": BaseType.DICT,
+    "set": BaseType.SET,
+    "sort_set": BaseType.SET,
+    "meta": BaseType.STRUCT,
+    "type": BaseType.STRUCT,
+    "line": BaseType.INTEGER,
+    "has_component": BaseType.BOOLEAN,
+    "implements": BaseType.BOOLEAN,
+}
 BITWISE_ASSIGNMENT_OPERATORS = frozenset({"&=", "|=", "^=", "<<=", ">>="})
 SHIFT_ASSIGNMENT_OPERATORS = frozenset({"<<=", ">>="})
+STATIC_FIELD_TYPES = frozenset({BaseType.INTEGER, BaseType.FLOAT, BaseType.BOOLEAN, BaseType.STRING})
+# Parameter types a `@memoize` cache can key on, and return types it can't hold
+MEMO_KEY_TYPES = frozenset({BaseType.INTEGER, BaseType.BOOLEAN, BaseType.STRING})
+UNMEMOIZABLE_RETURN_TYPES = frozenset({BaseType.STRUCT, BaseType.CALLABLE, BaseType.CHANNEL, BaseType.CONTEXT})
+_SIZED_MEMBERS = frozenset({"len", "is_empty", "contains", "contains_key"})
+_LIST_MEMBERS = frozenset({"push", "insert", "remove", "pop", "index_of", "reverse"})
+# List methods that change how many elements a list holds, which a fixed-size array can't do
+_RESIZING_LIST_MEMBERS = frozenset({"push", "insert", "remove", "pop"})
+# Argument count and description for the list methods beyond push
+LIST_METHOD_ARGUMENTS = {
+    "insert": (2, "index and value arguments"),
+    "remove": (1, "one index argument"),
+    "pop": (0, ""),
+    "index_of": (1, "one element argument"),
+    "reverse": (0, ""),
+}
+# Built-in members by receiver type, offered as 'did you mean' candidates
+BUILTIN_MEMBER_NAMES = {
+    BaseType.CALLABLE: frozenset({"call"}),
+    BaseType.CONTEXT: frozenset({"done", "cancel"}),
+    BaseType.STRING: _SIZED_MEMBERS,
+    BaseType.ARRAY: _SIZED_MEMBERS | _LIST_MEMBERS,
+    BaseType.DICT: _SIZED_MEMBERS | {"insert", "get", "remove", "clear", "keys", "values", "items"},
+    BaseType.SET: _SIZED_MEMBERS | {"push", "insert", "remove", "clear"},
+}
 
 
 class SymbolKind(Enum):
@@ -124,6 +207,7 @@ class Symbol:
     parameter_index: int = 0
     has_default: bool = False
     is_mutated: bool = False  # True if variable needs 'mut' (reassigned or mutating method called)
+    is_appended: bool = False  # True for a string variable grown with `s += piece` or `s = s + piece`
     is_shadow: bool = False  # True if this shadows a previous binding of the same name
     element_type: BaseType | None = None  # For arrays: type of elements
     element_exact_type: str | None = None
@@ -138,11 +222,14 @@ class Symbol:
     option_info: OptionTypeInfo | None = None
     element_struct_qualified_name: str | None = None
     element_anonymous_struct_info: AnonymousStructTypeInfo | None = None
+    array_length: int | None = None  # For fixed-size arrays: the element count
+    element_array_info: ArrayTypeInfo | None = None  # For nested lists: the rows' array metadata
     binding_unique_name: str | None = None  # For temps/captured refs: the underlying binding
     is_captured_binding: bool = False  # True when this binding is shared with one or more closures
     is_captured_ref: bool = False  # True for closure-local aliases of captured outer bindings
     constant_value: object | None = None
     line_num: int = 0
+    declaration: str | None = None  # unique_name of the symbol that first bound this name; reassignments share it
 
 
 @dataclass
@@ -168,6 +255,8 @@ class CaptureBindingInfo:
     option_info: OptionTypeInfo | None = None
     element_struct_qualified_name: str | None = None
     element_anonymous_struct_info: AnonymousStructTypeInfo | None = None
+    array_length: int | None = None
+    element_array_info: ArrayTypeInfo | None = None
 
 
 @dataclass
@@ -265,6 +354,281 @@ class FunctionParameterInfo:
     line_num: int
 
 
+def _diagnostic_position(diagnostic: Diagnostic) -> tuple[str, int, int]:
+    return diagnostic.file or "", diagnostic.line or 0, diagnostic.column or 0
+
+
+def _returns_field(method: StructMethodInfo, field_name: str) -> bool:
+    """Return True for a getter: an instance method without parameters whose body is `return self.<field>`."""
+    if method.is_static or method.parameters or method.body_ctx is None:
+        return False
+    statements = list(method.body_ctx.statement())
+    if len(statements) != 1 or statements[0].returnStatement() is None:
+        return False
+    returned = statements[0].returnStatement().expression()
+    return returned is not None and returned.getText() == f"self.{field_name}"
+
+
+def _is_declaration(stmt) -> bool:
+    return any(
+        child is not None
+        for child in (
+            stmt.functionDeclaration(),
+            stmt.asyncFunctionDeclaration(),
+            stmt.structDeclaration(),
+            stmt.enumDeclaration(),
+            stmt.constDeclaration(),
+            stmt.importStatement(),
+            stmt.externRustBlock(),
+        )
+    )
+
+
+def _literal_condition(expr) -> bool | None:
+    """The value of a condition spelled as a `true` or `false` literal, else None."""
+    if isinstance(expr, ZincParser.PrimaryExprContext):
+        return {"true": True, "false": False}.get(expr.getText())
+    return None
+
+
+# Words an interpolation can start with that aren't names to resolve.
+INTERPOLATION_KEYWORDS = frozenset({"and", "or", "not", "true", "false", "nil", "self"})
+
+# Parse tree nodes whose `break` statements belong to themselves, not to an enclosing loop.
+BREAK_SCOPES = (
+    ZincParser.ForStatementContext,
+    ZincParser.WhileStatementContext,
+    ZincParser.LoopStatementContext,
+    ZincParser.LambdaExpressionContext,
+    ZincParser.FunctionDeclarationContext,
+    ZincParser.AsyncFunctionDeclarationContext,
+)
+
+
+def _loop_breaks(block_ctx: ParserRuleContext) -> bool:
+    """Return True if a loop body has a `break` for that loop."""
+    stack = [block_ctx]
+    while stack:
+        node = stack.pop()
+        if isinstance(node, ZincParser.BreakStatementContext):
+            return True
+        if isinstance(node, ParserRuleContext) and not isinstance(node, BREAK_SCOPES):
+            stack.extend(node.getChild(i) for i in range(node.getChildCount()))
+    return False
+
+
+FUNCTION_CONTEXTS = (
+    ZincParser.FunctionDeclarationContext,
+    ZincParser.AsyncFunctionDeclarationContext,
+    ZincParser.LambdaExpressionContext,
+)
+
+
+def _assignment_sites(ctx: ParserRuleContext, name: str) -> list[ParserRuleContext]:
+    """Assignments to `name` in the outermost function around `ctx`, in source order."""
+    function_ctx = None
+    node = ctx
+    while node is not None:
+        if isinstance(node, FUNCTION_CONTEXTS):
+            function_ctx = node
+        node = node.parentCtx
+    if function_ctx is None:
+        return []
+    sites = []
+    stack = [function_ctx]
+    while stack:
+        node = stack.pop()
+        if isinstance(node, TerminalNodeImpl):
+            target = node.parentCtx
+            if isinstance(target, ZincParser.TupleAssignmentTargetContext):
+                target = target.parentCtx
+            if node.getText() == name and isinstance(target, (ZincParser.AssignmentTargetContext, ZincParser.TypedAssignmentTargetContext)):
+                sites.append(target.parentCtx)
+            continue
+        stack.extend(node.getChild(i) for i in range(node.getChildCount()))
+    return sorted(sites, key=lambda site: site.start.tokenIndex)
+
+
+def is_assigned_string_literal(ctx: ParserRuleContext, name: str) -> bool:
+    """Return True when some assignment to `name` in the function around `ctx` stores a plain string literal."""
+    for site in _assignment_sites(ctx, name):
+        value = site.expression() if isinstance(site, ZincParser.VariableAssignmentContext) else None
+        if isinstance(value, ZincParser.PrimaryExprContext) and value.primaryExpression().literal() is not None:
+            if value.primaryExpression().literal().STRING() is not None and not is_interpolated_string_literal(value.getText()):
+                return True
+    return False
+
+
+def included_file(module_file: Path, path_ctx: ParserRuleContext) -> Path:
+    """The file an `include_str()` call embeds; its path is a plain string literal, relative to the calling module."""
+    literal = path_ctx.primaryExpression().literal() if isinstance(path_ctx, ZincParser.PrimaryExprContext) else None
+    if literal is None or literal.STRING() is None or is_interpolated_string_literal(literal.getText()):
+        raise ZincTypeError("include_str() path must be a string literal", ctx=path_ctx)
+    return (module_file.parent / decode_string_literal(literal.getText())).resolve()
+
+
+
+def number_format_spec(spec_ctx: ParserRuleContext) -> str:
+    """The spec a `format_number()` call formats with; it is a plain string literal, checked here."""
+    literal = spec_ctx.primaryExpression().literal() if isinstance(spec_ctx, ZincParser.PrimaryExprContext) else None
+    if literal is None or literal.STRING() is None or is_interpolated_string_literal(literal.getText()):
+        raise ZincTypeError("format_number() takes its format as a string literal, such as ',.2f'", ctx=spec_ctx)
+    spec = decode_string_literal(literal.getText())
+    try:
+        parse_number_format(spec)
+    except ValueError as exc:
+        raise ZincTypeError(str(exc), ctx=spec_ctx) from exc
+    return spec
+
+
+LOOP_CONTEXTS = (ZincParser.ForStatementContext, ZincParser.WhileStatementContext, ZincParser.LoopStatementContext)
+
+
+def is_read_after(ctx: ParserRuleContext, name: str) -> bool:
+    """Return True when `name` may be read again after `ctx`: later in its function, or on the next loop iteration."""
+    function_ctx = None
+    node = ctx.parentCtx
+    while node is not None:
+        if isinstance(node, LOOP_CONTEXTS) and function_ctx is None:
+            return True
+        if isinstance(node, FUNCTION_CONTEXTS):
+            function_ctx = node
+        node = node.parentCtx
+    if function_ctx is None:
+        return False
+    interpolation = re.compile(rf"\{{\s*{re.escape(name)}\b")
+    stack = [function_ctx]
+    while stack:
+        node = stack.pop()
+        if isinstance(node, TerminalNodeImpl):
+            if node.symbol.tokenIndex > ctx.stop.tokenIndex and (
+                node.getText() == name or (node.symbol.type == ZincParser.STRING and interpolation.search(node.getText()))
+            ):
+                return True
+            continue
+        stack.extend(node.getChild(i) for i in range(node.getChildCount()))
+    return False
+
+
+# Operators whose result has the same numeric type as their operands.
+VALUE_OPERATORS = (
+    ZincParser.AdditiveExprContext,
+    ZincParser.MultiplicativeExprContext,
+    ZincParser.BitwiseAndExprContext,
+    ZincParser.BitwiseOrExprContext,
+    ZincParser.BitwiseXorExprContext,
+)
+
+# Operators that require both operands to share one numeric type.
+SAME_TYPE_OPERATORS = (*VALUE_OPERATORS, ZincParser.RelationalExprContext, ZincParser.EqualityExprContext)
+
+
+def _unsuffixed_literal(expr) -> str | None:
+    """The text of an argument spelled as an unsuffixed number such as `1` or `-2.5`, else None."""
+    if isinstance(expr, ZincParser.UnaryExprContext) and expr.getChild(0).getText() == "-":
+        expr = expr.expression()
+    if not isinstance(expr, ZincParser.PrimaryExprContext):
+        return None
+    primary = expr.primaryExpression()
+    if primary is None or primary.literal() is None:
+        return None
+    text = primary.literal().getText()
+    if not is_unsuffixed_numeric_literal(text):
+        return None
+    return text
+
+
+def _value_operands(expr) -> set[str]:
+    """Bare names whose type is the type of `expr`, looking through arithmetic and parentheses."""
+    if isinstance(expr, ZincParser.ParenExprContext):
+        return _value_operands(expr.expression())
+    if isinstance(expr, VALUE_OPERATORS):
+        return _value_operands(expr.expression(0)) | _value_operands(expr.expression(1))
+    if isinstance(expr, ZincParser.PrimaryExprContext):
+        primary = expr.primaryExpression()
+        if primary is not None and primary.IDENTIFIER() is not None and primary.getChildCount() == 1:
+            return {primary.IDENTIFIER().getText()}
+    return set()
+
+
+def _parameter_type_links(function_ctx: ParserRuleContext, names: set[str]) -> tuple[list[set[str]], dict[str, str]]:
+    """How a function body ties its untyped parameters together and to annotations.
+
+    Returns the groups of parameters that meet as operands of the same arithmetic or comparison, and
+    the annotation text each parameter is returned as or assigned to. Nested functions are skipped.
+    """
+    groups: list[set[str]] = []
+    annotations: dict[str, str] = {}
+
+    def link(operands: set[str]) -> None:
+        operands &= names
+        if len(operands) < 2:
+            return
+        touching = [group for group in groups if group & operands]
+        merged = set(operands).union(*touching)
+        groups[:] = [group for group in groups if not group & operands] + [merged]
+
+    return_type = function_ctx.type_() if hasattr(function_ctx, "type_") else None
+    stack = [function_ctx.block()] if function_ctx.block() is not None else []
+    while stack:
+        node = stack.pop()
+        if isinstance(node, TerminalNodeImpl) or isinstance(node, FUNCTION_CONTEXTS):
+            continue
+        if isinstance(node, SAME_TYPE_OPERATORS):
+            link(_value_operands(node.expression(0)) | _value_operands(node.expression(1)))
+        elif isinstance(node, ZincParser.ReturnStatementContext) and node.expression() is not None:
+            operands = _value_operands(node.expression())
+            link(operands)
+            if return_type is not None:
+                annotations.update(dict.fromkeys(operands & names, return_type.getText()))
+        elif isinstance(node, ZincParser.TypedVariableAssignmentContext):
+            operands = _value_operands(node.expression())
+            link(operands)
+            annotations.update(dict.fromkeys(operands & names, node.type_().getText()))
+        stack.extend(node.getChild(i) for i in range(node.getChildCount()))
+    return groups, annotations
+
+
+# Builtins that only read the values passed to them.
+BORROWING_BUILTINS = frozenset({"print"})
+
+# Collection and string members that only read their receiver.
+READING_MEMBERS = frozenset({"len", "is_empty", "contains", "contains_key", "get", "keys", "values", "items"})
+
+
+def _assigned_through(expr) -> bool:
+    """Return True when `expr` is the base of an assignment target such as `p.x = 1` or `p.items[0] = 1`."""
+
+    def receiver(ctx):
+        if isinstance(ctx, (ZincParser.MemberAccessExprContext, ZincParser.MemberAccessContext)):
+            return ctx.expression()
+        if isinstance(ctx, (ZincParser.IndexAccessExprContext, ZincParser.IndexAccessContext)):
+            return ctx.expression(0)
+        return None
+
+    node = expr
+    while isinstance(node.parentCtx, (ZincParser.MemberAccessExprContext, ZincParser.IndexAccessExprContext)) and receiver(node.parentCtx) is node:
+        node = node.parentCtx
+    target = node.parentCtx
+    if not isinstance(target, (ZincParser.MemberAccessContext, ZincParser.IndexAccessContext)) or receiver(target) is not node:
+        return False
+    return isinstance(target.parentCtx, ZincParser.AssignmentTargetContext)
+
+
+def identifier_names(ctx: ParserRuleContext) -> set[str]:
+    """Every identifier spelled inside a parse tree node."""
+    names = set()
+    stack = [ctx]
+    while stack:
+        node = stack.pop()
+        if isinstance(node, TerminalNodeImpl):
+            if node.symbol.type == ZincParser.IDENTIFIER:
+                names.add(node.getText())
+            continue
+        stack.extend(node.getChild(i) for i in range(node.getChildCount()))
+    return names
+
+
 def is_arrow_lambda_context(ctx) -> bool:
     """Return True when a lambdaExpression node uses arrow syntax."""
     return isinstance(ctx, ZincParser.LambdaExpressionContext) and ctx.block() is None
@@ -318,6 +682,7 @@ class SymbolTable:
         self._symbols: list[Symbol] = []
         self._by_interval: dict[str, Symbol] = {}  # "scope:(start, stop)" -> Symbol
         self._auto_unwrap_intervals: dict[str, BaseType] = {}  # "scope:(start, stop)" -> Result/Option family
+        self._checked_indexes: set[str] = set()  # "scope:(start, stop)" of fixed-size array reads that give a Result
         self._scope_stack: list[dict[str, Symbol]] = [{}]  # Stack of id -> Symbol
         self._temp_counter: int = 0
         self._scope_path: list[str] = []  # e.g., ["main", "if_0"]
@@ -390,6 +755,11 @@ class SymbolTable:
             constant_value=constant_value,
             line_num=line_num,
         )
+        previous = self.lookup_by_id(id)
+        if previous is not None and previous.kind in {SymbolKind.VARIABLE, SymbolKind.PARAMETER} and previous.declaration:
+            symbol.declaration = previous.declaration
+        else:
+            symbol.declaration = unique_name
         self._symbols.append(symbol)
         self._by_interval[self._interval_key(interval)] = symbol
         # Always update scope - this handles shadowing within same scope
@@ -429,6 +799,10 @@ class SymbolTable:
                 return scope[id]
         return None
 
+    def visible_names(self) -> set[str]:
+        """Names visible from the current scope."""
+        return {name for scope in self._scope_stack for name in scope}
+
     def lookup_by_interval(self, interval: tuple[int, int], function_scope: str | None = None) -> Symbol | None:
         """Look up symbol by source interval.
 
@@ -472,6 +846,26 @@ class SymbolTable:
         scope = function_scope if function_scope is not None else self._function_scope
         return self._auto_unwrap_intervals.get(f"{scope}:({interval[0]}, {interval[1]})")
 
+    def mark_checked_index(self, interval: tuple[int, int]) -> None:
+        """Record that one fixed-size array read gives a Result instead of panicking out of range."""
+        self._checked_indexes.add(self._interval_key(interval))
+
+    def is_checked_index(self, interval: tuple[int, int], function_scope: str | None = None) -> bool:
+        """Whether a read was recorded by `mark_checked_index` in the given function scope, or in any scope when None."""
+        suffix = f":({interval[0]}, {interval[1]})"
+        if function_scope is not None:
+            return f"{function_scope}{suffix}" in self._checked_indexes
+        return any(key.endswith(suffix) for key in self._checked_indexes)
+
+    def auto_unwrap_families_within(self, interval: tuple[int, int]) -> set[BaseType]:
+        """The try-propagation families recorded for expressions inside a token interval, in any scope."""
+        families = set()
+        for key, family in self._auto_unwrap_intervals.items():
+            start, stop = map(int, key.rpartition(":(")[2].rstrip(")").split(", "))
+            if interval[0] <= start and stop <= interval[1]:
+                families.add(family)
+        return families
+
 
 class SymbolTableVisitor(zincVisitor):
     """Walks reachable code and builds a SymbolTable."""
@@ -486,6 +880,7 @@ class SymbolTableVisitor(zincVisitor):
         self._current_module: str | None = None
         self._current_return_type: BaseType = BaseType.VOID  # Track return type during resolution
         self._current_return_exact_type: str | None = None
+        self._current_return_array_info: ArrayTypeInfo | None = None
         self._current_return_dict_info: DictTypeInfo | None = None
         self._current_return_set_info: SetTypeInfo | None = None
         self._current_return_tuple_info: TupleTypeInfo | None = None
@@ -518,6 +913,18 @@ class SymbolTableVisitor(zincVisitor):
         self._loop_depth = 0
         self._try_context_stack: list[dict[str, object | None]] = []
         self.operator_calls: dict[tuple[str | None, tuple[int, int]], ResolvedOperatorCall] = {}
+        # Diagnostics for statements that failed to check; reported together by resolve()
+        self.type_errors: list[Diagnostic] = []
+        # Names spelled in failed statements, which may never have been bound; not reported as unknown later
+        self._failed_statement_names: set[str] = set()
+        # Local bindings read anywhere, keyed like `_binding_key`; the rest are reported as unused
+        self._read_bindings: set[tuple[str, str, str]] = set()
+        # String bindings grown in place, keyed like `_binding_key`; codegen keeps them in an owned `String`
+        self._appended_bindings: set[tuple[str, str, str]] = set()
+        # Unreachable code found while checking; reported with the unused-name warnings
+        self._flow_warnings: list[Diagnostic] = []
+        # Warnings for a program that checked cleanly; see `_collect_unused_warnings`
+        self.warnings: list[Diagnostic] = []
 
     def _resolve_const_symbol(self, path: list[str]) -> ConstInstance | None:
         """Resolve a const path in the current module."""
@@ -673,7 +1080,7 @@ class SymbolTableVisitor(zincVisitor):
     def _parse_type_annotation_text(self, type_name: str):
         """Best-effort parse of a Zinc type annotation outside the main tree."""
         if any(char in type_name for char in "<>[](),->"):
-            parser = ZincParser(CommonTokenStream(ZincLexer(InputStream(type_name))))
+            parser = ZincParser(CommonTokenStream(source_lexer(type_name)))
             type_ctx = parser.type_()
             if parser.getNumberOfSyntaxErrors() == 0 and parser.getCurrentToken().type == -1:
                 return type_ctx
@@ -681,7 +1088,7 @@ class SymbolTableVisitor(zincVisitor):
 
     def _parse_required_type_annotation_text(self, type_name: str, label: str):
         """Parse a Zinc type annotation string, raising a type error on failure."""
-        parser = ZincParser(CommonTokenStream(ZincLexer(InputStream(type_name))))
+        parser = ZincParser(CommonTokenStream(source_lexer(type_name)))
         type_ctx = parser.type_()
         if parser.getNumberOfSyntaxErrors() > 0 or parser.getCurrentToken().type != -1:
             raise ZincTypeError(f"invalid type annotation '{type_name}' in {label}")
@@ -751,8 +1158,25 @@ class SymbolTableVisitor(zincVisitor):
         When a call site is visited, creates a specialization in the Atlas with
         concrete argument types. Then processes each specialization.
         """
+        try:
+            with default_integer_type(self.atlas.module_graph.default_int):
+                self._resolve_all()
+        except ZincTypeError as exc:
+            self._record_type_error(exc, None)
+        if self.type_errors:
+            raise ZincTypeErrors(sorted(self.type_errors, key=_diagnostic_position))
+        self._apply_appended_bindings()
+        warnings = dict.fromkeys([*self._collect_unused_warnings(), *self._flow_warnings])
+        self.warnings = sorted(warnings, key=_diagnostic_position)
+        return self.symbols
+
+    def _resolve_all(self) -> None:
+        """Resolve consts, types, and every function specialization; see `resolve`."""
         self._register_builtins()
         self._validate_decorator_targets()
+        for symbol in self.module_graph.top_level_symbols.values():
+            if symbol.kind == "function":
+                self._reject_builtin_name(symbol.ctx)
 
         for const in self.atlas.consts.values():
             self._resolve_const(const)
@@ -762,6 +1186,7 @@ class SymbolTableVisitor(zincVisitor):
             self._analyze_struct(struct)
         for enum in self.atlas.enums.values():
             self._analyze_enum(enum)
+        self._align_interface_receivers()
 
         # Two-phase processing to handle function return types correctly:
         # Phase 1: Discover all specializations (process callers first to find call sites)
@@ -796,12 +1221,183 @@ class SymbolTableVisitor(zincVisitor):
             after = tuple(self.atlas.functions.keys())
             if after == before:
                 break
+        self._check_memoized_functions()
 
-        return self.symbols
+    def _check_memoized_functions(self) -> None:
+        """Check that each `@memoize` specialization keys its cache on integers, bools, and strings, and returns a clonable value."""
+        for func in self.atlas.functions.values():
+            if not has_marker_decorator(func.ctx, "memoize"):
+                continue
+            for param, arg_type in zip(function_parameters(func.ctx), func.arg_types, strict=False):
+                if arg_type not in MEMO_KEY_TYPES:
+                    raise ZincTypeError(
+                        f"memoized function '{func.name}' can't cache on its {arg_type.name.lower()} parameter '{param.name}'",
+                        ctx=func.ctx,
+                        notes=("@memoize keys its cache on the arguments, which must be integers, bools, or strings",),
+                    )
+            if func.return_type in {BaseType.VOID, BaseType.NEVER}:
+                raise ZincTypeError(f"memoized function '{func.name}' must return a value to cache", ctx=func.ctx)
+            if func.return_type in UNMEMOIZABLE_RETURN_TYPES:
+                raise ZincTypeError(f"memoized function '{func.name}' can't cache its {func.return_type.name.lower()} result", ctx=func.ctx)
+
+    def _binding_key(self, unique_name: str, name: str) -> tuple[str, str, str] | None:
+        """Identify a local binding across specializations: function, block path, and name."""
+        scope = unique_name.rpartition("/")[0].rpartition(".")[0]
+        if not scope:
+            return None
+        function_scope, _, block_path = scope.partition(".")
+        func = self.atlas.functions.get(function_scope)
+        return (func.qualified_name if func is not None else function_scope, block_path, name)
+
+    def _mark_appended(self, symbol: Symbol | None) -> None:
+        """Record that a string variable is grown in place, following closure captures to the binding that owns it."""
+        while symbol is not None and symbol.is_captured_ref and symbol.binding_unique_name:
+            symbol = self.symbols.lookup_by_unique_name(symbol.binding_unique_name)
+        if symbol is None or symbol.id is None:
+            return
+        key = self._binding_key(symbol.declaration or symbol.unique_name, symbol.id)
+        if key is not None:
+            self._appended_bindings.add(key)
+
+    def _apply_appended_bindings(self) -> None:
+        """Flag every symbol of an appended binding, including those a later pass over its function defined again."""
+        for symbol in self.symbols.all_symbols():
+            if symbol.kind in {SymbolKind.VARIABLE, SymbolKind.PARAMETER} and symbol.id is not None:
+                symbol.is_appended = self._binding_key(symbol.declaration or symbol.unique_name, symbol.id) in self._appended_bindings
+
+    def _mark_read(self, symbol: Symbol | None) -> None:
+        """Record that a local binding's value is used, following closure captures to the captured binding."""
+        while symbol is not None and symbol.is_captured_ref and symbol.binding_unique_name:
+            symbol = self.symbols.lookup_by_unique_name(symbol.binding_unique_name)
+        if symbol is None or symbol.id is None:
+            return
+        key = self._binding_key(symbol.declaration or symbol.unique_name, symbol.id)
+        if key is not None:
+            self._read_bindings.add(key)
+
+    def _collect_unused_warnings(self) -> list[Diagnostic]:
+        """Warn about local bindings that are never read and private functions that are never called.
+
+        Names starting with an underscore are exempt from the binding check, so
+        `_unused = compute()` documents an intentionally ignored value.
+        """
+        sites: dict[tuple[str, str, str], tuple[str, int, int]] = {}
+        for symbol in self.symbols.all_symbols():
+            if symbol.kind not in {SymbolKind.VARIABLE, SymbolKind.PARAMETER} or symbol.is_captured_ref:
+                continue
+            if not symbol.id or symbol.id.startswith("_") or symbol.id == "self":
+                continue
+            key = self._binding_key(symbol.declaration or symbol.unique_name, symbol.id)
+            if key is None or key in self._read_bindings:
+                continue
+            func = self.atlas.functions.get(symbol.unique_name.partition(".")[0])
+            if func is None or symbol.declaration != symbol.unique_name:
+                continue
+            site = self._binding_site(func.module_id, symbol)
+            if site is not None and (key not in sites or site < sites[key]):
+                sites[key] = site
+        warnings = [
+            Diagnostic(
+                f"unused variable '{name}'",
+                file,
+                line,
+                column,
+                column + len(name),
+                severity="warning",
+                suggestions=(f"if this is intentional, prefix it with an underscore: '_{name}'",),
+                code="Z0011",
+            )
+            for (_, _, name), (file, line, column) in sites.items()
+        ]
+        called = {func.qualified_name for func in self.atlas.functions.values()}
+        for symbol in self.module_graph.top_level_symbols.values():
+            if symbol.kind != "function" or symbol.is_public or symbol.qualified_name in called or decorators_from_ctx(symbol.ctx):
+                continue
+            name_ctx = symbol.ctx.functionName() if isinstance(symbol.ctx, ZincParser.FunctionDeclarationContext) else symbol.ctx
+            name_token = name_ctx.IDENTIFIER().getSymbol()
+            warnings.append(
+                Diagnostic(
+                    f"function '{symbol.name}' is never used",
+                    getattr(name_token.getInputStream(), "name", None),
+                    name_token.line,
+                    name_token.column,
+                    name_token.column + len(symbol.name),
+                    severity="warning",
+                    code="Z0012",
+                )
+            )
+        return warnings
+
+    def _binding_site(self, module_id: str, symbol: Symbol) -> tuple[str, int, int] | None:
+        """File, line, and column of the name token that introduced a binding."""
+        tree = self.module_graph.get_module(module_id).tree
+        tokens = tree.parser.getTokenStream()
+        start, stop = symbol.source_interval
+        for index in range(max(start, 0), min(stop, len(tokens.tokens) - 1) + 1):
+            token = tokens.get(index)
+            if token.type == ZincParser.IDENTIFIER and token.text == symbol.id:
+                return getattr(token.getInputStream(), "name", None) or "", token.line, token.column
+        return None
+
+    def _record_type_error(self, exc: ZincTypeError, stmt: ParserRuleContext | None) -> None:
+        """Remember an error once; functions are checked in both resolution phases."""
+        if isinstance(exc, ZincTypeErrors):
+            diagnostics = exc.diagnostics
+        else:
+            node = exc.ctx if exc.ctx is not None else stmt
+            file, line, column, end_column = node_span(node) if node is not None else (None, None, None, None)
+            diagnostic = Diagnostic(
+                str(exc),
+                file,
+                line,
+                column,
+                end_column,
+                label=mismatch_label(exc.expected, exc.found),
+                notes=exc.notes,
+                suggestions=exc.suggestions,
+                code=exc.code,
+            )
+            diagnostics = [diagnostic]
+        for diagnostic in diagnostics:
+            if diagnostic not in self.type_errors:
+                self.type_errors.append(diagnostic)
+
+    def _visit_statement_recovering(self, stmt) -> None:
+        """Check a statement; on a type error, record it and restore scopes so the next statement can be checked."""
+        scope_depth = len(self.symbols._scope_stack)
+        scope_path = list(self.symbols._scope_path)
+        function_scope = self.symbols._function_scope
+        loop_depth = self._loop_depth
+        stack_depths = (len(self._iterating_dict_stack), len(self._try_context_stack), len(self._lexical_function_scopes))
+        try:
+            self.visit(stmt)
+        except ZincTypeError as exc:
+            self._record_type_error(exc, stmt)
+            self._failed_statement_names |= identifier_names(stmt)
+            del self.symbols._scope_stack[scope_depth:]
+            self.symbols._scope_path = scope_path
+            self.symbols._function_scope = function_scope
+            self._loop_depth = loop_depth
+            del self._iterating_dict_stack[stack_depths[0] :]
+            del self._try_context_stack[stack_depths[1] :]
+            del self._lexical_function_scopes[stack_depths[2] :]
+
+    def _reject_builtin_name(self, ctx: ZincParser.FunctionDeclarationContext | ZincParser.AsyncFunctionDeclarationContext) -> None:
+        """Reject a function named after a builtin, since calls by that name would run the builtin."""
+        name = function_name_from_ctx(ctx) if isinstance(ctx, ZincParser.FunctionDeclarationContext) else ctx.IDENTIFIER().getText()
+        if name in BUILTIN_RETURN_TYPES:
+            raise ZincTypeError(
+                f"function '{name}' has the name of the builtin '{name}()'",
+                ctx=ctx.functionName() if isinstance(ctx, ZincParser.FunctionDeclarationContext) else ctx,
+                notes=(f"calls to '{name}' would run the builtin, never this function",),
+                suggestions=("rename the function",),
+                code="Z0016",
+            )
 
     def _validate_decorator_targets(self) -> None:
         """Reject decorator forms that are parsed but not implemented yet."""
         for symbol in self.module_graph.top_level_symbols.values():
+            self._validate_marker_decorators(symbol.ctx, symbol.name, symbol.kind)
             has_decorators = bool(decorators_from_ctx(symbol.ctx))
             if has_decorators:
                 if symbol.kind == "struct":
@@ -813,31 +1409,116 @@ class SymbolTableVisitor(zincVisitor):
             if isinstance(symbol.ctx, ZincParser.StructDeclarationContext):
                 for member_ctx in symbol.ctx.structBody().structMember():
                     method_ctx = member_ctx.functionDeclaration()
+                    if method_ctx is not None:
+                        self._validate_marker_decorators(method_ctx, function_display_name_from_ctx(method_ctx), "method")
                     if method_ctx is not None and decorators_from_ctx(method_ctx):
                         display_name = function_display_name_from_ctx(method_ctx)
                         raise ZincTypeError(f"method decorator support is not implemented yet: '{display_name}'")
             if isinstance(symbol.ctx, ZincParser.EnumDeclarationContext):
                 for method_ctx in symbol.ctx.enumBody().functionDeclaration():
+                    self._validate_marker_decorators(method_ctx, function_display_name_from_ctx(method_ctx), "method")
                     if decorators_from_ctx(method_ctx):
                         display_name = function_display_name_from_ctx(method_ctx)
                         raise ZincTypeError(f"method decorator support is not implemented yet: '{display_name}'")
 
+    def _validate_marker_decorators(self, ctx, label: str, kind: str) -> None:
+        """Check built-in marker decorators such as `@test` on a declaration of `kind` (function, method, struct, ...)."""
+        for info in marker_decorators_from_ctx(ctx):
+            if info.display_name == "derive":
+                self._validate_derive(info, label, kind)
+                continue
+            if info.has_call:
+                raise ZincTypeError(f"@{info.display_name} does not take arguments")
+            if info.display_name in {"inline", "cold"}:
+                if kind not in {"function", "method"}:
+                    raise ZincTypeError(f"@{info.display_name} can only be applied to top-level functions and methods: '{label}'")
+                continue
+            if info.display_name == "memoize":
+                if kind != "function":
+                    raise ZincTypeError(f"@memoize can only be applied to top-level functions: '{label}'")
+                if isinstance(ctx, ZincParser.AsyncFunctionDeclarationContext):
+                    raise ZincTypeError(f"memoized function '{label}' must not be async")
+                continue
+            if info.display_name == "interface":
+                if kind != "struct":
+                    raise ZincTypeError(f"@interface can only be applied to structs: '{label}'")
+                continue
+            if kind != "function":
+                raise ZincTypeError(f"@{info.display_name} can only be applied to top-level functions: '{label}'")
+            if ctx.parameterList() is not None:
+                raise ZincTypeError(f"{info.display_name} function '{label}' must not take parameters")
+            if info.display_name == "bench" and isinstance(ctx, ZincParser.AsyncFunctionDeclarationContext):
+                raise ZincTypeError(f"bench function '{label}' must not be async")
+
+    def _validate_derive(self, info: DecoratorInfo, label: str, kind: str) -> None:
+        """Check that `@derive(...)` names known implementations and sits on a struct or enum."""
+        if kind not in {"struct", "enum"}:
+            raise ZincTypeError(f"@derive can only be applied to structs and enums: '{label}'")
+        arguments = info.argument_list_ctx.argument() if info.argument_list_ctx is not None else []
+        if not arguments:
+            raise ZincTypeError(f"@derive on '{label}' needs at least one of: {', '.join(DERIVES)}")
+        for argument in arguments:
+            if argument.getText() not in DERIVES:
+                raise ZincTypeError(
+                    f"unknown derive '{argument.getText()}' on '{label}'", ctx=argument, suggestions=(f"expected one of: {', '.join(DERIVES)}",)
+                )
+
+    def _require_json_value(self, info: ResolvedValueInfo) -> None:
+        """Reject to_json() arguments with no JSON form: callables, channels, and types without @derive(json)."""
+        if info.base_type in {BaseType.VOID, BaseType.NEVER, BaseType.CALLABLE, BaseType.CHANNEL, BaseType.CONTEXT}:
+            raise ZincTypeError(f"to_json() cannot serialize {info.base_type.name.lower()} values")
+        owner, kind = (info.struct_qualified_name, "struct") if info.base_type == BaseType.STRUCT else (info.exact_type, "enum")
+        if info.base_type in {BaseType.STRUCT, BaseType.ENUM} and "json" not in self._derives(owner):
+            name = self.module_graph.split_qualified_name(owner)[1] if owner else kind
+            raise ZincTypeError(f"to_json() needs @derive(json) on {kind} '{name}'", suggestions=(f"add @derive(json) to {kind} '{name}'",))
+
+    def _require_format_arguments(self, format_expr, argument_count: int) -> None:
+        """Check that print()'s arguments after the first fill the `{}` placeholders of a string literal, one each."""
+        literal = format_string_literal(format_expr)
+        if literal is None:
+            raise ZincTypeError(
+                "print() with more than one argument needs a double-quoted string literal first",
+                suggestions=('write the values into a literal like print("{} and {}", a, b)',),
+                code="Z0005",
+            )
+        placeholders = count_format_placeholders(literal.getText())
+        if placeholders != argument_count:
+            raise ZincTypeError(
+                f"print() has {argument_count} value{'s' if argument_count != 1 else ''} "
+                f"for {placeholders} {{}} placeholder{'s' if placeholders != 1 else ''}",
+                notes=("each {} is filled by the next argument after the string",),
+                code="Z0005",
+            )
+
+    def _require_display(self, info: ResolvedValueInfo) -> None:
+        """Let print() and `{}` show a struct or enum, through its to_string() method when it declares one.
+
+        Generated types then get Display, which falls back to the Debug form that
+        print_debug() uses, so those impls are needed too.
+        """
+        kind = "struct" if info.base_type == BaseType.STRUCT else "enum"
+        qualified_name = info.struct_qualified_name if kind == "struct" else info.exact_type
+        if is_meta_struct_qname(qualified_name) or qualified_name == COMPONENT_ORDER_QNAME:
+            return  # compile-time metadata, which prints in its own form
+        self.atlas.uses_display = True
+        self.atlas.uses_debug_print = True
+        if qualified_name is None:
+            return
+        if kind == "struct":
+            owner = self._analyze_struct_by_qualified_name(qualified_name)
+        else:
+            owner = self._analyze_enum_by_qualified_name(qualified_name)
+        declared = any(method.name == DISPLAY_METHOD for method in owner.methods)
+        if declared and display_method(owner.methods) is None:
+            raise ZincTypeError(
+                f"{kind} '{owner.name}' can't be printed with its {DISPLAY_METHOD}() method",
+                notes=(f"print() uses {DISPLAY_METHOD}() when it takes no arguments, returns a string, and doesn't change the {kind}",),
+                suggestions=(f"rename the method, or make {DISPLAY_METHOD}() take no arguments and return a string",),
+            )
+
     def _register_builtins(self) -> None:
         """Register built-in functions."""
-        for name, base_type in (
-            ("print", BaseType.VOID),
-            ("chan", BaseType.CHANNEL),
-            ("close", BaseType.VOID),
-            ("dict", BaseType.DICT),
-            ("sort_dict", BaseType.DICT),
-            ("set", BaseType.SET),
-            ("sort_set", BaseType.SET),
-            ("meta", BaseType.STRUCT),
-            ("type", BaseType.STRUCT),
-            ("line", BaseType.INTEGER),
-            ("has_component", BaseType.BOOLEAN),
-            ("implements", BaseType.BOOLEAN),
-        ):
+        for name, base_type in BUILTIN_RETURN_TYPES.items():
             self.symbols.define(
                 id=name,
                 kind=SymbolKind.BUILTIN,
@@ -856,10 +1537,13 @@ class SymbolTableVisitor(zincVisitor):
         """Analyze a struct declaration and populate fields/methods."""
         analyzed = self._analyze_struct_by_qualified_name(struct.qualified_name)
         struct.fields = [self._copy_struct_field(field) for field in analyzed.fields]
+        struct.static_fields = [self._copy_struct_field(field) for field in analyzed.static_fields]
         struct.methods = [self._copy_struct_method(method) for method in analyzed.methods]
         struct.composition_mode = analyzed.composition_mode
         struct.composition_sources = analyzed.composition_sources
         struct.has_decorators = analyzed.has_decorators
+        struct.is_interface = analyzed.is_interface
+        struct.interfaces = analyzed.interfaces
 
     def _analyze_enum(self, enum) -> None:
         """Analyze an enum declaration and populate variants/methods."""
@@ -886,6 +1570,7 @@ class SymbolTableVisitor(zincVisitor):
             module_id=symbol.module_id,
             ctx=symbol.ctx,
             has_decorators=bool(decorators_from_ctx(symbol.ctx)),
+            is_interface=has_marker_decorator(symbol.ctx, "interface"),
         )
 
         self._struct_analysis_stack.append(qualified_name)
@@ -897,7 +1582,10 @@ class SymbolTableVisitor(zincVisitor):
             field_indexes: dict[str, int] = {}
             methods: list[StructMethodInfo] = []
             method_indexes: dict[str, int] = {}
+            interfaces: list[StructInstance] = []
 
+            if composition is not None and struct.is_interface:
+                raise ZincTypeError(f"interface '{symbol.name}' cannot compose other structs")
             if composition is not None:
                 struct.composition_mode = composition.mode
                 source_names: list[str] = []
@@ -912,6 +1600,9 @@ class SymbolTableVisitor(zincVisitor):
                         raise ZincTypeError(f"struct '{symbol.name}' cannot compose itself")
                     source_names.append(source_symbol.qualified_name)
                     source_struct = self._analyze_struct_by_qualified_name(source_symbol.qualified_name)
+                    if source_struct.is_interface:
+                        interfaces.append(source_struct)
+                        continue
                     self._merge_struct_fields(
                         owner_name=symbol.name,
                         mode=composition.mode,
@@ -931,7 +1622,27 @@ class SymbolTableVisitor(zincVisitor):
 
                 struct.composition_sources = tuple(source_names)
 
-            local_fields = self._parse_struct_fields(ctx, qualified_name)
+            local_fields = []
+            parsed_fields = self._parse_struct_fields(ctx, qualified_name)
+            if parsed_fields and struct.is_interface:
+                raise ZincTypeError(f"interface '{symbol.name}' cannot declare fields")
+            field_ctxs = {
+                member.structField().IDENTIFIER().getText(): member.structField()
+                for member in (ctx.structBody().structMember() if ctx.structBody() else [])
+                if member.structField()
+            }
+            for field in parsed_fields:
+                field_ctx = field_ctxs.get(field.name)
+                if field_ctx is None or not is_static_field(field_ctx):
+                    local_fields.append(field)
+                    continue
+                if field.default_value is None or field.resolved_type not in STATIC_FIELD_TYPES:
+                    raise ZincTypeError(f"static field '{symbol.name}.{field.name}' needs a literal initial value", ctx=field_ctx)
+                if field.resolved_type in (BaseType.INTEGER, BaseType.FLOAT):
+                    field.exact_type = parse_numeric_literal(field.default_value).exact_type
+                else:
+                    field.exact_type = default_exact_type(field.resolved_type)
+                struct.static_fields.append(field)
             self._merge_struct_fields(
                 owner_name=symbol.name,
                 mode=struct.composition_mode,
@@ -955,6 +1666,8 @@ class SymbolTableVisitor(zincVisitor):
                                 constructor_owner_qualified_name=qualified_name,
                             )
                         )
+            if struct.is_interface:
+                self._check_interface_methods(symbol.name, local_methods)
 
             self._merge_struct_methods(
                 owner_name=symbol.name,
@@ -964,9 +1677,15 @@ class SymbolTableVisitor(zincVisitor):
                 incoming=local_methods,
                 allow_override=struct.composition_mode == "merge",
             )
+            if interfaces:
+                self._inherit_interface_methods(symbol.name, interfaces, methods, method_indexes)
+                struct.interfaces = tuple(interface.qualified_name for interface in interfaces)
 
-            if struct.composition_mode is not None:
+            if struct.composition_mode is not None or struct.is_interface:
                 self._validate_composed_struct_methods(symbol.name, fields, methods)
+            next_method = iterator_next_method(methods)
+            if next_method is not None:
+                next_method.self_mutability = "&mut self"  # for loops step the iterator through Rust's Iterator::next
 
             struct.fields = fields
             struct.methods = methods
@@ -1127,6 +1846,89 @@ class SymbolTableVisitor(zincVisitor):
                 )
             methods[existing_index] = copied
 
+    def _check_interface_methods(self, interface_name: str, methods: list[StructMethodInfo]) -> None:
+        """Make an interface's methods instance methods, and require typed parameters on the ones without a body."""
+        for method in methods:
+            if method.operator_symbol is not None:
+                raise ZincTypeError(f"interface '{interface_name}' cannot declare operator '{method.operator_symbol}'")
+            if is_required_method(method):
+                for name, type_ann, _resolved in method.parameters:
+                    if type_ann is None:
+                        label = f"{interface_name}.{method.name}"
+                        raise ZincTypeError(f"parameter '{name}' of interface method '{label}' needs a type annotation")
+            method.is_static = False
+            method.self_mutability = method.self_mutability or "&self"
+
+    def _inherit_interface_methods(
+        self,
+        owner_name: str,
+        interfaces: list[StructInstance],
+        methods: list[StructMethodInfo],
+        method_indexes: dict[str, int],
+    ) -> None:
+        """Give a struct the default methods of the interfaces it implements, and check the ones it provides itself."""
+        declared_by: dict[str, str] = {}
+        for interface in interfaces:
+            for method in interface.methods:
+                if method.name in declared_by:
+                    raise ZincTypeError(
+                        f"interfaces '{declared_by[method.name]}' and '{interface.name}' of '{owner_name}' both declare '{method.name}'"
+                    )
+                declared_by[method.name] = interface.name
+                index = method_indexes.get(method.name)
+                if index is None:
+                    if is_required_method(method):
+                        raise ZincTypeError(
+                            f"struct '{owner_name}' does not implement '{method.name}' from interface '{interface.name}'",
+                            suggestions=(f"add fn {self._interface_method_signature(method)} to '{owner_name}'",),
+                        )
+                    method_indexes[method.name] = len(methods)
+                    methods.append(self._copy_struct_method(method))
+                    continue
+                implementation = methods[index]
+                expected = self._interface_method_signature(method)
+                found = self._interface_method_signature(implementation)
+                if expected != found:
+                    raise ZincTypeError(
+                        f"'{owner_name}.{method.name}' does not match interface '{interface.name}'",
+                        notes=(f"expected fn {expected}", f"found fn {found}"),
+                    )
+                implementation.is_static = False
+                implementation.self_mutability = implementation.self_mutability or "&self"
+
+    def _interface_method_signature(self, method: StructMethodInfo) -> str:
+        """Render a method's parameter and return types the way its Rust trait signature spells them."""
+        params = ", ".join(
+            f"{name}: {normalize_exact_type(type_ann or resolved) or default_exact_type(BaseType.INTEGER)}"
+            for name, type_ann, resolved in method.parameters
+        )
+        return_type = f" -> {normalize_exact_type(method.return_type)}" if method.return_type else ""
+        return f"{method.name}({params}){return_type}"
+
+    def _align_interface_receivers(self) -> None:
+        """Give each interface method one receiver across the trait and its impls: `&mut self` when any of them writes."""
+        for interface in self.atlas.structs.values():
+            if not interface.is_interface:
+                continue
+            implementers = [struct for struct in self.atlas.structs.values() if interface.qualified_name in struct.interfaces]
+            versions = {method.name: [method] for method in interface.methods}
+            for struct in implementers:
+                for candidate in struct.methods:
+                    if candidate.name in versions:
+                        versions[candidate.name].append(candidate)
+            mutable = {name for name, candidates in versions.items() if any(m.self_mutability == "&mut self" for m in candidates)}
+            # A default body that calls a mutating method through self mutates too.
+            changed = True
+            while changed:
+                changed = False
+                for method in interface.methods:
+                    if method.name not in mutable and self._self_method_calls(method.body_ctx) & mutable:
+                        mutable.add(method.name)
+                        changed = True
+            for name in mutable:
+                for version in versions[name]:
+                    version.self_mutability = "&mut self"
+
     def _member_source_name(self, qualified_name: str | None) -> str:
         """Render a human-readable source-struct name for diagnostics."""
         if qualified_name is None:
@@ -1338,6 +2140,7 @@ class SymbolTableVisitor(zincVisitor):
             else self._infer_return_type(
                 ctx.block(),
                 field_types,
+                {name: resolved or type_ann for name, type_ann, resolved in resolved_params if resolved or type_ann},
                 source_module_id,
                 constructor_owner_qualified_name,
             )
@@ -1632,11 +2435,15 @@ class SymbolTableVisitor(zincVisitor):
         self,
         block_ctx,
         field_types: dict[str, str],
+        param_types: dict[str, str],
         source_module_id: str,
         constructor_owner_qualified_name: str,
     ) -> str | None:
         """Infer return type from return statements."""
 
+        def is_literal(expr_ctx) -> bool:
+            return isinstance(expr_ctx, ZincParser.PrimaryExprContext) and expr_ctx.primaryExpression().literal() is not None
+
         def get_expr_type(expr_ctx) -> str | None:
             """Get type of an expression if we can infer it."""
             if expr_ctx is None:
@@ -1668,6 +2475,8 @@ class SymbolTableVisitor(zincVisitor):
                         return type_to_rust(literal_type)
                     except ValueError:
                         pass
+                if primary and primary.IDENTIFIER():
+                    return param_types.get(primary.IDENTIFIER().getText())
 
             # self.field access
             if isinstance(expr_ctx, ZincParser.MemberAccessExprContext):
@@ -1688,10 +2497,11 @@ class SymbolTableVisitor(zincVisitor):
 
             # Binary expressions - infer from operands
             if isinstance(expr_ctx, (ZincParser.AdditiveExprContext, ZincParser.MultiplicativeExprContext)):
-                left_type = get_expr_type(expr_ctx.expression(0))
-                right_type = get_expr_type(expr_ctx.expression(1))
-                # Return the first non-None type (both should be same for valid ops)
-                return left_type or right_type
+                left, right = expr_ctx.expression(0), expr_ctx.expression(1)
+                # A literal adopts the other operand's type, so prefer the operand that isn't one
+                if is_literal(left) and not is_literal(right):
+                    left, right = right, left
+                return get_expr_type(left) or get_expr_type(right)
 
             # Parenthesized expression
             if isinstance(expr_ctx, ZincParser.ParenExprContext):
@@ -1812,6 +2622,22 @@ class SymbolTableVisitor(zincVisitor):
                         param_types,
                     )
 
+    def _self_method_calls(self, node) -> set[str]:
+        """Return the names of the methods a method body calls through `self.name(...)`."""
+        names: set[str] = set()
+        if node is None:
+            return names
+        if isinstance(node, ZincParser.FunctionCallExprContext) and isinstance(node.expression(), ZincParser.MemberAccessExprContext):
+            receiver = node.expression().expression()
+            if isinstance(receiver, ZincParser.PrimaryExprContext) and receiver.getText() == "self":
+                names.add(node.expression().IDENTIFIER().getText())
+        if hasattr(node, "getChildCount"):
+            for i in range(node.getChildCount()):
+                child = node.getChild(i)
+                if isinstance(child, ParserRuleContext):
+                    names |= self._self_method_calls(child)
+        return names
+
     def _method_body_uses_self(self, node) -> bool:
         """Return True when a method body references the special self binding."""
         if node is None:
@@ -2061,7 +2887,8 @@ class SymbolTableVisitor(zincVisitor):
                     option_info=self._copy_option_info(default_symbol.option_info) if default_symbol else None,
                 ):
                     raise ZincTypeError(
-                        f"parameter '{spec.name}' default expects a compatible '{spec.ctx.typeAlternative().getText()}' value"
+                        f"parameter '{spec.name}' default expects a compatible '{spec.ctx.typeAlternative().getText()}' value",
+                        code="Z0004",
                     )
                 continue
             if type_ctx is None:
@@ -2105,7 +2932,7 @@ class SymbolTableVisitor(zincVisitor):
                 expected_option=expected_option,
                 actual_option=self._copy_option_info(default_symbol.option_info) if default_symbol else None,
             ):
-                raise ZincTypeError(f"parameter '{spec.name}' default expects a compatible '{type_ctx.getText()}' value")
+                raise ZincTypeError(f"parameter '{spec.name}' default expects a compatible '{type_ctx.getText()}' value", code="Z0004")
 
     def _literal_constant_value_for_expr(self, expr_ctx, expr_symbol: Symbol | None) -> object | None:
         """Return a fit-check constant only for literal expressions, not typed bindings."""
@@ -2265,7 +3092,7 @@ class SymbolTableVisitor(zincVisitor):
         if type_ctx.LBRACK():
             (
                 element_type,
-                _nested_array,
+                element_array,
                 _nested_dict,
                 _nested_set,
                 element_tuple,
@@ -2286,6 +3113,8 @@ class SymbolTableVisitor(zincVisitor):
                     element_anonymous_struct_info=element_anonymous_struct_info,
                     element_result_info=element_result_info,
                     element_option_info=element_option_info,
+                    element_array_info=element_array,
+                    length=array_length(type_ctx),
                 ),
                 None,
                 None,
@@ -2743,6 +3572,31 @@ class SymbolTableVisitor(zincVisitor):
                 return len(arr_lit.expression()) == 0
         return False
 
+    def _fit_array_literal(self, expr_ctx, length: int | None) -> None:
+        """Give a plain array literal assigned to a fixed-size array type that array's length."""
+        if length is None or not isinstance(expr_ctx, ZincParser.PrimaryExprContext):
+            return
+        literal = expr_ctx.primaryExpression().arrayLiteral()
+        symbol = self._expr_symbol(expr_ctx)
+        if literal is None or symbol is None or symbol.array_length is not None:
+            return
+        count = len(literal.expression())
+        if count != length:
+            raise ZincTypeError(
+                f"a fixed-size array of length {length} can't hold {count} elements",
+                ctx=expr_ctx,
+                expected=f"{length} elements",
+                found=f"{count} elements",
+            )
+        symbol.array_length = length
+        symbol.constant_value = None
+
+    def _type_empty_array_literal(self, expr_ctx, array_info: ArrayTypeInfo) -> None:
+        """Give an empty array literal the element type its annotation declares, as in rows: [[i64]] = []."""
+        symbol = self._expr_symbol(expr_ctx)
+        if symbol is not None and array_info.length is None and self._is_empty_array_literal(expr_ctx):
+            self._apply_array_info_to_symbol(symbol, array_info)
+
     def _copy_dict_info(self, info: DictTypeInfo | None) -> DictTypeInfo | None:
         """Copy dict metadata so symbols do not accidentally share construction state."""
         return info.copy() if info is not None else None
@@ -2955,6 +3809,8 @@ class SymbolTableVisitor(zincVisitor):
         if info.base_type == BaseType.ARRAY and info.array_info is not None:
             temp.element_type = info.array_info.element_type
             temp.element_exact_type = info.array_info.element_exact_type
+            temp.array_length = info.array_info.length
+            temp.element_array_info = self._copy_array_info(info.array_info.element_array_info)
             temp.tuple_info = self._copy_tuple_info(info.array_info.element_tuple_info)
             temp.callable_info = self._copy_callable_info(info.array_info.element_callable_info)
             temp.element_struct_qualified_name = info.array_info.element_struct_qualified_name
@@ -3136,10 +3992,21 @@ class SymbolTableVisitor(zincVisitor):
         result = self._finish_operator_resolution(ctx, symbol, candidates)
         if result is not None:
             return result
+        if symbol in {"==", "!="} and left_owner is not None and left_owner == right_owner and "eq" in self._derives(left_owner):
+            return None
         if require_overload or left_owner is not None or right_owner is not None:
-            raise ZincTypeError(f"operator '{symbol}' is not defined for operands")
+            suggestions = ()
+            if symbol in {"==", "!="} and left_owner is not None and left_owner == right_owner:
+                name = self.module_graph.split_qualified_name(left_owner)[1]
+                suggestions = (f"add @derive(eq) to struct '{name}' to compare its values field by field",)
+            raise ZincTypeError(f"operator '{symbol}' is not defined for operands", suggestions=suggestions, code="Z0006")
         return None
 
+    def _derives(self, qualified_name: str | None) -> frozenset[str]:
+        """The `@derive(...)` implementations of a struct or enum declaration."""
+        symbol = self.module_graph.top_level_symbols.get(qualified_name) if qualified_name else None
+        return derives_from_ctx(symbol.ctx) if symbol is not None else frozenset()
+
     def _resolve_unary_operator(self, ctx, symbol: str, operand_info: ResolvedValueInfo) -> ResolvedValueInfo | None:
         """Resolve an overloaded unary operator, if any applies."""
         owner = operand_info.struct_qualified_name if operand_info.base_type == BaseType.STRUCT else None
@@ -3153,7 +4020,7 @@ class SymbolTableVisitor(zincVisitor):
         if result is not None:
             return result
         if owner is not None:
-            raise ZincTypeError(f"operator '{symbol}' is not defined for operand")
+            raise ZincTypeError(f"operator '{symbol}' is not defined for operand", code="Z0006")
         return None
 
     def _resolve_index_operator(
@@ -3172,14 +4039,15 @@ class SymbolTableVisitor(zincVisitor):
         if result is not None:
             return result
         if owner is not None:
-            raise ZincTypeError("index operator is not defined for struct")
+            raise ZincTypeError("index operator is not defined for struct", code="Z0006")
         return None
 
     def _module_source_file(self, module_id: str | None) -> str:
-        """Return the source path for a module id."""
+        """Return the source path for a module id, relative to the package root so generated code doesn't depend on where it was built."""
         if module_id is None:
             return ""
-        return self.module_graph.get_module(module_id).path.as_posix()
+        path = self.module_graph.get_module(module_id).path
+        return Path(os.path.relpath(path, self.module_graph.package_root)).as_posix()
 
     def _public_fqn(self, qualified_name: str | None) -> str:
         """Render a public slash-separated FQN."""
@@ -3252,7 +4120,7 @@ class SymbolTableVisitor(zincVisitor):
         if isinstance(value, int):
             return self._type_meta_from_base(
                 BaseType.INTEGER,
-                exact_type="i64",
+                exact_type=default_exact_type(BaseType.INTEGER),
             )
         if isinstance(value, float):
             return self._type_meta_from_base(
@@ -3335,6 +4203,19 @@ class SymbolTableVisitor(zincVisitor):
         """Return coarse return TypeMeta information for a built-in function."""
         builtin_returns = {
             "print": self._type_meta_from_base(BaseType.VOID),
+            "assert": self._type_meta_from_base(BaseType.VOID),
+            "assert_eq": self._type_meta_from_base(BaseType.VOID),
+            "exit": self._type_meta_from_base(BaseType.VOID),
+            "on_interrupt": self._type_meta_from_base(BaseType.VOID),
+            "dbg": self._type_meta_from_base(BaseType.VOID),
+            "print_debug": self._type_meta_from_base(BaseType.VOID),
+            "uuid": self._type_meta_from_base(BaseType.STRING),
+            "to_json": self._type_meta_from_base(BaseType.STRING),
+            "temp_file": self._type_meta_from_base(BaseType.STRING),
+            "temp_dir": self._type_meta_from_base(BaseType.STRING),
+            "include_str": self._type_meta_from_base(BaseType.STRING),
+            "matrix": self._type_meta_from_base(BaseType.ARRAY),
+            "format_number": self._type_meta_from_base(BaseType.STRING),
             "chan": self._type_meta_from_base(BaseType.CHANNEL),
             "close": self._type_meta_from_base(BaseType.VOID),
             "dict": self._type_meta_from_base(BaseType.DICT),
@@ -3406,7 +4287,7 @@ class SymbolTableVisitor(zincVisitor):
             return self.symbols.define_temp(
                 resolved_type=BaseType.INTEGER,
                 interval=interval,
-                exact_type="i64",
+                exact_type=default_exact_type(BaseType.INTEGER),
                 constant_value=value,
                 line_num=line_num,
             )
@@ -4438,7 +5319,7 @@ class SymbolTableVisitor(zincVisitor):
                     enum_info = self._analyze_enum_by_qualified_name(enum_symbol.qualified_name)
                     variant = next((item for item in enum_info.variants if item.name == variant_name), None)
                     if variant is None:
-                        raise ZincTypeError(f"unknown enum variant '{'.'.join(path)}'")
+                        raise ZincTypeError(f"unknown enum variant '{'.'.join(path)}'", code="Z0008")
                     return self._variant_meta_from_info(enum_info, variant)
             receiver_symbol = self._expr_symbol(expr_ctx.expression())
             if receiver_symbol is None:
@@ -4761,7 +5642,129 @@ class SymbolTableVisitor(zincVisitor):
         if refined:
             return
 
-        raise ZincTypeError(f"{label} must be a bool")
+        found = self._value_type_label(self._value_info_for_value_context(expr_ctx))
+        suggestions = ()
+        if expr_type in (BaseType.INTEGER, BaseType.FLOAT):
+            suggestions = (f"compare explicitly, e.g. `{expr_ctx.getText()} != 0`",)
+        raise ZincTypeError(f"{label} must be a bool", ctx=expr_ctx, expected="bool", found=found, suggestions=suggestions)
+
+    @staticmethod
+    def _value_type_label(info: ResolvedValueInfo) -> str:
+        """Short name of a value's type for diagnostics."""
+        if info.struct_qualified_name is not None:
+            return info.struct_qualified_name.rsplit("::", 1)[-1]
+        return exact_type_to_rust(info.exact_type, info.base_type)
+
+    def _visible_value_names(self) -> set[str]:
+        """Locals, nested functions, and top-level functions and consts visible here; candidates for 'did you mean'."""
+        names = self.symbols.visible_names()
+        for scope in self._lexical_function_scopes:
+            names.update(scope)
+        if self._current_module is not None:
+            names.update(self.module_graph.visible_names(self._current_module, {"function", "const"}))
+        return names
+
+    def _unknown_variable_error(self, name: str, ctx: ParserRuleContext) -> ZincTypeError:
+        """The error for reading a name with no binding in scope.
+
+        When the enclosing function does assign the name, the read either comes
+        before the first assignment or after the block that assigned it has ended.
+        """
+        sites = _assignment_sites(ctx, name)
+        if not sites:
+            return ZincTypeError(
+                f"unknown variable '{name}'",
+                ctx=ctx,
+                suggestions=did_you_mean(name, self._visible_value_names()),
+                code="Z0001",
+            )
+        earlier = [site for site in sites if site.stop.tokenIndex < ctx.start.tokenIndex]
+        if not earlier:
+            return ZincTypeError(
+                f"variable '{name}' is read before it is assigned",
+                ctx=ctx,
+                notes=(f"'{name}' is first assigned on line {sites[0].start.line}",),
+                suggestions=(f"assign '{name}' a value before this read",),
+                code="Z0002",
+            )
+        return ZincTypeError(
+            f"variable '{name}' is only assigned inside an earlier block",
+            ctx=ctx,
+            notes=(f"'{name}' is assigned on line {earlier[-1].start.line}, and a variable first assigned in a block goes away when the block ends",),
+            suggestions=(f"assign '{name}' before that block so every path to this read gives it a value",),
+            code="Z0002",
+        )
+
+    def _require_interpolated_names(self, ctx: ZincParser.LiteralContext, text: str) -> None:
+        """Reject an interpolation whose leading name isn't bound here, as a bare read of it would be."""
+        for expr in re.findall(r"\{([^}]+)\}", text):
+            match = re.match(r"\s*([^\W\d]\w*)\s*(::)?", expr)
+            if match is None or match.group(2) or match.group(1) in INTERPOLATION_KEYWORDS:
+                continue
+            name = match.group(1)
+            if (symbol := self.symbols.lookup_by_id(name)) is not None:
+                if member := re.match(r"\s*\.\s*([^\W\d]\w*)", expr[match.end() :]):
+                    self._require_named_field_access(symbol, name, member.group(1), ctx)
+                elif not expr[match.end() :].strip() and symbol.resolved_type in {BaseType.STRUCT, BaseType.ENUM}:
+                    self._require_display(self._value_info_from_symbol(symbol.resolved_type, symbol))
+                continue
+            if name in self._failed_statement_names:
+                continue
+            if self._current_module is not None and (
+                name in self.module_graph.visible_names(self._current_module) or self.module_graph.resolve_alias(self._current_module, name)
+            ):
+                continue
+            if name not in self._visible_function_names():
+                raise self._unknown_variable_error(name, ctx)
+
+    def _require_named_field_access(
+        self, receiver_symbol: Symbol, receiver: str, member_name: str, ctx: ParserRuleContext, *, write: bool = False
+    ) -> None:
+        """Apply `_require_field_access` to `receiver.member` when the receiver is a named struct with that field."""
+        struct_qualified_name = self._struct_qualified_name_for_symbol(receiver_symbol)
+        struct = self.atlas.structs.get(struct_qualified_name) if struct_qualified_name else None
+        field = next((candidate for candidate in struct.fields if candidate.name == member_name), None) if struct else None
+        if field is not None:
+            self._require_field_access(struct, field, ctx, receiver, write=write)
+
+    def _require_field_access(
+        self, struct: StructInstance, field: StructFieldInfo, ctx: ParserRuleContext, receiver: str, *, write: bool = False
+    ) -> None:
+        """Reject using a private (`_`-prefixed) field outside the methods of the struct that has it.
+
+        Methods a struct gets through composition keep access to the fields
+        their own struct declared. Struct literals may still set private fields.
+        """
+        if not field.is_private:
+            return
+        node = ctx.parentCtx
+        while node is not None and not isinstance(node, ZincParser.StructDeclarationContext):
+            node = node.parentCtx
+        if node is not None:
+            for owner in (struct.qualified_name, field.source_struct_qualified_name):
+                symbol = self.module_graph.top_level_symbols.get(owner) if owner else None
+                if symbol is not None and symbol.ctx is node:
+                    return
+        accessor = None if write else next((method.name for method in struct.methods if _returns_field(method, field.name)), None)
+        if accessor is not None:
+            suggestion = f"use the accessor instead: '{receiver}.{accessor}()'"
+        elif write:
+            suggestion = f"add a method to '{struct.name}' that updates 'self.{field.name}' and call that instead"
+        else:
+            suggestion = f"add a method to '{struct.name}' that returns 'self.{field.name}' and call that instead"
+        raise ZincTypeError(
+            f"field '{field.name}' of struct '{struct.name}' is private",
+            ctx=ctx,
+            notes=(f"fields whose names start with '_' can only be used inside the methods of '{struct.name}'",),
+            suggestions=(suggestion,),
+        )
+
+    def _visible_function_names(self) -> set[str]:
+        """Everything a bare call could name here: values, builtins, and extern Rust functions."""
+        names = self._visible_value_names() | BUILTIN_FUNCTIONS
+        if self._current_module is not None:
+            names.update(self.module_graph.get_module(self._current_module).rust_extern_functions)
+        return names
 
     def _merge_array_info(
         self,
@@ -4899,10 +5902,11 @@ class SymbolTableVisitor(zincVisitor):
             return incoming
         if incoming.base_type == BaseType.NEVER:
             return current
+        mismatch = {"expected": self._value_type_label(current), "found": self._value_type_label(incoming)}
         if current.base_type == BaseType.VOID or incoming.base_type == BaseType.VOID:
             if current.base_type == incoming.base_type:
                 return ResolvedValueInfo(BaseType.VOID)
-            raise ZincTypeError(f"{label} have incompatible types")
+            raise ZincTypeError(f"{label} have incompatible types", **mismatch, code="Z0010")
 
         merged_type = current.base_type
         if merged_type == BaseType.UNKNOWN:
@@ -4910,7 +5914,7 @@ class SymbolTableVisitor(zincVisitor):
         elif incoming.base_type != BaseType.UNKNOWN and incoming.base_type != merged_type:
             promoted = promote_numeric(merged_type, incoming.base_type)
             if promoted == BaseType.UNKNOWN:
-                raise ZincTypeError(f"{label} have incompatible types")
+                raise ZincTypeError(f"{label} have incompatible types", **mismatch, code="Z0010")
             merged_type = promoted
 
         merged = ResolvedValueInfo(
@@ -4962,7 +5966,7 @@ class SymbolTableVisitor(zincVisitor):
                 incoming.struct_qualified_name,
                 incoming.anonymous_struct_info,
             ):
-                raise ZincTypeError(f"{label} have incompatible types")
+                raise ZincTypeError(f"{label} have incompatible types", **mismatch, code="Z0010")
             merged.struct_qualified_name = current.struct_qualified_name or incoming.struct_qualified_name
             merged.anonymous_struct_info = self._copy_anonymous_struct_info(current.anonymous_struct_info or incoming.anonymous_struct_info)
         return merged
@@ -4974,6 +5978,7 @@ class SymbolTableVisitor(zincVisitor):
                 fn_ctx = stmt.functionDeclaration()
                 if function_is_operator(fn_ctx):
                     raise ZincTypeError("operator declarations must be inside structs")
+                self._reject_builtin_name(fn_ctx)
                 name = function_name_from_ctx(fn_ctx)
                 self._register_lexical_function_stub(
                     name,
@@ -4983,6 +5988,7 @@ class SymbolTableVisitor(zincVisitor):
                 )
             elif stmt.asyncFunctionDeclaration():
                 fn_ctx = stmt.asyncFunctionDeclaration()
+                self._reject_builtin_name(fn_ctx)
                 self._register_lexical_function_stub(
                     fn_ctx.IDENTIFIER().getText(),
                     fn_ctx,
@@ -5036,19 +6042,78 @@ class SymbolTableVisitor(zincVisitor):
             statements = list(block_ctx.statement())
             if not as_value:
                 for stmt in statements:
-                    self.visit(stmt)
+                    self._visit_statement_recovering(stmt)
+                self._warn_unreachable_statements(statements)
                 return None
             if not statements:
                 return ResolvedValueInfo(BaseType.VOID)
             for stmt in statements[:-1]:
-                self.visit(stmt)
-            return self._visit_tail_statement_as_value(statements[-1])
+                self._visit_statement_recovering(stmt)
+            value = self._visit_tail_statement_as_value(statements[-1])
+            self._warn_unreachable_statements(statements)
+            return value
         finally:
             self._lexical_function_scopes.pop()
 
+    def _warning(self, message: str, node: ParserRuleContext, note: str) -> None:
+        """Record a control-flow warning about a parse tree node."""
+        file, line, column, end_column = node_span(node)
+        self._flow_warnings.append(Diagnostic(message, file, line, column, end_column, severity="warning", notes=(note,), code="Z0013"))
+
+    def _warn_unreachable_statements(self, statements: list) -> None:
+        """Warn once per block about the first statement after one that never completes."""
+        for index, stmt in enumerate(statements):
+            description = self._diverging_statement(stmt)
+            if description is None:
+                continue
+            # Nested declarations are hoisted, so they stay reachable.
+            following = [later for later in statements[index + 1 :] if not _is_declaration(later)]
+            if following:
+                note = f"any code following this {description} on line {stmt.start.line} is unreachable"
+                self._warning("unreachable statement", following[0], note)
+            return
+
+    def _diverging_statement(self, stmt) -> str | None:
+        """Describe a statement after which control never continues in the same block, or return None."""
+        for keyword, child in (
+            ("return", stmt.returnStatement()),
+            ("fail", stmt.failStatement()),
+            ("break", stmt.breakStatement()),
+            ("continue", stmt.continueStatement()),
+        ):
+            if child is not None:
+                return f"`{keyword}`"
+        if stmt.block() is not None:
+            return "block" if self._block_diverges(stmt.block()) else None
+        if (if_ctx := stmt.ifStatement()) is not None:
+            has_else = len(if_ctx.block()) > len(if_ctx.expression())
+            return "`if`" if has_else and all(self._block_diverges(block) for block in if_ctx.block()) else None
+        if (loop_ctx := stmt.loopStatement()) is not None:
+            return "`loop`" if not _loop_breaks(loop_ctx.block()) else None
+        if (expr_ctx := stmt.expressionStatement()) is not None:
+            symbol = self._expr_symbol(expr_ctx.expression())
+            return "call" if symbol is not None and symbol.resolved_type == BaseType.NEVER else None
+        return None
+
+    def _block_diverges(self, block_ctx) -> bool:
+        return any(self._diverging_statement(stmt) is not None for stmt in block_ctx.statement())
+
+    def _warn_constant_branches(self, conditions: list, blocks: list) -> None:
+        """Warn about if/else-if/else branches ruled out by a literal `true` or `false` condition."""
+        for index, condition in enumerate(conditions):
+            value = _literal_condition(condition)
+            if value is False:
+                self._warning("unreachable branch", blocks[index], "its condition is always false")
+            elif value is True:
+                if index + 1 < len(blocks):
+                    self._warning("unreachable branch", blocks[index + 1], f"the condition on line {condition.start.line} is always true")
+                return
+
     def _analyze_if_expression_ctx(self, ctx) -> ResolvedValueInfo:
         """Resolve the value type of an expression-form if."""
         self._require_boolean_condition(ctx.expression(), "if condition")
+        else_ctx = (ctx.block(1) if len(ctx.block()) > 1 else ctx.ifExpression()) if ctx.ELSE() else None
+        self._warn_constant_branches([ctx.expression()], [ctx.block(0), *([else_ctx] if else_ctx is not None else [])])
         then_scope = self._next_block_name("if")
         self.symbols.enter_scope(then_scope)
         try:
@@ -5076,6 +6141,7 @@ class SymbolTableVisitor(zincVisitor):
 
     def _analyze_if_statement_as_value(self, ctx: ZincParser.IfStatementContext) -> ResolvedValueInfo:
         """Resolve the value type of a statement-form if used in tail position."""
+        self._warn_constant_branches(ctx.expression(), ctx.block())
         if len(ctx.block()) > len(ctx.expression()):
             else_scope = self._next_block_name("if")
             self.symbols.enter_scope(else_scope)
@@ -5112,6 +6178,8 @@ class SymbolTableVisitor(zincVisitor):
             element_anonymous_struct_info=self._copy_anonymous_struct_info(symbol.element_anonymous_struct_info),
             element_result_info=self._copy_result_info(symbol.result_info),
             element_option_info=self._copy_option_info(symbol.option_info),
+            element_array_info=self._copy_array_info(symbol.element_array_info),
+            length=symbol.array_length,
         )
 
     def _apply_array_info_to_symbol(self, symbol: Symbol, array_info: ArrayTypeInfo | None) -> None:
@@ -5126,6 +6194,8 @@ class SymbolTableVisitor(zincVisitor):
         symbol.element_anonymous_struct_info = self._copy_anonymous_struct_info(array_info.element_anonymous_struct_info)
         symbol.result_info = self._copy_result_info(array_info.element_result_info)
         symbol.option_info = self._copy_option_info(array_info.element_option_info)
+        symbol.array_length = array_info.length
+        symbol.element_array_info = self._copy_array_info(array_info.element_array_info)
 
     def _merge_callable_info(
         self,
@@ -5697,6 +6767,8 @@ class SymbolTableVisitor(zincVisitor):
             actual_exact_type=actual.element_exact_type,
         ):
             return False
+        if expected.length != actual.length:
+            return False
         if expected.element_type == BaseType.STRUCT:
             return self._structs_compatible(
                 expected.element_struct_qualified_name,
@@ -5712,6 +6784,8 @@ class SymbolTableVisitor(zincVisitor):
                 or actual.element_callable_info is None
                 or expected.element_callable_info.structural_key() == actual.element_callable_info.structural_key()
             )
+        if expected.element_type == BaseType.ARRAY and expected.element_array_info and actual.element_array_info:
+            return self._array_elements_compatible(expected.element_array_info, actual.element_array_info)
         return True
 
     def _tuple_infos_compatible(
@@ -5797,6 +6871,15 @@ class SymbolTableVisitor(zincVisitor):
         if any(arg.name is not None or arg.is_spread for arg in raw_args):
             raise ZincTypeError(f"{label} does not accept named arguments")
 
+    def _is_plain_variable_initializer(self, ctx) -> bool:
+        """Return whether an expression is the whole right-hand side of `name = expr`."""
+        parent = ctx.parentCtx
+        return (
+            isinstance(parent, ZincParser.VariableAssignmentContext)
+            and parent.assignmentOperator().getText() == "="
+            and parent.assignmentTarget().IDENTIFIER() is not None
+        )
+
     def _parameter_specs_from_ctx(self, ctx, owner_module_id: str | None) -> list[ParameterSpec]:
         """Build parameter binding specs from a function-like parse node."""
         return [
@@ -6014,9 +7097,20 @@ class SymbolTableVisitor(zincVisitor):
                     if index < len(callable_info.param_exact_types) and callable_info.param_exact_types[index] is not None
                     else function.params[index].type_text
                 )
-                raise ZincTypeError(f"{label} parameter '{function.params[index].name}' expects a compatible '{expected_label}' value")
+                raise ZincTypeError(
+                    f"{label} parameter '{function.params[index].name}' expects a compatible '{expected_label}' value",
+                    code="Z0004",
+                )
 
-        return self._return_info_from_callable_info(callable_info)
+        return_info = self._return_info_from_callable_info(callable_info)
+        if return_info.base_type == BaseType.ARRAY and function.return_type is not None:
+            # Callable metadata has no array return slot; recover element typing from the declaration.
+            return_ctx = self._parse_required_type_annotation_text(
+                function.return_type,
+                f"extern function '{function.display_name or function.name}'",
+            )
+            return_info.array_info = self._type_metadata_from_type_ctx(return_ctx)[1]
+        return return_info
 
     def _bind_call_arguments(
         self,
@@ -6053,13 +7147,13 @@ class SymbolTableVisitor(zincVisitor):
             if raw.name is not None:
                 saw_named = True
                 if raw.name not in name_to_index:
-                    raise ZincTypeError(f"{label} got an unknown named argument '{raw.name}'")
+                    raise ZincTypeError(f"{label} got an unknown named argument '{raw.name}'", code="Z0005")
                 index = name_to_index[raw.name]
             else:
                 if saw_named:
-                    raise ZincTypeError(f"{label} positional arguments must come before named arguments")
+                    raise ZincTypeError(f"{label} positional arguments must come before named arguments", code="Z0005")
                 if positional_index >= len(specs):
-                    raise ZincTypeError(f"{label} got too many arguments")
+                    raise ZincTypeError(f"{label} got too many arguments", code="Z0005")
                 index = positional_index
                 positional_index += 1
 
@@ -6075,7 +7169,7 @@ class SymbolTableVisitor(zincVisitor):
             if bound[index] is not None:
                 continue
             if spec.default_expr is None:
-                raise ZincTypeError(f"{label} missing required argument '{spec.name}'")
+                raise ZincTypeError(f"{label} missing required argument '{spec.name}'", code="Z0005")
             bound[index] = BoundArgument(
                 expression=spec.default_expr,
                 parameter_name=spec.name,
@@ -6137,15 +7231,15 @@ class SymbolTableVisitor(zincVisitor):
             if raw.name is not None:
                 saw_named = True
                 if raw.name not in name_to_index:
-                    raise ZincTypeError(f"{label} got an unknown named argument '{raw.name}'")
+                    raise ZincTypeError(f"{label} got an unknown named argument '{raw.name}'", code="Z0005")
                 index = name_to_index[raw.name]
                 if index == 0:
                     raise ZincTypeError(f"{label} got duplicate receiver argument '{raw.name}'")
             else:
                 if saw_named:
-                    raise ZincTypeError(f"{label} positional arguments must come before named arguments")
+                    raise ZincTypeError(f"{label} positional arguments must come before named arguments", code="Z0005")
                 if positional_index >= len(specs):
-                    raise ZincTypeError(f"{label} got too many arguments")
+                    raise ZincTypeError(f"{label} got too many arguments", code="Z0005")
                 index = positional_index
                 positional_index += 1
 
@@ -6161,7 +7255,7 @@ class SymbolTableVisitor(zincVisitor):
             if bound[index] is not None:
                 continue
             if spec.default_expr is None:
-                raise ZincTypeError(f"{label} missing required argument '{spec.name}'")
+                raise ZincTypeError(f"{label} missing required argument '{spec.name}'", code="Z0005")
             bound[index] = BoundArgument(
                 expression=spec.default_expr,
                 parameter_name=spec.name,
@@ -6204,6 +7298,16 @@ class SymbolTableVisitor(zincVisitor):
                     return False
         return True
 
+    def _member_names(self, receiver_ctx) -> set[str]:
+        """Built-in members, fields, and methods of a receiver; candidates for 'did you mean'."""
+        receiver_type = self.visit(receiver_ctx)
+        names = set(BUILTIN_MEMBER_NAMES.get(receiver_type, ()))
+        if receiver_type == BaseType.STRUCT:
+            struct = self.atlas.structs.get(self._struct_qualified_name_for_symbol(self._expr_symbol(receiver_ctx)))
+            if struct is not None:
+                names.update(member.name for member in (*struct.fields, *struct.methods))
+        return names
+
     def _receiver_has_real_member_for_ufcs(self, receiver_ctx, member_name: str) -> bool:
         """Return True when normal member lookup should own this call name."""
         receiver_type = self.visit(receiver_ctx)
@@ -6216,7 +7320,7 @@ class SymbolTableVisitor(zincVisitor):
         if member_name in {"len", "is_empty", "contains", "contains_key"}:
             return receiver_type in {BaseType.ARRAY, BaseType.DICT, BaseType.SET, BaseType.STRING}
         if receiver_type == BaseType.ARRAY:
-            return member_name == "push"
+            return member_name in _LIST_MEMBERS
         if receiver_type == BaseType.DICT:
             return member_name in {"insert", "get", "contains_key", "remove", "clear", "keys", "values", "items"}
         if receiver_type == BaseType.SET:
@@ -6411,6 +7515,7 @@ class SymbolTableVisitor(zincVisitor):
             return_info = ResolvedValueInfo(
                 base_type=func_instance.return_type,
                 exact_type=func_instance.return_exact_type,
+                array_info=self._copy_array_info(func_instance.return_array_info),
                 dict_info=self._copy_dict_info(func_instance.return_dict_info),
                 set_info=self._copy_set_info(func_instance.return_set_info),
                 tuple_info=self._copy_tuple_info(func_instance.return_tuple_info),
@@ -6500,7 +7605,10 @@ class SymbolTableVisitor(zincVisitor):
                     if index < len(callable_info.param_exact_types) and callable_info.param_exact_types[index] is not None
                     else function.params[index].type_text
                 )
-                raise ZincTypeError(f"{label} parameter '{function.params[index].name}' expects a compatible '{expected_label}' value")
+                raise ZincTypeError(
+                    f"{label} parameter '{function.params[index].name}' expects a compatible '{expected_label}' value",
+                    code="Z0004",
+                )
 
         return self._return_info_from_callable_info(callable_info)
 
@@ -6513,20 +7621,7 @@ class SymbolTableVisitor(zincVisitor):
 
         receiver_ctx = callee_ctx.expression()
         member_name = callee_ctx.IDENTIFIER().getText()
-        if member_name in {
-            "print",
-            "chan",
-            "close",
-            "dict",
-            "sort_dict",
-            "set",
-            "sort_set",
-            "meta",
-            "type",
-            "line",
-            "has_component",
-            "implements",
-        }:
+        if member_name in BUILTIN_FUNCTIONS:
             return None
         if not self._ufcs_receiver_is_value_candidate(receiver_ctx):
             return None
@@ -6573,7 +7668,11 @@ class SymbolTableVisitor(zincVisitor):
 
         receiver_type = self.visit(receiver_ctx)
         if receiver_type != BaseType.UNKNOWN:
-            raise ZincTypeError(f"no member '{member_name}' and no visible function '{member_name}' for UFCS")
+            raise ZincTypeError(
+                f"no member '{member_name}' and no visible function '{member_name}' for UFCS",
+                ctx=ctx.expression(),
+                suggestions=did_you_mean(member_name, self._member_names(receiver_ctx) | self._visible_function_names()),
+            )
         return None
 
     def _default_integer_exact_override(self, bound_arg: BoundArgument) -> str | None:
@@ -6611,6 +7710,46 @@ class SymbolTableVisitor(zincVisitor):
                 symbol.exact_type = exact_override
         return arg_type
 
+    def _widen_literal_arguments(self, bound_args: list[BoundArgument], arg_exact_types: list[str | None]) -> None:
+        """Give unsuffixed number arguments the width their untyped parameter is used at.
+
+        `add(x, 1)` with `x: i32` specializes `add` for `(i32, i32)` when its body adds the two
+        parameters. A parameter that is annotated, or is returned as or assigned to an annotated type,
+        lends that type to the parameters it meets. Literals with no such evidence keep their default width.
+        """
+        for i, bound_arg in enumerate(bound_args):
+            if bound_arg.is_default or bound_arg.value_info is not None:
+                continue
+            literal = _unsuffixed_literal(bound_arg.expression)
+            parameter_ctx = bound_arg.parameter_ctx
+            if literal is None or not isinstance(parameter_ctx, ZincParser.ParameterContext) or parameter_ctx.typeAlternative():
+                continue
+            function_ctx = parameter_ctx.parentCtx
+            while function_ctx is not None and not isinstance(function_ctx, FUNCTION_CONTEXTS):
+                function_ctx = function_ctx.parentCtx
+            if function_ctx is None:
+                continue
+
+            groups, annotations = _parameter_type_links(function_ctx, {arg.parameter_name for arg in bound_args})
+            for other in bound_args:
+                if isinstance(other.parameter_ctx, ZincParser.ParameterContext) and other.parameter_ctx.typeAlternative():
+                    annotations[other.parameter_name] = other.parameter_ctx.typeAlternative().getText()
+            linked = next((group for group in groups if bound_arg.parameter_name in group), {bound_arg.parameter_name})
+            candidates = [self._exact_type_name_from_text(annotations[name]) for name in sorted(linked) if name in annotations]
+            candidates += [
+                arg_exact_types[j]
+                for j, other in enumerate(bound_args)
+                if j != i and other.parameter_name in linked and _unsuffixed_literal(other.expression) is None
+            ]
+            literal_base = parse_numeric_literal(literal).base_type
+            widened = next((exact for exact in candidates if exact and exact_type_to_base(exact) == literal_base), None)
+            if widened is None:
+                continue
+            arg_exact_types[i] = widened
+            symbol = self._expr_symbol(bound_arg.expression)
+            if symbol is not None:
+                symbol.exact_type = widened
+
     def _collect_bound_argument_info(self, bound_args: list[BoundArgument]):
         """Collect canonical argument types and rich metadata for a bound call."""
         arg_types: list[BaseType] = []
@@ -6716,6 +7855,7 @@ class SymbolTableVisitor(zincVisitor):
                 if anonymous_struct_info is not None:
                     arg_anonymous_struct_infos[i] = self._copy_anonymous_struct_info(anonymous_struct_info) or AnonymousStructTypeInfo()
 
+        self._widen_literal_arguments(bound_args, arg_exact_types)
         return (
             arg_types,
             arg_exact_types,
@@ -6864,7 +8004,7 @@ class SymbolTableVisitor(zincVisitor):
             expected_option=expected.option_info,
             actual_option=self._current_return_option_info,
         ):
-            raise ZincTypeError(f"function '{func.name}' expects a compatible callable return value")
+            raise ZincTypeError(f"function '{func.name}' expects a compatible callable return value", code="Z0015")
 
         expected_callable = self._copy_callable_info(expected.callable_info)
         if expected.base_type == BaseType.CALLABLE:
@@ -6892,6 +8032,7 @@ class SymbolTableVisitor(zincVisitor):
         self._lexical_function_scopes = []
         self._current_return_type = BaseType.VOID  # Reset for this function
         self._current_return_exact_type = None
+        self._current_return_array_info = None
         self._current_return_dict_info = None
         self._current_return_set_info = None
         self._current_return_tuple_info = None
@@ -6923,6 +8064,8 @@ class SymbolTableVisitor(zincVisitor):
                 capture_symbol.channel_info = self._copy_channel_info(capture.channel_info)
                 capture_symbol.element_type = capture.element_type
                 capture_symbol.element_exact_type = capture.element_exact_type
+                capture_symbol.array_length = capture.array_length
+                capture_symbol.element_array_info = self._copy_array_info(capture.element_array_info)
                 capture_symbol.dict_info = self._copy_dict_info(capture.dict_info)
                 capture_symbol.set_info = self._copy_set_info(capture.set_info)
                 capture_symbol.tuple_info = self._copy_tuple_info(capture.tuple_info)
@@ -6979,7 +8122,10 @@ class SymbolTableVisitor(zincVisitor):
                     result_info=func.arg_result_infos.get(i),
                     option_info=func.arg_option_infos.get(i),
                 ):
-                    raise ZincTypeError(f"parameter '{param_name}' expects a compatible '{param_ctx.typeAlternative().getText()}' value")
+                    raise ZincTypeError(
+                        f"parameter '{param_name}' expects a compatible '{param_ctx.typeAlternative().getText()}' value",
+                        code="Z0004",
+                    )
             elif type_ctx is not None:
                 (
                     annotated_type,
@@ -7019,7 +8165,13 @@ class SymbolTableVisitor(zincVisitor):
                     expected_option=annotated_option_info,
                     actual_option=func.arg_option_infos.get(i),
                 ):
-                    raise ZincTypeError(f"parameter '{param_name}' expects a compatible '{type_ctx.getText()}' value")
+                    raise ZincTypeError(
+                        f"parameter '{param_name}' expects a compatible '{type_ctx.getText()}' value",
+                        ctx=param_ctx,
+                        expected=type_ctx.getText(),
+                        found=exact_type_to_rust(param_exact_type, param_type),
+                        code="Z0004",
+                    )
                 if declared_exact_type is not None:
                     param_exact_type = declared_exact_type
             param_symbol = self.symbols.define(
@@ -7120,7 +8272,7 @@ class SymbolTableVisitor(zincVisitor):
                 expected_exact_type=self._exact_type_name_from_type_ctx(ctx.type_()),
                 actual_exact_type=self._current_return_exact_type,
                 expected_array=annotated_return_array_info,
-                actual_array=None,
+                actual_array=self._current_return_array_info,
                 expected_dict=annotated_return_dict_info,
                 actual_dict=self._current_return_dict_info,
                 expected_set=annotated_return_set_info,
@@ -7138,7 +8290,16 @@ class SymbolTableVisitor(zincVisitor):
                 expected_option=annotated_return_option_info,
                 actual_option=self._current_return_option_info,
             ):
-                raise ZincTypeError(f"function '{func.name}' expects a compatible '{ctx.type_().getText()}' return value")
+                found = exact_type_to_rust(self._current_return_exact_type, self._current_return_type)
+                if self._current_return_struct_qualified_name is not None:
+                    found = self._current_return_struct_qualified_name.rsplit("::", 1)[-1]
+                raise ZincTypeError(
+                    f"function '{func.name}' expects a compatible '{ctx.type_().getText()}' return value",
+                    ctx=ctx.type_(),
+                    expected=ctx.type_().getText(),
+                    found=found,
+                    code="Z0015",
+                )
             if annotated_return_type == BaseType.CALLABLE:
                 annotated_return_callable_info = self._merge_callable_info(
                     annotated_return_callable_info,
@@ -7147,6 +8308,7 @@ class SymbolTableVisitor(zincVisitor):
                 )
             self._current_return_type = annotated_return_type
             self._current_return_exact_type = self._exact_type_name_from_type_ctx(ctx.type_())
+            self._current_return_array_info = self._copy_array_info(annotated_return_array_info)
             self._current_return_dict_info = self._copy_dict_info(annotated_return_dict_info)
             self._current_return_set_info = self._copy_set_info(annotated_return_set_info)
             self._current_return_tuple_info = self._copy_tuple_info(annotated_return_tuple_info)
@@ -7161,6 +8323,7 @@ class SymbolTableVisitor(zincVisitor):
         # Store the inferred return type
         func.return_type = self._current_return_type
         func.return_exact_type = self._current_return_exact_type
+        func.return_array_info = self._copy_array_info(self._current_return_array_info)
         func.return_dict_info = self._copy_dict_info(self._current_return_dict_info)
         func.return_set_info = self._copy_set_info(self._current_return_set_info)
         func.return_tuple_info = self._copy_tuple_info(self._current_return_tuple_info)
@@ -7198,6 +8361,7 @@ class SymbolTableVisitor(zincVisitor):
                         )
                         or CallableTypeInfo()
                     )
+        func.arg_borrows = self._borrowed_arguments(func, ctx, param_names)
 
         self.symbols.exit_scope()
         self._current_function = None
@@ -7220,7 +8384,7 @@ class SymbolTableVisitor(zincVisitor):
 
     def _sanitize_generated_name(self, text: str) -> str:
         """Convert a compiler-generated name fragment into a Rust-safe identifier."""
-        cleaned = re.sub(r"[^0-9A-Za-z_]+", "_", text)
+        cleaned = re.sub(r"\W+", "_", text)
         cleaned = cleaned.strip("_")
         return cleaned or "closure"
 
@@ -7291,6 +8455,8 @@ class SymbolTableVisitor(zincVisitor):
             option_info=self._copy_option_info(symbol.option_info),
             element_struct_qualified_name=symbol.element_struct_qualified_name,
             element_anonymous_struct_info=self._copy_anonymous_struct_info(symbol.element_anonymous_struct_info),
+            array_length=symbol.array_length,
+            element_array_info=self._copy_array_info(symbol.element_array_info),
         )
 
     def _analyze_lexical_captures(self, info: LexicalFunctionInfo) -> None:
@@ -7332,24 +8498,7 @@ class SymbolTableVisitor(zincVisitor):
             module = self.module_graph.get_module(self._current_module)
             top_level_names.update(module.symbols.keys())
             top_level_names.update(module.injected_symbols.keys())
-        top_level_names.update(
-            {
-                "print",
-                "chan",
-                "close",
-                "dict",
-                "sort_dict",
-                "set",
-                "sort_set",
-                "meta",
-                "type",
-                "line",
-                "has_component",
-                "implements",
-                "ComponentOrder",
-                "Context",
-            }
-        )
+        top_level_names.update({*BUILTIN_FUNCTIONS, "ComponentOrder", "Context"})
 
         def record_capture(name: str) -> None:
             if name in local_names or name in local_function_names or name in top_level_names:
@@ -7872,7 +9021,7 @@ class SymbolTableVisitor(zincVisitor):
         """Resolve a decorator path and validate that it names a top-level function."""
         symbol = self.module_graph.resolve_top_level_path(module_id, list(decorator.path))
         if symbol is None:
-            raise ZincTypeError(f"unknown decorator '{decorator.display_name}'")
+            raise ZincTypeError(f"unknown decorator '{decorator.display_name}'", code="Z0014")
         if symbol.kind != "function":
             raise ZincTypeError(f"decorator '{decorator.display_name}' is not callable")
         if isinstance(symbol.ctx, ZincParser.AsyncFunctionDeclarationContext):
@@ -7906,7 +9055,7 @@ class SymbolTableVisitor(zincVisitor):
         ]
         for index, spec in enumerate(specs[1:], start=1):
             if spec.default_expr is None:
-                raise ZincTypeError(f"{label} missing required argument '{spec.name}'")
+                raise ZincTypeError(f"{label} missing required argument '{spec.name}'", code="Z0005")
             bound.append(
                 BoundArgument(
                     expression=spec.default_expr,
@@ -8396,7 +9545,8 @@ class SymbolTableVisitor(zincVisitor):
                 ):
                     raise ZincTypeError(
                         f"parameter '{param_ctx.IDENTIFIER().getText()}' expects a compatible "
-                        f"'{param_ctx.typeAlternative().getText()}' value"
+                        f"'{param_ctx.typeAlternative().getText()}' value",
+                        code="Z0004",
                     )
                 continue
 
@@ -8425,7 +9575,28 @@ class SymbolTableVisitor(zincVisitor):
                 expected_option=expected_option_info,
                 actual_option=actual_option_info,
             ):
-                raise ZincTypeError(f"parameter '{param_ctx.IDENTIFIER().getText()}' expects a compatible '{type_ctx.getText()}' value")
+                found = exact_type_to_rust(actual_symbol.exact_type if actual_symbol else arg_exact_types[i], actual_type)
+                raise ZincTypeError(
+                    f"parameter '{param_ctx.IDENTIFIER().getText()}' expects a compatible '{type_ctx.getText()}' value",
+                    ctx=actual_expr,
+                    expected=type_ctx.getText(),
+                    found=actual_struct_qualified_name.rsplit("::", 1)[-1] if actual_struct_qualified_name else found,
+                    notes=self._inferred_type_notes(actual_expr),
+                    code="Z0004",
+                )
+
+    def _inferred_type_notes(self, expr_ctx: ParserRuleContext | None) -> tuple[str, ...]:
+        """Say where an unannotated variable got its type when it is given to an annotated variable or parameter."""
+        if not isinstance(expr_ctx, ZincParser.PrimaryExprContext) or expr_ctx.primaryExpression().IDENTIFIER() is None:
+            return ()
+        name = expr_ctx.getText()
+        symbol = self.symbols.lookup_by_id(name)
+        if symbol is None or symbol.has_declared_type or symbol.kind not in {SymbolKind.VARIABLE, SymbolKind.PARAMETER}:
+            return ()
+        inferred = exact_type_to_rust(symbol.exact_type, symbol.resolved_type)
+        if symbol.kind == SymbolKind.PARAMETER:
+            return (f"'{name}' has no type annotation, so it takes the type of its argument, '{inferred}'",)
+        return (f"'{name}' has no type annotation; it was inferred as '{inferred}' from its assignment on line {symbol.line_num}",)
 
     def _current_function_ctx(self):
         """Return the parse context for the current function-like owner."""
@@ -8547,6 +9718,12 @@ class SymbolTableVisitor(zincVisitor):
     def visitLiteral(self, ctx: ZincParser.LiteralContext) -> BaseType:
         """Visit a literal and create a symbol for it."""
         text = ctx.getText()
+        if is_interpolated_string_literal(text):
+            for name in re.findall(r"[^\W\d]\w*", " ".join(re.findall(r"\{([^}]+)\}", text))):
+                symbol = self.symbols.lookup_by_id(name)
+                if symbol is not None:
+                    self._mark_read(symbol)
+            self._require_interpolated_names(ctx, text)
         base_type = parse_literal(text)
         parsed_number = parse_numeric_literal(text) if base_type in {BaseType.INTEGER, BaseType.FLOAT} else None
         self.symbols.define_temp(
@@ -8558,6 +9735,17 @@ class SymbolTableVisitor(zincVisitor):
         )
         return base_type
 
+    @staticmethod
+    def _is_value_position(ctx: ZincParser.PrimaryExpressionContext) -> bool:
+        """Return True unless a bare name is a call's callee or the head of a member path, which resolve elsewhere."""
+        expr = ctx.parentCtx
+        if not isinstance(expr, ZincParser.PrimaryExprContext):
+            return False
+        parent = expr.parentCtx
+        if isinstance(parent, ZincParser.FunctionCallExprContext | ZincParser.MemberAccessExprContext):
+            return parent.expression() is not expr
+        return True
+
     def visitPrimaryExpression(self, ctx: ZincParser.PrimaryExpressionContext) -> BaseType:
         """Handle primary expressions (literals, identifiers, etc.)."""
         if ctx.literal():
@@ -8666,6 +9854,7 @@ class SymbolTableVisitor(zincVisitor):
             name = name_token.getText()
             symbol = self.symbols.lookup_by_id(name)
             if symbol:
+                self._mark_read(symbol)
                 temp = self.symbols.define_temp(
                     resolved_type=symbol.resolved_type,
                     interval=ctx.getSourceInterval(),
@@ -8674,6 +9863,8 @@ class SymbolTableVisitor(zincVisitor):
                 )
                 temp.element_type = symbol.element_type
                 temp.element_exact_type = symbol.element_exact_type
+                temp.array_length = symbol.array_length
+                temp.element_array_info = self._copy_array_info(symbol.element_array_info)
                 temp.channel_info = self._copy_channel_info(symbol.channel_info)
                 temp.dict_info = self._copy_dict_info(symbol.dict_info)
                 temp.set_info = self._copy_set_info(symbol.set_info)
@@ -8716,6 +9907,8 @@ class SymbolTableVisitor(zincVisitor):
                 if const_symbol.resolved_type == BaseType.ARRAY:
                     temp.element_type = const_symbol.element_type
                     temp.element_exact_type = const_symbol.element_exact_type
+                    temp.array_length = const_symbol.array_length
+                    temp.element_array_info = self._copy_array_info(const_symbol.element_array_info)
                     temp.element_struct_qualified_name = const_symbol.element_struct_qualified_name
                     temp.element_anonymous_struct_info = self._copy_anonymous_struct_info(const_symbol.element_anonymous_struct_info)
                 struct_qualified_name = self._struct_qualified_name_for_symbol(const_symbol)
@@ -8757,6 +9950,8 @@ class SymbolTableVisitor(zincVisitor):
                 self._materialize_callable_targets(callable_info)
                 return BaseType.CALLABLE
 
+            if ctx.IDENTIFIER() and self._is_value_position(ctx) and name not in self._failed_statement_names:
+                raise self._unknown_variable_error(name, ctx)
             self.symbols.define_temp(
                 resolved_type=BaseType.UNKNOWN,
                 interval=ctx.getSourceInterval(),
@@ -8809,7 +10004,7 @@ class SymbolTableVisitor(zincVisitor):
                 ctx.enumVariantPath().getText().split("."),
             )
             if resolved_struct is None:
-                raise ZincTypeError(f"unknown enum variant '{ctx.enumVariantPath().getText()}'")
+                raise ZincTypeError(f"unknown enum variant '{ctx.enumVariantPath().getText()}'", code="Z0008")
             struct_info = self.atlas.structs.get(resolved_struct.qualified_name)
             provided_exprs: dict[str, tuple[BaseType, Symbol | None, ParserRuleContext]] = {}
             for field_ctx in ctx.fieldInit():
@@ -8827,7 +10022,7 @@ class SymbolTableVisitor(zincVisitor):
                 for field_name, (actual_type, actual_symbol, actual_expr_ctx) in provided_exprs.items():
                     expected_field = field_map.get(field_name)
                     if expected_field is None:
-                        raise ZincTypeError(f"struct '{struct_info.name}' has no field '{field_name}'")
+                        raise ZincTypeError(f"struct '{struct_info.name}' has no field '{field_name}'", code="Z0007")
                     actual_struct_qualified_name, actual_anonymous_struct_info = self._struct_metadata_for_symbol(actual_symbol)
                     actual_array_info = self._array_info_from_symbol(actual_symbol)
                     if not self._assignment_metadata_compatible(
@@ -8865,7 +10060,7 @@ class SymbolTableVisitor(zincVisitor):
         enum_info = self._analyze_enum_by_qualified_name(enum_symbol.qualified_name)
         variant = next((candidate for candidate in enum_info.variants if candidate.name == variant_name), None)
         if variant is None:
-            raise ZincTypeError(f"enum '{enum_info.name}' has no variant '{variant_name}'")
+            raise ZincTypeError(f"enum '{enum_info.name}' has no variant '{variant_name}'", code="Z0008")
         if variant.is_unit:
             raise ZincTypeError(f"unit variant '{enum_info.name}.{variant_name}' cannot be constructed with fields")
 
@@ -8889,7 +10084,7 @@ class SymbolTableVisitor(zincVisitor):
         for field_name, (actual_type, actual_symbol, actual_expr_ctx) in provided_exprs.items():
             expected_field = field_map.get(field_name)
             if expected_field is None:
-                raise ZincTypeError(f"enum variant '{enum_info.name}.{variant_name}' has no field '{field_name}'")
+                raise ZincTypeError(f"enum variant '{enum_info.name}.{variant_name}' has no field '{field_name}'", code="Z0007")
             actual_struct_qualified_name, actual_anonymous_struct_info = self._struct_metadata_for_symbol(actual_symbol)
             actual_array_info = self._array_info_from_symbol(actual_symbol)
             if not self._assignment_metadata_compatible(
@@ -8983,6 +10178,8 @@ class SymbolTableVisitor(zincVisitor):
         temp.callable_info = self._copy_callable_info(inner_symbol.callable_info) if inner_symbol else None
         temp.anonymous_struct_info = self._copy_anonymous_struct_info(inner_symbol.anonymous_struct_info if inner_symbol else None)
         temp.element_exact_type = inner_symbol.element_exact_type if inner_symbol else None
+        temp.array_length = inner_symbol.array_length if inner_symbol else None
+        temp.element_array_info = self._copy_array_info(inner_symbol.element_array_info) if inner_symbol else None
         temp.element_struct_qualified_name = inner_symbol.element_struct_qualified_name if inner_symbol else None
         temp.element_anonymous_struct_info = self._copy_anonymous_struct_info(
             inner_symbol.element_anonymous_struct_info if inner_symbol else None
@@ -9303,6 +10500,14 @@ class SymbolTableVisitor(zincVisitor):
         overload = self._resolve_binary_operator(ctx, op, left_info, right_info)
         if overload is not None:
             return overload.base_type
+        for info in (left_info, right_info):
+            if info.base_type == BaseType.ENUM and "eq" not in self._derives(info.exact_type):
+                name = self.module_graph.split_qualified_name(info.exact_type)[1] if info.exact_type else "enum"
+                raise ZincTypeError(
+                    f"operator '{op}' is not defined for enum '{name}'",
+                    suggestions=(f"add @derive(eq) to enum '{name}', or use match to test for a variant",),
+                    code="Z0006",
+                )
         left_symbol = self._expr_symbol(ctx.expression(0))
         right_symbol = self._expr_symbol(ctx.expression(1))
         constant_value = None
@@ -9411,6 +10616,7 @@ class SymbolTableVisitor(zincVisitor):
         element_anonymous_struct_info = None
         element_result_info = None
         element_option_info = None
+        element_array_info = None
         constant_items: list[object] = []
         all_constant = True
         for expr_ctx in ctx.expression():
@@ -9434,6 +10640,8 @@ class SymbolTableVisitor(zincVisitor):
                     element_result_info = self._copy_result_info(expr_symbol.result_info)
                 if expr_type == BaseType.OPTION and expr_symbol and expr_symbol.option_info:
                     element_option_info = self._copy_option_info(expr_symbol.option_info)
+                if expr_type == BaseType.ARRAY:
+                    element_array_info = self._array_info_from_symbol(expr_symbol)
             else:
                 element_type = self._merge_value_type(element_type, expr_type, "array element")
                 element_exact_type = self._merge_exact_type_for_base(
@@ -9442,11 +10650,26 @@ class SymbolTableVisitor(zincVisitor):
                     element_type,
                     "array element",
                 )
+                if element_type == BaseType.ARRAY:
+                    row_info = self._array_info_from_symbol(expr_symbol)
+                    if element_array_info is None:
+                        element_array_info = row_info
+                    elif row_info is not None and not self._array_elements_compatible(element_array_info, row_info):
+                        raise ZincTypeError(
+                            "the rows of a nested list must hold the same type",
+                            ctx=expr_ctx,
+                            expected=element_array_info.to_rust_type(as_reference=False),
+                            found=row_info.to_rust_type(as_reference=False),
+                        )
+        length = array_length(ctx)
+        if length is not None and len(ctx.expression()) != 1:
+            raise ZincTypeError("a fixed-size array literal repeats one value, as in [0; 4]", ctx=ctx)
         symbol = self.symbols.define_temp(
             resolved_type=BaseType.ARRAY,
             interval=ctx.getSourceInterval(),
-            constant_value=constant_items if all_constant else None,
+            constant_value=constant_items if all_constant and length is None else None,
         )
+        symbol.array_length = length
         # Track element type from the first element
         if element_type is not None:
             symbol.element_type = element_type
@@ -9457,6 +10680,7 @@ class SymbolTableVisitor(zincVisitor):
             symbol.element_anonymous_struct_info = element_anonymous_struct_info
             symbol.result_info = element_result_info
             symbol.option_info = element_option_info
+            symbol.element_array_info = element_array_info
         return BaseType.ARRAY
 
     def visitCollectionLiteral(self, ctx: ZincParser.CollectionLiteralContext) -> BaseType:
@@ -9580,6 +10804,7 @@ class SymbolTableVisitor(zincVisitor):
         anonymous_struct_info = None
         result_info = None
         option_info = None
+        row_info = None
         if arr_type == BaseType.ARRAY:
             arr_symbol = collection_symbol
             if arr_symbol and arr_symbol.element_type:
@@ -9596,6 +10821,26 @@ class SymbolTableVisitor(zincVisitor):
                     result_info = self._copy_result_info(arr_symbol.result_info)
                 if element_type == BaseType.OPTION:
                     option_info = self._copy_option_info(arr_symbol.option_info)
+                if element_type == BaseType.ARRAY:
+                    row_info = self._copy_array_info(arr_symbol.element_array_info)
+            if arr_symbol and arr_symbol.array_length is not None and self._is_checked_index(ctx, arr_symbol.array_length):
+                temp = self.symbols.define_temp(resolved_type=BaseType.RESULT, interval=ctx.getSourceInterval())
+                temp.result_info = ResultTypeInfo(
+                    ok_type=self._value_spec_from_parts(
+                        element_type,
+                        exact_type=element_exact_type,
+                        array_info=row_info,
+                        tuple_info=tuple_info,
+                        callable_info=callable_info,
+                        struct_qualified_name=struct_qualified_name,
+                        anonymous_struct_info=anonymous_struct_info,
+                        result_info=result_info,
+                        option_info=option_info,
+                    ),
+                    err_type=self._value_spec_from_parts(BaseType.STRING, exact_type=default_exact_type(BaseType.STRING)),
+                )
+                self.symbols.mark_checked_index(ctx.getSourceInterval())
+                return BaseType.RESULT
         elif arr_type == BaseType.DICT:
             dict_ctx = ctx.expression(0)
             key_type = index_info.base_type
@@ -9662,6 +10907,7 @@ class SymbolTableVisitor(zincVisitor):
             if struct_qualified_name is not None:
                 self._struct_symbol_bindings[temp.unique_name] = struct_qualified_name
             temp.anonymous_struct_info = anonymous_struct_info
+        self._apply_array_info_to_symbol(temp, row_info)
         if (
             collection_symbol
             and isinstance(collection_symbol.constant_value, MetaListValue)
@@ -9673,6 +10919,38 @@ class SymbolTableVisitor(zincVisitor):
                 temp.constant_value = collection_symbol.constant_value.items[index]
         return element_type
 
+    def _is_checked_index(self, ctx: ZincParser.IndexAccessExprContext, length: int) -> bool:
+        """Check an index into a fixed-size array, and say whether it gives a Result under `--bounds error`.
+
+        A literal index is checked against the length here. Under `--bounds
+        error` any other read must be inside a `try` block, which unwraps it.
+        """
+        index = self._integer_literal_value(ctx.expression(1))
+        if index is not None:
+            if index >= length:
+                raise ZincTypeError(
+                    f"index {index} is out of bounds for a fixed-size array of length {length}",
+                    ctx=ctx.expression(1),
+                )
+            return False
+        if active_build_config().bounds != "error" or self._leads_to_element_write(ctx):
+            return False
+        if not self._try_context_stack:
+            raise ZincTypeError(
+                "under --bounds error, reading a fixed-size array through a computed index gives a Result",
+                ctx=ctx,
+                notes=("outside a `try` block there is nothing to unwrap it",),
+                suggestions=("read it inside a `try` block, as in `try { values[i] * 10 }`",),
+            )
+        return True
+
+    def _leads_to_element_write(self, ctx: ZincParser.IndexAccessExprContext) -> bool:
+        """Return True when an index read picks the row an assignment writes into, as grid[i] in grid[i][j] = v."""
+        node = ctx
+        while isinstance(node.parentCtx, ZincParser.IndexAccessExprContext) and node.parentCtx.expression(0) is node:
+            node = node.parentCtx
+        return isinstance(node.parentCtx, ZincParser.IndexAccessContext) and node.parentCtx.expression(0) is node
+
     def visitRangeExpr(self, ctx: ZincParser.RangeExprContext) -> BaseType:
         """Visit range expression."""
         left_info = self._value_info_for_value_context(ctx.expression(0))
@@ -9742,6 +11020,15 @@ class SymbolTableVisitor(zincVisitor):
                             interval=ctx.getSourceInterval(),
                         )
                         return resolved.resolved_type
+                static_field = self._static_field_target(path)
+                if static_field is not None:
+                    _struct, field = static_field
+                    self.symbols.define_temp(
+                        resolved_type=field.resolved_type,
+                        interval=ctx.getSourceInterval(),
+                        exact_type=field.exact_type,
+                    )
+                    return field.resolved_type
                 static_target = self.module_graph.resolve_static_method_target(self._current_module, path)
                 if static_target:
                     owner_symbol, method_name = static_target
@@ -9786,7 +11073,7 @@ class SymbolTableVisitor(zincVisitor):
                     enum_info = self._analyze_enum_by_qualified_name(enum_symbol.qualified_name)
                     variant = next((candidate for candidate in enum_info.variants if candidate.name == variant_name), None)
                     if variant is None:
-                        raise ZincTypeError(f"enum '{enum_info.name}' has no variant '{variant_name}'")
+                        raise ZincTypeError(f"enum '{enum_info.name}' has no variant '{variant_name}'", code="Z0008")
                     if not variant.is_unit:
                         raise ZincTypeError(f"payload variant '{enum_info.name}.{variant_name}' requires field construction")
                     self.symbols.define_temp(
@@ -9844,11 +11131,12 @@ class SymbolTableVisitor(zincVisitor):
                             interval=ctx.getSourceInterval(),
                         )
                         return BaseType.UNKNOWN
-                    raise ZincTypeError(f"opaque extern type has no member '{member_name}'")
+                    raise ZincTypeError(f"opaque extern type has no member '{member_name}'", code="Z0007")
                 struct = self.atlas.structs.get(struct_qualified_name)
                 if struct:
                     field = next((candidate for candidate in struct.fields if candidate.name == member_name), None)
                     if field is not None:
+                        self._require_field_access(struct, field, ctx, ctx.expression().getText())
                         concrete_field = None
                         anonymous_struct_info = self._anonymous_struct_info_for_symbol(receiver_symbol)
                         if anonymous_struct_info is not None:
@@ -9891,6 +11179,8 @@ class SymbolTableVisitor(zincVisitor):
                             temp.element_anonymous_struct_info = self._copy_anonymous_struct_info(
                                 resolved_array_info.element_anonymous_struct_info
                             )
+                            temp.element_array_info = self._copy_array_info(resolved_array_info.element_array_info)
+                            temp.array_length = resolved_array_info.length
                         temp.dict_info = self._copy_dict_info(resolved_dict_info)
                         temp.set_info = self._copy_set_info(resolved_set_info)
                         temp.tuple_info = self._copy_tuple_info(resolved_tuple_info) or temp.tuple_info
@@ -9948,6 +11238,8 @@ class SymbolTableVisitor(zincVisitor):
                         temp.element_anonymous_struct_info = self._copy_anonymous_struct_info(
                             field.array_info.element_anonymous_struct_info
                         )
+                        temp.element_array_info = self._copy_array_info(field.array_info.element_array_info)
+                        temp.array_length = field.array_info.length
                     return field.resolved_type
                 if is_direct_call:
                     self.symbols.define_temp(
@@ -9955,7 +11247,12 @@ class SymbolTableVisitor(zincVisitor):
                         interval=ctx.getSourceInterval(),
                     )
                     return BaseType.UNKNOWN
-                raise ZincTypeError(f"anonymous struct has no field '{member_name}'")
+                raise ZincTypeError(
+                    f"anonymous struct has no field '{member_name}'",
+                    ctx=ctx,
+                    suggestions=did_you_mean(member_name, (field.name for field in anonymous_struct_info.fields)),
+                    code="Z0007",
+                )
             if struct_qualified_name and struct is not None:
                 if is_direct_call:
                     self.symbols.define_temp(
@@ -9963,7 +11260,19 @@ class SymbolTableVisitor(zincVisitor):
                         interval=ctx.getSourceInterval(),
                     )
                     return BaseType.UNKNOWN
-                raise ZincTypeError(f"struct '{struct.name}' has no member '{member_name}'")
+                if any(field.name == member_name for field in struct.static_fields):
+                    raise ZincTypeError(
+                        f"struct '{struct.name}' has no member '{member_name}'; it is static, so use `{struct.name}.{member_name}`",
+                        ctx=ctx,
+                        notes=(f"a static field is shared by every '{struct.name}' rather than held by one",),
+                        code="Z0007",
+                    )
+                raise ZincTypeError(
+                    f"struct '{struct.name}' has no member '{member_name}'",
+                    ctx=ctx,
+                    suggestions=did_you_mean(member_name, [member.name for member in (*struct.fields, *struct.methods)]),
+                    code="Z0007",
+                )
 
         if receiver_type == BaseType.ENUM:
             enum_name = receiver_symbol.exact_type if receiver_symbol else None
@@ -9975,7 +11284,12 @@ class SymbolTableVisitor(zincVisitor):
                         interval=ctx.getSourceInterval(),
                     )
                     return BaseType.UNKNOWN
-                raise ZincTypeError(f"enum '{enum.name}' has no member '{member_name}'")
+                raise ZincTypeError(
+                    f"enum '{enum.name}' has no member '{member_name}'",
+                    ctx=ctx,
+                    suggestions=did_you_mean(member_name, [member.name for member in (*enum.variants, *enum.methods)]),
+                    code="Z0007",
+                )
 
         self.symbols.define_temp(
             resolved_type=BaseType.UNKNOWN,
@@ -10271,6 +11585,7 @@ class SymbolTableVisitor(zincVisitor):
                         return_info = ResolvedValueInfo(
                             base_type=func_instance.return_type,
                             exact_type=func_instance.return_exact_type,
+                            array_info=self._copy_array_info(func_instance.return_array_info),
                             dict_info=self._copy_dict_info(func_instance.return_dict_info),
                             set_info=self._copy_set_info(func_instance.return_set_info),
                             tuple_info=self._copy_tuple_info(func_instance.return_tuple_info),
@@ -10327,11 +11642,172 @@ class SymbolTableVisitor(zincVisitor):
                     return BaseType.VOID
                 if func_name == "print":
                     self._require_positional_arguments(raw_args, "print()")
+                    if len(arg_exprs) > 1:
+                        self._require_format_arguments(arg_exprs[0], len(arg_exprs) - 1)
+                    for arg_expr, arg_type in zip(arg_exprs, arg_types, strict=False):
+                        if arg_type in {BaseType.STRUCT, BaseType.ENUM}:
+                            self._require_display(self._value_info_for_value_context(arg_expr))
+                    self.symbols.define_temp(
+                        resolved_type=BaseType.VOID,
+                        interval=ctx.getSourceInterval(),
+                    )
+                    return BaseType.VOID
+                if func_name == "assert":
+                    self._require_positional_arguments(raw_args, "assert()")
+                    if len(arg_types) not in {1, 2}:
+                        raise ZincTypeError("assert() expects a condition and an optional message")
+                    if arg_types[0] != BaseType.BOOLEAN:
+                        raise ZincTypeError("assert() condition must be a bool")
+                    if len(arg_types) == 2 and arg_types[1] != BaseType.STRING:
+                        raise ZincTypeError("assert() message must be a string")
                     self.symbols.define_temp(
                         resolved_type=BaseType.VOID,
                         interval=ctx.getSourceInterval(),
                     )
                     return BaseType.VOID
+                if func_name == "assert_eq":
+                    self._require_positional_arguments(raw_args, "assert_eq()")
+                    if len(arg_types) not in {2, 3}:
+                        raise ZincTypeError("assert_eq() expects two values and an optional message")
+                    left_type, right_type = arg_types[0], arg_types[1]
+                    numeric_types = {BaseType.INTEGER, BaseType.FLOAT}
+                    if left_type != right_type and not {left_type, right_type} <= numeric_types:
+                        raise ZincTypeError(f"assert_eq() cannot compare {left_type.name.lower()} with {right_type.name.lower()}")
+                    if left_type in {BaseType.STRUCT, BaseType.ENUM, BaseType.CALLABLE, BaseType.CHANNEL, BaseType.CONTEXT}:
+                        raise ZincTypeError(f"assert_eq() does not support {left_type.name.lower()} values")
+                    if len(arg_types) == 3 and arg_types[2] != BaseType.STRING:
+                        raise ZincTypeError("assert_eq() message must be a string")
+                    self.symbols.define_temp(
+                        resolved_type=BaseType.VOID,
+                        interval=ctx.getSourceInterval(),
+                    )
+                    return BaseType.VOID
+                if func_name == "exit":
+                    self._require_positional_arguments(raw_args, "exit()")
+                    if len(arg_types) > 1:
+                        raise ZincTypeError("exit() accepts at most one status code argument")
+                    if arg_types and arg_types[0] != BaseType.INTEGER:
+                        raise ZincTypeError("exit() status code must be an integer")
+                    self.symbols.define_temp(
+                        resolved_type=BaseType.VOID,
+                        interval=ctx.getSourceInterval(),
+                    )
+                    return BaseType.VOID
+                if func_name == "on_interrupt":
+                    self._require_positional_arguments(raw_args, "on_interrupt()")
+                    if len(arg_types) != 1:
+                        raise ZincTypeError("on_interrupt() expects exactly one handler argument")
+                    handler_symbol = self._expr_symbol(arg_exprs[0])
+                    handler_info = handler_symbol.callable_info if handler_symbol is not None else None
+                    if arg_types[0] != BaseType.CALLABLE or handler_info is None or handler_info.param_types:
+                        raise ZincTypeError("on_interrupt() handler must be a callable that takes no arguments")
+                    self.atlas.interrupt_handlers.append(handler_info)
+                    self.symbols.define_temp(
+                        resolved_type=BaseType.VOID,
+                        interval=ctx.getSourceInterval(),
+                    )
+                    return BaseType.VOID
+                if func_name in {"dbg", "print_debug"}:
+                    self._require_positional_arguments(raw_args, f"{func_name}()")
+                    if len(arg_types) != 1:
+                        raise ZincTypeError(f"{func_name}() expects exactly one value")
+                    if arg_types[0] in {BaseType.VOID, BaseType.NEVER}:
+                        raise ZincTypeError(f"{func_name}() expects a value")
+                    self.atlas.uses_debug_print = True
+                    self.symbols.define_temp(
+                        resolved_type=BaseType.VOID,
+                        interval=ctx.getSourceInterval(),
+                    )
+                    return BaseType.VOID
+                if func_name == "to_json":
+                    self._require_positional_arguments(raw_args, "to_json()")
+                    if len(arg_types) != 1:
+                        raise ZincTypeError("to_json() expects exactly one value")
+                    self._require_json_value(self._value_info_for_value_context(arg_exprs[0]))
+                    self.symbols.define_temp(
+                        resolved_type=BaseType.STRING,
+                        interval=ctx.getSourceInterval(),
+                        exact_type="String",
+                    )
+                    return BaseType.STRING
+                if func_name == "uuid":
+                    self._require_positional_arguments(raw_args, "uuid()")
+                    if arg_types:
+                        raise ZincTypeError("uuid() does not accept arguments")
+                    self.symbols.define_temp(
+                        resolved_type=BaseType.STRING,
+                        interval=ctx.getSourceInterval(),
+                        exact_type="String",
+                    )
+                    return BaseType.STRING
+                if func_name == "matrix":
+                    self._require_positional_arguments(raw_args, "matrix()")
+                    if len(arg_types) != 3:
+                        raise ZincTypeError("matrix() expects a row count, a column count, and the value every cell starts with", ctx=ctx)
+                    for size_ctx, size_type in zip(arg_exprs[:2], arg_types[:2], strict=True):
+                        if size_type != BaseType.INTEGER:
+                            raise ZincTypeError("matrix() sizes must be integers", ctx=size_ctx)
+                    fill = self._value_info_from_symbol(arg_types[2], self._expr_symbol(arg_exprs[2]))
+                    if fill.base_type in {BaseType.VOID, BaseType.NEVER, BaseType.UNKNOWN}:
+                        raise ZincTypeError("matrix() needs a value to fill its cells with", ctx=arg_exprs[2])
+                    row = ArrayTypeInfo(
+                        element_type=fill.base_type,
+                        element_exact_type=fill.exact_type,
+                        element_tuple_info=fill.tuple_info,
+                        element_callable_info=fill.callable_info,
+                        element_struct_qualified_name=fill.struct_qualified_name,
+                        element_anonymous_struct_info=fill.anonymous_struct_info,
+                        element_result_info=fill.result_info,
+                        element_option_info=fill.option_info,
+                        element_array_info=fill.array_info,
+                    )
+                    symbol = self.symbols.define_temp(resolved_type=BaseType.ARRAY, interval=ctx.getSourceInterval())
+                    self._apply_array_info_to_symbol(symbol, ArrayTypeInfo(element_type=BaseType.ARRAY, element_array_info=row))
+                    return BaseType.ARRAY
+                if func_name == "format_number":
+                    self._require_positional_arguments(raw_args, "format_number()")
+                    if len(arg_types) != 2:
+                        raise ZincTypeError("format_number() expects a number and a format such as ',.2f'", ctx=ctx)
+                    if arg_types[0] not in {BaseType.INTEGER, BaseType.FLOAT}:
+                        raise ZincTypeError("format_number() formats integers and floats", ctx=arg_exprs[0])
+                    number_format_spec(arg_exprs[1])
+                    self.symbols.define_temp(
+                        resolved_type=BaseType.STRING,
+                        interval=ctx.getSourceInterval(),
+                        exact_type="String",
+                    )
+                    return BaseType.STRING
+                if func_name == "include_str":
+                    self._require_positional_arguments(raw_args, "include_str()")
+                    if len(arg_types) != 1:
+                        raise ZincTypeError("include_str() expects exactly one file path")
+                    path = included_file(self.module_graph.path_for_module(self._current_module), arg_exprs[0])
+                    try:
+                        path.read_text(encoding="utf-8")
+                    except FileNotFoundError as exc:
+                        raise ZincTypeError(f"include_str() can't find {path}", ctx=arg_exprs[0]) from exc
+                    except (OSError, UnicodeDecodeError) as exc:
+                        raise ZincTypeError(f"include_str() can't read {path} as UTF-8 text", ctx=arg_exprs[0]) from exc
+                    self.symbols.define_temp(
+                        resolved_type=BaseType.STRING,
+                        interval=ctx.getSourceInterval(),
+                        exact_type="String",
+                    )
+                    return BaseType.STRING
+                if func_name in {"temp_file", "temp_dir"}:
+                    self._require_positional_arguments(raw_args, f"{func_name}()")
+                    if arg_types:
+                        raise ZincTypeError(f"{func_name}() does not accept arguments")
+                    if not self._is_plain_variable_initializer(ctx):
+                        raise ZincTypeError(
+                            f"{func_name}() must be assigned directly to a variable; the path is removed when that variable goes out of scope"
+                        )
+                    self.symbols.define_temp(
+                        resolved_type=BaseType.STRING,
+                        interval=ctx.getSourceInterval(),
+                        exact_type="String",
+                    )
+                    return BaseType.STRING
                 if func_name in {"dict", "sort_dict"}:
                     self._require_positional_arguments(raw_args, f"{func_name}()")
                     if arg_types:
@@ -10405,7 +11881,7 @@ class SymbolTableVisitor(zincVisitor):
                         return_info = self._validate_extern_call(ctx, extern_method)
                         self._record_value_info(ctx.getSourceInterval(), return_info)
                         return return_info.base_type
-                    raise ZincTypeError(f"opaque extern type has no member '{method_name}'")
+                    raise ZincTypeError(f"opaque extern type has no member '{method_name}'", code="Z0007")
 
             if receiver_type == BaseType.CONTEXT:
                 if method_name == "done":
@@ -10431,6 +11907,19 @@ class SymbolTableVisitor(zincVisitor):
                     )
                     return BaseType.VOID
 
+            if method_name in _RESIZING_LIST_MEMBERS and receiver_type == BaseType.ARRAY:
+                receiver_symbol = self._expr_symbol(receiver_ctx)
+                if receiver_symbol is not None and receiver_symbol.array_length is not None:
+                    change = "grow" if method_name in {"push", "insert"} else "shrink"
+                    raise ZincTypeError(
+                        f"a fixed-size array can't {change}; it always holds {receiver_symbol.array_length} elements",
+                        ctx=ctx,
+                        suggestions=(f"use a growable list, as in [i64], to {method_name} elements",),
+                    )
+            if isinstance(receiver_ctx, ZincParser.IndexAccessExprContext) and is_mutating_method(receiver_type, method_name):
+                # grid[i].push(v) changes the list the row lives in
+                self._mark_element_owner_mutated(receiver_ctx.expression(0))
+
             # len() always returns an integer (usize in Rust, i64 in Zinc)
             if method_name == "len":
                 self._require_positional_arguments(raw_args, f"{method_name}()")
@@ -10463,36 +11952,40 @@ class SymbolTableVisitor(zincVisitor):
                                 raise ZincTypeError("cannot mutate dict during iteration")
                             var_symbol.is_mutated = True
 
-                        # For push on arrays, track element type
-                        if method_name == "push" and var_symbol.resolved_type == BaseType.ARRAY and arg_types:
-                            self._require_positional_arguments(raw_args, "array.push()")
-                            arg_symbol = self._expr_symbol(arg_exprs[0])
+                        # For push and insert on arrays, track element type from the pushed value
+                        pushes_value = method_name == "push" or (method_name == "insert" and len(arg_types) == 2)
+                        if pushes_value and var_symbol.resolved_type == BaseType.ARRAY and arg_types:
+                            self._require_positional_arguments(raw_args, f"array.{method_name}()")
+                            value_type = arg_types[-1]
+                            arg_symbol = self._expr_symbol(arg_exprs[-1])
                             if var_symbol.element_type is None:
-                                var_symbol.element_type = arg_types[0]
+                                var_symbol.element_type = value_type
                             var_symbol.element_exact_type = self._merge_exact_type_for_base(
                                 var_symbol.element_exact_type,
                                 arg_symbol.exact_type if arg_symbol else None,
                                 var_symbol.element_type,
                                 "array element",
                             )
-                            if arg_types[0] == BaseType.STRUCT and arg_symbol:
+                            if value_type == BaseType.STRUCT and arg_symbol:
                                 var_symbol.element_struct_qualified_name = self._struct_qualified_name_for_symbol(arg_symbol)
                                 var_symbol.element_anonymous_struct_info = self._copy_anonymous_struct_info(
                                     arg_symbol.anonymous_struct_info
                                 )
-                            if arg_types[0] == BaseType.TUPLE:
+                            if value_type == BaseType.TUPLE:
                                 if arg_symbol and arg_symbol.tuple_info:
                                     var_symbol.tuple_info = self._copy_tuple_info(arg_symbol.tuple_info)
-                            if arg_types[0] == BaseType.CALLABLE:
+                            if value_type == BaseType.CALLABLE:
                                 var_symbol.callable_info = self._merge_callable_info(
                                     var_symbol.callable_info,
                                     arg_symbol.callable_info if arg_symbol else None,
                                     "array element",
                                 )
-                            if arg_types[0] == BaseType.RESULT and arg_symbol and arg_symbol.result_info:
+                            if value_type == BaseType.RESULT and arg_symbol and arg_symbol.result_info:
                                 var_symbol.result_info = self._copy_result_info(arg_symbol.result_info)
-                            if arg_types[0] == BaseType.OPTION and arg_symbol and arg_symbol.option_info:
+                            if value_type == BaseType.OPTION and arg_symbol and arg_symbol.option_info:
                                 var_symbol.option_info = self._copy_option_info(arg_symbol.option_info)
+                            if value_type == BaseType.ARRAY and var_symbol.element_array_info is None:
+                                var_symbol.element_array_info = self._array_info_from_symbol(arg_symbol)
 
                         if var_symbol.resolved_type == BaseType.DICT and var_symbol.dict_info:
                             self._require_positional_arguments(raw_args, f"dict.{method_name}()")
@@ -10624,6 +12117,9 @@ class SymbolTableVisitor(zincVisitor):
                                 )
                                 return BaseType.VOID
 
+            if receiver_type == BaseType.ARRAY and method_name in _LIST_MEMBERS - {"push"}:
+                return self._visit_list_method(ctx, receiver_ctx, method_name, raw_args, arg_types, arg_exprs)
+
         callee_symbol = self._expr_symbol(callee_ctx)
         is_bare_top_level_function = False
         is_direct_static_method = False
@@ -10677,10 +12173,12 @@ class SymbolTableVisitor(zincVisitor):
             return_option_info = None
             return_struct_qualified_name = None
             return_anonymous_struct_info = None
+            return_array_info = None
 
             for target in callable_info.targets:
                 candidate_type = BaseType.UNKNOWN
                 candidate_exact_type = None
+                candidate_array_info = None
                 candidate_dict_info = None
                 candidate_set_info = None
                 candidate_tuple_info = None
@@ -10737,6 +12235,7 @@ class SymbolTableVisitor(zincVisitor):
                         if func_instance:
                             candidate_type = func_instance.return_type
                             candidate_exact_type = func_instance.return_exact_type
+                            candidate_array_info = func_instance.return_array_info
                             candidate_dict_info = func_instance.return_dict_info
                             candidate_set_info = func_instance.return_set_info
                             candidate_tuple_info = func_instance.return_tuple_info
@@ -10750,6 +12249,7 @@ class SymbolTableVisitor(zincVisitor):
                         if declared_info is not None:
                             candidate_type = declared_info.base_type
                             candidate_exact_type = declared_info.exact_type
+                            candidate_array_info = declared_info.array_info
                             candidate_dict_info = declared_info.dict_info
                             candidate_set_info = declared_info.set_info
                             candidate_tuple_info = declared_info.tuple_info
@@ -10791,6 +12291,7 @@ class SymbolTableVisitor(zincVisitor):
                 if return_type == BaseType.UNKNOWN:
                     return_type = candidate_type
                     return_exact_type = candidate_exact_type
+                    return_array_info = self._copy_array_info(candidate_array_info)
                     return_dict_info = self._copy_dict_info(candidate_dict_info)
                     return_set_info = self._copy_set_info(candidate_set_info)
                     return_tuple_info = self._copy_tuple_info(candidate_tuple_info)
@@ -10805,6 +12306,8 @@ class SymbolTableVisitor(zincVisitor):
                     raise ZincTypeError("indirect call targets disagree on return type")
                 return_type = promoted
                 return_exact_type = promote_exact_numeric(return_exact_type, candidate_exact_type, return_type)
+                if return_type == BaseType.ARRAY:
+                    return_array_info = self._merge_array_info(return_array_info, candidate_array_info, "indirect call targets")
                 if return_type == BaseType.DICT:
                     return_dict_info = self._merge_dict_info(return_dict_info, candidate_dict_info)
                 if return_type == BaseType.SET:
@@ -10873,6 +12376,8 @@ class SymbolTableVisitor(zincVisitor):
             temp.set_info = self._copy_set_info(return_set_info)
             temp.tuple_info = self._copy_tuple_info(return_tuple_info)
             temp.callable_info = return_callable_info
+            if return_type == BaseType.ARRAY:
+                self._apply_array_info_to_symbol(temp, return_array_info)
             temp.result_info = self._copy_result_info(return_result_info)
             temp.option_info = self._copy_option_info(return_option_info)
             temp.anonymous_struct_info = self._copy_anonymous_struct_info(return_anonymous_struct_info)
@@ -10903,40 +12408,18 @@ class SymbolTableVisitor(zincVisitor):
                     if method:
                         if not method.is_static:
                             raise ZincTypeError("instance methods must be bound to a receiver before use as callable values")
-                        bound_args = self._bind_call_arguments(
-                            ctx,
-                            self._parameter_specs_from_method(method),
-                            f"call to '{method_name}'",
-                        )
-                        self._collect_bound_argument_info(bound_args)
-                        return_info = self._resolved_named_type_info(
-                            method.return_type,
-                            source_module_id=method.source_module_id or owner_symbol.module_id,
-                            owner_qualified_name=owner_symbol.qualified_name,
-                            owner_kind=owner_symbol.kind,
-                        )
-                        self._record_value_info(ctx.getSourceInterval(), return_info)
-                        return return_info.base_type
+                        return self._visit_static_method_call(ctx, owner_symbol, method)
+
+            constructor_symbol = self._constructor_call_target(path)
+            if constructor_symbol is not None:
+                return self._visit_constructor_call(ctx, constructor_symbol)
 
             ufcs_type = self._try_resolve_ufcs_call(ctx, callee_ctx)
             if ufcs_type is not None:
                 return ufcs_type
 
             resolved_function = self.module_graph.resolve_function_path(self._current_module, path)
-            if resolved_function and resolved_function.name not in (
-                "print",
-                "chan",
-                "close",
-                "dict",
-                "sort_dict",
-                "set",
-                "sort_set",
-                "meta",
-                "type",
-                "line",
-                "has_component",
-                "implements",
-            ):
+            if resolved_function and resolved_function.name not in BUILTIN_FUNCTIONS:
                 func_def = self.atlas.function_defs.get(resolved_function.qualified_name)
                 if func_def:
                     bound_args = self._bind_call_arguments(
@@ -11025,6 +12508,7 @@ class SymbolTableVisitor(zincVisitor):
                         return_info = ResolvedValueInfo(
                             base_type=func_instance.return_type,
                             exact_type=func_instance.return_exact_type,
+                            array_info=self._copy_array_info(func_instance.return_array_info),
                             dict_info=self._copy_dict_info(func_instance.return_dict_info),
                             set_info=self._copy_set_info(func_instance.return_set_info),
                             tuple_info=self._copy_tuple_info(func_instance.return_tuple_info),
@@ -11051,6 +12535,18 @@ class SymbolTableVisitor(zincVisitor):
                     return func_symbol.resolved_type
                 if self.module_graph.rust_use_imports_name(self._current_module, path[0]):
                     raise ZincTypeError(f"missing extern declaration for Rust function '{path[0]}'")
+                if (
+                    resolved_function is None
+                    and self._current_lexical_function(path[0]) is None
+                    and path[0] not in BUILTIN_FUNCTIONS
+                    and path[0] not in self._failed_statement_names
+                ):
+                    raise ZincTypeError(
+                        f"unknown function '{path[0]}'",
+                        ctx=callee_ctx,
+                        suggestions=did_you_mean(path[0], self._visible_function_names()),
+                        code="Z0009",
+                    )
 
         ufcs_type = self._try_resolve_ufcs_call(ctx, callee_ctx)
         if ufcs_type is not None:
@@ -11062,6 +12558,97 @@ class SymbolTableVisitor(zincVisitor):
         )
         return BaseType.UNKNOWN
 
+    def _visit_static_method_call(self, ctx, owner_symbol, method: StructMethodInfo) -> BaseType:
+        """Bind and record a `Type.method(args...)` call to a static method."""
+        bound_args = self._bind_call_arguments(
+            ctx,
+            self._parameter_specs_from_method(method),
+            f"call to '{method.name}'",
+        )
+        self._collect_bound_argument_info(bound_args)
+        return_info = self._resolved_named_type_info(
+            method.return_type,
+            source_module_id=method.source_module_id or owner_symbol.module_id,
+            owner_qualified_name=owner_symbol.qualified_name,
+            owner_kind=owner_symbol.kind,
+        )
+        self._record_value_info(ctx.getSourceInterval(), return_info)
+        return return_info.base_type
+
+    def _constructor_call_target(self, path: list[str]):
+        """Return the struct symbol when `Name(args...)` is constructor shorthand rather than a call."""
+        if len(path) == 1 and self.symbols.lookup_by_id(path[0]) is not None:
+            return None
+        if self.module_graph.resolve_function_path(self._current_module, path) is not None:
+            return None
+        return self.module_graph.resolve_struct_path(self._current_module, path)
+
+    def _visit_constructor_call(self, ctx, struct_symbol) -> BaseType:
+        """Visit `Name(args...)`: a call to the static `new` when declared, else a field-wise struct literal."""
+        struct_info = self.atlas.structs.get(struct_symbol.qualified_name)
+        if struct_info is None:
+            raise ZincTypeError(f"unknown struct '{struct_symbol.name}'")
+        self._require_instantiable(struct_info)
+        constructor = next((method for method in struct_info.methods if method.name == "new" and method.is_static), None)
+        if constructor is not None:
+            return self._visit_static_method_call(ctx, struct_symbol, constructor)
+        bound_fields = self._bind_constructor_fields(ctx, struct_info)
+        return self._check_struct_literal(ctx, struct_symbol, struct_info, bound_fields)
+
+    def _require_instantiable(self, struct_info: StructInstance) -> None:
+        """Reject building a value of an interface, which only describes methods for structs to implement."""
+        if struct_info.is_interface:
+            raise ZincTypeError(
+                f"interface '{struct_info.name}' cannot be instantiated",
+                suggestions=(f"build a struct that implements it, declared as struct Name [{struct_info.name}]",),
+            )
+
+    def _bind_constructor_fields(self, ctx, struct_info: StructInstance) -> list[BoundStructField]:
+        """Bind `Name(args...)` arguments to struct fields in declaration order."""
+        label = f"call to '{struct_info.name}'"
+        field_names = [field.name for field in struct_info.fields]
+        bound_by_name: dict[str, BoundStructField] = {}
+        positional_index = 0
+        saw_named = False
+        for raw in self._raw_call_arguments(ctx.argumentList()):
+            if raw.is_spread:
+                saw_named = True
+                for field_name, value_info in self._spread_field_infos(raw.expression, label).items():
+                    if field_name in field_names[positional_index:]:
+                        bound_by_name[field_name] = BoundStructField(
+                            name=field_name,
+                            expression=raw.expression,
+                            value_info=value_info,
+                            spread_source_expr=raw.expression,
+                            spread_field_name=field_name,
+                        )
+                continue
+            if raw.name is not None:
+                saw_named = True
+                if raw.name not in field_names:
+                    raise ZincTypeError(f"{label} got an unknown named argument '{raw.name}'", code="Z0005")
+                field_name = raw.name
+            else:
+                if saw_named:
+                    raise ZincTypeError(f"{label} positional arguments must come before named arguments", code="Z0005")
+                if positional_index >= len(field_names):
+                    raise ZincTypeError(f"{label} got too many arguments", code="Z0005")
+                field_name = field_names[positional_index]
+                positional_index += 1
+            bound_by_name[field_name] = BoundStructField(
+                name=field_name,
+                expression=raw.expression,
+                value_info=self._value_info_for_value_context(raw.expression),
+            )
+
+        # Fields declared with a default value may be left out; the rest are required arguments.
+        for field in struct_info.fields:
+            if field.name not in bound_by_name and field.default_value is None:
+                raise ZincTypeError(f"{label} missing required argument '{field.name}'", code="Z0005")
+        result = [bound_by_name[name] for name in field_names if name in bound_by_name]
+        self.bound_struct_fields[self._call_key(ctx)] = result
+        return result
+
     def _typed_assignment_tokens(self, target_ctx) -> list:
         """Return identifier tokens from a typed local binding target."""
         if target_ctx.IDENTIFIER():
@@ -11160,7 +12747,14 @@ class SymbolTableVisitor(zincVisitor):
             actual_option=expr_option_info,
         ):
             expected_label = declared_exact_type or type_ctx.getText()
-            raise ZincTypeError(f"variable '{var_name}' expects a compatible '{expected_label}' value")
+            raise ZincTypeError(
+                f"variable '{var_name}' expects a compatible '{expected_label}' value",
+                ctx=expr_ctx,
+                expected=expected_label,
+                found=self._value_type_label(expr_info),
+                notes=self._inferred_type_notes(expr_ctx),
+                code="Z0003",
+            )
 
         new_sym = self.symbols.define(
             id=var_name,
@@ -11220,6 +12814,9 @@ class SymbolTableVisitor(zincVisitor):
         declared_exact_type = self._exact_type_name_from_type_ctx(ctx.type_())
 
         expr_type = self.visit(ctx.expression())
+        if annotated_array_info is not None:
+            self._fit_array_literal(ctx.expression(), annotated_array_info.length)
+            self._type_empty_array_literal(ctx.expression(), annotated_array_info)
         expr_symbol = self._expr_symbol(ctx.expression())
         expr_info = self._value_info_from_symbol(expr_type, expr_symbol)
         if self._try_context_stack and annotated_type not in {BaseType.RESULT, BaseType.OPTION}:
@@ -11354,6 +12951,8 @@ class SymbolTableVisitor(zincVisitor):
         )
         temp.element_type = symbol.element_type
         temp.element_exact_type = symbol.element_exact_type
+        temp.array_length = symbol.array_length
+        temp.element_array_info = self._copy_array_info(symbol.element_array_info)
         temp.channel_info = self._copy_channel_info(symbol.channel_info)
         temp.dict_info = self._copy_dict_info(symbol.dict_info)
         temp.set_info = self._copy_set_info(symbol.set_info)
@@ -11377,6 +12976,20 @@ class SymbolTableVisitor(zincVisitor):
         if symbol.is_captured_ref:
             raise ZincTypeError(f"assignment to captured outer variable '{name}' requires 'out {name}'")
 
+    def _require_let_to_change_type(self, symbol: Symbol, name: str, expr_ctx, expr_type: BaseType, expr_exact_type: str | None) -> None:
+        """Under `--shadowing strict`, reject an `=` that would implicitly shadow a variable with a value of another type."""
+        if active_build_config().shadowing != "strict":
+            return
+        expected = exact_type_to_rust(symbol.exact_type, symbol.resolved_type)
+        found = exact_type_to_rust(expr_exact_type, expr_type)
+        raise ZincTypeError(
+            f"'{name}' holds '{expected}', and `=` can't change its type under --shadowing strict",
+            ctx=expr_ctx,
+            expected=expected,
+            found=found,
+            suggestions=(f"write `let {name} = ...` to declare a new '{name}' of the new type",),
+        )
+
     def _define_broadcast_local_binding(
         self,
         token,
@@ -11414,7 +13027,14 @@ class SymbolTableVisitor(zincVisitor):
             )
         ):
             expected_label = existing.declared_exact_type or type_to_rust(existing.resolved_type)
-            raise ZincTypeError(f"variable '{var_name}' expects a compatible '{expected_label}' value")
+            raise ZincTypeError(
+                f"variable '{var_name}' expects a compatible '{expected_label}' value",
+                ctx=expr_ctx,
+                expected=expected_label,
+                found=self._value_type_label(expr_info),
+                notes=(f"'{var_name}' is declared as '{expected_label}'", *self._inferred_type_notes(expr_ctx)),
+                code="Z0003",
+            )
 
         constant_value = expr_symbol.constant_value if expr_symbol else None
         should_shadow = False
@@ -11472,6 +13092,105 @@ class SymbolTableVisitor(zincVisitor):
         )
         self._apply_value_info_to_binding_symbol(temp, expr_info)
 
+    def _borrowed_arguments(self, func: FunctionInstance, ctx, param_names: list[str]) -> dict[int, str]:
+        """Find the string and struct parameters that can be passed by reference.
+
+        A parameter is borrowed when every use of it reads it: printing, comparing, calling a `&self`
+        method, reading a field that is itself only read, or handing it to a function that borrows it
+        in turn. Struct parameters whose fields are assigned, whose `&mut self` methods are called, or
+        that are handed to a function taking `&mut` take `&mut`. Returning, storing, reassigning,
+        capturing, or passing the value to a function that owns it keeps it owned.
+        """
+        body = arrow_lambda_body_expression(ctx) or ctx.block()
+        if body is None or decorators_from_ctx(ctx):
+            return {}
+        candidates = {
+            name: func.arg_types[i]
+            for i, name in enumerate(param_names)
+            if i < len(func.arg_types) and func.arg_types[i] in (BaseType.STRING, BaseType.STRUCT)
+        }
+        escaping: set[str] = set()
+        updated: set[str] = set()
+        stack = [body]
+        while stack and len(escaping) < len(candidates):
+            node = stack.pop()
+            if isinstance(node, FUNCTION_CONTEXTS):
+                text = node.getText()
+                escaping.update(name for name in candidates if re.search(rf"\b{re.escape(name)}\b", text))
+                continue
+            if not isinstance(node, TerminalNodeImpl):
+                stack.extend(node.getChild(i) for i in range(node.getChildCount()))
+                continue
+            name = node.getText()
+            if name not in candidates or node.symbol.type != ZincParser.IDENTIFIER:
+                continue
+            primary = node.parentCtx
+            use = None
+            if isinstance(primary, ZincParser.PrimaryExpressionContext) and isinstance(primary.parentCtx, ZincParser.PrimaryExprContext):
+                use = self._borrowed_use(primary.parentCtx, candidates[name])
+            if use is None:
+                escaping.add(name)
+            elif use == "&mut":
+                updated.add(name)
+
+        borrows: dict[int, str] = {}
+        for i, name in enumerate(param_names):
+            if name in candidates and name not in escaping:
+                borrows[i] = "&mut" if name in updated else "&"
+        return borrows
+
+    def _borrowed_use(self, expr, base_type: BaseType) -> str | None:
+        """How a use of a string or struct value needs it: "&" to read, "&mut" to update in place, None to own it."""
+        if base_type == BaseType.STRUCT and _assigned_through(expr):
+            return "&mut"
+        parent = expr.parentCtx
+        while isinstance(parent, ZincParser.ParenExprContext):
+            expr, parent = parent, parent.parentCtx
+        if isinstance(parent, ZincParser.MemberAccessExprContext) and parent.expression() is expr:
+            call = parent.parentCtx
+            if isinstance(call, ZincParser.FunctionCallExprContext) and call.expression() is parent:
+                return self._borrowed_method_use(expr, base_type, parent.IDENTIFIER().getText()) or self._forwarded_borrow(call, expr)
+            field = self._expr_symbol(parent)
+            if field is None:
+                return None
+            if field.resolved_type in (BaseType.INTEGER, BaseType.FLOAT, BaseType.BOOLEAN):
+                return "&"
+            return self._borrowed_use(parent, field.resolved_type)
+        if base_type == BaseType.STRING and isinstance(parent, (ZincParser.RelationalExprContext, ZincParser.EqualityExprContext)):
+            return "&"
+        if isinstance(parent, ZincParser.ArgumentContext):
+            call = parent.parentCtx.parentCtx
+            callee = call.expression() if isinstance(call, ZincParser.FunctionCallExprContext) else None
+            if callee is not None and parent.IDENTIFIER() is None and callee.getText() in BORROWING_BUILTINS:
+                builtin = self.symbols.lookup_by_id(callee.getText())
+                if builtin is not None and builtin.kind == SymbolKind.BUILTIN:
+                    return "&"
+            return self._forwarded_borrow(call, expr)
+        return None
+
+    def _forwarded_borrow(self, call, arg_expr) -> str | None:
+        """How a resolved Zinc function call takes one of its arguments, or None when the callee owns it."""
+        if not isinstance(call, ZincParser.FunctionCallExprContext):
+            return None
+        key = self._call_key(call)
+        callee = self.atlas.functions.get(self.specialization_map.get(key, ""))
+        if callee is None:
+            return None
+        for bound in self.bound_call_args.get(key, []):
+            if bound.expression is arg_expr and not bound.is_default and bound.spread_field_name is None:
+                return callee.arg_borrows.get(bound.parameter_index)
+        return None
+
+    def _borrowed_method_use(self, receiver, base_type: BaseType, method_name: str) -> str | None:
+        """How a method call needs its receiver: "&" for readers, "&mut" for `&mut self` methods, None otherwise."""
+        if base_type != BaseType.STRUCT:
+            return "&" if method_name in READING_MEMBERS else None
+        struct = self.atlas.structs.get(self._struct_qualified_name_for_symbol(self._expr_symbol(receiver)) or "")
+        method = next((m for m in struct.methods if m.name == method_name and not m.is_static), None) if struct else None
+        if method is None:
+            return None
+        return "&mut" if method.self_mutability == "&mut self" else "&"
+
     def _mark_mutated_call_arguments(self, func_instance: FunctionInstance, arg_exprs: list) -> None:
         """Mark caller variables as mutable when callee parameters are inferred mutable."""
         for i, arg_expr in enumerate(arg_exprs):
@@ -11482,6 +13201,8 @@ class SymbolTableVisitor(zincVisitor):
                 is_mutated = True
             if i in func_instance.arg_set_infos and func_instance.arg_set_infos[i].is_mutated:
                 is_mutated = True
+            if func_instance.arg_borrows.get(i) == "&mut":
+                is_mutated = True
             if not is_mutated:
                 continue
             if isinstance(arg_expr, ZincParser.PrimaryExprContext):
@@ -11522,7 +13243,7 @@ class SymbolTableVisitor(zincVisitor):
             field_ctx = entry_ctx.fieldInit()
             field_name = field_ctx.IDENTIFIER().getText()
             if allowed_names is not None and field_name not in allowed_names:
-                raise ZincTypeError(f"{label} has no field '{field_name}'")
+                raise ZincTypeError(f"{label} has no field '{field_name}'", code="Z0007")
             if field_name not in bound_by_name:
                 order.append(field_name)
             value_info = self._value_info_for_value_context(field_ctx.expression())
@@ -11545,26 +13266,46 @@ class SymbolTableVisitor(zincVisitor):
                 has_spread = any(entry.fieldSpread() is not None for entry in ctx.structFieldEntry())
                 if has_spread and self.module_graph.resolve_enum_variant_path(self._current_module, struct_path_from_ctx(ctx)) is not None:
                     raise ZincTypeError("enum variant payloads do not accept spread")
-                raise ZincTypeError(f"unknown struct '{ctx.qualifiedName().getText()}'")
+                struct_name = ctx.qualifiedName().getText()
+                raise ZincTypeError(
+                    f"unknown struct '{struct_name}'",
+                    ctx=ctx.qualifiedName(),
+                    suggestions=did_you_mean(struct_name, self.module_graph.visible_names(self._current_module, {"struct"})),
+                )
         struct_info = self.atlas.structs.get(resolved_struct.qualified_name) if resolved_struct else None
-        concrete_anonymous_struct_info = None
+        if struct_info is not None:
+            self._require_instantiable(struct_info)
         bound_fields = self._bind_struct_literal_fields(
             ctx,
             allowed_names={field.name for field in struct_info.fields} if struct_info is not None else None,
             label=f"struct '{struct_info.name}'" if struct_info is not None else "struct literal",
         )
+        return self._check_struct_literal(ctx, resolved_struct, struct_info, bound_fields)
+
+    def _check_struct_literal(
+        self,
+        ctx,
+        resolved_struct,
+        struct_info: StructInstance | None,
+        bound_fields: list[BoundStructField],
+    ) -> BaseType:
+        """Check bound struct literal fields against the struct declaration and record the literal's type."""
+        concrete_anonymous_struct_info = None
         provided_exprs = {field.name: field for field in bound_fields}
         if struct_info is not None:
             field_map = {field.name: field for field in struct_info.fields}
             for field_name, bound_field in provided_exprs.items():
                 expected_field = field_map.get(field_name)
                 if expected_field is None:
-                    raise ZincTypeError(f"struct '{struct_info.name}' has no field '{field_name}'")
+                    raise ZincTypeError(f"struct '{struct_info.name}' has no field '{field_name}'", code="Z0007")
                 if expected_field.is_infer:
                     continue
                 actual_info = bound_field.value_info
                 actual_expr_ctx = None if bound_field.spread_source_expr is not None else bound_field.expression
                 actual_symbol = self._expr_symbol(actual_expr_ctx) if actual_expr_ctx is not None else None
+                if expected_field.array_info is not None and actual_symbol is not None and actual_info.array_info is not None:
+                    self._fit_array_literal(actual_expr_ctx, expected_field.array_info.length)
+                    actual_info.array_info.length = actual_symbol.array_length
                 if not self._assignment_metadata_compatible(
                     expected_field.resolved_type,
                     actual_info.base_type,
@@ -11773,7 +13514,11 @@ class SymbolTableVisitor(zincVisitor):
 
         if target.IDENTIFIER():
             var_name = target.IDENTIFIER().getText()
-            existing = self.symbols.lookup_by_id(var_name)
+            previous = self.symbols.lookup_by_id(var_name)
+            # `let` declares a new variable, so whatever the name held before is left alone.
+            existing = None if is_let_binding(target) else previous
+            if existing is not None and existing.resolved_type == BaseType.ARRAY:
+                self._fit_array_literal(ctx.expression(), existing.array_length)
 
             expr_symbol = self._expr_symbol(ctx.expression())
             expr_info = self._value_info_from_symbol(expr_type, expr_symbol)
@@ -11887,7 +13632,20 @@ class SymbolTableVisitor(zincVisitor):
                 )
             ):
                 expected_label = existing.declared_exact_type or type_to_rust(existing.resolved_type)
-                raise ZincTypeError(f"variable '{var_name}' expects a compatible '{expected_label}' value")
+                found = exact_type_to_rust(expr_exact_type, expr_type)
+                existing_array_info = self._array_info_from_symbol(existing)
+                if existing_array_info is not None and expr_array_info is not None:
+                    expected_label = existing_array_info.to_rust_type(as_reference=False)
+                    found = expr_array_info.to_rust_type(as_reference=False)
+                raise ZincTypeError(
+                    f"variable '{var_name}' expects a compatible '{expected_label}' value",
+                    ctx=ctx.expression(),
+                    expected=expected_label,
+                    found=expr_struct_qualified_name.rsplit("::", 1)[-1] if expr_struct_qualified_name else found,
+                    notes=(f"'{var_name}' is declared as '{expected_label}'", *self._inferred_type_notes(ctx.expression())),
+                    suggestions=("store a value of a different type under a new name",),
+                    code="Z0003",
+                )
 
             if expr_type == BaseType.CHANNEL and expr_channel_info is not None:
                 existing_channel_info = self._channel_infos.get(var_name)
@@ -11929,7 +13687,7 @@ class SymbolTableVisitor(zincVisitor):
                     resolved_type=expr_type,
                     interval=target.getSourceInterval(),
                     exact_type=expr_exact_type,
-                    is_shadow=False,
+                    is_shadow=previous is not None,
                     constant_value=expr_constant_value,
                     line_num=ctx.start.line if ctx.start is not None else 0,
                 )
@@ -11982,6 +13740,7 @@ class SymbolTableVisitor(zincVisitor):
                 )
             ):
                 # Type change - create shadow symbol
+                self._require_let_to_change_type(existing, var_name, ctx.expression(), expr_type, expr_exact_type)
                 new_sym = self.symbols.define(
                     id=var_name,
                     kind=SymbolKind.VARIABLE,
@@ -12018,6 +13777,7 @@ class SymbolTableVisitor(zincVisitor):
                     if existing.dict_info is None:
                         existing.dict_info = expr_dict_info
                     elif existing.dict_info.kind != expr_dict_info.kind:
+                        self._require_let_to_change_type(existing, var_name, ctx.expression(), expr_type, expr_exact_type)
                         new_sym = self.symbols.define(
                             id=var_name,
                             kind=SymbolKind.VARIABLE,
@@ -12036,6 +13796,7 @@ class SymbolTableVisitor(zincVisitor):
                                 existing.dict_info.value_type, expr_dict_info.value_type, "dict value"
                             )
                         except ZincTypeError:
+                            self._require_let_to_change_type(existing, var_name, ctx.expression(), expr_type, expr_exact_type)
                             new_sym = self.symbols.define(
                                 id=var_name,
                                 kind=SymbolKind.VARIABLE,
@@ -12059,6 +13820,7 @@ class SymbolTableVisitor(zincVisitor):
                     if existing.set_info is None:
                         existing.set_info = expr_set_info
                     elif existing.set_info.kind != expr_set_info.kind:
+                        self._require_let_to_change_type(existing, var_name, ctx.expression(), expr_type, expr_exact_type)
                         new_sym = self.symbols.define(
                             id=var_name,
                             kind=SymbolKind.VARIABLE,
@@ -12074,6 +13836,7 @@ class SymbolTableVisitor(zincVisitor):
                                 existing.set_info.element_type, expr_set_info.element_type, "set element"
                             )
                         except ZincTypeError:
+                            self._require_let_to_change_type(existing, var_name, ctx.expression(), expr_type, expr_exact_type)
                             new_sym = self.symbols.define(
                                 id=var_name,
                                 kind=SymbolKind.VARIABLE,
@@ -12153,6 +13916,7 @@ class SymbolTableVisitor(zincVisitor):
             elif expr_type == BaseType.ARRAY and existing.element_type is not None and self._is_empty_array_literal(ctx.expression()):
                 # Reassigning empty array to existing array that has element type
                 # This is likely shadowing with a different element type
+                self._require_let_to_change_type(existing, var_name, ctx.expression(), expr_type, expr_exact_type)
                 self.symbols.define(
                     id=var_name,
                     kind=SymbolKind.VARIABLE,
@@ -12169,6 +13933,13 @@ class SymbolTableVisitor(zincVisitor):
                     existing.exact_type = expr_exact_type
                 existing.constant_value = expr_constant_value
                 if expr_type == BaseType.ARRAY:
+                    if expr_array_info is not None and expr_array_info.length != existing.array_length:
+                        raise ZincTypeError(
+                            f"array '{var_name}' can't change between a growable list and a fixed-size array, or change its length",
+                            ctx=ctx.expression(),
+                            expected=self._array_info_from_symbol(existing).to_rust_type(as_reference=False),
+                            found=expr_array_info.to_rust_type(as_reference=False),
+                        )
                     existing.callable_info = self._merge_callable_info(
                         existing.callable_info,
                         expr_callable_info,
@@ -12195,21 +13966,34 @@ class SymbolTableVisitor(zincVisitor):
                     self._struct_symbol_bindings[existing.unique_name] = expr_struct_qualified_name
                 if expr_type == BaseType.STRUCT:
                     existing.anonymous_struct_info = expr_anonymous_struct_info
-                # Still create entry in _by_interval for this assignment
-                self.symbols.define_temp(
-                    resolved_type=expr_type,
-                    interval=target.getSourceInterval(),
-                    exact_type=expr_exact_type,
-                )
+                if expr_type == BaseType.STRING:
+                    if appended_pieces(var_name, ctx.expression()) is not None:
+                        self._mark_appended(existing)
+                    # Codegen finds the binding through the temp, to keep an appended string owned
+                    self._define_assignment_temp_for_binding(existing, target.getSourceInterval(), exact_type=expr_exact_type)
+                else:
+                    # Still create entry in _by_interval for this assignment
+                    self.symbols.define_temp(
+                        resolved_type=expr_type,
+                        interval=target.getSourceInterval(),
+                        exact_type=expr_exact_type,
+                    )
         elif target.memberAccess():
             member_ctx = target.memberAccess()
             receiver_ctx = member_ctx.expression()
+            static_field = self._static_field_target(extract_identifier_path(member_ctx))
+            if static_field is not None:
+                self._check_static_field_assignment(ctx, *static_field, expr_type, assignment_op)
+                return
             if isinstance(receiver_ctx, ZincParser.PrimaryExprContext):
                 primary = receiver_ctx.primaryExpression()
                 if primary and primary.IDENTIFIER():
                     receiver_symbol = self.symbols.lookup_by_id(primary.IDENTIFIER().getText())
                     if receiver_symbol is not None:
                         receiver_symbol.is_mutated = True
+                        self._require_named_field_access(
+                            receiver_symbol, primary.IDENTIFIER().getText(), member_ctx.IDENTIFIER().getText(), member_ctx, write=True
+                        )
             self.symbols.define_temp(
                 resolved_type=expr_type,
                 interval=target.getSourceInterval(),
@@ -12289,6 +14073,10 @@ class SymbolTableVisitor(zincVisitor):
                 struct_qualified_name = self._struct_qualified_name_for_symbol(collection_symbol)
                 if self._operator_methods(struct_qualified_name, INDEX_OPERATOR_SYMBOL):
                     raise ZincTypeError("overloaded index assignment is not supported")
+            if collection_type == BaseType.ARRAY:
+                self._check_array_element_write(collection_ctx, ctx.expression(), expr_type)
+                self._define_list_element_target(target, collection_ctx)
+                return
             if collection_type != BaseType.DICT:
                 self.symbols.define_temp(
                     resolved_type=expr_type,
@@ -12317,11 +14105,104 @@ class SymbolTableVisitor(zincVisitor):
                 interval=target.getSourceInterval(),
             )
 
+    def _visit_list_method(self, ctx, receiver_ctx, method_name: str, raw_args, arg_types: list[BaseType], arg_exprs) -> BaseType:
+        """Type a call to insert, remove, pop, index_of, or reverse on a list."""
+        self._require_positional_arguments(raw_args, f"array.{method_name}()")
+        arity, arguments = LIST_METHOD_ARGUMENTS[method_name]
+        if len(arg_types) != arity:
+            raise ZincTypeError(
+                f"array.{method_name}() expects {arguments}" if arity else f"array.{method_name}() does not accept arguments",
+                ctx=ctx,
+            )
+        if method_name in {"insert", "remove"} and arg_types[0] not in (BaseType.INTEGER, BaseType.UNKNOWN):
+            raise ZincTypeError(f"array.{method_name}() expects an integer index", ctx=arg_exprs[0])
+        array_info = self._array_info_from_symbol(self._expr_symbol(receiver_ctx))
+        element_info = self._list_element_value_info(array_info)
+        if method_name == "insert":
+            self._check_array_element_write(receiver_ctx, arg_exprs[1], arg_types[1])
+        elif is_mutating_method(BaseType.ARRAY, method_name):
+            self._mark_element_owner_mutated(receiver_ctx)
+        elif element_info.base_type not in (BaseType.UNKNOWN, arg_types[0]) and arg_types[0] != BaseType.NEVER:
+            if not (element_info.base_type == BaseType.FLOAT and arg_types[0] == BaseType.INTEGER):
+                expected = exact_type_to_rust(element_info.exact_type, element_info.base_type)
+                raise ZincTypeError(
+                    f"array.index_of() looks for a '{expected}' value",
+                    ctx=arg_exprs[0],
+                    expected=expected,
+                    found=self._value_type_label(self._value_info_for_value_context(arg_exprs[0])),
+                )
+
+        if method_name == "remove":
+            return self._record_value_info(ctx.getSourceInterval(), element_info).resolved_type
+        if method_name in {"pop", "index_of"}:
+            if method_name == "index_of":
+                some_type = self._value_spec_from_parts(BaseType.INTEGER, exact_type=default_exact_type(BaseType.INTEGER))
+            else:
+                some_type = self._value_spec_from_value_info(element_info)
+            temp = self.symbols.define_temp(resolved_type=BaseType.OPTION, interval=ctx.getSourceInterval())
+            temp.option_info = OptionTypeInfo(some_type=some_type)
+            return BaseType.OPTION
+        self.symbols.define_temp(resolved_type=BaseType.VOID, interval=ctx.getSourceInterval())
+        return BaseType.VOID
+
+    def _list_element_value_info(self, array_info: ArrayTypeInfo | None) -> ResolvedValueInfo:
+        """Describe the elements of a list, as a value remove() or pop() hands back."""
+        if array_info is None:
+            return ResolvedValueInfo(base_type=BaseType.UNKNOWN)
+        return ResolvedValueInfo(
+            base_type=array_info.element_type,
+            exact_type=array_info.element_exact_type,
+            array_info=self._copy_array_info(array_info.element_array_info),
+            tuple_info=self._copy_tuple_info(array_info.element_tuple_info),
+            callable_info=self._copy_callable_info(array_info.element_callable_info),
+            struct_qualified_name=array_info.element_struct_qualified_name,
+            anonymous_struct_info=self._copy_anonymous_struct_info(array_info.element_anonymous_struct_info),
+            result_info=self._copy_result_info(array_info.element_result_info),
+            option_info=self._copy_option_info(array_info.element_option_info),
+        )
+
+    def _check_array_element_write(self, collection_ctx, value_ctx, value_type: BaseType) -> None:
+        """Check a value written into a list element, as in grid[i][j] = v, and mark the list as mutated."""
+        collection_symbol = self._expr_symbol(collection_ctx)
+        element_type = collection_symbol.element_type if collection_symbol is not None else None
+        if element_type not in (None, BaseType.UNKNOWN, value_type) and value_type != BaseType.NEVER:
+            expected = exact_type_to_rust(collection_symbol.element_exact_type, element_type)
+            raise ZincTypeError(
+                f"an element of this list expects a '{expected}' value",
+                ctx=value_ctx,
+                expected=expected,
+                found=self._value_type_label(self._value_info_for_value_context(value_ctx)),
+            )
+        self._mark_element_owner_mutated(collection_ctx)
+
+    def _define_list_element_target(self, target, collection_ctx) -> None:
+        """Record the element type of a list element an assignment writes to."""
+        collection_symbol = self._expr_symbol(collection_ctx)
+        self.symbols.define_temp(
+            resolved_type=(collection_symbol.element_type if collection_symbol else None) or BaseType.UNKNOWN,
+            interval=target.getSourceInterval(),
+            exact_type=collection_symbol.element_exact_type if collection_symbol else None,
+        )
+
+    def _mark_element_owner_mutated(self, collection_ctx) -> None:
+        """Mark the variable that holds an indexed list as mutated, through any rows or fields in between."""
+        root = collection_ctx
+        while isinstance(root, (ZincParser.IndexAccessExprContext, ZincParser.MemberAccessExprContext, ZincParser.ParenExprContext)):
+            root = root.expression(0) if isinstance(root, ZincParser.IndexAccessExprContext) else root.expression()
+        if isinstance(root, ZincParser.PrimaryExprContext) and root.primaryExpression().IDENTIFIER():
+            symbol = self.symbols.lookup_by_id(root.primaryExpression().IDENTIFIER().getText())
+            if symbol is not None:
+                symbol.is_mutated = True
+
     def _visit_compound_assignment(self, ctx: ZincParser.VariableAssignmentContext, expr_type: BaseType, assignment_op: str) -> None:
         """Resolve compound assignment without creating shadow bindings."""
         target = ctx.assignmentTarget()
         if target.tupleAssignmentTarget():
             raise ZincTypeError(f"operator '{assignment_op}' cannot be used with tuple destructuring")
+        static_field = self._static_field_target(extract_identifier_path(target.memberAccess())) if target.memberAccess() else None
+        if static_field is not None:
+            self._check_static_field_assignment(ctx, *static_field, expr_type, assignment_op)
+            return
         base_operator = ASSIGNMENT_TO_BINARY_OPERATOR.get(assignment_op)
         if base_operator is not None and target.IDENTIFIER():
             var_name = target.IDENTIFIER().getText()
@@ -12367,6 +14248,15 @@ class SymbolTableVisitor(zincVisitor):
                 existing.constant_value = None
                 self._define_assignment_temp_for_binding(existing, target.getSourceInterval())
                 return
+            if existing is not None and existing.resolved_type == BaseType.STRING and assignment_op == "+=":
+                self._require_writable_capture(existing, var_name)
+                if expr_type != BaseType.STRING:
+                    raise ZincTypeError(f"operator '+=' on string '{var_name}' requires a string value", ctx=ctx.expression())
+                existing.is_mutated = True
+                existing.constant_value = None
+                self._mark_appended(existing)
+                self._define_assignment_temp_for_binding(existing, target.getSourceInterval())
+                return
         is_bitwise = assignment_op in BITWISE_ASSIGNMENT_OPERATORS
         is_shift = assignment_op in SHIFT_ASSIGNMENT_OPERATORS
         if is_bitwise:
@@ -12406,23 +14296,84 @@ class SymbolTableVisitor(zincVisitor):
                 metadata_compatible = True
             if not metadata_compatible:
                 expected_label = existing.declared_exact_type or existing.exact_type or type_to_rust(existing.resolved_type)
-                raise ZincTypeError(f"variable '{var_name}' expects a compatible '{expected_label}' value")
+                raise ZincTypeError(f"variable '{var_name}' expects a compatible '{expected_label}' value", code="Z0003")
 
             existing.is_mutated = True
             existing.constant_value = None
             self._define_assignment_temp_for_binding(existing, target.getSourceInterval())
             return
 
+        index_access = target.indexAccess()
+        if index_access is not None and self.visit(index_access.expression(0)) == BaseType.ARRAY:
+            self.visit(index_access.expression(1))
+            self._mark_element_owner_mutated(index_access.expression(0))
+            self._define_list_element_target(target, index_access.expression(0))
+            return
+
         self.symbols.define_temp(
             resolved_type=expr_type,
             interval=target.getSourceInterval(),
         )
 
+    def _static_field_target(self, path: list[str] | None) -> tuple[StructInstance, StructFieldInfo] | None:
+        """Return the struct and static field that `Type.NAME` names, if it names one."""
+        static_target = self.module_graph.resolve_static_method_target(self._current_module, path) if path else None
+        if static_target is None or static_target[0].kind != "struct":
+            return None
+        owner_symbol, name = static_target
+        struct = self.atlas.structs.get(owner_symbol.qualified_name)
+        field = next((candidate for candidate in struct.static_fields if candidate.name == name), None) if struct else None
+        return (struct, field) if field is not None else None
+
+    def _check_static_field_assignment(
+        self, ctx, struct: StructInstance, field: StructFieldInfo, expr_type: BaseType, assignment_op: str
+    ) -> None:
+        """Check a write to a static field, which keeps the type of its initial value."""
+        label = f"{struct.name}.{field.name}"
+        if field.is_const:
+            raise ZincTypeError(f"static field '{label}' is const and cannot be assigned", ctx=ctx.assignmentTarget())
+        expr_symbol = self._expr_symbol(ctx.expression())
+        if assignment_op == "+=" and field.resolved_type == BaseType.STRING:
+            if expr_type != BaseType.STRING:
+                raise ZincTypeError(f"operator '+=' on string '{label}' requires a string value", ctx=ctx.expression())
+        elif assignment_op != "=":
+            if field.resolved_type not in {BaseType.INTEGER, BaseType.FLOAT}:
+                raise ZincTypeError(f"operator '{assignment_op}' requires a numeric target")
+            if expr_type not in {BaseType.INTEGER, BaseType.FLOAT}:
+                raise ZincTypeError(f"operator '{assignment_op}' requires a numeric value")
+            if field.resolved_type == BaseType.INTEGER and expr_type == BaseType.FLOAT:
+                raise ZincTypeError(f"operator '{assignment_op}' cannot store a float in integer static field '{label}'")
+        elif expr_type != field.resolved_type or not self._assignment_metadata_compatible(
+            field.resolved_type,
+            expr_type,
+            expected_exact_type=field.exact_type,
+            actual_exact_type=expr_symbol.exact_type if expr_symbol else None,
+            actual_constant_value=self._literal_constant_value_for_expr(ctx.expression(), expr_symbol),
+        ):
+            raise ZincTypeError(
+                f"static field '{label}' expects a compatible '{field.exact_type}' value",
+                ctx=ctx.expression(),
+                expected=field.exact_type,
+                found=exact_type_to_rust(expr_symbol.exact_type if expr_symbol else None, expr_type),
+                code="Z0003",
+            )
+        self.symbols.define_temp(
+            resolved_type=field.resolved_type,
+            interval=ctx.assignmentTarget().getSourceInterval(),
+            exact_type=field.exact_type,
+        )
+
     def _record_return_expression(self, expr_ctx) -> None:
         """Visit a return-value expression and merge it into current function metadata."""
         return_type = self.visit(expr_ctx)
         expr_symbol = self.symbols.lookup_by_interval(expr_ctx.getSourceInterval(), self._current_function)
         return_exact_type = expr_symbol.exact_type if expr_symbol else self._resolved_exact_type(return_type, None)
+        if return_type == BaseType.ARRAY and expr_symbol:
+            self._current_return_array_info = self._merge_array_info(
+                self._current_return_array_info,
+                self._array_info_from_symbol(expr_symbol),
+                "function return paths",
+            )
         if return_type == BaseType.DICT and expr_symbol:
             self._current_return_dict_info = self._merge_dict_info(self._current_return_dict_info, expr_symbol.dict_info)
         if return_type == BaseType.SET and expr_symbol:
@@ -12510,6 +14461,8 @@ class SymbolTableVisitor(zincVisitor):
         if field.array_info is not None:
             symbol.element_type = field.array_info.element_type
             symbol.element_exact_type = field.array_info.element_exact_type
+            symbol.array_length = field.array_info.length
+            symbol.element_array_info = self._copy_array_info(field.array_info.element_array_info)
             symbol.tuple_info = self._copy_tuple_info(field.array_info.element_tuple_info)
             symbol.callable_info = self._copy_callable_info(field.array_info.element_callable_info)
             symbol.element_struct_qualified_name = field.array_info.element_struct_qualified_name
@@ -12545,6 +14498,8 @@ class SymbolTableVisitor(zincVisitor):
             array_info = value_info.array_info
             symbol.element_type = array_info.element_type if array_info else None
             symbol.element_exact_type = array_info.element_exact_type if array_info else None
+            symbol.array_length = array_info.length if array_info else None
+            symbol.element_array_info = self._copy_array_info(array_info.element_array_info) if array_info else None
             symbol.tuple_info = self._copy_tuple_info(array_info.element_tuple_info) if array_info else None
             symbol.callable_info = self._copy_callable_info(array_info.element_callable_info) if array_info else None
             symbol.element_struct_qualified_name = array_info.element_struct_qualified_name if array_info else None
@@ -12583,26 +14538,21 @@ class SymbolTableVisitor(zincVisitor):
         if scrutinee.base_type == BaseType.RESULT:
             if scrutinee.result_info is None:
                 raise ZincTypeError("match on Result requires resolved payload metadata")
-            covered_variants: set[str] = set()
-            saw_wildcard = False
-            arms = list(ctx.matchArm())
-            for arm_index, arm_ctx in enumerate(arms):
+            covered_variants: dict[str, ZincParser.MatchArmContext] = {}
+            wildcard_arm = None
+            for arm_ctx in ctx.matchArm():
                 pattern_ctx = arm_ctx.pattern()
                 is_wildcard = pattern_ctx.getText() == "_"
+                self._require_reachable_match_arm(arm_ctx, wildcard_arm)
                 if is_wildcard:
-                    if saw_wildcard:
-                        raise ZincTypeError("match supports at most one wildcard arm")
-                    if arm_index != len(arms) - 1:
-                        raise ZincTypeError("wildcard match arm must be last")
-                    saw_wildcard = True
+                    wildcard_arm = arm_ctx
                 else:
                     result_pattern = pattern_ctx.resultOptionPattern()
                     if result_pattern is None or result_pattern.SOME() or result_pattern.NONE():
                         raise ZincTypeError("Result match arms must use Ok(...), Err(...), or '_'")
-                    variant_name = "ok" if result_pattern.OK() else "err"
-                    if variant_name in covered_variants:
-                        raise ZincTypeError(f"duplicate match arm for {variant_name}(...)")
-                    covered_variants.add(variant_name)
+                    variant_name = "Ok" if result_pattern.OK() else "Err"
+                    self._require_new_match_variant(f"{variant_name}(...)", arm_ctx, covered_variants.get(variant_name))
+                    covered_variants[variant_name] = arm_ctx
                 block_name = self._next_block_name("match")
                 self.symbols.enter_scope(block_name)
                 try:
@@ -12622,33 +14572,28 @@ class SymbolTableVisitor(zincVisitor):
                         self.visit(arm_ctx.expression())
                 finally:
                     self.symbols.exit_scope()
-            if not saw_wildcard and covered_variants != {"ok", "err"}:
-                missing = "ok" if "ok" not in covered_variants else "err"
-                raise ZincTypeError(f"non-exhaustive match for Result; missing {missing}(...) arm")
+            self._check_match_exhaustive(ctx, "Result", {"Ok": "Ok(...)", "Err": "Err(...)"}, covered_variants, wildcard_arm)
             return
         if scrutinee.base_type == BaseType.OPTION:
             if scrutinee.option_info is None:
                 raise ZincTypeError("match on Option requires resolved payload metadata")
-            covered_variants: set[str] = set()
-            saw_wildcard = False
-            arms = list(ctx.matchArm())
-            for arm_index, arm_ctx in enumerate(arms):
+            covered_variants: dict[str, ZincParser.MatchArmContext] = {}
+            wildcard_arm = None
+            for arm_ctx in ctx.matchArm():
                 pattern_ctx = arm_ctx.pattern()
                 is_wildcard = pattern_ctx.getText() == "_"
+                self._require_reachable_match_arm(arm_ctx, wildcard_arm)
                 if is_wildcard:
-                    if saw_wildcard:
-                        raise ZincTypeError("match supports at most one wildcard arm")
-                    if arm_index != len(arms) - 1:
-                        raise ZincTypeError("wildcard match arm must be last")
-                    saw_wildcard = True
+                    wildcard_arm = arm_ctx
                 else:
                     option_pattern = pattern_ctx.resultOptionPattern()
                     if option_pattern is None or option_pattern.OK() or option_pattern.ERR():
                         raise ZincTypeError("Option match arms must use Some(...), None, or '_'")
-                    variant_name = "some" if option_pattern.SOME() else "none"
-                    if variant_name in covered_variants:
-                        raise ZincTypeError(f"duplicate match arm for {variant_name}")
-                    covered_variants.add(variant_name)
+                    variant_name = "Some" if option_pattern.SOME() else "None"
+                    self._require_new_match_variant(
+                        "Some(...)" if option_pattern.SOME() else "None", arm_ctx, covered_variants.get(variant_name)
+                    )
+                    covered_variants[variant_name] = arm_ctx
                 block_name = self._next_block_name("match")
                 self.symbols.enter_scope(block_name)
                 try:
@@ -12666,9 +14611,7 @@ class SymbolTableVisitor(zincVisitor):
                         self.visit(arm_ctx.expression())
                 finally:
                     self.symbols.exit_scope()
-            if not saw_wildcard and covered_variants != {"some", "none"}:
-                missing = "some" if "some" not in covered_variants else "none"
-                raise ZincTypeError(f"non-exhaustive match for Option; missing {missing} arm")
+            self._check_match_exhaustive(ctx, "Option", {"Some": "Some(...)", "None": "None"}, covered_variants, wildcard_arm)
             return
         if scrutinee.base_type != BaseType.ENUM or scrutinee.exact_type is None:
             raise ZincTypeError("match currently supports enum, Result, and Option values only")
@@ -12677,23 +14620,19 @@ class SymbolTableVisitor(zincVisitor):
 
         enum_info = self._analyze_enum_by_qualified_name(scrutinee.exact_type)
         variant_map = {variant.name: variant for variant in enum_info.variants}
-        covered_variants: set[str] = set()
-        saw_wildcard = False
-        arms = list(ctx.matchArm())
+        covered_variants: dict[str, ZincParser.MatchArmContext] = {}
+        wildcard_arm = None
 
-        for arm_index, arm_ctx in enumerate(arms):
+        for arm_ctx in ctx.matchArm():
             pattern_ctx = arm_ctx.pattern()
             is_wildcard = pattern_ctx.getText() == "_"
             variant: EnumVariantInfo | None = None
             variant_name: str | None = None
             field_patterns = []
 
+            self._require_reachable_match_arm(arm_ctx, wildcard_arm)
             if is_wildcard:
-                if saw_wildcard:
-                    raise ZincTypeError("match supports at most one wildcard arm")
-                if arm_index != len(arms) - 1:
-                    raise ZincTypeError("wildcard match arm must be last")
-                saw_wildcard = True
+                wildcard_arm = arm_ctx
             else:
                 enum_pattern = pattern_ctx.enumVariantPattern()
                 if enum_pattern is None:
@@ -12703,15 +14642,16 @@ class SymbolTableVisitor(zincVisitor):
                     enum_pattern.enumVariantPath().getText().split("."),
                 )
                 if variant_target is None:
-                    raise ZincTypeError(f"unknown enum variant '{enum_pattern.enumVariantPath().getText()}'")
+                    raise ZincTypeError(f"unknown enum variant '{enum_pattern.enumVariantPath().getText()}'", code="Z0008")
                 enum_symbol, variant_name = variant_target
                 if enum_symbol.qualified_name != enum_info.qualified_name:
                     raise ZincTypeError(
                         f"match arm '{enum_pattern.enumVariantPath().getText()}' does not belong to enum '{enum_info.name}'"
                     )
-                if variant_name in covered_variants:
-                    raise ZincTypeError(f"duplicate match arm for variant '{enum_info.name}.{variant_name}'")
-                covered_variants.add(variant_name)
+                self._require_new_match_variant(
+                    f"variant '{enum_info.name}.{variant_name}'", arm_ctx, covered_variants.get(variant_name)
+                )
+                covered_variants[variant_name] = arm_ctx
                 variant = variant_map.get(variant_name)
                 if variant is None:
                     raise ZincTypeError(f"enum '{enum_info.name}' has no variant '{variant_name}'")
@@ -12747,7 +14687,7 @@ class SymbolTableVisitor(zincVisitor):
                             seen_bindings.add(binding_name)
                             field = field_map.get(field_name)
                             if field is None:
-                                raise ZincTypeError(f"variant '{enum_info.name}.{variant.name}' has no field '{field_name}'")
+                                raise ZincTypeError(f"variant '{enum_info.name}.{variant.name}' has no field '{field_name}'", code="Z0007")
                             self._define_match_field_binding(binding_token, field)
                         missing_fields = [field.name for field in variant.fields if field.name not in seen_fields]
                         if missing_fields:
@@ -12762,9 +14702,50 @@ class SymbolTableVisitor(zincVisitor):
             finally:
                 self.symbols.exit_scope()
 
-        if not saw_wildcard and len(covered_variants) != len(variant_map):
-            missing_variant = next(variant.name for variant in enum_info.variants if variant.name not in covered_variants)
-            raise ZincTypeError(f"non-exhaustive match for enum '{enum_info.name}'; missing variant '{missing_variant}'")
+        self._check_match_exhaustive(
+            ctx,
+            f"enum '{enum_info.name}'",
+            {variant.name: f"'{variant.name}'" for variant in enum_info.variants},
+            covered_variants,
+            wildcard_arm,
+        )
+
+    @staticmethod
+    def _require_reachable_match_arm(arm_ctx, wildcard_arm) -> None:
+        """Reject an arm that follows the catch-all `_` arm, which already matches everything."""
+        if wildcard_arm is not None:
+            raise ZincTypeError(
+                "unreachable match arm",
+                ctx=arm_ctx.pattern(),
+                notes=(f"the '_' arm on line {wildcard_arm.start.line} already matches every value",),
+                suggestions=("move the '_' arm to the end of the match",),
+            )
+
+    @staticmethod
+    def _require_new_match_variant(description: str, arm_ctx, earlier_arm) -> None:
+        """Reject a second arm for a variant, which the earlier arm shadows."""
+        if earlier_arm is not None:
+            raise ZincTypeError(
+                f"duplicate match arm for {description}",
+                ctx=arm_ctx.pattern(),
+                notes=(f"the arm on line {earlier_arm.start.line} already matches it, so this arm is unreachable",),
+            )
+
+    def _check_match_exhaustive(self, ctx, subject: str, variants: dict[str, str], covered_variants, wildcard_arm) -> None:
+        """Require an arm for every variant unless there is a `_` arm, and warn when the `_` arm can never match.
+
+        `variants` maps each variant name, in declaration order, to how messages show it.
+        """
+        missing = [label for name, label in variants.items() if name not in covered_variants]
+        if missing and wildcard_arm is None:
+            noun = "variant" if len(missing) == 1 else "variants"
+            raise ZincTypeError(
+                f"non-exhaustive match for {subject}; missing {noun} {', '.join(missing)}",
+                ctx=ctx.expression(),
+                suggestions=("add an arm for each missing variant, or a '_' arm for the rest",),
+            )
+        if not missing and wildcard_arm is not None:
+            self._warning("unreachable match arm", wildcard_arm.pattern(), f"every variant of {subject} already has an arm")
 
     def visitIfExpr(self, ctx: ZincParser.IfExprContext) -> BaseType:
         """Visit an if-expression wrapper."""
@@ -12846,6 +14827,7 @@ class SymbolTableVisitor(zincVisitor):
         """Visit if/else statement."""
         for expr in ctx.expression():
             self._require_boolean_condition(expr, "if condition")
+        self._warn_constant_branches(ctx.expression(), ctx.block())
 
         for i, block in enumerate(ctx.block()):
             block_name = self._next_block_name("if")
@@ -12874,6 +14856,7 @@ class SymbolTableVisitor(zincVisitor):
             callable_info: CallableTypeInfo | None = None,
             struct_qualified_name: str | None = None,
             anonymous_struct_info: AnonymousStructTypeInfo | None = None,
+            array_info: ArrayTypeInfo | None = None,
         ) -> None:
             token = tokens[index]
             symbol = self.symbols.define(
@@ -12888,12 +14871,14 @@ class SymbolTableVisitor(zincVisitor):
             symbol.anonymous_struct_info = self._copy_anonymous_struct_info(anonymous_struct_info)
             if struct_qualified_name is not None:
                 self._struct_symbol_bindings[symbol.unique_name] = struct_qualified_name
+            self._apply_array_info_to_symbol(symbol, array_info)
 
         item_tuple_info: TupleTypeInfo | None = None
         item_callable_info: CallableTypeInfo | None = None
         item_exact_type: str | None = None
         item_struct_qualified_name: str | None = None
         item_anonymous_struct_info: AnonymousStructTypeInfo | None = None
+        item_array_info: ArrayTypeInfo | None = None
         if iterable_type == BaseType.INTEGER:
             var_type = BaseType.INTEGER
         elif iterable_type == BaseType.ARRAY:
@@ -12908,6 +14893,8 @@ class SymbolTableVisitor(zincVisitor):
                 if var_type == BaseType.STRUCT:
                     item_struct_qualified_name = expr_symbol.element_struct_qualified_name
                     item_anonymous_struct_info = expr_symbol.element_anonymous_struct_info
+                if var_type == BaseType.ARRAY:
+                    item_array_info = expr_symbol.element_array_info
         elif iterable_type == BaseType.SET:
             var_type = BaseType.UNKNOWN
             if expr_symbol and expr_symbol.set_info:
@@ -12933,6 +14920,14 @@ class SymbolTableVisitor(zincVisitor):
                 if var_type == BaseType.STRUCT:
                     item_struct_qualified_name = chan_info.element_struct_qualified_name
                     item_anonymous_struct_info = chan_info.element_anonymous_struct_info
+        elif iterable_type == BaseType.STRUCT:
+            item = self._struct_iteration_item(ctx.expression(), expr_symbol)
+            var_type = item.base_type
+            item_exact_type = item.exact_type
+            item_tuple_info = item.tuple_info
+            item_callable_info = item.callable_info
+            item_struct_qualified_name = item.struct_qualified_name
+            item_anonymous_struct_info = item.anonymous_struct_info
         else:
             var_type = BaseType.UNKNOWN
 
@@ -12945,6 +14940,7 @@ class SymbolTableVisitor(zincVisitor):
                 item_callable_info,
                 item_struct_qualified_name,
                 item_anonymous_struct_info,
+                item_array_info,
             )
         else:
             if var_type != BaseType.TUPLE or item_tuple_info is None:
@@ -12971,9 +14967,67 @@ class SymbolTableVisitor(zincVisitor):
             self._loop_depth -= 1
             self.symbols.exit_scope()
 
+    def _struct_iteration_item(self, expr_ctx, expr_symbol: Symbol | None) -> ResolvedValueInfo:
+        """Resolve the items a for loop gets from a struct, through its iterate() method or its own next()."""
+        qualified_name = self._struct_qualified_name_for_symbol(expr_symbol)
+        struct = self.atlas.structs.get(qualified_name) if qualified_name else None
+        if struct is None:
+            raise ZincTypeError("for loop cannot iterate this struct value", ctx=expr_ctx)
+        iterator = struct
+        iterate = iterate_method(struct.methods)
+        if iterate is not None:
+            returned = self._resolved_named_type_info(
+                iterate.return_type,
+                source_module_id=iterate.source_module_id,
+                owner_qualified_name=struct.qualified_name,
+                owner_kind="struct",
+            )
+            iterator = self.atlas.structs.get(returned.struct_qualified_name or "")
+            if iterator is None or iterator_next_method(iterator.methods) is None:
+                raise ZincTypeError(
+                    f"'{struct.name}.{ITERATE_METHOD}()' must return a struct with a {NEXT_METHOD}() method",
+                    ctx=expr_ctx,
+                    notes=(f"{NEXT_METHOD}() takes no arguments and returns Some(item) until the items run out, then None",),
+                )
+        next_method = iterator_next_method(iterator.methods)
+        if next_method is None:
+            raise ZincTypeError(
+                f"for loop cannot iterate struct '{struct.name}'",
+                ctx=expr_ctx,
+                suggestions=(
+                    f"declare {NEXT_METHOD}() -> Option<T> on '{struct.name}', "
+                    f"or {ITERATE_METHOD}() returning a struct that has one",
+                ),
+            )
+        if iterate is None and isinstance(expr_ctx, ZincParser.PrimaryExprContext) and expr_ctx.primaryExpression().IDENTIFIER():
+            # The loop advances the iterator it was given, so the variable changes.
+            binding = self.symbols.lookup_by_id(expr_ctx.primaryExpression().IDENTIFIER().getText())
+            if binding is not None:
+                binding.is_mutated = True
+        self.atlas.uses_iteration = True
+        returned = self._resolved_named_type_info(
+            next_method.return_type,
+            source_module_id=next_method.source_module_id,
+            owner_qualified_name=iterator.qualified_name,
+            owner_kind="struct",
+        )
+        if returned.option_info is None:
+            return ResolvedValueInfo(BaseType.UNKNOWN)
+        item = returned.option_info.some_type
+        return ResolvedValueInfo(
+            item.base_type,
+            exact_type=item.exact_type,
+            tuple_info=self._copy_tuple_info(item.tuple_info),
+            callable_info=self._copy_callable_info(item.callable_info),
+            struct_qualified_name=item.struct_qualified_name,
+            anonymous_struct_info=self._copy_anonymous_struct_info(item.anonymous_struct_info),
+        )
+
     def visitWhileStatement(self, ctx: ZincParser.WhileStatementContext) -> None:
         """Visit while loop statement."""
         self.visit(ctx.expression())
+        if _literal_condition(ctx.expression()) is False:
+            self._warning("unreachable loop body", ctx.block(), "the loop condition is always false")
 
         block_name = self._next_block_name("while")
         self.symbols.enter_scope(block_name)
@@ -13000,6 +15054,7 @@ class SymbolTableVisitor(zincVisitor):
         if function_is_operator(ctx):
             raise ZincTypeError("operator declarations must be inside structs")
         name = function_name_from_ctx(ctx)
+        self._validate_marker_decorators(ctx, name, "nested function")
         if decorators_from_ctx(ctx):
             raise ZincTypeError(f"nested function decorator support is not implemented yet: '{name}'")
         info = self._current_lexical_function(name)
@@ -13014,6 +15069,7 @@ class SymbolTableVisitor(zincVisitor):
 
     def visitAsyncFunctionDeclaration(self, ctx: ZincParser.AsyncFunctionDeclarationContext) -> None:
         """Finalize a nested async function declaration without visiting its body here."""
+        self._validate_marker_decorators(ctx, ctx.IDENTIFIER().getText(), "nested function")
         if decorators_from_ctx(ctx):
             raise ZincTypeError(f"async function decorator support is not implemented yet: '{ctx.IDENTIFIER().getText()}'")
         info = self._current_lexical_function(ctx.IDENTIFIER().getText())
@@ -13106,6 +15162,14 @@ class SymbolTableVisitor(zincVisitor):
 
         expr_type = self.visit(ctx.expression())
         assignment_op = ctx.assignmentOperator().getText()
+        if symbol.resolved_type == BaseType.STRING and assignment_op == "+=":
+            if expr_type != BaseType.STRING:
+                raise ZincTypeError(f"operator '+=' on string '{name}' requires a string value", ctx=ctx.expression())
+            symbol.is_mutated = True
+            symbol.constant_value = None
+            self._mark_appended(symbol)
+            self._define_assignment_temp_for_binding(symbol, tokens[1].getSourceInterval())
+            return
         if assignment_op != "=":
             is_bitwise = assignment_op in BITWISE_ASSIGNMENT_OPERATORS
             is_shift = assignment_op in SHIFT_ASSIGNMENT_OPERATORS
@@ -13138,7 +15202,7 @@ class SymbolTableVisitor(zincVisitor):
                 metadata_compatible = True
             if not metadata_compatible:
                 expected_label = symbol.declared_exact_type or symbol.exact_type or type_to_rust(symbol.resolved_type)
-                raise ZincTypeError(f"variable '{name}' expects a compatible '{expected_label}' value")
+                raise ZincTypeError(f"variable '{name}' expects a compatible '{expected_label}' value", code="Z0003")
 
             symbol.is_mutated = True
             symbol.constant_value = None
@@ -13156,6 +15220,8 @@ class SymbolTableVisitor(zincVisitor):
 
         symbol.is_mutated = True
         symbol.constant_value = None
+        if expr_type == BaseType.STRING and appended_pieces(name, ctx.expression()) is not None:
+            self._mark_appended(symbol)
         self._define_assignment_temp_for_binding(symbol, tokens[1].getSourceInterval())
 
     def visitSelectStatement(self, ctx: ZincParser.SelectStatementContext) -> None:
@@ -13238,6 +15304,7 @@ class SymbolTableVisitor(zincVisitor):
     def visitSelectSendCase(self, ctx: ZincParser.SelectSendCaseContext) -> None:
         """Visit a select send case."""
         channel_name = ctx.IDENTIFIER().getText()
+        self._mark_read(self.symbols.lookup_by_id(channel_name))
         value_type = self.visit(ctx.expression())
         value_symbol = self._expr_symbol(ctx.expression())
         value_callable_info = value_symbol.callable_info if value_symbol else None
@@ -13447,6 +15514,7 @@ class SymbolTableVisitor(zincVisitor):
     def visitChannelSendStatement(self, ctx: ZincParser.ChannelSendStatementContext) -> None:
         """Visit channel send statement and infer channel element type."""
         channel_name = ctx.IDENTIFIER().getText()
+        self._mark_read(self.symbols.lookup_by_id(channel_name))
         value_type = self.visit(ctx.expression())
         value_symbol = self._expr_symbol(ctx.expression())
         value_callable_info = value_symbol.callable_info if value_symbol else None
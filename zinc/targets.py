@@ -0,0 +1,145 @@
+"""Compilation targets for generated Rust.
+
+The host target needs nothing special. Other triples are passed to cargo as
+`--target`; cross-compiling needs the standard library for the triple
+(`rustup target add`) and a linker that can produce its binaries. WebAssembly targets have no threads or
+OS signals, so async programs run on a current-thread tokio runtime. The
+browser target (`wasm32-unknown-unknown`) has no stdout and no process entry
+point: it builds as a `cdylib` whose `wasm-bindgen` start function calls
+`main()`, and it routes printing to the browser console.
+"""
+
+import os
+import shutil
+import subprocess
+from dataclasses import dataclass
+from functools import cache
+
+from zinc.exceptions import ZincBuildError
+
+WASI_TRIPLE = "wasm32-wasip1"
+BROWSER_TRIPLE = "wasm32-unknown-unknown"
+TARGET_ALIASES = {"native": None, "wasm": WASI_TRIPLE, "wasi": WASI_TRIPLE, "wasm-browser": BROWSER_TRIPLE}
+WASI_TRIPLES = frozenset({WASI_TRIPLE, "wasm32-wasip2", "wasm32-wasi"})
+
+WASM_BINDGEN_DEPENDENCY = 'wasm-bindgen = "0.2"'
+# getrandom only supports the browser through its JS backend.
+BROWSER_GETRANDOM_DEPENDENCY = 'getrandom = { version = "0.2", features = ["js"] }'
+
+BROWSER_PRELUDE = """\
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = log)]
+    fn __zinc_console_log(message: &str);
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn __zinc_console_error(message: &str);
+}
+
+macro_rules! println {
+    ($($arg:tt)*) => { __zinc_console_log(&format!($($arg)*)) };
+}
+
+macro_rules! eprintln {
+    ($($arg:tt)*) => { __zinc_console_error(&format!($($arg)*)) };
+}
+"""
+
+BROWSER_START = """\
+#[wasm_bindgen(start)]
+pub fn __zinc_start() {
+    main();
+}"""
+
+
+@dataclass(frozen=True)
+class Target:
+    """A Rust target triple; `None` means the host."""
+
+    triple: str | None = None
+
+    @property
+    def is_wasm(self) -> bool:
+        """True for any 32-bit WebAssembly target."""
+        return self.triple is not None and self.triple.startswith("wasm32-")
+
+    @property
+    def is_browser(self) -> bool:
+        """True for the browser target, which has no WASI host."""
+        return self.triple == BROWSER_TRIPLE
+
+    @property
+    def is_wasi(self) -> bool:
+        """True for WASI targets, which run in standalone wasm runtimes."""
+        return self.triple in WASI_TRIPLES
+
+    @property
+    def is_windows(self) -> bool:
+        """True when the program will run on Windows, including host builds on Windows."""
+        if self.triple is None:
+            return os.name == "nt"
+        return "-windows" in self.triple
+
+    @property
+    def is_cross(self) -> bool:
+        """True when the built program can't run on this machine without an emulator."""
+        return self.triple is not None and not self.is_wasm and self.triple != host_triple()
+
+    @property
+    def artifact_suffix(self) -> str:
+        """File suffix of the built program."""
+        if self.is_wasm:
+            return ".wasm"
+        return ".exe" if self.is_windows else ""
+
+
+HOST = Target()
+
+
+@cache
+def host_triple() -> str | None:
+    """The triple rustc builds for by default, or None without a toolchain."""
+    try:
+        result = subprocess.run(["rustc", "-vV"], capture_output=True, text=True)
+    except FileNotFoundError:
+        return None
+    for line in result.stdout.splitlines():
+        if line.startswith("host: "):
+            return line.removeprefix("host: ").strip()
+    return None
+
+
+def cross_linker(target: Target) -> str | None:
+    """Find a GNU cross linker for a Linux triple, e.g. `aarch64-linux-gnu-gcc` for `aarch64-unknown-linux-gnu`."""
+    if not target.is_cross:
+        return None
+    arch, *rest = target.triple.split("-")
+    if rest and rest[0] in ("unknown", "pc"):
+        rest = rest[1:]
+    # Debian names its 32-bit ARM toolchains after the architecture family.
+    candidates = [target.triple, "-".join([arch, *rest])]
+    if arch.startswith("arm"):
+        candidates.append("-".join(["arm", *rest]))
+    for prefix in candidates:
+        linker = shutil.which(f"{prefix}-gcc")
+        if linker is not None:
+            return linker
+    return None
+
+
+def linker_env_var(target: Target) -> str:
+    """The environment variable cargo reads for a target's linker."""
+    return f"CARGO_TARGET_{target.triple.upper().replace('-', '_').replace('.', '_')}_LINKER"
+
+
+def resolve_target(name: str | None) -> Target:
+    """Turn a `--target` value (an alias such as `wasm`, or a triple) into a target."""
+    if name is None:
+        return HOST
+    triple = TARGET_ALIASES.get(name, name)
+    # Triples have at least an architecture and an OS; leave checking the rest to rustc.
+    if triple is not None and (triple.count("-") < 1 or not all(triple.split("-"))):
+        aliases = ", ".join(TARGET_ALIASES)
+        raise ZincBuildError(f"unsupported target '{name}'; expected a target triple such as aarch64-unknown-linux-gnu, or one of: {aliases}")
+    return Target(triple)
@@ -0,0 +1,162 @@
+"""Test discovery and execution for `zinc test`.
+
+Top-level functions marked `@test` are tests. Each source file gets one
+harness program whose `main()` calls every test, so all of them are
+type-checked and generated together; the harness's Rust `main` is then replaced
+with a dispatcher that runs only the test named by its first argument. Every
+test runs in its own process, so a failed assertion (which exits the process
+after printing its source location) fails only that test. With coverage on,
+every test process adds its counts to one file per harness, which is read back
+once all of the file's tests have run.
+"""
+
+import dataclasses
+import subprocess
+from collections.abc import Callable
+from dataclasses import dataclass
+from pathlib import Path
+
+from zinc.build import DEBUG, build_binary, crate_name_for, default_build_dir
+from zinc.codegen import CodeGenVisitor, RustProgram
+from zinc.coverage import CoverageOptions, CoverageReport, coverage_session, read_counts
+from zinc.decorators import has_marker_decorator
+from zinc.exceptions import ZincTypeError
+from zinc.modules import ModuleGraph, build_module_graph
+from zinc.sourcemap import SourceMap
+
+HARNESS_MODULE_ID = "__zinc_test_harness"
+UNKNOWN_TEST_EXIT_CODE = 2
+COVERAGE_COUNTS_FILE_NAME = "zinc-coverage.counts"
+
+
+@dataclass
+class TestResult:
+    """Outcome of one test process."""
+
+    __test__ = False  # not a pytest test class
+
+    name: str
+    passed: bool
+    output: str = ""
+
+
+def discover_tests(file: Path, marker: str = "test") -> tuple[ModuleGraph, list[str]]:
+    """Load a source file as a module and return its functions marked `@<marker>` in source order."""
+    graph = build_module_graph(file, require_main=False)
+    module = graph.get_module(graph.entry_module_id)
+    tests = [name for name, symbol in module.symbols.items() if symbol.kind == "function" and has_marker_decorator(symbol.ctx, marker)]
+    for name in tests:
+        if name not in module.exports:
+            raise ZincTypeError(f"{marker} function '{name}' must be public (drop the leading underscore)")
+    return graph, tests
+
+
+def harness_source(module_id: str, tests: list[str]) -> str:
+    """Render the Zinc harness that makes every test reachable."""
+    calls = "".join(f"    {name}()\n" for name in tests)
+    return f"import {module_id} [{', '.join(tests)}]\n\nfn main() {{\n{calls}}}\n"
+
+
+def rust_calls(codegen: CodeGenVisitor, module_id: str, tests: list[str]) -> dict[str, str]:
+    """Map each test name to the Rust expression that calls its generated function."""
+    functions = {func.qualified_name: func for func in codegen.atlas.functions.values()}
+    calls = {}
+    for name in tests:
+        func = functions[ModuleGraph.qualified_name(module_id, name)]
+        calls[name] = f"{func.mangled_name}().await" if func.is_async else f"{func.mangled_name}()"
+    return calls
+
+
+def dispatch_main_body(arms: dict[str, str], preamble: tuple[str, ...] = ()) -> list[str]:
+    """Render a Rust `main` body that runs the arm named by argv[1]."""
+    lines = [*preamble, "match std::env::args().nth(1).as_deref() {"]
+    for name, statement in arms.items():
+        lines.append(f'    Some("{name}") => {{ {statement} }}')
+    lines.extend(
+        [
+            "    _ => {",
+            '        eprintln!("unknown test");',
+            f"        std::process::exit({UNKNOWN_TEST_EXIT_CODE});",
+            "    }",
+            "}",
+        ]
+    )
+    return ["\n".join(lines)]
+
+
+def compile_harness(
+    file: Path,
+    compile_pipeline: Callable[[Path, dict[Path, str]], CodeGenVisitor],
+    marker: str = "test",
+    coverage: CoverageOptions | None = None,
+) -> tuple[RustProgram, dict[str, str]]:
+    """Compile the harness for a file's `@<marker>` functions; returns the program and each one's Rust call."""
+    graph, tests = discover_tests(file, marker)
+    if not tests:
+        return RustProgram(), {}
+    module_id = graph.entry_module_id
+    harness = graph.package_root / f"{HARNESS_MODULE_ID}.zn"
+    codegen = compile_pipeline(harness, {harness.resolve(): harness_source(module_id, tests)})
+    return codegen.generate(coverage=coverage), rust_calls(codegen, module_id, tests)
+
+
+def compile_tests(
+    file: Path, compile_pipeline: Callable[[Path, dict[Path, str]], CodeGenVisitor], coverage: CoverageOptions | None = None
+) -> tuple[RustProgram, list[str]]:
+    """Compile the test harness for a source file; returns the program and test names."""
+    program, calls = compile_harness(file, compile_pipeline, coverage=coverage)
+    preamble = (coverage_session(),) if coverage is not None else ()
+    main_body = dispatch_main_body({name: f"{call};" for name, call in calls.items()}, preamble)
+    return dataclasses.replace(program, main_body=main_body), list(calls)
+
+
+def tests_build_dir(file: Path) -> Path:
+    """Keep test builds apart from the regular build of the same file."""
+    return default_build_dir(file).with_name(f"{crate_name_for(file)}-tests")
+
+
+def run_tests(binary: Path, tests: list[str], source_map: SourceMap | None = None) -> list[TestResult]:
+    """Run each test in its own process, capturing its output; panic locations are mapped to Zinc source."""
+    results = []
+    for name in tests:
+        result = subprocess.run([str(binary), name], capture_output=True, text=True)
+        output = result.stdout + result.stderr
+        results.append(TestResult(name, result.returncode == 0, source_map.rewrite(output) if source_map is not None else output))
+    return results
+
+
+def build_and_run_tests(
+    file: Path, compile_pipeline: Callable[[Path, dict[Path, str]], CodeGenVisitor], coverage: CoverageReport | None = None
+) -> list[TestResult]:
+    """Compile, build, and run every test in a source file, adding what they ran to `coverage` if given."""
+    counts_path = tests_build_dir(file) / COVERAGE_COUNTS_FILE_NAME
+    options = CoverageOptions(counts_path) if coverage is not None else None
+    program, tests = compile_tests(file, compile_pipeline, options)
+    if not tests:
+        return []
+    binary = build_binary(program, file, build_dir=tests_build_dir(file), profile=DEBUG)
+    counts_path.unlink(missing_ok=True)
+    results = run_tests(binary, tests, SourceMap.load(tests_build_dir(file)))
+    if coverage is not None:
+        coverage.add(program.coverage_points, read_counts(counts_path, len(program.coverage_points)))
+    return results
+
+
+def report(results: dict[Path, list[TestResult]], write: Callable[[str], None] = print) -> bool:
+    """Print a per-test report plus failure output; return True when everything passed."""
+    total = sum(len(file_results) for file_results in results.values())
+    write(f"running {total} test{'s' if total != 1 else ''}")
+    failures = []
+    for file, file_results in results.items():
+        for result in file_results:
+            write(f"test {file}::{result.name} ... {'ok' if result.passed else 'FAILED'}")
+            if not result.passed:
+                failures.append((file, result))
+    if failures:
+        write("\nfailures:")
+        for file, result in failures:
+            write(f"\n---- {file}::{result.name} ----")
+            write(result.output.rstrip("\n") or "(no output)")
+    passed = total - len(failures)
+    write(f"\ntest result: {'FAILED' if failures else 'ok'}. {passed} passed; {len(failures)} failed")
+    return not failures
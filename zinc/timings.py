@@ -0,0 +1,124 @@
+"""Compile-time tracing behind `--timings`: how long each compiler phase took.
+
+The compiler wraps its phases in `phase(...)`: `lex` (reading a module's
+tokens, including macro expansion and `when` blocks), `parse`, `typecheck`,
+`codegen`, `rustfmt`, and `cargo build`. Phases cost next to nothing unless a
+`Timings` is recording them through `record_timings`, which the CLI does for
+`--timings`. Each phase runs once per module, program, or crate, so a report
+adds up the spans of each phase and counts them.
+
+Entry files compiled in worker processes record their own spans and hand them
+back, so a report covers every file. A summed phase can then take longer than
+the command's wall time. `to_chrome_trace` shows the spans on one timeline,
+with a row per process, for chrome://tracing or https://ui.perfetto.dev.
+"""
+
+import json
+import os
+import time
+from collections.abc import Iterator
+from contextlib import contextmanager
+from contextvars import ContextVar
+from dataclasses import asdict, dataclass, field
+
+PHASES = ("lex", "parse", "typecheck", "codegen", "rustfmt", "cargo build")
+
+
+@dataclass(frozen=True)
+class Span:
+    """One run of a phase: `start` is a `time.perf_counter()` reading, and `detail` names the file or crate it worked on."""
+
+    phase: str
+    start: float
+    duration: float
+    detail: str | None = None
+    process: int = field(default_factory=os.getpid)
+
+
+@dataclass
+class Timings:
+    """The spans recorded since `started`, in the order they finished."""
+
+    spans: list[Span] = field(default_factory=list)
+    started: float = field(default_factory=time.perf_counter)
+
+    def totals(self) -> dict[str, tuple[float, int]]:
+        """Each phase's summed seconds and number of spans, in pipeline order."""
+        totals: dict[str, tuple[float, int]] = {}
+        for span in sorted(self.spans, key=lambda span: PHASES.index(span.phase) if span.phase in PHASES else len(PHASES)):
+            seconds, count = totals.get(span.phase, (0.0, 0))
+            totals[span.phase] = (seconds + span.duration, count + 1)
+        return totals
+
+    def render(self, elapsed: float | None = None) -> str:
+        """A table of the phases for people, ending with the wall time since recording started."""
+        elapsed = time.perf_counter() - self.started if elapsed is None else elapsed
+        totals = self.totals()
+        phase_time = sum(seconds for seconds, _ in totals.values()) or 1.0
+        width = max([len("phase"), *map(len, totals)])
+        lines = [f"{'phase':<{width}}  {'time':>10}  {'share':>6}  runs"]
+        for name, (seconds, count) in totals.items():
+            lines.append(f"{name:<{width}}  {_milliseconds(seconds):>10}  {seconds / phase_time:>6.1%}  {count:>4}")
+        lines.append(f"{'total':<{width}}  {_milliseconds(elapsed):>10}")
+        return "\n".join(lines)
+
+    def to_json(self, elapsed: float | None = None) -> str:
+        """The phase totals and every span, in seconds, with span starts relative to `started`."""
+        elapsed = time.perf_counter() - self.started if elapsed is None else elapsed
+        phases = [{"phase": name, "seconds": seconds, "runs": count} for name, (seconds, count) in self.totals().items()]
+        spans = [{**asdict(span), "start": span.start - self.started} for span in self.spans]
+        return json.dumps({"total": elapsed, "phases": phases, "spans": spans}, indent=2)
+
+    def to_chrome_trace(self) -> str:
+        """The spans in the Chrome trace event format, as complete events timed in microseconds."""
+        events = [
+            {
+                "name": span.phase,
+                "cat": "zinc",
+                "ph": "X",
+                "ts": round((span.start - self.started) * 1e6),
+                "dur": round(span.duration * 1e6),
+                "pid": span.process,
+                "tid": span.process,
+                "args": {"detail": span.detail} if span.detail is not None else {},
+            }
+            for span in sorted(self.spans, key=lambda span: span.start)
+        ]
+        return json.dumps({"traceEvents": events, "displayTimeUnit": "ms"}, indent=2)
+
+
+def _milliseconds(seconds: float) -> str:
+    return f"{seconds * 1000:.1f}ms"
+
+
+_active_timings: ContextVar[Timings | None] = ContextVar("timings", default=None)
+
+
+@contextmanager
+def record_timings(timings: Timings | None = None) -> Iterator[Timings]:
+    """Record the phases that run inside the block into `timings`, or into a new `Timings`."""
+    timings = timings if timings is not None else Timings()
+    token = _active_timings.set(timings)
+    try:
+        yield timings
+    finally:
+        _active_timings.reset(token)
+
+
+def active_timings() -> Timings | None:
+    """The `Timings` being recorded, or None when nothing is."""
+    return _active_timings.get()
+
+
+@contextmanager
+def phase(name: str, detail: str | None = None) -> Iterator[None]:
+    """Time the block as a run of the phase `name` when timings are being recorded."""
+    timings = _active_timings.get()
+    if timings is None:
+        yield
+        return
+    start = time.perf_counter()
+    try:
+        yield
+    finally:
+        timings.spans.append(Span(name, start, time.perf_counter() - start, detail))
@@ -0,0 +1,103 @@
+"""Comments recovered from the source text between tokens.
+
+The lexer skips whitespace and comments, so neither reaches the parse tree.
+Everything it skipped lies in the gaps between consecutive tokens, so those
+gaps hold all the trivia a module has. `Trivia` indexes the comments of one
+parsed module by the tokens around them: a comment that starts its own line
+leads the next token, and one that follows code on the same line trails the
+token before it.
+
+`///` comments document the declaration they lead and `//!` comments at the
+top of a file document the module, as in Rust.
+"""
+
+import bisect
+import re
+from collections.abc import Sequence
+from dataclasses import dataclass
+
+from antlr4 import ParserRuleContext, Token
+
+COMMENT_PATTERN = re.compile(r"//[^\r\n]*|/\*.*?\*/", re.DOTALL)
+DOC_MARKER = "///"
+MODULE_DOC_MARKER = "//!"
+
+
+@dataclass(frozen=True)
+class Comment:
+    """A comment as written, `//` or `/* */` included; `line` is 1-based."""
+
+    text: str
+    line: int
+
+    @property
+    def is_doc(self) -> bool:
+        """True for a `///` doc comment; four or more slashes are an ordinary comment, as in Rust."""
+        return self.text.startswith(DOC_MARKER) and not self.text.startswith("////")
+
+    def rust_lines(self, *, doc: bool = False) -> list[str]:
+        """The comment as Rust `//` lines, or `///` lines for a doc comment when `doc` is set.
+
+        Block comments are rewritten because Rust nests `/* */` and Zinc does
+        not, so `/* a /* b */` would leave a Rust block comment open. Doc
+        comments outside declarations, and `//!` anywhere, would be misplaced
+        Rust docs, so they become ordinary comments.
+        """
+        if self.is_doc:
+            return [f"{DOC_MARKER if doc else '//'}{self.text.removeprefix(DOC_MARKER)}".rstrip()]
+        if self.text.startswith(MODULE_DOC_MARKER):
+            return [f"//{self.text.removeprefix(MODULE_DOC_MARKER)}".rstrip()]
+        if self.text.startswith("//"):
+            return [self.text.rstrip()]
+        lines = [line.strip().removeprefix("*").strip() for line in self.text[2:-2].split("\n")]
+        while lines and not lines[0]:
+            lines.pop(0)
+        while lines and not lines[-1]:
+            lines.pop()
+        return [f"// {line}".rstrip() for line in lines]
+
+
+class Trivia:
+    """The comments of one module, keyed by the token they lead or trail."""
+
+    def __init__(self, text: str, tokens: Sequence[Token]):
+        self._leading: dict[int, list[Comment]] = {}
+        self._trailing: dict[int, list[Comment]] = {}
+        line_starts = [0, *(match.end() for match in re.finditer(r"\n", text))]
+        previous: Token | None = None
+        for token in tokens:
+            gap_start = previous.stop + 1 if previous is not None else 0
+            gap_stop = token.start if token.type != Token.EOF else len(text)
+            for match in COMMENT_PATTERN.finditer(text, gap_start, gap_stop):
+                line = bisect.bisect_right(line_starts, match.start())
+                own_line = not text[line_starts[line - 1] : match.start()].strip()
+                comment = Comment(match.group(), line)
+                if own_line or previous is None:
+                    self._leading.setdefault(token.tokenIndex, []).append(comment)
+                else:
+                    self._trailing.setdefault(previous.tokenIndex, []).append(comment)
+            previous = token
+
+    @classmethod
+    def of(cls, tree: ParserRuleContext) -> "Trivia":
+        """Index the comments of a parsed module."""
+        stream = tree.parser.getTokenStream()
+        stream.fill()
+        return cls(str(stream.tokenSource.inputStream), stream.tokens)
+
+    def leading(self, token: Token) -> list[Comment]:
+        """Comments on their own lines between the previous token and `token`."""
+        return self._leading.get(token.tokenIndex, [])
+
+    def trailing(self, token: Token) -> list[Comment]:
+        """Comments after `token` on its line, before the next token."""
+        return self._trailing.get(token.tokenIndex, [])
+
+    def doc(self, token: Token, marker: str = DOC_MARKER) -> str:
+        """The doc text above `token`: its `marker` comments with the marker and one space removed."""
+        lines = []
+        for comment in self.leading(token):
+            if comment.text.startswith(marker) and (marker != DOC_MARKER or comment.is_doc):
+                text = comment.text.removeprefix(marker)
+                lines.append(text.removeprefix(" ").rstrip())
+        return "\n".join(lines)
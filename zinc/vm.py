@@ -0,0 +1,652 @@
+"""Stack machine behind `zinc exec`, which runs programs compiled by `zinc.bytecode`.
+
+Each call runs its code object with an operand stack and a stack of the
+loops and try blocks it is inside. Everything a value does is left to the
+interpreter's `Runtime`, so a program behaves the same however it is run.
+"""
+
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from typing import TextIO
+
+from zinc.ast.types import BaseType
+from zinc.bytecode import OPCODES, BytecodeError, FieldLayout, MethodLayout, Program, TypeLayout
+from zinc.interpreter import (
+    NONE,
+    DictValue,
+    EnumValue,
+    Err,
+    Failure,
+    Function,
+    ModuleValue,
+    Ok,
+    Propagation,
+    RangeValue,
+    Runtime,
+    Scope,
+    SetValue,
+    Some,
+    StructValue,
+    TryBlock,
+    TypeValue,
+    ZincPanic,
+    copy_value,
+    run_on_large_stack,
+)
+
+WRAPPERS = {"Ok": Ok, "Err": Err, "Some": Some}
+
+NOTHING = object()  # The receiver slot of a call that has no receiver to prepend.
+SKIPPED = object()  # A spread that was never evaluated because every value it could supply is given again.
+
+
+@dataclass(frozen=True)
+class _Prepend:
+    """The receiver slot of a uniform call `x.f(y)`, which passes `x` as the first argument of `f`."""
+
+    value: object
+
+
+@dataclass(eq=False)
+class _Loop:
+    exit: int
+    resume: int
+    scope: Scope
+    depth: int
+
+
+@dataclass(eq=False)
+class _Try:
+    after: int
+    family: str | None
+    scope: Scope
+    depth: int
+    block: TryBlock
+
+
+@dataclass(eq=False)
+class _Activation:
+    code: object
+    stack: list = field(default_factory=list)
+    blocks: list = field(default_factory=list)
+    pc: int = 0
+
+
+def _is_number(value: object) -> bool:
+    return isinstance(value, (int, float)) and not isinstance(value, bool)
+
+
+class VirtualMachine(Runtime):
+    """Runs a compiled program's `main()`."""
+
+    error = BytecodeError
+
+    def __init__(self, program: Program, *, stdout: TextIO | None = None, stderr: TextIO | None = None):
+        """Prepare to run `program`, writing its output to `stdout` and `stderr`."""
+        super().__init__(program.default_int, stdout=stdout, stderr=stderr, overflow=program.overflow)
+        self.program = program
+        self._consts: dict[str, object] = {}
+        self._functions: dict[str, object] = {}
+        self._activation: _Activation | None = None
+        self._handlers = {opcode: getattr(self, f"_op_{opcode.lower()}") for opcode in OPCODES}
+
+    # Running
+
+    def _main_function(self) -> object:
+        return self._top_level_function(self.program.main)
+
+    def _panic(self, message: str, ctx) -> ZincPanic:
+        activation = self._activation
+        line, column = activation.code.positions[activation.pc - 1]
+        return ZincPanic(message, f"{activation.code.source}.zn:{line}:{column + 1}")
+
+    def _execute(self, index: int) -> object:
+        """Run a code object in the current scope until it returns."""
+        activation = _Activation(self.program.codes[index])
+        instructions = activation.code.instructions
+        saved = self._activation
+        self._activation = activation
+        try:
+            while True:
+                opcode, argument = instructions[activation.pc]
+                activation.pc += 1
+                try:
+                    if self._handlers[opcode](activation, argument):
+                        return activation.stack.pop()
+                except (Failure, Propagation) as exc:
+                    if not self._recover(activation, exc):
+                        raise
+        finally:
+            self._activation = saved
+
+    def _recover(self, activation: _Activation, exc: Failure | Propagation) -> bool:
+        """End the innermost try block with an `Err` or `None`, if the code is inside one."""
+        while activation.blocks:
+            block = activation.blocks.pop()
+            if isinstance(block, _Try):
+                self.frame.try_blocks.pop()
+                self.scope = block.scope
+                del activation.stack[block.depth :]
+                activation.stack.append(exc.value if isinstance(exc, Propagation) else Err(exc.value))
+                activation.pc = block.after
+                return True
+        return False
+
+    def _unwind_to_loop(self, activation: _Activation) -> _Loop:
+        """Leave the try blocks inside the innermost loop, restoring the loop's scope and stack."""
+        while not isinstance(activation.blocks[-1], _Loop):
+            activation.blocks.pop()
+            self.frame.try_blocks.pop()
+        loop = activation.blocks[-1]
+        self.scope = loop.scope
+        del activation.stack[loop.depth :]
+        return loop
+
+    # Code, names, and types
+
+    def _parameters_of(self, function: Function) -> list[tuple[str, str | None, object]]:
+        return [tuple(parameter) for parameter in self.program.codes[function.code].parameters]
+
+    def _default_value(self, function: Function, default: object) -> object:
+        return self._execute(default)
+
+    def _run_body(self, function: Function) -> object:
+        return self._execute(function.code)
+
+    def _return_type(self, function: Function) -> str | None:
+        return self.program.codes[function.code].return_type
+
+    def _in_code_module(self, index: int) -> object:
+        return self._in_module(self.program.codes[index].module_id, lambda: self._execute(index))
+
+    def _const(self, qualified_name: str) -> object:
+        if qualified_name not in self._consts:
+            self._consts[qualified_name] = self._in_code_module(self.program.consts[qualified_name])
+        return self._consts[qualified_name]
+
+    def _top_level_function(self, qualified_name: str) -> object:
+        if qualified_name not in self._functions:
+            self._functions[qualified_name] = self._in_code_module(self.program.functions[qualified_name])
+        return self._functions[qualified_name]
+
+    def _ref_value(self, reference: list) -> object:
+        kind, target = reference
+        if kind == "module":
+            return ModuleValue(target)
+        if kind == "const":
+            return copy_value(self._const(target))
+        if kind == "function":
+            return self._top_level_function(target)
+        return TypeValue(target, kind)
+
+    def _constant(self, constant: list) -> object:
+        kind = constant[0]
+        if kind == "number":
+            return self._number(constant[1], constant[2])
+        if kind in ("str", "bool"):
+            return constant[1]
+        return {"unit": lambda: None, "none": lambda: NONE, "list": list, "dict": DictValue, "set": SetValue}[kind]()
+
+    def _export(self, module_id: str, name: str) -> object:
+        raise self.error(f"the name '{name}'")
+
+    def _type_member(self, owner: TypeValue, name: str, ctx) -> object:
+        layout = self.program.types[owner.qualified_name]
+        if name in layout.variants:
+            return EnumValue(layout.qualified_name, layout.name, name)
+        method = next((candidate for candidate in layout.methods if candidate.name == name), None)
+        if method is not None:
+            return self._method_function(layout, method)
+        raise self.error(f"'{owner.qualified_name.partition('::')[2]}.{name}'")
+
+    def _method(self, receiver: StructValue | EnumValue, name: str) -> Function | None:
+        layout = self.program.types.get(receiver.qualified_name) if receiver.qualified_name is not None else None
+        method = next((candidate for candidate in layout.methods if candidate.name == name), None) if layout is not None else None
+        if method is None:
+            return None
+        return self._method_function(layout, method, None if method.is_static else receiver)
+
+    def _method_function(self, layout: TypeLayout, method: MethodLayout, receiver: object = None) -> Function:
+        return Function(
+            method.name,
+            method.code,
+            method.module_id,
+            owner=layout,
+            receiver=receiver,
+            has_receiver=receiver is not None,
+            constructs=method.constructs,
+        )
+
+    def _operator_function(self, value: object, symbol: str) -> tuple[Function, bool] | None:
+        if not isinstance(value, StructValue) or value.qualified_name is None:
+            return None
+        layout = self.program.types[value.qualified_name]
+        method = next((method for method in layout.methods if method.operator == symbol), None)
+        return (self._method_function(layout, method), method.is_static) if method is not None else None
+
+    def _field_type(self, qualified_name: str, name: str) -> str | None:
+        return next(info for info in self.program.types[qualified_name].fields if info.name == name).exact_type
+
+    def _struct_for(self, qualified_name: str, swap: bool) -> TypeLayout:
+        """The struct an instantiation builds; a method copied into a composed struct builds that one instead."""
+        owner = self.frame.owner
+        if swap and qualified_name == self.frame.constructs and isinstance(owner, TypeLayout) and owner.kind == "struct":
+            return owner
+        return self.program.types[qualified_name]
+
+    def _struct_definition(self, qualified_name: str) -> TypeLayout:
+        return self.program.types[qualified_name]
+
+    def _new_struct(self, layout: TypeLayout, given: dict) -> StructValue:
+        fields = {}
+        for info in layout.fields:
+            fields[info.name] = self._convert(given[info.name] if info.name in given else self._field_default(info), info.exact_type)
+        return StructValue(layout.qualified_name, layout.name, fields)
+
+    def _static_field(self, qualified_name: str, name: str) -> tuple[str | None, Callable[[], object]] | None:
+        layout = self.program.types.get(qualified_name)
+        info = next((candidate for candidate in layout.statics if candidate.name == name), None) if layout is not None else None
+        return (info.exact_type, lambda: self._field_default(info)) if info is not None else None
+
+    def _field_default(self, info: FieldLayout) -> object:
+        if info.default is not None:
+            return self._in_code_module(info.default)
+        if info.zero is not None:
+            return self._constant(info.zero)
+        raise self.error(f"a default value for the field '{info.name}'")
+
+    def _pattern_matches(self, pattern: list, value: object, bindings: dict) -> bool:
+        kind = pattern[0]
+        if kind == "_":
+            return True
+        if kind == "literal":
+            return self._equal(self._constant(pattern[1]), value)
+        if kind == "none":
+            return value is NONE
+        if kind == "wrap":
+            return isinstance(value, WRAPPERS[pattern[1]]) and self._pattern_matches(pattern[2], value.value, bindings)
+        if kind == "variant":
+            _, qualified_name, variant, fields = pattern
+            if not isinstance(value, EnumValue) or value.qualified_name != qualified_name or value.variant != variant:
+                return False
+            for name, binding in fields:
+                bindings[binding] = value.fields[name]
+            return True
+        if kind == "range":
+            _, low, high, inclusive = pattern
+            return _is_number(value) and low <= value and (value <= high if inclusive else value < high)
+        if kind == "struct":
+            _, name, fields = pattern
+            if not isinstance(value, StructValue) or value.name != name:
+                return False
+            for field_name, sub in fields:
+                if sub is None:
+                    bindings[field_name] = value.fields[field_name]
+                elif not self._pattern_matches(sub, value.fields[field_name], bindings):
+                    return False
+            return True
+        if kind == "tuple":
+            patterns = pattern[1]
+            if not isinstance(value, tuple) or len(value) != len(patterns):
+                return False
+            return all(self._pattern_matches(sub, item, bindings) for sub, item in zip(patterns, value, strict=True))
+        bindings[pattern[1]] = value
+        return True
+
+    @staticmethod
+    def _pop(activation: _Activation, count: int) -> list:
+        if not count:
+            return []
+        values = activation.stack[-count:]
+        del activation.stack[-count:]
+        return values
+
+    # Names and stores
+
+    def _op_load_const(self, activation: _Activation, constant: list) -> None:
+        activation.stack.append(self._constant(constant))
+
+    def _op_load_name(self, activation: _Activation, argument: list) -> None:
+        name, reference = argument
+        holder = self.scope.find(name)
+        if holder is not None:
+            activation.stack.append(holder.variables[name])
+        elif reference is not None:
+            activation.stack.append(self._ref_value(reference))
+        else:
+            raise self.error(f"the name '{name}'")
+
+    def _op_load_global(self, activation: _Activation, argument: list) -> None:
+        reference, name = argument
+        if reference is None:
+            raise self.error(f"the name '{name}'")
+        activation.stack.append(self._ref_value(reference))
+
+    def _op_load_self(self, activation: _Activation, argument: None) -> None:
+        activation.stack.append(self.frame.receiver)
+
+    def _op_store_assign(self, activation: _Activation, name: str) -> None:
+        self._assign(name, activation.stack.pop())
+
+    def _op_store_define(self, activation: _Activation, name: str) -> None:
+        self._define(name, activation.stack.pop())
+
+    def _op_store_let(self, activation: _Activation, name: str) -> None:
+        self._rebind(name, activation.stack.pop())
+
+    def _op_store_outer(self, activation: _Activation, name: str) -> None:
+        self._store(name, activation.stack.pop())
+
+    def _op_assign_tuple(self, activation: _Activation, argument: list) -> None:
+        names, define, annotation = argument
+        self._assign_tuple(names, activation.stack.pop(), self._define if define else self._assign, annotation)
+
+    # Stack
+
+    def _op_pop(self, activation: _Activation, argument: None) -> None:
+        activation.stack.pop()
+
+    def _op_pop_below(self, activation: _Activation, argument: None) -> None:
+        del activation.stack[-2]
+
+    def _op_dup(self, activation: _Activation, argument: None) -> None:
+        activation.stack.append(activation.stack[-1])
+
+    def _op_dup2(self, activation: _Activation, argument: None) -> None:
+        activation.stack.extend(activation.stack[-2:])
+
+    def _op_copy(self, activation: _Activation, argument: None) -> None:
+        activation.stack[-1] = copy_value(activation.stack[-1])
+
+    def _op_convert(self, activation: _Activation, annotation: str) -> None:
+        activation.stack[-1] = self._convert(activation.stack[-1], annotation)
+
+    # Scopes and jumps
+
+    def _op_push_scope(self, activation: _Activation, argument: None) -> None:
+        self.scope = Scope(self.frame, self.scope)
+
+    def _op_pop_scope(self, activation: _Activation, argument: None) -> None:
+        self._leave_scope()
+
+    def _op_jump(self, activation: _Activation, target: int) -> None:
+        activation.pc = target
+
+    def _op_jump_if_false(self, activation: _Activation, target: int) -> None:
+        if not self._truth(activation.stack.pop(), None):
+            activation.pc = target
+
+    # Loops
+
+    def _op_setup_loop(self, activation: _Activation, argument: list) -> None:
+        exit_target, resume = argument
+        activation.blocks.append(_Loop(exit_target, resume, self.scope, len(activation.stack)))
+
+    def _op_pop_block(self, activation: _Activation, argument: None) -> None:
+        activation.blocks.pop()
+
+    def _op_break(self, activation: _Activation, argument: None) -> None:
+        loop = self._unwind_to_loop(activation)
+        activation.blocks.pop()
+        activation.pc = loop.exit
+
+    def _op_continue(self, activation: _Activation, argument: None) -> None:
+        activation.pc = self._unwind_to_loop(activation).resume
+
+    def _op_get_iter(self, activation: _Activation, argument: None) -> None:
+        activation.stack[-1] = self._iterate(activation.stack[-1], None)
+
+    def _op_for_iter(self, activation: _Activation, target: int) -> None:
+        item = next(activation.stack[-1], NOTHING)
+        if item is NOTHING:
+            activation.pc = target
+        else:
+            activation.stack.append(item)
+
+    # Try blocks and control flow
+
+    def _op_setup_try(self, activation: _Activation, argument: list) -> None:
+        family, after = argument
+        block = TryBlock()
+        self.frame.try_blocks.append(block)
+        activation.blocks.append(_Try(after, family, self.scope, len(activation.stack), block))
+
+    def _op_end_try(self, activation: _Activation, argument: None) -> None:
+        entry = activation.blocks.pop()
+        self.frame.try_blocks.pop()
+        family = entry.block.family or (BaseType[entry.family] if entry.family is not None else None)
+        if family is None:
+            raise self.error("try blocks whose Result or Option type is only known from annotations")
+        value = activation.stack.pop()
+        activation.stack.append(Ok(value) if family == BaseType.RESULT else Some(value))
+
+    def _op_unwrap_try(self, activation: _Activation, argument: None) -> None:
+        activation.stack[-1] = self._unwrap_try(activation.stack[-1])
+
+    def _op_unwrap_unless_wrapped(self, activation: _Activation, name: str) -> None:
+        holder = self.scope.find(name)
+        existing = holder.variables[name] if holder is not None else None
+        if not isinstance(existing, (Ok, Err, Some)) and existing is not NONE:
+            activation.stack[-1] = self._unwrap_try(activation.stack[-1])
+
+    def _op_fail(self, activation: _Activation, argument: None) -> None:
+        raise Failure(activation.stack.pop())
+
+    def _op_return(self, activation: _Activation, argument: None) -> bool:
+        return True
+
+    def _op_match(self, activation: _Activation, argument: list) -> None:
+        pattern, next_arm = argument
+        bindings: dict[str, object] = {}
+        if not self._pattern_matches(pattern, activation.stack[-1], bindings):
+            activation.pc = next_arm
+            return
+        self.scope = Scope(self.frame, self.scope)
+        for name, value in bindings.items():
+            self._define(name, copy_value(value))
+
+    def _op_no_match(self, activation: _Activation, argument: None) -> None:
+        raise self._panic("no match arm matched the value", None)
+
+    # Builders
+
+    def _op_build_list(self, activation: _Activation, count: int) -> None:
+        activation.stack.append(self._pop(activation, count))
+
+    def _op_build_repeat(self, activation: _Activation, length: int) -> None:
+        value = activation.stack.pop()
+        activation.stack.append([copy_value(value) for _ in range(length)])
+
+    def _op_build_tuple(self, activation: _Activation, count: int) -> None:
+        activation.stack.append(tuple(self._pop(activation, count)))
+
+    def _op_build_dict(self, activation: _Activation, count: int) -> None:
+        items = self._pop(activation, 2 * count)
+        activation.stack.append(DictValue(dict(zip(items[::2], items[1::2], strict=True))))
+
+    def _op_build_set(self, activation: _Activation, count: int) -> None:
+        activation.stack.append(SetValue(dict.fromkeys(self._pop(activation, count))))
+
+    def _op_build_range(self, activation: _Activation, inclusive: bool) -> None:
+        start, stop = self._pop(activation, 2)
+        activation.stack.append(RangeValue(start, stop, inclusive))
+
+    def _op_build_anon(self, activation: _Activation, names: list) -> None:
+        fields: dict[str, object] = {}
+        for name, value in zip(names, self._pop(activation, len(names)), strict=True):
+            if name is None:
+                fields.update((key, copy_value(item)) for key, item in value.fields.items())
+            else:
+                fields[name] = value
+        activation.stack.append(StructValue(None, "struct", fields))
+
+    def _op_format(self, activation: _Activation, pieces: list) -> None:
+        values = self._pop(activation, len(pieces) - 1)
+        text = [pieces[0]]
+        for value, piece in zip(values, pieces[1:], strict=True):
+            text += [self._display(value), piece]
+        activation.stack.append("".join(text))
+
+    def _op_wrap(self, activation: _Activation, head: str) -> None:
+        activation.stack[-1] = WRAPPERS[head](activation.stack[-1])
+
+    def _op_new_struct(self, activation: _Activation, argument: list) -> None:
+        qualified_name, names, swap = argument
+        layout = self._struct_for(qualified_name, swap)
+        names_in_layout = {info.name for info in layout.fields}
+        given: dict[str, object] = {}
+        for name, value in zip(names, self._pop(activation, len(names)), strict=True):
+            if value is SKIPPED:
+                continue
+            if name is None:
+                given.update((key, copy_value(item)) for key, item in value.fields.items() if key in names_in_layout)
+            else:
+                given[name] = value
+        activation.stack.append(self._new_struct(layout, given))
+
+    def _op_check_field_spread(self, activation: _Activation, argument: list) -> None:
+        qualified_name, later, target = argument
+        if {info.name for info in self._struct_for(qualified_name, True).fields} <= set(later):
+            activation.stack.append(SKIPPED)
+            activation.pc = target
+
+    def _op_new_variant(self, activation: _Activation, argument: list) -> None:
+        qualified_name, variant, names = argument
+        layout = self.program.types[qualified_name]
+        given = dict(zip(names, self._pop(activation, len(names)), strict=True))
+        fields = {info.name: self._convert(given[info.name], info.exact_type) for info in layout.variants[variant]}
+        activation.stack.append(EnumValue(qualified_name, layout.name, variant, fields))
+
+    # Functions
+
+    def _op_make_function(self, activation: _Activation, argument: list) -> None:
+        index, name, closes = argument
+        module_id = self.program.codes[index].module_id
+        activation.stack.append(Function(name, index, module_id, closure=self.scope if closes else None))
+
+    def _op_memoize(self, activation: _Activation, argument: None) -> None:
+        activation.stack[-1].memo = {}
+
+    def _op_decorate(self, activation: _Activation, argument: None) -> None:
+        function, decorator = self._pop(activation, 2)
+        activation.stack.append(self._call(decorator, [function], [], None))
+
+    # Members and indexes
+
+    def _op_load_attr(self, activation: _Activation, name: str) -> None:
+        activation.stack[-1] = self._member(activation.stack[-1], name, None)
+
+    def _op_store_attr(self, activation: _Activation, name: str) -> None:
+        receiver, value = self._pop(activation, 2)
+        self._set_field(receiver, name, value, None)
+
+    def _op_index(self, activation: _Activation, argument: None) -> None:
+        container, index = self._pop(activation, 2)
+        activation.stack.append(self._index(container, index, None))
+
+    def _op_index_checked(self, activation: _Activation, argument: None) -> None:
+        container, index = self._pop(activation, 2)
+        activation.stack.append(self._checked_index(container, index))
+
+    def _op_store_index(self, activation: _Activation, argument: None) -> None:
+        container, index, value = self._pop(activation, 3)
+        self._set_index(container, index, value, None)
+
+    # Operators
+
+    def _op_unary(self, activation: _Activation, operator: str) -> None:
+        activation.stack[-1] = self._unary(operator, activation.stack[-1], None)
+
+    def _op_binary(self, activation: _Activation, operator: str) -> None:
+        left, right = self._pop(activation, 2)
+        activation.stack.append(self._binary(operator, left, right, None))
+
+    def _op_custom_op(self, activation: _Activation, symbol: str) -> None:
+        left, right = self._pop(activation, 2)
+        activation.stack.append(self._call_operator(left, symbol, [right], None))
+
+    def _op_contains(self, activation: _Activation, argument: None) -> None:
+        item, collection = self._pop(activation, 2)
+        if isinstance(collection, StructValue):
+            activation.stack.append(self._call_operator(collection, "in", [item], None))
+        else:
+            activation.stack.append(self._contains(collection, item))
+
+    def _op_short_circuit(self, activation: _Activation, argument: list) -> None:
+        operator, target = argument
+        left = activation.stack[-1]
+        if isinstance(left, StructValue):
+            return
+        if self._truth(left, None) == (operator == "||"):
+            activation.pc = target
+
+    def _op_logic(self, activation: _Activation, operator: str) -> None:
+        left, right = self._pop(activation, 2)
+        if isinstance(left, StructValue):
+            activation.stack.append(self._call_operator(left, operator, [right], None))
+        else:
+            activation.stack.append(self._truth(right, None))
+
+    # Calls
+
+    def _op_push_nothing(self, activation: _Activation, argument: None) -> None:
+        activation.stack.append(NOTHING)
+
+    def _op_load_method(self, activation: _Activation, argument: list) -> None:
+        name, function = argument
+        receiver = activation.stack.pop()
+        callee = self._callable_member(receiver, name, None)
+        if callee is not None:
+            activation.stack += [callee, NOTHING]
+            return
+        # Uniform call syntax: `x.f(y)` calls the function `f(x, y)`.
+        holder = self.scope.find(name)
+        if holder is not None:
+            callee = holder.variables[name]
+        elif function is not None:
+            callee = self._ref_value(function)
+        else:
+            raise self.error(f"'.{name}()' on {self._describe(receiver)}")
+        activation.stack += [callee, _Prepend(receiver)]
+
+    def _op_check_spread(self, activation: _Activation, argument: list) -> None:
+        offset, positional, later, target = argument
+        callee, slot = activation.stack[-offset], activation.stack[-offset + 1]
+        if not isinstance(callee, Function):
+            return
+        skip = 1 if isinstance(slot, _Prepend) else 0
+        parameters = [name for name, _, _ in self._parameters_of(callee)]
+        if set(parameters[skip + positional :]) <= set(later):
+            activation.stack.append(SKIPPED)
+            activation.pc = target
+
+    @staticmethod
+    def _split_arguments(kinds: list, values: list) -> tuple[list, list[tuple[str | None, object]]]:
+        positional = [value for kind, value in zip(kinds, values, strict=True) if kind is None]
+        keywords = [
+            (None if kind == ".." else kind, value)
+            for kind, value in zip(kinds, values, strict=True)
+            if kind is not None and value is not SKIPPED
+        ]
+        return positional, keywords
+
+    def _op_call(self, activation: _Activation, kinds: list) -> None:
+        positional, keywords = self._split_arguments(kinds, self._pop(activation, len(kinds)))
+        callee, slot = self._pop(activation, 2)
+        if isinstance(slot, _Prepend):
+            positional.insert(0, slot.value)
+        activation.stack.append(self._call(callee, positional, keywords, None))
+
+    def _op_call_builtin(self, activation: _Activation, argument: list) -> None:
+        name, kinds, location, sources = argument
+        positional, keywords = self._split_arguments(kinds, self._pop(activation, len(kinds)))
+        holder = self.scope.find(name)
+        if holder is not None:
+            activation.stack.append(self._call(holder.variables[name], positional, keywords, None))
+        else:
+            activation.stack.append(self._builtin(name, positional, location, sources))
+
+
+def run_bytecode(program: Program, *, stdout: TextIO | None = None, stderr: TextIO | None = None) -> int:
+    """Run a compiled program and return its exit status."""
+    return run_on_large_stack(VirtualMachine(program, stdout=stdout, stderr=stderr))
@@ -0,0 +1,81 @@
+"""Polling file watcher used by `zinc run --watch`."""
+
+import subprocess
+import time
+from collections.abc import Callable
+from pathlib import Path
+
+from zinc.build import BUILD_DIR_NAME
+from zinc.modules import MANIFEST_FILE_NAMES
+
+WATCH_INTERVAL_SECONDS = 0.5
+
+
+def source_snapshot(root: Path) -> dict[Path, int]:
+    """Return modification times for every Zinc source and manifest under a package root."""
+    snapshot = {}
+    for path in [path for pattern in ("*.zn", *MANIFEST_FILE_NAMES) for path in root.rglob(pattern)]:
+        if BUILD_DIR_NAME in path.relative_to(root).parts:
+            continue
+        try:
+            snapshot[path] = path.stat().st_mtime_ns
+        except FileNotFoundError:
+            continue
+    return snapshot
+
+
+class SourceWatcher:
+    """Detect added, removed, and modified source files between polls."""
+
+    def __init__(self, root: Path):
+        self.root = root
+        self._snapshot = source_snapshot(root)
+
+    def poll(self) -> bool:
+        """Return True when the source tree changed since the previous poll."""
+        snapshot = source_snapshot(self.root)
+        changed = snapshot != self._snapshot
+        self._snapshot = snapshot
+        return changed
+
+
+def stop_process(process: subprocess.Popen | None) -> None:
+    """Terminate a running program, escalating to kill if it ignores the request."""
+    if process is None or process.poll() is not None:
+        return
+    process.terminate()
+    try:
+        process.wait(timeout=5)
+    except subprocess.TimeoutExpired:
+        process.kill()
+        process.wait()
+
+
+def watch_and_run(
+    root: Path,
+    rebuild: Callable[[], Path | None],
+    args: tuple[str, ...] = (),
+    notify: Callable[[str], None] = print,
+    interval: float = WATCH_INTERVAL_SECONDS,
+) -> None:
+    """Rebuild and restart the program every time a source under `root` changes.
+
+    `rebuild` returns the fresh binary, or None when compilation failed (it is
+    expected to report the error itself). Runs until interrupted with Ctrl-C.
+    """
+    watcher = SourceWatcher(root)
+    process = None
+    try:
+        while True:
+            binary = rebuild()
+            process = subprocess.Popen([str(binary), *args]) if binary is not None else None
+            reported_exit = False
+            while not watcher.poll():
+                if process is not None and not reported_exit and process.poll() is not None:
+                    notify(f"[zinc] program exited with status {process.returncode}; waiting for changes")
+                    reported_exit = True
+                time.sleep(interval)
+            stop_process(process)
+            notify("[zinc] change detected, rebuilding")
+    except KeyboardInterrupt:
+        stop_process(process)